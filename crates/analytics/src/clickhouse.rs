@@ -15,10 +15,14 @@ use super::{
     payments::{
         distribution::PaymentDistributionRow, filters::PaymentFilterRow, metrics::PaymentMetricRow,
     },
-    query::{Aggregate, ToSql, Window},
+    payouts::{
+        distribution::PayoutDistributionRow, filters::PayoutFilterRow, metrics::PayoutMetricRow,
+    },
+    query::{Aggregate, TimeDifference, ToSql, Window},
     refunds::{
         distribution::RefundDistributionRow, filters::RefundFilterRow, metrics::RefundMetricRow,
     },
+    reports::rows::{DisputeReportRow, PaymentReportRow, RefundReportRow},
     sdk_events::{filters::SdkEventFilter, metrics::SdkEventMetricRow},
     types::{AnalyticsCollection, AnalyticsDataSource, LoadRow, QueryExecutionError},
 };
@@ -140,9 +144,8 @@ impl AnalyticsDataSource for ClickhouseClient {
             | AnalyticsCollection::PaymentIntent
             | AnalyticsCollection::PaymentIntentSessionized
             | AnalyticsCollection::Authentications
-            | AnalyticsCollection::Dispute => {
-                TableEngine::CollapsingMergeTree { sign: "sign_flag" }
-            }
+            | AnalyticsCollection::Dispute
+            | AnalyticsCollection::Payout => TableEngine::CollapsingMergeTree { sign: "sign_flag" },
             AnalyticsCollection::DisputeSessionized => {
                 TableEngine::CollapsingMergeTree { sign: "sign_flag" }
             }
@@ -193,6 +196,9 @@ impl super::outgoing_webhook_event::events::OutgoingWebhookLogsFilterAnalytics
 }
 impl super::disputes::filters::DisputeFilterAnalytics for ClickhouseClient {}
 impl super::disputes::metrics::DisputeMetricAnalytics for ClickhouseClient {}
+impl super::payouts::metrics::PayoutMetricAnalytics for ClickhouseClient {}
+impl super::payouts::filters::PayoutFilterAnalytics for ClickhouseClient {}
+impl super::payouts::distribution::PayoutDistributionAnalytics for ClickhouseClient {}
 
 #[derive(Debug, serde::Serialize)]
 struct CkhQuery {
@@ -206,6 +212,36 @@ struct CkhOutput<T> {
     data: Vec<T>,
 }
 
+impl TryInto<PaymentReportRow> for serde_json::Value {
+    type Error = Report<ParsingError>;
+
+    fn try_into(self) -> Result<PaymentReportRow, Self::Error> {
+        serde_json::from_value(self).change_context(ParsingError::StructParseFailure(
+            "Failed to parse PaymentReportRow in clickhouse results",
+        ))
+    }
+}
+
+impl TryInto<RefundReportRow> for serde_json::Value {
+    type Error = Report<ParsingError>;
+
+    fn try_into(self) -> Result<RefundReportRow, Self::Error> {
+        serde_json::from_value(self).change_context(ParsingError::StructParseFailure(
+            "Failed to parse RefundReportRow in clickhouse results",
+        ))
+    }
+}
+
+impl TryInto<DisputeReportRow> for serde_json::Value {
+    type Error = Report<ParsingError>;
+
+    fn try_into(self) -> Result<DisputeReportRow, Self::Error> {
+        serde_json::from_value(self).change_context(ParsingError::StructParseFailure(
+            "Failed to parse DisputeReportRow in clickhouse results",
+        ))
+    }
+}
+
 impl TryInto<ApiLogsResult> for serde_json::Value {
     type Error = Report<ParsingError>;
 
@@ -316,6 +352,36 @@ impl TryInto<RefundDistributionRow> for serde_json::Value {
     }
 }
 
+impl TryInto<PayoutMetricRow> for serde_json::Value {
+    type Error = Report<ParsingError>;
+
+    fn try_into(self) -> Result<PayoutMetricRow, Self::Error> {
+        serde_json::from_value(self).change_context(ParsingError::StructParseFailure(
+            "Failed to parse PayoutMetricRow in clickhouse results",
+        ))
+    }
+}
+
+impl TryInto<PayoutFilterRow> for serde_json::Value {
+    type Error = Report<ParsingError>;
+
+    fn try_into(self) -> Result<PayoutFilterRow, Self::Error> {
+        serde_json::from_value(self).change_context(ParsingError::StructParseFailure(
+            "Failed to parse PayoutFilterRow in clickhouse results",
+        ))
+    }
+}
+
+impl TryInto<PayoutDistributionRow> for serde_json::Value {
+    type Error = Report<ParsingError>;
+
+    fn try_into(self) -> Result<PayoutDistributionRow, Self::Error> {
+        serde_json::from_value(self).change_context(ParsingError::StructParseFailure(
+            "Failed to parse PayoutDistributionRow in clickhouse results",
+        ))
+    }
+}
+
 impl TryInto<FrmMetricRow> for serde_json::Value {
     type Error = Report<ParsingError>;
 
@@ -451,6 +517,12 @@ impl ToSql<ClickhouseClient> for PrimitiveDateTime {
     }
 }
 
+impl ToSql<ClickhouseClient> for TimeDifference {
+    fn to_sql(&self, _table_engine: &TableEngine) -> error_stack::Result<String, ParsingError> {
+        Ok(format!("dateDiff('second', {}, {})", self.start, self.end))
+    }
+}
+
 impl ToSql<ClickhouseClient> for AnalyticsCollection {
     fn to_sql(&self, _table_engine: &TableEngine) -> error_stack::Result<String, ParsingError> {
         match self {
@@ -471,6 +543,7 @@ impl ToSql<ClickhouseClient> for AnalyticsCollection {
             Self::DisputeSessionized => Ok("sessionizer_dispute".to_string()),
             Self::ActivePaymentsAnalytics => Ok("active_payments".to_string()),
             Self::Authentications => Ok("authentications".to_string()),
+            Self::Payout => Ok("payout".to_string()),
         }
     }
 }
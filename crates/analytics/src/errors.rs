@@ -5,7 +5,6 @@ pub type AnalyticsResult<T> = CustomResult<T, AnalyticsError>;
 
 #[derive(Debug, Clone, serde::Serialize, thiserror::Error)]
 pub enum AnalyticsError {
-    #[allow(dead_code)]
     #[error("Not implemented: {0}")]
     NotImplemented(&'static str),
     #[error("Unknown Analytics Error")]
@@ -14,6 +13,14 @@ pub enum AnalyticsError {
     AccessForbiddenError,
     #[error("Failed to fetch currency exchange rate")]
     ForexFetchFailed,
+    #[error("Report not found")]
+    ReportNotFound,
+    #[error("Analytics digest subscription not found")]
+    DigestSubscriptionNotFound,
+    #[error("Analytics digest subscription already exists for the role")]
+    DuplicateDigestSubscription,
+    #[error("Role cannot be subscribed to analytics digests")]
+    InvalidDigestSubscriptionRole,
 }
 
 impl ErrorSwitch<ApiErrorResponse> for AnalyticsError {
@@ -40,6 +47,30 @@ impl ErrorSwitch<ApiErrorResponse> for AnalyticsError {
                 "Failed to fetch currency exchange rate",
                 None,
             )),
+            Self::ReportNotFound => ApiErrorResponse::NotFound(ApiError::new(
+                "IR",
+                0,
+                "Report does not exist or the download link has expired",
+                None,
+            )),
+            Self::DigestSubscriptionNotFound => ApiErrorResponse::NotFound(ApiError::new(
+                "IR",
+                0,
+                "Analytics digest subscription does not exist",
+                None,
+            )),
+            Self::DuplicateDigestSubscription => ApiErrorResponse::Conflict(ApiError::new(
+                "IR",
+                0,
+                "Analytics digest subscription already exists for the role",
+                None,
+            )),
+            Self::InvalidDigestSubscriptionRole => ApiErrorResponse::BadRequest(ApiError::new(
+                "IR",
+                0,
+                "Role does not exist or cannot be subscribed to analytics digests",
+                None,
+            )),
         }
     }
 }
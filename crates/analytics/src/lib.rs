@@ -14,8 +14,10 @@ pub mod opensearch;
 pub mod outgoing_webhook_event;
 pub mod payment_intents;
 pub mod payments;
+pub mod payouts;
 mod query;
 pub mod refunds;
+pub mod reports;
 pub mod sdk_events;
 pub mod search;
 mod sqlx;
@@ -51,11 +53,13 @@ use api_models::analytics::{
         PaymentIntentMetricsBucketIdentifier,
     },
     payments::{PaymentDimensions, PaymentFilters, PaymentMetrics, PaymentMetricsBucketIdentifier},
+    payouts::{PayoutDimensions, PayoutFilters, PayoutMetrics, PayoutMetricsBucketIdentifier},
     refunds::{RefundDimensions, RefundFilters, RefundMetrics, RefundMetricsBucketIdentifier},
     sdk_events::{
         SdkEventDimensions, SdkEventFilters, SdkEventMetrics, SdkEventMetricsBucketIdentifier,
     },
-    Granularity, PaymentDistributionBody, RefundDistributionBody, TimeRange,
+    Granularity, PaymentDistributionBody, PayoutDistributionBody, RefundDistributionBody,
+    TimeRange,
 };
 use clickhouse::ClickhouseClient;
 pub use clickhouse::ClickhouseConfig;
@@ -65,7 +69,7 @@ use router_env::{
     tracing::{self, instrument},
     types::FlowMetric,
 };
-use storage_impl::config::Database;
+use storage_impl::{config::Database, errors::ApplicationError};
 use strum::Display;
 
 use self::{
@@ -77,6 +81,10 @@ use self::{
         distribution::{PaymentDistribution, PaymentDistributionRow},
         metrics::{PaymentMetric, PaymentMetricRow},
     },
+    payouts::{
+        distribution::{PayoutDistribution, PayoutDistributionRow},
+        metrics::{PayoutMetric, PayoutMetricRow},
+    },
     refunds::metrics::{RefundMetric, RefundMetricRow},
     sdk_events::metrics::{SdkEventMetric, SdkEventMetricRow},
     sqlx::SqlxClient,
@@ -841,6 +849,208 @@ impl AnalyticsProvider {
         .await
     }
 
+    pub async fn get_payout_metrics(
+        &self,
+        metric: &PayoutMetrics,
+        dimensions: &[PayoutDimensions],
+        auth: &AuthInfo,
+        filters: &PayoutFilters,
+        granularity: Option<Granularity>,
+        time_range: &TimeRange,
+    ) -> types::MetricsResult<HashSet<(PayoutMetricsBucketIdentifier, PayoutMetricRow)>> {
+        // Metrics to get the fetch time for each payout metric
+        metrics::request::record_operation_time(
+            async {
+                match self {
+                    Self::Sqlx(pool) => {
+                        metric.load_metrics(
+                        dimensions,
+                        auth,
+                        filters,
+                        granularity,
+                        time_range,
+                        pool,
+                    )
+                        .await
+                    }
+                    Self::Clickhouse(pool) => {
+                        metric.load_metrics(
+                        dimensions,
+                        auth,
+                        filters,
+                        granularity,
+                        time_range,
+                        pool,
+                    )
+                        .await
+                    }
+                    Self::CombinedCkh(sqlx_pool, ckh_pool) => {
+                        let (ckh_result, sqlx_result) = tokio::join!(
+                            metric.load_metrics(
+                        dimensions,
+                        auth,
+                        filters,
+                        granularity,
+                        time_range,
+                        ckh_pool,
+                    ),
+                            metric.load_metrics(
+                        dimensions,
+                        auth,
+                        filters,
+                        granularity,
+                        time_range,
+                        sqlx_pool,
+                    )
+                        );
+                        match (&sqlx_result, &ckh_result) {
+                            (Ok(ref sqlx_res), Ok(ref ckh_res)) if sqlx_res != ckh_res => {
+                                logger::error!(clickhouse_result=?ckh_res, postgres_result=?sqlx_res, "Mismatch between clickhouse & postgres payouts analytics metrics")
+                            }
+                            _ => {}
+                        };
+                        ckh_result
+                    }
+                    Self::CombinedSqlx(sqlx_pool, ckh_pool) => {
+                        let (ckh_result, sqlx_result) = tokio::join!(
+                            metric.load_metrics(
+                        dimensions,
+                        auth,
+                        filters,
+                        granularity,
+                        time_range,
+                        ckh_pool,
+                    ),
+                            metric.load_metrics(
+                        dimensions,
+                        auth,
+                        filters,
+                        granularity,
+                        time_range,
+                        sqlx_pool,
+                    )
+                        );
+                        match (&sqlx_result, &ckh_result) {
+                            (Ok(ref sqlx_res), Ok(ref ckh_res)) if sqlx_res != ckh_res => {
+                                logger::error!(clickhouse_result=?ckh_res, postgres_result=?sqlx_res, "Mismatch between clickhouse & postgres payouts analytics metrics")
+                            }
+                            _ => {}
+                        };
+                        sqlx_result
+                    }
+                }
+            },
+            &metrics::METRIC_FETCH_TIME,
+            metric,
+            self,
+        )
+        .await
+    }
+
+    pub async fn get_payout_distribution(
+        &self,
+        distribution: &PayoutDistributionBody,
+        dimensions: &[PayoutDimensions],
+        auth: &AuthInfo,
+        filters: &PayoutFilters,
+        granularity: &Option<Granularity>,
+        time_range: &TimeRange,
+    ) -> types::MetricsResult<Vec<(PayoutMetricsBucketIdentifier, PayoutDistributionRow)>> {
+        // Metrics to get the fetch time for each payout distribution
+        metrics::request::record_operation_time(
+            async {
+                match self {
+                    Self::Sqlx(pool) => {
+                        distribution.distribution_for.load_distribution(
+                        distribution,
+                        dimensions,
+                        auth,
+                        filters,
+                        granularity,
+                        time_range,
+                        pool,
+                    )
+                        .await
+                    }
+                    Self::Clickhouse(pool) => {
+                        distribution.distribution_for.load_distribution(
+                        distribution,
+                        dimensions,
+                        auth,
+                        filters,
+                        granularity,
+                        time_range,
+                        pool,
+                    )
+                        .await
+                    }
+                    Self::CombinedCkh(sqlx_pool, ckh_pool) => {
+                        let (ckh_result, sqlx_result) = tokio::join!(
+                            distribution.distribution_for.load_distribution(
+                        distribution,
+                        dimensions,
+                        auth,
+                        filters,
+                        granularity,
+                        time_range,
+                        ckh_pool,
+                    ),
+                            distribution.distribution_for.load_distribution(
+                        distribution,
+                        dimensions,
+                        auth,
+                        filters,
+                        granularity,
+                        time_range,
+                        sqlx_pool,
+                    )
+                        );
+                        match (&sqlx_result, &ckh_result) {
+                            (Ok(ref sqlx_res), Ok(ref ckh_res)) if sqlx_res != ckh_res => {
+                                logger::error!(clickhouse_result=?ckh_res, postgres_result=?sqlx_res, "Mismatch between clickhouse & postgres payouts analytics distribution")
+                            }
+                            _ => {}
+                        };
+                        ckh_result
+                    }
+                    Self::CombinedSqlx(sqlx_pool, ckh_pool) => {
+                        let (ckh_result, sqlx_result) = tokio::join!(
+                            distribution.distribution_for.load_distribution(
+                        distribution,
+                        dimensions,
+                        auth,
+                        filters,
+                        granularity,
+                        time_range,
+                        ckh_pool,
+                    ),
+                            distribution.distribution_for.load_distribution(
+                        distribution,
+                        dimensions,
+                        auth,
+                        filters,
+                        granularity,
+                        time_range,
+                        sqlx_pool,
+                    )
+                        );
+                        match (&sqlx_result, &ckh_result) {
+                            (Ok(ref sqlx_res), Ok(ref ckh_res)) if sqlx_res != ckh_res => {
+                                logger::error!(clickhouse_result=?ckh_res, postgres_result=?sqlx_res, "Mismatch between clickhouse & postgres payouts analytics distribution")
+                            }
+                            _ => {}
+                        };
+                        sqlx_result
+                    }
+                }
+            },
+            &metrics::METRIC_FETCH_TIME,
+            &distribution.distribution_for,
+            self,
+        )
+        .await
+    }
+
     pub async fn get_sdk_event_metrics(
         &self,
         metric: &SdkEventMetrics,
@@ -1122,12 +1332,66 @@ impl Default for AnalyticsConfig {
 }
 
 #[derive(Clone, Debug, serde::Deserialize, Default, serde::Serialize)]
+#[serde(default)]
 pub struct ReportConfig {
     pub payment_function: String,
     pub refund_function: String,
     pub dispute_function: String,
     pub authentication_function: String,
     pub region: String,
+    pub generator: ReportGenerator,
+    pub native: NativeReportConfig,
+}
+
+impl ReportConfig {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        if self.generator != ReportGenerator::Native {
+            return Ok(());
+        }
+
+        when(self.native.page_size == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "Report generation page size must be greater than zero".into(),
+            ))
+        })?;
+
+        when(self.native.download_link_expiry_in_secs <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "Report download link expiry must be greater than zero".into(),
+            ))
+        })
+    }
+}
+
+/// Generator used to build the payment, refund and dispute reports
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReportGenerator {
+    /// Reports are generated by invoking the configured AWS Lambda functions
+    #[default]
+    Lambda,
+    /// Reports are generated by the scheduler and stored in the configured file storage
+    Native,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct NativeReportConfig {
+    /// Number of rows fetched from the analytics data source in a single query
+    pub page_size: u64,
+    /// Validity of the report download link
+    pub download_link_expiry_in_secs: i64,
+}
+
+impl Default for NativeReportConfig {
+    fn default() -> Self {
+        Self {
+            page_size: 10_000,
+            download_link_expiry_in_secs: 3600,
+        }
+    }
 }
 
 /// Analytics Flow routes Enums
@@ -1156,6 +1420,12 @@ pub enum AnalyticsFlow {
     GenerateDisputeReport,
     GenerateRefundReport,
     GenerateAuthenticationReport,
+    RetrieveReport,
+    DownloadReport,
+    CreateDigestSubscription,
+    ListDigestSubscriptions,
+    UpdateDigestSubscription,
+    DeleteDigestSubscription,
     GetApiEventMetrics,
     GetApiEventFilters,
     GetConnectorEvents,
@@ -1165,6 +1435,8 @@ pub enum AnalyticsFlow {
     GetDisputeFilters,
     GetDisputeMetrics,
     GetSankey,
+    GetPayoutMetrics,
+    GetPayoutFilters,
 }
 
 impl FlowMetric for AnalyticsFlow {}
@@ -0,0 +1,10 @@
+pub mod accumulator;
+mod core;
+
+pub mod distribution;
+pub mod filters;
+pub mod metrics;
+pub mod types;
+pub use accumulator::{PayoutMetricAccumulator, PayoutMetricsAccumulator};
+
+pub use self::core::{get_filters, get_metrics};
@@ -0,0 +1,225 @@
+use api_models::analytics::{payouts::PayoutMetricsBucketValue, refunds::ErrorMessagesResult};
+use bigdecimal::ToPrimitive;
+use diesel_models::enums as storage_enums;
+
+use super::{distribution::PayoutDistributionRow, metrics::PayoutMetricRow};
+
+#[derive(Debug, Default)]
+pub struct PayoutMetricsAccumulator {
+    pub payout_success_rate: SuccessRateAccumulator,
+    pub payout_count: CountAccumulator,
+    pub payout_success: CountAccumulator,
+    pub processed_amount: PayoutProcessedAmountAccumulator,
+    pub avg_time_to_success: AverageAccumulator,
+    pub error_message_distribution: PayoutErrorMessageDistributionAccumulator,
+}
+
+#[derive(Debug, Default)]
+pub struct PayoutErrorMessageDistributionRow {
+    pub count: i64,
+    pub total: i64,
+    pub error_message: String,
+}
+
+#[derive(Debug, Default)]
+pub struct PayoutErrorMessageDistributionAccumulator {
+    pub error_message_vec: Vec<PayoutErrorMessageDistributionRow>,
+}
+
+#[derive(Debug, Default)]
+pub struct SuccessRateAccumulator {
+    pub success: u32,
+    pub total: u32,
+}
+
+#[derive(Debug, Default)]
+#[repr(transparent)]
+pub struct CountAccumulator {
+    pub count: Option<i64>,
+}
+
+#[derive(Debug, Default)]
+pub struct PayoutProcessedAmountAccumulator {
+    pub count: Option<i64>,
+    pub total: Option<i64>,
+}
+
+#[derive(Debug, Default)]
+pub struct AverageAccumulator {
+    pub total: f64,
+    pub count: i64,
+}
+
+pub trait PayoutMetricAccumulator {
+    type MetricOutput;
+
+    fn add_metrics_bucket(&mut self, metrics: &PayoutMetricRow);
+
+    fn collect(self) -> Self::MetricOutput;
+}
+
+pub trait PayoutDistributionAccumulator {
+    type DistributionOutput;
+
+    fn add_distribution_bucket(&mut self, distribution: &PayoutDistributionRow);
+
+    fn collect(self) -> Self::DistributionOutput;
+}
+
+impl PayoutDistributionAccumulator for PayoutErrorMessageDistributionAccumulator {
+    type DistributionOutput = Option<Vec<ErrorMessagesResult>>;
+
+    fn add_distribution_bucket(&mut self, distribution: &PayoutDistributionRow) {
+        self.error_message_vec
+            .push(PayoutErrorMessageDistributionRow {
+                count: distribution.count.unwrap_or_default(),
+                total: distribution
+                    .total
+                    .clone()
+                    .map(|i| i.to_i64().unwrap_or_default())
+                    .unwrap_or_default(),
+                error_message: distribution.error_message.clone().unwrap_or_default(),
+            })
+    }
+
+    fn collect(mut self) -> Self::DistributionOutput {
+        if self.error_message_vec.is_empty() {
+            None
+        } else {
+            self.error_message_vec.sort_by(|a, b| b.count.cmp(&a.count));
+            let mut res: Vec<ErrorMessagesResult> = Vec::new();
+            for val in self.error_message_vec.into_iter() {
+                let perc = f64::from(u32::try_from(val.count).ok()?) * 100.0
+                    / f64::from(u32::try_from(val.total).ok()?);
+
+                res.push(ErrorMessagesResult {
+                    error_message: val.error_message,
+                    count: val.count,
+                    percentage: (perc * 100.0).round() / 100.0,
+                })
+            }
+
+            Some(res)
+        }
+    }
+}
+
+impl PayoutMetricAccumulator for CountAccumulator {
+    type MetricOutput = Option<u64>;
+    #[inline]
+    fn add_metrics_bucket(&mut self, metrics: &PayoutMetricRow) {
+        self.count = match (self.count, metrics.count) {
+            (None, None) => None,
+            (None, i @ Some(_)) | (i @ Some(_), None) => i,
+            (Some(a), Some(b)) => Some(a + b),
+        }
+    }
+    #[inline]
+    fn collect(self) -> Self::MetricOutput {
+        self.count.and_then(|i| u64::try_from(i).ok())
+    }
+}
+
+impl PayoutMetricAccumulator for PayoutProcessedAmountAccumulator {
+    type MetricOutput = (Option<u64>, Option<u64>, Option<u64>);
+    #[inline]
+    fn add_metrics_bucket(&mut self, metrics: &PayoutMetricRow) {
+        self.total = match (
+            self.total,
+            metrics.total.as_ref().and_then(ToPrimitive::to_i64),
+        ) {
+            (None, None) => None,
+            (None, i @ Some(_)) | (i @ Some(_), None) => i,
+            (Some(a), Some(b)) => Some(a + b),
+        };
+
+        self.count = match (self.count, metrics.count) {
+            (None, None) => None,
+            (None, i @ Some(_)) | (i @ Some(_), None) => i,
+            (Some(a), Some(b)) => Some(a + b),
+        };
+    }
+    #[inline]
+    fn collect(self) -> Self::MetricOutput {
+        let total = u64::try_from(self.total.unwrap_or_default()).ok();
+        let count = self.count.and_then(|i| u64::try_from(i).ok());
+
+        (total, count, Some(0))
+    }
+}
+
+impl PayoutMetricAccumulator for SuccessRateAccumulator {
+    type MetricOutput = (Option<u32>, Option<u32>, Option<f64>);
+
+    fn add_metrics_bucket(&mut self, metrics: &PayoutMetricRow) {
+        if let Some(ref status) = metrics.status {
+            if status.as_ref() == &storage_enums::PayoutStatus::Success {
+                if let Some(success) = metrics
+                    .count
+                    .and_then(|success| u32::try_from(success).ok())
+                {
+                    self.success += success;
+                }
+            }
+        };
+        if let Some(total) = metrics.count.and_then(|total| u32::try_from(total).ok()) {
+            self.total += total;
+        }
+    }
+
+    fn collect(self) -> Self::MetricOutput {
+        if self.total == 0 {
+            (None, None, None)
+        } else {
+            let success = Some(self.success);
+            let total = Some(self.total);
+            let success_rate = match (success, total) {
+                (Some(s), Some(t)) if t > 0 => Some(f64::from(s) * 100.0 / f64::from(t)),
+                _ => None,
+            };
+            (success, total, success_rate)
+        }
+    }
+}
+
+impl PayoutMetricAccumulator for AverageAccumulator {
+    type MetricOutput = Option<f64>;
+
+    fn add_metrics_bucket(&mut self, metrics: &PayoutMetricRow) {
+        if let (Some(total), Some(count)) = (
+            metrics.total.as_ref().and_then(ToPrimitive::to_f64),
+            metrics.count,
+        ) {
+            self.total += total;
+            self.count += count;
+        }
+    }
+
+    fn collect(self) -> Self::MetricOutput {
+        u32::try_from(self.count)
+            .ok()
+            .filter(|count| *count > 0)
+            .map(|count| self.total / f64::from(count))
+    }
+}
+
+impl PayoutMetricsAccumulator {
+    pub fn collect(self) -> PayoutMetricsBucketValue {
+        let (successful_payouts, total_payouts, payout_success_rate) =
+            self.payout_success_rate.collect();
+        let (payout_processed_amount, payout_processed_count, payout_processed_amount_in_usd) =
+            self.processed_amount.collect();
+        PayoutMetricsBucketValue {
+            successful_payouts,
+            total_payouts,
+            payout_success_rate,
+            payout_count: self.payout_count.collect(),
+            payout_success_count: self.payout_success.collect(),
+            payout_processed_amount,
+            payout_processed_amount_in_usd,
+            payout_processed_count,
+            payout_avg_time_to_success: self.avg_time_to_success.collect(),
+            payout_error_message_distribution: self.error_message_distribution.collect(),
+        }
+    }
+}
@@ -0,0 +1,114 @@
+use std::collections::HashSet;
+
+use api_models::analytics::{
+    payouts::{PayoutDimensions, PayoutFilters, PayoutMetrics, PayoutMetricsBucketIdentifier},
+    Granularity, TimeRange,
+};
+use diesel_models::enums as storage_enums;
+use time::PrimitiveDateTime;
+mod payout_avg_time_to_success;
+mod payout_count;
+mod payout_processed_amount;
+mod payout_success_count;
+mod payout_success_rate;
+
+use payout_avg_time_to_success::PayoutAvgTimeToSuccess;
+use payout_count::PayoutCount;
+use payout_processed_amount::PayoutProcessedAmount;
+use payout_success_count::PayoutSuccessCount;
+use payout_success_rate::PayoutSuccessRate;
+
+use crate::{
+    enums::AuthInfo,
+    query::{Aggregate, GroupByClause, TimeDifference, ToSql, Window},
+    types::{AnalyticsCollection, AnalyticsDataSource, DBEnumWrapper, LoadRow, MetricsResult},
+};
+
+#[derive(Debug, Eq, PartialEq, serde::Deserialize, Hash)]
+pub struct PayoutMetricRow {
+    pub connector: Option<String>,
+    pub payout_type: Option<DBEnumWrapper<storage_enums::PayoutType>>,
+    pub destination_currency: Option<DBEnumWrapper<storage_enums::Currency>>,
+    pub status: Option<DBEnumWrapper<storage_enums::PayoutStatus>>,
+    pub profile_id: Option<String>,
+    pub total: Option<bigdecimal::BigDecimal>,
+    pub count: Option<i64>,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub start_bucket: Option<PrimitiveDateTime>,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub end_bucket: Option<PrimitiveDateTime>,
+}
+
+pub trait PayoutMetricAnalytics: LoadRow<PayoutMetricRow> {}
+
+#[async_trait::async_trait]
+pub trait PayoutMetric<T>
+where
+    T: AnalyticsDataSource + PayoutMetricAnalytics,
+    PrimitiveDateTime: ToSql<T>,
+    AnalyticsCollection: ToSql<T>,
+    Granularity: GroupByClause<T>,
+    Aggregate<&'static str>: ToSql<T>,
+    Aggregate<TimeDifference>: ToSql<T>,
+    Window<&'static str>: ToSql<T>,
+{
+    async fn load_metrics(
+        &self,
+        dimensions: &[PayoutDimensions],
+        auth: &AuthInfo,
+        filters: &PayoutFilters,
+        granularity: Option<Granularity>,
+        time_range: &TimeRange,
+        pool: &T,
+    ) -> MetricsResult<HashSet<(PayoutMetricsBucketIdentifier, PayoutMetricRow)>>;
+}
+
+#[async_trait::async_trait]
+impl<T> PayoutMetric<T> for PayoutMetrics
+where
+    T: AnalyticsDataSource + PayoutMetricAnalytics,
+    PrimitiveDateTime: ToSql<T>,
+    AnalyticsCollection: ToSql<T>,
+    Granularity: GroupByClause<T>,
+    Aggregate<&'static str>: ToSql<T>,
+    Aggregate<TimeDifference>: ToSql<T>,
+    Window<&'static str>: ToSql<T>,
+{
+    async fn load_metrics(
+        &self,
+        dimensions: &[PayoutDimensions],
+        auth: &AuthInfo,
+        filters: &PayoutFilters,
+        granularity: Option<Granularity>,
+        time_range: &TimeRange,
+        pool: &T,
+    ) -> MetricsResult<HashSet<(PayoutMetricsBucketIdentifier, PayoutMetricRow)>> {
+        match self {
+            Self::PayoutSuccessRate => {
+                PayoutSuccessRate
+                    .load_metrics(dimensions, auth, filters, granularity, time_range, pool)
+                    .await
+            }
+            Self::PayoutCount => {
+                PayoutCount
+                    .load_metrics(dimensions, auth, filters, granularity, time_range, pool)
+                    .await
+            }
+            Self::PayoutSuccessCount => {
+                PayoutSuccessCount
+                    .load_metrics(dimensions, auth, filters, granularity, time_range, pool)
+                    .await
+            }
+            Self::PayoutProcessedAmount => {
+                PayoutProcessedAmount
+                    .load_metrics(dimensions, auth, filters, granularity, time_range, pool)
+                    .await
+            }
+            Self::PayoutAvgTimeToSuccess => {
+                PayoutAvgTimeToSuccess
+                    .load_metrics(dimensions, auth, filters, granularity, time_range, pool)
+                    .await
+            }
+        }
+    }
+}
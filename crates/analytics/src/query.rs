@@ -9,13 +9,14 @@ use api_models::{
         frm::{FrmDimensions, FrmTransactionType},
         payment_intents::PaymentIntentDimensions,
         payments::{PaymentDimensions, PaymentDistributions},
+        payouts::{PayoutDimensions, PayoutDistributions},
         refunds::{RefundDimensions, RefundDistributions, RefundType},
         sdk_events::{SdkEventDimensions, SdkEventNames},
         Granularity,
     },
     enums::{
         AttemptStatus, AuthenticationType, Connector, Currency, DisputeStage, IntentStatus,
-        PaymentMethod, PaymentMethodType,
+        PaymentMethod, PaymentMethodType, PayoutStatus, PayoutType,
     },
     refunds::RefundStatus,
 };
@@ -267,6 +268,13 @@ pub enum Aggregate<R> {
     },
 }
 
+/// Difference between two timestamp columns in seconds, to be used as the field of an aggregate
+#[derive(Debug, Clone, Copy)]
+pub struct TimeDifference {
+    pub start: &'static str,
+    pub end: &'static str,
+}
+
 // Window functions in query
 // ---
 // Description -
@@ -350,6 +358,17 @@ impl fmt::Display for LimitByClause {
     }
 }
 
+#[derive(Debug, Clone, Copy)]
+pub struct LimitClause {
+    limit: u64,
+}
+
+impl fmt::Display for LimitClause {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "LIMIT {}", self.limit)
+    }
+}
+
 #[derive(Debug, Default, Clone, Copy)]
 pub enum FilterCombinator {
     #[default]
@@ -412,6 +431,7 @@ where
     order_by: Vec<String>,
     having: Option<Vec<(String, FilterTypes, String)>>,
     limit_by: Option<LimitByClause>,
+    limit: Option<LimitClause>,
     outer_select: Vec<String>,
     top_n: Option<TopN>,
     table: AnalyticsCollection,
@@ -528,7 +548,12 @@ impl_to_sql_for_to_string!(
     DisputeDimensions,
     DisputeStage,
     AuthEventDimensions,
-    &AuthEventDimensions
+    &AuthEventDimensions,
+    &PayoutDimensions,
+    PayoutDimensions,
+    &PayoutDistributions,
+    PayoutStatus,
+    PayoutType
 );
 
 #[derive(Debug, Clone, Copy)]
@@ -573,6 +598,7 @@ where
             order_by: Default::default(),
             having: Default::default(),
             limit_by: Default::default(),
+            limit: Default::default(),
             outer_select: Default::default(),
             top_n: Default::default(),
             table,
@@ -690,6 +716,48 @@ where
         }
     }
 
+    /// Adds a filter on the rows ordered after the provided values of the order column and the
+    /// tiebreaker column, used for keyset pagination.
+    pub fn add_keyset_filter_clause(
+        &mut self,
+        order_column: &str,
+        order_value: impl ToSql<T>,
+        tiebreaker_column: &str,
+        tiebreaker_value: impl ToSql<T>,
+    ) -> QueryResult<()> {
+        let order_value = order_value
+            .to_sql(&self.table_engine)
+            .change_context(QueryBuildingError::SqlSerializeError)
+            .attach_printable("Error serializing keyset order value")?;
+        let tiebreaker_value = tiebreaker_value
+            .to_sql(&self.table_engine)
+            .change_context(QueryBuildingError::SqlSerializeError)
+            .attach_printable("Error serializing keyset tiebreaker value")?;
+
+        self.add_nested_filter_clause(Filter::NestedFilter(
+            FilterCombinator::Or,
+            vec![
+                Filter::Plain(
+                    order_column.to_owned(),
+                    FilterTypes::Gt,
+                    format!("'{order_value}'"),
+                ),
+                Filter::NestedFilter(
+                    FilterCombinator::And,
+                    vec![
+                        Filter::Plain(order_column.to_owned(), FilterTypes::Equal, order_value),
+                        Filter::Plain(
+                            tiebreaker_column.to_owned(),
+                            FilterTypes::Gt,
+                            format!("'{tiebreaker_value}'"),
+                        ),
+                    ],
+                ),
+            ],
+        ));
+        Ok(())
+    }
+
     pub fn add_filter_in_range_clause(
         &mut self,
         key: impl ToSql<T>,
@@ -752,6 +820,10 @@ where
         Ok(())
     }
 
+    pub fn set_limit(&mut self, limit: u64) {
+        self.limit = Some(LimitClause { limit });
+    }
+
     pub fn add_granularity_in_mins(&mut self, granularity: Granularity) -> QueryResult<()> {
         let interval = match granularity {
             Granularity::OneMin => "1",
@@ -894,6 +966,10 @@ where
             query.push_str(&format!(" {}", limit_by));
         }
 
+        if let Some(limit) = &self.limit {
+            query.push_str(&format!(" {}", limit));
+        }
+
         if !self.outer_select.is_empty() {
             query.insert_str(
                 0,
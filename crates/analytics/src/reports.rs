@@ -0,0 +1,5 @@
+mod core;
+pub mod parquet;
+pub mod rows;
+
+pub use self::core::{get_report_rows, ReportRecord};
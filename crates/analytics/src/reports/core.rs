@@ -0,0 +1,81 @@
+use api_models::analytics::{ReportType, TimeRange};
+use common_utils::errors::ReportSwitchExt;
+use router_env::{instrument, tracing};
+
+use super::rows::{
+    get_report_page, DisputeReportRow, PaymentReportRow, RefundReportRow, ReportCursor, ReportRow,
+};
+use crate::{
+    clickhouse::ClickhouseClient, enums::AuthInfo, errors::AnalyticsResult, sqlx::SqlxClient,
+    types::LoadRow, AnalyticsProvider,
+};
+
+/// A row of any of the report types, serialized without the variant name.
+#[derive(Debug, serde::Serialize)]
+#[serde(untagged)]
+pub enum ReportRecord {
+    Payment(PaymentReportRow),
+    Refund(RefundReportRow),
+    Dispute(DisputeReportRow),
+}
+
+impl ReportRecord {
+    pub fn get_cursor(&self) -> ReportCursor {
+        match self {
+            Self::Payment(row) => row.get_cursor(),
+            Self::Refund(row) => row.get_cursor(),
+            Self::Dispute(row) => row.get_cursor(),
+        }
+    }
+}
+
+#[instrument(skip_all)]
+pub async fn get_report_rows(
+    pool: &AnalyticsProvider,
+    report_type: ReportType,
+    auth: &AuthInfo,
+    time_range: &TimeRange,
+    cursor: Option<&ReportCursor>,
+    limit: u64,
+) -> AnalyticsResult<Vec<ReportRecord>> {
+    match report_type {
+        ReportType::Payment => {
+            get_rows_from_provider::<PaymentReportRow>(pool, auth, time_range, cursor, limit)
+                .await
+                .map(|rows| rows.into_iter().map(ReportRecord::Payment).collect())
+        }
+        ReportType::Refund => {
+            get_rows_from_provider::<RefundReportRow>(pool, auth, time_range, cursor, limit)
+                .await
+                .map(|rows| rows.into_iter().map(ReportRecord::Refund).collect())
+        }
+        ReportType::Dispute => {
+            get_rows_from_provider::<DisputeReportRow>(pool, auth, time_range, cursor, limit)
+                .await
+                .map(|rows| rows.into_iter().map(ReportRecord::Dispute).collect())
+        }
+    }
+}
+
+async fn get_rows_from_provider<R>(
+    pool: &AnalyticsProvider,
+    auth: &AuthInfo,
+    time_range: &TimeRange,
+    cursor: Option<&ReportCursor>,
+    limit: u64,
+) -> AnalyticsResult<Vec<R>>
+where
+    R: ReportRow,
+    SqlxClient: LoadRow<R>,
+    ClickhouseClient: LoadRow<R>,
+{
+    match pool {
+        AnalyticsProvider::Sqlx(pool) | AnalyticsProvider::CombinedSqlx(pool, _) => {
+            get_report_page(auth, time_range, cursor, limit, pool).await
+        }
+        AnalyticsProvider::Clickhouse(pool) | AnalyticsProvider::CombinedCkh(_, pool) => {
+            get_report_page(auth, time_range, cursor, limit, pool).await
+        }
+    }
+    .switch()
+}
@@ -0,0 +1,134 @@
+use std::sync::Arc;
+
+use api_models::analytics::ReportType;
+use arrow::{
+    array::{ArrayRef, StringArray},
+    datatypes::{DataType, Field, Schema, SchemaRef},
+    record_batch::RecordBatch,
+};
+use error_stack::ResultExt;
+use parquet::{
+    arrow::{arrow_reader::ParquetRecordBatchReaderBuilder, ArrowWriter},
+    basic::Compression,
+    file::properties::WriterProperties,
+};
+
+use super::{
+    core::ReportRecord,
+    rows::{DisputeReportRow, PaymentReportRow, RefundReportRow, ReportRow},
+};
+use crate::errors::{AnalyticsError, AnalyticsResult};
+
+/// Encodes a page of report rows as a standalone Parquet file. Every column is written as a
+/// nullable string, formatted the same way as in the CSV report.
+pub fn encode_report_part(
+    report_type: ReportType,
+    rows: &[ReportRecord],
+) -> AnalyticsResult<Vec<u8>> {
+    let schema = get_report_schema(report_type);
+    let rows = rows
+        .iter()
+        .map(serde_json::to_value)
+        .collect::<Result<Vec<_>, _>>()
+        .change_context(AnalyticsError::UnknownError)
+        .attach_printable("Failed to serialize report rows")?;
+    let columns = schema
+        .fields()
+        .iter()
+        .map(|field| {
+            let values = rows
+                .iter()
+                .map(|row| get_column_value(row, field.name()))
+                .collect::<StringArray>();
+            Arc::new(values) as ArrayRef
+        })
+        .collect();
+    let record_batch = RecordBatch::try_new(schema.clone(), columns)
+        .change_context(AnalyticsError::UnknownError)
+        .attach_printable("Failed to build report record batch")?;
+
+    let mut writer = get_writer(schema)?;
+    writer
+        .write(&record_batch)
+        .change_context(AnalyticsError::UnknownError)
+        .attach_printable("Failed to write report rows")?;
+    writer
+        .into_inner()
+        .change_context(AnalyticsError::UnknownError)
+        .attach_printable("Failed to write report")
+}
+
+/// Merges the parts of a report, which are standalone Parquet files, into a single Parquet file
+/// with a row group per part. Parts are appended one at a time, so that only the merged report
+/// and a single part are held in memory.
+pub struct ParquetReportWriter {
+    writer: ArrowWriter<Vec<u8>>,
+}
+
+impl ParquetReportWriter {
+    pub fn new(report_type: ReportType) -> AnalyticsResult<Self> {
+        get_writer(get_report_schema(report_type)).map(|writer| Self { writer })
+    }
+
+    pub fn append_part(&mut self, report_part: Vec<u8>) -> AnalyticsResult<()> {
+        let reader = ParquetRecordBatchReaderBuilder::try_new(bytes::Bytes::from(report_part))
+            .and_then(|builder| builder.build())
+            .change_context(AnalyticsError::UnknownError)
+            .attach_printable("Failed to read report part")?;
+
+        for record_batch in reader {
+            let record_batch = record_batch
+                .change_context(AnalyticsError::UnknownError)
+                .attach_printable("Failed to read report part")?;
+            self.writer
+                .write(&record_batch)
+                .change_context(AnalyticsError::UnknownError)
+                .attach_printable("Failed to write report rows")?;
+        }
+
+        self.writer
+            .flush()
+            .change_context(AnalyticsError::UnknownError)
+            .attach_printable("Failed to write report row group")
+    }
+
+    pub fn finish(self) -> AnalyticsResult<Vec<u8>> {
+        self.writer
+            .into_inner()
+            .change_context(AnalyticsError::UnknownError)
+            .attach_printable("Failed to write report")
+    }
+}
+
+fn get_writer(schema: SchemaRef) -> AnalyticsResult<ArrowWriter<Vec<u8>>> {
+    let properties = WriterProperties::builder()
+        .set_compression(Compression::SNAPPY)
+        .build();
+
+    ArrowWriter::try_new(Vec::new(), schema, Some(properties))
+        .change_context(AnalyticsError::UnknownError)
+        .attach_printable("Failed to create report writer")
+}
+
+fn get_report_schema(report_type: ReportType) -> SchemaRef {
+    let columns = match report_type {
+        ReportType::Payment => PaymentReportRow::COLUMNS,
+        ReportType::Refund => RefundReportRow::COLUMNS,
+        ReportType::Dispute => DisputeReportRow::COLUMNS,
+    };
+
+    Arc::new(Schema::new(
+        columns
+            .iter()
+            .map(|column| Field::new(*column, DataType::Utf8, true))
+            .collect::<Vec<_>>(),
+    ))
+}
+
+fn get_column_value(row: &serde_json::Value, column: &str) -> Option<String> {
+    match row.get(column) {
+        None | Some(serde_json::Value::Null) => None,
+        Some(serde_json::Value::String(value)) => Some(value.clone()),
+        Some(value) => Some(value.to_string()),
+    }
+}
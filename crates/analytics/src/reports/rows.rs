@@ -0,0 +1,246 @@
+use api_models::{
+    analytics::{refunds::RefundType, Granularity, TimeRange},
+    enums::{DisputeStage, DisputeStatus},
+};
+use common_utils::errors::ReportSwitchExt;
+use diesel_models::enums::{AttemptStatus, AuthenticationType, Currency, RefundStatus};
+use error_stack::ResultExt;
+use time::PrimitiveDateTime;
+
+use crate::{
+    enums::AuthInfo,
+    query::{Aggregate, GroupByClause, Order, QueryBuilder, QueryFilter, ToSql, Window},
+    types::{
+        AnalyticsCollection, AnalyticsDataSource, DBEnumWrapper, FiltersError, FiltersResult,
+        LoadRow,
+    },
+};
+
+/// A single row of a generated report, along with the table and columns it is read from.
+pub trait ReportRow: serde::Serialize + Sized {
+    const COLLECTION: AnalyticsCollection;
+    const COLUMNS: &'static [&'static str];
+    /// Column uniquely identifying a row, used along with `created_at` for a stable ordering
+    const ID_COLUMN: &'static str;
+
+    fn get_cursor(&self) -> ReportCursor;
+}
+
+/// Position of the last row of a page, the next page starts with the rows ordered after it.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct ReportCursor {
+    pub created_at: PrimitiveDateTime,
+    pub id: String,
+}
+
+/// Fetches a page of report rows created within the time range, ordered by creation time and
+/// starting after the cursor if one is provided.
+///
+/// All the selected columns are grouped on, which collapses the cancelled versions of a row
+/// for data sources storing every update to a row. Pages are fetched using the keyset of the
+/// last row rather than an offset, so that the data source does not aggregate all the
+/// preceding rows again for every page.
+pub async fn get_report_page<T, R>(
+    auth: &AuthInfo,
+    time_range: &TimeRange,
+    cursor: Option<&ReportCursor>,
+    limit: u64,
+    pool: &T,
+) -> FiltersResult<Vec<R>>
+where
+    R: ReportRow,
+    T: AnalyticsDataSource + LoadRow<R>,
+    PrimitiveDateTime: ToSql<T>,
+    AnalyticsCollection: ToSql<T>,
+    Granularity: GroupByClause<T>,
+    Aggregate<&'static str>: ToSql<T>,
+    Window<&'static str>: ToSql<T>,
+{
+    let mut query_builder: QueryBuilder<T> = QueryBuilder::new(R::COLLECTION);
+
+    for column in R::COLUMNS {
+        query_builder.add_select_column(*column).switch()?;
+        query_builder.add_group_by_clause(*column).switch()?;
+    }
+
+    time_range
+        .set_filter_clause(&mut query_builder)
+        .attach_printable("Error filtering time range")
+        .switch()?;
+
+    auth.set_filter_clause(&mut query_builder).switch()?;
+
+    if let Some(cursor) = cursor {
+        query_builder
+            .add_keyset_filter_clause("created_at", cursor.created_at, R::ID_COLUMN, &cursor.id)
+            .attach_printable("Error adding report cursor filter")
+            .switch()?;
+    }
+
+    query_builder
+        .add_order_by_clause("created_at", Order::Ascending)
+        .switch()?;
+    query_builder
+        .add_order_by_clause(R::ID_COLUMN, Order::Ascending)
+        .switch()?;
+    query_builder.set_limit(limit);
+
+    query_builder
+        .execute_query::<R, _>(pool)
+        .await
+        .change_context(FiltersError::QueryBuildingError)?
+        .change_context(FiltersError::QueryExecutionFailure)
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct PaymentReportRow {
+    pub payment_id: String,
+    pub attempt_id: String,
+    pub merchant_id: String,
+    pub profile_id: Option<String>,
+    pub status: Option<DBEnumWrapper<AttemptStatus>>,
+    pub amount: Option<i64>,
+    pub currency: Option<DBEnumWrapper<Currency>>,
+    pub connector: Option<String>,
+    pub payment_method: Option<String>,
+    pub payment_method_type: Option<String>,
+    pub authentication_type: Option<DBEnumWrapper<AuthenticationType>>,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+impl ReportRow for PaymentReportRow {
+    const COLLECTION: AnalyticsCollection = AnalyticsCollection::Payment;
+    const COLUMNS: &'static [&'static str] = &[
+        "payment_id",
+        "attempt_id",
+        "merchant_id",
+        "profile_id",
+        "status",
+        "amount",
+        "currency",
+        "connector",
+        "payment_method",
+        "payment_method_type",
+        "authentication_type",
+        "error_code",
+        "error_message",
+        "created_at",
+        "modified_at",
+    ];
+    const ID_COLUMN: &'static str = "attempt_id";
+
+    fn get_cursor(&self) -> ReportCursor {
+        ReportCursor {
+            created_at: self.created_at,
+            id: self.attempt_id.clone(),
+        }
+    }
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct RefundReportRow {
+    pub refund_id: String,
+    pub payment_id: String,
+    pub attempt_id: String,
+    pub merchant_id: String,
+    pub profile_id: Option<String>,
+    pub refund_status: Option<DBEnumWrapper<RefundStatus>>,
+    pub refund_type: Option<DBEnumWrapper<RefundType>>,
+    pub total_amount: Option<i64>,
+    pub refund_amount: Option<i64>,
+    pub currency: Option<DBEnumWrapper<Currency>>,
+    pub connector: Option<String>,
+    pub refund_reason: Option<String>,
+    pub refund_error_code: Option<String>,
+    pub refund_error_message: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+impl ReportRow for RefundReportRow {
+    const COLLECTION: AnalyticsCollection = AnalyticsCollection::Refund;
+    const COLUMNS: &'static [&'static str] = &[
+        "refund_id",
+        "payment_id",
+        "attempt_id",
+        "merchant_id",
+        "profile_id",
+        "refund_status",
+        "refund_type",
+        "total_amount",
+        "refund_amount",
+        "currency",
+        "connector",
+        "refund_reason",
+        "refund_error_code",
+        "refund_error_message",
+        "created_at",
+        "modified_at",
+    ];
+    const ID_COLUMN: &'static str = "refund_id";
+
+    fn get_cursor(&self) -> ReportCursor {
+        ReportCursor {
+            created_at: self.created_at,
+            id: self.refund_id.clone(),
+        }
+    }
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct DisputeReportRow {
+    pub dispute_id: String,
+    pub payment_id: String,
+    pub attempt_id: String,
+    pub merchant_id: String,
+    pub profile_id: Option<String>,
+    pub dispute_stage: Option<DBEnumWrapper<DisputeStage>>,
+    pub dispute_status: Option<DBEnumWrapper<DisputeStatus>>,
+    pub dispute_amount: Option<i64>,
+    pub currency: Option<String>,
+    pub connector: Option<String>,
+    pub connector_status: Option<String>,
+    pub connector_reason: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub challenge_required_by: Option<PrimitiveDateTime>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+impl ReportRow for DisputeReportRow {
+    const COLLECTION: AnalyticsCollection = AnalyticsCollection::Dispute;
+    const COLUMNS: &'static [&'static str] = &[
+        "dispute_id",
+        "payment_id",
+        "attempt_id",
+        "merchant_id",
+        "profile_id",
+        "dispute_stage",
+        "dispute_status",
+        "dispute_amount",
+        "currency",
+        "connector",
+        "connector_status",
+        "connector_reason",
+        "challenge_required_by",
+        "created_at",
+        "modified_at",
+    ];
+    const ID_COLUMN: &'static str = "dispute_id";
+
+    fn get_cursor(&self) -> ReportCursor {
+        ReportCursor {
+            created_at: self.created_at,
+            id: self.dispute_id.clone(),
+        }
+    }
+}
@@ -13,7 +13,7 @@ use common_utils::{
 };
 use diesel_models::enums::{
     AttemptStatus, AuthenticationType, Currency, FraudCheckStatus, IntentStatus, PaymentMethod,
-    RefundStatus,
+    PayoutStatus, PayoutType, RefundStatus,
 };
 use error_stack::ResultExt;
 use sqlx::{
@@ -27,7 +27,7 @@ use time::PrimitiveDateTime;
 
 use super::{
     health_check::HealthCheck,
-    query::{Aggregate, ToSql, Window},
+    query::{Aggregate, TimeDifference, ToSql, Window},
     types::{
         AnalyticsCollection, AnalyticsDataSource, DBEnumWrapper, LoadRow, QueryExecutionError,
         TableEngine,
@@ -103,6 +103,8 @@ db_type!(AuthenticationStatus);
 db_type!(TransactionStatus);
 db_type!(AuthenticationConnectors);
 db_type!(DecoupledAuthenticationType);
+db_type!(PayoutStatus);
+db_type!(PayoutType);
 
 impl<'q, Type> Encode<'q, Postgres> for DBEnumWrapper<Type>
 where
@@ -164,6 +166,9 @@ impl super::refunds::filters::RefundFilterAnalytics for SqlxClient {}
 impl super::refunds::distribution::RefundDistributionAnalytics for SqlxClient {}
 impl super::disputes::filters::DisputeFilterAnalytics for SqlxClient {}
 impl super::disputes::metrics::DisputeMetricAnalytics for SqlxClient {}
+impl super::payouts::metrics::PayoutMetricAnalytics for SqlxClient {}
+impl super::payouts::filters::PayoutFilterAnalytics for SqlxClient {}
+impl super::payouts::distribution::PayoutDistributionAnalytics for SqlxClient {}
 impl super::frm::metrics::FrmMetricAnalytics for SqlxClient {}
 impl super::frm::filters::FrmFilterAnalytics for SqlxClient {}
 impl super::auth_events::metrics::AuthEventMetricAnalytics for SqlxClient {}
@@ -1117,12 +1122,384 @@ impl<'a> FromRow<'a, PgRow> for super::disputes::metrics::DisputeMetricRow {
     }
 }
 
+impl<'a> FromRow<'a, PgRow> for super::payouts::metrics::PayoutMetricRow {
+    fn from_row(row: &'a PgRow) -> sqlx::Result<Self> {
+        let connector: Option<String> = row.try_get("connector").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let payout_type: Option<DBEnumWrapper<PayoutType>> =
+            row.try_get("payout_type").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let destination_currency: Option<DBEnumWrapper<Currency>> =
+            row.try_get("destination_currency").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let status: Option<DBEnumWrapper<PayoutStatus>> =
+            row.try_get("status").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let profile_id: Option<String> = row.try_get("profile_id").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let total: Option<bigdecimal::BigDecimal> = row.try_get("total").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let count: Option<i64> = row.try_get("count").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        // Removing millisecond precision to get accurate diffs against clickhouse
+        let start_bucket: Option<PrimitiveDateTime> = row
+            .try_get::<Option<PrimitiveDateTime>, _>("start_bucket")?
+            .and_then(|dt| dt.replace_millisecond(0).ok());
+        let end_bucket: Option<PrimitiveDateTime> = row
+            .try_get::<Option<PrimitiveDateTime>, _>("end_bucket")?
+            .and_then(|dt| dt.replace_millisecond(0).ok());
+        Ok(Self {
+            connector,
+            payout_type,
+            destination_currency,
+            status,
+            profile_id,
+            total,
+            count,
+            start_bucket,
+            end_bucket,
+        })
+    }
+}
+
+impl<'a> FromRow<'a, PgRow> for super::payouts::filters::PayoutFilterRow {
+    fn from_row(row: &'a PgRow) -> sqlx::Result<Self> {
+        let connector: Option<String> = row.try_get("connector").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let payout_type: Option<DBEnumWrapper<PayoutType>> =
+            row.try_get("payout_type").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let destination_currency: Option<DBEnumWrapper<Currency>> =
+            row.try_get("destination_currency").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let status: Option<DBEnumWrapper<PayoutStatus>> =
+            row.try_get("status").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let profile_id: Option<String> = row.try_get("profile_id").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        Ok(Self {
+            connector,
+            payout_type,
+            destination_currency,
+            status,
+            profile_id,
+        })
+    }
+}
+
+impl<'a> FromRow<'a, PgRow> for super::payouts::distribution::PayoutDistributionRow {
+    fn from_row(row: &'a PgRow) -> sqlx::Result<Self> {
+        let connector: Option<String> = row.try_get("connector").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let payout_type: Option<DBEnumWrapper<PayoutType>> =
+            row.try_get("payout_type").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let destination_currency: Option<DBEnumWrapper<Currency>> =
+            row.try_get("destination_currency").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let status: Option<DBEnumWrapper<PayoutStatus>> =
+            row.try_get("status").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let profile_id: Option<String> = row.try_get("profile_id").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let error_message: Option<String> = row.try_get("error_message").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let total: Option<bigdecimal::BigDecimal> = row.try_get("total").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let count: Option<i64> = row.try_get("count").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        // Removing millisecond precision to get accurate diffs against clickhouse
+        let start_bucket: Option<PrimitiveDateTime> = row
+            .try_get::<Option<PrimitiveDateTime>, _>("start_bucket")?
+            .and_then(|dt| dt.replace_millisecond(0).ok());
+        let end_bucket: Option<PrimitiveDateTime> = row
+            .try_get::<Option<PrimitiveDateTime>, _>("end_bucket")?
+            .and_then(|dt| dt.replace_millisecond(0).ok());
+        Ok(Self {
+            connector,
+            payout_type,
+            destination_currency,
+            status,
+            profile_id,
+            error_message,
+            total,
+            count,
+            start_bucket,
+            end_bucket,
+        })
+    }
+}
+
 impl ToSql<SqlxClient> for PrimitiveDateTime {
     fn to_sql(&self, _table_engine: &TableEngine) -> error_stack::Result<String, ParsingError> {
         Ok(self.to_string())
     }
 }
 
+impl ToSql<SqlxClient> for TimeDifference {
+    fn to_sql(&self, _table_engine: &TableEngine) -> error_stack::Result<String, ParsingError> {
+        Ok(format!(
+            "EXTRACT(EPOCH FROM ({} - {}))",
+            self.end, self.start
+        ))
+    }
+}
+
+impl<'a> FromRow<'a, PgRow> for super::reports::rows::PaymentReportRow {
+    fn from_row(row: &'a PgRow) -> sqlx::Result<Self> {
+        let payment_id: String = row.try_get("payment_id")?;
+        let attempt_id: String = row.try_get("attempt_id")?;
+        let merchant_id: String = row.try_get("merchant_id")?;
+        let profile_id: Option<String> = row.try_get("profile_id").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let status: Option<DBEnumWrapper<AttemptStatus>> =
+            row.try_get("status").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let amount: Option<i64> = row.try_get("amount").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let currency: Option<DBEnumWrapper<Currency>> =
+            row.try_get("currency").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let connector: Option<String> = row.try_get("connector").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let payment_method: Option<String> =
+            row.try_get("payment_method").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let payment_method_type: Option<String> =
+            row.try_get("payment_method_type").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let authentication_type: Option<DBEnumWrapper<AuthenticationType>> =
+            row.try_get("authentication_type").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let error_code: Option<String> = row.try_get("error_code").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let error_message: Option<String> = row.try_get("error_message").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let created_at: PrimitiveDateTime = row.try_get("created_at")?;
+        let modified_at: PrimitiveDateTime = row.try_get("modified_at")?;
+        Ok(Self {
+            payment_id,
+            attempt_id,
+            merchant_id,
+            profile_id,
+            status,
+            amount,
+            currency,
+            connector,
+            payment_method,
+            payment_method_type,
+            authentication_type,
+            error_code,
+            error_message,
+            created_at,
+            modified_at,
+        })
+    }
+}
+
+impl<'a> FromRow<'a, PgRow> for super::reports::rows::RefundReportRow {
+    fn from_row(row: &'a PgRow) -> sqlx::Result<Self> {
+        let refund_id: String = row.try_get("refund_id")?;
+        let payment_id: String = row.try_get("payment_id")?;
+        let attempt_id: String = row.try_get("attempt_id")?;
+        let merchant_id: String = row.try_get("merchant_id")?;
+        let profile_id: Option<String> = row.try_get("profile_id").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let refund_status: Option<DBEnumWrapper<RefundStatus>> =
+            row.try_get("refund_status").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let refund_type: Option<DBEnumWrapper<RefundType>> =
+            row.try_get("refund_type").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let total_amount: Option<i64> = row.try_get("total_amount").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let refund_amount: Option<i64> = row.try_get("refund_amount").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let currency: Option<DBEnumWrapper<Currency>> =
+            row.try_get("currency").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let connector: Option<String> = row.try_get("connector").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let refund_reason: Option<String> = row.try_get("refund_reason").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let refund_error_code: Option<String> =
+            row.try_get("refund_error_code").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let refund_error_message: Option<String> =
+            row.try_get("refund_error_message").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let created_at: PrimitiveDateTime = row.try_get("created_at")?;
+        let modified_at: PrimitiveDateTime = row.try_get("modified_at")?;
+        Ok(Self {
+            refund_id,
+            payment_id,
+            attempt_id,
+            merchant_id,
+            profile_id,
+            refund_status,
+            refund_type,
+            total_amount,
+            refund_amount,
+            currency,
+            connector,
+            refund_reason,
+            refund_error_code,
+            refund_error_message,
+            created_at,
+            modified_at,
+        })
+    }
+}
+
+impl<'a> FromRow<'a, PgRow> for super::reports::rows::DisputeReportRow {
+    fn from_row(row: &'a PgRow) -> sqlx::Result<Self> {
+        let dispute_id: String = row.try_get("dispute_id")?;
+        let payment_id: String = row.try_get("payment_id")?;
+        let attempt_id: String = row.try_get("attempt_id")?;
+        let merchant_id: String = row.try_get("merchant_id")?;
+        let profile_id: Option<String> = row.try_get("profile_id").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let dispute_stage: Option<DBEnumWrapper<DisputeStage>> =
+            row.try_get("dispute_stage").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let dispute_status: Option<DBEnumWrapper<DisputeStatus>> =
+            row.try_get("dispute_status").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let dispute_amount: Option<i64> = row.try_get("dispute_amount").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let currency: Option<String> = row.try_get("currency").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let connector: Option<String> = row.try_get("connector").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let connector_status: Option<String> =
+            row.try_get("connector_status").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let connector_reason: Option<String> =
+            row.try_get("connector_reason").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let challenge_required_by: Option<PrimitiveDateTime> =
+            row.try_get("challenge_required_by").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let created_at: PrimitiveDateTime = row.try_get("created_at")?;
+        let modified_at: PrimitiveDateTime = row.try_get("modified_at")?;
+        Ok(Self {
+            dispute_id,
+            payment_id,
+            attempt_id,
+            merchant_id,
+            profile_id,
+            dispute_stage,
+            dispute_status,
+            dispute_amount,
+            currency,
+            connector,
+            connector_status,
+            connector_reason,
+            challenge_required_by,
+            created_at,
+            modified_at,
+        })
+    }
+}
+
 impl ToSql<SqlxClient> for AnalyticsCollection {
     fn to_sql(&self, _table_engine: &TableEngine) -> error_stack::Result<String, ParsingError> {
         match self {
@@ -1157,6 +1534,17 @@ impl ToSql<SqlxClient> for AnalyticsCollection {
                 .attach_printable("DisputeSessionized table is not implemented for Sqlx"))?,
             Self::Authentications => Err(error_stack::report!(ParsingError::UnknownError)
                 .attach_printable("Authentications table is not implemented for Sqlx"))?,
+            // Payouts and their latest attempts are stored in separate tables
+            Self::Payout => Ok(
+                "(SELECT payouts.payout_id, payouts.merchant_id, payouts.profile_id, \
+                payouts.payout_type, payouts.amount, payouts.destination_currency, payouts.status, \
+                payouts.created_at, payouts.last_modified_at, payout_attempt.connector, \
+                payout_attempt.error_code, payout_attempt.error_message FROM payouts \
+                LEFT JOIN payout_attempt ON payout_attempt.merchant_id = payouts.merchant_id \
+                AND payout_attempt.payout_attempt_id = \
+                payouts.payout_id || '_' || payouts.attempt_count) AS payout"
+                    .to_string(),
+            ),
         }
     }
 }
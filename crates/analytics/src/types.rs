@@ -21,6 +21,7 @@ pub enum AnalyticsDomain {
     SdkEvents,
     ApiEvents,
     Dispute,
+    Payouts,
 }
 
 #[derive(Debug, strum::AsRefStr, strum::Display, Clone, Copy)]
@@ -42,6 +43,7 @@ pub enum AnalyticsCollection {
     DisputeSessionized,
     ApiEventsAnalytics,
     ActivePaymentsAnalytics,
+    Payout,
 }
 
 #[allow(dead_code)]
@@ -5,6 +5,7 @@ use api_models::analytics::{
     frm::{FrmDimensions, FrmMetrics},
     payment_intents::{PaymentIntentDimensions, PaymentIntentMetrics},
     payments::{PaymentDimensions, PaymentMetrics},
+    payouts::{PayoutDimensions, PayoutMetrics},
     refunds::{RefundDimensions, RefundMetrics},
     sdk_events::{SdkEventDimensions, SdkEventMetrics},
     NameDescription,
@@ -109,3 +110,11 @@ pub fn get_dispute_metrics_info() -> Vec<NameDescription> {
 pub fn get_dispute_dimensions() -> Vec<NameDescription> {
     DisputeDimensions::iter().map(Into::into).collect()
 }
+
+pub fn get_payout_metrics_info() -> Vec<NameDescription> {
+    PayoutMetrics::iter().map(Into::into).collect()
+}
+
+pub fn get_payout_dimensions() -> Vec<NameDescription> {
+    PayoutDimensions::iter().map(Into::into).collect()
+}
@@ -2152,6 +2152,11 @@ pub struct ProfileCreate {
 
     /// External Vault Connector Details
     pub external_vault_connector_details: Option<ExternalVaultConnectorDetails>,
+
+    /// Retry policy used for revenue recovery payments of the profile
+    #[schema(value_type = Option<RevenueRecoveryRetryPolicy>)]
+    pub revenue_recovery_retry_policy:
+        Option<common_types::revenue_recovery::RevenueRecoveryRetryPolicy>,
 }
 
 #[cfg(feature = "v1")]
@@ -2464,6 +2469,11 @@ pub struct ProfileResponse {
 
     /// External Vault Connector Details
     pub external_vault_connector_details: Option<ExternalVaultConnectorDetails>,
+
+    /// Retry policy used for revenue recovery payments of the profile
+    #[schema(value_type = Option<RevenueRecoveryRetryPolicy>)]
+    pub revenue_recovery_retry_policy:
+        Option<common_types::revenue_recovery::RevenueRecoveryRetryPolicy>,
 }
 
 #[cfg(feature = "v1")]
@@ -2751,6 +2761,11 @@ pub struct ProfileUpdate {
 
     /// External Vault Connector Details
     pub external_vault_connector_details: Option<ExternalVaultConnectorDetails>,
+
+    /// Retry policy used for revenue recovery payments of the profile
+    #[schema(value_type = Option<RevenueRecoveryRetryPolicy>)]
+    pub revenue_recovery_retry_policy:
+        Option<common_types::revenue_recovery::RevenueRecoveryRetryPolicy>,
 }
 
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
@@ -3071,6 +3086,36 @@ pub struct ConnectorAgnosticMitChoice {
 
 impl common_utils::events::ApiEventMetric for ConnectorAgnosticMitChoice {}
 
+/// Request to rotate the key used to sign outgoing webhooks for a business profile
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ProfileWebhookSecretRotateRequest {
+    /// Duration (in seconds) for which outgoing webhooks will additionally be signed with the
+    /// previous key. Defaults to 24 hours.
+    #[schema(example = 86400, maximum = 604800)]
+    pub rotation_window_in_seconds: Option<u32>,
+}
+
+impl common_utils::events::ApiEventMetric for ProfileWebhookSecretRotateRequest {}
+
+/// Response after rotating the key used to sign outgoing webhooks for a business profile
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct ProfileWebhookSecretRotateResponse {
+    /// The identifier for the business profile
+    #[schema(value_type = String, max_length = 64, example = "pro_abcdefghijklmnopqrstuvwxyz")]
+    pub profile_id: id_type::ProfileId,
+
+    /// The new key used to sign outgoing webhooks
+    pub payment_response_hash_key: String,
+
+    /// Time until which outgoing webhooks will also be signed with the previous key
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub previous_payment_response_hash_key_expires_at: Option<time::PrimitiveDateTime>,
+}
+
+impl common_utils::events::ApiEventMetric for ProfileWebhookSecretRotateResponse {}
+
 impl common_utils::events::ApiEventMetric for payment_methods::PaymentMethodMigrate {}
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
@@ -12,6 +12,7 @@ use self::{
     frm::{FrmDimensions, FrmMetrics},
     payment_intents::{PaymentIntentDimensions, PaymentIntentMetrics},
     payments::{PaymentDimensions, PaymentDistributions, PaymentMetrics},
+    payouts::{PayoutDimensions, PayoutDistributions, PayoutMetrics},
     refunds::{RefundDimensions, RefundDistributions, RefundMetrics},
     sdk_events::{SdkEventDimensions, SdkEventMetrics},
 };
@@ -24,6 +25,7 @@ pub mod frm;
 pub mod outgoing_webhook_event;
 pub mod payment_intents;
 pub mod payments;
+pub mod payouts;
 pub mod refunds;
 pub mod sdk_events;
 pub mod search;
@@ -73,6 +75,7 @@ pub struct AnalyticsRequest {
     pub payment_attempt: Option<GetPaymentMetricRequest>,
     pub refund: Option<GetRefundMetricRequest>,
     pub dispute: Option<GetDisputeMetricRequest>,
+    pub payout: Option<GetPayoutMetricRequest>,
 }
 
 impl AnalyticsRequest {
@@ -96,6 +99,11 @@ impl AnalyticsRequest {
                 .as_ref()
                 .map(|req| req.metrics.iter().any(|metric| metric.is_forex_metric()))
                 .unwrap_or_default()
+            || self
+                .payout
+                .as_ref()
+                .map(|req| req.metrics.iter().any(|metric| metric.is_forex_metric()))
+                .unwrap_or_default()
     }
 }
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
@@ -145,11 +153,21 @@ pub struct RefundDistributionBody {
     pub distribution_cardinality: QueryLimit,
 }
 
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PayoutDistributionBody {
+    pub distribution_for: PayoutDistributions,
+    pub distribution_cardinality: QueryLimit,
+}
+
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ReportRequest {
     pub time_range: TimeRange,
     pub emails: Option<Vec<Secret<String, EmailStrategy>>>,
+    /// File format of the generated report, only supported by native report generation
+    #[serde(default)]
+    pub format: ReportFormat,
 }
 
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
@@ -161,6 +179,90 @@ pub struct GenerateReportRequest {
     pub email: Secret<String, EmailStrategy>,
 }
 
+#[derive(Clone, Copy, Debug, serde::Deserialize, serde::Serialize, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ReportType {
+    Payment,
+    Refund,
+    Dispute,
+}
+
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, Eq, serde::Deserialize, serde::Serialize, strum::Display,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ReportFormat {
+    #[default]
+    Csv,
+    Parquet,
+}
+
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize, strum::Display,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ReportStatus {
+    Pending,
+    Completed,
+    Failed,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GenerateReportResponse {
+    pub report_id: String,
+    pub status: ReportStatus,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReportStatusResponse {
+    pub report_id: String,
+    pub report_type: ReportType,
+    pub format: ReportFormat,
+    pub status: ReportStatus,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub start_time: time::PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub end_time: Option<time::PrimitiveDateTime>,
+    /// Number of rows in the generated report
+    pub row_count: Option<u64>,
+    /// Link to download the generated report, present once the report is completed
+    pub download_url: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub download_url_expires_at: Option<time::PrimitiveDateTime>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateDigestSubscriptionRequest {
+    /// Role of the users of the merchant receiving the digest
+    pub role_id: String,
+    pub frequency: common_enums::AnalyticsDigestFrequency,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateDigestSubscriptionRequest {
+    pub frequency: common_enums::AnalyticsDigestFrequency,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DigestSubscriptionResponse {
+    pub subscription_id: String,
+    pub role_id: String,
+    pub frequency: common_enums::AnalyticsDigestFrequency,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+    /// Time at which the last digest was sent, if any
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub last_sent_at: Option<time::PrimitiveDateTime>,
+}
+
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GetPaymentIntentMetricRequest {
@@ -190,6 +292,21 @@ pub struct GetRefundMetricRequest {
     pub delta: bool,
 }
 
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetPayoutMetricRequest {
+    pub time_series: Option<TimeSeries>,
+    pub time_range: TimeRange,
+    #[serde(default)]
+    pub group_by_names: Vec<PayoutDimensions>,
+    #[serde(default)]
+    pub filters: payouts::PayoutFilters,
+    pub metrics: HashSet<PayoutMetrics>,
+    pub distribution: Option<PayoutDistributionBody>,
+    #[serde(default)]
+    pub delta: bool,
+}
+
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GetFrmMetricRequest {
@@ -285,6 +402,14 @@ pub struct RefundsAnalyticsMetadata {
     pub total_refund_reason_count: Option<u64>,
     pub total_refund_error_message_count: Option<u64>,
 }
+
+#[derive(Debug, serde::Serialize)]
+pub struct PayoutsAnalyticsMetadata {
+    pub total_payout_success_rate: Option<f64>,
+    pub total_payout_processed_amount: Option<u64>,
+    pub total_payout_processed_amount_in_usd: Option<u64>,
+    pub total_payout_processed_count: Option<u64>,
+}
 #[derive(Debug, serde::Deserialize, serde::Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GetPaymentFiltersRequest {
@@ -348,6 +473,27 @@ pub struct RefundFilterValue {
     pub values: Vec<String>,
 }
 
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetPayoutFilterRequest {
+    pub time_range: TimeRange,
+    #[serde(default)]
+    pub group_by_names: Vec<PayoutDimensions>,
+}
+
+#[derive(Debug, Default, serde::Serialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PayoutFiltersResponse {
+    pub query_data: Vec<PayoutFilterValue>,
+}
+
+#[derive(Debug, serde::Serialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PayoutFilterValue {
+    pub dimension: PayoutDimensions,
+    pub values: Vec<String>,
+}
+
 #[derive(Debug, serde::Deserialize, serde::Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GetFrmFilterRequest {
@@ -428,6 +574,13 @@ pub struct RefundsMetricsResponse<T> {
     pub query_data: Vec<T>,
     pub meta_data: [RefundsAnalyticsMetadata; 1],
 }
+
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PayoutsMetricsResponse<T> {
+    pub query_data: Vec<T>,
+    pub meta_data: [PayoutsAnalyticsMetadata; 1],
+}
 #[derive(Debug, serde::Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DisputesMetricsResponse<T> {
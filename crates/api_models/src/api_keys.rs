@@ -23,6 +23,16 @@ pub struct CreateApiKeyRequest {
     /// rotating your keys once every 6 months.
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: ApiKeyExpiration,
+
+    /// The permission groups that the API Key is allowed to access. If not provided, the API Key
+    /// can access all the APIs available to the merchant account.
+    #[schema(value_type = Option<Vec<PermissionGroup>>, example = json!(["operations_view"]))]
+    pub permission_groups: Option<Vec<common_enums::PermissionGroup>>,
+
+    /// The profile that the API Key is restricted to. If provided, the API Key can only access
+    /// the resources belonging to this profile.
+    #[schema(value_type = Option<String>, max_length = 64, example = "pro_abcdefghijklmnop")]
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
 }
 
 /// The response body for creating an API Key.
@@ -60,6 +70,15 @@ pub struct CreateApiKeyResponse {
     /// The expiration date for the API Key.
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: ApiKeyExpiration,
+
+    /// The permission groups that the API Key is allowed to access. If not set, the API Key can
+    /// access all the APIs available to the merchant account.
+    #[schema(value_type = Option<Vec<PermissionGroup>>, example = json!(["operations_view"]))]
+    pub permission_groups: Option<Vec<common_enums::PermissionGroup>>,
+
+    /// The profile that the API Key is restricted to.
+    #[schema(value_type = Option<String>, max_length = 64, example = "pro_abcdefghijklmnop")]
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
     /*
     /// The date and time indicating when the API Key was last used.
     #[schema(example = "2022-09-10T10:11:12Z")]
@@ -102,6 +121,15 @@ pub struct RetrieveApiKeyResponse {
     /// The expiration date for the API Key.
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: ApiKeyExpiration,
+
+    /// The permission groups that the API Key is allowed to access. If not set, the API Key can
+    /// access all the APIs available to the merchant account.
+    #[schema(value_type = Option<Vec<PermissionGroup>>, example = json!(["operations_view"]))]
+    pub permission_groups: Option<Vec<common_enums::PermissionGroup>>,
+
+    /// The profile that the API Key is restricted to.
+    #[schema(value_type = Option<String>, max_length = 64, example = "pro_abcdefghijklmnop")]
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
     /*
     /// The date and time indicating when the API Key was last used.
     #[schema(example = "2022-09-10T10:11:12Z")]
@@ -130,6 +158,11 @@ pub struct UpdateApiKeyRequest {
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: Option<ApiKeyExpiration>,
 
+    /// The permission groups that the API Key is allowed to access. The existing permission
+    /// groups of the API Key are replaced with the ones provided.
+    #[schema(value_type = Option<Vec<PermissionGroup>>, example = json!(["operations_view"]))]
+    pub permission_groups: Option<Vec<common_enums::PermissionGroup>>,
+
     #[serde(skip_deserializing)]
     #[schema(value_type = String)]
     pub key_id: common_utils::id_type::ApiKeyId,
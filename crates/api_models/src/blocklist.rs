@@ -20,7 +20,32 @@ pub struct GenerateFingerprintRequest {
 pub struct Card {
     pub card_number: StrongSecret<String>,
 }
-pub type AddToBlocklistRequest = BlocklistRequest;
+/// The kind of data being blocked, same as the `type` of a [`BlocklistRequest`]
+#[derive(Debug, Clone, Copy, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BlocklistRequestType {
+    CardBin,
+    Fingerprint,
+    ExtendedCardBin,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct AddToBlocklistRequest {
+    /// The kind of data being blocked
+    #[serde(rename = "type")]
+    pub entry_type: BlocklistRequestType,
+    /// The card bin, extended card bin or fingerprint to be blocked
+    pub data: String,
+    /// The time after which the entry is no longer blocked. The entry is blocked indefinitely if
+    /// this is not provided
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub expires_at: Option<time::PrimitiveDateTime>,
+    /// The reason or category for blocking the entry
+    #[schema(max_length = 255, example = "card_testing_attack")]
+    pub reason: Option<String>,
+}
+
 pub type DeleteFromBlocklistRequest = BlocklistRequest;
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
@@ -30,6 +55,17 @@ pub struct BlocklistResponse {
     pub data_kind: enums::BlocklistDataKind,
     #[serde(with = "common_utils::custom_serde::iso8601")]
     pub created_at: time::PrimitiveDateTime,
+    /// The time after which the entry is no longer blocked
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub expires_at: Option<time::PrimitiveDateTime>,
+    /// The reason or category for blocking the entry
+    pub reason: Option<String>,
+    /// The creator of the entry, either the API key or the dashboard user which created the entry
+    #[schema(example = "ApiKey:dev_ZbwXJzUWtJxOIk7tcaNt")]
+    pub created_by: Option<String>,
+    #[schema(value_type = BlocklistEntryStatus)]
+    pub status: enums::BlocklistEntryStatus,
 }
 
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
@@ -47,8 +83,10 @@ pub type DeleteFromBlocklistResponse = BlocklistResponse;
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
 pub struct ListBlocklistQuery {
-    #[schema(value_type = BlocklistDataKind)]
-    pub data_kind: enums::BlocklistDataKind,
+    #[schema(value_type = Option<BlocklistDataKind>)]
+    pub data_kind: Option<enums::BlocklistDataKind>,
+    #[schema(value_type = Option<BlocklistEntryStatus>)]
+    pub status: Option<enums::BlocklistEntryStatus>,
     #[serde(default = "default_list_limit")]
     pub limit: u16,
     #[serde(default)]
@@ -66,6 +104,7 @@ pub struct ToggleBlocklistQuery {
 }
 
 impl ApiEventMetric for BlocklistRequest {}
+impl ApiEventMetric for AddToBlocklistRequest {}
 impl ApiEventMetric for BlocklistResponse {}
 impl ApiEventMetric for ToggleBlocklistResponse {}
 impl ApiEventMetric for ListBlocklistQuery {}
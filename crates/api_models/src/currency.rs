@@ -17,5 +17,32 @@ pub struct CurrencyConversionResponse {
     pub currency: String,
 }
 
+/// QueryParams to be send to convert the amount -> from_currency -> to_currency, rounded to the
+/// precision of to_currency
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct RoundedCurrencyConversionParams {
+    pub amount: MinorUnit,
+    pub to_currency: String,
+    pub from_currency: String,
+    /// The rounding applied to the converted amount, defaults to half-even
+    pub rounding_mode: Option<common_enums::RoundingMode>,
+    /// The FX fee charged on top of the exchange rate, in basis points, of at most 10000
+    pub markup_bps: Option<u16>,
+}
+
+/// Response to be send for convert currency with rounding route
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct RoundedCurrencyConversionResponse {
+    /// The converted amount in the major unit of the currency, rounded to its precision
+    pub converted_amount: String,
+    /// The converted amount in the minor unit of the currency
+    pub converted_minor_amount: MinorUnit,
+    pub currency: String,
+}
+
 impl ApiEventMetric for CurrencyConversionResponse {}
 impl ApiEventMetric for CurrencyConversionParams {}
+impl ApiEventMetric for RoundedCurrencyConversionResponse {}
+impl ApiEventMetric for RoundedCurrencyConversionParams {}
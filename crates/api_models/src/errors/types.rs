@@ -97,6 +97,8 @@ pub enum ApiErrorResponse {
     MethodNotAllowed(ApiError),
     BadRequest(ApiError),
     DomainError(ApiError),
+    /// The second field is the number of seconds after which the request can be retried
+    TooManyRequests(ApiError, #[serde(skip_serializing)] u64),
 }
 
 impl ::core::fmt::Display for ApiErrorResponse {
@@ -126,7 +128,8 @@ impl ApiErrorResponse {
             | Self::MethodNotAllowed(i)
             | Self::BadRequest(i)
             | Self::DomainError(i)
-            | Self::ConnectorError(i, _) => i,
+            | Self::ConnectorError(i, _)
+            | Self::TooManyRequests(i, _) => i,
         }
     }
 
@@ -144,7 +147,8 @@ impl ApiErrorResponse {
             | Self::MethodNotAllowed(i)
             | Self::BadRequest(i)
             | Self::DomainError(i)
-            | Self::ConnectorError(i, _) => i,
+            | Self::ConnectorError(i, _)
+            | Self::TooManyRequests(i, _) => i,
         }
     }
 
@@ -159,7 +163,8 @@ impl ApiErrorResponse {
             | Self::NotImplemented(_)
             | Self::MethodNotAllowed(_)
             | Self::NotFound(_)
-            | Self::BadRequest(_) => "invalid_request",
+            | Self::BadRequest(_)
+            | Self::TooManyRequests(_, _) => "invalid_request",
             Self::InternalServerError(_) => "api",
             Self::DomainError(_) => "blocked",
             Self::ConnectorError(_, _) => "connector",
@@ -121,6 +121,9 @@ impl_api_event_type!(
         PaymentFiltersResponse,
         GetRefundFilterRequest,
         RefundFiltersResponse,
+        GetPayoutMetricRequest,
+        GetPayoutFilterRequest,
+        PayoutFiltersResponse,
         AuthEventFiltersResponse,
         GetSdkEventFiltersRequest,
         SdkEventFiltersResponse,
@@ -128,6 +131,11 @@ impl_api_event_type!(
         GetApiEventMetricRequest,
         SdkEventsRequest,
         ReportRequest,
+        GenerateReportResponse,
+        ReportStatusResponse,
+        CreateDigestSubscriptionRequest,
+        UpdateDigestSubscriptionRequest,
+        DigestSubscriptionResponse,
         ConnectorEventsRequest,
         OutgoingWebhookLogsRequest,
         GetGlobalSearchRequest,
@@ -180,6 +188,12 @@ impl<T> ApiEventMetric for RefundsMetricsResponse<T> {
     }
 }
 
+impl<T> ApiEventMetric for PayoutsMetricsResponse<T> {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
 impl<T> ApiEventMetric for DisputesMetricsResponse<T> {
     fn get_api_event_type(&self) -> Option<ApiEventsType> {
         Some(ApiEventsType::Miscellaneous)
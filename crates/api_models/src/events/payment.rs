@@ -303,6 +303,28 @@ impl ApiEventMetric for PaymentMethodListRequest {
 
 impl ApiEventMetric for ListCountriesCurrenciesRequest {}
 
+#[cfg(feature = "v1")]
+impl ApiEventMetric for payment_methods::ToggleCardAccountUpdaterQuery {}
+
+#[cfg(feature = "v1")]
+impl ApiEventMetric for payment_methods::ToggleCardAccountUpdaterResponse {}
+
+impl ApiEventMetric for payment_methods::PaymentMethodMigrationJobRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::PaymentMethodMigrationJob {
+            job_id: self.job_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for payment_methods::PaymentMethodMigrationJobResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::PaymentMethodMigrationJob {
+            job_id: self.job_id.clone(),
+        })
+    }
+}
+
 impl ApiEventMetric for ListCountriesCurrenciesResponse {}
 impl ApiEventMetric for PaymentMethodListResponse {}
 
@@ -12,16 +12,18 @@ use crate::user::{
     },
     AcceptInviteFromEmailRequest, AuthSelectRequest, AuthorizeResponse, BeginTotpResponse,
     ChangePasswordRequest, CloneConnectorRequest, ConnectAccountRequest, CreateInternalUserRequest,
-    CreateTenantUserRequest, CreateUserAuthenticationMethodRequest, ForgotPasswordRequest,
-    GetSsoAuthUrlRequest, GetUserAuthenticationMethodsRequest, GetUserDetailsResponse,
-    GetUserRoleDetailsRequest, GetUserRoleDetailsResponseV2, InviteUserRequest,
-    PlatformAccountCreateRequest, PlatformAccountCreateResponse, ReInviteUserRequest,
-    RecoveryCodes, ResetPasswordRequest, RotatePasswordRequest, SendVerifyEmailRequest,
-    SignUpRequest, SignUpWithMerchantIdRequest, SsoSignInRequest, SwitchMerchantRequest,
-    SwitchOrganizationRequest, SwitchProfileRequest, TokenResponse, TwoFactorAuthStatusResponse,
-    TwoFactorStatus, UpdateUserAccountDetailsRequest, UpdateUserAuthenticationMethodRequest,
-    UserFromEmailRequest, UserMerchantAccountResponse, UserMerchantCreate,
-    UserOrgMerchantCreateRequest, VerifyEmailRequest, VerifyRecoveryCodeRequest, VerifyTotpRequest,
+    CreateTenantUserRequest, CreateUserAuthenticationMethodRequest,
+    FinishPasskeyRegistrationRequest, ForgotPasswordRequest, GetSsoAuthUrlRequest,
+    GetUserAuthenticationMethodsRequest, GetUserDetailsResponse, GetUserRoleDetailsRequest,
+    GetUserRoleDetailsResponseV2, InviteUserRequest, PasskeyChallengeResponse, PasskeyResponse,
+    PasskeySignInChallengeResponse, PasskeySignInRequest, PlatformAccountCreateRequest,
+    PlatformAccountCreateResponse, ReInviteUserRequest, RecoveryCodes, ResetPasswordRequest,
+    RotatePasswordRequest, SendVerifyEmailRequest, SignUpRequest, SignUpWithMerchantIdRequest,
+    SsoSignInRequest, SwitchMerchantRequest, SwitchOrganizationRequest, SwitchProfileRequest,
+    TokenResponse, TwoFactorAuthStatusResponse, TwoFactorStatus, UpdateUserAccountDetailsRequest,
+    UpdateUserAuthenticationMethodRequest, UserFromEmailRequest, UserMerchantAccountResponse,
+    UserMerchantCreate, UserOrgMerchantCreateRequest, VerifyEmailRequest, VerifyPasskeyRequest,
+    VerifyRecoveryCodeRequest, VerifyTotpRequest,
 };
 
 common_utils::impl_api_event_type!(
@@ -72,7 +74,13 @@ common_utils::impl_api_event_type!(
         GetSsoAuthUrlRequest,
         SsoSignInRequest,
         AuthSelectRequest,
-        CloneConnectorRequest
+        CloneConnectorRequest,
+        PasskeyChallengeResponse,
+        FinishPasskeyRegistrationRequest,
+        VerifyPasskeyRequest,
+        PasskeySignInChallengeResponse,
+        PasskeySignInRequest,
+        PasskeyResponse
     )
 );
 
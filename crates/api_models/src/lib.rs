@@ -39,6 +39,7 @@ pub mod recon;
 pub mod refunds;
 pub mod relay;
 pub mod routing;
+pub mod subscriptions;
 pub mod surcharge_decision_configs;
 #[cfg(feature = "tokenization_v2")]
 pub mod tokenization;
@@ -46,6 +47,7 @@ pub mod user;
 pub mod user_role;
 pub mod verifications;
 pub mod verify_connector;
+pub mod webhook_endpoints;
 pub mod webhook_events;
 pub mod webhooks;
 
@@ -822,7 +822,7 @@ pub enum PaymentMethodResponseData {
     any(feature = "v1", feature = "v2"),
     not(feature = "payment_methods_v2")
 ))]
-#[derive(Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
 pub struct PaymentMethodResponse {
     /// Unique identifier for a merchant
     #[schema(example = "merchant_1671528864", value_type = String)]
@@ -2701,6 +2701,72 @@ impl From<(PaymentMethodRecord, id_type::MerchantId)> for customers::CustomerReq
     }
 }
 
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct PaymentMethodMigrationJobResponse {
+    /// Unique identifier for the migration job
+    pub job_id: String,
+
+    /// The identifier for the merchant account
+    pub merchant_id: id_type::MerchantId,
+
+    /// The connector for which the connector mandate references of the rows are migrated
+    pub merchant_connector_id: Option<id_type::MerchantConnectorAccountId>,
+
+    /// The aggregate status of the job
+    pub status: api_enums::PaymentMethodMigrationJobStatus,
+
+    /// Whether the job was created only to validate the rows of the file
+    pub dry_run: bool,
+
+    /// The name of the uploaded file
+    pub file_name: Option<String>,
+
+    /// The number of rows in the file
+    pub total_count: i32,
+
+    /// The number of rows which passed validation
+    pub valid_count: i32,
+
+    /// The number of rows which failed validation
+    pub invalid_count: i32,
+
+    /// The number of valid rows for which payment methods were migrated
+    pub migrated_count: i32,
+
+    /// The number of valid rows for which payment methods could not be migrated
+    pub failed_count: i32,
+
+    /// The number of valid rows for which payment methods are yet to be migrated
+    pub pending_count: i32,
+
+    /// Time at which the file was uploaded
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+
+    /// Time at which the status of the job was last updated
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub last_modified_at: time::PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct PaymentMethodMigrationJobRequest {
+    /// The identifier for the merchant account
+    pub merchant_id: id_type::MerchantId,
+
+    /// Unique identifier for the migration job
+    pub job_id: String,
+}
+
+/// A row of the report of a payment method migration job
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct PaymentMethodMigrationJobReportRecord {
+    pub line_number: i32,
+    pub status: api_enums::PaymentMethodMigrationJobItemStatus,
+    pub customer_id: Option<id_type::CustomerId>,
+    pub payment_method_id: Option<String>,
+    pub error_message: Option<String>,
+}
+
 #[derive(Debug, serde::Deserialize, serde::Serialize, ToSchema)]
 pub struct CardNetworkTokenizeRequest {
     /// Merchant ID associated with the tokenization request
@@ -2995,3 +3061,22 @@ pub struct AuthenticationDetails {
     #[schema(value_type = Option<ErrorDetails>)]
     pub error: Option<payments::ErrorDetails>,
 }
+
+#[cfg(feature = "v1")]
+#[derive(Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct ToggleCardAccountUpdaterQuery {
+    /// Whether the stored cards of the merchant are to be refreshed through the card account updater
+    pub status: bool,
+
+    /// The profile to which the webhooks for the updated and closed cards are sent. Defaults to
+    /// the default profile of the merchant.
+    #[schema(value_type = Option<String>)]
+    pub profile_id: Option<id_type::ProfileId>,
+}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, serde::Serialize, ToSchema)]
+pub struct ToggleCardAccountUpdaterResponse {
+    /// `enabled` or `disabled`
+    pub card_account_updater_status: String,
+}
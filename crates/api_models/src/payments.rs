@@ -1166,6 +1166,15 @@ pub struct PaymentsRequest {
     /// Indicates if the redirection has to open in the iframe
     pub is_iframe_redirection_enabled: Option<bool>,
 
+    /// Allows the connector to approve a lower amount than the requested amount, if the issuer only approves part of it
+    #[remove_in(PaymentsUpdateRequest, PaymentsConfirmRequest)]
+    pub enable_partial_authorization: Option<bool>,
+
+    /// Level 2 and Level 3 data for commercial card payments, sent to connectors that support it
+    #[schema(value_type = Option<L2L3Data>)]
+    #[remove_in(PaymentsUpdateRequest, PaymentsConfirmRequest)]
+    pub l2_l3_data: Option<common_types::payments::L2L3Data>,
+
     /// If enabled, provides whole connector response
     pub all_keys_required: Option<bool>,
 }
@@ -5153,6 +5162,9 @@ pub struct PaymentsResponse {
     /// Indicates if the redirection has to open in the iframe
     pub is_iframe_redirection_enabled: Option<bool>,
 
+    /// Indicates if partial authorization was enabled for this payment
+    pub enable_partial_authorization: Option<bool>,
+
     /// Contains whole connector response
     pub whole_connector_response: Option<String>,
 }
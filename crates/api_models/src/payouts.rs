@@ -187,6 +187,12 @@ pub struct PayoutCreateRequest {
 
     /// Identifier for payout method
     pub payout_method_id: Option<String>,
+
+    /// Time at which the payout is to be fulfilled. The payout is created with the connector when it is confirmed, and stays in the `scheduled` status until it is fulfilled at this time. Supported only for payouts to a saved payout method passed in `payout_method_id`, and can not be updated during Payout _Update_.
+    #[remove_in(PayoutUpdateRequest, PayoutConfirmRequest)]
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub scheduled_at: Option<PrimitiveDateTime>,
 }
 
 impl PayoutCreateRequest {
@@ -574,6 +580,11 @@ pub struct PayoutCreateResponse {
 
     /// Identifier for payout method
     pub payout_method_id: Option<String>,
+
+    /// Time at which the payout is to be fulfilled, if the payout is scheduled
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub scheduled_at: Option<PrimitiveDateTime>,
 }
 
 /// The payout method information for response
@@ -986,3 +997,95 @@ impl From<payout_method_utils::AdditionalPayoutMethodData> for PayoutMethodDataR
         }
     }
 }
+
+/// A row of a payout batch file in the CSV format. Payout methods other than the saved payout
+/// methods and cards are supported only in the JSON format, in which every row is a payout create
+/// request.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PayoutBatchRecord {
+    pub payout_id: Option<String>,
+    pub amount: common_utils::types::MinorUnit,
+    pub currency: api_enums::Currency,
+    pub customer_id: Option<id_type::CustomerId>,
+    pub profile_id: Option<id_type::ProfileId>,
+    pub payout_type: Option<api_enums::PayoutType>,
+    pub payout_method_id: Option<String>,
+    pub payout_token: Option<String>,
+    pub connector: Option<api_enums::PayoutConnectors>,
+    pub confirm: Option<bool>,
+    pub auto_fulfill: Option<bool>,
+    pub description: Option<String>,
+    pub entity_type: Option<api_enums::PayoutEntityType>,
+    pub priority: Option<api_enums::PayoutSendPriority>,
+    pub business_country: Option<api_enums::CountryAlpha2>,
+    pub business_label: Option<String>,
+    pub email: Option<Email>,
+    pub name: Option<Secret<String>>,
+    pub phone: Option<Secret<String>>,
+    pub phone_country_code: Option<String>,
+    pub card_number: Option<CardNumber>,
+    pub card_exp_month: Option<Secret<String>>,
+    pub card_exp_year: Option<Secret<String>>,
+    pub card_holder_name: Option<Secret<String>>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct PayoutBatchResponse {
+    /// Unique identifier for the batch
+    #[schema(example = "payout_batch_cmPwW3AWDSXKaBsDAkCR")]
+    pub batch_id: String,
+
+    /// The identifier for the merchant account
+    #[schema(value_type = String, example = "merchant_1668273825")]
+    pub merchant_id: id_type::MerchantId,
+
+    /// The profile used for the rows of the batch which do not specify a profile
+    #[schema(value_type = Option<String>)]
+    pub profile_id: Option<id_type::ProfileId>,
+
+    /// The aggregate status of the batch
+    #[schema(value_type = PayoutBatchStatus, example = "processing")]
+    pub status: api_enums::PayoutBatchStatus,
+
+    /// The name of the uploaded file
+    pub file_name: Option<String>,
+
+    /// The number of rows in the batch
+    pub total_count: i32,
+
+    /// The number of rows for which payouts were created
+    pub succeeded_count: i32,
+
+    /// The number of rows which were invalid, or for which payouts could not be created or failed
+    pub failed_count: i32,
+
+    /// The number of rows for which payouts are yet to be created
+    pub pending_count: i32,
+
+    /// Time at which the batch was uploaded
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+
+    /// Time at which the status of the batch was last updated
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub last_modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct PayoutBatchRetrieveRequest {
+    /// Unique identifier for the batch
+    pub batch_id: String,
+}
+
+/// A row of the result report of a payout batch
+#[derive(Debug, Clone, Serialize)]
+pub struct PayoutBatchReportRecord {
+    pub row_number: i32,
+    pub status: api_enums::PayoutBatchItemStatus,
+    pub payout_id: Option<String>,
+    pub payout_status: Option<api_enums::PayoutStatus>,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+}
@@ -1283,3 +1283,82 @@ impl RoutableConnectorChoiceWithBucketName {
         }
     }
 }
+
+/// The algorithm to be evaluated by a routing simulation
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(tag = "type", content = "data", rename_all = "snake_case")]
+pub enum RoutingSimulationAlgorithm {
+    /// A routing algorithm which has been created under the profile
+    #[schema(value_type = String)]
+    AlgorithmId(common_utils::id_type::RoutingId),
+    /// An advanced routing program which has not been persisted
+    #[schema(value_type = ProgramConnectorSelection)]
+    Advanced(Program<ConnectorSelection>),
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct RoutingSimulationRequest {
+    /// The profile whose payments are to be routed using the algorithm
+    #[schema(value_type = String)]
+    pub profile_id: common_utils::id_type::ProfileId,
+    /// The algorithm to be evaluated
+    pub algorithm: RoutingSimulationAlgorithm,
+    /// The time range within which the payments were created
+    pub time_range: common_utils::types::TimeRange,
+    /// The maximum number of payments to be evaluated, starting from the most recent payment
+    /// within the time range. Defaults to 1000, and can be at most 10000.
+    #[schema(example = 1000)]
+    pub limit: Option<u32>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct RoutingSimulationResponse {
+    #[schema(value_type = String)]
+    pub profile_id: common_utils::id_type::ProfileId,
+    /// The identifier of the evaluated algorithm, if an existing algorithm was evaluated
+    #[schema(value_type = Option<String>)]
+    pub algorithm_id: Option<common_utils::id_type::RoutingId>,
+    pub time_range: common_utils::types::TimeRange,
+    /// The number of payments which were evaluated
+    pub total_count: usize,
+    /// The number of payments for which the algorithm chose the connector that was actually used
+    pub matched_count: usize,
+    /// The number of payments which could not be evaluated using the algorithm
+    pub failed_count: usize,
+    /// The number of payments routed to each connector, by the algorithm and in reality
+    pub connector_distribution: Vec<RoutingSimulationConnectorCount>,
+    /// The number of payments which matched each rule of an advanced routing algorithm
+    pub rule_hits: Vec<RoutingSimulationRuleHitCount>,
+    /// The number of payments which did not match any rule of an advanced routing algorithm, and
+    /// were routed using the default selection of the algorithm
+    pub default_selection_count: usize,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct RoutingSimulationConnectorCount {
+    /// The name of the connector
+    #[schema(example = "stripe")]
+    pub connector: String,
+    /// The number of payments the algorithm routed to the connector
+    pub simulated_count: usize,
+    /// The number of payments which were actually routed to the connector
+    pub actual_count: usize,
+    /// The percentage of the evaluated payments the algorithm routed to the connector
+    #[schema(example = 62.5)]
+    pub simulated_percentage: f64,
+    /// The percentage of the evaluated payments which were actually routed to the connector
+    #[schema(example = 50.0)]
+    pub actual_percentage: f64,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct RoutingSimulationRuleHitCount {
+    /// The name of the rule
+    pub rule_name: String,
+    /// The number of payments which matched the rule
+    pub hit_count: usize,
+    /// The number of payments matching the rule, for which the rule chose the connector that was
+    /// actually used
+    pub matched_count: usize,
+}
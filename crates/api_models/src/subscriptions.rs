@@ -0,0 +1,459 @@
+use common_enums::{
+    Currency, ProrationBehavior, SubscriptionBillingReason, SubscriptionInterval,
+    SubscriptionInvoiceStatus, SubscriptionStatus,
+};
+use common_utils::{
+    events::{ApiEventMetric, ApiEventsType},
+    id_type, pii,
+    types::MinorUnit,
+};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+/// The request body for creating a subscription plan.
+#[derive(Clone, Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SubscriptionPlanCreateRequest {
+    /// The name of the plan.
+    #[schema(max_length = 255, example = "Pro monthly")]
+    pub name: String,
+
+    /// A description of the plan.
+    #[schema(example = "Pro features billed monthly")]
+    pub description: Option<String>,
+
+    /// The amount charged for every billing period, in the lowest denomination of the currency.
+    #[schema(value_type = i64, example = 2000)]
+    pub amount: MinorUnit,
+
+    /// The currency in which the plan is charged.
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: Currency,
+
+    /// The unit of time of the billing period.
+    #[schema(value_type = SubscriptionInterval, example = "month")]
+    pub billing_interval: SubscriptionInterval,
+
+    /// The number of intervals in a billing period, e.g. 3 with an interval of `month` bills
+    /// every quarter. Defaults to 1.
+    #[schema(minimum = 1, example = 1)]
+    pub interval_count: Option<u16>,
+
+    /// The number of days for which a subscription to the plan is not charged after it is created.
+    #[schema(example = 14)]
+    pub trial_period_days: Option<u16>,
+
+    /// You can specify up to 50 keys, with key names up to 40 characters long and values up to 500 characters long. Metadata is useful for storing additional, structured information on an object.
+    #[schema(value_type = Option<Object>, example = r#"{ "udf1": "some-value", "udf2": "some-value" }"#)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+}
+
+/// The request body for updating a subscription plan. The pricing of a plan cannot be updated,
+/// create a new plan instead.
+#[derive(Clone, Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SubscriptionPlanUpdateRequest {
+    #[serde(skip_deserializing)]
+    pub plan_id: String,
+
+    /// The name of the plan.
+    #[schema(max_length = 255, example = "Pro monthly")]
+    pub name: Option<String>,
+
+    /// A description of the plan.
+    #[schema(example = "Pro features billed monthly")]
+    pub description: Option<String>,
+
+    /// Whether new subscriptions can be created for the plan. Existing subscriptions to an
+    /// inactive plan continue to be billed.
+    #[schema(example = false)]
+    pub is_active: Option<bool>,
+
+    /// You can specify up to 50 keys, with key names up to 40 characters long and values up to 500 characters long. Metadata is useful for storing additional, structured information on an object.
+    #[schema(value_type = Option<Object>, example = r#"{ "udf1": "some-value", "udf2": "some-value" }"#)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+}
+
+#[derive(Clone, Debug, Deserialize, ToSchema)]
+pub struct SubscriptionPlanRetrieveRequest {
+    /// The identifier for the subscription plan.
+    pub plan_id: String,
+}
+
+/// The constraints for listing subscription plans.
+#[derive(Clone, Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SubscriptionPlanListConstraints {
+    /// The maximum number of plans to be returned. Defaults to 10, and cannot exceed 100.
+    pub limit: Option<u32>,
+
+    /// The number of plans to be skipped.
+    pub offset: Option<u32>,
+}
+
+/// The response body for a subscription plan.
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct SubscriptionPlanResponse {
+    /// The identifier for the subscription plan.
+    #[schema(max_length = 64, example = "plan_N0ZJYYBl0rXGRkYIi5Ez")]
+    pub plan_id: String,
+
+    /// The identifier for the Merchant Account.
+    #[schema(max_length = 64, example = "y3oqhf46pyzuxjbcn2giaqnb44", value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+
+    /// The name of the plan.
+    pub name: String,
+
+    /// A description of the plan.
+    pub description: Option<String>,
+
+    /// The amount charged for every billing period, in the lowest denomination of the currency.
+    #[schema(value_type = i64, example = 2000)]
+    pub amount: MinorUnit,
+
+    /// The currency in which the plan is charged.
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: Currency,
+
+    /// The unit of time of the billing period.
+    #[schema(value_type = SubscriptionInterval, example = "month")]
+    pub billing_interval: SubscriptionInterval,
+
+    /// The number of intervals in a billing period.
+    pub interval_count: i32,
+
+    /// The number of days for which a subscription to the plan is not charged after it is created.
+    pub trial_period_days: Option<i32>,
+
+    /// Whether new subscriptions can be created for the plan.
+    pub is_active: bool,
+
+    /// You can specify up to 50 keys, with key names up to 40 characters long and values up to 500 characters long. Metadata is useful for storing additional, structured information on an object.
+    #[schema(value_type = Option<Object>, example = r#"{ "udf1": "some-value", "udf2": "some-value" }"#)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+
+    /// Time at which the plan was created.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+/// The request body for creating a subscription.
+#[derive(Clone, Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SubscriptionCreateRequest {
+    /// The identifier for the customer who is subscribing to the plan.
+    #[schema(value_type = String, max_length = 64, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: id_type::CustomerId,
+
+    /// The identifier for the subscription plan.
+    #[schema(max_length = 64, example = "plan_N0ZJYYBl0rXGRkYIi5Ez")]
+    pub plan_id: String,
+
+    /// The identifier for the Business Profile through which the invoices of the subscription are
+    /// charged. Required if the API key is not associated with a profile.
+    #[schema(max_length = 64, value_type = Option<String>, example = "pro_abcdefghijklmnop")]
+    pub profile_id: Option<id_type::ProfileId>,
+
+    /// The identifier for the mandate with which the invoices of the subscription are charged.
+    /// Either `mandate_id` or `payment_method_id` is required.
+    #[schema(max_length = 64, example = "man_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub mandate_id: Option<String>,
+
+    /// The identifier for the stored payment method of the customer with which the invoices of
+    /// the subscription are charged. Either `mandate_id` or `payment_method_id` is required.
+    #[schema(max_length = 64, example = "pm_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub payment_method_id: Option<String>,
+
+    /// The number of trial days for the subscription, overrides the trial period of the plan.
+    /// Pass 0 to start the subscription without a trial.
+    #[schema(example = 14)]
+    pub trial_period_days: Option<u16>,
+
+    /// You can specify up to 50 keys, with key names up to 40 characters long and values up to 500 characters long. Metadata is useful for storing additional, structured information on an object.
+    #[schema(value_type = Option<Object>, example = r#"{ "udf1": "some-value", "udf2": "some-value" }"#)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+}
+
+/// The request body for updating a subscription.
+#[derive(Clone, Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SubscriptionUpdateRequest {
+    #[serde(skip_deserializing)]
+    pub subscription_id: String,
+
+    /// The identifier for the plan to which the subscription is moved. The plan must have the
+    /// same currency and billing period as the current plan of the subscription.
+    #[schema(max_length = 64, example = "plan_N0ZJYYBl0rXGRkYIi5Ez")]
+    pub plan_id: Option<String>,
+
+    /// Determines whether the plan change is prorated for the remaining time of the current
+    /// billing period. Defaults to `create_prorations`.
+    #[schema(value_type = Option<ProrationBehavior>, example = "create_prorations")]
+    pub proration_behavior: Option<ProrationBehavior>,
+
+    /// The identifier for the mandate with which the invoices of the subscription are charged.
+    #[schema(max_length = 64, example = "man_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub mandate_id: Option<String>,
+
+    /// The identifier for the stored payment method of the customer with which the invoices of
+    /// the subscription are charged.
+    #[schema(max_length = 64, example = "pm_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub payment_method_id: Option<String>,
+
+    /// You can specify up to 50 keys, with key names up to 40 characters long and values up to 500 characters long. Metadata is useful for storing additional, structured information on an object.
+    #[schema(value_type = Option<Object>, example = r#"{ "udf1": "some-value", "udf2": "some-value" }"#)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+}
+
+/// The request body for cancelling a subscription.
+#[derive(Clone, Debug, Default, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SubscriptionCancelRequest {
+    #[serde(skip_deserializing)]
+    pub subscription_id: String,
+
+    /// Whether the subscription is cancelled at the end of the current billing period instead of
+    /// immediately. Defaults to `false`.
+    #[schema(example = true)]
+    pub cancel_at_period_end: Option<bool>,
+}
+
+#[derive(Clone, Debug, Deserialize, ToSchema)]
+pub struct SubscriptionRetrieveRequest {
+    /// The identifier for the subscription.
+    pub subscription_id: String,
+}
+
+/// The constraints for listing subscriptions.
+#[derive(Clone, Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SubscriptionListConstraints {
+    /// Only the subscriptions of the customer are returned.
+    #[schema(value_type = Option<String>)]
+    pub customer_id: Option<id_type::CustomerId>,
+
+    /// The maximum number of subscriptions to be returned. Defaults to 10, and cannot exceed 100.
+    pub limit: Option<u32>,
+
+    /// The number of subscriptions to be skipped.
+    pub offset: Option<u32>,
+}
+
+/// The response body for a subscription.
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct SubscriptionResponse {
+    /// The identifier for the subscription.
+    #[schema(max_length = 64, example = "sub_N0ZJYYBl0rXGRkYIi5Ez")]
+    pub subscription_id: String,
+
+    /// The identifier for the Merchant Account.
+    #[schema(max_length = 64, example = "y3oqhf46pyzuxjbcn2giaqnb44", value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+
+    /// The identifier for the Business Profile.
+    #[schema(max_length = 64, value_type = String, example = "pro_abcdefghijklmnop")]
+    pub profile_id: id_type::ProfileId,
+
+    /// The identifier for the customer.
+    #[schema(value_type = String, max_length = 64, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: id_type::CustomerId,
+
+    /// The identifier for the current plan of the subscription.
+    #[schema(max_length = 64, example = "plan_N0ZJYYBl0rXGRkYIi5Ez")]
+    pub plan_id: String,
+
+    /// The status of the subscription.
+    #[schema(value_type = SubscriptionStatus, example = "active")]
+    pub status: SubscriptionStatus,
+
+    /// The identifier for the mandate with which the invoices of the subscription are charged.
+    pub mandate_id: Option<String>,
+
+    /// The identifier for the stored payment method with which the invoices of the subscription
+    /// are charged.
+    pub payment_method_id: Option<String>,
+
+    /// Start of the current billing period, or of the trial period.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub current_period_start: PrimitiveDateTime,
+
+    /// End of the current billing period, or of the trial period. The subscription is renewed at
+    /// this time.
+    #[schema(example = "2022-10-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub current_period_end: PrimitiveDateTime,
+
+    /// End of the trial period, if the subscription had a trial.
+    #[schema(example = "2022-09-24T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub trial_end: Option<PrimitiveDateTime>,
+
+    /// Whether the subscription is cancelled at the end of the current billing period.
+    pub cancel_at_period_end: bool,
+
+    /// Time at which the subscription was cancelled.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub cancelled_at: Option<PrimitiveDateTime>,
+
+    /// The amount credited by the proration of plan downgrades, which is deducted from the next
+    /// invoice.
+    #[schema(value_type = i64, example = 500)]
+    pub credit_balance: MinorUnit,
+
+    /// The latest invoice of the subscription.
+    pub latest_invoice: Option<SubscriptionInvoiceResponse>,
+
+    /// You can specify up to 50 keys, with key names up to 40 characters long and values up to 500 characters long. Metadata is useful for storing additional, structured information on an object.
+    #[schema(value_type = Option<Object>, example = r#"{ "udf1": "some-value", "udf2": "some-value" }"#)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+
+    /// Time at which the subscription was created.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+/// The response body for an invoice of a subscription.
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct SubscriptionInvoiceResponse {
+    /// The identifier for the invoice.
+    #[schema(max_length = 64, example = "inv_N0ZJYYBl0rXGRkYIi5Ez")]
+    pub invoice_id: String,
+
+    /// The identifier for the subscription.
+    #[schema(max_length = 64, example = "sub_N0ZJYYBl0rXGRkYIi5Ez")]
+    pub subscription_id: String,
+
+    /// The identifier for the plan for which the invoice was generated.
+    #[schema(max_length = 64, example = "plan_N0ZJYYBl0rXGRkYIi5Ez")]
+    pub plan_id: String,
+
+    /// The reason for which the invoice was generated.
+    #[schema(value_type = SubscriptionBillingReason, example = "subscription_cycle")]
+    pub billing_reason: SubscriptionBillingReason,
+
+    /// The status of the invoice.
+    #[schema(value_type = SubscriptionInvoiceStatus, example = "paid")]
+    pub status: SubscriptionInvoiceStatus,
+
+    /// The amount due, after deducting the credit balance of the subscription.
+    #[schema(value_type = i64, example = 2000)]
+    pub amount: MinorUnit,
+
+    /// The currency of the invoice.
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: Currency,
+
+    /// Start of the period for which the invoice was generated.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub period_start: PrimitiveDateTime,
+
+    /// End of the period for which the invoice was generated.
+    #[schema(example = "2022-10-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub period_end: PrimitiveDateTime,
+
+    /// The identifier for the payment of the latest attempt to charge the invoice.
+    #[schema(value_type = Option<String>, example = "pay_mbabizu24mvu3mela5njyhpit4")]
+    pub payment_id: Option<id_type::PaymentId>,
+
+    /// The number of attempts made to charge the invoice.
+    pub attempt_count: i32,
+
+    /// The error code of the latest failed attempt to charge the invoice.
+    pub error_code: Option<String>,
+
+    /// The error message of the latest failed attempt to charge the invoice.
+    pub error_message: Option<String>,
+
+    /// Time at which the invoice was created.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+impl ApiEventMetric for SubscriptionPlanCreateRequest {}
+
+impl ApiEventMetric for SubscriptionPlanUpdateRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::SubscriptionPlan {
+            plan_id: self.plan_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for SubscriptionPlanRetrieveRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::SubscriptionPlan {
+            plan_id: self.plan_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for SubscriptionPlanListConstraints {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ResourceListAPI)
+    }
+}
+
+impl ApiEventMetric for SubscriptionPlanResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::SubscriptionPlan {
+            plan_id: self.plan_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for SubscriptionCreateRequest {}
+
+impl ApiEventMetric for SubscriptionUpdateRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Subscription {
+            subscription_id: self.subscription_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for SubscriptionCancelRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Subscription {
+            subscription_id: self.subscription_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for SubscriptionRetrieveRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Subscription {
+            subscription_id: self.subscription_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for SubscriptionListConstraints {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ResourceListAPI)
+    }
+}
+
+impl ApiEventMetric for SubscriptionResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Subscription {
+            subscription_id: self.subscription_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for SubscriptionInvoiceResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Subscription {
+            subscription_id: self.subscription_id.clone(),
+        })
+    }
+}
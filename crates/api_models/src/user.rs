@@ -3,6 +3,7 @@ use std::fmt::Debug;
 use common_enums::{EntityType, TokenPurpose};
 use common_utils::{crypto::OptionalEncryptableName, id_type, pii};
 use masking::Secret;
+use time::PrimitiveDateTime;
 
 use crate::user_role::UserStatus;
 pub mod dashboard_metadata;
@@ -241,6 +242,7 @@ pub struct TokenResponse {
 pub struct TwoFactorAuthStatusResponse {
     pub totp: bool,
     pub recovery_code: bool,
+    pub passkey: bool,
 }
 
 #[derive(Debug, serde::Deserialize, serde::Serialize)]
@@ -253,6 +255,7 @@ pub struct TwoFactorAuthAttempts {
 pub struct TwoFactorAuthStatusResponseWithAttempts {
     pub totp: TwoFactorAuthAttempts,
     pub recovery_code: TwoFactorAuthAttempts,
+    pub passkey: TwoFactorAuthAttempts,
 }
 
 #[derive(Debug, serde::Deserialize, serde::Serialize)]
@@ -292,6 +295,53 @@ pub struct RecoveryCodes {
     pub recovery_codes: Vec<Secret<String>>,
 }
 
+/// WebAuthn options to be passed as is to `navigator.credentials.create()` or
+/// `navigator.credentials.get()` by the dashboard.
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct PasskeyChallengeResponse {
+    pub options: serde_json::Value,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct FinishPasskeyRegistrationRequest {
+    /// Name given by the user to identify the passkey
+    pub name: String,
+    /// The `PublicKeyCredential` returned by `navigator.credentials.create()`
+    pub credential: serde_json::Value,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct VerifyPasskeyRequest {
+    /// The `PublicKeyCredential` returned by `navigator.credentials.get()`
+    pub credential: serde_json::Value,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct PasskeySignInChallengeResponse {
+    /// Identifier of the sign in challenge, to be sent back along with the credential
+    pub challenge_id: String,
+    /// Options to be passed to `navigator.credentials.get()` by the dashboard
+    pub options: serde_json::Value,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct PasskeySignInRequest {
+    pub email: pii::Email,
+    pub challenge_id: String,
+    /// The `PublicKeyCredential` returned by `navigator.credentials.get()`
+    pub credential: serde_json::Value,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct PasskeyResponse {
+    pub passkey_id: String,
+    pub name: String,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub last_used_at: Option<PrimitiveDateTime>,
+}
+
 #[derive(Debug, serde::Deserialize, serde::Serialize)]
 #[serde(tag = "auth_type")]
 #[serde(rename_all = "snake_case")]
@@ -302,6 +352,7 @@ pub enum AuthConfig {
     },
     MagicLink,
     Password,
+    Passkey,
 }
 
 #[derive(Debug, serde::Deserialize, serde::Serialize, Clone)]
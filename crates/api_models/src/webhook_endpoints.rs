@@ -0,0 +1,175 @@
+use common_enums::EventType;
+use common_utils::events::{ApiEventMetric, ApiEventsType};
+use masking::Secret;
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+/// The request body for registering a webhook endpoint on a business profile.
+#[derive(Clone, Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct WebhookEndpointCreateRequest {
+    /// The URL to which outgoing webhooks are delivered.
+    #[schema(value_type = String, example = "https://merchant.example.com/webhooks")]
+    pub url: url::Url,
+
+    /// The event types this endpoint is subscribed to.
+    #[schema(min_items = 1)]
+    pub enabled_events: Vec<EventType>,
+
+    /// A description of the endpoint.
+    #[schema(
+        max_length = 255,
+        example = "Refund notifications for the finance team"
+    )]
+    pub description: Option<String>,
+
+    /// Whether webhooks should be delivered to this endpoint. Defaults to `true`.
+    #[schema(example = true)]
+    pub is_active: Option<bool>,
+}
+
+/// The request body for updating a webhook endpoint.
+#[derive(Clone, Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct WebhookEndpointUpdateRequest {
+    /// The URL to which outgoing webhooks are delivered.
+    #[schema(value_type = Option<String>, example = "https://merchant.example.com/webhooks")]
+    pub url: Option<url::Url>,
+
+    /// The event types this endpoint is subscribed to. Replaces the existing subscriptions.
+    pub enabled_events: Option<Vec<EventType>>,
+
+    /// A description of the endpoint.
+    #[schema(
+        max_length = 255,
+        example = "Refund notifications for the finance team"
+    )]
+    pub description: Option<String>,
+
+    /// Whether webhooks should be delivered to this endpoint.
+    #[schema(example = false)]
+    pub is_active: Option<bool>,
+}
+
+/// The response body for a webhook endpoint.
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct WebhookEndpointResponse {
+    /// The identifier for the webhook endpoint.
+    #[schema(max_length = 64, example = "whe_N0ZJYYBl0rXGRkYIi5Ez")]
+    pub endpoint_id: String,
+
+    /// The identifier for the Merchant Account.
+    #[schema(max_length = 64, example = "y3oqhf46pyzuxjbcn2giaqnb44", value_type = String)]
+    pub merchant_id: common_utils::id_type::MerchantId,
+
+    /// The identifier for the Business Profile.
+    #[schema(max_length = 64, value_type = String, example = "SqB0zwDGR5wHppWf0bx7GKr1f2")]
+    pub profile_id: common_utils::id_type::ProfileId,
+
+    /// The URL to which outgoing webhooks are delivered.
+    #[schema(value_type = String, example = "https://merchant.example.com/webhooks")]
+    pub url: Secret<String>,
+
+    /// The secret used to sign webhooks delivered to this endpoint. Only returned when the endpoint
+    /// is created, a new secret can be obtained by rotating the secret of the endpoint.
+    #[schema(value_type = Option<String>)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secret: Option<Secret<String>>,
+
+    /// The event types this endpoint is subscribed to.
+    pub enabled_events: Vec<EventType>,
+
+    /// Whether webhooks are delivered to this endpoint.
+    pub is_active: bool,
+
+    /// A description of the endpoint.
+    pub description: Option<String>,
+
+    /// Time at which the endpoint was created.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+
+    /// Time at which the endpoint was last modified.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub last_modified_at: PrimitiveDateTime,
+}
+
+/// The request body for rotating the secret of a webhook endpoint.
+#[derive(Clone, Debug, Default, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct WebhookEndpointSecretRotateRequest {
+    /// Duration (in seconds) for which webhooks will additionally be signed with the previous
+    /// secret. Defaults to 24 hours.
+    #[schema(example = 86400, maximum = 604800)]
+    pub rotation_window_in_seconds: Option<u32>,
+}
+
+/// The response body for rotating the secret of a webhook endpoint.
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct WebhookEndpointSecretRotateResponse {
+    /// The identifier for the webhook endpoint.
+    #[schema(max_length = 64, example = "whe_N0ZJYYBl0rXGRkYIi5Ez")]
+    pub endpoint_id: String,
+
+    /// The identifier for the Merchant Account.
+    #[schema(max_length = 64, example = "y3oqhf46pyzuxjbcn2giaqnb44", value_type = String)]
+    pub merchant_id: common_utils::id_type::MerchantId,
+
+    /// The new secret used to sign webhooks delivered to this endpoint.
+    #[schema(value_type = String)]
+    pub secret: Secret<String>,
+
+    /// Time until which webhooks will also be signed with the previous secret.
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub previous_secret_expires_at: Option<PrimitiveDateTime>,
+}
+
+/// The response body for deleting a webhook endpoint.
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct WebhookEndpointDeleteResponse {
+    /// The identifier for the deleted webhook endpoint.
+    #[schema(max_length = 64, example = "whe_N0ZJYYBl0rXGRkYIi5Ez")]
+    pub endpoint_id: String,
+
+    /// The identifier for the Merchant Account.
+    #[schema(max_length = 64, example = "y3oqhf46pyzuxjbcn2giaqnb44", value_type = String)]
+    pub merchant_id: common_utils::id_type::MerchantId,
+
+    /// Whether the endpoint was deleted.
+    #[schema(example = true)]
+    pub deleted: bool,
+}
+
+impl ApiEventMetric for WebhookEndpointCreateRequest {}
+
+impl ApiEventMetric for WebhookEndpointUpdateRequest {}
+
+impl ApiEventMetric for WebhookEndpointResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Events {
+            merchant_id: self.merchant_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for WebhookEndpointSecretRotateRequest {}
+
+impl ApiEventMetric for WebhookEndpointSecretRotateResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Events {
+            merchant_id: self.merchant_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for WebhookEndpointDeleteResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Events {
+            merchant_id: self.merchant_id.clone(),
+        })
+    }
+}
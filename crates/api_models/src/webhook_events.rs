@@ -226,3 +226,137 @@ impl common_utils::events::ApiEventMetric for WebhookDeliveryRetryRequestInterna
         })
     }
 }
+
+/// The request body for replaying the webhook events of a business profile in bulk.
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct WebhookEventReplayRequest {
+    /// The identifier for the Business Profile whose events are to be replayed.
+    #[schema(value_type = String, example = "SqB0zwDGR5wHppWf0bx7GKr1f2")]
+    pub profile_id: common_utils::id_type::ProfileId,
+
+    /// Replay events created after the specified time.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_after: PrimitiveDateTime,
+
+    /// Replay events created before the specified time. Defaults to the current time.
+    #[schema(example = "2022-09-11T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub created_before: Option<PrimitiveDateTime>,
+
+    /// Replay only the events of the specified types.
+    pub event_types: Option<HashSet<EventType>>,
+
+    /// Replay only the events whose overall delivery status matches the specified value.
+    pub is_delivered: Option<bool>,
+
+    /// The number of events to be redelivered in each run of the replay job.
+    #[schema(example = 50)]
+    pub batch_size: Option<u16>,
+}
+
+/// The status of a webhook event replay job.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, ToSchema, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookEventReplayStatus {
+    /// The replay job has not processed any events yet.
+    Pending,
+    /// The replay job is redelivering the events.
+    InProgress,
+    /// All the events of the replay job were processed.
+    Completed,
+    /// The replay job was stopped before all the events were processed.
+    Failed,
+}
+
+/// The response body for a webhook event replay job.
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct WebhookEventReplayResponse {
+    /// The identifier for the replay job.
+    #[schema(example = "whr_018e31720d1b7a2b82677d3032cab959")]
+    pub replay_id: String,
+
+    /// The identifier for the Merchant Account.
+    #[schema(max_length = 64, example = "y3oqhf46pyzuxjbcn2giaqnb44", value_type = String)]
+    pub merchant_id: common_utils::id_type::MerchantId,
+
+    /// The identifier for the Business Profile whose events are replayed.
+    #[schema(max_length = 64, value_type = String, example = "SqB0zwDGR5wHppWf0bx7GKr1f2")]
+    pub profile_id: common_utils::id_type::ProfileId,
+
+    /// The status of the replay job.
+    pub status: WebhookEventReplayStatus,
+
+    /// Events created after this time are replayed.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_after: PrimitiveDateTime,
+
+    /// Events created before this time are replayed.
+    #[schema(example = "2022-09-11T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_before: PrimitiveDateTime,
+
+    /// The event types which are replayed. All event types are replayed if empty.
+    pub event_types: HashSet<EventType>,
+
+    /// The overall delivery status of the events which are replayed.
+    pub is_delivered: Option<bool>,
+
+    /// The number of events in the time range with the specified event types.
+    pub total_count: i64,
+
+    /// The number of events which were processed.
+    pub processed_count: i64,
+
+    /// The number of events for which a new delivery attempt was made.
+    pub replayed_count: i64,
+
+    /// The number of events which were skipped since their delivery status did not match.
+    pub skipped_count: i64,
+
+    /// The number of events which could not be replayed.
+    pub failed_count: i64,
+
+    /// Time at which the replay job was created.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+}
+
+impl common_utils::events::ApiEventMetric for WebhookEventReplayResponse {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Events {
+            merchant_id: self.merchant_id.clone(),
+        })
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct WebhookEventReplayRequestInternal {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub request: WebhookEventReplayRequest,
+}
+
+impl common_utils::events::ApiEventMetric for WebhookEventReplayRequestInternal {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Events {
+            merchant_id: self.merchant_id.clone(),
+        })
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct WebhookEventReplayRetrieveRequestInternal {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub replay_id: String,
+}
+
+impl common_utils::events::ApiEventMetric for WebhookEventReplayRetrieveRequestInternal {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Events {
+            merchant_id: self.merchant_id.clone(),
+        })
+    }
+}
@@ -6,6 +6,8 @@ use utoipa::ToSchema;
 #[cfg(feature = "payouts")]
 use crate::payouts;
 use crate::{disputes, enums as api_enums, mandates, payments, refunds};
+#[cfg(feature = "v1")]
+use crate::{payment_methods, subscriptions};
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Copy)]
 #[serde(rename_all = "snake_case")]
@@ -346,6 +348,10 @@ pub enum OutgoingWebhookContent {
     #[cfg(feature = "payouts")]
     #[schema(value_type = PayoutCreateResponse, title = "PayoutCreateResponse")]
     PayoutDetails(Box<payouts::PayoutCreateResponse>),
+    #[schema(value_type = PaymentMethodResponse, title = "PaymentMethodResponse")]
+    PaymentMethodDetails(Box<payment_methods::PaymentMethodResponse>),
+    #[schema(value_type = SubscriptionResponse, title = "SubscriptionResponse")]
+    SubscriptionDetails(Box<subscriptions::SubscriptionResponse>),
 }
 
 #[derive(Debug, Clone, Serialize, ToSchema)]
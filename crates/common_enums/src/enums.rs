@@ -153,6 +153,7 @@ pub enum AttemptStatus {
     PaymentMethodAwaited,
     ConfirmationAwaited,
     DeviceDataCollectionPending,
+    PartiallyAuthorized,
 }
 
 impl AttemptStatus {
@@ -181,7 +182,8 @@ impl AttemptStatus {
             | Self::Pending
             | Self::PaymentMethodAwaited
             | Self::ConfirmationAwaited
-            | Self::DeviceDataCollectionPending => false,
+            | Self::DeviceDataCollectionPending
+            | Self::PartiallyAuthorized => false,
         }
     }
 }
@@ -391,6 +393,56 @@ pub enum BlocklistDataKind {
     ExtendedCardBin,
 }
 
+/// The status of a blocklist entry, entries are blocked only while they are active
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    PartialEq,
+    Eq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+    Hash,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum BlocklistEntryStatus {
+    /// The entry is enforced on payments
+    #[default]
+    Active,
+    /// The expiry of the entry has elapsed, and it is no longer enforced on payments
+    Expired,
+}
+
+/// How often an analytics digest is sent to the subscribed users
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+    Hash,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AnalyticsDigestFrequency {
+    /// The digest covering the previous day is sent every day at midnight UTC
+    Daily,
+    /// The digest covering the previous week is sent every Monday at midnight UTC
+    Weekly,
+}
+
 /// Default value if not passed is set to 'automatic' which results in Auth and Capture in one single API request. Pass 'manual' or 'manual_multiple' in case you want do a separate Auth and Capture by first authorizing and placing a hold on your customer's funds so that you can use the Payments/Capture endpoint later to capture the authorized amount. Pass 'manual' if you want to only capture the amount later once or 'manual_multiple' if you want to capture the funds multiple times later. Both 'manual' and 'manual_multiple' are only supported by a specific list of processors
 #[derive(
     Clone,
@@ -1413,6 +1465,8 @@ pub enum EventClass {
     Mandates,
     #[cfg(feature = "payouts")]
     Payouts,
+    PaymentMethods,
+    Subscriptions,
 }
 
 impl EventClass {
@@ -1449,6 +1503,17 @@ impl EventClass {
                 EventType::PayoutExpired,
                 EventType::PayoutReversed,
             ]),
+            Self::PaymentMethods => HashSet::from([
+                EventType::PaymentMethodUpdated,
+                EventType::PaymentMethodClosed,
+            ]),
+            Self::Subscriptions => HashSet::from([
+                EventType::SubscriptionCreated,
+                EventType::SubscriptionRenewed,
+                EventType::SubscriptionUpdated,
+                EventType::SubscriptionPaymentFailed,
+                EventType::SubscriptionCancelled,
+            ]),
         }
     }
 }
@@ -1505,6 +1570,20 @@ pub enum EventType {
     PayoutExpired,
     #[cfg(feature = "payouts")]
     PayoutReversed,
+    /// The card details of a stored payment method were updated by the card account updater
+    PaymentMethodUpdated,
+    /// The account backing a stored payment method was reported closed by the card account updater
+    PaymentMethodClosed,
+    /// A subscription was created
+    SubscriptionCreated,
+    /// The invoice for a new billing period of a subscription was paid
+    SubscriptionRenewed,
+    /// The plan of a subscription was changed
+    SubscriptionUpdated,
+    /// The invoice for a billing period of a subscription could not be charged
+    SubscriptionPaymentFailed,
+    /// A subscription was cancelled
+    SubscriptionCancelled,
 }
 
 #[derive(
@@ -1746,7 +1825,9 @@ impl From<AttemptStatus> for PaymentMethodStatus {
             | AttemptStatus::PartialChargedAndChargeable
             | AttemptStatus::ConfirmationAwaited
             | AttemptStatus::DeviceDataCollectionPending => Self::Inactive,
-            AttemptStatus::Charged | AttemptStatus::Authorized => Self::Active,
+            AttemptStatus::Charged
+            | AttemptStatus::Authorized
+            | AttemptStatus::PartiallyAuthorized => Self::Active,
         }
     }
 }
@@ -2275,6 +2356,148 @@ pub enum MandateStatus {
     Revoked,
 }
 
+/// The unit of time in which the billing period of a subscription plan is specified
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    ToSchema,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum SubscriptionInterval {
+    Day,
+    Week,
+    #[default]
+    Month,
+    Year,
+}
+
+/// The status of a subscription
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    ToSchema,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum SubscriptionStatus {
+    /// The subscription is in its trial period, and is charged when the trial ends
+    Trialing,
+    /// The invoice for the current billing period of the subscription is paid
+    #[default]
+    Active,
+    /// The invoice for the current billing period of the subscription could not be charged, and
+    /// the charge is being retried
+    PastDue,
+    /// The subscription was cancelled, or the invoice for a billing period could not be charged
+    /// after all the retries
+    Cancelled,
+}
+
+/// The status of an invoice of a subscription
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    ToSchema,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum SubscriptionInvoiceStatus {
+    /// The invoice is yet to be paid
+    #[default]
+    Open,
+    /// The invoice was paid
+    Paid,
+    /// The invoice could not be charged
+    Failed,
+    /// The invoice was voided, since the subscription was cancelled before it was paid
+    Void,
+}
+
+/// The reason for which an invoice of a subscription was generated
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    ToSchema,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum SubscriptionBillingReason {
+    /// The invoice for the first billing period of the subscription
+    #[default]
+    SubscriptionCreate,
+    /// The invoice for a renewal of the subscription
+    SubscriptionCycle,
+    /// The invoice for the proration of a plan change of the subscription
+    SubscriptionUpdate,
+}
+
+/// Determines whether a plan change in the middle of a billing period of a subscription is
+/// prorated
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    ToSchema,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ProrationBehavior {
+    /// The price difference for the remaining time of the billing period is charged immediately
+    /// for an upgrade, or credited to the next invoice for a downgrade
+    #[default]
+    CreateProrations,
+    /// The new plan is charged from the next billing period
+    None,
+}
+
 /// Indicates the card network.
 #[derive(
     Clone,
@@ -6855,6 +7078,135 @@ pub enum PayoutStatus {
     RequiresPayoutMethodData,
     RequiresFulfillment,
     RequiresVendorAccountCreation,
+    /// The payout is created with the connector, and is fulfilled at the scheduled time
+    Scheduled,
+}
+
+/// The aggregate status of a batch of payouts
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    ToSchema,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PayoutBatchStatus {
+    /// The payouts in the batch are yet to be created
+    #[default]
+    Pending,
+    /// The payouts in the batch are being created
+    Processing,
+    /// The payouts for all the rows in the batch were created
+    Completed,
+    /// The payouts for some of the rows in the batch could not be created
+    PartiallyCompleted,
+    /// The payouts for none of the rows in the batch could be created
+    Failed,
+}
+
+/// The status of a single row of a batch of payouts
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    ToSchema,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PayoutBatchItemStatus {
+    /// The payout for the row is yet to be created
+    #[default]
+    Pending,
+    /// The payout for the row was created, and has not failed
+    Succeeded,
+    /// The row is invalid, or the payout for the row could not be created or has failed
+    Failed,
+}
+
+/// The aggregate status of a payment method migration job
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    ToSchema,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PaymentMethodMigrationJobStatus {
+    /// The rows of the job were validated, and the job is awaiting execution
+    #[default]
+    Validated,
+    /// The payment methods of the valid rows are yet to be migrated
+    Pending,
+    /// The payment methods of the valid rows are being migrated
+    Processing,
+    /// The payment methods of all the valid rows were migrated
+    Completed,
+    /// The payment methods of some of the valid rows could not be migrated
+    PartiallyCompleted,
+    /// The job has no valid rows, or none of the payment methods of the valid rows could be
+    /// migrated
+    Failed,
+}
+
+/// The status of a single row of a payment method migration job
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    ToSchema,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PaymentMethodMigrationJobItemStatus {
+    /// The row passed validation, and its payment method is yet to be migrated
+    #[default]
+    Valid,
+    /// The row failed validation, and is not migrated
+    Invalid,
+    /// The payment method of the row is being migrated
+    Migrating,
+    /// The payment method of the row was migrated
+    Migrated,
+    /// The payment method of the row could not be migrated
+    Failed,
 }
 
 /// The payout_type of the payout request is a mandatory field for confirming the payouts. It should be specified in the Create request. If not provided, it must be updated in the Payout Update request before it can be confirmed.
@@ -7325,6 +7677,7 @@ pub enum TransactionStatus {
     strum::Display,
     strum::EnumString,
     strum::EnumIter,
+    ToSchema,
 )]
 #[router_derive::diesel_enum(storage_type = "text")]
 #[serde(rename_all = "snake_case")]
@@ -7671,6 +8024,7 @@ pub enum UserAuthType {
     MagicLink,
     #[default]
     Password,
+    Passkey,
 }
 
 #[derive(
@@ -8030,6 +8384,15 @@ impl ErrorCategory {
             | Self::FrmDecline => false,
         }
     }
+
+    pub fn is_hard_decline(self) -> bool {
+        match self {
+            Self::IssueWithPaymentMethod
+            | Self::ProcessorDeclineIncorrectData
+            | Self::FrmDecline => true,
+            Self::ProcessorDowntime | Self::ProcessorDeclineUnauthorized => false,
+        }
+    }
 }
 
 #[derive(
@@ -8300,6 +8663,15 @@ pub enum ProcessTrackerRunner {
     AttachPayoutAccountWorkflow,
     PaymentMethodStatusUpdateWorkflow,
     PassiveRecoveryWorkflow,
+    BlocklistEntryExpiryWorkflow,
+    CardAccountUpdaterWorkflow,
+    PayoutBatchWorkflow,
+    ScheduledPayoutWorkflow,
+    SubscriptionBillingWorkflow,
+    PaymentMethodMigrationWorkflow,
+    WebhookEventReplayWorkflow,
+    ReportGenerationWorkflow,
+    AnalyticsDigestWorkflow,
 }
 
 #[derive(Debug)]
@@ -8342,3 +8714,32 @@ pub enum TokenDataType {
     /// Fetch network token for the given payment method
     NetworkToken,
 }
+
+/// The rounding applied to an amount converted from one currency to another, when it has more
+/// decimal places than the minor unit of the target currency
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum RoundingMode {
+    /// Round to the nearest value, and to the nearest even value when halfway between two values
+    #[default]
+    HalfEven,
+    /// Round to the nearest value, and away from zero when halfway between two values
+    HalfUp,
+    /// Round towards negative infinity
+    Floor,
+    /// Round towards positive infinity
+    Ceil,
+}
@@ -2090,7 +2090,7 @@ impl From<AttemptStatus> for IntentStatus {
             AttemptStatus::ConfirmationAwaited => Self::RequiresConfirmation,
             AttemptStatus::PaymentMethodAwaited => Self::RequiresPaymentMethod,
 
-            AttemptStatus::Authorized => Self::RequiresCapture,
+            AttemptStatus::Authorized | AttemptStatus::PartiallyAuthorized => Self::RequiresCapture,
             AttemptStatus::AuthenticationPending | AttemptStatus::DeviceDataCollectionPending => {
                 Self::RequiresCustomerAction
             }
@@ -254,3 +254,106 @@ pub struct XenditMultipleSplitResponse {
     pub routes: Vec<XenditSplitRoute>,
 }
 impl_to_sql_from_sql_json!(XenditMultipleSplitResponse);
+
+/// Level 2 / Level 3 data sent to the connector for commercial card payments
+#[derive(
+    Serialize, Deserialize, Debug, Clone, PartialEq, Eq, FromSqlRow, AsExpression, ToSchema,
+)]
+#[diesel(sql_type = Jsonb)]
+#[serde(deny_unknown_fields)]
+pub struct L2L3Data {
+    /// Reference of the customer for this order, usually the purchase order number
+    #[schema(example = "PO-12345")]
+    pub customer_reference: Option<String>,
+    /// Total tax amount applied on the order
+    #[schema(value_type = Option<i64>, example = 650)]
+    pub tax_amount: Option<MinorUnit>,
+    /// Indicates if the order is exempt from tax
+    pub tax_exempt: Option<bool>,
+    /// Total discount amount applied on the order
+    #[schema(value_type = Option<i64>, example = 200)]
+    pub discount_amount: Option<MinorUnit>,
+    /// Total duty amount applied on the order
+    #[schema(value_type = Option<i64>, example = 0)]
+    pub duty_amount: Option<MinorUnit>,
+    /// Freight or shipping amount of the order
+    #[schema(value_type = Option<i64>, example = 1000)]
+    pub freight_amount: Option<MinorUnit>,
+    /// Postal code of the location from which the goods are shipped
+    #[schema(example = "94105")]
+    pub ship_from_postal_code: Option<String>,
+    /// Line items of the order
+    pub line_items: Option<Vec<L2L3LineItem>>,
+}
+impl_to_sql_from_sql_json!(L2L3Data);
+
+impl L2L3Data {
+    /// Validates the line items of the order
+    pub fn validate(&self) -> Result<(), errors::ValidationError> {
+        self.line_items.iter().flatten().try_for_each(|line_item| {
+            if line_item.quantity == 0 {
+                Err(errors::ValidationError::InvalidValue {
+                    message: "l2_l3_data.line_items.quantity must be greater than 0".to_string(),
+                })
+            } else {
+                line_item.get_total_amount().map(|_| ())
+            }
+        })
+    }
+}
+
+/// Line item of an order, sent to the connector as part of Level 3 data
+#[derive(
+    Serialize, Deserialize, Debug, Clone, PartialEq, Eq, FromSqlRow, AsExpression, ToSchema,
+)]
+#[diesel(sql_type = Jsonb)]
+#[serde(deny_unknown_fields)]
+pub struct L2L3LineItem {
+    /// Name of the product
+    #[schema(example = "Laptop stand")]
+    pub product_name: String,
+    /// Merchant's identifier for the product, such as the SKU
+    #[schema(example = "SKU-1234")]
+    pub product_code: Option<String>,
+    /// Commodity code of the product, as per the classification used by the card network
+    #[schema(example = "43211500")]
+    pub commodity_code: Option<String>,
+    /// Description of the product
+    pub description: Option<String>,
+    /// Quantity of the product
+    #[schema(example = 2)]
+    pub quantity: u16,
+    /// Unit of measure for the quantity
+    #[schema(example = "EA")]
+    pub unit_of_measure: Option<String>,
+    /// Price of a single unit of the product
+    #[schema(value_type = i64, example = 2500)]
+    pub unit_amount: MinorUnit,
+    /// Discount amount applied on this line item
+    #[schema(value_type = Option<i64>, example = 100)]
+    pub discount_amount: Option<MinorUnit>,
+    /// Tax amount applied on this line item
+    #[schema(value_type = Option<i64>, example = 325)]
+    pub tax_amount: Option<MinorUnit>,
+}
+impl_to_sql_from_sql_json!(L2L3LineItem);
+
+impl L2L3LineItem {
+    /// Total amount of the line item, excluding tax
+    pub fn get_total_amount(&self) -> Result<MinorUnit, errors::ValidationError> {
+        self.unit_amount
+            .get_amount_as_i64()
+            .checked_mul(i64::from(self.quantity))
+            .and_then(|amount| {
+                amount.checked_sub(
+                    self.discount_amount
+                        .unwrap_or(MinorUnit::zero())
+                        .get_amount_as_i64(),
+                )
+            })
+            .map(MinorUnit::new)
+            .ok_or(errors::ValidationError::InvalidValue {
+                message: "l2_l3_data.line_items total amount is out of range".to_string(),
+            })
+    }
+}
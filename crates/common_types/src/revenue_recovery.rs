@@ -0,0 +1,105 @@
+//! Revenue recovery related types
+
+use common_utils::{errors, impl_to_sql_from_sql_json};
+use diesel::{sql_types::Jsonb, AsExpression, FromSqlRow};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(
+    Serialize, Deserialize, Debug, Clone, PartialEq, Eq, FromSqlRow, AsExpression, ToSchema,
+)]
+#[diesel(sql_type = Jsonb)]
+#[serde(deny_unknown_fields)]
+/// Retry policy used for revenue recovery payments of a profile
+pub struct RevenueRecoveryRetryPolicy {
+    /// Maximum number of retries attempted for an invoice before it is marked as a terminal failure
+    #[schema(example = 12)]
+    pub max_retry_attempts: u16,
+    /// Delay in seconds before the first retry is attempted
+    #[schema(example = 60)]
+    pub start_after_seconds: u32,
+    /// Spacing between retries, applied in order. Once all the steps are used up, the interval of
+    /// the last step is used for the remaining retries
+    pub retry_intervals: Vec<RetryIntervalStep>,
+    /// Windows of the day (in UTC) during which retries are not attempted. A retry falling in a
+    /// blackout window is moved to the end of the window
+    #[serde(default)]
+    pub blackout_windows: Vec<RetryBlackoutWindow>,
+}
+
+impl_to_sql_from_sql_json!(RevenueRecoveryRetryPolicy);
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+#[serde(deny_unknown_fields)]
+/// A step of the retry spacing curve
+pub struct RetryIntervalStep {
+    /// Interval in seconds between two retries of this step
+    #[schema(example = 10800)]
+    pub interval_in_seconds: u32,
+    /// Number of retries for which this interval applies
+    #[schema(example = 2)]
+    pub count: u16,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+#[serde(deny_unknown_fields)]
+/// A daily window (in UTC) during which revenue recovery retries are not attempted
+pub struct RetryBlackoutWindow {
+    /// Hour of the day at which the window starts (0-23)
+    #[schema(example = 22)]
+    pub start_hour: u8,
+    /// Hour of the day at which the window ends, exclusive (0-23). The window wraps around
+    /// midnight if `end_hour` is less than `start_hour`
+    #[schema(example = 6)]
+    pub end_hour: u8,
+}
+
+impl RetryBlackoutWindow {
+    /// Checks whether the given hour of the day falls in the blackout window
+    pub fn contains_hour(&self, hour: u8) -> bool {
+        if self.start_hour < self.end_hour {
+            hour >= self.start_hour && hour < self.end_hour
+        } else {
+            hour >= self.start_hour || hour < self.end_hour
+        }
+    }
+}
+
+impl RevenueRecoveryRetryPolicy {
+    /// Validates the retry policy
+    pub fn validate(&self) -> Result<(), errors::ValidationError> {
+        if self.max_retry_attempts == 0 {
+            return Err(errors::ValidationError::InvalidValue {
+                message: "max_retry_attempts must be greater than 0".to_string(),
+            });
+        }
+
+        if self.retry_intervals.is_empty() {
+            return Err(errors::ValidationError::MissingRequiredField {
+                field_name: "retry_intervals".to_string(),
+            });
+        }
+
+        if self
+            .retry_intervals
+            .iter()
+            .any(|step| step.interval_in_seconds == 0 || step.count == 0)
+        {
+            return Err(errors::ValidationError::InvalidValue {
+                message: "interval_in_seconds and count of a retry interval must be greater than 0"
+                    .to_string(),
+            });
+        }
+
+        if self.blackout_windows.iter().any(|window| {
+            window.start_hour > 23 || window.end_hour > 23 || window.start_hour == window.end_hour
+        }) {
+            return Err(errors::ValidationError::InvalidValue {
+                message: "blackout window hours must be between 0 and 23 and must not be equal"
+                    .to_string(),
+            });
+        }
+
+        Ok(())
+    }
+}
@@ -112,6 +112,18 @@ pub enum ApiEventsType {
     Poll {
         poll_id: String,
     },
+    PayoutBatch {
+        batch_id: String,
+    },
+    PaymentMethodMigrationJob {
+        job_id: String,
+    },
+    SubscriptionPlan {
+        plan_id: String,
+    },
+    Subscription {
+        subscription_id: String,
+    },
     Analytics,
     #[cfg(feature = "v2")]
     ClientSecret {
@@ -158,6 +170,12 @@ impl<Q: ApiEventMetric, E> ApiEventMetric for Result<Q, E> {
     }
 }
 
+impl<Q: ApiEventMetric> ApiEventMetric for Option<Q> {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        self.as_ref().and_then(ApiEventMetric::get_api_event_type)
+    }
+}
+
 // TODO: Ideally all these types should be replaced by newtype responses
 impl<T> ApiEventMetric for Vec<T> {
     fn get_api_event_type(&self) -> Option<ApiEventsType> {
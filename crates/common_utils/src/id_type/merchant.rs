@@ -226,4 +226,9 @@ impl MerchantId {
             self.get_string_repr()
         )
     }
+
+    /// get_api_rate_limit_key
+    pub fn get_api_rate_limit_key(&self) -> String {
+        format!("api_rate_limit_{}", self.get_string_repr())
+    }
 }
@@ -1418,7 +1418,7 @@ where
 impl_enum_str!(
     tag_delimiter = ":",
     /// CreatedBy conveys the information about the creator (identifier) as well as the origin or
-    /// trigger (Api, Jwt) of the record.
+    /// trigger (Api, ApiKey, Jwt) of the record.
     #[derive(Eq, PartialEq, Debug, Clone)]
     pub enum CreatedBy {
         /// Api variant
@@ -1426,6 +1426,11 @@ impl_enum_str!(
             /// merchant id of creator.
             merchant_id: String,
         },
+        /// ApiKey variant
+        ApiKey {
+            /// id of the API key used by the creator.
+            key_id: String,
+        },
         /// Jwt variant
         Jwt {
             /// user id of creator.
@@ -1,5 +1,6 @@
-use common_enums::Currency;
-use rust_decimal::Decimal;
+use common_enums::{Currency, RoundingMode};
+use common_utils::types::MinorUnit;
+use rust_decimal::{Decimal, RoundingStrategy};
 use rusty_money::Money;
 
 use crate::{
@@ -7,6 +8,12 @@ use crate::{
     types::{currency_match, ExchangeRates},
 };
 
+/// The number of decimal places of a rate expressed in basis points
+const BASIS_POINTS_SCALE: u32 = 4;
+
+/// The maximum markup in basis points, which doubles the exchange rate
+pub const MAX_MARKUP_BPS: u16 = 10_000;
+
 pub fn convert(
     ex_rates: &ExchangeRates,
     from_currency: Currency,
@@ -26,6 +33,80 @@ pub fn convert(
     }
 }
 
+/// Convert an amount in the minor unit of from_currency to the minor unit of to_currency, rounded
+/// to the precision of to_currency using the given rounding mode.
+///
+/// The markup, in basis points, is the FX fee charged on top of the exchange rate, and is applied
+/// before the amount is rounded. It cannot exceed `MAX_MARKUP_BPS`, and no markup is applied when
+/// both the currencies are the same.
+pub fn convert_to_minor_unit(
+    ex_rates: &ExchangeRates,
+    from_currency: Currency,
+    to_currency: Currency,
+    amount: MinorUnit,
+    rounding_mode: RoundingMode,
+    markup_bps: Option<u16>,
+) -> Result<MinorUnit, CurrencyConversionError> {
+    if from_currency == to_currency {
+        return Ok(amount);
+    }
+
+    let rate = ex_rates.cross_rate(from_currency, to_currency)?;
+    let rate = match markup_bps {
+        Some(markup_bps) => apply_markup(rate, markup_bps)?,
+        None => rate,
+    };
+    let converted_amount = to_major_unit(amount.get_amount_as_i64(), from_currency)
+        .checked_mul(rate)
+        .ok_or(CurrencyConversionError::DecimalMultiplicationFailed)?;
+
+    to_minor_unit(converted_amount, to_currency, rounding_mode)
+}
+
+fn apply_markup(rate: Decimal, markup_bps: u16) -> Result<Decimal, CurrencyConversionError> {
+    if markup_bps > MAX_MARKUP_BPS {
+        return Err(CurrencyConversionError::MarkupOutOfRange(markup_bps));
+    }
+
+    let markup = Decimal::new(i64::from(markup_bps), BASIS_POINTS_SCALE);
+    rate.checked_mul(Decimal::ONE + markup)
+        .ok_or(CurrencyConversionError::DecimalMultiplicationFailed)
+}
+
+/// Convert an amount in the minor unit of the currency to its major unit, without any rounding.
+pub fn to_major_unit(amount: i64, currency: Currency) -> Decimal {
+    Decimal::new(
+        amount,
+        u32::from(currency.number_of_digits_after_decimal_point()),
+    )
+}
+
+fn to_minor_unit(
+    amount: Decimal,
+    currency: Currency,
+    rounding_mode: RoundingMode,
+) -> Result<MinorUnit, CurrencyConversionError> {
+    let decimal_places = u32::from(currency.number_of_digits_after_decimal_point());
+    let mut rounded_amount =
+        amount.round_dp_with_strategy(decimal_places, get_rounding_strategy(rounding_mode));
+    // Rounding does not add trailing zeroes, the mantissa is in the minor unit only once the scale
+    // matches the number of decimal places of the currency
+    rounded_amount.rescale(decimal_places);
+
+    i64::try_from(rounded_amount.mantissa())
+        .map(MinorUnit::new)
+        .map_err(|_| CurrencyConversionError::AmountOutOfRange)
+}
+
+fn get_rounding_strategy(rounding_mode: RoundingMode) -> RoundingStrategy {
+    match rounding_mode {
+        RoundingMode::HalfEven => RoundingStrategy::MidpointNearestEven,
+        RoundingMode::HalfUp => RoundingStrategy::MidpointAwayFromZero,
+        RoundingMode::Floor => RoundingStrategy::ToNegativeInfinity,
+        RoundingMode::Ceil => RoundingStrategy::ToPositiveInfinity,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     #![allow(clippy::expect_used, clippy::print_stdout)]
@@ -98,4 +179,159 @@ mod tests {
             amount, convert_from, convert_to, res
         );
     }
+
+    fn get_sample_rates() -> ExchangeRates {
+        use super::*;
+        let mut conversion: HashMap<Currency, CurrencyFactors> = HashMap::new();
+        conversion.insert(
+            Currency::USD,
+            CurrencyFactors::new(Decimal::new(1, 0), Decimal::new(1, 0)),
+        );
+        conversion.insert(
+            Currency::JPY,
+            CurrencyFactors::new(Decimal::new(15025, 2), Decimal::new(665557, 8)),
+        );
+        conversion.insert(
+            Currency::KWD,
+            CurrencyFactors::new(Decimal::new(30745, 5), Decimal::new(325256, 5)),
+        );
+        ExchangeRates::new(Currency::USD, conversion)
+    }
+
+    #[test]
+    fn base_to_zero_decimal_currency_minor_unit_conversion() {
+        use super::*;
+        let sample_rates = get_sample_rates();
+        // 10.05 USD is 1510.0125 JPY
+        let amount = MinorUnit::new(1005);
+
+        let half_even = convert_to_minor_unit(
+            &sample_rates,
+            Currency::USD,
+            Currency::JPY,
+            amount,
+            RoundingMode::HalfEven,
+            None,
+        )
+        .expect("converted_currency");
+        let ceil = convert_to_minor_unit(
+            &sample_rates,
+            Currency::USD,
+            Currency::JPY,
+            amount,
+            RoundingMode::Ceil,
+            None,
+        )
+        .expect("converted_currency");
+
+        assert_eq!(half_even, MinorUnit::new(1510));
+        assert_eq!(ceil, MinorUnit::new(1511));
+    }
+
+    #[test]
+    fn base_to_three_decimal_currency_minor_unit_conversion() {
+        use super::*;
+        let sample_rates = get_sample_rates();
+        // 10.00 USD is 3.0745 KWD, which is halfway between two minor units
+        let amount = MinorUnit::new(1000);
+
+        let converted_amounts = [
+            RoundingMode::HalfEven,
+            RoundingMode::HalfUp,
+            RoundingMode::Floor,
+            RoundingMode::Ceil,
+        ]
+        .map(|rounding_mode| {
+            convert_to_minor_unit(
+                &sample_rates,
+                Currency::USD,
+                Currency::KWD,
+                amount,
+                rounding_mode,
+                None,
+            )
+            .expect("converted_currency")
+        });
+
+        assert_eq!(
+            converted_amounts,
+            [
+                MinorUnit::new(3074),
+                MinorUnit::new(3075),
+                MinorUnit::new(3074),
+                MinorUnit::new(3075),
+            ]
+        );
+    }
+
+    #[test]
+    fn minor_unit_conversion_with_markup() {
+        use super::*;
+        let sample_rates = get_sample_rates();
+        // 100 JPY is 0.665557 USD, and 0.67221257 USD with a markup of 100 bps
+        let amount = MinorUnit::new(100);
+
+        let converted_amount = convert_to_minor_unit(
+            &sample_rates,
+            Currency::JPY,
+            Currency::USD,
+            amount,
+            RoundingMode::HalfEven,
+            Some(100),
+        )
+        .expect("converted_currency");
+        let same_currency_amount = convert_to_minor_unit(
+            &sample_rates,
+            Currency::USD,
+            Currency::USD,
+            amount,
+            RoundingMode::HalfEven,
+            Some(100),
+        )
+        .expect("converted_currency");
+
+        assert_eq!(converted_amount, MinorUnit::new(67));
+        assert_eq!(same_currency_amount, amount);
+    }
+
+    #[test]
+    fn minor_unit_to_major_unit_conversion() {
+        use super::*;
+
+        assert_eq!(to_major_unit(1000, Currency::USD).to_string(), "10.00");
+        assert_eq!(to_major_unit(1510, Currency::JPY).to_string(), "1510");
+        assert_eq!(to_major_unit(3075, Currency::KWD).to_string(), "3.075");
+    }
+
+    #[test]
+    fn minor_unit_conversion_with_markup_out_of_range() {
+        use super::*;
+        let sample_rates = get_sample_rates();
+        let amount = MinorUnit::new(100);
+
+        let max_markup_amount = convert_to_minor_unit(
+            &sample_rates,
+            Currency::USD,
+            Currency::JPY,
+            amount,
+            RoundingMode::HalfEven,
+            Some(MAX_MARKUP_BPS),
+        )
+        .expect("converted_currency");
+        let out_of_range_markup_error = convert_to_minor_unit(
+            &sample_rates,
+            Currency::USD,
+            Currency::JPY,
+            amount,
+            RoundingMode::HalfEven,
+            Some(MAX_MARKUP_BPS + 1),
+        );
+
+        // 1.00 USD is 150.25 JPY, and 300.50 JPY with the markup doubling the rate
+        assert_eq!(max_markup_amount, MinorUnit::new(300));
+        assert!(matches!(
+            out_of_range_markup_error,
+            Err(CurrencyConversionError::MarkupOutOfRange(10_001))
+        ));
+    }
 }
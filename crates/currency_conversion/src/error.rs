@@ -5,4 +5,8 @@ pub enum CurrencyConversionError {
     DecimalMultiplicationFailed,
     #[error("Currency not supported: '{0}'")]
     ConversionNotSupported(String),
+    #[error("Converted amount is out of range")]
+    AmountOutOfRange,
+    #[error("Markup of {0} basis points is out of range")]
+    MarkupOutOfRange(u16),
 }
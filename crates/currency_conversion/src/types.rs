@@ -20,6 +20,18 @@ pub struct CurrencyFactors {
     pub to_factor: Decimal,
     /// The factor that will be multiplied to provide for the base output
     pub from_factor: Decimal,
+    /// The provider which supplied the rate
+    #[serde(default)]
+    pub source: Option<RateSource>,
+}
+
+/// The origin of an exchange rate
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct RateSource {
+    /// The name of the forex provider which supplied the rate
+    pub provider: String,
+    /// The UNIX timestamp (in seconds) at which the provider published the rate
+    pub timestamp: i64,
 }
 
 impl CurrencyFactors {
@@ -27,6 +39,7 @@ impl CurrencyFactors {
         Self {
             to_factor,
             from_factor,
+            source: None,
         }
     }
 }
@@ -39,6 +52,14 @@ impl ExchangeRates {
         }
     }
 
+    /// Record the provider which supplied all the rates
+    pub fn with_source(mut self, source: RateSource) -> Self {
+        self.conversion
+            .values_mut()
+            .for_each(|factors| factors.source = Some(source.clone()));
+        self
+    }
+
     /// The flow here is from_currency -> base_currency -> to_currency
     /// from to_currency -> base currency
     pub fn forward_conversion(
@@ -73,6 +94,25 @@ impl ExchangeRates {
         amt.checked_mul(to_factor)
             .ok_or(CurrencyConversionError::DecimalMultiplicationFailed)
     }
+
+    /// The amount of to_currency that a single unit of from_currency converts to, derived from
+    /// the rates of both the currencies against the base currency
+    pub fn cross_rate(
+        &self,
+        from_currency: Currency,
+        to_currency: Currency,
+    ) -> Result<Decimal, CurrencyConversionError> {
+        if from_currency == to_currency {
+            Ok(Decimal::ONE)
+        } else if to_currency == self.base_currency {
+            self.forward_conversion(Decimal::ONE, from_currency)
+        } else if from_currency == self.base_currency {
+            self.backward_conversion(Decimal::ONE, to_currency)
+        } else {
+            let base_rate = self.forward_conversion(Decimal::ONE, from_currency)?;
+            self.backward_conversion(base_rate, to_currency)
+        }
+    }
 }
 
 pub fn currency_match(currency: Currency) -> &'static iso::Currency {
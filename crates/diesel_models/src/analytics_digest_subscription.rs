@@ -0,0 +1,69 @@
+use common_utils::id_type;
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::analytics_digest_subscription};
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = analytics_digest_subscription)]
+pub struct AnalyticsDigestSubscriptionNew {
+    pub subscription_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub org_id: id_type::OrganizationId,
+    pub role_id: String,
+    pub frequency: storage_enums::AnalyticsDigestFrequency,
+    pub created_by: String,
+    pub created_at: PrimitiveDateTime,
+    pub last_modified_at: PrimitiveDateTime,
+    pub last_sent_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable)]
+#[diesel(table_name = analytics_digest_subscription, primary_key(subscription_id), check_for_backend(diesel::pg::Pg))]
+pub struct AnalyticsDigestSubscription {
+    pub subscription_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub org_id: id_type::OrganizationId,
+    pub role_id: String,
+    pub frequency: storage_enums::AnalyticsDigestFrequency,
+    pub created_by: String,
+    pub created_at: PrimitiveDateTime,
+    pub last_modified_at: PrimitiveDateTime,
+    pub last_sent_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Debug)]
+pub enum AnalyticsDigestSubscriptionUpdate {
+    FrequencyUpdate {
+        frequency: storage_enums::AnalyticsDigestFrequency,
+    },
+    DigestSent {
+        last_sent_at: PrimitiveDateTime,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = analytics_digest_subscription)]
+pub struct AnalyticsDigestSubscriptionUpdateInternal {
+    frequency: Option<storage_enums::AnalyticsDigestFrequency>,
+    last_sent_at: Option<PrimitiveDateTime>,
+    last_modified_at: PrimitiveDateTime,
+}
+
+impl From<AnalyticsDigestSubscriptionUpdate> for AnalyticsDigestSubscriptionUpdateInternal {
+    fn from(subscription_update: AnalyticsDigestSubscriptionUpdate) -> Self {
+        let last_modified_at = common_utils::date_time::now();
+        match subscription_update {
+            AnalyticsDigestSubscriptionUpdate::FrequencyUpdate { frequency } => Self {
+                frequency: Some(frequency),
+                last_sent_at: None,
+                last_modified_at,
+            },
+            AnalyticsDigestSubscriptionUpdate::DigestSent { last_sent_at } => Self {
+                frequency: None,
+                last_sent_at: Some(last_sent_at),
+                last_modified_at,
+            },
+        }
+    }
+}
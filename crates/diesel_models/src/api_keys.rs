@@ -2,7 +2,7 @@ use diesel::{AsChangeset, AsExpression, Identifiable, Insertable, Queryable, Sel
 use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
 
-use crate::schema::api_keys;
+use crate::{enums, schema::api_keys};
 
 #[derive(
     serde::Serialize, serde::Deserialize, Debug, Clone, Identifiable, Queryable, Selectable,
@@ -18,6 +18,9 @@ pub struct ApiKey {
     pub created_at: PrimitiveDateTime,
     pub expires_at: Option<PrimitiveDateTime>,
     pub last_used: Option<PrimitiveDateTime>,
+    #[diesel(deserialize_as = super::OptionalDieselArray<enums::PermissionGroup>)]
+    pub permission_groups: Option<Vec<enums::PermissionGroup>>,
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
 }
 
 #[derive(Debug, Insertable)]
@@ -32,6 +35,8 @@ pub struct ApiKeyNew {
     pub created_at: PrimitiveDateTime,
     pub expires_at: Option<PrimitiveDateTime>,
     pub last_used: Option<PrimitiveDateTime>,
+    pub permission_groups: Option<Vec<enums::PermissionGroup>>,
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
 }
 
 #[derive(Debug)]
@@ -41,6 +46,7 @@ pub enum ApiKeyUpdate {
         description: Option<String>,
         expires_at: Option<Option<PrimitiveDateTime>>,
         last_used: Option<PrimitiveDateTime>,
+        permission_groups: Option<Vec<enums::PermissionGroup>>,
     },
     LastUsedUpdate {
         last_used: PrimitiveDateTime,
@@ -54,6 +60,7 @@ pub(crate) struct ApiKeyUpdateInternal {
     pub description: Option<String>,
     pub expires_at: Option<Option<PrimitiveDateTime>>,
     pub last_used: Option<PrimitiveDateTime>,
+    pub permission_groups: Option<Vec<enums::PermissionGroup>>,
 }
 
 impl From<ApiKeyUpdate> for ApiKeyUpdateInternal {
@@ -64,17 +71,20 @@ impl From<ApiKeyUpdate> for ApiKeyUpdateInternal {
                 description,
                 expires_at,
                 last_used,
+                permission_groups,
             } => Self {
                 name,
                 description,
                 expires_at,
                 last_used,
+                permission_groups,
             },
             ApiKeyUpdate::LastUsedUpdate { last_used } => Self {
                 last_used: Some(last_used),
                 name: None,
                 description: None,
                 expires_at: None,
+                permission_groups: None,
             },
         }
     }
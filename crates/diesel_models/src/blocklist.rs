@@ -1,4 +1,4 @@
-use diesel::{Identifiable, Insertable, Queryable, Selectable};
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
 use serde::{Deserialize, Serialize};
 
 use crate::schema::blocklist;
@@ -11,6 +11,10 @@ pub struct BlocklistNew {
     pub data_kind: common_enums::BlocklistDataKind,
     pub metadata: Option<serde_json::Value>,
     pub created_at: time::PrimitiveDateTime,
+    pub expires_at: Option<time::PrimitiveDateTime>,
+    pub reason: Option<String>,
+    pub created_by: Option<String>,
+    pub status: common_enums::BlocklistEntryStatus,
 }
 
 #[derive(
@@ -23,4 +27,29 @@ pub struct Blocklist {
     pub data_kind: common_enums::BlocklistDataKind,
     pub metadata: Option<serde_json::Value>,
     pub created_at: time::PrimitiveDateTime,
+    pub expires_at: Option<time::PrimitiveDateTime>,
+    pub reason: Option<String>,
+    pub created_by: Option<String>,
+    pub status: common_enums::BlocklistEntryStatus,
+}
+
+#[derive(Clone, Debug)]
+pub enum BlocklistUpdate {
+    StatusUpdate {
+        status: common_enums::BlocklistEntryStatus,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset)]
+#[diesel(table_name = blocklist)]
+pub struct BlocklistUpdateInternal {
+    status: common_enums::BlocklistEntryStatus,
+}
+
+impl From<BlocklistUpdate> for BlocklistUpdateInternal {
+    fn from(blocklist_update: BlocklistUpdate) -> Self {
+        match blocklist_update {
+            BlocklistUpdate::StatusUpdate { status } => Self { status },
+        }
+    }
 }
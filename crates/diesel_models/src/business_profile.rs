@@ -74,6 +74,8 @@ pub struct Profile {
     pub is_iframe_redirection_enabled: Option<bool>,
     pub is_pre_network_tokenization_enabled: Option<bool>,
     pub three_ds_decision_rule_algorithm: Option<serde_json::Value>,
+    pub previous_payment_response_hash_key: Option<String>,
+    pub previous_payment_response_hash_key_expires_at: Option<time::PrimitiveDateTime>,
 }
 
 #[cfg(feature = "v1")]
@@ -183,6 +185,9 @@ pub struct ProfileUpdateInternal {
     pub is_iframe_redirection_enabled: Option<bool>,
     pub is_pre_network_tokenization_enabled: Option<bool>,
     pub three_ds_decision_rule_algorithm: Option<serde_json::Value>,
+    /// `Some(None)` clears the previous key, for instance when a profile without a key is rotated
+    pub previous_payment_response_hash_key: Option<Option<String>>,
+    pub previous_payment_response_hash_key_expires_at: Option<Option<time::PrimitiveDateTime>>,
 }
 
 #[cfg(feature = "v1")]
@@ -234,6 +239,8 @@ impl ProfileUpdateInternal {
             is_iframe_redirection_enabled,
             is_pre_network_tokenization_enabled,
             three_ds_decision_rule_algorithm,
+            previous_payment_response_hash_key,
+            previous_payment_response_hash_key_expires_at,
         } = self;
         Profile {
             profile_id: source.profile_id,
@@ -316,6 +323,11 @@ impl ProfileUpdateInternal {
                 .or(source.is_pre_network_tokenization_enabled),
             three_ds_decision_rule_algorithm: three_ds_decision_rule_algorithm
                 .or(source.three_ds_decision_rule_algorithm),
+            previous_payment_response_hash_key: previous_payment_response_hash_key
+                .unwrap_or(source.previous_payment_response_hash_key),
+            previous_payment_response_hash_key_expires_at:
+                previous_payment_response_hash_key_expires_at
+                    .unwrap_or(source.previous_payment_response_hash_key_expires_at),
         }
     }
 }
@@ -389,6 +401,8 @@ pub struct Profile {
     pub external_vault_connector_details: Option<ExternalVaultConnectorDetails>,
     pub revenue_recovery_retry_algorithm_type: Option<common_enums::RevenueRecoveryAlgorithmType>,
     pub revenue_recovery_retry_algorithm_data: Option<RevenueRecoveryAlgorithmData>,
+    pub revenue_recovery_retry_policy:
+        Option<common_types::revenue_recovery::RevenueRecoveryRetryPolicy>,
 }
 
 impl Profile {
@@ -462,6 +476,8 @@ pub struct ProfileNew {
     pub is_iframe_redirection_enabled: Option<bool>,
     pub is_external_vault_enabled: Option<bool>,
     pub external_vault_connector_details: Option<ExternalVaultConnectorDetails>,
+    pub revenue_recovery_retry_policy:
+        Option<common_types::revenue_recovery::RevenueRecoveryRetryPolicy>,
 }
 
 #[cfg(feature = "v2")]
@@ -519,6 +535,8 @@ pub struct ProfileUpdateInternal {
     pub is_iframe_redirection_enabled: Option<bool>,
     pub is_external_vault_enabled: Option<bool>,
     pub external_vault_connector_details: Option<ExternalVaultConnectorDetails>,
+    pub revenue_recovery_retry_policy:
+        Option<common_types::revenue_recovery::RevenueRecoveryRetryPolicy>,
 }
 
 #[cfg(feature = "v2")]
@@ -573,6 +591,7 @@ impl ProfileUpdateInternal {
             is_iframe_redirection_enabled,
             is_external_vault_enabled,
             external_vault_connector_details,
+            revenue_recovery_retry_policy,
         } = self;
         Profile {
             id: source.id,
@@ -665,6 +684,8 @@ impl ProfileUpdateInternal {
             external_vault_connector_details: external_vault_connector_details
                 .or(source.external_vault_connector_details),
             three_ds_decision_rule_algorithm: None,
+            revenue_recovery_retry_policy: revenue_recovery_retry_policy
+                .or(source.revenue_recovery_retry_policy),
         }
     }
 }
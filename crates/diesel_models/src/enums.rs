@@ -80,6 +80,8 @@ pub enum EventObjectType {
     DisputeDetails,
     MandateDetails,
     PayoutDetails,
+    PaymentMethodDetails,
+    SubscriptionDetails,
 }
 
 // Refund
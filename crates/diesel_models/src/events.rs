@@ -27,6 +27,7 @@ pub struct EventNew {
     pub delivery_attempt: Option<storage_enums::WebhookDeliveryAttempt>,
     pub metadata: Option<EventMetadata>,
     pub is_overall_delivery_successful: Option<bool>,
+    pub webhook_endpoint_id: Option<String>,
 }
 
 #[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
@@ -60,6 +61,7 @@ pub struct Event {
     pub delivery_attempt: Option<storage_enums::WebhookDeliveryAttempt>,
     pub metadata: Option<EventMetadata>,
     pub is_overall_delivery_successful: Option<bool>,
+    pub webhook_endpoint_id: Option<String>,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize, AsExpression, diesel::FromSqlRow)]
@@ -102,6 +104,12 @@ pub enum EventMetadata {
         payment_method_id: String,
         mandate_id: String,
     },
+    PaymentMethod {
+        payment_method_id: String,
+    },
+    Subscription {
+        subscription_id: String,
+    },
 }
 
 common_utils::impl_to_sql_from_sql_json!(EventMetadata);
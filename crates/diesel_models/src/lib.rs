@@ -1,4 +1,5 @@
 pub mod address;
+pub mod analytics_digest_subscription;
 pub mod api_keys;
 pub mod blocklist_lookup;
 pub mod business_profile;
@@ -35,7 +36,9 @@ pub mod payment_attempt;
 pub mod payment_intent;
 pub mod payment_link;
 pub mod payment_method;
+pub mod payment_method_migration_job;
 pub mod payout_attempt;
+pub mod payout_batch;
 pub mod payouts;
 pub mod process_tracker;
 pub mod query;
@@ -44,6 +47,7 @@ pub mod relay;
 pub mod reverse_lookup;
 pub mod role;
 pub mod routing_algorithm;
+pub mod subscription;
 pub mod types;
 pub mod unified_translations;
 
@@ -58,7 +62,9 @@ pub mod schema_v2;
 pub mod user;
 pub mod user_authentication_method;
 pub mod user_key_store;
+pub mod user_passkey;
 pub mod user_role;
+pub mod webhook_endpoint;
 
 use diesel_impl::{DieselArray, OptionalDieselArray};
 
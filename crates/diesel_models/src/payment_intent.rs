@@ -154,6 +154,8 @@ pub struct PaymentIntent {
     pub processor_merchant_id: Option<common_utils::id_type::MerchantId>,
     pub created_by: Option<String>,
     pub is_iframe_redirection_enabled: Option<bool>,
+    pub enable_partial_authorization: Option<bool>,
+    pub l2_l3_data: Option<common_types::payments::L2L3Data>,
 }
 
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize, diesel::AsExpression, PartialEq)]
@@ -422,6 +424,8 @@ pub struct PaymentIntentNew {
     pub processor_merchant_id: Option<common_utils::id_type::MerchantId>,
     pub created_by: Option<String>,
     pub is_iframe_redirection_enabled: Option<bool>,
+    pub enable_partial_authorization: Option<bool>,
+    pub l2_l3_data: Option<common_types::payments::L2L3Data>,
 }
 
 #[cfg(feature = "v2")]
@@ -0,0 +1,163 @@
+use common_utils::{encryption::Encryption, id_type};
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use time::PrimitiveDateTime;
+
+use crate::{
+    enums as storage_enums,
+    schema::{payment_method_migration_job, payment_method_migration_job_item},
+};
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payment_method_migration_job)]
+pub struct PaymentMethodMigrationJobNew {
+    pub job_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub merchant_connector_id: Option<id_type::MerchantConnectorAccountId>,
+    pub status: storage_enums::PaymentMethodMigrationJobStatus,
+    pub dry_run: bool,
+    pub file_name: Option<String>,
+    pub total_count: i32,
+    pub valid_count: i32,
+    pub invalid_count: i32,
+    pub migrated_count: i32,
+    pub failed_count: i32,
+    pub created_at: PrimitiveDateTime,
+    pub last_modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable)]
+#[diesel(
+    table_name = payment_method_migration_job,
+    primary_key(job_id),
+    check_for_backend(diesel::pg::Pg)
+)]
+pub struct PaymentMethodMigrationJob {
+    pub job_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub merchant_connector_id: Option<id_type::MerchantConnectorAccountId>,
+    pub status: storage_enums::PaymentMethodMigrationJobStatus,
+    pub dry_run: bool,
+    pub file_name: Option<String>,
+    pub total_count: i32,
+    pub valid_count: i32,
+    pub invalid_count: i32,
+    pub migrated_count: i32,
+    pub failed_count: i32,
+    pub created_at: PrimitiveDateTime,
+    pub last_modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum PaymentMethodMigrationJobUpdate {
+    StatusUpdate {
+        status: storage_enums::PaymentMethodMigrationJobStatus,
+    },
+    CountsUpdate {
+        status: storage_enums::PaymentMethodMigrationJobStatus,
+        migrated_count: i32,
+        failed_count: i32,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payment_method_migration_job)]
+pub struct PaymentMethodMigrationJobUpdateInternal {
+    status: Option<storage_enums::PaymentMethodMigrationJobStatus>,
+    migrated_count: Option<i32>,
+    failed_count: Option<i32>,
+    last_modified_at: PrimitiveDateTime,
+}
+
+impl From<PaymentMethodMigrationJobUpdate> for PaymentMethodMigrationJobUpdateInternal {
+    fn from(job_update: PaymentMethodMigrationJobUpdate) -> Self {
+        let last_modified_at = common_utils::date_time::now();
+        match job_update {
+            PaymentMethodMigrationJobUpdate::StatusUpdate { status } => Self {
+                status: Some(status),
+                migrated_count: None,
+                failed_count: None,
+                last_modified_at,
+            },
+            PaymentMethodMigrationJobUpdate::CountsUpdate {
+                status,
+                migrated_count,
+                failed_count,
+            } => Self {
+                status: Some(status),
+                migrated_count: Some(migrated_count),
+                failed_count: Some(failed_count),
+                last_modified_at,
+            },
+        }
+    }
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payment_method_migration_job_item)]
+pub struct PaymentMethodMigrationJobItemNew {
+    pub job_id: String,
+    pub line_number: i32,
+    pub merchant_id: id_type::MerchantId,
+    pub status: storage_enums::PaymentMethodMigrationJobItemStatus,
+    /// The record of the row, encrypted with the key of the merchant. Absent for invalid rows.
+    pub record: Option<Encryption>,
+    pub customer_id: Option<id_type::CustomerId>,
+    pub payment_method_id: Option<String>,
+    pub error_message: Option<String>,
+    pub created_at: PrimitiveDateTime,
+    pub last_modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable)]
+#[diesel(
+    table_name = payment_method_migration_job_item,
+    primary_key(job_id, line_number),
+    check_for_backend(diesel::pg::Pg)
+)]
+pub struct PaymentMethodMigrationJobItem {
+    pub job_id: String,
+    pub line_number: i32,
+    pub merchant_id: id_type::MerchantId,
+    pub status: storage_enums::PaymentMethodMigrationJobItemStatus,
+    pub record: Option<Encryption>,
+    pub customer_id: Option<id_type::CustomerId>,
+    pub payment_method_id: Option<String>,
+    pub error_message: Option<String>,
+    pub created_at: PrimitiveDateTime,
+    pub last_modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum PaymentMethodMigrationJobItemUpdate {
+    ResultUpdate {
+        status: storage_enums::PaymentMethodMigrationJobItemStatus,
+        payment_method_id: Option<String>,
+        error_message: Option<String>,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payment_method_migration_job_item)]
+pub struct PaymentMethodMigrationJobItemUpdateInternal {
+    status: Option<storage_enums::PaymentMethodMigrationJobItemStatus>,
+    payment_method_id: Option<String>,
+    error_message: Option<String>,
+    last_modified_at: PrimitiveDateTime,
+}
+
+impl From<PaymentMethodMigrationJobItemUpdate> for PaymentMethodMigrationJobItemUpdateInternal {
+    fn from(job_item_update: PaymentMethodMigrationJobItemUpdate) -> Self {
+        match job_item_update {
+            PaymentMethodMigrationJobItemUpdate::ResultUpdate {
+                status,
+                payment_method_id,
+                error_message,
+            } => Self {
+                status: Some(status),
+                payment_method_id,
+                error_message,
+                last_modified_at: common_utils::date_time::now(),
+            },
+        }
+    }
+}
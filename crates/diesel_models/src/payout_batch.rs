@@ -0,0 +1,160 @@
+use common_utils::{encryption::Encryption, id_type};
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use time::PrimitiveDateTime;
+
+use crate::{
+    enums as storage_enums,
+    schema::{payout_batch, payout_batch_item},
+};
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payout_batch)]
+pub struct PayoutBatchNew {
+    pub batch_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub profile_id: Option<id_type::ProfileId>,
+    pub status: storage_enums::PayoutBatchStatus,
+    pub file_name: Option<String>,
+    pub total_count: i32,
+    pub succeeded_count: i32,
+    pub failed_count: i32,
+    pub created_at: PrimitiveDateTime,
+    pub last_modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable)]
+#[diesel(table_name = payout_batch, primary_key(batch_id), check_for_backend(diesel::pg::Pg))]
+pub struct PayoutBatch {
+    pub batch_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub profile_id: Option<id_type::ProfileId>,
+    pub status: storage_enums::PayoutBatchStatus,
+    pub file_name: Option<String>,
+    pub total_count: i32,
+    pub succeeded_count: i32,
+    pub failed_count: i32,
+    pub created_at: PrimitiveDateTime,
+    pub last_modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum PayoutBatchUpdate {
+    StatusUpdate {
+        status: storage_enums::PayoutBatchStatus,
+    },
+    CountsUpdate {
+        status: storage_enums::PayoutBatchStatus,
+        succeeded_count: i32,
+        failed_count: i32,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payout_batch)]
+pub struct PayoutBatchUpdateInternal {
+    status: Option<storage_enums::PayoutBatchStatus>,
+    succeeded_count: Option<i32>,
+    failed_count: Option<i32>,
+    last_modified_at: PrimitiveDateTime,
+}
+
+impl From<PayoutBatchUpdate> for PayoutBatchUpdateInternal {
+    fn from(payout_batch_update: PayoutBatchUpdate) -> Self {
+        let last_modified_at = common_utils::date_time::now();
+        match payout_batch_update {
+            PayoutBatchUpdate::StatusUpdate { status } => Self {
+                status: Some(status),
+                succeeded_count: None,
+                failed_count: None,
+                last_modified_at,
+            },
+            PayoutBatchUpdate::CountsUpdate {
+                status,
+                succeeded_count,
+                failed_count,
+            } => Self {
+                status: Some(status),
+                succeeded_count: Some(succeeded_count),
+                failed_count: Some(failed_count),
+                last_modified_at,
+            },
+        }
+    }
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payout_batch_item)]
+pub struct PayoutBatchItemNew {
+    pub batch_id: String,
+    pub row_number: i32,
+    pub merchant_id: id_type::MerchantId,
+    pub status: storage_enums::PayoutBatchItemStatus,
+    /// The payout create request of the row, encrypted with the key of the merchant. Absent for
+    /// rows which could not be parsed.
+    pub payout_request: Option<Encryption>,
+    pub payout_id: Option<String>,
+    pub payout_status: Option<storage_enums::PayoutStatus>,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+    pub created_at: PrimitiveDateTime,
+    pub last_modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable)]
+#[diesel(
+    table_name = payout_batch_item,
+    primary_key(batch_id, row_number),
+    check_for_backend(diesel::pg::Pg)
+)]
+pub struct PayoutBatchItem {
+    pub batch_id: String,
+    pub row_number: i32,
+    pub merchant_id: id_type::MerchantId,
+    pub status: storage_enums::PayoutBatchItemStatus,
+    pub payout_request: Option<Encryption>,
+    pub payout_id: Option<String>,
+    pub payout_status: Option<storage_enums::PayoutStatus>,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+    pub created_at: PrimitiveDateTime,
+    pub last_modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum PayoutBatchItemUpdate {
+    ResultUpdate {
+        status: storage_enums::PayoutBatchItemStatus,
+        payout_status: Option<storage_enums::PayoutStatus>,
+        error_code: Option<String>,
+        error_message: Option<String>,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payout_batch_item)]
+pub struct PayoutBatchItemUpdateInternal {
+    status: Option<storage_enums::PayoutBatchItemStatus>,
+    payout_status: Option<storage_enums::PayoutStatus>,
+    error_code: Option<String>,
+    error_message: Option<String>,
+    last_modified_at: PrimitiveDateTime,
+}
+
+impl From<PayoutBatchItemUpdate> for PayoutBatchItemUpdateInternal {
+    fn from(payout_batch_item_update: PayoutBatchItemUpdate) -> Self {
+        match payout_batch_item_update {
+            PayoutBatchItemUpdate::ResultUpdate {
+                status,
+                payout_status,
+                error_code,
+                error_message,
+            } => Self {
+                status: Some(status),
+                payout_status,
+                error_code,
+                error_message,
+                last_modified_at: common_utils::date_time::now(),
+            },
+        }
+    }
+}
@@ -1,4 +1,5 @@
 pub mod address;
+pub mod analytics_digest_subscription;
 pub mod api_keys;
 pub mod blocklist_lookup;
 pub mod business_profile;
@@ -31,7 +32,9 @@ pub mod payment_attempt;
 pub mod payment_intent;
 pub mod payment_link;
 pub mod payment_method;
+pub mod payment_method_migration_job;
 pub mod payout_attempt;
+pub mod payout_batch;
 pub mod payouts;
 pub mod process_tracker;
 pub mod refund;
@@ -39,11 +42,14 @@ pub mod relay;
 pub mod reverse_lookup;
 pub mod role;
 pub mod routing_algorithm;
+pub mod subscription;
 #[cfg(feature = "tokenization_v2")]
 pub mod tokenization;
 pub mod unified_translations;
 pub mod user;
 pub mod user_authentication_method;
 pub mod user_key_store;
+pub mod user_passkey;
 pub mod user_role;
 mod utils;
+pub mod webhook_endpoint;
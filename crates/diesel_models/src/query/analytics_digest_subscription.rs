@@ -0,0 +1,83 @@
+use common_utils::id_type;
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    analytics_digest_subscription::{
+        AnalyticsDigestSubscription, AnalyticsDigestSubscriptionNew,
+        AnalyticsDigestSubscriptionUpdate, AnalyticsDigestSubscriptionUpdateInternal,
+    },
+    schema::analytics_digest_subscription::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl AnalyticsDigestSubscriptionNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<AnalyticsDigestSubscription> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl AnalyticsDigestSubscription {
+    pub async fn find_by_merchant_id_subscription_id(
+        conn: &PgPooledConn,
+        merchant_id: &id_type::MerchantId,
+        subscription_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::subscription_id.eq(subscription_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn list_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &id_type::MerchantId,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id.eq(merchant_id.to_owned()),
+            None,
+            None,
+            Some(dsl::created_at.asc()),
+        )
+        .await
+    }
+
+    pub async fn update_by_merchant_id_subscription_id(
+        conn: &PgPooledConn,
+        merchant_id: &id_type::MerchantId,
+        subscription_id: &str,
+        subscription_update: AnalyticsDigestSubscriptionUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::subscription_id.eq(subscription_id.to_owned())),
+            AnalyticsDigestSubscriptionUpdateInternal::from(subscription_update),
+        )
+        .await
+    }
+
+    pub async fn delete_by_merchant_id_subscription_id(
+        conn: &PgPooledConn,
+        merchant_id: &id_type::MerchantId,
+        subscription_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_delete_one_with_result::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::subscription_id.eq(subscription_id.to_owned())),
+        )
+        .await
+    }
+}
@@ -1,8 +1,13 @@
-use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+use async_bb8_diesel::AsyncRunQueryDsl;
+use diesel::{
+    associations::HasTable, debug_query, pg::Pg, BoolExpressionMethods, ExpressionMethods, QueryDsl,
+};
+use error_stack::ResultExt;
 
 use super::generics;
 use crate::{
-    blocklist::{Blocklist, BlocklistNew},
+    blocklist::{Blocklist, BlocklistNew, BlocklistUpdate, BlocklistUpdateInternal},
+    errors,
     schema::blocklist::dsl,
     PgPooledConn, StorageResult,
 };
@@ -28,23 +33,55 @@ impl Blocklist {
         .await
     }
 
-    pub async fn list_by_merchant_id_data_kind(
+    pub async fn list_by_merchant_id_constraints(
         conn: &PgPooledConn,
         merchant_id: &common_utils::id_type::MerchantId,
-        data_kind: common_enums::BlocklistDataKind,
+        data_kind: Option<common_enums::BlocklistDataKind>,
+        status: Option<common_enums::BlocklistEntryStatus>,
         limit: i64,
         offset: i64,
     ) -> StorageResult<Vec<Self>> {
-        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
-            conn,
-            dsl::merchant_id
-                .eq(merchant_id.to_owned())
-                .and(dsl::data_kind.eq(data_kind.to_owned())),
-            Some(limit),
-            Some(offset),
-            Some(dsl::created_at.desc()),
+        let mut query = <Self as HasTable>::table()
+            .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
+            .order(dsl::created_at.desc())
+            .limit(limit)
+            .offset(offset)
+            .into_boxed();
+
+        if let Some(data_kind) = data_kind {
+            query = query.filter(dsl::data_kind.eq(data_kind));
+        }
+
+        // The status of an entry is updated by the expiry workflow some time after it expires, an
+        // entry is only active until its expiry
+        let now = common_utils::date_time::now();
+        match status {
+            Some(common_enums::BlocklistEntryStatus::Active) => {
+                query = query.filter(
+                    dsl::status
+                        .eq(common_enums::BlocklistEntryStatus::Active)
+                        .and(dsl::expires_at.is_null().or(dsl::expires_at.gt(now))),
+                );
+            }
+            Some(common_enums::BlocklistEntryStatus::Expired) => {
+                query = query.filter(
+                    dsl::status
+                        .eq(common_enums::BlocklistEntryStatus::Expired)
+                        .or(dsl::expires_at.le(now)),
+                );
+            }
+            None => {}
+        }
+
+        router_env::logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        generics::db_metrics::track_database_call::<Self, _, _>(
+            query.get_results_async(conn),
+            generics::db_metrics::DatabaseOperation::Filter,
         )
         .await
+        .change_context(errors::DatabaseError::Others) // Query returns empty Vec when no records are found
+        .attach_printable("Error filtering blocklist entries by constraints")
     }
 
     pub async fn list_by_merchant_id(
@@ -74,4 +111,25 @@ impl Blocklist {
         )
         .await
     }
+
+    pub async fn update_by_merchant_id_fingerprint_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        fingerprint_id: &str,
+        blocklist_update: BlocklistUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::fingerprint_id.eq(fingerprint_id.to_owned())),
+            BlocklistUpdateInternal::from(blocklist_update),
+        )
+        .await
+    }
 }
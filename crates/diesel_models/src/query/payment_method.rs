@@ -184,6 +184,27 @@ impl PaymentMethod {
         .await
     }
 
+    pub async fn find_by_merchant_id_payment_method_status(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_method: storage_enums::PaymentMethod,
+        status: storage_enums::PaymentMethodStatus,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::payment_method.eq(payment_method))
+                .and(dsl::status.eq(status)),
+            limit,
+            offset,
+            Some(dsl::created_at.asc()),
+        )
+        .await
+    }
+
     pub async fn update_with_payment_method_id(
         self,
         conn: &PgPooledConn,
@@ -0,0 +1,148 @@
+use async_bb8_diesel::AsyncRunQueryDsl;
+use common_utils::id_type;
+use diesel::{
+    associations::HasTable, debug_query, pg::Pg, BoolExpressionMethods, ExpressionMethods, QueryDsl,
+};
+use error_stack::ResultExt;
+
+use super::generics;
+use crate::{
+    enums as storage_enums, errors,
+    payment_method_migration_job::{
+        PaymentMethodMigrationJob, PaymentMethodMigrationJobItem, PaymentMethodMigrationJobItemNew,
+        PaymentMethodMigrationJobItemUpdate, PaymentMethodMigrationJobItemUpdateInternal,
+        PaymentMethodMigrationJobNew, PaymentMethodMigrationJobUpdate,
+        PaymentMethodMigrationJobUpdateInternal,
+    },
+    schema::{
+        payment_method_migration_job::dsl, payment_method_migration_job_item::dsl as item_dsl,
+    },
+    PgPooledConn, StorageResult,
+};
+
+impl PaymentMethodMigrationJobNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<PaymentMethodMigrationJob> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl PaymentMethodMigrationJob {
+    pub async fn find_by_merchant_id_job_id(
+        conn: &PgPooledConn,
+        merchant_id: &id_type::MerchantId,
+        job_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::job_id.eq(job_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn update_by_merchant_id_job_id(
+        conn: &PgPooledConn,
+        merchant_id: &id_type::MerchantId,
+        job_id: &str,
+        job_update: PaymentMethodMigrationJobUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::job_id.eq(job_id.to_owned())),
+            PaymentMethodMigrationJobUpdateInternal::from(job_update),
+        )
+        .await
+    }
+}
+
+impl PaymentMethodMigrationJobItemNew {
+    pub async fn batch_insert(job_items: Vec<Self>, conn: &PgPooledConn) -> StorageResult<()> {
+        generics::generic_insert::<_, _, PaymentMethodMigrationJobItem>(conn, job_items).await?;
+        Ok(())
+    }
+}
+
+impl PaymentMethodMigrationJobItem {
+    pub async fn find_by_job_id_status(
+        conn: &PgPooledConn,
+        job_id: &str,
+        status: Option<storage_enums::PaymentMethodMigrationJobItemStatus>,
+        limit: Option<i64>,
+    ) -> StorageResult<Vec<Self>> {
+        match status {
+            Some(status) => {
+                generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+                    conn,
+                    item_dsl::job_id
+                        .eq(job_id.to_owned())
+                        .and(item_dsl::status.eq(status)),
+                    limit,
+                    None,
+                    Some(item_dsl::line_number.asc()),
+                )
+                .await
+            }
+            None => {
+                generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+                    conn,
+                    item_dsl::job_id.eq(job_id.to_owned()),
+                    limit,
+                    None,
+                    Some(item_dsl::line_number.asc()),
+                )
+                .await
+            }
+        }
+    }
+
+    pub async fn get_count_by_job_id_status(
+        conn: &PgPooledConn,
+        job_id: &str,
+        status: storage_enums::PaymentMethodMigrationJobItemStatus,
+    ) -> StorageResult<i64> {
+        let query = <Self as HasTable>::table().count().filter(
+            item_dsl::job_id
+                .eq(job_id.to_owned())
+                .and(item_dsl::status.eq(status)),
+        );
+
+        router_env::logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        generics::db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            query.get_result_async::<i64>(conn),
+            generics::db_metrics::DatabaseOperation::Count,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Failed to get a count of payment method migration job items")
+    }
+
+    pub async fn update_by_job_id_line_number(
+        conn: &PgPooledConn,
+        job_id: &str,
+        line_number: i32,
+        job_item_update: PaymentMethodMigrationJobItemUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            item_dsl::job_id
+                .eq(job_id.to_owned())
+                .and(item_dsl::line_number.eq(line_number)),
+            PaymentMethodMigrationJobItemUpdateInternal::from(job_item_update),
+        )
+        .await
+    }
+}
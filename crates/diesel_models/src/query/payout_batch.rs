@@ -0,0 +1,164 @@
+use async_bb8_diesel::AsyncRunQueryDsl;
+use common_utils::id_type;
+use diesel::{
+    associations::HasTable, debug_query, pg::Pg, BoolExpressionMethods, ExpressionMethods, QueryDsl,
+};
+use error_stack::ResultExt;
+
+use super::generics;
+use crate::{
+    enums as storage_enums, errors,
+    payout_batch::{
+        PayoutBatch, PayoutBatchItem, PayoutBatchItemNew, PayoutBatchItemUpdate,
+        PayoutBatchItemUpdateInternal, PayoutBatchNew, PayoutBatchUpdate,
+        PayoutBatchUpdateInternal,
+    },
+    schema::{payout_batch::dsl, payout_batch_item::dsl as item_dsl},
+    PgPooledConn, StorageResult,
+};
+
+impl PayoutBatchNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<PayoutBatch> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl PayoutBatch {
+    pub async fn find_by_merchant_id_batch_id(
+        conn: &PgPooledConn,
+        merchant_id: &id_type::MerchantId,
+        batch_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::batch_id.eq(batch_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn update_by_merchant_id_batch_id(
+        conn: &PgPooledConn,
+        merchant_id: &id_type::MerchantId,
+        batch_id: &str,
+        payout_batch_update: PayoutBatchUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::batch_id.eq(batch_id.to_owned())),
+            PayoutBatchUpdateInternal::from(payout_batch_update),
+        )
+        .await
+    }
+}
+
+impl PayoutBatchItemNew {
+    pub async fn batch_insert(
+        payout_batch_items: Vec<Self>,
+        conn: &PgPooledConn,
+    ) -> StorageResult<()> {
+        generics::generic_insert::<_, _, PayoutBatchItem>(conn, payout_batch_items).await?;
+        Ok(())
+    }
+}
+
+impl PayoutBatchItem {
+    pub async fn find_by_batch_id_status(
+        conn: &PgPooledConn,
+        batch_id: &str,
+        status: Option<storage_enums::PayoutBatchItemStatus>,
+        limit: Option<i64>,
+    ) -> StorageResult<Vec<Self>> {
+        match status {
+            Some(status) => {
+                generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+                    conn,
+                    item_dsl::batch_id
+                        .eq(batch_id.to_owned())
+                        .and(item_dsl::status.eq(status)),
+                    limit,
+                    None,
+                    Some(item_dsl::row_number.asc()),
+                )
+                .await
+            }
+            None => {
+                generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+                    conn,
+                    item_dsl::batch_id.eq(batch_id.to_owned()),
+                    limit,
+                    None,
+                    Some(item_dsl::row_number.asc()),
+                )
+                .await
+            }
+        }
+    }
+
+    pub async fn get_count_by_batch_id_status(
+        conn: &PgPooledConn,
+        batch_id: &str,
+        status: storage_enums::PayoutBatchItemStatus,
+    ) -> StorageResult<i64> {
+        let query = <Self as HasTable>::table().count().filter(
+            item_dsl::batch_id
+                .eq(batch_id.to_owned())
+                .and(item_dsl::status.eq(status)),
+        );
+
+        router_env::logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        generics::db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            query.get_result_async::<i64>(conn),
+            generics::db_metrics::DatabaseOperation::Count,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Failed to get a count of payout batch items")
+    }
+
+    pub async fn update_by_batch_id_status(
+        conn: &PgPooledConn,
+        batch_id: &str,
+        status: storage_enums::PayoutBatchItemStatus,
+        payout_batch_item_update: PayoutBatchItemUpdate,
+    ) -> StorageResult<usize> {
+        generics::generic_update::<<Self as HasTable>::Table, _, _>(
+            conn,
+            item_dsl::batch_id
+                .eq(batch_id.to_owned())
+                .and(item_dsl::status.eq(status)),
+            PayoutBatchItemUpdateInternal::from(payout_batch_item_update),
+        )
+        .await
+    }
+
+    pub async fn update_by_batch_id_row_number(
+        conn: &PgPooledConn,
+        batch_id: &str,
+        row_number: i32,
+        payout_batch_item_update: PayoutBatchItemUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            item_dsl::batch_id
+                .eq(batch_id.to_owned())
+                .and(item_dsl::row_number.eq(row_number)),
+            PayoutBatchItemUpdateInternal::from(payout_batch_item_update),
+        )
+        .await
+    }
+}
@@ -0,0 +1,217 @@
+use common_utils::id_type;
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    enums as storage_enums,
+    schema::{
+        subscription::dsl, subscription_invoice::dsl as invoice_dsl,
+        subscription_plan::dsl as plan_dsl,
+    },
+    subscription::{
+        Subscription, SubscriptionInvoice, SubscriptionInvoiceNew, SubscriptionInvoiceUpdate,
+        SubscriptionInvoiceUpdateInternal, SubscriptionNew, SubscriptionPlan, SubscriptionPlanNew,
+        SubscriptionPlanUpdate, SubscriptionPlanUpdateInternal, SubscriptionUpdate,
+        SubscriptionUpdateInternal,
+    },
+    PgPooledConn, StorageResult,
+};
+
+impl SubscriptionPlanNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<SubscriptionPlan> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl SubscriptionPlan {
+    pub async fn find_by_merchant_id_plan_id(
+        conn: &PgPooledConn,
+        merchant_id: &id_type::MerchantId,
+        plan_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            plan_dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(plan_dsl::plan_id.eq(plan_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn list_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &id_type::MerchantId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            plan_dsl::merchant_id.eq(merchant_id.to_owned()),
+            limit,
+            offset,
+            Some(plan_dsl::created_at.desc()),
+        )
+        .await
+    }
+
+    pub async fn update_by_merchant_id_plan_id(
+        conn: &PgPooledConn,
+        merchant_id: &id_type::MerchantId,
+        plan_id: &str,
+        subscription_plan_update: SubscriptionPlanUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            plan_dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(plan_dsl::plan_id.eq(plan_id.to_owned())),
+            SubscriptionPlanUpdateInternal::from(subscription_plan_update),
+        )
+        .await
+    }
+}
+
+impl SubscriptionNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<Subscription> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl Subscription {
+    pub async fn find_by_merchant_id_subscription_id(
+        conn: &PgPooledConn,
+        merchant_id: &id_type::MerchantId,
+        subscription_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::subscription_id.eq(subscription_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn list_by_merchant_id_customer_id(
+        conn: &PgPooledConn,
+        merchant_id: &id_type::MerchantId,
+        customer_id: Option<&id_type::CustomerId>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> StorageResult<Vec<Self>> {
+        match customer_id {
+            Some(customer_id) => {
+                generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+                    conn,
+                    dsl::merchant_id
+                        .eq(merchant_id.to_owned())
+                        .and(dsl::customer_id.eq(customer_id.to_owned())),
+                    limit,
+                    offset,
+                    Some(dsl::created_at.desc()),
+                )
+                .await
+            }
+            None => {
+                generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+                    conn,
+                    dsl::merchant_id.eq(merchant_id.to_owned()),
+                    limit,
+                    offset,
+                    Some(dsl::created_at.desc()),
+                )
+                .await
+            }
+        }
+    }
+
+    pub async fn update_by_merchant_id_subscription_id(
+        conn: &PgPooledConn,
+        merchant_id: &id_type::MerchantId,
+        subscription_id: &str,
+        subscription_update: SubscriptionUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::subscription_id.eq(subscription_id.to_owned())),
+            SubscriptionUpdateInternal::from(subscription_update),
+        )
+        .await
+    }
+}
+
+impl SubscriptionInvoiceNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<SubscriptionInvoice> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl SubscriptionInvoice {
+    pub async fn list_by_merchant_id_subscription_id(
+        conn: &PgPooledConn,
+        merchant_id: &id_type::MerchantId,
+        subscription_id: &str,
+        limit: Option<i64>,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            invoice_dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(invoice_dsl::subscription_id.eq(subscription_id.to_owned())),
+            limit,
+            None,
+            Some(invoice_dsl::created_at.desc()),
+        )
+        .await
+    }
+
+    pub async fn find_by_subscription_id_status(
+        conn: &PgPooledConn,
+        subscription_id: &str,
+        status: storage_enums::SubscriptionInvoiceStatus,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            invoice_dsl::subscription_id
+                .eq(subscription_id.to_owned())
+                .and(invoice_dsl::status.eq(status)),
+            None,
+            None,
+            Some(invoice_dsl::created_at.desc()),
+        )
+        .await
+    }
+
+    pub async fn update_by_merchant_id_invoice_id(
+        conn: &PgPooledConn,
+        merchant_id: &id_type::MerchantId,
+        invoice_id: &str,
+        subscription_invoice_update: SubscriptionInvoiceUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            invoice_dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(invoice_dsl::invoice_id.eq(invoice_id.to_owned())),
+            SubscriptionInvoiceUpdateInternal::from(subscription_invoice_update),
+        )
+        .await
+    }
+}
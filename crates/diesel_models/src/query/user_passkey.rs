@@ -0,0 +1,62 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    schema::user_passkeys::dsl,
+    user_passkey::{UserPasskey, UserPasskeyNew, UserPasskeyUpdate, UserPasskeyUpdateInternal},
+    PgPooledConn, StorageResult,
+};
+
+impl UserPasskeyNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<UserPasskey> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl UserPasskey {
+    pub async fn list_by_user_id(conn: &PgPooledConn, user_id: &str) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::user_id.eq(user_id.to_owned()),
+            None,
+            None,
+            Some(dsl::created_at.asc()),
+        )
+        .await
+    }
+
+    pub async fn update_by_user_id_passkey_id(
+        conn: &PgPooledConn,
+        user_id: &str,
+        passkey_id: &str,
+        passkey_update: UserPasskeyUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::user_id
+                .eq(user_id.to_owned())
+                .and(dsl::passkey_id.eq(passkey_id.to_owned())),
+            UserPasskeyUpdateInternal::from(passkey_update),
+        )
+        .await
+    }
+
+    pub async fn delete_by_user_id_passkey_id(
+        conn: &PgPooledConn,
+        user_id: &str,
+        passkey_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_delete_one_with_result::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::user_id
+                .eq(user_id.to_owned())
+                .and(dsl::passkey_id.eq(passkey_id.to_owned())),
+        )
+        .await
+    }
+}
@@ -34,6 +34,29 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    analytics_digest_subscription (subscription_id) {
+        #[max_length = 64]
+        subscription_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 32]
+        org_id -> Varchar,
+        #[max_length = 64]
+        role_id -> Varchar,
+        #[max_length = 32]
+        frequency -> Varchar,
+        #[max_length = 64]
+        created_by -> Varchar,
+        created_at -> Timestamp,
+        last_modified_at -> Timestamp,
+        last_sent_at -> Nullable<Timestamp>,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -54,6 +77,9 @@ diesel::table! {
         created_at -> Timestamp,
         expires_at -> Nullable<Timestamp>,
         last_used -> Nullable<Timestamp>,
+        permission_groups -> Nullable<Array<Nullable<Text>>>,
+        #[max_length = 64]
+        profile_id -> Nullable<Varchar>,
     }
 }
 
@@ -138,6 +164,13 @@ diesel::table! {
         data_kind -> BlocklistDataKind,
         metadata -> Nullable<Jsonb>,
         created_at -> Timestamp,
+        expires_at -> Nullable<Timestamp>,
+        #[max_length = 255]
+        reason -> Nullable<Varchar>,
+        #[max_length = 255]
+        created_by -> Nullable<Varchar>,
+        #[max_length = 32]
+        status -> Varchar,
     }
 }
 
@@ -228,6 +261,9 @@ diesel::table! {
         is_iframe_redirection_enabled -> Nullable<Bool>,
         is_pre_network_tokenization_enabled -> Nullable<Bool>,
         three_ds_decision_rule_algorithm -> Nullable<Jsonb>,
+        #[max_length = 255]
+        previous_payment_response_hash_key -> Nullable<Varchar>,
+        previous_payment_response_hash_key_expires_at -> Nullable<Timestamp>,
     }
 }
 
@@ -471,7 +507,7 @@ diesel::table! {
         #[max_length = 64]
         business_profile_id -> Nullable<Varchar>,
         primary_object_created_at -> Nullable<Timestamp>,
-        #[max_length = 64]
+        #[max_length = 255]
         idempotent_event_id -> Nullable<Varchar>,
         #[max_length = 64]
         initial_attempt_id -> Nullable<Varchar>,
@@ -480,6 +516,8 @@ diesel::table! {
         delivery_attempt -> Nullable<WebhookDeliveryAttempt>,
         metadata -> Nullable<Jsonb>,
         is_overall_delivery_successful -> Nullable<Bool>,
+        #[max_length = 64]
+        webhook_endpoint_id -> Nullable<Varchar>,
     }
 }
 
@@ -1039,6 +1077,8 @@ diesel::table! {
         #[max_length = 255]
         created_by -> Nullable<Varchar>,
         is_iframe_redirection_enabled -> Nullable<Bool>,
+        enable_partial_authorization -> Nullable<Bool>,
+        l2_l3_data -> Nullable<Jsonb>,
     }
 }
 
@@ -1072,6 +1112,55 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    payment_method_migration_job (job_id) {
+        #[max_length = 64]
+        job_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        merchant_connector_id -> Nullable<Varchar>,
+        #[max_length = 32]
+        status -> Varchar,
+        dry_run -> Bool,
+        #[max_length = 255]
+        file_name -> Nullable<Varchar>,
+        total_count -> Int4,
+        valid_count -> Int4,
+        invalid_count -> Int4,
+        migrated_count -> Int4,
+        failed_count -> Int4,
+        created_at -> Timestamp,
+        last_modified_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    payment_method_migration_job_item (job_id, line_number) {
+        #[max_length = 64]
+        job_id -> Varchar,
+        line_number -> Int4,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 32]
+        status -> Varchar,
+        record -> Nullable<Bytea>,
+        #[max_length = 64]
+        customer_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        payment_method_id -> Nullable<Varchar>,
+        error_message -> Nullable<Text>,
+        created_at -> Timestamp,
+        last_modified_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -1177,6 +1266,53 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    payout_batch (batch_id) {
+        #[max_length = 64]
+        batch_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Nullable<Varchar>,
+        #[max_length = 32]
+        status -> Varchar,
+        #[max_length = 255]
+        file_name -> Nullable<Varchar>,
+        total_count -> Int4,
+        succeeded_count -> Int4,
+        failed_count -> Int4,
+        created_at -> Timestamp,
+        last_modified_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    payout_batch_item (batch_id, row_number) {
+        #[max_length = 64]
+        batch_id -> Varchar,
+        row_number -> Int4,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 32]
+        status -> Varchar,
+        payout_request -> Nullable<Bytea>,
+        #[max_length = 64]
+        payout_id -> Nullable<Varchar>,
+        payout_status -> Nullable<PayoutStatus>,
+        #[max_length = 64]
+        error_code -> Nullable<Varchar>,
+        error_message -> Nullable<Text>,
+        created_at -> Timestamp,
+        last_modified_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -1218,6 +1354,7 @@ diesel::table! {
         client_secret -> Nullable<Varchar>,
         #[max_length = 32]
         priority -> Nullable<Varchar>,
+        scheduled_at -> Nullable<Timestamp>,
     }
 }
 
@@ -1416,6 +1553,98 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    subscription (subscription_id) {
+        #[max_length = 64]
+        subscription_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Varchar,
+        #[max_length = 64]
+        customer_id -> Varchar,
+        #[max_length = 64]
+        plan_id -> Varchar,
+        #[max_length = 32]
+        status -> Varchar,
+        #[max_length = 64]
+        payment_method_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        mandate_id -> Nullable<Varchar>,
+        current_period_start -> Timestamp,
+        current_period_end -> Timestamp,
+        trial_end -> Nullable<Timestamp>,
+        cancel_at_period_end -> Bool,
+        cancelled_at -> Nullable<Timestamp>,
+        credit_balance -> Int8,
+        metadata -> Nullable<Jsonb>,
+        created_at -> Timestamp,
+        last_modified_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    subscription_invoice (invoice_id) {
+        #[max_length = 64]
+        invoice_id -> Varchar,
+        #[max_length = 64]
+        subscription_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        customer_id -> Varchar,
+        #[max_length = 64]
+        plan_id -> Varchar,
+        #[max_length = 32]
+        billing_reason -> Varchar,
+        #[max_length = 32]
+        status -> Varchar,
+        amount -> Int8,
+        currency -> Currency,
+        period_start -> Timestamp,
+        period_end -> Timestamp,
+        #[max_length = 64]
+        payment_id -> Nullable<Varchar>,
+        attempt_count -> Int4,
+        #[max_length = 64]
+        error_code -> Nullable<Varchar>,
+        error_message -> Nullable<Text>,
+        created_at -> Timestamp,
+        last_modified_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    subscription_plan (plan_id) {
+        #[max_length = 64]
+        plan_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 255]
+        name -> Varchar,
+        description -> Nullable<Text>,
+        amount -> Int8,
+        currency -> Currency,
+        #[max_length = 32]
+        billing_interval -> Varchar,
+        interval_count -> Int4,
+        trial_period_days -> Nullable<Int4>,
+        is_active -> Bool,
+        metadata -> Nullable<Jsonb>,
+        created_at -> Timestamp,
+        last_modified_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -1504,6 +1733,25 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    user_passkeys (passkey_id) {
+        #[max_length = 64]
+        passkey_id -> Varchar,
+        #[max_length = 64]
+        user_id -> Varchar,
+        credential_id -> Text,
+        #[max_length = 255]
+        name -> Varchar,
+        passkey -> Jsonb,
+        created_at -> Timestamp,
+        last_modified_at -> Timestamp,
+        last_used_at -> Nullable<Timestamp>,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -1561,8 +1809,35 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    webhook_endpoint (endpoint_id) {
+        #[max_length = 64]
+        endpoint_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Varchar,
+        url -> Text,
+        #[max_length = 255]
+        secret -> Varchar,
+        enabled_events -> Array<Nullable<EventType>>,
+        is_active -> Bool,
+        #[max_length = 255]
+        description -> Nullable<Varchar>,
+        created_at -> Timestamp,
+        last_modified_at -> Timestamp,
+        #[max_length = 255]
+        previous_secret -> Nullable<Varchar>,
+        previous_secret_expires_at -> Nullable<Timestamp>,
+    }
+}
+
 diesel::allow_tables_to_appear_in_same_query!(
     address,
+    analytics_digest_subscription,
     api_keys,
     authentication,
     blocklist,
@@ -1592,8 +1867,12 @@ diesel::allow_tables_to_appear_in_same_query!(
     payment_attempt,
     payment_intent,
     payment_link,
+    payment_method_migration_job,
+    payment_method_migration_job_item,
     payment_methods,
     payout_attempt,
+    payout_batch,
+    payout_batch_item,
     payouts,
     process_tracker,
     refund,
@@ -1601,10 +1880,15 @@ diesel::allow_tables_to_appear_in_same_query!(
     reverse_lookup,
     roles,
     routing_algorithm,
+    subscription,
+    subscription_invoice,
+    subscription_plan,
     themes,
     unified_translations,
     user_authentication_methods,
     user_key_store,
+    user_passkeys,
     user_roles,
     users,
+    webhook_endpoint,
 );
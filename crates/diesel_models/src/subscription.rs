@@ -0,0 +1,323 @@
+use common_utils::{id_type, pii, types::MinorUnit};
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use time::PrimitiveDateTime;
+
+use crate::{
+    enums as storage_enums,
+    schema::{subscription, subscription_invoice, subscription_plan},
+};
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = subscription_plan)]
+pub struct SubscriptionPlanNew {
+    pub plan_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub name: String,
+    pub description: Option<String>,
+    pub amount: MinorUnit,
+    pub currency: storage_enums::Currency,
+    pub billing_interval: storage_enums::SubscriptionInterval,
+    pub interval_count: i32,
+    pub trial_period_days: Option<i32>,
+    pub is_active: bool,
+    pub metadata: Option<pii::SecretSerdeValue>,
+    pub created_at: PrimitiveDateTime,
+    pub last_modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable)]
+#[diesel(table_name = subscription_plan, primary_key(plan_id), check_for_backend(diesel::pg::Pg))]
+pub struct SubscriptionPlan {
+    pub plan_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub name: String,
+    pub description: Option<String>,
+    pub amount: MinorUnit,
+    pub currency: storage_enums::Currency,
+    pub billing_interval: storage_enums::SubscriptionInterval,
+    pub interval_count: i32,
+    pub trial_period_days: Option<i32>,
+    pub is_active: bool,
+    pub metadata: Option<pii::SecretSerdeValue>,
+    pub created_at: PrimitiveDateTime,
+    pub last_modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum SubscriptionPlanUpdate {
+    Update {
+        name: Option<String>,
+        description: Option<String>,
+        is_active: Option<bool>,
+        metadata: Option<pii::SecretSerdeValue>,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = subscription_plan)]
+pub struct SubscriptionPlanUpdateInternal {
+    name: Option<String>,
+    description: Option<String>,
+    is_active: Option<bool>,
+    metadata: Option<pii::SecretSerdeValue>,
+    last_modified_at: PrimitiveDateTime,
+}
+
+impl From<SubscriptionPlanUpdate> for SubscriptionPlanUpdateInternal {
+    fn from(subscription_plan_update: SubscriptionPlanUpdate) -> Self {
+        match subscription_plan_update {
+            SubscriptionPlanUpdate::Update {
+                name,
+                description,
+                is_active,
+                metadata,
+            } => Self {
+                name,
+                description,
+                is_active,
+                metadata,
+                last_modified_at: common_utils::date_time::now(),
+            },
+        }
+    }
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = subscription)]
+pub struct SubscriptionNew {
+    pub subscription_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub profile_id: id_type::ProfileId,
+    pub customer_id: id_type::CustomerId,
+    pub plan_id: String,
+    pub status: storage_enums::SubscriptionStatus,
+    pub payment_method_id: Option<String>,
+    pub mandate_id: Option<String>,
+    pub current_period_start: PrimitiveDateTime,
+    pub current_period_end: PrimitiveDateTime,
+    pub trial_end: Option<PrimitiveDateTime>,
+    pub cancel_at_period_end: bool,
+    pub cancelled_at: Option<PrimitiveDateTime>,
+    pub credit_balance: MinorUnit,
+    pub metadata: Option<pii::SecretSerdeValue>,
+    pub created_at: PrimitiveDateTime,
+    pub last_modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable)]
+#[diesel(table_name = subscription, primary_key(subscription_id), check_for_backend(diesel::pg::Pg))]
+pub struct Subscription {
+    pub subscription_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub profile_id: id_type::ProfileId,
+    pub customer_id: id_type::CustomerId,
+    pub plan_id: String,
+    pub status: storage_enums::SubscriptionStatus,
+    pub payment_method_id: Option<String>,
+    pub mandate_id: Option<String>,
+    pub current_period_start: PrimitiveDateTime,
+    pub current_period_end: PrimitiveDateTime,
+    pub trial_end: Option<PrimitiveDateTime>,
+    pub cancel_at_period_end: bool,
+    pub cancelled_at: Option<PrimitiveDateTime>,
+    /// The amount credited to the subscription by the proration of plan downgrades, which is
+    /// deducted from the next invoice
+    pub credit_balance: MinorUnit,
+    pub metadata: Option<pii::SecretSerdeValue>,
+    pub created_at: PrimitiveDateTime,
+    pub last_modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum SubscriptionUpdate {
+    Update {
+        plan_id: Option<String>,
+        cancel_at_period_end: Option<bool>,
+        credit_balance: Option<MinorUnit>,
+        metadata: Option<pii::SecretSerdeValue>,
+    },
+    /// Replaces the mandate and the payment method with which the subscription is charged
+    PaymentInstrumentUpdate {
+        mandate_id: Option<String>,
+        payment_method_id: Option<String>,
+    },
+    PeriodUpdate {
+        current_period_start: PrimitiveDateTime,
+        current_period_end: PrimitiveDateTime,
+        credit_balance: MinorUnit,
+    },
+    StatusUpdate {
+        status: storage_enums::SubscriptionStatus,
+    },
+    Cancel {
+        cancelled_at: PrimitiveDateTime,
+    },
+}
+
+#[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = subscription)]
+pub struct SubscriptionUpdateInternal {
+    plan_id: Option<String>,
+    status: Option<storage_enums::SubscriptionStatus>,
+    payment_method_id: Option<Option<String>>,
+    mandate_id: Option<Option<String>>,
+    current_period_start: Option<PrimitiveDateTime>,
+    current_period_end: Option<PrimitiveDateTime>,
+    cancel_at_period_end: Option<bool>,
+    cancelled_at: Option<PrimitiveDateTime>,
+    credit_balance: Option<MinorUnit>,
+    metadata: Option<pii::SecretSerdeValue>,
+    last_modified_at: Option<PrimitiveDateTime>,
+}
+
+impl From<SubscriptionUpdate> for SubscriptionUpdateInternal {
+    fn from(subscription_update: SubscriptionUpdate) -> Self {
+        let last_modified_at = Some(common_utils::date_time::now());
+        match subscription_update {
+            SubscriptionUpdate::Update {
+                plan_id,
+                cancel_at_period_end,
+                credit_balance,
+                metadata,
+            } => Self {
+                plan_id,
+                cancel_at_period_end,
+                credit_balance,
+                metadata,
+                last_modified_at,
+                ..Default::default()
+            },
+            SubscriptionUpdate::PaymentInstrumentUpdate {
+                mandate_id,
+                payment_method_id,
+            } => Self {
+                mandate_id: Some(mandate_id),
+                payment_method_id: Some(payment_method_id),
+                last_modified_at,
+                ..Default::default()
+            },
+            SubscriptionUpdate::PeriodUpdate {
+                current_period_start,
+                current_period_end,
+                credit_balance,
+            } => Self {
+                current_period_start: Some(current_period_start),
+                current_period_end: Some(current_period_end),
+                credit_balance: Some(credit_balance),
+                last_modified_at,
+                ..Default::default()
+            },
+            SubscriptionUpdate::StatusUpdate { status } => Self {
+                status: Some(status),
+                last_modified_at,
+                ..Default::default()
+            },
+            SubscriptionUpdate::Cancel { cancelled_at } => Self {
+                status: Some(storage_enums::SubscriptionStatus::Cancelled),
+                cancelled_at: Some(cancelled_at),
+                last_modified_at,
+                ..Default::default()
+            },
+        }
+    }
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = subscription_invoice)]
+pub struct SubscriptionInvoiceNew {
+    pub invoice_id: String,
+    pub subscription_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub customer_id: id_type::CustomerId,
+    pub plan_id: String,
+    pub billing_reason: storage_enums::SubscriptionBillingReason,
+    pub status: storage_enums::SubscriptionInvoiceStatus,
+    pub amount: MinorUnit,
+    pub currency: storage_enums::Currency,
+    pub period_start: PrimitiveDateTime,
+    pub period_end: PrimitiveDateTime,
+    pub payment_id: Option<id_type::PaymentId>,
+    pub attempt_count: i32,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+    pub created_at: PrimitiveDateTime,
+    pub last_modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable)]
+#[diesel(table_name = subscription_invoice, primary_key(invoice_id), check_for_backend(diesel::pg::Pg))]
+pub struct SubscriptionInvoice {
+    pub invoice_id: String,
+    pub subscription_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub customer_id: id_type::CustomerId,
+    pub plan_id: String,
+    pub billing_reason: storage_enums::SubscriptionBillingReason,
+    pub status: storage_enums::SubscriptionInvoiceStatus,
+    pub amount: MinorUnit,
+    pub currency: storage_enums::Currency,
+    pub period_start: PrimitiveDateTime,
+    pub period_end: PrimitiveDateTime,
+    /// The payment of the latest attempt to charge the invoice
+    pub payment_id: Option<id_type::PaymentId>,
+    pub attempt_count: i32,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+    pub created_at: PrimitiveDateTime,
+    pub last_modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum SubscriptionInvoiceUpdate {
+    PaymentUpdate {
+        status: storage_enums::SubscriptionInvoiceStatus,
+        payment_id: Option<id_type::PaymentId>,
+        attempt_count: i32,
+        error_code: Option<String>,
+        error_message: Option<String>,
+    },
+    StatusUpdate {
+        status: storage_enums::SubscriptionInvoiceStatus,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = subscription_invoice)]
+pub struct SubscriptionInvoiceUpdateInternal {
+    status: Option<storage_enums::SubscriptionInvoiceStatus>,
+    payment_id: Option<id_type::PaymentId>,
+    attempt_count: Option<i32>,
+    error_code: Option<String>,
+    error_message: Option<String>,
+    last_modified_at: PrimitiveDateTime,
+}
+
+impl From<SubscriptionInvoiceUpdate> for SubscriptionInvoiceUpdateInternal {
+    fn from(subscription_invoice_update: SubscriptionInvoiceUpdate) -> Self {
+        let last_modified_at = common_utils::date_time::now();
+        match subscription_invoice_update {
+            SubscriptionInvoiceUpdate::PaymentUpdate {
+                status,
+                payment_id,
+                attempt_count,
+                error_code,
+                error_message,
+            } => Self {
+                status: Some(status),
+                payment_id,
+                attempt_count: Some(attempt_count),
+                error_code,
+                error_message,
+                last_modified_at,
+            },
+            SubscriptionInvoiceUpdate::StatusUpdate { status } => Self {
+                status: Some(status),
+                payment_id: None,
+                attempt_count: None,
+                error_code: None,
+                error_message: None,
+                last_modified_at,
+            },
+        }
+    }
+}
@@ -0,0 +1,64 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use time::PrimitiveDateTime;
+
+use crate::schema::user_passkeys;
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable)]
+#[diesel(table_name = user_passkeys, primary_key(passkey_id), check_for_backend(diesel::pg::Pg))]
+pub struct UserPasskey {
+    pub passkey_id: String,
+    pub user_id: String,
+    pub credential_id: String,
+    pub name: String,
+    pub passkey: serde_json::Value,
+    pub created_at: PrimitiveDateTime,
+    pub last_modified_at: PrimitiveDateTime,
+    pub last_used_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = user_passkeys)]
+pub struct UserPasskeyNew {
+    pub passkey_id: String,
+    pub user_id: String,
+    pub credential_id: String,
+    pub name: String,
+    pub passkey: serde_json::Value,
+    pub created_at: PrimitiveDateTime,
+    pub last_modified_at: PrimitiveDateTime,
+    pub last_used_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Debug)]
+pub enum UserPasskeyUpdate {
+    /// The passkey was used to authenticate the user, and its signature counter and backup state
+    /// may have changed.
+    PasskeyUsed {
+        passkey: Option<serde_json::Value>,
+        last_used_at: PrimitiveDateTime,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = user_passkeys)]
+pub struct UserPasskeyUpdateInternal {
+    passkey: Option<serde_json::Value>,
+    last_used_at: Option<PrimitiveDateTime>,
+    last_modified_at: PrimitiveDateTime,
+}
+
+impl From<UserPasskeyUpdate> for UserPasskeyUpdateInternal {
+    fn from(passkey_update: UserPasskeyUpdate) -> Self {
+        let last_modified_at = common_utils::date_time::now();
+        match passkey_update {
+            UserPasskeyUpdate::PasskeyUsed {
+                passkey,
+                last_used_at,
+            } => Self {
+                passkey,
+                last_used_at: Some(last_used_at),
+                last_modified_at,
+            },
+        }
+    }
+}
@@ -0,0 +1,134 @@
+use common_utils::id_type;
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use masking::Secret;
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::webhook_endpoint};
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = webhook_endpoint)]
+pub struct WebhookEndpointNew {
+    pub endpoint_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub profile_id: id_type::ProfileId,
+    pub url: Secret<String>,
+    pub secret: Secret<String>,
+    pub enabled_events: Vec<storage_enums::EventType>,
+    pub is_active: bool,
+    pub description: Option<String>,
+    pub created_at: PrimitiveDateTime,
+    pub last_modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable)]
+#[diesel(table_name = webhook_endpoint, primary_key(endpoint_id), check_for_backend(diesel::pg::Pg))]
+pub struct WebhookEndpoint {
+    pub endpoint_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub profile_id: id_type::ProfileId,
+    pub url: Secret<String>,
+    pub secret: Secret<String>,
+    #[diesel(deserialize_as = super::DieselArray<storage_enums::EventType>)]
+    pub enabled_events: Vec<storage_enums::EventType>,
+    pub is_active: bool,
+    pub description: Option<String>,
+    pub created_at: PrimitiveDateTime,
+    pub last_modified_at: PrimitiveDateTime,
+    pub previous_secret: Option<Secret<String>>,
+    pub previous_secret_expires_at: Option<PrimitiveDateTime>,
+}
+
+impl WebhookEndpoint {
+    /// Whether the endpoint is active and subscribed to the specified event type
+    pub fn is_subscribed_to(&self, event_type: storage_enums::EventType) -> bool {
+        self.is_active && self.enabled_events.contains(&event_type)
+    }
+
+    /// The previous secret of the endpoint, if its rotation window has not elapsed
+    fn get_unexpired_previous_secret(&self) -> Option<&Secret<String>> {
+        let now = common_utils::date_time::now();
+        self.previous_secret.as_ref().filter(|_| {
+            self.previous_secret_expires_at
+                .is_some_and(|expires_at| expires_at > now)
+        })
+    }
+
+    /// Secrets that webhooks should be signed with: the current secret, followed by the previous
+    /// secret if its rotation window has not elapsed.
+    pub fn get_active_secrets(&self) -> Vec<Secret<String>> {
+        std::iter::once(self.secret.clone())
+            .chain(self.get_unexpired_previous_secret().cloned())
+            .collect()
+    }
+
+    /// Secret for signatures which can only carry a single signature. The previous secret is used
+    /// until its rotation window elapses, so that consumers have the whole window to switch over.
+    pub fn get_single_signature_secret(&self) -> &Secret<String> {
+        self.get_unexpired_previous_secret().unwrap_or(&self.secret)
+    }
+}
+
+#[derive(Debug)]
+pub enum WebhookEndpointUpdate {
+    Update {
+        url: Option<Secret<String>>,
+        enabled_events: Option<Vec<storage_enums::EventType>>,
+        is_active: Option<bool>,
+        description: Option<String>,
+    },
+    SecretRotation {
+        secret: Secret<String>,
+        previous_secret: Option<Secret<String>>,
+        previous_secret_expires_at: Option<PrimitiveDateTime>,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = webhook_endpoint)]
+pub struct WebhookEndpointUpdateInternal {
+    url: Option<Secret<String>>,
+    secret: Option<Secret<String>>,
+    enabled_events: Option<Vec<storage_enums::EventType>>,
+    is_active: Option<bool>,
+    description: Option<String>,
+    last_modified_at: PrimitiveDateTime,
+    previous_secret: Option<Secret<String>>,
+    previous_secret_expires_at: Option<PrimitiveDateTime>,
+}
+
+impl From<WebhookEndpointUpdate> for WebhookEndpointUpdateInternal {
+    fn from(webhook_endpoint_update: WebhookEndpointUpdate) -> Self {
+        let last_modified_at = common_utils::date_time::now();
+        match webhook_endpoint_update {
+            WebhookEndpointUpdate::Update {
+                url,
+                enabled_events,
+                is_active,
+                description,
+            } => Self {
+                url,
+                secret: None,
+                enabled_events,
+                is_active,
+                description,
+                last_modified_at,
+                previous_secret: None,
+                previous_secret_expires_at: None,
+            },
+            WebhookEndpointUpdate::SecretRotation {
+                secret,
+                previous_secret,
+                previous_secret_expires_at,
+            } => Self {
+                url: None,
+                secret: Some(secret),
+                enabled_events: None,
+                is_active: None,
+                description: None,
+                last_modified_at,
+                previous_secret,
+                previous_secret_expires_at,
+            },
+        }
+    }
+}
@@ -0,0 +1,544 @@
+use std::{collections::HashMap, sync::Arc};
+
+use actix_web::{web, HttpRequest, Scope};
+use common_utils::id_type;
+use error_stack::report;
+use masking::{PeekInterface, Secret};
+use redis_interface as redis;
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    errors::{self, DeadLetterError},
+    metrics,
+    services::{self, log_and_return_error_response, Store},
+};
+
+/// Entries moved to the dead letter stream retain all the fields of the original drainer stream
+/// entry, and the fields below are added alongside them. All of them share a common prefix, so
+/// that they can be removed when the entry is replayed.
+const DEAD_LETTER_FIELD_PREFIX: &str = "dlq_";
+const ERROR_FIELD: &str = "dlq_error";
+const FAILED_AT_FIELD: &str = "dlq_failed_at";
+const RETRY_COUNT_FIELD: &str = "dlq_retry_count";
+const SOURCE_ENTRY_ID_FIELD: &str = "dlq_source_entry_id";
+
+const DEFAULT_LIST_LIMIT: u64 = 20;
+const MAX_LIST_LIMIT: u64 = 100;
+
+const ADMIN_API_KEY_HEADER: &str = "api-key";
+
+/// A replayed entry is applied after the entries which were added to the drainer stream since it
+/// failed. If it updates a row, it would overwrite the changes made to the row by those entries.
+const REPLAY_WARNING: &str = "The entry was appended to the end of the drainer stream. If it \
+    updates a row, it overwrites any change made to the row since the entry failed.";
+
+type Stores = HashMap<id_type::TenantId, Arc<Store>>;
+
+/// Key with which the requests to the dead letter endpoints are authenticated
+#[derive(Clone)]
+struct AdminApiKey(Secret<String>);
+
+impl Store {
+    /// Move an entry which could not be applied to the database to the dead letter stream of the
+    /// shard, along with the error encountered while applying it.
+    #[router_env::instrument(skip_all)]
+    pub(crate) async fn push_to_dead_letter_stream(
+        &self,
+        dead_letter_stream_name: &str,
+        entry_id: &str,
+        entry: HashMap<String, String>,
+        error: String,
+        retry_count: u8,
+    ) -> errors::DrainerResult<()> {
+        let fields = get_dead_letter_entry_fields(
+            entry_id,
+            entry,
+            error,
+            retry_count,
+            common_utils::date_time::now_unix_timestamp(),
+        );
+
+        self.redis_conn
+            .stream_append_entry(
+                &dead_letter_stream_name.into(),
+                &redis::RedisEntryId::AutoGeneratedID,
+                fields,
+            )
+            .await
+            .map_err(errors::DrainerError::from)?;
+
+        metrics::ENTRIES_MOVED_TO_DEAD_LETTER_STREAM.add(
+            1,
+            router_env::metric_attributes!(("stream", dead_letter_stream_name.to_owned())),
+        );
+
+        Ok(())
+    }
+}
+
+fn get_dead_letter_entry_fields(
+    entry_id: &str,
+    entry: HashMap<String, String>,
+    error: String,
+    retry_count: u8,
+    failed_at: i64,
+) -> Vec<(String, String)> {
+    entry
+        .into_iter()
+        .chain([
+            (ERROR_FIELD.to_string(), error),
+            (FAILED_AT_FIELD.to_string(), failed_at.to_string()),
+            (RETRY_COUNT_FIELD.to_string(), retry_count.to_string()),
+            (SOURCE_ENTRY_ID_FIELD.to_string(), entry_id.to_string()),
+        ])
+        .collect()
+}
+
+/// The fields of the original drainer stream entry, without the fields added to it when it was
+/// moved to the dead letter stream.
+fn get_replay_payload(fields: HashMap<String, String>) -> Vec<(String, String)> {
+    fields
+        .into_iter()
+        .filter(|(field, _)| !field.starts_with(DEAD_LETTER_FIELD_PREFIX))
+        .collect()
+}
+
+/// Start of the range of entries to be listed. The `(` prefix makes the start of the range
+/// exclusive.
+fn get_list_range_start(after: Option<String>) -> String {
+    after
+        .map(|entry_id| format!("({entry_id}"))
+        .unwrap_or_else(|| "-".to_string())
+}
+
+fn get_list_limit(limit: Option<u64>) -> u64 {
+    limit.unwrap_or(DEFAULT_LIST_LIMIT).min(MAX_LIST_LIMIT)
+}
+
+fn authenticate(
+    request: &HttpRequest,
+    admin_api_key: &AdminApiKey,
+) -> Result<(), error_stack::Report<DeadLetterError>> {
+    let request_admin_api_key = request
+        .headers()
+        .get(ADMIN_API_KEY_HEADER)
+        .and_then(|value| value.to_str().ok());
+
+    common_utils::fp_utils::when(
+        request_admin_api_key != Some(admin_api_key.0.peek().as_str()),
+        || Err(report!(DeadLetterError::Unauthorized)),
+    )
+}
+
+/// Endpoints to inspect and act upon the entries in the dead letter streams. These are meant to be
+/// used by operators, and require the admin API key of the drainer in the `api-key` header.
+pub struct DeadLetter;
+
+impl DeadLetter {
+    pub fn server(stores: Stores, admin_api_key: Secret<String>) -> Scope {
+        web::scope("dead_letter")
+            .app_data(web::Data::new(stores))
+            .app_data(web::Data::new(AdminApiKey(admin_api_key)))
+            .service(
+                web::resource("/{tenant_id}/{shard_index}")
+                    .route(web::get().to(list_dead_letter_entries)),
+            )
+            .service(
+                web::resource("/{tenant_id}/{shard_index}/{entry_id}")
+                    .route(web::delete().to(discard_dead_letter_entry)),
+            )
+            .service(
+                web::resource("/{tenant_id}/{shard_index}/{entry_id}/replay")
+                    .route(web::post().to(replay_dead_letter_entry)),
+            )
+    }
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct DeadLetterListQuery {
+    /// List the entries after the entry with this ID
+    pub after: Option<String>,
+    pub limit: Option<u64>,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct DeadLetterListResponse {
+    pub total_count: usize,
+    pub entries: Vec<DeadLetterEntry>,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct DeadLetterEntry {
+    pub entry_id: String,
+    pub source_entry_id: Option<String>,
+    pub error: Option<String>,
+    pub failed_at: Option<i64>,
+    pub retry_count: Option<u8>,
+    /// The fields of the original drainer stream entry
+    pub payload: HashMap<String, String>,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct DeadLetterEntryActionResponse {
+    pub entry_id: String,
+    pub status: DeadLetterEntryStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub warning: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DeadLetterEntryStatus {
+    Replayed,
+    Discarded,
+}
+
+impl From<(String, HashMap<String, String>)> for DeadLetterEntry {
+    fn from((entry_id, mut fields): (String, HashMap<String, String>)) -> Self {
+        let source_entry_id = fields.remove(SOURCE_ENTRY_ID_FIELD);
+        let error = fields.remove(ERROR_FIELD);
+        let failed_at = fields
+            .remove(FAILED_AT_FIELD)
+            .and_then(|failed_at| failed_at.parse().ok());
+        let retry_count = fields
+            .remove(RETRY_COUNT_FIELD)
+            .and_then(|retry_count| retry_count.parse().ok());
+
+        Self {
+            entry_id,
+            source_entry_id,
+            error,
+            failed_at,
+            retry_count,
+            payload: fields,
+        }
+    }
+}
+
+#[instrument(skip_all)]
+pub async fn list_dead_letter_entries(
+    request: HttpRequest,
+    stores: web::Data<Stores>,
+    admin_api_key: web::Data<AdminApiKey>,
+    path: web::Path<(id_type::TenantId, u8)>,
+    query: web::Query<DeadLetterListQuery>,
+) -> impl actix_web::Responder {
+    if let Err(error) = authenticate(&request, &admin_api_key) {
+        return log_and_return_error_response(error);
+    }
+    let (tenant_id, shard_index) = path.into_inner();
+
+    match list_dead_letter_entries_func(&stores, &tenant_id, shard_index, query.into_inner()).await
+    {
+        Ok(response) => json_response(&response),
+        Err(error) => log_and_return_error_response(error),
+    }
+}
+
+#[instrument(skip_all)]
+pub async fn replay_dead_letter_entry(
+    request: HttpRequest,
+    stores: web::Data<Stores>,
+    admin_api_key: web::Data<AdminApiKey>,
+    path: web::Path<(id_type::TenantId, u8, String)>,
+) -> impl actix_web::Responder {
+    if let Err(error) = authenticate(&request, &admin_api_key) {
+        return log_and_return_error_response(error);
+    }
+    let (tenant_id, shard_index, entry_id) = path.into_inner();
+
+    match replay_dead_letter_entry_func(&stores, &tenant_id, shard_index, entry_id).await {
+        Ok(response) => json_response(&response),
+        Err(error) => log_and_return_error_response(error),
+    }
+}
+
+#[instrument(skip_all)]
+pub async fn discard_dead_letter_entry(
+    request: HttpRequest,
+    stores: web::Data<Stores>,
+    admin_api_key: web::Data<AdminApiKey>,
+    path: web::Path<(id_type::TenantId, u8, String)>,
+) -> impl actix_web::Responder {
+    if let Err(error) = authenticate(&request, &admin_api_key) {
+        return log_and_return_error_response(error);
+    }
+    let (tenant_id, shard_index, entry_id) = path.into_inner();
+
+    match discard_dead_letter_entry_func(&stores, &tenant_id, shard_index, entry_id).await {
+        Ok(response) => json_response(&response),
+        Err(error) => log_and_return_error_response(error),
+    }
+}
+
+async fn list_dead_letter_entries_func(
+    stores: &Stores,
+    tenant_id: &id_type::TenantId,
+    shard_index: u8,
+    query: DeadLetterListQuery,
+) -> Result<DeadLetterListResponse, error_stack::Report<DeadLetterError>> {
+    let store = get_store(stores, tenant_id, shard_index)?;
+    let dead_letter_stream = store.get_dead_letter_stream_name(shard_index);
+
+    let start = get_list_range_start(query.after);
+    let limit = get_list_limit(query.limit);
+
+    let entries = store
+        .redis_conn
+        .stream_read_range(
+            &dead_letter_stream.as_str().into(),
+            &start,
+            "+",
+            Some(limit),
+        )
+        .await
+        .map_err(redis_error)?;
+
+    let total_count = store
+        .redis_conn
+        .stream_get_length(&dead_letter_stream.as_str().into())
+        .await
+        .map_err(redis_error)?;
+
+    Ok(DeadLetterListResponse {
+        total_count,
+        entries: entries.into_iter().map(DeadLetterEntry::from).collect(),
+    })
+}
+
+/// Append the original entry back to the drainer stream of the shard, and remove it from the
+/// dead letter stream. The entry is appended to the end of the drainer stream, and will be applied
+/// after any entry which is already present in the stream. The version of the row is not checked
+/// before the entry is applied, so the response carries a warning about stale updates.
+async fn replay_dead_letter_entry_func(
+    stores: &Stores,
+    tenant_id: &id_type::TenantId,
+    shard_index: u8,
+    entry_id: String,
+) -> Result<DeadLetterEntryActionResponse, error_stack::Report<DeadLetterError>> {
+    let store = get_store(stores, tenant_id, shard_index)?;
+    let dead_letter_stream = store.get_dead_letter_stream_name(shard_index);
+
+    let fields = find_dead_letter_entry(store, &dead_letter_stream, &entry_id).await?;
+    let payload = get_replay_payload(fields);
+
+    store
+        .redis_conn
+        .stream_append_entry(
+            &store.get_drainer_stream_name(shard_index).as_str().into(),
+            &redis::RedisEntryId::AutoGeneratedID,
+            payload,
+        )
+        .await
+        .map_err(redis_error)?;
+
+    store
+        .redis_conn
+        .stream_delete_entries(&dead_letter_stream.as_str().into(), entry_id.as_str())
+        .await
+        .map_err(redis_error)?;
+
+    logger::info!(%entry_id, dead_letter_stream, "Replayed dead letter entry");
+    metrics::DEAD_LETTER_ENTRIES_REPLAYED.add(1, &[]);
+
+    Ok(DeadLetterEntryActionResponse {
+        entry_id,
+        status: DeadLetterEntryStatus::Replayed,
+        warning: Some(REPLAY_WARNING.to_string()),
+    })
+}
+
+async fn discard_dead_letter_entry_func(
+    stores: &Stores,
+    tenant_id: &id_type::TenantId,
+    shard_index: u8,
+    entry_id: String,
+) -> Result<DeadLetterEntryActionResponse, error_stack::Report<DeadLetterError>> {
+    let store = get_store(stores, tenant_id, shard_index)?;
+    let dead_letter_stream = store.get_dead_letter_stream_name(shard_index);
+
+    let fields = find_dead_letter_entry(store, &dead_letter_stream, &entry_id).await?;
+
+    store
+        .redis_conn
+        .stream_delete_entries(&dead_letter_stream.as_str().into(), entry_id.as_str())
+        .await
+        .map_err(redis_error)?;
+
+    logger::info!(%entry_id, dead_letter_stream, ?fields, "Discarded dead letter entry");
+    metrics::DEAD_LETTER_ENTRIES_DISCARDED.add(1, &[]);
+
+    Ok(DeadLetterEntryActionResponse {
+        entry_id,
+        status: DeadLetterEntryStatus::Discarded,
+        warning: None,
+    })
+}
+
+fn get_store<'a>(
+    stores: &'a Stores,
+    tenant_id: &id_type::TenantId,
+    shard_index: u8,
+) -> Result<&'a Arc<Store>, error_stack::Report<DeadLetterError>> {
+    let store = stores.get(tenant_id).ok_or_else(|| {
+        report!(DeadLetterError::TenantNotFound {
+            tenant_id: tenant_id.get_string_repr().to_owned(),
+        })
+    })?;
+
+    common_utils::fp_utils::when(shard_index >= store.config.drainer_num_partitions, || {
+        Err(report!(DeadLetterError::InvalidShardIndex {
+            num_partitions: store.config.drainer_num_partitions,
+        }))
+    })?;
+
+    Ok(store)
+}
+
+async fn find_dead_letter_entry(
+    store: &Store,
+    dead_letter_stream: &str,
+    entry_id: &str,
+) -> Result<HashMap<String, String>, error_stack::Report<DeadLetterError>> {
+    store
+        .redis_conn
+        .stream_read_range(&dead_letter_stream.into(), entry_id, entry_id, Some(1))
+        .await
+        .map_err(redis_error)?
+        .into_iter()
+        .next()
+        .map(|(_, fields)| fields)
+        .ok_or_else(|| {
+            report!(DeadLetterError::EntryNotFound {
+                entry_id: entry_id.to_owned(),
+            })
+        })
+}
+
+fn redis_error(
+    error: error_stack::Report<redis::errors::RedisError>,
+) -> error_stack::Report<DeadLetterError> {
+    let message = error.to_string();
+    error.change_context(DeadLetterError::RedisError { message })
+}
+
+fn json_response<T: serde::Serialize>(response: &T) -> actix_web::HttpResponse {
+    services::http_response_json(
+        serde_json::to_string(response)
+            .map_err(|err| {
+                logger::error!(serialization_error=?err);
+            })
+            .unwrap_or_default(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_drainer_stream_entry() -> HashMap<String, String> {
+        HashMap::from([
+            ("request_id".to_string(), "request_1".to_string()),
+            ("global_id".to_string(), "global_1".to_string()),
+            ("pushed_at".to_string(), "1700000000".to_string()),
+            ("typed_sql".to_string(), "{}".to_string()),
+        ])
+    }
+
+    #[test]
+    fn test_dead_letter_entry_from_fields() {
+        let fields = get_dead_letter_entry_fields(
+            "1700000000000-0",
+            get_drainer_stream_entry(),
+            "DatabaseError".to_string(),
+            3,
+            1700000100,
+        );
+
+        let entry = DeadLetterEntry::from((
+            "1700000100000-0".to_string(),
+            fields.into_iter().collect::<HashMap<_, _>>(),
+        ));
+
+        assert_eq!(entry.entry_id, "1700000100000-0");
+        assert_eq!(entry.source_entry_id.as_deref(), Some("1700000000000-0"));
+        assert_eq!(entry.error.as_deref(), Some("DatabaseError"));
+        assert_eq!(entry.failed_at, Some(1700000100));
+        assert_eq!(entry.retry_count, Some(3));
+        assert_eq!(entry.payload, get_drainer_stream_entry());
+    }
+
+    #[test]
+    fn test_dead_letter_entry_with_invalid_fields() {
+        let fields = HashMap::from([
+            (FAILED_AT_FIELD.to_string(), "yesterday".to_string()),
+            (RETRY_COUNT_FIELD.to_string(), "-1".to_string()),
+        ]);
+
+        let entry = DeadLetterEntry::from(("1700000100000-0".to_string(), fields));
+
+        assert_eq!(entry.source_entry_id, None);
+        assert_eq!(entry.error, None);
+        assert_eq!(entry.failed_at, None);
+        assert_eq!(entry.retry_count, None);
+        assert!(entry.payload.is_empty());
+    }
+
+    #[test]
+    fn test_get_replay_payload() {
+        let fields = get_dead_letter_entry_fields(
+            "1700000000000-0",
+            get_drainer_stream_entry(),
+            "DatabaseError".to_string(),
+            3,
+            1700000100,
+        );
+
+        let payload = get_replay_payload(fields.into_iter().collect())
+            .into_iter()
+            .collect::<HashMap<_, _>>();
+
+        assert_eq!(payload, get_drainer_stream_entry());
+    }
+
+    #[test]
+    fn test_authenticate() {
+        let admin_api_key = AdminApiKey(Secret::new("admin_api_key".to_string()));
+        let get_request = |api_key: &str| {
+            actix_web::test::TestRequest::default()
+                .insert_header((ADMIN_API_KEY_HEADER, api_key))
+                .to_http_request()
+        };
+
+        assert!(authenticate(&get_request("admin_api_key"), &admin_api_key).is_ok());
+        assert!(authenticate(&get_request("other_api_key"), &admin_api_key).is_err());
+        assert!(authenticate(
+            &actix_web::test::TestRequest::default().to_http_request(),
+            &admin_api_key
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_get_list_range_start() {
+        assert_eq!(get_list_range_start(None), "-");
+        assert_eq!(
+            get_list_range_start(Some("1700000100000-0".to_string())),
+            "(1700000100000-0"
+        );
+    }
+
+    #[test]
+    fn test_get_list_limit() {
+        let cases = [
+            (None, DEFAULT_LIST_LIMIT),
+            (Some(5), 5),
+            (Some(MAX_LIST_LIMIT), MAX_LIST_LIMIT),
+            (Some(MAX_LIST_LIMIT + 1), MAX_LIST_LIMIT),
+        ];
+
+        for (limit, expected) in cases {
+            assert_eq!(get_list_limit(limit), expected, "{limit:?}");
+        }
+    }
+}
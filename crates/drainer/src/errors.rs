@@ -27,6 +27,20 @@ pub enum HealthCheckError {
     RedisError { message: String },
 }
 
+#[derive(Debug, Error, Clone, serde::Serialize)]
+pub enum DeadLetterError {
+    #[error("Admin API key is missing or invalid")]
+    Unauthorized,
+    #[error("Tenant `{tenant_id}` is not configured")]
+    TenantNotFound { tenant_id: String },
+    #[error("Shard index must be less than {num_partitions}")]
+    InvalidShardIndex { num_partitions: u8 },
+    #[error("Dead letter entry `{entry_id}` was not found")]
+    EntryNotFound { entry_id: String },
+    #[error("Error during redis operation on the dead letter stream: {message}")]
+    RedisError { message: String },
+}
+
 impl From<std::io::Error> for DrainerError {
     fn from(err: std::io::Error) -> Self {
         Self::IoError(err)
@@ -56,3 +70,16 @@ impl actix_web::ResponseError for HealthCheckError {
         }
     }
 }
+
+impl actix_web::ResponseError for DeadLetterError {
+    fn status_code(&self) -> reqwest::StatusCode {
+        use reqwest::StatusCode;
+
+        match self {
+            Self::Unauthorized => StatusCode::UNAUTHORIZED,
+            Self::TenantNotFound { .. } | Self::EntryNotFound { .. } => StatusCode::NOT_FOUND,
+            Self::InvalidShardIndex { .. } => StatusCode::BAD_REQUEST,
+            Self::RedisError { .. } => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
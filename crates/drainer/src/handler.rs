@@ -11,7 +11,7 @@ use tokio::{
 };
 
 use crate::{
-    errors, instrument, logger, metrics, query::ExecuteQuery, tracing, utils, DrainerSettings,
+    errors, instrument, kv, logger, metrics, query::ExecuteQuery, tracing, utils, DrainerSettings,
     Store, StreamData,
 };
 
@@ -180,11 +180,13 @@ async fn drainer_handler(
     active_tasks.fetch_add(1, atomic::Ordering::Release);
 
     let stream_name = store.get_drainer_stream_name(stream_index);
+    let dead_letter_stream_name = store.get_dead_letter_stream_name(stream_index);
 
     let drainer_result = Box::pin(drainer(
         store.clone(),
         max_read_count,
         stream_name.as_str(),
+        dead_letter_stream_name.as_str(),
         jobs_picked,
     ))
     .await;
@@ -205,6 +207,7 @@ async fn drainer(
     store: Arc<Store>,
     max_read_count: u64,
     stream_name: &str,
+    dead_letter_stream_name: &str,
     jobs_picked: Arc<atomic::AtomicU8>,
 ) -> errors::DrainerResult<()> {
     let stream_read = match store.read_from_stream(stream_name, max_read_count).await {
@@ -245,7 +248,7 @@ async fn drainer(
     let mut last_processed_id = String::new();
 
     for (entry_id, entry) in entries.clone() {
-        let data = match StreamData::from_hashmap(entry) {
+        let data = match StreamData::from_hashmap(entry.clone()) {
             Ok(data) => data,
             Err(err) => {
                 logger::error!(operation = "deserialization", err=?err);
@@ -263,7 +266,10 @@ async fn drainer(
         tracing::Span::current().record("global_id", data.global_id);
         tracing::Span::current().record("session_id", &session_id);
 
-        match data.typed_sql.execute_query(&store, data.pushed_at).await {
+        let (query_result, retry_count) =
+            execute_query_with_retries(&store, &entry, data.typed_sql, data.pushed_at).await;
+
+        match query_result {
             Ok(_) => {
                 last_processed_id = entry_id;
             }
@@ -273,8 +279,37 @@ async fn drainer(
                 diesel_models::errors::DatabaseError::UniqueViolation => {
                     last_processed_id = entry_id;
                 }
-                // break from the loop in case of an error in query
-                _ => break,
+                // break from the loop in case the database could not be reached, so that the
+                // entry and the ones after it are applied in order once it is available again
+                _ if !is_deterministic_failure(&err) => break,
+                // Move the entry to the dead letter stream once the retries are exhausted, so
+                // that it can be inspected and replayed later instead of blocking the stream
+                _ => match store
+                    .push_to_dead_letter_stream(
+                        dead_letter_stream_name,
+                        &entry_id,
+                        entry,
+                        format!("{err:?}"),
+                        retry_count,
+                    )
+                    .await
+                {
+                    Ok(()) => {
+                        logger::error!(
+                            %entry_id,
+                            dead_letter_stream_name,
+                            retry_count,
+                            "Moved entry to the dead letter stream"
+                        );
+                        last_processed_id = entry_id;
+                    }
+                    // break from the loop if the entry could not be moved to the dead letter
+                    // stream, so that it is retained in the drainer stream
+                    Err(error) => {
+                        logger::error!(operation = "dead_letter", ?error);
+                        break;
+                    }
+                },
             },
         }
     }
@@ -297,3 +332,145 @@ async fn drainer(
 
     Ok(())
 }
+
+/// Execute the query in the stream entry, retrying up to the configured number of times in case
+/// of errors other than unique constraint violations. Returns the result of the last attempt along
+/// with the number of retries made.
+async fn execute_query_with_retries(
+    store: &Arc<Store>,
+    entry: &HashMap<String, String>,
+    mut typed_sql: kv::DBOperation,
+    pushed_at: i64,
+) -> (
+    common_utils::errors::CustomResult<(), diesel_models::errors::DatabaseError>,
+    u8,
+) {
+    let retry_interval = Duration::from_millis(store.config.drainer_query_retry_interval.into());
+    let mut retry_count = 0;
+
+    loop {
+        let result = typed_sql.execute_query(store, pushed_at).await;
+
+        let should_retry = result.as_ref().is_err_and(|error| {
+            !matches!(
+                error.current_context(),
+                diesel_models::errors::DatabaseError::UniqueViolation
+            )
+        }) && retry_count < store.config.drainer_max_query_retries;
+        if !should_retry {
+            return (result, retry_count);
+        }
+
+        // The query is consumed on execution, so it is parsed again from the stream entry
+        typed_sql = match StreamData::from_hashmap(entry.clone()) {
+            Ok(data) => data.typed_sql,
+            Err(_) => return (result, retry_count),
+        };
+
+        retry_count += 1;
+        metrics::QUERY_EXECUTION_RETRIES.add(1, &[]);
+        time::sleep(retry_interval).await;
+    }
+}
+
+/// Whether the query failed because of the data in the entry, such that it would fail again
+/// whenever it is executed. Errors in reaching the database, and the errors which cannot be
+/// identified, are not considered as such.
+fn is_deterministic_failure(
+    error: &error_stack::Report<diesel_models::errors::DatabaseError>,
+) -> bool {
+    use diesel::result::{DatabaseErrorKind, Error as DieselError};
+    use diesel_models::errors::DatabaseError;
+
+    match error.current_context() {
+        DatabaseError::UniqueViolation
+        | DatabaseError::NotFound
+        | DatabaseError::NoFieldsToUpdate
+        | DatabaseError::QueryGenerationFailed => true,
+        DatabaseError::DatabaseConnectionError => false,
+        DatabaseError::Others => {
+            error
+                .downcast_ref::<DieselError>()
+                .is_some_and(|error| match error {
+                    DieselError::DatabaseError(
+                        DatabaseErrorKind::ForeignKeyViolation
+                        | DatabaseErrorKind::NotNullViolation
+                        | DatabaseErrorKind::CheckViolation,
+                        _,
+                    )
+                    | DieselError::NotFound
+                    | DieselError::QueryBuilderError(_)
+                    | DieselError::SerializationError(_)
+                    | DieselError::DeserializationError(_) => true,
+                    _ => false,
+                })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use diesel::result::{DatabaseErrorKind, Error as DieselError};
+    use diesel_models::errors::DatabaseError;
+    use error_stack::report;
+
+    use super::*;
+
+    fn database_error(kind: DatabaseErrorKind) -> DieselError {
+        DieselError::DatabaseError(kind, Box::new(String::from("error")))
+    }
+
+    #[test]
+    fn test_is_deterministic_failure() {
+        let cases = [
+            (report!(DatabaseError::NotFound), true),
+            (report!(DatabaseError::NoFieldsToUpdate), true),
+            (report!(DatabaseError::QueryGenerationFailed), true),
+            (report!(DatabaseError::DatabaseConnectionError), false),
+            (report!(DatabaseError::Others), false),
+            (
+                report!(database_error(DatabaseErrorKind::ForeignKeyViolation))
+                    .change_context(DatabaseError::Others),
+                true,
+            ),
+            (
+                report!(database_error(DatabaseErrorKind::NotNullViolation))
+                    .change_context(DatabaseError::Others),
+                true,
+            ),
+            (
+                report!(database_error(DatabaseErrorKind::CheckViolation))
+                    .change_context(DatabaseError::Others),
+                true,
+            ),
+            (
+                report!(database_error(DatabaseErrorKind::ClosedConnection))
+                    .change_context(DatabaseError::Others),
+                false,
+            ),
+            (
+                report!(database_error(DatabaseErrorKind::UnableToSendCommand))
+                    .change_context(DatabaseError::Others),
+                false,
+            ),
+            (
+                report!(database_error(DatabaseErrorKind::SerializationFailure))
+                    .change_context(DatabaseError::Others),
+                false,
+            ),
+            (
+                report!(database_error(DatabaseErrorKind::Unknown))
+                    .change_context(DatabaseError::Others),
+                false,
+            ),
+            (
+                report!(DieselError::NotFound).change_context(DatabaseError::Others),
+                true,
+            ),
+        ];
+
+        for (error, expected) in cases {
+            assert_eq!(is_deterministic_failure(&error), expected, "{error:?}");
+        }
+    }
+}
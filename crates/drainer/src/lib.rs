@@ -1,4 +1,5 @@
 mod connection;
+mod dead_letter;
 pub mod errors;
 mod handler;
 mod health_check;
@@ -14,7 +15,7 @@ use std::{collections::HashMap, sync::Arc};
 mod secrets_transformers;
 
 use actix_web::dev::Server;
-use common_utils::{id_type, signals::get_allowed_signals};
+use common_utils::{ext_traits::ConfigExt, id_type, signals::get_allowed_signals};
 use diesel_models::kv;
 use error_stack::ResultExt;
 use hyperswitch_interfaces::secrets_interface::secret_state::RawSecret;
@@ -65,8 +66,23 @@ pub async fn start_web_server(
     stores: HashMap<id_type::TenantId, Arc<Store>>,
 ) -> Result<Server, errors::DrainerError> {
     let server = conf.server.clone();
+    let admin_api_key = conf.secrets.get_inner().admin_api_key.clone();
+    let is_dead_letter_server_enabled = !admin_api_key.is_default_or_empty();
+    if !is_dead_letter_server_enabled {
+        logger::info!("Dead letter endpoints are disabled as the admin API key is not configured");
+    }
+
     let web_server = actix_web::HttpServer::new(move || {
-        actix_web::App::new().service(health_check::Health::server(conf.clone(), stores.clone()))
+        actix_web::App::new()
+            .service(health_check::Health::server(conf.clone(), stores.clone()))
+            .configure(|config| {
+                if is_dead_letter_server_enabled {
+                    config.service(dead_letter::DeadLetter::server(
+                        stores.clone(),
+                        admin_api_key.clone(),
+                    ));
+                }
+            })
     })
     .bind((server.host.as_str(), server.port))?
     .run();
@@ -1,11 +1,11 @@
-use common_utils::errors::CustomResult;
+use common_utils::{errors::CustomResult, ext_traits::ConfigExt};
 use hyperswitch_interfaces::secrets_interface::{
     secret_handler::SecretsHandler,
     secret_state::{RawSecret, SecretStateContainer, SecuredSecret},
     SecretManagementInterface, SecretsManagementError,
 };
 
-use crate::settings::{Database, Settings};
+use crate::settings::{Database, Secrets, Settings};
 
 #[async_trait::async_trait]
 impl SecretsHandler for Database {
@@ -25,6 +25,27 @@ impl SecretsHandler for Database {
     }
 }
 
+#[async_trait::async_trait]
+impl SecretsHandler for Secrets {
+    async fn convert_to_raw_secret(
+        value: SecretStateContainer<Self, SecuredSecret>,
+        secret_management_client: &dyn SecretManagementInterface,
+    ) -> CustomResult<SecretStateContainer<Self, RawSecret>, SecretsManagementError> {
+        let secrets = value.get_inner();
+
+        // The admin API key is optional, and there is no secret to fetch if it is not configured
+        if secrets.admin_api_key.is_default_or_empty() {
+            return Ok(value.transition_state(|secrets| secrets));
+        }
+
+        let admin_api_key = secret_management_client
+            .get_secret(secrets.admin_api_key.clone())
+            .await?;
+
+        Ok(value.transition_state(|_| Self { admin_api_key }))
+    }
+}
+
 /// # Panics
 ///
 /// Will panic even if fetching raw secret fails for at least one config value
@@ -37,6 +58,11 @@ pub async fn fetch_raw_secrets(
         .await
         .expect("Failed to decrypt database password");
 
+    #[allow(clippy::expect_used)]
+    let secrets = Secrets::convert_to_raw_secret(conf.secrets, secret_management_client)
+        .await
+        .expect("Failed to decrypt secrets");
+
     Settings {
         server: conf.server,
         master_database: database,
@@ -46,5 +72,6 @@ pub async fn fetch_raw_secrets(
         encryption_management: conf.encryption_management,
         secrets_management: conf.secrets_management,
         multitenancy: conf.multitenancy,
+        secrets,
     }
 }
@@ -74,6 +74,15 @@ pub struct Settings<S: SecretState> {
     pub encryption_management: EncryptionManagementConfig,
     pub secrets_management: SecretsManagementConfig,
     pub multitenancy: Multitenancy,
+    pub secrets: SecretStateContainer<Secrets, S>,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct Secrets {
+    /// Key with which the requests to the dead letter endpoints are authenticated. The endpoints
+    /// are not served if it is not configured.
+    pub admin_api_key: Secret<String>,
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -114,6 +123,9 @@ pub struct DrainerSettings {
     pub max_read_count: u64,
     pub shutdown_interval: u32, // in milliseconds
     pub loop_interval: u32,     // in milliseconds
+    pub max_query_retries: u8,
+    pub query_retry_interval: u32, // in milliseconds
+    pub dead_letter_stream_name: String,
 }
 
 #[derive(Debug, Deserialize, Clone, Default)]
@@ -224,6 +236,9 @@ impl Default for DrainerSettings {
             max_read_count: 100,
             shutdown_interval: 1000, // in milliseconds
             loop_interval: 100,      // in milliseconds
+            max_query_retries: 3,
+            query_retry_interval: 100, // in milliseconds
+            dead_letter_stream_name: "DRAINER_DEAD_LETTER_STREAM".into(),
         }
     }
 }
@@ -274,6 +289,12 @@ impl DrainerSettings {
             Err(errors::DrainerError::ConfigParsingError(
                 "drainer stream name must not be empty".into(),
             ))
+        })?;
+
+        common_utils::fp_utils::when(self.dead_letter_stream_name.is_default_or_empty(), || {
+            Err(errors::DrainerError::ConfigParsingError(
+                "drainer dead letter stream name must not be empty".into(),
+            ))
         })
     }
 }
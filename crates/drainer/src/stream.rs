@@ -15,6 +15,20 @@ impl Store {
         format!("{{{}}}_{}", shard_key, self.config.drainer_stream_name,)
     }
 
+    #[inline(always)]
+    pub fn dead_letter_stream(&self, shard_key: &str) -> String {
+        // Example: {shard_5}_drainer_dead_letter_stream
+        format!(
+            "{{{}}}_{}",
+            shard_key, self.config.drainer_dead_letter_stream_name,
+        )
+    }
+
+    #[inline(always)]
+    pub(crate) fn get_dead_letter_stream_name(&self, stream_index: u8) -> String {
+        self.dead_letter_stream(format!("shard_{stream_index}").as_str())
+    }
+
     #[inline(always)]
     pub(crate) fn get_stream_key_flag(&self, stream_index: u8) -> String {
         format!("{}_in_use", self.get_drainer_stream_name(stream_index))
@@ -80,12 +80,36 @@ where
         }
     }
 
+    fn eval_condition(
+        condition: &ast::Condition,
+        ctx: &types::Context,
+    ) -> Result<bool, types::InterpreterError> {
+        match condition {
+            ast::Condition::Comparison(comparison) => Self::eval_comparison(comparison, ctx),
+            ast::Condition::Group(ast::ConditionGroup::Any(conditions)) => {
+                for condition in conditions {
+                    if Self::eval_condition(condition, ctx)? {
+                        return Ok(true);
+                    }
+                }
+
+                Ok(false)
+            }
+            ast::Condition::Group(ast::ConditionGroup::All(conditions)) => {
+                Self::eval_if_condition(conditions, ctx)
+            }
+            ast::Condition::Group(ast::ConditionGroup::Not(condition)) => {
+                Ok(!Self::eval_condition(condition, ctx)?)
+            }
+        }
+    }
+
     fn eval_if_condition(
-        condition: &ast::IfCondition,
+        conditions: &[ast::Condition],
         ctx: &types::Context,
     ) -> Result<bool, types::InterpreterError> {
-        for comparison in condition {
-            let res = Self::eval_comparison(comparison, ctx)?;
+        for condition in conditions {
+            let res = Self::eval_condition(condition, ctx)?;
 
             if !res {
                 return Ok(false);
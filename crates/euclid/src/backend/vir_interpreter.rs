@@ -34,13 +34,22 @@ where
         }
     }
 
+    fn eval_condition(cond: &vir::ValuedCondition, ctx: &types::Context) -> bool {
+        match cond {
+            vir::ValuedCondition::Comparison(comp) => Self::eval_comparison(comp, ctx),
+            vir::ValuedCondition::Any(conds) => conds.iter().any(|c| Self::eval_condition(c, ctx)),
+            vir::ValuedCondition::All(conds) => Self::eval_if_condition(conds, ctx),
+            vir::ValuedCondition::Not(cond) => !Self::eval_condition(cond, ctx),
+        }
+    }
+
     #[inline]
-    fn eval_condition(cond: &vir::ValuedIfCondition, ctx: &types::Context) -> bool {
-        cond.iter().all(|comp| Self::eval_comparison(comp, ctx))
+    fn eval_if_condition(conds: &[vir::ValuedCondition], ctx: &types::Context) -> bool {
+        conds.iter().all(|cond| Self::eval_condition(cond, ctx))
     }
 
     fn eval_statement(stmt: &vir::ValuedIfStatement, ctx: &types::Context) -> bool {
-        if Self::eval_condition(&stmt.condition, ctx) {
+        if Self::eval_if_condition(&stmt.condition, ctx) {
             {
                 stmt.nested.as_ref().map_or(true, |nested_stmts| {
                     nested_stmts.iter().any(|s| Self::eval_statement(s, ctx))
@@ -629,4 +638,56 @@ mod test {
             "rule_1"
         );
     }
+
+    #[test]
+    fn test_condition_groups() {
+        let program_str = r#"
+        default: ["stripe", "adyen"]
+
+        rule_1: ["stripe"]
+        {
+           any(pay_later = klarna, amount > 1000) & not(currency = GBP)
+        }
+        "#;
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        let inp = inputs::BackendInput {
+            metadata: None,
+            payment: inputs::PaymentInput {
+                amount: MinorUnit::new(32),
+                card_bin: None,
+                currency: enums::Currency::USD,
+                authentication_type: Some(enums::AuthenticationType::NoThreeDs),
+                capture_method: Some(enums::CaptureMethod::Automatic),
+                business_country: Some(enums::Country::UnitedStatesOfAmerica),
+                billing_country: Some(enums::Country::France),
+                business_label: None,
+                setup_future_usage: None,
+            },
+            payment_method: inputs::PaymentMethodInput {
+                payment_method: Some(enums::PaymentMethod::PayLater),
+                payment_method_type: Some(enums::PaymentMethodType::Affirm),
+                card_network: None,
+            },
+            mandate: inputs::MandateData {
+                mandate_acceptance_type: None,
+                mandate_type: None,
+                payment_type: None,
+            },
+        };
+        let mut inp_large_amount = inp.clone();
+        inp_large_amount.payment.amount = MinorUnit::new(1500);
+        let mut inp_excluded_currency = inp_large_amount.clone();
+        inp_excluded_currency.payment.currency = enums::Currency::GBP;
+
+        let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
+        let result = backend.execute(inp).expect("Execution");
+        let result_large_amount = backend.execute(inp_large_amount).expect("Execution");
+        let result_excluded_currency = backend.execute(inp_excluded_currency).expect("Execution");
+        assert!(result.rule_name.is_none());
+        assert_eq!(
+            result_large_amount.rule_name.expect("Rule Name").as_str(),
+            "rule_1"
+        );
+        assert!(result_excluded_currency.rule_name.is_none());
+    }
 }
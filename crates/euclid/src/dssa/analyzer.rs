@@ -227,11 +227,13 @@ pub fn analyze<O: EuclidAnalysable + EuclidDirFilter>(
     let dir_program = ast::lowering::lower_program(program)?;
 
     let selection_data = state_machine::make_connector_selection_data(&dir_program);
-    let mut ctx_manager = state_machine::AnalysisContextManager::new(&dir_program, &selection_data);
-    while let Some(ctx) = ctx_manager.advance().map_err(|err| types::AnalysisError {
+    let state_machine_error = |err| types::AnalysisError {
         metadata: Default::default(),
         error_type: types::AnalysisErrorType::StateMachine(err),
-    })? {
+    };
+    let mut ctx_manager = state_machine::AnalysisContextManager::new(&dir_program, &selection_data)
+        .map_err(state_machine_error)?;
+    while let Some(ctx) = ctx_manager.advance().map_err(state_machine_error)? {
         perform_context_analyses(ctx, knowledge_graph.unwrap_or(&truth::ANALYSIS_GRAPH))?;
     }
 
@@ -378,6 +380,68 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_conflicting_assertion_in_condition_group_detection() {
+        let program_str = r#"
+            default: ["stripe"]
+
+            rule_1: ["adyen"]
+            {
+                payment_method = wallet & any(capture_method = manual, payment_method = card)
+            }
+        "#;
+
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        let analysis_result = analyze(program, None);
+
+        if let Err(types::AnalysisError {
+            error_type: types::AnalysisErrorType::ConflictingAssertions { key, values },
+            ..
+        }) = analysis_result
+        {
+            assert!(
+                matches!(key.kind, dir::DirKeyKind::PaymentMethod),
+                "Key should be payment_method"
+            );
+            let values: Vec<dir::DirValue> = values.into_iter().map(|v| v.value).collect();
+            assert!(
+                values.contains(&dirval!(PaymentMethod = Card)),
+                "Condition should include payment_method = card"
+            );
+        } else {
+            panic!("Did not receive conflicting assertions error");
+        }
+    }
+
+    #[test]
+    fn test_negated_condition_group_detection() {
+        let program_str = r#"
+            default: ["stripe"]
+
+            rule_1: ["adyen"]
+            {
+                payment_method = wallet & not(payment_method = wallet)
+            }
+        "#;
+
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        let analysis_result = analyze(program, None);
+
+        if let Err(types::AnalysisError {
+            error_type: types::AnalysisErrorType::NegatedAssertion { value, .. },
+            ..
+        }) = analysis_result
+        {
+            assert_eq!(
+                value,
+                dirval!(PaymentMethod = Wallet),
+                "Expected to catch payment_method = wallet as conflict"
+            );
+        } else {
+            panic!("Expected negated assertion error");
+        }
+    }
+
     #[test]
     fn test_negation_graph_analysis() {
         let graph = knowledge! {
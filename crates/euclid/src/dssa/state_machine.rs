@@ -1,17 +1,88 @@
 use super::types::EuclidAnalysable;
-use crate::{dssa::types, frontend::dir, types::Metadata};
+use crate::{
+    dssa::types,
+    frontend::{dir, dnf},
+    types::Metadata,
+};
 
 #[derive(Debug, Clone, serde::Serialize, thiserror::Error)]
 #[serde(tag = "type", content = "info", rename_all = "snake_case")]
 pub enum StateMachineError {
     #[error("Index out of bounds: {0}")]
     IndexOutOfBounds(&'static str),
+    #[error(transparent)]
+    TooManyAlternatives(dnf::TooManyAlternatives),
+}
+
+/// A comparison in a condition that has been expanded into its disjunctive normal form. The logic
+/// of the comparison is inverted when it is nested within an odd number of `Not` groups.
+#[derive(Debug, Clone)]
+pub struct ExpandedComparison<'a> {
+    values: &'a [dir::DirValue],
+    logic: dir::DirComparisonLogic,
+    metadata: &'a Metadata,
+}
+
+impl<'a> dnf::Expandable<'a> for dir::DirCondition {
+    type Literal = ExpandedComparison<'a>;
+
+    fn node(&'a self) -> dnf::ConditionNode<'a, Self, ExpandedComparison<'a>> {
+        match self {
+            Self::Comparison(comparison) => dnf::ConditionNode::Literal(ExpandedComparison {
+                values: &comparison.values,
+                logic: comparison.logic.clone(),
+                metadata: &comparison.metadata,
+            }),
+            Self::Any(conditions) => dnf::ConditionNode::Any(conditions),
+            Self::All(conditions) => dnf::ConditionNode::All(conditions),
+            Self::Not(condition) => dnf::ConditionNode::Not(condition),
+        }
+    }
+
+    fn negate(literal: ExpandedComparison<'a>) -> Vec<ExpandedComparison<'a>> {
+        let logic = match literal.logic {
+            dir::DirComparisonLogic::PositiveDisjunction => {
+                dir::DirComparisonLogic::NegativeConjunction
+            }
+            dir::DirComparisonLogic::NegativeConjunction => {
+                dir::DirComparisonLogic::PositiveDisjunction
+            }
+        };
+
+        vec![ExpandedComparison { logic, ..literal }]
+    }
+}
+
+/// An if statement whose condition is a conjunction of comparisons. An if statement having `Any`
+/// groups in its condition is analysed as multiple such statements, one for each alternative.
+#[derive(Debug, Clone)]
+struct ConjunctiveIfStatement<'a> {
+    condition: Vec<ExpandedComparison<'a>>,
+    nested: Option<&'a [dir::DirIfStatement]>,
+}
+
+fn expand_if_statements(
+    stmts: &[dir::DirIfStatement],
+) -> Result<Vec<ConjunctiveIfStatement<'_>>, StateMachineError> {
+    stmts.iter().try_fold(Vec::new(), |mut expanded, stmt| {
+        let alternatives =
+            dnf::expand_all(&stmt.condition).map_err(StateMachineError::TooManyAlternatives)?;
+        expanded.extend(
+            alternatives
+                .into_iter()
+                .map(|condition| ConjunctiveIfStatement {
+                    condition,
+                    nested: stmt.nested.as_deref(),
+                }),
+        );
+        Ok(expanded)
+    })
 }
 
 #[derive(Debug)]
 struct ComparisonStateMachine<'a> {
     values: &'a [dir::DirValue],
-    logic: &'a dir::DirComparisonLogic,
+    logic: dir::DirComparisonLogic,
     metadata: &'a Metadata,
     count: usize,
     ctx_idx: usize,
@@ -84,15 +155,15 @@ struct ConditionStateMachine<'a> {
 }
 
 impl<'a> ConditionStateMachine<'a> {
-    fn new(condition: &'a [dir::DirComparison], start_idx: usize) -> Self {
+    fn new(condition: &[ExpandedComparison<'a>], start_idx: usize) -> Self {
         let mut machines = Vec::<ComparisonStateMachine<'a>>::with_capacity(condition.len());
 
         let mut machine_idx = start_idx;
         for cond in condition {
             let machine = ComparisonStateMachine {
-                values: &cond.values,
-                logic: &cond.logic,
-                metadata: &cond.metadata,
+                values: cond.values,
+                logic: cond.logic.clone(),
+                metadata: cond.metadata,
                 count: 0,
                 ctx_idx: machine_idx,
             };
@@ -152,23 +223,26 @@ impl<'a> ConditionStateMachine<'a> {
 #[derive(Debug)]
 struct IfStmtStateMachine<'a> {
     condition_machine: ConditionStateMachine<'a>,
-    nested: Vec<&'a dir::DirIfStatement>,
+    nested: Vec<ConjunctiveIfStatement<'a>>,
     nested_idx: usize,
 }
 
 impl<'a> IfStmtStateMachine<'a> {
-    fn new(stmt: &'a dir::DirIfStatement, ctx_start_idx: usize) -> Self {
+    fn new(
+        stmt: &ConjunctiveIfStatement<'a>,
+        ctx_start_idx: usize,
+    ) -> Result<Self, StateMachineError> {
         let condition_machine = ConditionStateMachine::new(&stmt.condition, ctx_start_idx);
-        let nested: Vec<&'a dir::DirIfStatement> = match &stmt.nested {
+        let nested: Vec<ConjunctiveIfStatement<'a>> = match stmt.nested {
             None => Vec::new(),
-            Some(nested_stmts) => nested_stmts.iter().collect(),
+            Some(nested_stmts) => expand_if_statements(nested_stmts)?,
         };
 
-        Self {
+        Ok(Self {
             condition_machine,
             nested,
             nested_idx: 0,
-        }
+        })
     }
 
     fn init(
@@ -176,10 +250,10 @@ impl<'a> IfStmtStateMachine<'a> {
         context: &mut types::ConjunctiveContext<'a>,
     ) -> Result<Option<Self>, StateMachineError> {
         self.condition_machine.init(context)?;
-        Ok(self
-            .nested
+        self.nested
             .first()
-            .map(|nested| Self::new(nested, self.condition_machine.get_next_ctx_idx())))
+            .map(|nested| Self::new(nested, self.condition_machine.get_next_ctx_idx()))
+            .transpose()
     }
 
     #[inline]
@@ -211,14 +285,15 @@ impl<'a> IfStmtStateMachine<'a> {
             Ok(None)
         } else {
             self.nested_idx = (self.nested_idx + 1) % self.nested.len();
-            Ok(Some(Self::new(
+            Self::new(
                 self.nested
                     .get(self.nested_idx)
                     .ok_or(StateMachineError::IndexOutOfBounds(
                         "in IfStmtStateMachine while advancing",
                     ))?,
                 self.condition_machine.get_next_ctx_idx(),
-            )))
+            )
+            .map(Some)
         }
     }
 }
@@ -235,23 +310,19 @@ impl<'a> RuleStateMachine<'a> {
     fn new<O>(
         rule: &'a dir::DirRule<O>,
         connector_selection_data: &'a [(dir::DirValue, Metadata)],
-    ) -> Self {
-        let mut if_stmt_machines: Vec<IfStmtStateMachine<'a>> =
-            Vec::with_capacity(rule.statements.len());
-
-        for stmt in rule.statements.iter().rev() {
-            if_stmt_machines.push(IfStmtStateMachine::new(
-                stmt,
-                connector_selection_data.len(),
-            ));
-        }
+    ) -> Result<Self, StateMachineError> {
+        let if_stmt_machines: Vec<IfStmtStateMachine<'a>> = expand_if_statements(&rule.statements)?
+            .iter()
+            .rev()
+            .map(|stmt| IfStmtStateMachine::new(stmt, connector_selection_data.len()))
+            .collect::<Result<_, _>>()?;
 
-        Self {
+        Ok(Self {
             connector_selection_data,
             connectors_added: false,
             if_stmt_machines,
             running_stack: Vec::new(),
-        }
+        })
     }
 
     fn is_finished(&self) -> bool {
@@ -345,12 +416,12 @@ impl<'a> RuleContextManager<'a> {
     pub fn new<O>(
         rule: &'a dir::DirRule<O>,
         connector_selection_data: &'a [(dir::DirValue, Metadata)],
-    ) -> Self {
-        Self {
+    ) -> Result<Self, StateMachineError> {
+        Ok(Self {
             context: Vec::new(),
-            machine: RuleStateMachine::new(rule, connector_selection_data),
+            machine: RuleStateMachine::new(rule, connector_selection_data)?,
             init: false,
-        }
+        })
     }
 
     pub fn advance(&mut self) -> Result<Option<&types::ConjunctiveContext<'a>>, StateMachineError> {
@@ -403,7 +474,7 @@ impl<'a> ProgramStateMachine<'a> {
     pub fn new<O>(
         program: &'a dir::DirProgram<O>,
         connector_selection_data: &'a [Vec<(dir::DirValue, Metadata)>],
-    ) -> Self {
+    ) -> Result<Self, StateMachineError> {
         let mut rule_machines: Vec<RuleStateMachine<'a>> = program
             .rules
             .iter()
@@ -412,13 +483,13 @@ impl<'a> ProgramStateMachine<'a> {
             .map(|(rule, connector_selection_data)| {
                 RuleStateMachine::new(rule, connector_selection_data)
             })
-            .collect();
+            .collect::<Result<_, _>>()?;
 
-        Self {
+        Ok(Self {
             current_rule_machine: rule_machines.pop(),
             rule_machines,
             is_init: false,
-        }
+        })
     }
 
     pub fn is_finished(&self) -> bool {
@@ -474,15 +545,15 @@ impl<'a> AnalysisContextManager<'a> {
     pub fn new<O>(
         program: &'a dir::DirProgram<O>,
         connector_selection_data: &'a [Vec<(dir::DirValue, Metadata)>],
-    ) -> Self {
-        let machine = ProgramStateMachine::new(program, connector_selection_data);
+    ) -> Result<Self, StateMachineError> {
+        let machine = ProgramStateMachine::new(program, connector_selection_data)?;
         let context: types::ConjunctiveContext<'a> = Vec::new();
 
-        Self {
+        Ok(Self {
             context,
             machine,
             init: false,
-        }
+        })
     }
 
     pub fn advance(&mut self) -> Result<Option<&types::ConjunctiveContext<'a>>, StateMachineError> {
@@ -554,7 +625,8 @@ mod tests {
         let lowered = ast::lowering::lower_program(program).expect("Lowering");
 
         let selection_data = make_connector_selection_data(&lowered);
-        let mut state_machine = ProgramStateMachine::new(&lowered, &selection_data);
+        let mut state_machine =
+            ProgramStateMachine::new(&lowered, &selection_data).expect("State machine");
         let mut ctx: types::ConjunctiveContext<'_> = Vec::new();
         state_machine.init(&mut ctx).expect("State machine init");
 
@@ -692,7 +764,8 @@ mod tests {
 
         assert_eq!(expected_idx, 14);
 
-        let mut ctx_manager = AnalysisContextManager::new(&lowered, &selection_data);
+        let mut ctx_manager =
+            AnalysisContextManager::new(&lowered, &selection_data).expect("Context manager");
         expected_idx = 0;
         while let Some(ctx) = ctx_manager.advance().expect("Context Manager Context") {
             let values = ctx
@@ -144,7 +144,7 @@ pub enum AnalysisErrorType {
         graph::AnalysisError<dir::DirValue>,
         hyperswitch_constraint_graph::Memoization<dir::DirValue>,
     ),
-    #[error("State machine error")]
+    #[error("State machine error: {0}")]
     StateMachine(dssa::state_machine::StateMachineError),
     #[error("Unsupported program key '{0}'")]
     UnsupportedProgramKey(dir::DirKeyKind),
@@ -1,3 +1,4 @@
 pub mod ast;
 pub mod dir;
+pub mod dnf;
 pub mod vir;
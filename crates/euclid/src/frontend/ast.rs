@@ -7,7 +7,10 @@ use common_utils::types::MinorUnit;
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
-use crate::types::{DataType, Metadata};
+use crate::{
+    frontend::dnf,
+    types::{DataType, Metadata},
+};
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub struct ConnectorChoice {
@@ -79,6 +82,20 @@ pub enum ComparisonType {
     GreaterThanEqual,
 }
 
+impl ComparisonType {
+    /// Returns the comparison type which is satisfied exactly when this one is not
+    pub fn negate(&self) -> Self {
+        match self {
+            Self::Equal => Self::NotEqual,
+            Self::NotEqual => Self::Equal,
+            Self::LessThan => Self::GreaterThanEqual,
+            Self::LessThanEqual => Self::GreaterThan,
+            Self::GreaterThan => Self::LessThanEqual,
+            Self::GreaterThanEqual => Self::LessThan,
+        }
+    }
+}
+
 /// Represents a single comparison condition.
 #[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
@@ -96,13 +113,93 @@ pub struct Comparison {
     pub metadata: Metadata,
 }
 
+/// Represents a condition, which is either a single comparison or a group of conditions combined
+/// using boolean logic.
+///
+/// Comparisons are represented as is, so that conditions consisting of only comparisons retain
+/// their existing representation.
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+#[serde(untagged)]
+pub enum Condition {
+    Comparison(Comparison),
+    Group(ConditionGroup),
+}
+
+/// Represents a group of conditions combined using boolean logic
+/// eg:
+///
+/// ```text
+/// any(payment.method.network = visa, payment.method.card_bin = "424242")
+/// all(payment.currency = USD, payment.amount > 1000)
+/// not(billing.country = US)
+/// ```
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ConditionGroup {
+    /// Satisfied when at least one of the conditions is satisfied
+    #[schema(value_type = Vec<Condition>)]
+    Any(Vec<Condition>),
+    /// Satisfied when all of the conditions are satisfied
+    #[schema(value_type = Vec<Condition>)]
+    All(Vec<Condition>),
+    /// Satisfied when the condition is not satisfied
+    #[schema(value_type = Condition)]
+    Not(Box<Condition>),
+}
+
+impl Comparison {
+    /// Returns the comparisons which must all be satisfied for this comparison to not be satisfied
+    fn negate(self) -> Vec<Self> {
+        match self.value {
+            // The number is required to satisfy at least one of the comparisons, so the negation
+            // requires it to satisfy none of them
+            ValueType::NumberComparisonArray(comparisons)
+                if self.comparison == ComparisonType::Equal =>
+            {
+                comparisons
+                    .into_iter()
+                    .map(|comparison| Self {
+                        lhs: self.lhs.clone(),
+                        comparison: comparison.comparison_type.negate(),
+                        value: ValueType::Number(comparison.number),
+                        metadata: self.metadata.clone(),
+                    })
+                    .collect()
+            }
+            value => vec![Self {
+                comparison: self.comparison.negate(),
+                value,
+                ..self
+            }],
+        }
+    }
+}
+
+impl<'a> dnf::Expandable<'a> for Condition {
+    type Literal = Comparison;
+
+    fn node(&'a self) -> dnf::ConditionNode<'a, Self, Comparison> {
+        match self {
+            Self::Comparison(comparison) => dnf::ConditionNode::Literal(comparison.clone()),
+            Self::Group(ConditionGroup::Any(conditions)) => dnf::ConditionNode::Any(conditions),
+            Self::Group(ConditionGroup::All(conditions)) => dnf::ConditionNode::All(conditions),
+            Self::Group(ConditionGroup::Not(condition)) => dnf::ConditionNode::Not(condition),
+        }
+    }
+
+    fn negate(literal: Comparison) -> Vec<Comparison> {
+        literal.negate()
+    }
+}
+
 /// Represents all the conditions of an IF statement
 /// eg:
 ///
 /// ```text
 /// payment.method = card & payment.method.cardtype = debit & payment.method.network = diners
+/// payment.method = card & any(payment.method.network = visa, payment.method.card_bin = "424242")
 /// ```
-pub type IfCondition = Vec<Comparison>;
+pub type IfCondition = Vec<Condition>;
 
 /// Represents an IF statement with conditions and optional nested IF statements
 ///
@@ -116,7 +213,7 @@ pub type IfCondition = Vec<Comparison>;
 #[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct IfStatement {
-    #[schema(value_type=Vec<Comparison>)]
+    #[schema(value_type=Vec<Condition>)]
     pub condition: IfCondition,
     pub nested: Option<Vec<IfStatement>>,
 }
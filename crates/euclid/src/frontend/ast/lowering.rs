@@ -304,6 +304,33 @@ fn lower_comparison<O: EuclidDirFilter>(
     })
 }
 
+/// lowers a condition, which is either a single comparison or a group of conditions, retaining
+/// the structure of the group
+fn lower_condition<O: EuclidDirFilter>(
+    condition: ast::Condition,
+) -> Result<dir::DirCondition, AnalysisError> {
+    Ok(match condition {
+        ast::Condition::Comparison(comp) => {
+            dir::DirCondition::Comparison(lower_comparison::<O>(comp)?)
+        }
+        ast::Condition::Group(ast::ConditionGroup::Any(conditions)) => dir::DirCondition::Any(
+            conditions
+                .into_iter()
+                .map(lower_condition::<O>)
+                .collect::<Result<_, _>>()?,
+        ),
+        ast::Condition::Group(ast::ConditionGroup::All(conditions)) => dir::DirCondition::All(
+            conditions
+                .into_iter()
+                .map(lower_condition::<O>)
+                .collect::<Result<_, _>>()?,
+        ),
+        ast::Condition::Group(ast::ConditionGroup::Not(condition)) => {
+            dir::DirCondition::Not(Box::new(lower_condition::<O>(*condition)?))
+        }
+    })
+}
+
 /// lowers the if statement accordingly with a condition and following nested if statements (if
 /// present)
 fn lower_if_statement<O: EuclidDirFilter>(
@@ -313,7 +340,7 @@ fn lower_if_statement<O: EuclidDirFilter>(
         condition: stmt
             .condition
             .into_iter()
-            .map(lower_comparison::<O>)
+            .map(lower_condition::<O>)
             .collect::<Result<_, _>>()?,
         nested: stmt
             .nested
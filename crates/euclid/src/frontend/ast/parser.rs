@@ -332,22 +332,95 @@ pub fn arbitrary_comparison(input: &str) -> ParseResult<&str, ast::Comparison> {
     )(input)
 }
 
-pub fn comparison_array(input: &str) -> ParseResult<&str, Vec<ast::Comparison>> {
+pub fn condition(input: &str) -> ParseResult<&str, ast::Condition> {
+    error::context(
+        "condition",
+        branch::alt((
+            combinator::map(condition_group, ast::Condition::Group),
+            combinator::map(comparison, ast::Condition::Comparison),
+            combinator::map(arbitrary_comparison, ast::Condition::Comparison),
+        )),
+    )(input)
+}
+
+/// Combines the conditions separated by `&` into a single condition
+fn conjunction(mut conditions: Vec<ast::Condition>) -> ast::Condition {
+    if conditions.len() == 1 {
+        if let Some(condition) = conditions.pop() {
+            return condition;
+        }
+    }
+
+    ast::Condition::Group(ast::ConditionGroup::All(conditions))
+}
+
+pub fn condition_list(input: &str) -> ParseResult<&str, Vec<ast::Condition>> {
+    let many_with_comma = multi::many0(sequence::preceded(
+        skip_ws(complete::tag(",")),
+        combinator::map(condition_array, conjunction),
+    ));
+
+    let full_sequence = sequence::pair(
+        combinator::map(condition_array, conjunction),
+        many_with_comma,
+    );
+
+    error::context(
+        "condition_list",
+        combinator::map(
+            sequence::delimited(
+                skip_ws(complete::tag("(")),
+                full_sequence,
+                skip_ws(complete::tag(")")),
+            ),
+            |tup: (ast::Condition, Vec<ast::Condition>)| {
+                let mut rest = tup.1;
+                rest.insert(0, tup.0);
+                rest
+            },
+        ),
+    )(input)
+}
+
+pub fn condition_group(input: &str) -> ParseResult<&str, ast::ConditionGroup> {
+    let negated_condition = sequence::delimited(
+        skip_ws(complete::tag("(")),
+        combinator::map(condition_array, conjunction),
+        skip_ws(complete::tag(")")),
+    );
+
+    error::context(
+        "condition_group",
+        branch::alt((
+            combinator::map(
+                sequence::preceded(skip_ws(complete::tag("any")), condition_list),
+                ast::ConditionGroup::Any,
+            ),
+            combinator::map(
+                sequence::preceded(skip_ws(complete::tag("all")), condition_list),
+                ast::ConditionGroup::All,
+            ),
+            combinator::map(
+                sequence::preceded(skip_ws(complete::tag("not")), negated_condition),
+                |condition| ast::ConditionGroup::Not(Box::new(condition)),
+            ),
+        )),
+    )(input)
+}
+
+pub fn condition_array(input: &str) -> ParseResult<&str, Vec<ast::Condition>> {
     let many_with_ampersand = error::context(
         "many_with_amp",
-        multi::many0(sequence::preceded(skip_ws(complete::tag("&")), comparison)),
+        multi::many0(sequence::preceded(skip_ws(complete::tag("&")), condition)),
     );
 
-    let full_sequence = sequence::pair(
-        skip_ws(branch::alt((comparison, arbitrary_comparison))),
-        many_with_ampersand,
-    );
+    let full_sequence = sequence::pair(skip_ws(condition), many_with_ampersand);
 
     error::context(
-        "comparison_array",
+        "condition_array",
         combinator::map(
             full_sequence,
-            |tup: (ast::Comparison, Vec<ast::Comparison>)| {
+            |tup: (ast::Condition, Vec<ast::Condition>)| {
                 let mut rest = tup.1;
                 rest.insert(0, tup.0);
                 rest
@@ -366,7 +439,7 @@ pub fn if_statement(input: &str) -> ParseResult<&str, ast::IfStatement> {
     error::context(
         "if_statement",
         combinator::map(
-            sequence::pair(comparison_array, combinator::opt(nested_block)),
+            sequence::pair(condition_array, combinator::opt(nested_block)),
             |tup: (ast::IfCondition, Option<Vec<ast::IfStatement>>)| ast::IfStatement {
                 condition: tup.0,
                 nested: tup.1,
@@ -895,7 +895,15 @@ pub struct DirComparison {
     pub metadata: types::Metadata,
 }
 
-pub type DirIfCondition = Vec<DirComparison>;
+#[derive(Debug, Clone)]
+pub enum DirCondition {
+    Comparison(DirComparison),
+    Any(Vec<DirCondition>),
+    All(Vec<DirCondition>),
+    Not(Box<DirCondition>),
+}
+
+pub type DirIfCondition = Vec<DirCondition>;
 
 #[derive(Debug, Clone)]
 pub struct DirIfStatement {
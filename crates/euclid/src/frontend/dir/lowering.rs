@@ -300,6 +300,31 @@ fn lower_comparison(
     })
 }
 
+fn lower_condition(
+    dir_condition: dir::DirCondition,
+) -> Result<vir::ValuedCondition, AnalysisErrorType> {
+    Ok(match dir_condition {
+        dir::DirCondition::Comparison(comparison) => {
+            vir::ValuedCondition::Comparison(lower_comparison(comparison)?)
+        }
+        dir::DirCondition::Any(conditions) => vir::ValuedCondition::Any(
+            conditions
+                .into_iter()
+                .map(lower_condition)
+                .collect::<Result<_, _>>()?,
+        ),
+        dir::DirCondition::All(conditions) => vir::ValuedCondition::All(
+            conditions
+                .into_iter()
+                .map(lower_condition)
+                .collect::<Result<_, _>>()?,
+        ),
+        dir::DirCondition::Not(condition) => {
+            vir::ValuedCondition::Not(Box::new(lower_condition(*condition)?))
+        }
+    })
+}
+
 fn lower_if_statement(
     dir_if_statement: dir::DirIfStatement,
 ) -> Result<vir::ValuedIfStatement, AnalysisErrorType> {
@@ -307,7 +332,7 @@ fn lower_if_statement(
         condition: dir_if_statement
             .condition
             .into_iter()
-            .map(lower_comparison)
+            .map(lower_condition)
             .collect::<Result<_, _>>()?,
         nested: dir_if_statement
             .nested
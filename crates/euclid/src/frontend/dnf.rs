@@ -0,0 +1,218 @@
+//! Expansion of conditions into their disjunctive normal form
+//!
+//! Conditions built using `any`, `all` and `not` groups are expanded into a list of alternatives,
+//! each consisting of literals that must all be satisfied. `not` groups are eliminated by negating
+//! the literals they contain.
+
+/// Maximum number of alternatives a condition can be expanded into. The number of alternatives
+/// grows exponentially with the number of `any` groups combined using `all`, so conditions
+/// exceeding this are rejected instead of being expanded.
+pub const MAX_ALTERNATIVES: usize = 256;
+
+#[derive(Debug, Clone, serde::Serialize, thiserror::Error)]
+#[error("Condition expands into more than {max_alternatives} alternatives")]
+pub struct TooManyAlternatives {
+    pub max_alternatives: usize,
+}
+
+/// A node in the tree of a condition
+pub enum ConditionNode<'a, C, L> {
+    Literal(L),
+    Any(&'a [C]),
+    All(&'a [C]),
+    Not(&'a C),
+}
+
+/// A condition which can be expanded into its disjunctive normal form
+pub trait Expandable<'a>: Sized + 'a {
+    type Literal: Clone;
+
+    fn node(&'a self) -> ConditionNode<'a, Self, Self::Literal>;
+
+    /// Returns the literals which must all be satisfied for the literal to not be satisfied
+    fn negate(literal: Self::Literal) -> Vec<Self::Literal>;
+}
+
+pub type Alternatives<L> = Vec<Vec<L>>;
+
+/// Expands the condition into its disjunctive normal form
+pub fn expand<'a, C: Expandable<'a>>(
+    condition: &'a C,
+) -> Result<Alternatives<C::Literal>, TooManyAlternatives> {
+    expand_inner(condition, false)
+}
+
+/// Expands the conjunction of the conditions into its disjunctive normal form
+pub fn expand_all<'a, C: Expandable<'a>>(
+    conditions: &'a [C],
+) -> Result<Alternatives<C::Literal>, TooManyAlternatives> {
+    expand_conjunction(
+        conditions
+            .iter()
+            .map(|condition| expand_inner(condition, false)),
+    )
+}
+
+fn expand_inner<'a, C: Expandable<'a>>(
+    condition: &'a C,
+    negated: bool,
+) -> Result<Alternatives<C::Literal>, TooManyAlternatives> {
+    match (condition.node(), negated) {
+        (ConditionNode::Literal(literal), false) => Ok(vec![vec![literal]]),
+        (ConditionNode::Literal(literal), true) => Ok(vec![C::negate(literal)]),
+        (ConditionNode::Not(condition), negated) => expand_inner(condition, !negated),
+        (ConditionNode::Any(conditions), false) | (ConditionNode::All(conditions), true) => {
+            conditions
+                .iter()
+                .try_fold(Vec::new(), |mut alternatives, condition| {
+                    alternatives.extend(expand_inner(condition, negated)?);
+                    check_alternatives_count(alternatives.len())?;
+                    Ok(alternatives)
+                })
+        }
+        (ConditionNode::All(conditions), false) | (ConditionNode::Any(conditions), true) => {
+            expand_conjunction(
+                conditions
+                    .iter()
+                    .map(|condition| expand_inner(condition, negated)),
+            )
+        }
+    }
+}
+
+/// Combines the alternatives of conditions that must all be satisfied into the alternatives of
+/// their conjunction
+fn expand_conjunction<L: Clone>(
+    conditions: impl Iterator<Item = Result<Alternatives<L>, TooManyAlternatives>>,
+) -> Result<Alternatives<L>, TooManyAlternatives> {
+    conditions.try_fold(vec![Vec::new()], |alternatives, condition_alternatives| {
+        let condition_alternatives = condition_alternatives?;
+        check_alternatives_count(
+            alternatives
+                .len()
+                .saturating_mul(condition_alternatives.len()),
+        )?;
+
+        Ok(alternatives
+            .iter()
+            .flat_map(|alternative| {
+                condition_alternatives
+                    .iter()
+                    .map(move |condition_alternative| {
+                        alternative
+                            .iter()
+                            .chain(condition_alternative)
+                            .cloned()
+                            .collect()
+                    })
+            })
+            .collect())
+    })
+}
+
+fn check_alternatives_count(count: usize) -> Result<(), TooManyAlternatives> {
+    if count > MAX_ALTERNATIVES {
+        Err(TooManyAlternatives {
+            max_alternatives: MAX_ALTERNATIVES,
+        })
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+
+    use common_utils::types::MinorUnit;
+
+    use super::*;
+    use crate::frontend::ast;
+
+    fn comparison(lhs: &str, amount: i64) -> ast::Condition {
+        ast::Condition::Comparison(ast::Comparison {
+            lhs: lhs.to_string(),
+            comparison: ast::ComparisonType::GreaterThan,
+            value: ast::ValueType::Number(MinorUnit::new(amount)),
+            metadata: Default::default(),
+        })
+    }
+
+    fn lhs_of(
+        alternatives: &Alternatives<ast::Comparison>,
+    ) -> Vec<Vec<(&str, ast::ComparisonType)>> {
+        alternatives
+            .iter()
+            .map(|alternative| {
+                alternative
+                    .iter()
+                    .map(|comparison| (comparison.lhs.as_str(), comparison.comparison.clone()))
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_expand_distributes_all_over_any() {
+        let condition = ast::Condition::Group(ast::ConditionGroup::All(vec![
+            comparison("a", 1),
+            ast::Condition::Group(ast::ConditionGroup::Any(vec![
+                comparison("b", 1),
+                comparison("c", 1),
+            ])),
+        ]));
+
+        let alternatives = expand(&condition).expect("Expansion");
+
+        assert_eq!(
+            lhs_of(&alternatives),
+            vec![
+                vec![
+                    ("a", ast::ComparisonType::GreaterThan),
+                    ("b", ast::ComparisonType::GreaterThan)
+                ],
+                vec![
+                    ("a", ast::ComparisonType::GreaterThan),
+                    ("c", ast::ComparisonType::GreaterThan)
+                ],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_expand_negates_any_into_conjunction() {
+        let condition =
+            ast::Condition::Group(ast::ConditionGroup::Not(Box::new(ast::Condition::Group(
+                ast::ConditionGroup::Any(vec![comparison("a", 1), comparison("b", 1)]),
+            ))));
+
+        let alternatives = expand(&condition).expect("Expansion");
+
+        assert_eq!(
+            lhs_of(&alternatives),
+            vec![vec![
+                ("a", ast::ComparisonType::LessThanEqual),
+                ("b", ast::ComparisonType::LessThanEqual),
+            ]]
+        );
+    }
+
+    #[test]
+    fn test_expand_rejects_too_many_alternatives() {
+        // Each `any` group doubles the number of alternatives of the conjunction
+        let any_group = || {
+            ast::Condition::Group(ast::ConditionGroup::Any(vec![
+                comparison("a", 1),
+                comparison("b", 1),
+            ]))
+        };
+        let within_limit = vec![any_group(); 8];
+        let exceeding_limit = vec![any_group(); 9];
+
+        assert_eq!(
+            expand_all(&within_limit).expect("Expansion").len(),
+            MAX_ALTERNATIVES
+        );
+        assert!(expand_all(&exceeding_limit).is_err());
+    }
+}
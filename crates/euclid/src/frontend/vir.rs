@@ -16,7 +16,15 @@ pub struct ValuedComparison {
     pub metadata: Metadata,
 }
 
-pub type ValuedIfCondition = Vec<ValuedComparison>;
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum ValuedCondition {
+    Comparison(ValuedComparison),
+    Any(Vec<ValuedCondition>),
+    All(Vec<ValuedCondition>),
+    Not(Box<ValuedCondition>),
+}
+
+pub type ValuedIfCondition = Vec<ValuedCondition>;
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ValuedIfStatement {
@@ -149,7 +149,7 @@ pub fn get_valid_connectors_for_rule(rule: JsValue) -> JsResult {
         .collect();
     let mut invalid_connectors: HashSet<ast::ConnectorChoice> = HashSet::new();
 
-    let mut ctx_manager = state_machine::RuleContextManager::new(&dir_rule, &[]);
+    let mut ctx_manager = state_machine::RuleContextManager::new(&dir_rule, &[]).err_to_js()?;
 
     let dummy_meta = HashMap::new();
 
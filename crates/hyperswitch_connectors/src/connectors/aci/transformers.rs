@@ -759,6 +759,7 @@ impl<F, T> TryFrom<ResponseRouterData<F, AciPaymentsResponse, T, PaymentsRespons
                 connector_response_reference_id: Some(item.response.id),
                 incremental_authorization_allowed: None,
                 charges: None,
+                authorized_amount: None,
             }),
             ..item.data
         })
@@ -3705,6 +3705,7 @@ impl TryFrom<PaymentsCancelResponseRouterData<AdyenCancelResponse>> for Payments
                 connector_response_reference_id: Some(item.response.reference),
                 incremental_authorization_allowed: None,
                 charges: None,
+                authorized_amount: None,
             }),
             ..item.data
         })
@@ -3740,6 +3741,7 @@ impl<F>
                 connector_response_reference_id: None,
                 incremental_authorization_allowed: None,
                 charges: None,
+                authorized_amount: None,
             }),
             payment_method_balance: Some(PaymentMethodBalance {
                 currency: item.response.balance.currency,
@@ -3827,6 +3829,7 @@ pub fn get_adyen_response(
         connector_response_reference_id: Some(response.merchant_reference),
         incremental_authorization_allowed: None,
         charges,
+        authorized_amount: None,
     };
     Ok((status, error, payments_response_data))
 }
@@ -3897,6 +3900,7 @@ pub fn get_webhook_response(
             connector_response_reference_id: Some(response.merchant_reference_id),
             incremental_authorization_allowed: None,
             charges: None,
+            authorized_amount: None,
         };
         Ok((status, error, payments_response_data))
     }
@@ -3983,6 +3987,7 @@ pub fn get_redirection_response(
             .or(response.psp_reference),
         incremental_authorization_allowed: None,
         charges,
+        authorized_amount: None,
     };
     Ok((status, error, payments_response_data))
 }
@@ -4051,6 +4056,7 @@ pub fn get_present_to_shopper_response(
             .or(response.psp_reference),
         incremental_authorization_allowed: None,
         charges,
+        authorized_amount: None,
     };
     Ok((status, error, payments_response_data))
 }
@@ -4118,6 +4124,7 @@ pub fn get_qr_code_response(
             .or(response.psp_reference),
         incremental_authorization_allowed: None,
         charges,
+        authorized_amount: None,
     };
     Ok((status, error, payments_response_data))
 }
@@ -4172,6 +4179,7 @@ pub fn get_redirection_error_response(
             .or(response.psp_reference),
         incremental_authorization_allowed: None,
         charges: None,
+        authorized_amount: None,
     };
 
     Ok((status, error, payments_response_data))
@@ -4532,6 +4540,7 @@ impl TryFrom<PaymentsCaptureResponseRouterData<AdyenCaptureResponse>>
                 connector_response_reference_id: Some(item.response.reference),
                 incremental_authorization_allowed: None,
                 charges,
+                authorized_amount: None,
             }),
             amount_captured: Some(0),
             ..item.data
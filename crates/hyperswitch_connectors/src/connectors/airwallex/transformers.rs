@@ -667,6 +667,7 @@ impl<F, T> TryFrom<ResponseRouterData<F, AirwallexPaymentsResponse, T, PaymentsR
                 connector_response_reference_id: Some(item.response.id),
                 incremental_authorization_allowed: None,
                 charges: None,
+                authorized_amount: None,
             }),
             ..item.data
         })
@@ -710,6 +711,7 @@ impl
                 connector_response_reference_id: Some(item.response.id),
                 incremental_authorization_allowed: None,
                 charges: None,
+                authorized_amount: None,
             }),
             ..item.data
         })
@@ -136,6 +136,7 @@ impl<F, T> TryFrom<ResponseRouterData<F, AmazonpayPaymentsResponse, T, PaymentsR
                 connector_response_reference_id: None,
                 incremental_authorization_allowed: None,
                 charges: None,
+                authorized_amount: None,
             }),
             ..item.data
         })
@@ -884,6 +884,7 @@ impl<F>
                     .then_some(item.response.transaction_id),
                 connector_response_reference_id: None,
                 incremental_authorization_allowed: Some(is_incremental_allowed),
+                authorized_amount: None,
             }),
             ..item.data
         })
@@ -938,6 +939,7 @@ impl<F>
                 network_txn_id: None,
                 connector_response_reference_id: None,
                 incremental_authorization_allowed: None,
+                authorized_amount: None,
             }),
             ..item.data
         })
@@ -1009,6 +1011,7 @@ impl<F>
                 network_txn_id: None,
                 connector_response_reference_id: None,
                 incremental_authorization_allowed: None,
+                authorized_amount: None,
             }),
             ..item.data
         })
@@ -1121,6 +1124,7 @@ impl<F>
                 network_txn_id: Some(item.response.transaction_id.clone()),
                 connector_response_reference_id: Some(item.response.transaction_id),
                 incremental_authorization_allowed: Some(false),
+                authorized_amount: None,
             }),
             ..item.data
         })
@@ -1185,6 +1189,7 @@ impl<F>
                 network_txn_id: None,
                 connector_response_reference_id: None,
                 incremental_authorization_allowed: None,
+                authorized_amount: None,
             }),
             ..item.data
         })
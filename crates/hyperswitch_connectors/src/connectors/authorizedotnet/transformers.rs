@@ -538,6 +538,7 @@ impl<F, T>
                     connector_response_reference_id: None,
                     incremental_authorization_allowed: None,
                     charges: None,
+                    authorized_amount: None,
                 }),
                 ..item.data
             }),
@@ -1303,6 +1304,7 @@ impl<F, T>
                             ),
                             incremental_authorization_allowed: None,
                             charges: None,
+                            authorized_amount: None,
                         }),
                     },
                     ..item.data
@@ -1372,6 +1374,7 @@ impl<F, T> TryFrom<ResponseRouterData<F, AuthorizedotnetVoidResponse, T, Payment
                             ),
                             incremental_authorization_allowed: None,
                             charges: None,
+                            authorized_amount: None,
                         }),
                     },
                     ..item.data
@@ -1698,6 +1701,7 @@ impl<F, Req> TryFrom<ResponseRouterData<F, AuthorizedotnetSyncResponse, Req, Pay
                         connector_response_reference_id: Some(transaction.transaction_id.clone()),
                         incremental_authorization_allowed: None,
                         charges: None,
+                        authorized_amount: None,
                     }),
                     status: payment_status,
                     ..item.data
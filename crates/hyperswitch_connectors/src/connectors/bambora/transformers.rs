@@ -479,6 +479,7 @@ impl<F> TryFrom<ResponseRouterData<F, BamboraResponse, PaymentsAuthorizeData, Pa
                     connector_response_reference_id: Some(pg_response.order_number.to_string()),
                     incremental_authorization_allowed: None,
                     charges: None,
+                    authorized_amount: None,
                 }),
                 ..item.data
             }),
@@ -506,6 +507,7 @@ impl<F> TryFrom<ResponseRouterData<F, BamboraResponse, PaymentsAuthorizeData, Pa
                         ),
                         incremental_authorization_allowed: None,
                         charges: None,
+                        authorized_amount: None,
                     }),
                     ..item.data
                 })
@@ -549,6 +551,7 @@ impl<F>
                 connector_response_reference_id: Some(item.response.order_number.to_string()),
                 incremental_authorization_allowed: None,
                 charges: None,
+                authorized_amount: None,
             }),
             ..item.data
         })
@@ -594,6 +597,7 @@ impl<F>
                 connector_response_reference_id: Some(item.response.order_number.to_string()),
                 incremental_authorization_allowed: None,
                 charges: None,
+                authorized_amount: None,
             }),
             ..item.data
         })
@@ -629,6 +633,7 @@ impl<F>
                 connector_response_reference_id: Some(item.response.order_number.to_string()),
                 incremental_authorization_allowed: None,
                 charges: None,
+                authorized_amount: None,
             }),
             ..item.data
         })
@@ -664,6 +669,7 @@ impl<F>
                 connector_response_reference_id: Some(item.response.order_number.to_string()),
                 incremental_authorization_allowed: None,
                 charges: None,
+                authorized_amount: None,
             }),
             ..item.data
         })
@@ -314,6 +314,7 @@ impl<F>
                     connector_response_reference_id: Some(connector_transaction_id),
                     incremental_authorization_allowed: None,
                     charges: None,
+                    authorized_amount: None,
                 }),
                 ..item.data
             })
@@ -489,6 +490,7 @@ impl<F>
                     connector_response_reference_id: None,
                     incremental_authorization_allowed: None,
                     charges: None,
+                    authorized_amount: None,
                 }),
                 ..item.data
             })
@@ -638,6 +640,7 @@ impl<F>
                     connector_response_reference_id: Some(connector_transaction_id),
                     incremental_authorization_allowed: None,
                     charges: None,
+                    authorized_amount: None,
                 }),
                 ..item.data
             })
@@ -920,6 +923,7 @@ impl<F>
                     connector_response_reference_id: Some(connector_transaction_id),
                     incremental_authorization_allowed: None,
                     charges: None,
+                    authorized_amount: None,
                 }),
                 ..item.data
             })
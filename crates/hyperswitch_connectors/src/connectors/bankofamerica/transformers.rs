@@ -1,6 +1,7 @@
 use base64::Engine;
 use common_enums::{enums, FutureUsage};
-use common_utils::{consts, ext_traits::OptionExt, pii};
+use common_types::payments::{L2L3Data, L2L3LineItem};
+use common_utils::{consts, ext_traits::OptionExt, pii, types::MinorUnit};
 use hyperswitch_domain_models::{
     payment_method_data::{
         ApplePayWalletData, GooglePayWalletData, PaymentMethodData, SamsungPayWalletData,
@@ -110,6 +111,8 @@ pub struct ProcessingInformation {
     capture: Option<bool>,
     capture_options: Option<CaptureOptions>,
     payment_solution: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    purchase_level: Option<String>,
 }
 
 #[derive(Debug, Serialize)]
@@ -269,6 +272,48 @@ pub const FLUID_DATA_DESCRIPTOR_FOR_SAMSUNG_PAY: &str = "FID=COMMON.SAMSUNG.INAP
 pub struct OrderInformationWithBill {
     amount_details: Amount,
     bill_to: Option<BillTo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    line_items: Option<Vec<LineItem>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    invoice_details: Option<InvoiceDetails>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ship_from: Option<ShipFrom>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LineItem {
+    product_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    product_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    product_description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    commodity_code: Option<String>,
+    quantity: u16,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    unit_of_measure: Option<String>,
+    unit_price: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    discount_amount: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tax_amount: Option<String>,
+    total_amount: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InvoiceDetails {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    purchase_order_number: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    taxable: Option<bool>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShipFrom {
+    postal_code: String,
 }
 
 #[derive(Debug, Serialize)]
@@ -276,6 +321,14 @@ pub struct OrderInformationWithBill {
 pub struct Amount {
     total_amount: String,
     currency: api_models::enums::Currency,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tax_amount: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    discount_amount: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    duty_amount: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    freight_amount: Option<String>,
 }
 
 #[derive(Debug, Serialize)]
@@ -441,6 +494,7 @@ impl<F, T>
                             ),
                             incremental_authorization_allowed: None,
                             charges: None,
+                            authorized_amount: None,
                         }),
                     },
                     connector_response,
@@ -593,27 +647,128 @@ pub enum TransactionType {
 }
 
 impl
-    From<(
+    TryFrom<(
         &BankOfAmericaRouterData<&PaymentsAuthorizeRouterData>,
         Option<BillTo>,
     )> for OrderInformationWithBill
 {
-    fn from(
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
         (item, bill_to): (
             &BankOfAmericaRouterData<&PaymentsAuthorizeRouterData>,
             Option<BillTo>,
         ),
-    ) -> Self {
-        Self {
+    ) -> Result<Self, Self::Error> {
+        let currency = item.router_data.request.currency;
+        let l2_l3_data = item.router_data.request.l2_l3_data.as_ref();
+        let line_items = l2_l3_data
+            .and_then(|l2_l3_data| l2_l3_data.line_items.as_ref())
+            .map(|line_items| {
+                line_items
+                    .iter()
+                    .map(|line_item| LineItem::try_from((line_item, currency)))
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .transpose()?;
+        Ok(Self {
             amount_details: Amount {
                 total_amount: item.amount.to_owned(),
-                currency: item.router_data.request.currency,
+                currency,
+                tax_amount: get_optional_amount(
+                    l2_l3_data.and_then(|l2_l3_data| l2_l3_data.tax_amount),
+                    currency,
+                )?,
+                discount_amount: get_optional_amount(
+                    l2_l3_data.and_then(|l2_l3_data| l2_l3_data.discount_amount),
+                    currency,
+                )?,
+                duty_amount: get_optional_amount(
+                    l2_l3_data.and_then(|l2_l3_data| l2_l3_data.duty_amount),
+                    currency,
+                )?,
+                freight_amount: get_optional_amount(
+                    l2_l3_data.and_then(|l2_l3_data| l2_l3_data.freight_amount),
+                    currency,
+                )?,
             },
             bill_to,
+            line_items,
+            invoice_details: l2_l3_data.map(InvoiceDetails::from),
+            ship_from: l2_l3_data
+                .and_then(|l2_l3_data| l2_l3_data.ship_from_postal_code.clone())
+                .map(|postal_code| ShipFrom { postal_code }),
+        })
+    }
+}
+
+impl TryFrom<(&L2L3LineItem, enums::Currency)> for LineItem {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        (line_item, currency): (&L2L3LineItem, enums::Currency),
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            product_name: line_item.product_name.clone(),
+            product_code: line_item.product_code.clone(),
+            product_description: line_item.description.clone(),
+            commodity_code: line_item.commodity_code.clone(),
+            quantity: line_item.quantity,
+            unit_of_measure: line_item.unit_of_measure.clone(),
+            unit_price: utils::get_amount_as_string(
+                &api::CurrencyUnit::Base,
+                line_item.unit_amount.get_amount_as_i64(),
+                currency,
+            )?,
+            discount_amount: get_optional_amount(line_item.discount_amount, currency)?,
+            tax_amount: get_optional_amount(line_item.tax_amount, currency)?,
+            total_amount: utils::get_amount_as_string(
+                &api::CurrencyUnit::Base,
+                line_item
+                    .get_total_amount()
+                    .map_err(|_| errors::ConnectorError::InvalidDataFormat {
+                        field_name: "l2_l3_data.line_items",
+                    })?
+                    .get_amount_as_i64(),
+                currency,
+            )?,
+        })
+    }
+}
+
+impl From<&L2L3Data> for InvoiceDetails {
+    fn from(l2_l3_data: &L2L3Data) -> Self {
+        Self {
+            purchase_order_number: l2_l3_data.customer_reference.clone(),
+            taxable: l2_l3_data.tax_exempt.map(|tax_exempt| !tax_exempt),
         }
     }
 }
 
+fn get_optional_amount(
+    amount: Option<MinorUnit>,
+    currency: enums::Currency,
+) -> Result<Option<String>, error_stack::Report<errors::ConnectorError>> {
+    amount
+        .map(|amount| {
+            utils::get_amount_as_string(
+                &api::CurrencyUnit::Base,
+                amount.get_amount_as_i64(),
+                currency,
+            )
+        })
+        .transpose()
+}
+
+/// Level 3 is sent when line items are present, Level 2 otherwise
+fn get_purchase_level(l2_l3_data: Option<&L2L3Data>) -> Option<String> {
+    l2_l3_data.map(|l2_l3_data| {
+        if l2_l3_data.line_items.is_some() {
+            "3".to_string()
+        } else {
+            "2".to_string()
+        }
+    })
+}
+
 impl
     TryFrom<(
         &BankOfAmericaRouterData<&PaymentsAuthorizeRouterData>,
@@ -684,6 +839,7 @@ impl
             authorization_options,
             capture_options: None,
             commerce_indicator,
+            purchase_level: get_purchase_level(item.router_data.request.l2_l3_data.as_ref()),
         })
     }
 }
@@ -850,7 +1006,7 @@ impl
 
         let email = item.router_data.request.get_email()?;
         let bill_to = build_bill_to(item.router_data.get_optional_billing(), email)?;
-        let order_information = OrderInformationWithBill::from((item, Some(bill_to)));
+        let order_information = OrderInformationWithBill::try_from((item, Some(bill_to)))?;
         let payment_information = PaymentInformation::try_from(&ccard)?;
         let processing_information = ProcessingInformation::try_from((item, None, None))?;
         let client_reference_information = ClientReferenceInformation::from(item);
@@ -889,7 +1045,7 @@ impl
     ) -> Result<Self, Self::Error> {
         let email = item.router_data.request.get_email()?;
         let bill_to = build_bill_to(item.router_data.get_optional_billing(), email)?;
-        let order_information = OrderInformationWithBill::from((item, Some(bill_to)));
+        let order_information = OrderInformationWithBill::try_from((item, Some(bill_to)))?;
         let processing_information = ProcessingInformation::try_from((
             item,
             Some(PaymentSolution::ApplePay),
@@ -945,7 +1101,7 @@ impl
     ) -> Result<Self, Self::Error> {
         let email = item.router_data.request.get_email()?;
         let bill_to = build_bill_to(item.router_data.get_optional_billing(), email)?;
-        let order_information = OrderInformationWithBill::from((item, Some(bill_to)));
+        let order_information = OrderInformationWithBill::try_from((item, Some(bill_to)))?;
         let payment_information = PaymentInformation::from(&google_pay_data);
         let processing_information =
             ProcessingInformation::try_from((item, Some(PaymentSolution::GooglePay), None))?;
@@ -1032,7 +1188,7 @@ impl TryFrom<&BankOfAmericaRouterData<&PaymentsAuthorizeRouterData>>
                                         email,
                                     )?;
                                     let order_information: OrderInformationWithBill =
-                                        OrderInformationWithBill::from((item, Some(bill_to)));
+                                        OrderInformationWithBill::try_from((item, Some(bill_to)))?;
                                     let processing_information =
                                         ProcessingInformation::try_from((
                                             item,
@@ -1202,7 +1358,7 @@ impl
             .get_billing_email()
             .or(item.router_data.request.get_email())?;
         let bill_to = build_bill_to(item.router_data.get_optional_billing(), email)?;
-        let order_information = OrderInformationWithBill::from((item, Some(bill_to)));
+        let order_information = OrderInformationWithBill::try_from((item, Some(bill_to)))?;
 
         let samsung_pay_fluid_data_value =
             get_samsung_pay_fluid_data_value(&samsung_pay_data.payment_credential.token_data)?;
@@ -1269,7 +1425,7 @@ impl
             item.router_data.request.get_email().ok().and_then(|email| {
                 build_bill_to(item.router_data.get_optional_billing(), email).ok()
             });
-        let order_information = OrderInformationWithBill::from((item, bill_to));
+        let order_information = OrderInformationWithBill::try_from((item, bill_to))?;
         let payment_information =
             PaymentInformation::MandatePayment(Box::new(MandatePaymentInformation {
                 payment_instrument,
@@ -1655,6 +1811,7 @@ fn get_payment_response(
                 ),
                 incremental_authorization_allowed: None,
                 charges: None,
+                authorized_amount: None,
             })
         }
     }
@@ -1959,6 +2116,7 @@ impl<F>
                                 .unwrap_or(Some(item.response.id)),
                             incremental_authorization_allowed: None,
                             charges: None,
+                            authorized_amount: None,
                         }),
                         connector_response,
                         ..item.data
@@ -1976,6 +2134,7 @@ impl<F>
                     connector_response_reference_id: Some(item.response.id),
                     incremental_authorization_allowed: None,
                     charges: None,
+                    authorized_amount: None,
                 }),
                 ..item.data
             }),
@@ -2014,6 +2173,10 @@ impl TryFrom<&BankOfAmericaRouterData<&PaymentsCaptureRouterData>> for BankOfAme
                 amount_details: Amount {
                     total_amount: value.amount.to_owned(),
                     currency: value.router_data.request.currency,
+                    tax_amount: None,
+                    discount_amount: None,
+                    duty_amount: None,
+                    freight_amount: None,
                 },
             },
             client_reference_information: ClientReferenceInformation {
@@ -2064,6 +2227,10 @@ impl TryFrom<&BankOfAmericaRouterData<&PaymentsCancelRouterData>> for BankOfAmer
                             field_name: "Currency",
                         },
                     )?,
+                    tax_amount: None,
+                    discount_amount: None,
+                    duty_amount: None,
+                    freight_amount: None,
                 },
                 reason: value
                     .router_data
@@ -2096,6 +2263,10 @@ impl<F> TryFrom<&BankOfAmericaRouterData<&RefundsRouterData<F>>> for BankOfAmeri
                 amount_details: Amount {
                     total_amount: item.amount.clone(),
                     currency: item.router_data.request.currency,
+                    tax_amount: None,
+                    discount_amount: None,
+                    duty_amount: None,
+                    freight_amount: None,
                 },
             },
             client_reference_information: ClientReferenceInformation {
@@ -2551,6 +2722,7 @@ impl TryFrom<(Option<PaymentSolution>, Option<String>)> for ProcessingInformatio
             authorization_options,
             commerce_indicator,
             payment_solution: solution.map(String::from),
+            purchase_level: None,
         })
     }
 }
@@ -2566,8 +2738,15 @@ impl TryFrom<&SetupMandateRouterData> for OrderInformationWithBill {
             amount_details: Amount {
                 total_amount: "0".to_string(),
                 currency: item.request.currency,
+                tax_amount: None,
+                discount_amount: None,
+                duty_amount: None,
+                freight_amount: None,
             },
             bill_to: Some(bill_to),
+            line_items: None,
+            invoice_details: None,
+            ship_from: None,
         })
     }
 }
@@ -2748,3 +2927,88 @@ pub fn get_error_reason(
         (None, None, None) => None,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    fn get_l2_l3_line_item(unit_amount: i64) -> L2L3LineItem {
+        L2L3LineItem {
+            product_name: "Laptop stand".to_string(),
+            product_code: Some("SKU-1234".to_string()),
+            commodity_code: Some("43211500".to_string()),
+            description: None,
+            quantity: 2,
+            unit_of_measure: Some("EA".to_string()),
+            unit_amount: MinorUnit::new(unit_amount),
+            discount_amount: Some(MinorUnit::new(100)),
+            tax_amount: Some(MinorUnit::new(325)),
+        }
+    }
+
+    fn get_l2_l3_data(line_items: Option<Vec<L2L3LineItem>>) -> L2L3Data {
+        L2L3Data {
+            customer_reference: Some("PO-12345".to_string()),
+            tax_amount: Some(MinorUnit::new(650)),
+            tax_exempt: Some(false),
+            discount_amount: None,
+            duty_amount: None,
+            freight_amount: Some(MinorUnit::new(1000)),
+            ship_from_postal_code: Some("94105".to_string()),
+            line_items,
+        }
+    }
+
+    #[test]
+    fn test_l2_l3_line_item() {
+        let line_item =
+            LineItem::try_from((&get_l2_l3_line_item(2500), enums::Currency::USD)).unwrap();
+
+        assert_eq!(
+            serde_json::to_value(line_item).unwrap(),
+            serde_json::json!({
+                "productName": "Laptop stand",
+                "productCode": "SKU-1234",
+                "commodityCode": "43211500",
+                "quantity": 2,
+                "unitOfMeasure": "EA",
+                "unitPrice": "25.00",
+                "discountAmount": "1.00",
+                "taxAmount": "3.25",
+                "totalAmount": "49.00"
+            })
+        );
+    }
+
+    #[test]
+    fn test_l2_l3_line_item_with_total_amount_out_of_range() {
+        assert!(
+            LineItem::try_from((&get_l2_l3_line_item(i64::MAX), enums::Currency::USD)).is_err()
+        );
+    }
+
+    #[test]
+    fn test_l2_l3_invoice_details_and_purchase_level() {
+        let level_2_data = get_l2_l3_data(None);
+        let level_3_data = get_l2_l3_data(Some(vec![get_l2_l3_line_item(2500)]));
+
+        assert_eq!(
+            serde_json::to_value(InvoiceDetails::from(&level_2_data)).unwrap(),
+            serde_json::json!({
+                "purchaseOrderNumber": "PO-12345",
+                "taxable": true
+            })
+        );
+        assert_eq!(
+            get_purchase_level(Some(&level_2_data)),
+            Some("2".to_string())
+        );
+        assert_eq!(
+            get_purchase_level(Some(&level_3_data)),
+            Some("3".to_string())
+        );
+        assert_eq!(get_purchase_level(None), None);
+    }
+}
@@ -801,6 +801,7 @@ fn get_payment_response(
             ),
             incremental_authorization_allowed: None,
             charges: None,
+            authorized_amount: None,
         }),
     }
 }
@@ -1105,6 +1106,7 @@ impl<F>
                                 .unwrap_or(Some(item.response.id)),
                             incremental_authorization_allowed: None,
                             charges: None,
+                            authorized_amount: None,
                         }),
                         connector_response,
                         ..item.data
@@ -1122,6 +1124,7 @@ impl<F>
                     connector_response_reference_id: Some(item.response.id),
                     incremental_authorization_allowed: None,
                     charges: None,
+                    authorized_amount: None,
                 }),
                 ..item.data
             }),
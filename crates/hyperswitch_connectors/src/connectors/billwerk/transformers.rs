@@ -295,6 +295,7 @@ impl<F, T> TryFrom<ResponseRouterData<F, BillwerkPaymentsResponse, T, PaymentsRe
             connector_response_reference_id: Some(item.response.handle),
             incremental_authorization_allowed: None,
             charges: None,
+            authorized_amount: None,
         };
         Ok(Self {
             status: enums::AttemptStatus::from(item.response.state),
@@ -784,6 +784,7 @@ impl ConnectorIntegration<Authorize, PaymentsAuthorizeData, PaymentsResponseData
                         connector_response_reference_id: None,
                         incremental_authorization_allowed: None,
                         charges: None,
+                        authorized_amount: None,
                     }),
                     ..data.clone()
                 })
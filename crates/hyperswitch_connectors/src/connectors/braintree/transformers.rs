@@ -500,6 +500,7 @@ impl<F>
                         connector_response_reference_id: None,
                         incremental_authorization_allowed: None,
                         charges: None,
+                        authorized_amount: None,
                     })
                 };
                 Ok(Self {
@@ -524,6 +525,7 @@ impl<F>
                     connector_response_reference_id: None,
                     incremental_authorization_allowed: None,
                     charges: None,
+                    authorized_amount: None,
                 }),
                 ..item.data
             }),
@@ -686,6 +688,7 @@ impl<F>
                         connector_response_reference_id: None,
                         incremental_authorization_allowed: None,
                         charges: None,
+                        authorized_amount: None,
                     })
                 };
                 Ok(Self {
@@ -710,6 +713,7 @@ impl<F>
                     connector_response_reference_id: None,
                     incremental_authorization_allowed: None,
                     charges: None,
+                    authorized_amount: None,
                 }),
                 ..item.data
             }),
@@ -774,6 +778,7 @@ impl<F>
                         connector_response_reference_id: None,
                         incremental_authorization_allowed: None,
                         charges: None,
+                        authorized_amount: None,
                     })
                 };
                 Ok(Self {
@@ -843,6 +848,7 @@ impl<F>
                         connector_response_reference_id: None,
                         incremental_authorization_allowed: None,
                         charges: None,
+                        authorized_amount: None,
                     })
                 };
                 Ok(Self {
@@ -1392,6 +1398,7 @@ impl TryFrom<PaymentsCaptureResponseRouterData<BraintreeCaptureResponse>>
                         connector_response_reference_id: None,
                         incremental_authorization_allowed: None,
                         charges: None,
+                        authorized_amount: None,
                     })
                 };
                 Ok(Self {
@@ -1595,6 +1602,7 @@ impl<F, T> TryFrom<ResponseRouterData<F, BraintreeCancelResponse, T, PaymentsRes
                         connector_response_reference_id: None,
                         incremental_authorization_allowed: None,
                         charges: None,
+                        authorized_amount: None,
                     })
                 };
                 Ok(Self {
@@ -1701,6 +1709,7 @@ impl<F, T> TryFrom<ResponseRouterData<F, BraintreePSyncResponse, T, PaymentsResp
                         connector_response_reference_id: None,
                         incremental_authorization_allowed: None,
                         charges: None,
+                        authorized_amount: None,
                     })
                 };
                 Ok(Self {
@@ -278,6 +278,7 @@ impl<F>
                         connector_response_reference_id: None,
                         incremental_authorization_allowed: None,
                         charges: None,
+                        authorized_amount: None,
                     }),
                 )
             }
@@ -311,6 +312,7 @@ impl<F, T> TryFrom<ResponseRouterData<F, CashtocodePaymentsSyncResponse, T, Paym
                 connector_response_reference_id: None,
                 incremental_authorization_allowed: None,
                 charges: None,
+                authorized_amount: None,
             }),
             ..item.data
         })
@@ -725,6 +725,7 @@ impl TryFrom<PaymentsResponseRouterData<PaymentsResponse>> for PaymentsAuthorize
             ),
             incremental_authorization_allowed: None,
             charges: None,
+            authorized_amount: None,
         };
         Ok(Self {
             status,
@@ -780,6 +781,7 @@ impl TryFrom<PaymentsSyncResponseRouterData<PaymentsResponse>> for PaymentsSyncR
             ),
             incremental_authorization_allowed: None,
             charges: None,
+            authorized_amount: None,
         };
         Ok(Self {
             status,
@@ -852,6 +854,7 @@ impl TryFrom<PaymentsCancelResponseRouterData<PaymentVoidResponse>> for Payments
                 connector_response_reference_id: None,
                 incremental_authorization_allowed: None,
                 charges: None,
+                authorized_amount: None,
             }),
             status: response.into(),
             ..item.data
@@ -953,6 +956,7 @@ impl TryFrom<PaymentsCaptureResponseRouterData<PaymentCaptureResponse>>
                 connector_response_reference_id: item.response.reference,
                 incremental_authorization_allowed: None,
                 charges: None,
+                authorized_amount: None,
             }),
             status,
             amount_captured,
@@ -153,6 +153,7 @@ impl<F, T> TryFrom<ResponseRouterData<F, CoinbasePaymentsResponse, T, PaymentsRe
                 connector_response_reference_id: Some(item.response.data.id.clone()),
                 incremental_authorization_allowed: None,
                 charges: None,
+                authorized_amount: None,
             }),
             |context| {
                 Ok(PaymentsResponseData::TransactionUnresolvedResponse{
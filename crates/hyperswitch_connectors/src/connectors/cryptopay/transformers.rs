@@ -198,6 +198,7 @@ impl<F, T>
                     .or(Some(item.response.data.id)),
                 incremental_authorization_allowed: None,
                 charges: None,
+                authorized_amount: None,
             })
         };
         match amount_captured_in_minor_units {
@@ -3,11 +3,12 @@ use api_models::payments;
 use api_models::payouts::PayoutMethodData;
 use base64::Engine;
 use common_enums::{enums, FutureUsage};
+use common_types::payments::{L2L3Data, L2L3LineItem};
 use common_utils::{
     consts,
     ext_traits::{OptionExt, ValueExt},
     pii,
-    types::{SemanticVersion, StringMajorUnit},
+    types::{MinorUnit, SemanticVersion, StringMajorUnit, StringMajorUnitForConnector},
 };
 use error_stack::ResultExt;
 #[cfg(feature = "payouts")]
@@ -136,8 +137,15 @@ impl TryFrom<&SetupMandateRouterData> for CybersourceZeroMandateRequest {
             amount_details: Amount {
                 total_amount: StringMajorUnit::zero(),
                 currency: item.request.currency,
+                tax_amount: None,
+                discount_amount: None,
+                duty_amount: None,
+                freight_amount: None,
             },
             bill_to: Some(bill_to),
+            line_items: None,
+            invoice_details: None,
+            ship_from: None,
         };
         let connector_merchant_config =
             CybersourceConnectorMetadataObject::try_from(&item.connector_meta_data)?;
@@ -331,6 +339,7 @@ impl TryFrom<&SetupMandateRouterData> for CybersourceZeroMandateRequest {
             authorization_options,
             commerce_indicator: String::from("internet"),
             payment_solution: solution.map(String::from),
+            purchase_level: None,
         };
         Ok(Self {
             processing_information,
@@ -364,6 +373,8 @@ pub struct ProcessingInformation {
     capture: Option<bool>,
     capture_options: Option<CaptureOptions>,
     payment_solution: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    purchase_level: Option<String>,
 }
 
 #[derive(Debug, Serialize)]
@@ -584,6 +595,48 @@ pub struct Card {
 pub struct OrderInformationWithBill {
     amount_details: Amount,
     bill_to: Option<BillTo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    line_items: Option<Vec<LineItem>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    invoice_details: Option<InvoiceDetails>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ship_from: Option<ShipFrom>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LineItem {
+    product_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    product_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    product_description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    commodity_code: Option<String>,
+    quantity: u16,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    unit_of_measure: Option<String>,
+    unit_price: StringMajorUnit,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    discount_amount: Option<StringMajorUnit>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tax_amount: Option<StringMajorUnit>,
+    total_amount: StringMajorUnit,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InvoiceDetails {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    purchase_order_number: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    taxable: Option<bool>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShipFrom {
+    postal_code: String,
 }
 
 #[derive(Debug, Serialize)]
@@ -603,6 +656,14 @@ pub struct OrderInformation {
 pub struct Amount {
     total_amount: StringMajorUnit,
     currency: api_models::enums::Currency,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tax_amount: Option<StringMajorUnit>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    discount_amount: Option<StringMajorUnit>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    duty_amount: Option<StringMajorUnit>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    freight_amount: Option<StringMajorUnit>,
 }
 
 #[derive(Debug, Serialize)]
@@ -966,6 +1027,7 @@ impl
             capture_options: None,
             commerce_indicator: commerce_indicator_for_external_authentication
                 .unwrap_or(commerce_indicator),
+            purchase_level: get_purchase_level(item.router_data.request.l2_l3_data.as_ref()),
         })
     }
 }
@@ -1076,32 +1138,127 @@ impl
                 .indicator
                 .to_owned()
                 .unwrap_or(String::from("internet")),
+            purchase_level: None,
         })
     }
 }
 
 impl
-    From<(
+    TryFrom<(
         &CybersourceRouterData<&PaymentsAuthorizeRouterData>,
         Option<BillTo>,
     )> for OrderInformationWithBill
 {
-    fn from(
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
         (item, bill_to): (
             &CybersourceRouterData<&PaymentsAuthorizeRouterData>,
             Option<BillTo>,
         ),
-    ) -> Self {
-        Self {
+    ) -> Result<Self, Self::Error> {
+        let currency = item.router_data.request.currency;
+        let l2_l3_data = item.router_data.request.l2_l3_data.as_ref();
+        let line_items = l2_l3_data
+            .and_then(|l2_l3_data| l2_l3_data.line_items.as_ref())
+            .map(|line_items| {
+                line_items
+                    .iter()
+                    .map(|line_item| LineItem::try_from((line_item, currency)))
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .transpose()?;
+        Ok(Self {
             amount_details: Amount {
                 total_amount: item.amount.to_owned(),
-                currency: item.router_data.request.currency,
+                currency,
+                tax_amount: get_optional_amount(
+                    l2_l3_data.and_then(|l2_l3_data| l2_l3_data.tax_amount),
+                    currency,
+                )?,
+                discount_amount: get_optional_amount(
+                    l2_l3_data.and_then(|l2_l3_data| l2_l3_data.discount_amount),
+                    currency,
+                )?,
+                duty_amount: get_optional_amount(
+                    l2_l3_data.and_then(|l2_l3_data| l2_l3_data.duty_amount),
+                    currency,
+                )?,
+                freight_amount: get_optional_amount(
+                    l2_l3_data.and_then(|l2_l3_data| l2_l3_data.freight_amount),
+                    currency,
+                )?,
             },
             bill_to,
+            line_items,
+            invoice_details: l2_l3_data.map(InvoiceDetails::from),
+            ship_from: l2_l3_data
+                .and_then(|l2_l3_data| l2_l3_data.ship_from_postal_code.clone())
+                .map(|postal_code| ShipFrom { postal_code }),
+        })
+    }
+}
+
+impl TryFrom<(&L2L3LineItem, enums::Currency)> for LineItem {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        (line_item, currency): (&L2L3LineItem, enums::Currency),
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            product_name: line_item.product_name.clone(),
+            product_code: line_item.product_code.clone(),
+            product_description: line_item.description.clone(),
+            commodity_code: line_item.commodity_code.clone(),
+            quantity: line_item.quantity,
+            unit_of_measure: line_item.unit_of_measure.clone(),
+            unit_price: utils::convert_amount(
+                &StringMajorUnitForConnector,
+                line_item.unit_amount,
+                currency,
+            )?,
+            discount_amount: get_optional_amount(line_item.discount_amount, currency)?,
+            tax_amount: get_optional_amount(line_item.tax_amount, currency)?,
+            total_amount: utils::convert_amount(
+                &StringMajorUnitForConnector,
+                line_item.get_total_amount().map_err(|_| {
+                    errors::ConnectorError::InvalidDataFormat {
+                        field_name: "l2_l3_data.line_items",
+                    }
+                })?,
+                currency,
+            )?,
+        })
+    }
+}
+
+impl From<&L2L3Data> for InvoiceDetails {
+    fn from(l2_l3_data: &L2L3Data) -> Self {
+        Self {
+            purchase_order_number: l2_l3_data.customer_reference.clone(),
+            taxable: l2_l3_data.tax_exempt.map(|tax_exempt| !tax_exempt),
         }
     }
 }
 
+fn get_optional_amount(
+    amount: Option<MinorUnit>,
+    currency: enums::Currency,
+) -> Result<Option<StringMajorUnit>, error_stack::Report<errors::ConnectorError>> {
+    amount
+        .map(|amount| utils::convert_amount(&StringMajorUnitForConnector, amount, currency))
+        .transpose()
+}
+
+/// Level 3 is sent when line items are present, Level 2 otherwise
+fn get_purchase_level(l2_l3_data: Option<&L2L3Data>) -> Option<String> {
+    l2_l3_data.map(|l2_l3_data| {
+        if l2_l3_data.line_items.is_some() {
+            "3".to_string()
+        } else {
+            "2".to_string()
+        }
+    })
+}
+
 impl
     From<(
         &CybersourceRouterData<&PaymentsCompleteAuthorizeRouterData>,
@@ -1118,8 +1275,15 @@ impl
             amount_details: Amount {
                 total_amount: item.amount.to_owned(),
                 currency: item.router_data.request.currency,
+                tax_amount: None,
+                discount_amount: None,
+                duty_amount: None,
+                freight_amount: None,
             },
             bill_to: Some(bill_to),
+            line_items: None,
+            invoice_details: None,
+            ship_from: None,
         }
     }
 }
@@ -1242,7 +1406,7 @@ impl
             .get_billing_email()
             .or(item.router_data.request.get_email())?;
         let bill_to = build_bill_to(item.router_data.get_optional_billing(), email)?;
-        let order_information = OrderInformationWithBill::from((item, Some(bill_to)));
+        let order_information = OrderInformationWithBill::try_from((item, Some(bill_to)))?;
 
         let additional_card_network = item
             .router_data
@@ -1368,7 +1532,7 @@ impl
             .get_billing_email()
             .or(item.router_data.request.get_email())?;
         let bill_to = build_bill_to(item.router_data.get_optional_billing(), email)?;
-        let order_information = OrderInformationWithBill::from((item, Some(bill_to)));
+        let order_information = OrderInformationWithBill::try_from((item, Some(bill_to)))?;
 
         let card_issuer = ccard.get_card_issuer();
         let card_type = match card_issuer {
@@ -1462,7 +1626,7 @@ impl
     ) -> Result<Self, Self::Error> {
         let email = item.router_data.request.get_email()?;
         let bill_to = build_bill_to(item.router_data.get_optional_billing(), email)?;
-        let order_information = OrderInformationWithBill::from((item, Some(bill_to)));
+        let order_information = OrderInformationWithBill::try_from((item, Some(bill_to)))?;
 
         let card_issuer = token_data.get_card_issuer();
         let card_type = match card_issuer {
@@ -1582,7 +1746,7 @@ impl
             country: paze_data.billing_address.country_code,
             email,
         };
-        let order_information = OrderInformationWithBill::from((item, Some(bill_to)));
+        let order_information = OrderInformationWithBill::try_from((item, Some(bill_to)))?;
 
         let payment_information =
             PaymentInformation::NetworkToken(Box::new(NetworkTokenPaymentInformation {
@@ -1737,7 +1901,7 @@ impl
             .get_billing_email()
             .or(item.router_data.request.get_email())?;
         let bill_to = build_bill_to(item.router_data.get_optional_billing(), email)?;
-        let order_information = OrderInformationWithBill::from((item, Some(bill_to)));
+        let order_information = OrderInformationWithBill::try_from((item, Some(bill_to)))?;
         let processing_information = ProcessingInformation::try_from((
             item,
             Some(PaymentSolution::ApplePay),
@@ -1810,7 +1974,7 @@ impl
             .get_billing_email()
             .or(item.router_data.request.get_email())?;
         let bill_to = build_bill_to(item.router_data.get_optional_billing(), email)?;
-        let order_information = OrderInformationWithBill::from((item, Some(bill_to)));
+        let order_information = OrderInformationWithBill::try_from((item, Some(bill_to)))?;
 
         let payment_information =
             PaymentInformation::GooglePayToken(Box::new(GooglePayTokenPaymentInformation {
@@ -1862,7 +2026,7 @@ impl
             .get_billing_email()
             .or(item.router_data.request.get_email())?;
         let bill_to = build_bill_to(item.router_data.get_optional_billing(), email)?;
-        let order_information = OrderInformationWithBill::from((item, Some(bill_to)));
+        let order_information = OrderInformationWithBill::try_from((item, Some(bill_to)))?;
 
         let payment_information =
             PaymentInformation::GooglePay(Box::new(GooglePayPaymentInformation {
@@ -1947,7 +2111,7 @@ impl
             .get_billing_email()
             .or(item.router_data.request.get_email())?;
         let bill_to = build_bill_to(item.router_data.get_optional_billing(), email)?;
-        let order_information = OrderInformationWithBill::from((item, Some(bill_to)));
+        let order_information = OrderInformationWithBill::try_from((item, Some(bill_to)))?;
 
         let payment_information = get_samsung_pay_payment_information(&samsung_pay_data)
             .attach_printable("Failed to get samsung pay payment information")?;
@@ -2066,7 +2230,7 @@ impl TryFrom<&CybersourceRouterData<&PaymentsAuthorizeRouterData>> for Cybersour
                                         email,
                                     )?;
                                     let order_information =
-                                        OrderInformationWithBill::from((item, Some(bill_to)));
+                                        OrderInformationWithBill::try_from((item, Some(bill_to)))?;
                                     let processing_information =
                                         ProcessingInformation::try_from((
                                             item,
@@ -2256,7 +2420,7 @@ impl TryFrom<(&CybersourceRouterData<&PaymentsAuthorizeRouterData>, String)>
             .get_optional_billing_email()
             .or(item.router_data.request.get_optional_email())
             .and_then(|email| build_bill_to(item.router_data.get_optional_billing(), email).ok());
-        let order_information = OrderInformationWithBill::from((item, bill_to));
+        let order_information = OrderInformationWithBill::try_from((item, bill_to))?;
         let payment_information =
             PaymentInformation::MandatePayment(Box::new(MandatePaymentInformation {
                 payment_instrument,
@@ -2408,13 +2572,21 @@ impl TryFrom<&CybersourceRouterData<&PaymentsCaptureRouterData>>
                 capture: None,
                 commerce_indicator: String::from("internet"),
                 payment_solution: None,
+                purchase_level: None,
             },
             order_information: OrderInformationWithBill {
                 amount_details: Amount {
                     total_amount: item.amount.clone(),
                     currency: item.router_data.request.currency,
+                    tax_amount: None,
+                    discount_amount: None,
+                    duty_amount: None,
+                    freight_amount: None,
                 },
                 bill_to: None,
+                line_items: None,
+                invoice_details: None,
+                ship_from: None,
             },
             client_reference_information: ClientReferenceInformation {
                 code: Some(item.router_data.connector_request_reference_id.clone()),
@@ -2456,6 +2628,7 @@ impl TryFrom<&CybersourceRouterData<&PaymentsIncrementalAuthorizationRouterData>
                 capture: None,
                 capture_options: None,
                 payment_solution: None,
+                purchase_level: None,
             },
             order_information: OrderInformationIncrementalAuthorization {
                 amount_details: AdditionalAmount {
@@ -2508,6 +2681,10 @@ impl TryFrom<&CybersourceRouterData<&PaymentsCancelRouterData>> for CybersourceV
                             field_name: "Currency",
                         },
                     )?,
+                    tax_amount: None,
+                    discount_amount: None,
+                    duty_amount: None,
+                    freight_amount: None,
                 },
                 reason: value
                     .router_data
@@ -2794,6 +2971,7 @@ fn get_payment_response(
                 ),
                 incremental_authorization_allowed,
                 charges: None,
+                authorized_amount: None,
             })
         }
     }
@@ -2889,6 +3067,7 @@ impl<F>
                     ),
                     incremental_authorization_allowed: None,
                     charges: None,
+                    authorized_amount: None,
                 }),
                 ..item.data
             }),
@@ -3066,6 +3245,10 @@ impl TryFrom<&CybersourceRouterData<&PaymentsPreProcessingRouterData>>
                     field_name: "currency",
                 },
             )?,
+            tax_amount: None,
+            discount_amount: None,
+            duty_amount: None,
+            freight_amount: None,
         };
 
         match redirect_response.params {
@@ -3087,6 +3270,9 @@ impl TryFrom<&CybersourceRouterData<&PaymentsPreProcessingRouterData>>
                 let order_information = OrderInformationWithBill {
                     amount_details,
                     bill_to: Some(bill_to),
+                    line_items: None,
+                    invoice_details: None,
+                    ship_from: None,
                 };
                 Ok(Self::AuthEnrollment(Box::new(
                     CybersourceAuthEnrollmentRequest {
@@ -3316,6 +3502,7 @@ impl<F>
                             connector_response_reference_id,
                             incremental_authorization_allowed: None,
                             charges: None,
+                            authorized_amount: None,
                         }),
                         ..item.data
                     })
@@ -3575,6 +3762,7 @@ impl
                         mandate_status == enums::AttemptStatus::Authorized,
                     ),
                     charges: None,
+                    authorized_amount: None,
                 }),
             },
             connector_response,
@@ -3695,6 +3883,7 @@ impl<F>
                                 .unwrap_or(Some(item.response.id)),
                             incremental_authorization_allowed,
                             charges: None,
+                            authorized_amount: None,
                         }),
                         ..item.data
                     })
@@ -3711,6 +3900,7 @@ impl<F>
                     connector_response_reference_id: Some(item.response.id),
                     incremental_authorization_allowed: None,
                     charges: None,
+                    authorized_amount: None,
                 }),
                 ..item.data
             }),
@@ -3733,6 +3923,10 @@ impl<F> TryFrom<&CybersourceRouterData<&RefundsRouterData<F>>> for CybersourceRe
                 amount_details: Amount {
                     total_amount: item.amount.clone(),
                     currency: item.router_data.request.currency,
+                    tax_amount: None,
+                    discount_amount: None,
+                    duty_amount: None,
+                    freight_amount: None,
                 },
             },
             client_reference_information: ClientReferenceInformation {
@@ -3965,6 +4159,10 @@ impl TryFrom<&CybersourceRouterData<&PayoutsRouterData<PoFulfill>>>
                     amount_details: Amount {
                         total_amount: item.amount.to_owned(),
                         currency: item.router_data.request.destination_currency,
+                        tax_amount: None,
+                        discount_amount: None,
+                        duty_amount: None,
+                        freight_amount: None,
                     },
                 };
 
@@ -4322,3 +4520,88 @@ impl RemoveNewLine for Option<String> {
         self.clone().map(|value| value.replace("\n", " "))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    fn get_l2_l3_line_item(unit_amount: i64) -> L2L3LineItem {
+        L2L3LineItem {
+            product_name: "Laptop stand".to_string(),
+            product_code: Some("SKU-1234".to_string()),
+            commodity_code: Some("43211500".to_string()),
+            description: None,
+            quantity: 2,
+            unit_of_measure: Some("EA".to_string()),
+            unit_amount: MinorUnit::new(unit_amount),
+            discount_amount: Some(MinorUnit::new(100)),
+            tax_amount: Some(MinorUnit::new(325)),
+        }
+    }
+
+    fn get_l2_l3_data(line_items: Option<Vec<L2L3LineItem>>) -> L2L3Data {
+        L2L3Data {
+            customer_reference: Some("PO-12345".to_string()),
+            tax_amount: Some(MinorUnit::new(650)),
+            tax_exempt: Some(false),
+            discount_amount: None,
+            duty_amount: None,
+            freight_amount: Some(MinorUnit::new(1000)),
+            ship_from_postal_code: Some("94105".to_string()),
+            line_items,
+        }
+    }
+
+    #[test]
+    fn test_l2_l3_line_item() {
+        let line_item =
+            LineItem::try_from((&get_l2_l3_line_item(2500), enums::Currency::USD)).unwrap();
+
+        assert_eq!(
+            serde_json::to_value(line_item).unwrap(),
+            serde_json::json!({
+                "productName": "Laptop stand",
+                "productCode": "SKU-1234",
+                "commodityCode": "43211500",
+                "quantity": 2,
+                "unitOfMeasure": "EA",
+                "unitPrice": "25.00",
+                "discountAmount": "1.00",
+                "taxAmount": "3.25",
+                "totalAmount": "49.00"
+            })
+        );
+    }
+
+    #[test]
+    fn test_l2_l3_line_item_with_total_amount_out_of_range() {
+        assert!(
+            LineItem::try_from((&get_l2_l3_line_item(i64::MAX), enums::Currency::USD)).is_err()
+        );
+    }
+
+    #[test]
+    fn test_l2_l3_invoice_details_and_purchase_level() {
+        let level_2_data = get_l2_l3_data(None);
+        let level_3_data = get_l2_l3_data(Some(vec![get_l2_l3_line_item(2500)]));
+
+        assert_eq!(
+            serde_json::to_value(InvoiceDetails::from(&level_2_data)).unwrap(),
+            serde_json::json!({
+                "purchaseOrderNumber": "PO-12345",
+                "taxable": true
+            })
+        );
+        assert_eq!(
+            get_purchase_level(Some(&level_2_data)),
+            Some("2".to_string())
+        );
+        assert_eq!(
+            get_purchase_level(Some(&level_3_data)),
+            Some("3".to_string())
+        );
+        assert_eq!(get_purchase_level(None), None);
+    }
+}
@@ -576,6 +576,7 @@ impl<F>
                     connector_response_reference_id: None,
                     incremental_authorization_allowed: None,
                     charges: None,
+                    authorized_amount: None,
                 })
             }
             DatatransResponse::ThreeDSResponse(response) => {
@@ -598,6 +599,7 @@ impl<F>
                     connector_response_reference_id: None,
                     incremental_authorization_allowed: None,
                     charges: None,
+                    authorized_amount: None,
                 })
             }
         };
@@ -648,6 +650,7 @@ impl<F>
                     connector_response_reference_id: None,
                     incremental_authorization_allowed: None,
                     charges: None,
+                    authorized_amount: None,
                 })
             }
             DatatransResponse::ThreeDSResponse(response) => {
@@ -670,6 +673,7 @@ impl<F>
                     connector_response_reference_id: None,
                     incremental_authorization_allowed: None,
                     charges: None,
+                    authorized_amount: None,
                 })
             }
         };
@@ -826,6 +830,7 @@ impl TryFrom<PaymentsSyncResponseRouterData<DatatransSyncResponse>>
                         connector_response_reference_id: None,
                         incremental_authorization_allowed: None,
                         charges: None,
+                        authorized_amount: None,
                     })
                 };
                 Ok(Self {
@@ -390,6 +390,7 @@ impl
                             connector_response_reference_id: Some(processed.tx_id.clone()),
                             incremental_authorization_allowed: None,
                             charges: None,
+                            authorized_amount: None,
                         }),
                         ..item.data
                     }),
@@ -422,6 +423,7 @@ impl
                             connector_response_reference_id: None,
                             incremental_authorization_allowed: None,
                             charges: None,
+                            authorized_amount: None,
                         }),
                         ..item.data
                     }),
@@ -600,6 +602,7 @@ impl
                     connector_response_reference_id: None,
                     incremental_authorization_allowed: None,
                     charges: None,
+                    authorized_amount: None,
                 }),
                 ..item.data
             }),
@@ -651,6 +654,7 @@ impl
                     connector_response_reference_id: None,
                     incremental_authorization_allowed: None,
                     charges: None,
+                    authorized_amount: None,
                 }),
                 ..item.data
             })
@@ -901,6 +905,7 @@ impl
                     connector_response_reference_id: None,
                     incremental_authorization_allowed: None,
                     charges: None,
+                    authorized_amount: None,
                 }),
                 ..item.data
             })
@@ -990,6 +995,7 @@ impl
                     connector_response_reference_id: None,
                     incremental_authorization_allowed: None,
                     charges: None,
+                    authorized_amount: None,
                 }),
                 ..item.data
             })
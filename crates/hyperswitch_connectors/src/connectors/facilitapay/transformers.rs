@@ -422,6 +422,7 @@ impl<F, T> TryFrom<ResponseRouterData<F, FacilitapayPaymentsResponse, T, Payment
                     connector_response_reference_id: Some(item.response.data.transaction_id),
                     incremental_authorization_allowed: None,
                     charges: None,
+                    authorized_amount: None,
                 })
             },
             ..item.data
@@ -872,6 +872,7 @@ impl<F>
                     connector_response_reference_id: None,
                     incremental_authorization_allowed: None,
                     charges: None,
+                    authorized_amount: None,
                 }),
                 ..item.data
             }),
@@ -911,6 +912,7 @@ impl<F>
                             connector_response_reference_id: None,
                             incremental_authorization_allowed: None,
                             charges: None,
+                            authorized_amount: None,
                         }),
                         ..item.data
                     })
@@ -970,6 +972,7 @@ impl<F>
                             connector_response_reference_id: None,
                             incremental_authorization_allowed: None,
                             charges: None,
+                            authorized_amount: None,
                         })
                     };
                     Ok(Self {
@@ -1019,6 +1022,7 @@ impl<F>
                                 connector_response_reference_id: None,
                                 incremental_authorization_allowed: None,
                                 charges: None,
+                                authorized_amount: None,
                             })
                         };
                         Self {
@@ -1038,6 +1042,7 @@ impl<F>
                             connector_response_reference_id: None,
                             incremental_authorization_allowed: None,
                             charges: None,
+                            authorized_amount: None,
                         });
                         Self {
                             response,
@@ -1388,6 +1393,7 @@ impl TryFrom<PaymentsSyncResponseRouterData<FiuuPaymentResponse>> for PaymentsSy
                     connector_response_reference_id: None,
                     incremental_authorization_allowed: None,
                     charges: None,
+                    authorized_amount: None,
                 };
                 Ok(Self {
                     status,
@@ -1452,6 +1458,7 @@ impl TryFrom<PaymentsSyncResponseRouterData<FiuuPaymentResponse>> for PaymentsSy
                     connector_response_reference_id: None,
                     incremental_authorization_allowed: None,
                     charges: None,
+                    authorized_amount: None,
                 };
                 Ok(Self {
                     status,
@@ -1624,6 +1631,7 @@ impl TryFrom<PaymentsCaptureResponseRouterData<PaymentCaptureResponse>>
             connector_response_reference_id: None,
             incremental_authorization_allowed: None,
             charges: None,
+            authorized_amount: None,
         };
         Ok(Self {
             status,
@@ -1739,6 +1747,7 @@ impl TryFrom<PaymentsCancelResponseRouterData<FiuuPaymentCancelResponse>>
             connector_response_reference_id: None,
             incremental_authorization_allowed: None,
             charges: None,
+            authorized_amount: None,
         };
         Ok(Self {
             status,
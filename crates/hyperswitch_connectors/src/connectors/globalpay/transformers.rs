@@ -333,6 +333,7 @@ fn get_payment_response(
             connector_response_reference_id: response.reference,
             incremental_authorization_allowed: None,
             charges: None,
+            authorized_amount: None,
         }),
     }
 }
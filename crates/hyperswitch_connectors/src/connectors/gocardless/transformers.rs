@@ -533,6 +533,7 @@ impl<F>
                 mandate_reference: Box::new(mandate_reference),
                 network_txn_id: None,
                 charges: None,
+                authorized_amount: None,
             }),
             status: enums::AttemptStatus::Charged,
             ..item.data
@@ -688,6 +689,7 @@ impl<F>
                 connector_response_reference_id: None,
                 incremental_authorization_allowed: None,
                 charges: None,
+                authorized_amount: None,
             }),
             ..item.data
         })
@@ -719,6 +721,7 @@ impl<F>
                 connector_response_reference_id: None,
                 incremental_authorization_allowed: None,
                 charges: None,
+                authorized_amount: None,
             }),
             ..item.data
         })
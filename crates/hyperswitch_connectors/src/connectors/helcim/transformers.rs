@@ -385,6 +385,7 @@ impl<F>
                 connector_response_reference_id: item.response.invoice_number.clone(),
                 incremental_authorization_allowed: None,
                 charges: None,
+                authorized_amount: None,
             }),
             status: enums::AttemptStatus::from(item.response),
             ..item.data
@@ -436,6 +437,7 @@ impl<F>
                 connector_response_reference_id: item.response.invoice_number.clone(),
                 incremental_authorization_allowed: None,
                 charges: None,
+                authorized_amount: None,
             }),
             status: enums::AttemptStatus::from(item.response),
             ..item.data
@@ -485,6 +487,7 @@ impl<F>
                     connector_response_reference_id: item.response.invoice_number.clone(),
                     incremental_authorization_allowed: None,
                     charges: None,
+                    authorized_amount: None,
                 }),
                 status: enums::AttemptStatus::from(item.response),
                 ..item.data
@@ -565,6 +568,7 @@ impl<F>
                 connector_response_reference_id: item.response.invoice_number.clone(),
                 incremental_authorization_allowed: None,
                 charges: None,
+                authorized_amount: None,
             }),
             status: enums::AttemptStatus::from(item.response),
             ..item.data
@@ -622,6 +626,7 @@ impl<F>
                 connector_response_reference_id: item.response.invoice_number.clone(),
                 incremental_authorization_allowed: None,
                 charges: None,
+                authorized_amount: None,
             }),
             status: enums::AttemptStatus::from(item.response),
             ..item.data
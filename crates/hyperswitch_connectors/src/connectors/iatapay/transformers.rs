@@ -394,6 +394,7 @@ fn get_iatpay_response(
                 connector_response_reference_id: connector_response_reference_id.clone(),
                 incremental_authorization_allowed: None,
                 charges: None,
+                authorized_amount: None,
             }
         }
         None => PaymentsResponseData::TransactionResponse {
@@ -405,6 +406,7 @@ fn get_iatpay_response(
             connector_response_reference_id: connector_response_reference_id.clone(),
             incremental_authorization_allowed: None,
             charges: None,
+            authorized_amount: None,
         },
     };
 
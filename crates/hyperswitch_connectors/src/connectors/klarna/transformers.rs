@@ -394,6 +394,7 @@ impl TryFrom<PaymentsResponseRouterData<KlarnaAuthResponse>>
                         connector_response_reference_id: Some(response.order_id.clone()),
                         incremental_authorization_allowed: None,
                         charges: None,
+                        authorized_amount: None,
                     }),
                     status: get_fraud_status(
                         response.fraud_status.clone(),
@@ -415,6 +416,7 @@ impl TryFrom<PaymentsResponseRouterData<KlarnaAuthResponse>>
                     connector_response_reference_id: Some(response.order_id.clone()),
                     incremental_authorization_allowed: None,
                     charges: None,
+                    authorized_amount: None,
                 }),
                 status: get_checkout_status(
                     response.status.clone(),
@@ -577,6 +579,7 @@ impl<F, T> TryFrom<ResponseRouterData<F, KlarnaPsyncResponse, T, PaymentsRespons
                         .or(Some(response.order_id.clone())),
                     incremental_authorization_allowed: None,
                     charges: None,
+                    authorized_amount: None,
                 }),
                 ..item.data
             }),
@@ -591,6 +594,7 @@ impl<F, T> TryFrom<ResponseRouterData<F, KlarnaPsyncResponse, T, PaymentsRespons
                     connector_response_reference_id: Some(response.order_id.clone()),
                     incremental_authorization_allowed: None,
                     charges: None,
+                    authorized_amount: None,
                 }),
                 ..item.data
             }),
@@ -663,6 +667,7 @@ impl<F>
                 connector_response_reference_id: None,
                 incremental_authorization_allowed: None,
                 charges: None,
+                authorized_amount: None,
             }),
             status,
             ..item.data
@@ -1012,6 +1012,7 @@ impl<F, T> TryFrom<ResponseRouterData<F, MultisafepayAuthResponse, T, PaymentsRe
                             ),
                             incremental_authorization_allowed: None,
                             charges: None,
+                            authorized_amount: None,
                         })
                     },
                     ..item.data
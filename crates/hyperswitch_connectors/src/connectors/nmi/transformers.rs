@@ -228,6 +228,7 @@ impl
                     connector_response_reference_id: Some(item.response.transactionid),
                     incremental_authorization_allowed: None,
                     charges: None,
+                    authorized_amount: None,
                 }),
                 AttemptStatus::AuthenticationPending,
             ),
@@ -383,6 +384,7 @@ impl
                     connector_response_reference_id: Some(item.response.orderid),
                     incremental_authorization_allowed: None,
                     charges: None,
+                    authorized_amount: None,
                 }),
                 if let Some(CaptureMethod::Automatic) = item.data.request.capture_method {
                     AttemptStatus::CaptureInitiated
@@ -742,6 +744,7 @@ impl
                     connector_response_reference_id: Some(item.response.orderid),
                     incremental_authorization_allowed: None,
                     charges: None,
+                    authorized_amount: None,
                 }),
                 AttemptStatus::CaptureInitiated,
             ),
@@ -846,6 +849,7 @@ impl<T> TryFrom<ResponseRouterData<SetupMandate, StandardResponse, T, PaymentsRe
                     connector_response_reference_id: Some(item.response.orderid),
                     incremental_authorization_allowed: None,
                     charges: None,
+                    authorized_amount: None,
                 }),
                 AttemptStatus::Charged,
             ),
@@ -900,6 +904,7 @@ impl TryFrom<PaymentsResponseRouterData<StandardResponse>>
                     connector_response_reference_id: Some(item.response.orderid),
                     incremental_authorization_allowed: None,
                     charges: None,
+                    authorized_amount: None,
                 }),
                 if let Some(CaptureMethod::Automatic) = item.data.request.capture_method {
                     AttemptStatus::CaptureInitiated
@@ -940,6 +945,7 @@ impl<T> TryFrom<ResponseRouterData<Void, StandardResponse, T, PaymentsResponseDa
                     connector_response_reference_id: Some(item.response.orderid),
                     incremental_authorization_allowed: None,
                     charges: None,
+                    authorized_amount: None,
                 }),
                 AttemptStatus::VoidInitiated,
             ),
@@ -988,6 +994,7 @@ impl<F, T> TryFrom<ResponseRouterData<F, SyncResponse, T, PaymentsResponseData>>
                     connector_response_reference_id: None,
                     incremental_authorization_allowed: None,
                     charges: None,
+                    authorized_amount: None,
                 }),
                 ..item.data
             }),
@@ -613,6 +613,7 @@ impl<F, T> TryFrom<ResponseRouterData<F, NoonPaymentsResponse, T, PaymentsRespon
                         connector_response_reference_id,
                         incremental_authorization_allowed: None,
                         charges: None,
+                        authorized_amount: None,
                     })
                 }
             },
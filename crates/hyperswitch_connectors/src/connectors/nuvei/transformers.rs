@@ -1647,6 +1647,7 @@ where
                     connector_response_reference_id: response.order_id,
                     incremental_authorization_allowed: None,
                     charges: None,
+                    authorized_amount: None,
                 })
             },
             ..item.data
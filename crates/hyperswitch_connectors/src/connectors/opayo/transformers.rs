@@ -161,6 +161,7 @@ impl<F, T> TryFrom<ResponseRouterData<F, OpayoPaymentsResponse, T, PaymentsRespo
                 connector_response_reference_id: Some(item.response.transaction_id),
                 incremental_authorization_allowed: None,
                 charges: None,
+                authorized_amount: None,
             }),
             ..item.data
         })
@@ -142,6 +142,7 @@ impl<F, T> TryFrom<ResponseRouterData<F, OpennodePaymentsResponse, T, PaymentsRe
                 connector_response_reference_id: item.response.data.order_id,
                 incremental_authorization_allowed: None,
                 charges: None,
+                authorized_amount: None,
             })
         } else {
             Ok(PaymentsResponseData::TransactionUnresolvedResponse {
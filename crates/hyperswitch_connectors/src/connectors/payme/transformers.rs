@@ -265,6 +265,7 @@ impl TryFrom<&PaymePaySaleResponse> for PaymentsResponseData {
             connector_response_reference_id: None,
             incremental_authorization_allowed: None,
             charges: None,
+            authorized_amount: None,
         })
     }
 }
@@ -335,6 +336,7 @@ impl From<&SaleQuery> for PaymentsResponseData {
             connector_response_reference_id: None,
             incremental_authorization_allowed: None,
             charges: None,
+            authorized_amount: None,
         }
     }
 }
@@ -556,6 +558,7 @@ impl<F>
                             connector_response_reference_id: None,
                             incremental_authorization_allowed: None,
                             charges: None,
+                            authorized_amount: None,
                         }),
                         ..item.data
                     }),
@@ -1129,6 +1132,7 @@ impl TryFrom<PaymentsCancelResponseRouterData<PaymeVoidResponse>> for PaymentsCa
                 connector_response_reference_id: None,
                 incremental_authorization_allowed: None,
                 charges: None,
+                authorized_amount: None,
             })
         };
         Ok(Self {
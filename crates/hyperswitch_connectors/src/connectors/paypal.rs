@@ -1216,6 +1216,7 @@ impl ConnectorIntegration<PreProcessing, PaymentsPreProcessingData, PaymentsResp
                                 connector_response_reference_id: None,
                                 incremental_authorization_allowed: None,
                                 charges: None,
+                                authorized_amount: None,
                             }),
                             ..data.clone()
                         })
@@ -1270,6 +1271,7 @@ impl ConnectorIntegration<PreProcessing, PaymentsPreProcessingData, PaymentsResp
                         connector_response_reference_id: None,
                         incremental_authorization_allowed: None,
                         charges: None,
+                        authorized_amount: None,
                     }),
                     ..data.clone()
                 })
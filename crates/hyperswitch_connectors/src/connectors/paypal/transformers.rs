@@ -639,6 +639,7 @@ impl<F, T> TryFrom<ResponseRouterData<F, PaypalSetupMandatesResponse, T, Payment
                 connector_response_reference_id: Some(info_response.id.clone()),
                 incremental_authorization_allowed: None,
                 charges: None,
+                authorized_amount: None,
             }),
             ..item.data
         })
@@ -1870,6 +1871,7 @@ impl<F, T> TryFrom<ResponseRouterData<F, PaypalOrdersResponse, T, PaymentsRespon
                     .or(Some(item.response.id)),
                 incremental_authorization_allowed: None,
                 charges: None,
+                authorized_amount: None,
             }),
             ..item.data
         })
@@ -1982,6 +1984,7 @@ impl<F, T>
                 ),
                 incremental_authorization_allowed: None,
                 charges: None,
+                authorized_amount: None,
             }),
             ..item.data
         })
@@ -2034,6 +2037,7 @@ impl
                 ),
                 incremental_authorization_allowed: None,
                 charges: None,
+                authorized_amount: None,
             }),
             ..item.data
         })
@@ -2084,6 +2088,7 @@ impl
                 connector_response_reference_id: None,
                 incremental_authorization_allowed: None,
                 charges: None,
+                authorized_amount: None,
             }),
             ..item.data
         })
@@ -2117,6 +2122,7 @@ impl<F>
                 connector_response_reference_id: None,
                 incremental_authorization_allowed: None,
                 charges: None,
+                authorized_amount: None,
             }),
             ..item.data
         })
@@ -2165,6 +2171,7 @@ impl<F>
                 connector_response_reference_id: None,
                 incremental_authorization_allowed: None,
                 charges: None,
+                authorized_amount: None,
             }),
             ..item.data
         })
@@ -2227,6 +2234,7 @@ impl<F, T> TryFrom<ResponseRouterData<F, PaypalPaymentsSyncResponse, T, Payments
                     .or(Some(item.response.supplementary_data.related_ids.order_id)),
                 incremental_authorization_allowed: None,
                 charges: None,
+                authorized_amount: None,
             }),
             ..item.data
         })
@@ -2509,6 +2517,7 @@ impl TryFrom<PaymentsCaptureResponseRouterData<PaypalCaptureResponse>>
         let amount_captured = match status {
             storage_enums::AttemptStatus::Pending
             | storage_enums::AttemptStatus::Authorized
+            | storage_enums::AttemptStatus::PartiallyAuthorized
             | storage_enums::AttemptStatus::Failure
             | storage_enums::AttemptStatus::RouterDeclined
             | storage_enums::AttemptStatus::AuthenticationFailed
@@ -2558,6 +2567,7 @@ impl TryFrom<PaymentsCaptureResponseRouterData<PaypalCaptureResponse>>
                     .or(Some(item.response.id)),
                 incremental_authorization_allowed: None,
                 charges: None,
+                authorized_amount: None,
             }),
             amount_captured: Some(amount_captured),
             ..item.data
@@ -2603,6 +2613,7 @@ impl<F, T> TryFrom<ResponseRouterData<F, PaypalPaymentsCancelResponse, T, Paymen
                     .or(Some(item.response.id)),
                 incremental_authorization_allowed: None,
                 charges: None,
+                authorized_amount: None,
             }),
             ..item.data
         })
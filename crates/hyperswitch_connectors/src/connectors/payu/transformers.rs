@@ -241,6 +241,7 @@ impl<F, T> TryFrom<ResponseRouterData<F, PayuPaymentsResponse, T, PaymentsRespon
                     .or(Some(item.response.order_id)),
                 incremental_authorization_allowed: None,
                 charges: None,
+                authorized_amount: None,
             }),
             amount_captured: None,
             ..item.data
@@ -288,6 +289,7 @@ impl<F, T> TryFrom<ResponseRouterData<F, PayuPaymentsCaptureResponse, T, Payment
                 connector_response_reference_id: None,
                 incremental_authorization_allowed: None,
                 charges: None,
+                authorized_amount: None,
             }),
             amount_captured: None,
             ..item.data
@@ -366,6 +368,7 @@ impl<F, T> TryFrom<ResponseRouterData<F, PayuPaymentsCancelResponse, T, Payments
                     .or(Some(item.response.order_id)),
                 incremental_authorization_allowed: None,
                 charges: None,
+                authorized_amount: None,
             }),
             amount_captured: None,
             ..item.data
@@ -495,6 +498,7 @@ impl<F, T> TryFrom<ResponseRouterData<F, PayuPaymentsSyncResponse, T, PaymentsRe
                     .or(Some(order.order_id.clone())),
                 incremental_authorization_allowed: None,
                 charges: None,
+                authorized_amount: None,
             }),
             amount_captured: Some(
                 order
@@ -323,6 +323,7 @@ impl<F, T> TryFrom<ResponseRouterData<F, PlaidPaymentsResponse, T, PaymentsRespo
                     connector_response_reference_id: Some(item.response.payment_id),
                     incremental_authorization_allowed: None,
                     charges: None,
+                    authorized_amount: None,
                 })
             },
             ..item.data
@@ -411,6 +412,7 @@ impl<F, T> TryFrom<ResponseRouterData<F, PlaidSyncResponse, T, PaymentsResponseD
                     connector_response_reference_id: Some(item.response.payment_id),
                     incremental_authorization_allowed: None,
                     charges: None,
+                    authorized_amount: None,
                 })
             },
             ..item.data
@@ -344,6 +344,7 @@ impl<F, T> TryFrom<ResponseRouterData<F, PowertranzBaseResponse, T, PaymentsResp
                 connector_response_reference_id: Some(item.response.order_identifier),
                 incremental_authorization_allowed: None,
                 charges: None,
+                authorized_amount: None,
             }),
             Err,
         );
@@ -488,6 +488,7 @@ impl<F, T> TryFrom<ResponseRouterData<F, RapydPaymentsResponse, T, PaymentsRespo
                                     .to_owned(),
                                 incremental_authorization_allowed: None,
                                 charges: None,
+                                authorized_amount: None,
                             }),
                         )
                     }
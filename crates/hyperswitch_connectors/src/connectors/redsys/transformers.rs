@@ -731,6 +731,7 @@ fn handle_threeds_invoke<F>(
             connector_response_reference_id: Some(response_data.ds_order.clone()),
             incremental_authorization_allowed: None,
             charges: None,
+            authorized_amount: None,
         }),
         ..item.data
     })
@@ -767,6 +768,7 @@ fn handle_threeds_invoke_exempt<F>(
             connector_response_reference_id: Some(response_data.ds_order.clone()),
             incremental_authorization_allowed: None,
             charges: None,
+            authorized_amount: None,
         }),
         ..item.data
     })
@@ -1270,6 +1272,7 @@ impl<F> TryFrom<ResponseRouterData<F, RedsysResponse, PaymentsCaptureData, Payme
                         connector_response_reference_id: Some(response_data.ds_order.clone()),
                         incremental_authorization_allowed: None,
                         charges: None,
+                        authorized_amount: None,
                     })
                 };
                 (response, status)
@@ -1371,6 +1374,7 @@ impl<F> TryFrom<ResponseRouterData<F, RedsysResponse, PaymentsCancelData, Paymen
                         connector_response_reference_id: Some(response_data.ds_order.clone()),
                         incremental_authorization_allowed: None,
                         charges: None,
+                        authorized_amount: None,
                     })
                 };
                 (response, status)
@@ -1503,6 +1507,7 @@ fn get_payments_response(
                 connector_response_reference_id: Some(redsys_payments_response.ds_order.clone()),
                 incremental_authorization_allowed: None,
                 charges: None,
+                authorized_amount: None,
             })
         };
 
@@ -1523,6 +1528,7 @@ fn get_payments_response(
             connector_response_reference_id: Some(redsys_payments_response.ds_order.clone()),
             incremental_authorization_allowed: None,
             charges: None,
+            authorized_amount: None,
         });
 
         Ok((response, enums::AttemptStatus::AuthenticationPending))
@@ -1577,6 +1583,7 @@ fn get_transaction_type(
         | enums::AttemptStatus::Started
         | enums::AttemptStatus::Authorizing
         | enums::AttemptStatus::Authorized
+        | enums::AttemptStatus::PartiallyAuthorized
         | enums::AttemptStatus::DeviceDataCollectionPending => match capture_method {
             Some(enums::CaptureMethod::Automatic) | None => {
                 Ok(transaction_type::PAYMENT.to_owned())
@@ -1791,6 +1798,7 @@ impl<F> TryFrom<ResponseRouterData<F, RedsysSyncResponse, PaymentsSyncData, Paym
                             connector_response_reference_id: Some(response.ds_order.clone()),
                             incremental_authorization_allowed: None,
                             charges: None,
+                            authorized_amount: None,
                         });
                         (status, payment_response)
                     }
@@ -1805,6 +1813,7 @@ impl<F> TryFrom<ResponseRouterData<F, RedsysSyncResponse, PaymentsSyncData, Paym
                         connector_response_reference_id: Some(response.ds_order.clone()),
                         incremental_authorization_allowed: None,
                         charges: None,
+                        authorized_amount: None,
                     });
 
                     (item.data.status, payment_response)
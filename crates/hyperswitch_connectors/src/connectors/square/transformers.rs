@@ -399,6 +399,7 @@ impl<F, T> TryFrom<ResponseRouterData<F, SquarePaymentsResponse, T, PaymentsResp
                 connector_response_reference_id: item.response.payment.reference_id,
                 incremental_authorization_allowed: None,
                 charges: None,
+                authorized_amount: None,
             }),
             amount_captured,
             ..item.data
@@ -2549,6 +2549,7 @@ where
                 connector_response_reference_id: Some(item.response.id),
                 incremental_authorization_allowed: None,
                 charges,
+                authorized_amount: None,
             })
         };
 
@@ -2808,6 +2809,7 @@ where
                 connector_response_reference_id: Some(item.response.id.clone()),
                 incremental_authorization_allowed: None,
                 charges,
+                authorized_amount: None,
             })
         };
 
@@ -2885,6 +2887,7 @@ impl<F, T> TryFrom<ResponseRouterData<F, SetupIntentResponse, T, PaymentsRespons
                 connector_response_reference_id: Some(item.response.id),
                 incremental_authorization_allowed: None,
                 charges: None,
+                authorized_amount: None,
             })
         };
 
@@ -3575,6 +3578,7 @@ impl<F, T> TryFrom<ResponseRouterData<F, ChargesResponse, T, PaymentsResponseDat
                 connector_response_reference_id: Some(item.response.id.clone()),
                 incremental_authorization_allowed: None,
                 charges: None,
+                authorized_amount: None,
             })
         };
 
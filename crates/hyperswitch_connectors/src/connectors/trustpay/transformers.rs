@@ -840,6 +840,7 @@ fn handle_cards_response(
         connector_response_reference_id: None,
         incremental_authorization_allowed: None,
         charges: None,
+        authorized_amount: None,
     };
     Ok((status, error, payment_response_data))
 }
@@ -868,6 +869,7 @@ fn handle_bank_redirects_response(
         connector_response_reference_id: None,
         incremental_authorization_allowed: None,
         charges: None,
+        authorized_amount: None,
     };
     Ok((status, error, payment_response_data))
 }
@@ -910,6 +912,7 @@ fn handle_bank_redirects_error_response(
         connector_response_reference_id: None,
         incremental_authorization_allowed: None,
         charges: None,
+        authorized_amount: None,
     };
     Ok((status, error, payment_response_data))
 }
@@ -974,6 +977,7 @@ fn handle_bank_redirects_sync_response(
         connector_response_reference_id: None,
         incremental_authorization_allowed: None,
         charges: None,
+        authorized_amount: None,
     };
     Ok((status, error, payment_response_data))
 }
@@ -1025,6 +1029,7 @@ pub fn handle_webhook_response(
         connector_response_reference_id: None,
         incremental_authorization_allowed: None,
         charges: None,
+        authorized_amount: None,
     };
     Ok((status, error, payment_response_data))
 }
@@ -254,6 +254,7 @@ fn get_payments_response(connector_response: TsysResponse) -> PaymentsResponseDa
         connector_response_reference_id: Some(connector_response.transaction_id),
         incremental_authorization_allowed: None,
         charges: None,
+        authorized_amount: None,
     }
 }
 
@@ -279,6 +280,7 @@ fn get_payments_sync_response(
         ),
         incremental_authorization_allowed: None,
         charges: None,
+        authorized_amount: None,
     }
 }
 
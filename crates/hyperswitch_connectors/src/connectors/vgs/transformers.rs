@@ -137,6 +137,7 @@ impl<F, T> TryFrom<ResponseRouterData<F, VgsPaymentsResponse, T, PaymentsRespons
                 connector_response_reference_id: None,
                 incremental_authorization_allowed: None,
                 charges: None,
+                authorized_amount: None,
             }),
             ..item.data
         })
@@ -291,6 +291,7 @@ impl<F, T> TryFrom<ResponseRouterData<F, VoltPaymentsResponse, T, PaymentsRespon
                 connector_response_reference_id: Some(item.response.id),
                 incremental_authorization_allowed: None,
                 charges: None,
+                authorized_amount: None,
             }),
             ..item.data
         })
@@ -372,6 +373,7 @@ impl<F, T> TryFrom<ResponseRouterData<F, VoltPaymentsResponseData, T, PaymentsRe
                                 .or(Some(payment_response.id)),
                             incremental_authorization_allowed: None,
                             charges: None,
+                            authorized_amount: None,
                         })
                     },
                     ..item.data
@@ -416,6 +418,7 @@ impl<F, T> TryFrom<ResponseRouterData<F, VoltPaymentsResponseData, T, PaymentsRe
                                 .or(Some(webhook_response.payment)),
                             incremental_authorization_allowed: None,
                             charges: None,
+                            authorized_amount: None,
                         })
                     },
                     ..item.data
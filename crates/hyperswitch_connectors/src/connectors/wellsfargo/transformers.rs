@@ -1,9 +1,10 @@
 use api_models::payments;
 use base64::Engine;
 use common_enums::{enums, FutureUsage};
+use common_types::payments::{L2L3Data, L2L3LineItem};
 use common_utils::{
     consts, pii,
-    types::{SemanticVersion, StringMajorUnit},
+    types::{MinorUnit, SemanticVersion, StringMajorUnit, StringMajorUnitForConnector},
 };
 use hyperswitch_domain_models::{
     payment_method_data::{
@@ -78,8 +79,15 @@ impl TryFrom<&SetupMandateRouterData> for WellsfargoZeroMandateRequest {
             amount_details: Amount {
                 total_amount: StringMajorUnit::zero(),
                 currency: item.request.currency,
+                tax_amount: None,
+                discount_amount: None,
+                duty_amount: None,
+                freight_amount: None,
             },
             bill_to: Some(bill_to),
+            line_items: None,
+            invoice_details: None,
+            ship_from: None,
         };
         let (action_list, action_token_types, authorization_options) = (
             Some(vec![WellsfargoActionsList::TokenCreate]),
@@ -94,6 +102,7 @@ impl TryFrom<&SetupMandateRouterData> for WellsfargoZeroMandateRequest {
                     stored_credential_used: None,
                 }),
                 merchant_intitiated_transaction: None,
+                partial_auth_indicator: None,
             }),
         );
 
@@ -246,6 +255,7 @@ impl TryFrom<&SetupMandateRouterData> for WellsfargoZeroMandateRequest {
             authorization_options,
             commerce_indicator: String::from("internet"),
             payment_solution: solution.map(String::from),
+            purchase_level: None,
         };
         Ok(Self {
             processing_information,
@@ -279,6 +289,8 @@ pub struct ProcessingInformation {
     capture: Option<bool>,
     capture_options: Option<CaptureOptions>,
     payment_solution: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    purchase_level: Option<String>,
 }
 
 #[derive(Debug, Serialize)]
@@ -325,6 +337,8 @@ pub enum WellsfargoActionsTokenType {
 pub struct WellsfargoAuthorizationOptions {
     initiator: Option<WellsfargoPaymentInitiator>,
     merchant_intitiated_transaction: Option<MerchantInitiatedTransaction>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    partial_auth_indicator: Option<bool>,
 }
 
 #[derive(Debug, Serialize)]
@@ -475,6 +489,48 @@ pub struct Card {
 pub struct OrderInformationWithBill {
     amount_details: Amount,
     bill_to: Option<BillTo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    line_items: Option<Vec<LineItem>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    invoice_details: Option<InvoiceDetails>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ship_from: Option<ShipFrom>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LineItem {
+    product_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    product_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    product_description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    commodity_code: Option<String>,
+    quantity: u16,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    unit_of_measure: Option<String>,
+    unit_price: StringMajorUnit,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    discount_amount: Option<StringMajorUnit>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tax_amount: Option<StringMajorUnit>,
+    total_amount: StringMajorUnit,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InvoiceDetails {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    purchase_order_number: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    taxable: Option<bool>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShipFrom {
+    postal_code: String,
 }
 
 #[derive(Debug, Serialize)]
@@ -494,6 +550,14 @@ pub struct OrderInformation {
 pub struct Amount {
     total_amount: StringMajorUnit,
     currency: api_models::enums::Currency,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tax_amount: Option<StringMajorUnit>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    discount_amount: Option<StringMajorUnit>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    duty_amount: Option<StringMajorUnit>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    freight_amount: Option<StringMajorUnit>,
 }
 
 #[derive(Debug, Serialize)]
@@ -608,6 +672,7 @@ impl
                         stored_credential_used: None,
                     }),
                     merchant_intitiated_transaction: None,
+                    partial_auth_indicator: None,
                 }),
             )
         } else if item.router_data.request.mandate_id.is_some() {
@@ -641,6 +706,7 @@ impl
                                 )?),
                                 previous_transaction_id: None,
                             }),
+                            partial_auth_indicator: None,
                         }),
                     )
                 }
@@ -707,6 +773,7 @@ impl
                                 original_authorized_amount,
                                 previous_transaction_id: Some(Secret::new(network_transaction_id)),
                             }),
+                            partial_auth_indicator: None,
                         }),
                     )
                 }
@@ -717,6 +784,10 @@ impl
         } else {
             (None, None, None)
         };
+        let authorization_options = get_authorization_options_with_partial_authorization(
+            authorization_options,
+            item.router_data.request.enable_partial_authorization,
+        );
         // this logic is for external authenticated card
         let commerce_indicator_for_external_authentication = item
             .router_data
@@ -744,10 +815,33 @@ impl
             capture_options: None,
             commerce_indicator: commerce_indicator_for_external_authentication
                 .unwrap_or(commerce_indicator),
+            purchase_level: get_purchase_level(item.router_data.request.l2_l3_data.as_ref()),
         })
     }
 }
 
+/// Issuers approve a part of the amount only when the partial authorization indicator is sent, which
+/// is done only for merchants who opted in to partial authorization.
+fn get_authorization_options_with_partial_authorization(
+    authorization_options: Option<WellsfargoAuthorizationOptions>,
+    enable_partial_authorization: Option<bool>,
+) -> Option<WellsfargoAuthorizationOptions> {
+    if enable_partial_authorization != Some(true) {
+        return authorization_options;
+    }
+    Some(match authorization_options {
+        Some(authorization_options) => WellsfargoAuthorizationOptions {
+            partial_auth_indicator: Some(true),
+            ..authorization_options
+        },
+        None => WellsfargoAuthorizationOptions {
+            initiator: None,
+            merchant_intitiated_transaction: None,
+            partial_auth_indicator: Some(true),
+        },
+    })
+}
+
 fn get_commerce_indicator_for_external_authentication(
     card_network: Option<String>,
     eci: String,
@@ -799,27 +893,121 @@ fn get_commerce_indicator_for_external_authentication(
 }
 
 impl
-    From<(
+    TryFrom<(
         &WellsfargoRouterData<&PaymentsAuthorizeRouterData>,
         Option<BillTo>,
     )> for OrderInformationWithBill
 {
-    fn from(
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
         (item, bill_to): (
             &WellsfargoRouterData<&PaymentsAuthorizeRouterData>,
             Option<BillTo>,
         ),
-    ) -> Self {
-        Self {
+    ) -> Result<Self, Self::Error> {
+        let currency = item.router_data.request.currency;
+        let l2_l3_data = item.router_data.request.l2_l3_data.as_ref();
+        let line_items = l2_l3_data
+            .and_then(|l2_l3_data| l2_l3_data.line_items.as_ref())
+            .map(|line_items| {
+                line_items
+                    .iter()
+                    .map(|line_item| LineItem::try_from((line_item, currency)))
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .transpose()?;
+        Ok(Self {
             amount_details: Amount {
                 total_amount: item.amount.to_owned(),
-                currency: item.router_data.request.currency,
+                currency,
+                tax_amount: get_optional_amount(
+                    l2_l3_data.and_then(|l2_l3_data| l2_l3_data.tax_amount),
+                    currency,
+                )?,
+                discount_amount: get_optional_amount(
+                    l2_l3_data.and_then(|l2_l3_data| l2_l3_data.discount_amount),
+                    currency,
+                )?,
+                duty_amount: get_optional_amount(
+                    l2_l3_data.and_then(|l2_l3_data| l2_l3_data.duty_amount),
+                    currency,
+                )?,
+                freight_amount: get_optional_amount(
+                    l2_l3_data.and_then(|l2_l3_data| l2_l3_data.freight_amount),
+                    currency,
+                )?,
             },
             bill_to,
+            line_items,
+            invoice_details: l2_l3_data.map(InvoiceDetails::from),
+            ship_from: l2_l3_data
+                .and_then(|l2_l3_data| l2_l3_data.ship_from_postal_code.clone())
+                .map(|postal_code| ShipFrom { postal_code }),
+        })
+    }
+}
+
+impl TryFrom<(&L2L3LineItem, enums::Currency)> for LineItem {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        (line_item, currency): (&L2L3LineItem, enums::Currency),
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            product_name: line_item.product_name.clone(),
+            product_code: line_item.product_code.clone(),
+            product_description: line_item.description.clone(),
+            commodity_code: line_item.commodity_code.clone(),
+            quantity: line_item.quantity,
+            unit_of_measure: line_item.unit_of_measure.clone(),
+            unit_price: utils::convert_amount(
+                &StringMajorUnitForConnector,
+                line_item.unit_amount,
+                currency,
+            )?,
+            discount_amount: get_optional_amount(line_item.discount_amount, currency)?,
+            tax_amount: get_optional_amount(line_item.tax_amount, currency)?,
+            total_amount: utils::convert_amount(
+                &StringMajorUnitForConnector,
+                line_item.get_total_amount().map_err(|_| {
+                    errors::ConnectorError::InvalidDataFormat {
+                        field_name: "l2_l3_data.line_items",
+                    }
+                })?,
+                currency,
+            )?,
+        })
+    }
+}
+
+impl From<&L2L3Data> for InvoiceDetails {
+    fn from(l2_l3_data: &L2L3Data) -> Self {
+        Self {
+            purchase_order_number: l2_l3_data.customer_reference.clone(),
+            taxable: l2_l3_data.tax_exempt.map(|tax_exempt| !tax_exempt),
         }
     }
 }
 
+fn get_optional_amount(
+    amount: Option<MinorUnit>,
+    currency: enums::Currency,
+) -> Result<Option<StringMajorUnit>, error_stack::Report<errors::ConnectorError>> {
+    amount
+        .map(|amount| utils::convert_amount(&StringMajorUnitForConnector, amount, currency))
+        .transpose()
+}
+
+/// Level 3 is sent when line items are present, Level 2 otherwise
+fn get_purchase_level(l2_l3_data: Option<&L2L3Data>) -> Option<String> {
+    l2_l3_data.map(|l2_l3_data| {
+        if l2_l3_data.line_items.is_some() {
+            "3".to_string()
+        } else {
+            "2".to_string()
+        }
+    })
+}
+
 fn get_phone_number(
     item: Option<&hyperswitch_domain_models::address::Address>,
 ) -> Option<Secret<String>> {
@@ -899,7 +1087,7 @@ impl
     ) -> Result<Self, Self::Error> {
         let email = item.router_data.request.get_email()?;
         let bill_to = build_bill_to(item.router_data.get_optional_billing(), email)?;
-        let order_information = OrderInformationWithBill::from((item, Some(bill_to)));
+        let order_information = OrderInformationWithBill::try_from((item, Some(bill_to)))?;
 
         let card_issuer = ccard.get_card_issuer();
         let card_type = match card_issuer {
@@ -981,7 +1169,7 @@ impl
     ) -> Result<Self, Self::Error> {
         let email = item.router_data.request.get_email()?;
         let bill_to = build_bill_to(item.router_data.get_optional_billing(), email)?;
-        let order_information = OrderInformationWithBill::from((item, Some(bill_to)));
+        let order_information = OrderInformationWithBill::try_from((item, Some(bill_to)))?;
         let processing_information = ProcessingInformation::try_from((
             item,
             Some(PaymentSolution::ApplePay),
@@ -1050,7 +1238,7 @@ impl
     ) -> Result<Self, Self::Error> {
         let email = item.router_data.request.get_email()?;
         let bill_to = build_bill_to(item.router_data.get_optional_billing(), email)?;
-        let order_information = OrderInformationWithBill::from((item, Some(bill_to)));
+        let order_information = OrderInformationWithBill::try_from((item, Some(bill_to)))?;
 
         let payment_information =
             PaymentInformation::GooglePay(Box::new(GooglePayPaymentInformation {
@@ -1113,7 +1301,7 @@ impl
     ) -> Result<Self, Self::Error> {
         let email = item.router_data.request.get_email()?;
         let bill_to = build_bill_to(item.router_data.get_optional_billing(), email)?;
-        let order_information = OrderInformationWithBill::from((item, Some(bill_to)));
+        let order_information = OrderInformationWithBill::try_from((item, Some(bill_to)))?;
         let payment_information = match bank_debit_data {
             BankDebitData::AchBankDebit {
                 account_number,
@@ -1189,7 +1377,7 @@ impl TryFrom<&WellsfargoRouterData<&PaymentsAuthorizeRouterData>> for Wellsfargo
                                         email,
                                     )?;
                                     let order_information =
-                                        OrderInformationWithBill::from((item, Some(bill_to)));
+                                        OrderInformationWithBill::try_from((item, Some(bill_to)))?;
                                     let processing_information =
                                         ProcessingInformation::try_from((
                                             item,
@@ -1332,7 +1520,7 @@ impl TryFrom<(&WellsfargoRouterData<&PaymentsAuthorizeRouterData>, String)>
             item.router_data.request.get_email().ok().and_then(|email| {
                 build_bill_to(item.router_data.get_optional_billing(), email).ok()
             });
-        let order_information = OrderInformationWithBill::from((item, bill_to));
+        let order_information = OrderInformationWithBill::try_from((item, bill_to))?;
         let payment_information =
             PaymentInformation::MandatePayment(Box::new(MandatePaymentInformation {
                 payment_instrument,
@@ -1397,13 +1585,21 @@ impl TryFrom<&WellsfargoRouterData<&PaymentsCaptureRouterData>>
                 capture: None,
                 commerce_indicator: String::from("internet"),
                 payment_solution: None,
+                purchase_level: None,
             },
             order_information: OrderInformationWithBill {
                 amount_details: Amount {
                     total_amount: item.amount.clone(),
                     currency: item.router_data.request.currency,
+                    tax_amount: None,
+                    discount_amount: None,
+                    duty_amount: None,
+                    freight_amount: None,
                 },
                 bill_to: None,
+                line_items: None,
+                invoice_details: None,
+                ship_from: None,
             },
             client_reference_information: ClientReferenceInformation {
                 code: Some(item.router_data.connector_request_reference_id.clone()),
@@ -1435,11 +1631,13 @@ impl TryFrom<&WellsfargoRouterData<&PaymentsIncrementalAuthorizationRouterData>>
                         previous_transaction_id: None,
                         original_authorized_amount: None,
                     }),
+                    partial_auth_indicator: None,
                 }),
                 commerce_indicator: String::from("internet"),
                 capture: None,
                 capture_options: None,
                 payment_solution: None,
+                purchase_level: None,
             },
             order_information: OrderInformationIncrementalAuthorization {
                 amount_details: AdditionalAmount {
@@ -1491,6 +1689,10 @@ impl TryFrom<&WellsfargoRouterData<&PaymentsCancelRouterData>> for WellsfargoVoi
                             field_name: "Currency",
                         },
                     )?,
+                    tax_amount: None,
+                    discount_amount: None,
+                    duty_amount: None,
+                    freight_amount: None,
                 },
                 reason: value
                     .router_data
@@ -1554,7 +1756,7 @@ pub enum WellsfargoPaymentStatus {
     Accepted,
     Cancelled,
     StatusNotReceived,
-    //PartialAuthorized, not being consumed yet.
+    PartialAuthorized,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -1565,6 +1767,23 @@ pub enum WellsfargoIncrementalAuthorizationStatus {
     AuthorizedPendingReview,
 }
 
+/// A partial approval is not expected unless the merchant opted in to partial authorization, and is
+/// treated as a failure otherwise, as the payment would not be for the amount the merchant asked for.
+fn map_authorize_attempt_status(
+    status: WellsfargoPaymentStatus,
+    capture: bool,
+    enable_partial_authorization: Option<bool>,
+) -> enums::AttemptStatus {
+    match status {
+        WellsfargoPaymentStatus::PartialAuthorized
+            if enable_partial_authorization != Some(true) =>
+        {
+            enums::AttemptStatus::Failure
+        }
+        status => map_attempt_status(status, capture),
+    }
+}
+
 fn map_attempt_status(status: WellsfargoPaymentStatus, capture: bool) -> enums::AttemptStatus {
     match status {
         WellsfargoPaymentStatus::Authorized | WellsfargoPaymentStatus::AuthorizedPendingReview => {
@@ -1582,6 +1801,13 @@ fn map_attempt_status(status: WellsfargoPaymentStatus, capture: bool) -> enums::
                 enums::AttemptStatus::Pending
             }
         }
+        WellsfargoPaymentStatus::PartialAuthorized => {
+            if capture {
+                enums::AttemptStatus::Charged
+            } else {
+                enums::AttemptStatus::PartiallyAuthorized
+            }
+        }
         WellsfargoPaymentStatus::Succeeded | WellsfargoPaymentStatus::Transmitted => {
             enums::AttemptStatus::Charged
         }
@@ -1624,6 +1850,45 @@ pub struct WellsfargoPaymentsResponse {
     risk_information: Option<ClientRiskInformation>,
     token_information: Option<WellsfargoTokenInformation>,
     error_information: Option<WellsfargoErrorInformation>,
+    order_information: Option<WellsfargoOrderInformationResponse>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WellsfargoOrderInformationResponse {
+    amount_details: Option<WellsfargoAmountDetailsResponse>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WellsfargoAmountDetailsResponse {
+    authorized_amount: Option<StringMajorUnit>,
+}
+
+/// Amount approved by the issuer, when the payment was authorized only partially
+fn get_partially_authorized_amount(
+    response: &WellsfargoPaymentsResponse,
+    currency: enums::Currency,
+) -> Result<Option<MinorUnit>, error_stack::Report<errors::ConnectorError>> {
+    if !matches!(
+        response.status,
+        Some(WellsfargoPaymentStatus::PartialAuthorized)
+    ) {
+        return Ok(None);
+    }
+    response
+        .order_information
+        .as_ref()
+        .and_then(|order_information| order_information.amount_details.as_ref())
+        .and_then(|amount_details| amount_details.authorized_amount.clone())
+        .map(|authorized_amount| {
+            utils::convert_back_amount_to_minor_units(
+                &StringMajorUnitForConnector,
+                authorized_amount,
+                currency,
+            )
+        })
+        .transpose()
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -1709,7 +1974,12 @@ fn get_error_response_if_failure(
 }
 
 fn get_payment_response(
-    (info_response, status, http_code): (&WellsfargoPaymentsResponse, enums::AttemptStatus, u16),
+    (info_response, status, http_code, authorized_amount): (
+        &WellsfargoPaymentsResponse,
+        enums::AttemptStatus,
+        u16,
+        Option<MinorUnit>,
+    ),
 ) -> Result<PaymentsResponseData, Box<ErrorResponse>> {
     let error_response = get_error_response_if_failure((info_response, status, http_code));
     match error_response {
@@ -1747,6 +2017,7 @@ fn get_payment_response(
                 ),
                 incremental_authorization_allowed,
                 charges: None,
+                authorized_amount,
             })
         }
     }
@@ -1771,15 +2042,19 @@ impl
             PaymentsResponseData,
         >,
     ) -> Result<Self, Self::Error> {
-        let status = map_attempt_status(
+        let status = map_authorize_attempt_status(
             item.response
                 .status
                 .clone()
                 .unwrap_or(WellsfargoPaymentStatus::StatusNotReceived),
             item.data.request.is_auto_capture()?,
+            item.data.request.enable_partial_authorization,
         );
+        let authorized_amount =
+            get_partially_authorized_amount(&item.response, item.data.request.currency)?;
         let response =
-            get_payment_response((&item.response, status, item.http_code)).map_err(|err| *err);
+            get_payment_response((&item.response, status, item.http_code, authorized_amount))
+                .map_err(|err| *err);
         let connector_response = item
             .response
             .processor_information
@@ -1837,8 +2112,8 @@ impl<F>
                 .unwrap_or(WellsfargoPaymentStatus::StatusNotReceived),
             true,
         );
-        let response =
-            get_payment_response((&item.response, status, item.http_code)).map_err(|err| *err);
+        let response = get_payment_response((&item.response, status, item.http_code, None))
+            .map_err(|err| *err);
         Ok(Self {
             status,
             response,
@@ -1868,8 +2143,8 @@ impl<F>
                 .unwrap_or(WellsfargoPaymentStatus::StatusNotReceived),
             false,
         );
-        let response =
-            get_payment_response((&item.response, status, item.http_code)).map_err(|err| *err);
+        let response = get_payment_response((&item.response, status, item.http_code, None))
+            .map_err(|err| *err);
         Ok(Self {
             status,
             response,
@@ -1957,6 +2232,7 @@ impl
                         mandate_status == enums::AttemptStatus::Authorized,
                     ),
                     charges: None,
+                    authorized_amount: None,
                 }),
             },
             connector_response,
@@ -2074,6 +2350,7 @@ impl<F>
                                 .unwrap_or(Some(item.response.id)),
                             incremental_authorization_allowed,
                             charges: None,
+                            authorized_amount: None,
                         }),
                         ..item.data
                     })
@@ -2090,6 +2367,7 @@ impl<F>
                     connector_response_reference_id: Some(item.response.id),
                     incremental_authorization_allowed: None,
                     charges: None,
+                    authorized_amount: None,
                 }),
                 ..item.data
             }),
@@ -2112,6 +2390,10 @@ impl<F> TryFrom<&WellsfargoRouterData<&RefundsRouterData<F>>> for WellsfargoRefu
                 amount_details: Amount {
                     total_amount: item.amount.clone(),
                     currency: item.router_data.request.currency,
+                    tax_amount: None,
+                    discount_amount: None,
+                    duty_amount: None,
+                    freight_amount: None,
                 },
             },
             client_reference_information: ClientReferenceInformation {
@@ -2414,3 +2696,196 @@ pub fn get_error_reason(
         (None, None, None) => None,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    #[test]
+    fn test_partially_authorized_payment_response() {
+        let response: WellsfargoPaymentsResponse = serde_json::from_value(serde_json::json!({
+            "id": "7125345937716456703955",
+            "status": "PARTIAL_AUTHORIZED",
+            "orderInformation": {
+                "amountDetails": {
+                    "authorizedAmount": "6.50",
+                    "currency": "USD"
+                }
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(
+            map_authorize_attempt_status(
+                WellsfargoPaymentStatus::PartialAuthorized,
+                false,
+                Some(true)
+            ),
+            enums::AttemptStatus::PartiallyAuthorized
+        );
+        assert_eq!(
+            map_authorize_attempt_status(
+                WellsfargoPaymentStatus::PartialAuthorized,
+                true,
+                Some(true)
+            ),
+            enums::AttemptStatus::Charged
+        );
+        assert_eq!(
+            get_partially_authorized_amount(&response, enums::Currency::USD).unwrap(),
+            Some(MinorUnit::new(650))
+        );
+    }
+
+    #[test]
+    fn test_authorized_amount_is_ignored_for_full_authorizations() {
+        let response: WellsfargoPaymentsResponse = serde_json::from_value(serde_json::json!({
+            "id": "7125345937716456703955",
+            "status": "AUTHORIZED",
+            "orderInformation": {
+                "amountDetails": {
+                    "authorizedAmount": "10.00",
+                    "currency": "USD"
+                }
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(
+            get_partially_authorized_amount(&response, enums::Currency::USD).unwrap(),
+            None
+        );
+    }
+
+    fn get_l2_l3_line_item(unit_amount: i64) -> L2L3LineItem {
+        L2L3LineItem {
+            product_name: "Laptop stand".to_string(),
+            product_code: Some("SKU-1234".to_string()),
+            commodity_code: Some("43211500".to_string()),
+            description: None,
+            quantity: 2,
+            unit_of_measure: Some("EA".to_string()),
+            unit_amount: MinorUnit::new(unit_amount),
+            discount_amount: Some(MinorUnit::new(100)),
+            tax_amount: Some(MinorUnit::new(325)),
+        }
+    }
+
+    fn get_l2_l3_data(line_items: Option<Vec<L2L3LineItem>>) -> L2L3Data {
+        L2L3Data {
+            customer_reference: Some("PO-12345".to_string()),
+            tax_amount: Some(MinorUnit::new(650)),
+            tax_exempt: Some(false),
+            discount_amount: None,
+            duty_amount: None,
+            freight_amount: Some(MinorUnit::new(1000)),
+            ship_from_postal_code: Some("94105".to_string()),
+            line_items,
+        }
+    }
+
+    #[test]
+    fn test_l2_l3_line_item() {
+        let line_item =
+            LineItem::try_from((&get_l2_l3_line_item(2500), enums::Currency::USD)).unwrap();
+
+        assert_eq!(
+            serde_json::to_value(line_item).unwrap(),
+            serde_json::json!({
+                "productName": "Laptop stand",
+                "productCode": "SKU-1234",
+                "commodityCode": "43211500",
+                "quantity": 2,
+                "unitOfMeasure": "EA",
+                "unitPrice": "25.00",
+                "discountAmount": "1.00",
+                "taxAmount": "3.25",
+                "totalAmount": "49.00"
+            })
+        );
+    }
+
+    #[test]
+    fn test_l2_l3_line_item_with_total_amount_out_of_range() {
+        assert!(
+            LineItem::try_from((&get_l2_l3_line_item(i64::MAX), enums::Currency::USD)).is_err()
+        );
+    }
+
+    #[test]
+    fn test_l2_l3_invoice_details_and_purchase_level() {
+        let level_2_data = get_l2_l3_data(None);
+        let level_3_data = get_l2_l3_data(Some(vec![get_l2_l3_line_item(2500)]));
+
+        assert_eq!(
+            serde_json::to_value(InvoiceDetails::from(&level_2_data)).unwrap(),
+            serde_json::json!({
+                "purchaseOrderNumber": "PO-12345",
+                "taxable": true
+            })
+        );
+        assert_eq!(
+            get_purchase_level(Some(&level_2_data)),
+            Some("2".to_string())
+        );
+        assert_eq!(
+            get_purchase_level(Some(&level_3_data)),
+            Some("3".to_string())
+        );
+        assert_eq!(get_purchase_level(None), None);
+    }
+
+    #[test]
+    fn test_partial_approval_without_opt_in_is_a_failure() {
+        assert_eq!(
+            map_authorize_attempt_status(WellsfargoPaymentStatus::PartialAuthorized, false, None),
+            enums::AttemptStatus::Failure
+        );
+        assert_eq!(
+            map_authorize_attempt_status(
+                WellsfargoPaymentStatus::PartialAuthorized,
+                true,
+                Some(false)
+            ),
+            enums::AttemptStatus::Failure
+        );
+    }
+
+    #[test]
+    fn test_partial_auth_indicator_is_sent_only_on_opt_in() {
+        let get_authorization_options = |enable_partial_authorization| {
+            serde_json::to_value(get_authorization_options_with_partial_authorization(
+                Some(WellsfargoAuthorizationOptions {
+                    initiator: None,
+                    merchant_intitiated_transaction: None,
+                    partial_auth_indicator: None,
+                }),
+                enable_partial_authorization,
+            ))
+            .unwrap()
+        };
+
+        assert_eq!(
+            get_authorization_options(Some(true)),
+            serde_json::json!({
+                "initiator": null,
+                "merchantIntitiatedTransaction": null,
+                "partialAuthIndicator": true
+            })
+        );
+        assert_eq!(
+            get_authorization_options(None),
+            serde_json::json!({ "initiator": null, "merchantIntitiatedTransaction": null })
+        );
+        assert!(get_authorization_options_with_partial_authorization(None, Some(false)).is_none());
+        assert!(
+            get_authorization_options_with_partial_authorization(None, Some(true)).is_some_and(
+                |authorization_options| {
+                    authorization_options.partial_auth_indicator == Some(true)
+                }
+            )
+        );
+    }
+}
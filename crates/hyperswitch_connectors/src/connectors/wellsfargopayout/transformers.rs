@@ -138,6 +138,7 @@ impl<F, T> TryFrom<ResponseRouterData<F, WellsfargopayoutPaymentsResponse, T, Pa
                 connector_response_reference_id: None,
                 incremental_authorization_allowed: None,
                 charges: None,
+                authorized_amount: None,
             }),
             ..item.data
         })
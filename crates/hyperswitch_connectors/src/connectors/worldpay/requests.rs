@@ -38,6 +38,38 @@ pub struct Instruction {
     pub token_creation: Option<TokenCreation>,
     /// For specifying CIT vs MIT
     pub customer_agreement: Option<CustomerAgreement>,
+    /// Level 2 / Level 3 data for commercial cards
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub purchase: Option<Purchase>,
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Purchase {
+    pub customer_reference: Option<String>,
+    pub sales_tax: Option<i64>,
+    pub tax_exempt: Option<bool>,
+    pub discount_amount: Option<i64>,
+    pub shipping_amount: Option<i64>,
+    pub duty_amount: Option<i64>,
+    pub ship_from_postal_code: Option<String>,
+    pub line_items: Option<Vec<PurchaseLineItem>>,
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PurchaseLineItem {
+    pub description: String,
+    pub product_code: Option<String>,
+    pub commodity_code: Option<String>,
+    pub quantity: u16,
+    pub unit_of_measure: Option<String>,
+    pub unit_cost: i64,
+    pub discount_amount: Option<i64>,
+    pub tax_amount: Option<i64>,
+    pub total_amount: i64,
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize)]
@@ -3,6 +3,7 @@ use std::collections::HashMap;
 use api_models::payments::{MandateIds, MandateReferenceId};
 use base64::Engine;
 use common_enums::enums;
+use common_types::payments::{L2L3Data, L2L3LineItem};
 use common_utils::{
     consts::BASE64_ENGINE, errors::CustomResult, ext_traits::OptionExt, pii, types::MinorUnit,
 };
@@ -249,6 +250,9 @@ trait WorldpayPaymentsRequestData {
     fn get_settlement_info(&self, _amount: i64) -> Option<AutoSettlement> {
         None
     }
+    fn get_l2_l3_data(&self) -> Option<&L2L3Data> {
+        None
+    }
 }
 
 impl WorldpayPaymentsRequestData
@@ -381,6 +385,57 @@ impl WorldpayPaymentsRequestData
             _ => None,
         }
     }
+
+    fn get_l2_l3_data(&self) -> Option<&L2L3Data> {
+        self.request.l2_l3_data.as_ref()
+    }
+}
+
+impl TryFrom<&L2L3Data> for Purchase {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(l2_l3_data: &L2L3Data) -> Result<Self, Self::Error> {
+        Ok(Self {
+            customer_reference: l2_l3_data.customer_reference.clone(),
+            sales_tax: l2_l3_data.tax_amount.map(MinorUnit::get_amount_as_i64),
+            tax_exempt: l2_l3_data.tax_exempt,
+            discount_amount: l2_l3_data.discount_amount.map(MinorUnit::get_amount_as_i64),
+            shipping_amount: l2_l3_data.freight_amount.map(MinorUnit::get_amount_as_i64),
+            duty_amount: l2_l3_data.duty_amount.map(MinorUnit::get_amount_as_i64),
+            ship_from_postal_code: l2_l3_data.ship_from_postal_code.clone(),
+            line_items: l2_l3_data
+                .line_items
+                .as_ref()
+                .map(|line_items| {
+                    line_items
+                        .iter()
+                        .map(PurchaseLineItem::try_from)
+                        .collect::<Result<_, _>>()
+                })
+                .transpose()?,
+        })
+    }
+}
+
+impl TryFrom<&L2L3LineItem> for PurchaseLineItem {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(line_item: &L2L3LineItem) -> Result<Self, Self::Error> {
+        Ok(Self {
+            description: line_item.product_name.clone(),
+            product_code: line_item.product_code.clone(),
+            commodity_code: line_item.commodity_code.clone(),
+            quantity: line_item.quantity,
+            unit_of_measure: line_item.unit_of_measure.clone(),
+            unit_cost: line_item.unit_amount.get_amount_as_i64(),
+            discount_amount: line_item.discount_amount.map(MinorUnit::get_amount_as_i64),
+            tax_amount: line_item.tax_amount.map(MinorUnit::get_amount_as_i64),
+            total_amount: line_item
+                .get_total_amount()
+                .map_err(|_| errors::ConnectorError::InvalidDataFormat {
+                    field_name: "l2_l3_data.line_items",
+                })?
+                .get_amount_as_i64(),
+        })
+    }
 }
 
 // Dangling helper function to create ThreeDS request
@@ -548,6 +603,11 @@ impl<T: WorldpayPaymentsRequestData> TryFrom<(&WorldpayRouterData<&T>, &Secret<S
                 three_ds,
                 token_creation,
                 customer_agreement,
+                purchase: item
+                    .router_data
+                    .get_l2_l3_data()
+                    .map(Purchase::try_from)
+                    .transpose()?,
             },
             merchant: Merchant {
                 entity: entity_id.clone(),
@@ -781,6 +841,7 @@ impl<F, T>
                 connector_response_reference_id: optional_correlation_id.clone(),
                 incremental_authorization_allowed: None,
                 charges: None,
+                authorized_amount: None,
             }),
             (Some(reason), _) => Err(ErrorResponse {
                 code: worldpay_status.to_string(),
@@ -885,3 +946,64 @@ impl TryFrom<&types::PaymentsCompleteAuthorizeRouterData> for WorldpayCompleteAu
             .change_context(errors::ConnectorError::ResponseDeserializationFailed)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    fn get_l2_l3_data(unit_amount: i64) -> L2L3Data {
+        L2L3Data {
+            customer_reference: Some("PO-12345".to_string()),
+            tax_amount: Some(MinorUnit::new(650)),
+            tax_exempt: Some(false),
+            discount_amount: None,
+            duty_amount: None,
+            freight_amount: Some(MinorUnit::new(1000)),
+            ship_from_postal_code: Some("94105".to_string()),
+            line_items: Some(vec![L2L3LineItem {
+                product_name: "Laptop stand".to_string(),
+                product_code: Some("SKU-1234".to_string()),
+                commodity_code: None,
+                description: None,
+                quantity: 2,
+                unit_of_measure: Some("EA".to_string()),
+                unit_amount: MinorUnit::new(unit_amount),
+                discount_amount: Some(MinorUnit::new(100)),
+                tax_amount: Some(MinorUnit::new(325)),
+            }]),
+        }
+    }
+
+    #[test]
+    fn test_l2_l3_purchase() {
+        let purchase = Purchase::try_from(&get_l2_l3_data(2500)).unwrap();
+
+        assert_eq!(
+            serde_json::to_value(purchase).unwrap(),
+            serde_json::json!({
+                "customerReference": "PO-12345",
+                "salesTax": 650,
+                "taxExempt": false,
+                "shippingAmount": 1000,
+                "shipFromPostalCode": "94105",
+                "lineItems": [{
+                    "description": "Laptop stand",
+                    "productCode": "SKU-1234",
+                    "quantity": 2,
+                    "unitOfMeasure": "EA",
+                    "unitCost": 2500,
+                    "discountAmount": 100,
+                    "taxAmount": 325,
+                    "totalAmount": 4900
+                }]
+            })
+        );
+    }
+
+    #[test]
+    fn test_l2_l3_purchase_with_total_amount_out_of_range() {
+        assert!(Purchase::try_from(&get_l2_l3_data(i64::MAX)).is_err());
+    }
+}
@@ -694,6 +694,7 @@ impl<F> TryFrom<ResponseRouterData<F, PaymentService, PaymentsSyncData, Payments
                         connector_response_reference_id: Some(order_status.order_code.clone()),
                         incremental_authorization_allowed: None,
                         charges: None,
+                        authorized_amount: None,
                     }),
                     ..item.data
                 })
@@ -711,6 +712,7 @@ impl<F> TryFrom<ResponseRouterData<F, PaymentService, PaymentsSyncData, Payments
                     connector_response_reference_id: None,
                     incremental_authorization_allowed: None,
                     charges: None,
+                    authorized_amount: None,
                 }),
                 ..item.data
             })
@@ -856,6 +858,7 @@ impl<F> TryFrom<ResponseRouterData<F, PaymentService, PaymentsCaptureData, Payme
                     connector_response_reference_id: Some(capture_received.order_code.clone()),
                     incremental_authorization_allowed: None,
                     charges: None,
+                    authorized_amount: None,
                 }),
                 ..item.data
             })
@@ -917,6 +920,7 @@ impl<F> TryFrom<ResponseRouterData<F, PaymentService, PaymentsCancelData, Paymen
                     connector_response_reference_id: Some(cancel_received.order_code.clone()),
                     incremental_authorization_allowed: None,
                     charges: None,
+                    authorized_amount: None,
                 }),
                 ..item.data
             })
@@ -1127,6 +1131,7 @@ fn process_payment_response(
             connector_response_reference_id: Some(order_code.clone()),
             incremental_authorization_allowed: None,
             charges: None,
+            authorized_amount: None,
         })
     }
 }
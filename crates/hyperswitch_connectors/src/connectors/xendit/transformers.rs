@@ -399,6 +399,7 @@ impl<F>
                 ),
                 incremental_authorization_allowed: None,
                 charges,
+                authorized_amount: None,
             })
         };
         Ok(Self {
@@ -460,6 +461,7 @@ impl<F>
                 ),
                 incremental_authorization_allowed: None,
                 charges: None,
+                authorized_amount: None,
             })
         };
         Ok(Self {
@@ -544,6 +546,7 @@ impl<F>
                     common_types::payments::XenditChargeResponseData::MultipleSplits(charges),
                 ),
             ),
+            authorized_amount: None,
         };
 
         Ok(Self {
@@ -594,6 +597,7 @@ impl TryFrom<PaymentsSyncResponseRouterData<XenditResponse>> for PaymentsSyncRou
                         connector_response_reference_id: None,
                         incremental_authorization_allowed: None,
                         charges: None,
+                        authorized_amount: None,
                     })
                 };
                 Ok(Self {
@@ -957,6 +957,7 @@ fn get_zen_response(
         connector_response_reference_id: None,
         incremental_authorization_allowed: None,
         charges: None,
+        authorized_amount: None,
     };
     Ok((status, error, payment_response_data))
 }
@@ -1001,6 +1002,7 @@ impl<F, T> TryFrom<ResponseRouterData<F, CheckoutResponse, T, PaymentsResponseDa
                 connector_response_reference_id: None,
                 incremental_authorization_allowed: None,
                 charges: None,
+                authorized_amount: None,
             }),
             ..value.data
         })
@@ -427,6 +427,7 @@ pub(crate) fn is_payment_failure(status: AttemptStatus) -> bool {
         | AttemptStatus::AuthenticationPending
         | AttemptStatus::AuthenticationSuccessful
         | AttemptStatus::Authorized
+        | AttemptStatus::PartiallyAuthorized
         | AttemptStatus::Charged
         | AttemptStatus::Authorizing
         | AttemptStatus::CodInitiated
@@ -6064,6 +6065,8 @@ pub(crate) fn convert_setup_mandate_router_data_to_authorize_router_data(
         customer_id: None,
         surcharge_details: None,
         request_extended_authorization: None,
+        enable_partial_authorization: None,
+        l2_l3_data: None,
         request_incremental_authorization: data.request.request_incremental_authorization,
         metadata: None,
         authentication_data: None,
@@ -6172,6 +6175,7 @@ impl FrmTransactionRouterDataRequest for FrmTransactionRouterData {
             AttemptStatus::AuthenticationSuccessful
             | AttemptStatus::PartialChargedAndChargeable
             | AttemptStatus::Authorized
+            | AttemptStatus::PartiallyAuthorized
             | AttemptStatus::Charged => Some(true),
 
             AttemptStatus::Started
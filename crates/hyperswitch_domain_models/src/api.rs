@@ -18,6 +18,7 @@ pub enum ApplicationResponse<R> {
     FileData((Vec<u8>, mime::Mime)),
     JsonWithHeaders((R, Vec<(String, masking::Maskable<String>)>)),
     GenericLinkForm(Box<GenericLinks>),
+    StoredResponse(Box<StoredResponse>),
 }
 
 impl<R> ApplicationResponse<R> {
@@ -34,6 +35,7 @@ impl<R> ApplicationResponse<R> {
             | Self::PaymentLinkForm(_)
             | Self::FileData(_)
             | Self::GenericLinkForm(_)
+            | Self::StoredResponse(_)
             | Self::StatusOk => Err(common_utils::errors::ValidationError::InvalidValue {
                 message: "expected either Json or JsonWithHeaders Response".to_string(),
             }
@@ -54,6 +56,14 @@ impl<T: ApiEventMetric> ApiEventMetric for ApplicationResponse<T> {
 
 impl_api_event_type!(Miscellaneous, (PaymentLinkFormData, GenericLinkFormData));
 
+/// A response which was served earlier for the same request, and is served again as is
+#[derive(Debug, Eq, PartialEq)]
+pub struct StoredResponse {
+    pub status_code: u16,
+    pub headers: Vec<(String, masking::Maskable<String>)>,
+    pub body: Vec<u8>,
+}
+
 #[derive(Debug, Eq, PartialEq)]
 pub struct RedirectionFormData {
     pub redirect_form: crate::router_response_types::RedirectForm,
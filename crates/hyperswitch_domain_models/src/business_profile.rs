@@ -76,6 +76,8 @@ pub struct Profile {
     pub is_iframe_redirection_enabled: Option<bool>,
     pub is_pre_network_tokenization_enabled: bool,
     pub three_ds_decision_rule_algorithm: Option<serde_json::Value>,
+    pub previous_payment_response_hash_key: Option<String>,
+    pub previous_payment_response_hash_key_expires_at: Option<time::PrimitiveDateTime>,
 }
 
 #[cfg(feature = "v1")]
@@ -188,6 +190,8 @@ impl From<ProfileSetter> for Profile {
             is_iframe_redirection_enabled: value.is_iframe_redirection_enabled,
             is_pre_network_tokenization_enabled: value.is_pre_network_tokenization_enabled,
             three_ds_decision_rule_algorithm: None, // three_ds_decision_rule_algorithm is not yet created during profile creation
+            previous_payment_response_hash_key: None,
+            previous_payment_response_hash_key_expires_at: None,
         }
     }
 }
@@ -198,6 +202,37 @@ impl Profile {
         &self.profile_id
     }
 
+    /// Returns the keys that outgoing webhooks should be signed with: the current payment
+    /// response hash key, followed by the previous key if its rotation window has not elapsed.
+    #[cfg(feature = "v1")]
+    pub fn get_active_payment_response_hash_keys(&self) -> Vec<&str> {
+        self.payment_response_hash_key
+            .as_deref()
+            .into_iter()
+            .chain(self.get_unexpired_previous_payment_response_hash_key())
+            .collect()
+    }
+
+    /// Returns the key for signatures which can only carry a single signature, such as the legacy
+    /// outgoing webhook signature header. The previous key is used until its rotation window
+    /// elapses, so that merchants have the whole window to switch over to the new key.
+    #[cfg(feature = "v1")]
+    pub fn get_single_signature_payment_response_hash_key(&self) -> Option<&str> {
+        self.get_unexpired_previous_payment_response_hash_key()
+            .or(self.payment_response_hash_key.as_deref())
+    }
+
+    #[cfg(feature = "v1")]
+    fn get_unexpired_previous_payment_response_hash_key(&self) -> Option<&str> {
+        let now = date_time::now();
+        self.previous_payment_response_hash_key
+            .as_deref()
+            .filter(|_| {
+                self.previous_payment_response_hash_key_expires_at
+                    .is_some_and(|expires_at| expires_at > now)
+            })
+    }
+
     #[cfg(feature = "v2")]
     pub fn get_id(&self) -> &common_utils::id_type::ProfileId {
         &self.id
@@ -274,6 +309,11 @@ pub enum ProfileUpdate {
     CardTestingSecretKeyUpdate {
         card_testing_secret_key: OptionalEncryptableName,
     },
+    PaymentResponseHashKeyRotation {
+        payment_response_hash_key: String,
+        previous_payment_response_hash_key: Option<String>,
+        previous_payment_response_hash_key_expires_at: Option<time::PrimitiveDateTime>,
+    },
 }
 
 #[cfg(feature = "v1")]
@@ -373,6 +413,8 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                     is_iframe_redirection_enabled,
                     is_pre_network_tokenization_enabled,
                     three_ds_decision_rule_algorithm: None,
+                    previous_payment_response_hash_key: None,
+                    previous_payment_response_hash_key_expires_at: None,
                 }
             }
             ProfileUpdate::RoutingAlgorithmUpdate {
@@ -425,6 +467,8 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 is_iframe_redirection_enabled: None,
                 is_pre_network_tokenization_enabled: None,
                 three_ds_decision_rule_algorithm,
+                previous_payment_response_hash_key: None,
+                previous_payment_response_hash_key_expires_at: None,
             },
             ProfileUpdate::DynamicRoutingAlgorithmUpdate {
                 dynamic_routing_algorithm,
@@ -474,6 +518,8 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 is_iframe_redirection_enabled: None,
                 is_pre_network_tokenization_enabled: None,
                 three_ds_decision_rule_algorithm: None,
+                previous_payment_response_hash_key: None,
+                previous_payment_response_hash_key_expires_at: None,
             },
             ProfileUpdate::ExtendedCardInfoUpdate {
                 is_extended_card_info_enabled,
@@ -523,6 +569,8 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 is_iframe_redirection_enabled: None,
                 is_pre_network_tokenization_enabled: None,
                 three_ds_decision_rule_algorithm: None,
+                previous_payment_response_hash_key: None,
+                previous_payment_response_hash_key_expires_at: None,
             },
             ProfileUpdate::ConnectorAgnosticMitUpdate {
                 is_connector_agnostic_mit_enabled,
@@ -572,6 +620,8 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 is_iframe_redirection_enabled: None,
                 is_pre_network_tokenization_enabled: None,
                 three_ds_decision_rule_algorithm: None,
+                previous_payment_response_hash_key: None,
+                previous_payment_response_hash_key_expires_at: None,
             },
             ProfileUpdate::NetworkTokenizationUpdate {
                 is_network_tokenization_enabled,
@@ -621,6 +671,8 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 is_iframe_redirection_enabled: None,
                 is_pre_network_tokenization_enabled: None,
                 three_ds_decision_rule_algorithm: None,
+                previous_payment_response_hash_key: None,
+                previous_payment_response_hash_key_expires_at: None,
             },
             ProfileUpdate::CardTestingSecretKeyUpdate {
                 card_testing_secret_key,
@@ -670,6 +722,63 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 is_iframe_redirection_enabled: None,
                 is_pre_network_tokenization_enabled: None,
                 three_ds_decision_rule_algorithm: None,
+                previous_payment_response_hash_key: None,
+                previous_payment_response_hash_key_expires_at: None,
+            },
+            ProfileUpdate::PaymentResponseHashKeyRotation {
+                payment_response_hash_key,
+                previous_payment_response_hash_key,
+                previous_payment_response_hash_key_expires_at,
+            } => Self {
+                profile_name: None,
+                modified_at: now,
+                return_url: None,
+                enable_payment_response_hash: None,
+                payment_response_hash_key: Some(payment_response_hash_key),
+                redirect_to_merchant_with_http_post: None,
+                webhook_details: None,
+                metadata: None,
+                routing_algorithm: None,
+                intent_fulfillment_time: None,
+                frm_routing_algorithm: None,
+                payout_routing_algorithm: None,
+                is_recon_enabled: None,
+                applepay_verified_domains: None,
+                payment_link_config: None,
+                session_expiry: None,
+                authentication_connector_details: None,
+                payout_link_config: None,
+                is_extended_card_info_enabled: None,
+                extended_card_info_config: None,
+                is_connector_agnostic_mit_enabled: None,
+                use_billing_as_payment_method_billing: None,
+                collect_shipping_details_from_wallet_connector: None,
+                collect_billing_details_from_wallet_connector: None,
+                outgoing_webhook_custom_http_headers: None,
+                always_collect_billing_details_from_wallet_connector: None,
+                always_collect_shipping_details_from_wallet_connector: None,
+                tax_connector_id: None,
+                is_tax_connector_enabled: None,
+                dynamic_routing_algorithm: None,
+                is_network_tokenization_enabled: None,
+                is_auto_retries_enabled: None,
+                max_auto_retries_enabled: None,
+                always_request_extended_authorization: None,
+                is_click_to_pay_enabled: None,
+                authentication_product_ids: None,
+                card_testing_guard_config: None,
+                card_testing_secret_key: None,
+                is_clear_pan_retries_enabled: None,
+                force_3ds_challenge: None,
+                is_debit_routing_enabled: None,
+                merchant_business_country: None,
+                is_iframe_redirection_enabled: None,
+                is_pre_network_tokenization_enabled: None,
+                three_ds_decision_rule_algorithm: None,
+                previous_payment_response_hash_key: Some(previous_payment_response_hash_key),
+                previous_payment_response_hash_key_expires_at: Some(
+                    previous_payment_response_hash_key_expires_at,
+                ),
             },
         }
     }
@@ -739,6 +848,9 @@ impl super::behaviour::Conversion for Profile {
             is_iframe_redirection_enabled: self.is_iframe_redirection_enabled,
             is_pre_network_tokenization_enabled: Some(self.is_pre_network_tokenization_enabled),
             three_ds_decision_rule_algorithm: self.three_ds_decision_rule_algorithm,
+            previous_payment_response_hash_key: self.previous_payment_response_hash_key,
+            previous_payment_response_hash_key_expires_at: self
+                .previous_payment_response_hash_key_expires_at,
         })
     }
 
@@ -834,6 +946,9 @@ impl super::behaviour::Conversion for Profile {
                     .is_pre_network_tokenization_enabled
                     .unwrap_or(false),
                 three_ds_decision_rule_algorithm: item.three_ds_decision_rule_algorithm,
+                previous_payment_response_hash_key: item.previous_payment_response_hash_key,
+                previous_payment_response_hash_key_expires_at: item
+                    .previous_payment_response_hash_key_expires_at,
             })
         }
         .await
@@ -956,6 +1071,8 @@ pub struct Profile {
     pub is_iframe_redirection_enabled: Option<bool>,
     pub is_external_vault_enabled: Option<bool>,
     pub external_vault_connector_details: Option<ExternalVaultConnectorDetails>,
+    pub revenue_recovery_retry_policy:
+        Option<common_types::revenue_recovery::RevenueRecoveryRetryPolicy>,
 }
 
 #[cfg(feature = "v2")]
@@ -1011,6 +1128,8 @@ pub struct ProfileSetter {
     pub is_iframe_redirection_enabled: Option<bool>,
     pub is_external_vault_enabled: Option<bool>,
     pub external_vault_connector_details: Option<ExternalVaultConnectorDetails>,
+    pub revenue_recovery_retry_policy:
+        Option<common_types::revenue_recovery::RevenueRecoveryRetryPolicy>,
 }
 
 #[cfg(feature = "v2")]
@@ -1071,6 +1190,7 @@ impl From<ProfileSetter> for Profile {
             is_iframe_redirection_enabled: value.is_iframe_redirection_enabled,
             is_external_vault_enabled: value.is_external_vault_enabled,
             external_vault_connector_details: value.external_vault_connector_details,
+            revenue_recovery_retry_policy: value.revenue_recovery_retry_policy,
         }
     }
 }
@@ -1145,6 +1265,8 @@ pub struct ProfileGeneralUpdate {
     pub is_iframe_redirection_enabled: Option<bool>,
     pub is_external_vault_enabled: Option<bool>,
     pub external_vault_connector_details: Option<ExternalVaultConnectorDetails>,
+    pub revenue_recovery_retry_policy:
+        Option<common_types::revenue_recovery::RevenueRecoveryRetryPolicy>,
 }
 
 #[cfg(feature = "v2")]
@@ -1223,6 +1345,7 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                     is_iframe_redirection_enabled,
                     is_external_vault_enabled,
                     external_vault_connector_details,
+                    revenue_recovery_retry_policy,
                 } = *update;
                 Self {
                     profile_name,
@@ -1274,6 +1397,7 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                     is_iframe_redirection_enabled: None,
                     is_external_vault_enabled,
                     external_vault_connector_details,
+                    revenue_recovery_retry_policy,
                 }
             }
             ProfileUpdate::RoutingAlgorithmUpdate {
@@ -1328,6 +1452,7 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 is_iframe_redirection_enabled: None,
                 is_external_vault_enabled: None,
                 external_vault_connector_details: None,
+                revenue_recovery_retry_policy: None,
             },
             ProfileUpdate::ExtendedCardInfoUpdate {
                 is_extended_card_info_enabled,
@@ -1380,6 +1505,7 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 is_iframe_redirection_enabled: None,
                 is_external_vault_enabled: None,
                 external_vault_connector_details: None,
+                revenue_recovery_retry_policy: None,
             },
             ProfileUpdate::ConnectorAgnosticMitUpdate {
                 is_connector_agnostic_mit_enabled,
@@ -1432,6 +1558,7 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 is_iframe_redirection_enabled: None,
                 is_external_vault_enabled: None,
                 external_vault_connector_details: None,
+                revenue_recovery_retry_policy: None,
             },
             ProfileUpdate::DefaultRoutingFallbackUpdate {
                 default_fallback_routing,
@@ -1484,6 +1611,7 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 is_iframe_redirection_enabled: None,
                 is_external_vault_enabled: None,
                 external_vault_connector_details: None,
+                revenue_recovery_retry_policy: None,
             },
             ProfileUpdate::NetworkTokenizationUpdate {
                 is_network_tokenization_enabled,
@@ -1536,6 +1664,7 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 is_iframe_redirection_enabled: None,
                 is_external_vault_enabled: None,
                 external_vault_connector_details: None,
+                revenue_recovery_retry_policy: None,
             },
             ProfileUpdate::CollectCvvDuringPaymentUpdate {
                 should_collect_cvv_during_payment,
@@ -1588,6 +1717,7 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 is_iframe_redirection_enabled: None,
                 is_external_vault_enabled: None,
                 external_vault_connector_details: None,
+                revenue_recovery_retry_policy: None,
             },
             ProfileUpdate::DecisionManagerRecordUpdate {
                 three_ds_decision_manager_config,
@@ -1640,6 +1770,7 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 is_iframe_redirection_enabled: None,
                 is_external_vault_enabled: None,
                 external_vault_connector_details: None,
+                revenue_recovery_retry_policy: None,
             },
             ProfileUpdate::CardTestingSecretKeyUpdate {
                 card_testing_secret_key,
@@ -1692,6 +1823,7 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 is_iframe_redirection_enabled: None,
                 is_external_vault_enabled: None,
                 external_vault_connector_details: None,
+                revenue_recovery_retry_policy: None,
             },
             ProfileUpdate::RevenueRecoveryAlgorithmUpdate {
                 revenue_recovery_retry_algorithm_type,
@@ -1745,6 +1877,7 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 is_iframe_redirection_enabled: None,
                 is_external_vault_enabled: None,
                 external_vault_connector_details: None,
+                revenue_recovery_retry_policy: None,
             },
         }
     }
@@ -1819,6 +1952,7 @@ impl super::behaviour::Conversion for Profile {
             is_iframe_redirection_enabled: self.is_iframe_redirection_enabled,
             is_external_vault_enabled: self.is_external_vault_enabled,
             external_vault_connector_details: self.external_vault_connector_details,
+            revenue_recovery_retry_policy: self.revenue_recovery_retry_policy,
             three_ds_decision_rule_algorithm: None,
         })
     }
@@ -1914,6 +2048,7 @@ impl super::behaviour::Conversion for Profile {
                 is_iframe_redirection_enabled: item.is_iframe_redirection_enabled,
                 is_external_vault_enabled: item.is_external_vault_enabled,
                 external_vault_connector_details: item.external_vault_connector_details,
+                revenue_recovery_retry_policy: item.revenue_recovery_retry_policy,
             })
         }
         .await
@@ -1982,6 +2117,135 @@ impl super::behaviour::Conversion for Profile {
             is_iframe_redirection_enabled: self.is_iframe_redirection_enabled,
             is_external_vault_enabled: self.is_external_vault_enabled,
             external_vault_connector_details: self.external_vault_connector_details,
+            revenue_recovery_retry_policy: self.revenue_recovery_retry_policy,
         })
     }
 }
+
+#[cfg(all(test, feature = "v1"))]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn get_profile_with_payment_response_hash_keys(
+        payment_response_hash_key: Option<&str>,
+        previous_payment_response_hash_key: Option<&str>,
+        previous_payment_response_hash_key_expires_at: Option<time::PrimitiveDateTime>,
+    ) -> Profile {
+        Profile {
+            profile_id: common_utils::id_type::ProfileId::generate(),
+            merchant_id: common_utils::id_type::MerchantId::default(),
+            profile_name: String::from("default"),
+            created_at: date_time::now(),
+            modified_at: date_time::now(),
+            return_url: None,
+            enable_payment_response_hash: true,
+            payment_response_hash_key: payment_response_hash_key.map(String::from),
+            redirect_to_merchant_with_http_post: false,
+            webhook_details: None,
+            metadata: None,
+            routing_algorithm: None,
+            intent_fulfillment_time: None,
+            frm_routing_algorithm: None,
+            payout_routing_algorithm: None,
+            is_recon_enabled: false,
+            applepay_verified_domains: None,
+            payment_link_config: None,
+            session_expiry: None,
+            authentication_connector_details: None,
+            payout_link_config: None,
+            is_extended_card_info_enabled: None,
+            extended_card_info_config: None,
+            is_connector_agnostic_mit_enabled: None,
+            use_billing_as_payment_method_billing: None,
+            collect_shipping_details_from_wallet_connector: None,
+            collect_billing_details_from_wallet_connector: None,
+            outgoing_webhook_custom_http_headers: None,
+            always_collect_billing_details_from_wallet_connector: None,
+            always_collect_shipping_details_from_wallet_connector: None,
+            tax_connector_id: None,
+            is_tax_connector_enabled: false,
+            version: common_enums::ApiVersion::V1,
+            dynamic_routing_algorithm: None,
+            is_network_tokenization_enabled: false,
+            is_auto_retries_enabled: false,
+            max_auto_retries_enabled: None,
+            always_request_extended_authorization: None,
+            is_click_to_pay_enabled: false,
+            authentication_product_ids: None,
+            card_testing_guard_config: None,
+            card_testing_secret_key: None,
+            is_clear_pan_retries_enabled: false,
+            force_3ds_challenge: false,
+            is_debit_routing_enabled: false,
+            merchant_business_country: None,
+            is_iframe_redirection_enabled: None,
+            is_pre_network_tokenization_enabled: false,
+            three_ds_decision_rule_algorithm: None,
+            previous_payment_response_hash_key: previous_payment_response_hash_key
+                .map(String::from),
+            previous_payment_response_hash_key_expires_at,
+        }
+    }
+
+    #[test]
+    fn test_previous_key_is_used_within_rotation_window() {
+        let profile = get_profile_with_payment_response_hash_keys(
+            Some("current_key"),
+            Some("previous_key"),
+            Some(date_time::now() + time::Duration::hours(1)),
+        );
+
+        assert_eq!(
+            profile.get_active_payment_response_hash_keys(),
+            vec!["current_key", "previous_key"]
+        );
+        assert_eq!(
+            profile.get_single_signature_payment_response_hash_key(),
+            Some("previous_key")
+        );
+    }
+
+    #[test]
+    fn test_previous_key_is_not_used_after_rotation_window() {
+        let profile = get_profile_with_payment_response_hash_keys(
+            Some("current_key"),
+            Some("previous_key"),
+            Some(date_time::now() - time::Duration::hours(1)),
+        );
+
+        assert_eq!(
+            profile.get_active_payment_response_hash_keys(),
+            vec!["current_key"]
+        );
+        assert_eq!(
+            profile.get_single_signature_payment_response_hash_key(),
+            Some("current_key")
+        );
+    }
+
+    #[test]
+    fn test_previous_key_without_expiry_is_not_used() {
+        let profile = get_profile_with_payment_response_hash_keys(
+            Some("current_key"),
+            Some("previous_key"),
+            None,
+        );
+
+        assert_eq!(
+            profile.get_active_payment_response_hash_keys(),
+            vec!["current_key"]
+        );
+    }
+
+    #[test]
+    fn test_no_keys_without_payment_response_hash_key() {
+        let profile = get_profile_with_payment_response_hash_keys(None, None, None);
+
+        assert!(profile.get_active_payment_response_hash_keys().is_empty());
+        assert_eq!(
+            profile.get_single_signature_payment_response_hash_key(),
+            None
+        );
+    }
+}
@@ -117,6 +117,12 @@ pub enum ApiErrorResponse {
     PayoutNotFound,
     #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "Event does not exist in our records")]
     EventNotFound,
+    #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "Webhook endpoint with the given id '{id}' does not exist in our records")]
+    WebhookEndpointNotFound { id: String },
+    #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "Subscription plan with the given id '{id}' does not exist in our records")]
+    SubscriptionPlanNotFound { id: String },
+    #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "Subscription with the given id '{id}' does not exist in our records")]
+    SubscriptionNotFound { id: String },
     #[error(error_type = ErrorType::ValidationError, code = "HE_03", message = "Invalid mandate id passed from connector")]
     MandateSerializationFailed,
     #[error(error_type = ErrorType::ValidationError, code = "HE_03", message = "Unable to parse the mandate identifier passed from connector")]
@@ -160,6 +166,8 @@ pub enum ApiErrorResponse {
     InvalidTenant { tenant_id: String },
     #[error(error_type = ErrorType::ValidationError, code = "HE_06", message = "Failed to convert amount to {amount_type} type")]
     AmountConversionFailed { amount_type: &'static str },
+    #[error(error_type = ErrorType::DuplicateRequest, code = "HE_07", message = "A request with the same idempotency key is currently being processed")]
+    IdempotencyKeyInUse,
     #[error(error_type = ErrorType::ServerNotAvailable, code = "IR_00", message = "{message:?}")]
     NotImplemented { message: NotImplementedMessage },
     #[error(
@@ -285,6 +293,10 @@ pub enum ApiErrorResponse {
     InvalidPlatformOperation,
     #[error(error_type = ErrorType::InvalidRequestError, code = "IR_45", message = "External vault failed during processing with connector")]
     ExternalVaultFailed,
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_46", message = "Idempotency key has already been used with a different request body")]
+    IdempotencyKeyMismatch,
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_47", message = "Too many requests, retry after {retry_after} seconds")]
+    RateLimitExceeded { retry_after: u64 },
     #[error(error_type = ErrorType::InvalidRequestError, code = "WE_01", message = "Failed to authenticate the webhook")]
     WebhookAuthenticationFailed,
     #[error(error_type = ErrorType::InvalidRequestError, code = "WE_02", message = "Bad request received in webhook")]
@@ -325,6 +337,21 @@ impl std::fmt::Debug for NotImplementedMessage {
     }
 }
 
+impl ApiErrorResponse {
+    /// Whether the error is raised while validating the request, before the request has any
+    /// side effects
+    pub fn is_request_validation_error(&self) -> bool {
+        matches!(
+            self,
+            Self::MissingRequiredField { .. }
+                | Self::InvalidDataFormat { .. }
+                | Self::InvalidRequestData { .. }
+                | Self::InvalidDataValue { .. }
+                | Self::MissingRequiredFields { .. }
+        )
+    }
+}
+
 impl ::core::fmt::Display for ApiErrorResponse {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -445,6 +472,15 @@ impl ErrorSwitch<api_models::errors::types::ApiErrorResponse> for ApiErrorRespon
             Self::EventNotFound => {
                 AER::NotFound(ApiError::new("HE", 2, "Event does not exist in our records", None))
             }
+            Self::WebhookEndpointNotFound { .. } => {
+                AER::NotFound(ApiError::new("HE", 2, "Webhook endpoint does not exist in our records", None))
+            }
+            Self::SubscriptionPlanNotFound { .. } => {
+                AER::NotFound(ApiError::new("HE", 2, "Subscription plan does not exist in our records", None))
+            }
+            Self::SubscriptionNotFound { .. } => {
+                AER::NotFound(ApiError::new("HE", 2, "Subscription does not exist in our records", None))
+            }
             Self::MandateSerializationFailed | Self::MandateDeserializationFailed => {
                 AER::InternalServerError(ApiError::new("HE", 3, "Something went wrong", None))
             },
@@ -497,6 +533,9 @@ impl ErrorSwitch<api_models::errors::types::ApiErrorResponse> for ApiErrorRespon
             Self::AmountConversionFailed { amount_type }  => {
                 AER::InternalServerError(ApiError::new("HE", 6, format!("Failed to convert amount to {amount_type} type"), None))
             }
+            Self::IdempotencyKeyInUse => {
+                AER::Conflict(ApiError::new("HE", 7, "A request with the same idempotency key is currently being processed", None))
+            }
 
             Self::NotImplemented { message } => {
                 AER::NotImplemented(ApiError::new("IR", 0, format!("{message:?}"), None))
@@ -681,6 +720,12 @@ impl ErrorSwitch<api_models::errors::types::ApiErrorResponse> for ApiErrorRespon
             Self::InvalidPlatformOperation => {
                 AER::Unauthorized(ApiError::new("IR", 44, "Invalid platform account operation", None))
             }
+            Self::IdempotencyKeyMismatch => {
+                AER::Unprocessable(ApiError::new("IR", 46, "Idempotency key has already been used with a different request body", None))
+            }
+            Self::RateLimitExceeded { retry_after } => {
+                AER::TooManyRequests(ApiError::new("IR", 47, format!("Too many requests, retry after {retry_after} seconds"), None), *retry_after)
+            }
         }
     }
 }
@@ -838,6 +838,22 @@ pub trait PaymentMethodInterface {
         status: common_enums::PaymentMethodStatus,
     ) -> CustomResult<i64, Self::Error>;
 
+    #[cfg(all(
+        any(feature = "v1", feature = "v2"),
+        not(feature = "payment_methods_v2")
+    ))]
+    #[allow(clippy::too_many_arguments)]
+    async fn find_payment_method_by_merchant_id_payment_method_status(
+        &self,
+        state: &keymanager::KeyManagerState,
+        key_store: &MerchantKeyStore,
+        merchant_id: &id_type::MerchantId,
+        payment_method: common_enums::PaymentMethod,
+        status: common_enums::PaymentMethodStatus,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<PaymentMethod>, Self::Error>;
+
     async fn insert_payment_method(
         &self,
         state: &keymanager::KeyManagerState,
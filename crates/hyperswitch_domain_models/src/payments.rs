@@ -120,6 +120,8 @@ pub struct PaymentIntent {
     pub force_3ds_challenge: Option<bool>,
     pub force_3ds_challenge_trigger: Option<bool>,
     pub is_iframe_redirection_enabled: Option<bool>,
+    pub enable_partial_authorization: Option<bool>,
+    pub l2_l3_data: Option<common_types::payments::L2L3Data>,
 }
 
 impl PaymentIntent {
@@ -2009,6 +2009,8 @@ impl behaviour::Conversion for PaymentIntent {
             force_3ds_challenge: self.force_3ds_challenge,
             force_3ds_challenge_trigger: self.force_3ds_challenge_trigger,
             is_iframe_redirection_enabled: self.is_iframe_redirection_enabled,
+            enable_partial_authorization: self.enable_partial_authorization,
+            l2_l3_data: self.l2_l3_data,
         })
     }
 
@@ -2107,6 +2109,8 @@ impl behaviour::Conversion for PaymentIntent {
                 force_3ds_challenge: storage_model.force_3ds_challenge,
                 force_3ds_challenge_trigger: storage_model.force_3ds_challenge_trigger,
                 is_iframe_redirection_enabled: storage_model.is_iframe_redirection_enabled,
+                enable_partial_authorization: storage_model.enable_partial_authorization,
+                l2_l3_data: storage_model.l2_l3_data,
             })
         }
         .await
@@ -2178,6 +2182,8 @@ impl behaviour::Conversion for PaymentIntent {
             force_3ds_challenge: self.force_3ds_challenge,
             force_3ds_challenge_trigger: self.force_3ds_challenge_trigger,
             is_iframe_redirection_enabled: self.is_iframe_redirection_enabled,
+            enable_partial_authorization: self.enable_partial_authorization,
+            l2_l3_data: self.l2_l3_data,
         })
     }
 }
@@ -117,6 +117,7 @@ pub struct Payouts {
     pub payout_link_id: Option<String>,
     pub client_secret: Option<String>,
     pub priority: Option<storage_enums::PayoutSendPriority>,
+    pub scheduled_at: Option<PrimitiveDateTime>,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -145,6 +146,7 @@ pub struct PayoutsNew {
     pub payout_link_id: Option<String>,
     pub client_secret: Option<String>,
     pub priority: Option<storage_enums::PayoutSendPriority>,
+    pub scheduled_at: Option<PrimitiveDateTime>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -573,6 +573,7 @@ impl
                     connector_response_reference_id,
                     incremental_authorization_allowed,
                     charges,
+                    authorized_amount: _,
                 } => {
                     let attempt_status = self.get_attempt_status_for_db_update(payment_data);
 
@@ -806,6 +807,7 @@ impl
                     connector_response_reference_id,
                     incremental_authorization_allowed,
                     charges,
+                    authorized_amount: _,
                 } => {
                     let attempt_status = self.status;
 
@@ -1036,6 +1038,7 @@ impl
                     connector_response_reference_id,
                     incremental_authorization_allowed,
                     charges,
+                    authorized_amount: _,
                 } => {
                     let attempt_status = self.get_attempt_status_for_db_update(payment_data);
 
@@ -1269,6 +1272,7 @@ impl
                     connector_response_reference_id,
                     incremental_authorization_allowed,
                     charges,
+                    authorized_amount: _,
                 } => {
                     let attempt_status = self.get_attempt_status_for_db_update(payment_data);
 
@@ -63,6 +63,10 @@ pub struct PaymentsAuthorizeData {
     pub authentication_data: Option<AuthenticationData>,
     pub request_extended_authorization:
         Option<common_types::primitive_wrappers::RequestExtendedAuthorizationBool>,
+    /// Allows the connector to approve an amount lower than the requested amount
+    pub enable_partial_authorization: Option<bool>,
+    /// Level 2 and Level 3 data of the order, for connectors that support it
+    pub l2_l3_data: Option<common_types::payments::L2L3Data>,
     pub split_payments: Option<common_types::payments::SplitPaymentsRequest>,
 
     // New amount for amount frame work
@@ -30,6 +30,8 @@ pub enum PaymentsResponseData {
         connector_response_reference_id: Option<String>,
         incremental_authorization_allowed: Option<bool>,
         charges: Option<common_types::payments::ConnectorChargeResponseData>,
+        /// Amount approved by the issuer, populated when it is lower than the requested amount
+        authorized_amount: Option<MinorUnit>,
     },
     MultipleCaptureResponse {
         // pending_capture_id_list: Vec<String>,
@@ -168,6 +170,7 @@ impl PaymentsResponseData {
                     connector_response_reference_id: auth_connector_response_reference_id,
                     incremental_authorization_allowed: auth_incremental_auth_allowed,
                     charges: auth_charges,
+                    authorized_amount: auth_authorized_amount,
                 },
                 Self::TransactionResponse {
                     resource_id: capture_resource_id,
@@ -178,6 +181,7 @@ impl PaymentsResponseData {
                     connector_response_reference_id: capture_connector_response_reference_id,
                     incremental_authorization_allowed: capture_incremental_auth_allowed,
                     charges: capture_charges,
+                    authorized_amount: capture_authorized_amount,
                 },
             ) => Ok(Self::TransactionResponse {
                 resource_id: capture_resource_id.clone(),
@@ -203,6 +207,7 @@ impl PaymentsResponseData {
                 incremental_authorization_allowed: (*capture_incremental_auth_allowed)
                     .or(*auth_incremental_auth_allowed),
                 charges: auth_charges.clone().or(capture_charges.clone()),
+                authorized_amount: (*auth_authorized_amount).or(*capture_authorized_amount),
             }),
             _ => Err(ApiErrorResponse::NotSupported {
                 message: "Invalid Flow ".to_owned(),
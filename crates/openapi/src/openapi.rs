@@ -63,6 +63,7 @@ Never share your secret api keys. Keep them guarded and secure.
         (name = "Disputes", description = "Manage disputes"),
         (name = "API Key", description = "Create and manage API Keys"),
         (name = "Payouts", description = "Create and manage payouts"),
+        (name = "Subscriptions", description = "Create and manage subscription plans and subscriptions"),
         (name = "payment link", description = "Create payment link"),
         (name = "Routing", description = "Create and manage routing configurations"),
         (name = "Event", description = "Manage events"),
@@ -138,6 +139,7 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::payment_method::list_customer_payment_method_api,
         routes::payment_method::list_customer_payment_method_api_client,
         routes::payment_method::default_payment_method_set_api,
+        routes::payment_method::toggle_card_account_updater_api,
         routes::payment_method::payment_method_retrieve_api,
         routes::payment_method::payment_method_update_api,
         routes::payment_method::payment_method_delete_api,
@@ -157,6 +159,7 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::routing::routing_create_config,
         routes::routing::routing_link_config,
         routes::routing::routing_retrieve_config,
+        routes::routing::routing_simulate,
         routes::routing::list_routing_configs,
         routes::routing::routing_unlink_config,
         routes::routing::routing_update_default_config,
@@ -186,6 +189,21 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::payouts::payouts_confirm,
         routes::payouts::payouts_list_filters,
         routes::payouts::payouts_list_by_filter,
+        routes::payouts::payouts_batch_create,
+        routes::payouts::payouts_batch_retrieve,
+        routes::payouts::payouts_batch_report,
+
+        // Routes for subscriptions
+        routes::subscriptions::subscription_plan_create,
+        routes::subscriptions::subscription_plan_retrieve,
+        routes::subscriptions::subscription_plan_list,
+        routes::subscriptions::subscription_plan_update,
+        routes::subscriptions::subscription_create,
+        routes::subscriptions::subscription_retrieve,
+        routes::subscriptions::subscription_list,
+        routes::subscriptions::subscription_update,
+        routes::subscriptions::subscription_cancel,
+        routes::subscriptions::subscription_invoice_list,
 
         // Routes for api keys
         routes::api_keys::api_key_create,
@@ -199,6 +217,8 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::webhook_events::list_initial_webhook_delivery_attempts_with_jwtauth,
         routes::webhook_events::list_webhook_delivery_attempts,
         routes::webhook_events::retry_webhook_delivery_attempt,
+        routes::webhook_events::create_webhook_event_replay,
+        routes::webhook_events::retrieve_webhook_event_replay,
 
         // Routes for poll apis
         routes::poll::retrieve_poll_status,
@@ -234,6 +254,8 @@ Never share your secret api keys. Keep them guarded and secure.
         common_types::refunds::StripeSplitRefundRequest,
         common_types::payments::ConnectorChargeResponseData,
         common_types::payments::StripeChargeResponseData,
+        common_types::payments::L2L3Data,
+        common_types::payments::L2L3LineItem,
         common_types::three_ds_decision_rule_engine::ThreeDSDecisionRule,
         common_types::three_ds_decision_rule_engine::ThreeDSDecision,
         api_models::refunds::RefundRequest,
@@ -273,6 +295,8 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payment_methods::BankTransferTypes,
         api_models::payment_methods::CustomerPaymentMethodsListResponse,
         api_models::payment_methods::PaymentMethodDeleteResponse,
+        api_models::payment_methods::ToggleCardAccountUpdaterQuery,
+        api_models::payment_methods::ToggleCardAccountUpdaterResponse,
         api_models::payment_methods::PaymentMethodUpdate,
         api_models::payment_methods::CustomerDefaultPaymentMethodResponse,
         api_models::payment_methods::CardDetailFromLocker,
@@ -614,9 +638,25 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payouts::PayoutLinkResponse,
         api_models::payouts::Bank,
         api_models::payouts::PayoutCreatePayoutLinkConfig,
+        api_models::payouts::PayoutBatchResponse,
         api_models::enums::PayoutEntityType,
         api_models::enums::PayoutSendPriority,
         api_models::enums::PayoutStatus,
+        api_models::enums::PayoutBatchStatus,
+        api_models::enums::PayoutBatchItemStatus,
+        api_models::subscriptions::SubscriptionPlanCreateRequest,
+        api_models::subscriptions::SubscriptionPlanUpdateRequest,
+        api_models::subscriptions::SubscriptionPlanResponse,
+        api_models::subscriptions::SubscriptionCreateRequest,
+        api_models::subscriptions::SubscriptionUpdateRequest,
+        api_models::subscriptions::SubscriptionCancelRequest,
+        api_models::subscriptions::SubscriptionResponse,
+        api_models::subscriptions::SubscriptionInvoiceResponse,
+        api_models::enums::SubscriptionInterval,
+        api_models::enums::SubscriptionStatus,
+        api_models::enums::SubscriptionInvoiceStatus,
+        api_models::enums::SubscriptionBillingReason,
+        api_models::enums::ProrationBehavior,
         api_models::enums::PayoutType,
         api_models::enums::TransactionType,
         api_models::payments::FrmMessage,
@@ -638,6 +678,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::api_keys::RetrieveApiKeyResponse,
         api_models::api_keys::RevokeApiKeyResponse,
         api_models::api_keys::UpdateApiKeyRequest,
+        api_models::enums::PermissionGroup,
         api_models::payments::RetrievePaymentLinkRequest,
         api_models::payments::PaymentLinkResponse,
         api_models::payments::RetrievePaymentLinkResponse,
@@ -647,6 +688,11 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::GooglePayAssuranceDetails,
         api_models::routing::RoutingConfigRequest,
         api_models::routing::RoutingDictionaryRecord,
+        api_models::routing::RoutingSimulationAlgorithm,
+        api_models::routing::RoutingSimulationRequest,
+        api_models::routing::RoutingSimulationResponse,
+        api_models::routing::RoutingSimulationConnectorCount,
+        api_models::routing::RoutingSimulationRuleHitCount,
         api_models::routing::RoutingKind,
         api_models::routing::RoutableConnectorChoice,
         api_models::routing::DynamicRoutingFeatures,
@@ -684,6 +730,8 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::routing::ast::ProgramConnectorSelection,
         api_models::routing::ast::RuleConnectorSelection,
         api_models::routing::ast::IfStatement,
+        api_models::routing::ast::Condition,
+        api_models::routing::ast::ConditionGroup,
         api_models::routing::ast::Comparison,
         api_models::routing::ast::ComparisonType,
         api_models::routing::ast::ValueType,
@@ -692,10 +740,13 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payment_methods::RequestPaymentMethodTypes,
         api_models::payments::PaymentLinkStatus,
         api_models::blocklist::BlocklistRequest,
+        api_models::blocklist::AddToBlocklistRequest,
+        api_models::blocklist::BlocklistRequestType,
         api_models::blocklist::BlocklistResponse,
         api_models::blocklist::ToggleBlocklistResponse,
         api_models::blocklist::ListBlocklistQuery,
         api_models::enums::BlocklistDataKind,
+        api_models::enums::BlocklistEntryStatus,
         api_models::enums::ErrorCategory,
         api_models::webhook_events::EventListConstraints,
         api_models::webhook_events::EventListItemResponse,
@@ -703,6 +754,9 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::webhook_events::OutgoingWebhookRequestContent,
         api_models::webhook_events::OutgoingWebhookResponseContent,
         api_models::webhook_events::TotalEventsResponse,
+        api_models::webhook_events::WebhookEventReplayRequest,
+        api_models::webhook_events::WebhookEventReplayResponse,
+        api_models::webhook_events::WebhookEventReplayStatus,
         api_models::enums::WebhookDeliveryAttempt,
         api_models::enums::PaymentChargeType,
         api_models::enums::StripeChargeType,
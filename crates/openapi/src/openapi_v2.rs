@@ -188,6 +188,9 @@ Never share your secret api keys. Keep them guarded and secure.
         common_types::payments::XenditMultipleSplitRequest,
         common_types::domain::XenditSplitSubMerchantData,
         common_types::domain::AdyenSplitItem,
+        common_types::revenue_recovery::RevenueRecoveryRetryPolicy,
+        common_types::revenue_recovery::RetryIntervalStep,
+        common_types::revenue_recovery::RetryBlackoutWindow,
         common_types::refunds::StripeSplitRefundRequest,
         common_utils::types::ChargeRefunds,
         common_types::payment_methods::PaymentMethodsEnabled,
@@ -634,6 +637,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::api_keys::RetrieveApiKeyResponse,
         api_models::api_keys::RevokeApiKeyResponse,
         api_models::api_keys::UpdateApiKeyRequest,
+        api_models::enums::PermissionGroup,
         api_models::payments::RetrievePaymentLinkRequest,
         api_models::payments::PaymentLinkResponse,
         api_models::payments::RetrievePaymentLinkResponse,
@@ -680,6 +684,8 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::routing::ast::ProgramConnectorSelection,
         api_models::routing::ast::RuleConnectorSelection,
         api_models::routing::ast::IfStatement,
+        api_models::routing::ast::Condition,
+        api_models::routing::ast::ConditionGroup,
         api_models::routing::ast::Comparison,
         api_models::routing::ast::ComparisonType,
         api_models::routing::ast::ValueType,
@@ -689,10 +695,13 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payment_methods::RequestPaymentMethodTypes,
         api_models::payments::PaymentLinkStatus,
         api_models::blocklist::BlocklistRequest,
+        api_models::blocklist::AddToBlocklistRequest,
+        api_models::blocklist::BlocklistRequestType,
         api_models::blocklist::BlocklistResponse,
         api_models::blocklist::ToggleBlocklistResponse,
         api_models::blocklist::ListBlocklistQuery,
         api_models::enums::BlocklistDataKind,
+        api_models::enums::BlocklistEntryStatus,
         api_models::enums::ErrorCategory,
         api_models::webhook_events::EventListItemResponse,
         api_models::webhook_events::EventRetrieveResponse,
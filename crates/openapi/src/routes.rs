@@ -20,5 +20,6 @@ pub mod refunds;
 pub mod relay;
 pub mod revenue_recovery;
 pub mod routing;
+pub mod subscriptions;
 pub mod tokenization;
 pub mod webhook_events;
@@ -17,7 +17,7 @@ pub async fn toggle_blocklist_guard() {}
 #[utoipa::path(
     post,
     path = "/blocklist",
-    request_body = BlocklistRequest,
+    request_body = AddToBlocklistRequest,
     responses(
         (status = 200, description = "Fingerprint Blocked", body = BlocklistResponse),
         (status = 400, description = "Invalid Data")
@@ -46,7 +46,10 @@ pub async fn remove_entry_from_blocklist() {}
     get,
     path = "/blocklist",
     params (
-        ("data_kind" = BlocklistDataKind, Query, description = "Kind of the fingerprint list requested"),
+        ("data_kind" = Option<BlocklistDataKind>, Query, description = "Kind of the fingerprint list requested"),
+        ("status" = Option<BlocklistEntryStatus>, Query, description = "Status of the entries requested"),
+        ("limit" = Option<u16>, Query, description = "The maximum number of entries to include in the response"),
+        ("offset" = Option<u16>, Query, description = "The number of entries to skip"),
     ),
     responses(
         (status = 200, description = "Blocked Fingerprints", body = BlocklistResponse),
@@ -198,6 +198,30 @@ pub async fn payment_method_delete_api() {}
 )]
 pub async fn default_payment_method_set_api() {}
 
+/// Payment Method - Toggle Card Account Updater
+///
+/// Enables or disables the recurring card account updater for the merchant. Stored cards which
+/// are about to expire are refreshed with the new expiry date or card number reported by the
+/// issuer, and cards of closed accounts are marked inactive.
+#[utoipa::path(
+    post,
+    path = "/payment_methods/account_updater/toggle",
+    params (
+        ("status" = bool, Query, description = "Boolean value to enable/disable the card account updater"),
+        ("profile_id" = Option<String>, Query, description = "The profile whose webhook settings are used for the card account updater webhooks, defaults to the default profile of the merchant"),
+    ),
+    responses(
+        (status = 200, description = "Card account updater enabled/disabled", body = ToggleCardAccountUpdaterResponse),
+        (status = 400, description = "Invalid Data"),
+        (status = 404, description = "Profile not found")
+    ),
+    tag = "Payment Methods",
+    operation_id = "Toggle card account updater for a particular merchant",
+    security(("api_key" = []))
+)]
+#[cfg(feature = "v1")]
+pub async fn toggle_card_account_updater_api() {}
+
 /// Payment Method - Create Intent
 ///
 /// Creates a payment method for customer with billing information and other metadata.
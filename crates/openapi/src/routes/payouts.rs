@@ -190,3 +190,60 @@ pub async fn payouts_list_by_filter_profile() {}
     security(("api_key" = []))
 )]
 pub async fn payouts_confirm() {}
+
+/// Payouts - Batch Create
+///
+/// Upload a batch file of payouts. The file is a CSV file, or a JSON array of payout create requests when the file name ends with `.json`. Every row is validated upfront, and the payouts of the valid rows are created asynchronously.
+#[utoipa::path(
+    post,
+    path = "/payouts/batch",
+    request_body(
+        content = String,
+        content_type = "multipart/form-data",
+        description = "The batch file in the `file` field, and an optional `profile_id` field which is used for the rows that do not specify a profile"
+    ),
+    responses(
+        (status = 200, description = "Payout batch created", body = PayoutBatchResponse),
+        (status = 400, description = "Invalid batch file")
+    ),
+    tag = "Payouts",
+    operation_id = "Create a Payout Batch",
+    security(("api_key" = []))
+)]
+pub async fn payouts_batch_create() {}
+
+/// Payouts - Batch Retrieve
+#[utoipa::path(
+    get,
+    path = "/payouts/batch/{batch_id}",
+    params(
+        ("batch_id" = String, Path, description = "The identifier for the payout batch")
+    ),
+    responses(
+        (status = 200, description = "Payout batch retrieved", body = PayoutBatchResponse),
+        (status = 404, description = "Payout batch does not exist in our records")
+    ),
+    tag = "Payouts",
+    operation_id = "Retrieve a Payout Batch",
+    security(("api_key" = []))
+)]
+pub async fn payouts_batch_retrieve() {}
+
+/// Payouts - Batch Report
+///
+/// Download the result of every row of a payout batch as a CSV file.
+#[utoipa::path(
+    get,
+    path = "/payouts/batch/{batch_id}/report",
+    params(
+        ("batch_id" = String, Path, description = "The identifier for the payout batch")
+    ),
+    responses(
+        (status = 200, description = "Payout batch report", content_type = "text/csv", body = String),
+        (status = 404, description = "Payout batch does not exist in our records")
+    ),
+    tag = "Payouts",
+    operation_id = "Retrieve a Payout Batch Report",
+    security(("api_key" = []))
+)]
+pub async fn payouts_batch_report() {}
@@ -86,6 +86,29 @@ pub async fn routing_link_config() {}
 )]
 pub async fn routing_retrieve_config() {}
 
+#[cfg(feature = "v1")]
+/// Routing - Simulate
+///
+/// Evaluate a routing algorithm against the payments created under a profile within a time range,
+/// and compare the connectors chosen by the algorithm with the connectors that were actually used.
+/// The routing of live payments is not affected.
+#[utoipa::path(
+    post,
+    path = "/routing/simulate",
+    request_body = RoutingSimulationRequest,
+    responses(
+        (status = 200, description = "Routing simulation completed", body = RoutingSimulationResponse),
+        (status = 400, description = "Request body is malformed"),
+        (status = 500, description = "Internal server error"),
+        (status = 404, description = "Resource missing"),
+        (status = 403, description = "Forbidden")
+    ),
+   tag = "Routing",
+   operation_id = "Simulate a routing algorithm",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn routing_simulate() {}
+
 #[cfg(feature = "v2")]
 /// Routing - Retrieve
 ///
@@ -0,0 +1,184 @@
+/// Subscription Plans - Create
+///
+/// Creates a plan, which defines the amount, currency and billing period of its subscriptions
+#[utoipa::path(
+    post,
+    path = "/subscriptions/plans",
+    request_body = SubscriptionPlanCreateRequest,
+    responses(
+        (status = 200, description = "Subscription plan created", body = SubscriptionPlanResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Subscriptions",
+    operation_id = "Create a Subscription Plan",
+    security(("api_key" = []))
+)]
+pub async fn subscription_plan_create() {}
+
+/// Subscription Plans - Retrieve
+#[utoipa::path(
+    get,
+    path = "/subscriptions/plans/{plan_id}",
+    params(
+        ("plan_id" = String, Path, description = "The identifier for the subscription plan")
+    ),
+    responses(
+        (status = 200, description = "Subscription plan retrieved", body = SubscriptionPlanResponse),
+        (status = 404, description = "Subscription plan does not exist in our records")
+    ),
+    tag = "Subscriptions",
+    operation_id = "Retrieve a Subscription Plan",
+    security(("api_key" = []))
+)]
+pub async fn subscription_plan_retrieve() {}
+
+/// Subscription Plans - List
+#[utoipa::path(
+    get,
+    path = "/subscriptions/plans",
+    params(
+        ("limit" = Option<u32>, Query, description = "The maximum number of plans to be returned"),
+        ("offset" = Option<u32>, Query, description = "The number of plans to be skipped"),
+    ),
+    responses(
+        (status = 200, description = "Subscription plans listed", body = Vec<SubscriptionPlanResponse>),
+    ),
+    tag = "Subscriptions",
+    operation_id = "List Subscription Plans",
+    security(("api_key" = []))
+)]
+pub async fn subscription_plan_list() {}
+
+/// Subscription Plans - Update
+///
+/// Updates the details of a plan. The amount and the billing period of a plan cannot be updated.
+#[utoipa::path(
+    post,
+    path = "/subscriptions/plans/{plan_id}",
+    params(
+        ("plan_id" = String, Path, description = "The identifier for the subscription plan")
+    ),
+    request_body = SubscriptionPlanUpdateRequest,
+    responses(
+        (status = 200, description = "Subscription plan updated", body = SubscriptionPlanResponse),
+        (status = 404, description = "Subscription plan does not exist in our records")
+    ),
+    tag = "Subscriptions",
+    operation_id = "Update a Subscription Plan",
+    security(("api_key" = []))
+)]
+pub async fn subscription_plan_update() {}
+
+/// Subscriptions - Create
+///
+/// Subscribes a customer to a plan. The invoices of the subscription are charged to the mandate or
+/// the stored payment method of the customer at the start of every billing period, after the trial
+/// period if any.
+#[utoipa::path(
+    post,
+    path = "/subscriptions",
+    request_body = SubscriptionCreateRequest,
+    responses(
+        (status = 200, description = "Subscription created", body = SubscriptionResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Subscriptions",
+    operation_id = "Create a Subscription",
+    security(("api_key" = []))
+)]
+pub async fn subscription_create() {}
+
+/// Subscriptions - Retrieve
+#[utoipa::path(
+    get,
+    path = "/subscriptions/{subscription_id}",
+    params(
+        ("subscription_id" = String, Path, description = "The identifier for the subscription")
+    ),
+    responses(
+        (status = 200, description = "Subscription retrieved", body = SubscriptionResponse),
+        (status = 404, description = "Subscription does not exist in our records")
+    ),
+    tag = "Subscriptions",
+    operation_id = "Retrieve a Subscription",
+    security(("api_key" = []))
+)]
+pub async fn subscription_retrieve() {}
+
+/// Subscriptions - List
+#[utoipa::path(
+    get,
+    path = "/subscriptions",
+    params(
+        ("customer_id" = Option<String>, Query, description = "The identifier for the customer whose subscriptions are listed"),
+        ("limit" = Option<u32>, Query, description = "The maximum number of subscriptions to be returned"),
+        ("offset" = Option<u32>, Query, description = "The number of subscriptions to be skipped"),
+    ),
+    responses(
+        (status = 200, description = "Subscriptions listed", body = Vec<SubscriptionResponse>),
+    ),
+    tag = "Subscriptions",
+    operation_id = "List Subscriptions",
+    security(("api_key" = []))
+)]
+pub async fn subscription_list() {}
+
+/// Subscriptions - Update
+///
+/// Updates a subscription. When the plan of the subscription is changed, the difference in the
+/// amount of the plans for the remaining time of the current billing period is charged or
+/// credited, unless `proration_behavior` is `none`.
+#[utoipa::path(
+    post,
+    path = "/subscriptions/{subscription_id}",
+    params(
+        ("subscription_id" = String, Path, description = "The identifier for the subscription")
+    ),
+    request_body = SubscriptionUpdateRequest,
+    responses(
+        (status = 200, description = "Subscription updated", body = SubscriptionResponse),
+        (status = 400, description = "Invalid data"),
+        (status = 404, description = "Subscription does not exist in our records")
+    ),
+    tag = "Subscriptions",
+    operation_id = "Update a Subscription",
+    security(("api_key" = []))
+)]
+pub async fn subscription_update() {}
+
+/// Subscriptions - Cancel
+///
+/// Cancels a subscription immediately, or at the end of the current billing period
+#[utoipa::path(
+    post,
+    path = "/subscriptions/{subscription_id}/cancel",
+    params(
+        ("subscription_id" = String, Path, description = "The identifier for the subscription")
+    ),
+    request_body = SubscriptionCancelRequest,
+    responses(
+        (status = 200, description = "Subscription cancelled", body = SubscriptionResponse),
+        (status = 404, description = "Subscription does not exist in our records")
+    ),
+    tag = "Subscriptions",
+    operation_id = "Cancel a Subscription",
+    security(("api_key" = []))
+)]
+pub async fn subscription_cancel() {}
+
+/// Subscriptions - List Invoices
+#[utoipa::path(
+    get,
+    path = "/subscriptions/{subscription_id}/invoices",
+    params(
+        ("subscription_id" = String, Path, description = "The identifier for the subscription")
+    ),
+    responses(
+        (status = 200, description = "Invoices of the subscription listed", body = Vec<SubscriptionInvoiceResponse>),
+        (status = 404, description = "Subscription does not exist in our records")
+    ),
+    tag = "Subscriptions",
+    operation_id = "List Invoices of a Subscription",
+    security(("api_key" = []))
+)]
+pub async fn subscription_invoice_list() {}
@@ -115,3 +115,56 @@ pub fn list_webhook_delivery_attempts() {}
     security(("admin_api_key" = []))
 )]
 pub fn retry_webhook_delivery_attempt() {}
+
+/// Events - Replay
+///
+/// Create a job to redeliver the Events of a Business Profile created within the specified time range.
+#[utoipa::path(
+    post,
+    path = "/events/{merchant_id}/replay",
+    params(
+        ("merchant_id" = String, Path, description = "The unique identifier for the Merchant Account."),
+    ),
+    request_body(
+        content = WebhookEventReplayRequest,
+        description = "The Events to be replayed",
+        examples(
+            (
+                "Replay the undelivered Events of a Business Profile" = (
+                value = json!({
+                    "profile_id": "SqB0zwDGR5wHppWf0bx7GKr1f2",
+                    "created_after": "2023-01-01T00:00:00",
+                    "created_before": "2023-01-02T00:00:00",
+                    "is_delivered": false
+                })
+            )),
+        )
+    ),
+    responses(
+        (status = 200, description = "Replay job created successfully", body = WebhookEventReplayResponse),
+    ),
+    tag = "Event",
+    operation_id = "Replay Events of a Profile",
+    security(("admin_api_key" = []))
+)]
+pub fn create_webhook_event_replay() {}
+
+/// Events - Replay Retrieve
+///
+/// Retrieve the progress of the specified Event replay job.
+#[utoipa::path(
+    get,
+    path = "/events/{merchant_id}/replay/{replay_id}",
+    params(
+        ("merchant_id" = String, Path, description = "The unique identifier for the Merchant Account."),
+        ("replay_id" = String, Path, description = "The unique identifier for the replay job"),
+    ),
+    responses(
+        (status = 200, description = "Replay job retrieved successfully", body = WebhookEventReplayResponse),
+        (status = 404, description = "Replay job not found"),
+    ),
+    tag = "Event",
+    operation_id = "Retrieve an Event replay job",
+    security(("admin_api_key" = []))
+)]
+pub fn retrieve_webhook_event_replay() {}
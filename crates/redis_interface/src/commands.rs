@@ -4,7 +4,7 @@
 //! and deserialization while calling redis.
 //! It also includes instruments to provide tracing.
 
-use std::fmt::Debug;
+use std::{collections::HashMap, fmt::Debug};
 
 use common_utils::{
     errors::CustomResult,
@@ -711,6 +711,20 @@ impl super::RedisConnectionPool {
             .change_context(errors::RedisError::StreamAcknowledgeFailed)
     }
 
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn stream_read_range(
+        &self,
+        stream: &RedisKey,
+        start: &str,
+        end: &str,
+        count: Option<u64>,
+    ) -> CustomResult<Vec<(String, HashMap<String, String>)>, errors::RedisError> {
+        self.pool
+            .xrange_values(stream.tenant_aware_key(self), start, end, count)
+            .await
+            .change_context(errors::RedisError::StreamReadFailed)
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn stream_get_length(
         &self,
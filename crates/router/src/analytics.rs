@@ -3,27 +3,35 @@ pub use analytics::*;
 pub mod routes {
     use std::{
         collections::{HashMap, HashSet},
-        sync::Arc,
+        sync::{Arc, OnceLock},
     };
 
-    use actix_web::{web, Responder, Scope};
+    use actix_web::{web, HttpResponse, Responder, Scope};
     use analytics::{
-        api_event::api_events_core, connector_events::connector_events_core, enums::AuthInfo,
-        errors::AnalyticsError, lambda_utils::invoke_lambda, opensearch::OpenSearchError,
-        outgoing_webhook_event::outgoing_webhook_events_core, sdk_events::sdk_events_core,
-        AnalyticsFlow,
+        api_event::api_events_core,
+        connector_events::connector_events_core,
+        enums::AuthInfo,
+        errors::{AnalyticsError, AnalyticsResult},
+        lambda_utils::invoke_lambda,
+        opensearch::OpenSearchError,
+        outgoing_webhook_event::outgoing_webhook_events_core,
+        sdk_events::sdk_events_core,
+        AnalyticsFlow, ReportGenerator,
     };
     use api_models::analytics::{
         api_event::QueryType,
         search::{
             GetGlobalSearchRequest, GetSearchRequest, GetSearchRequestWithIndex, SearchIndex,
         },
-        AnalyticsRequest, GenerateReportRequest, GetActivePaymentsMetricRequest,
-        GetApiEventFiltersRequest, GetApiEventMetricRequest, GetAuthEventFilterRequest,
-        GetAuthEventMetricRequest, GetDisputeMetricRequest, GetFrmFilterRequest,
-        GetFrmMetricRequest, GetPaymentFiltersRequest, GetPaymentIntentFiltersRequest,
-        GetPaymentIntentMetricRequest, GetPaymentMetricRequest, GetRefundFilterRequest,
-        GetRefundMetricRequest, GetSdkEventFiltersRequest, GetSdkEventMetricRequest, ReportRequest,
+        AnalyticsRequest, CreateDigestSubscriptionRequest, GenerateReportRequest,
+        GenerateReportResponse, GetActivePaymentsMetricRequest, GetApiEventFiltersRequest,
+        GetApiEventMetricRequest, GetAuthEventFilterRequest, GetAuthEventMetricRequest,
+        GetDisputeMetricRequest, GetFrmFilterRequest, GetFrmMetricRequest,
+        GetPaymentFiltersRequest, GetPaymentIntentFiltersRequest, GetPaymentIntentMetricRequest,
+        GetPaymentMetricRequest, GetPayoutFilterRequest, GetPayoutMetricRequest,
+        GetRefundFilterRequest, GetRefundMetricRequest, GetSdkEventFiltersRequest,
+        GetSdkEventMetricRequest, ReportFormat, ReportRequest, ReportType,
+        UpdateDigestSubscriptionRequest,
     };
     use common_enums::EntityType;
     use common_utils::types::TimeRange;
@@ -33,9 +41,11 @@ pub mod routes {
     use crate::{
         analytics_validator::request_validator,
         consts::opensearch::SEARCH_INDEXES,
-        core::{api_locking, errors::user::UserErrors, verification::utils},
+        core::{
+            analytics_digest, api_locking, errors::user::UserErrors, reports, verification::utils,
+        },
         db::{user::UserInterface, user_role::ListUserRolesByUserIdPayload},
-        routes::AppState,
+        routes::{AppState, SessionState},
         services::{
             api,
             authentication::{self as auth, AuthenticationData, UserFromToken},
@@ -77,6 +87,14 @@ pub mod routes {
                             web::resource("filters/refunds")
                                 .route(web::post().to(get_merchant_refund_filters)),
                         )
+                        .service(
+                            web::resource("metrics/payouts")
+                                .route(web::post().to(get_merchant_payout_metrics)),
+                        )
+                        .service(
+                            web::resource("filters/payouts")
+                                .route(web::post().to(get_merchant_payout_filters)),
+                        )
                         .service(web::resource("{domain}/info").route(web::get().to(get_info)))
                         .service(
                             web::resource("report/dispute")
@@ -94,6 +112,14 @@ pub mod routes {
                             web::resource("report/authentications")
                                 .route(web::post().to(generate_merchant_authentication_report)),
                         )
+                        .service(
+                            web::resource("report/status/{report_id}")
+                                .route(web::get().to(retrieve_merchant_report)),
+                        )
+                        .service(
+                            web::resource("report/download/{token}")
+                                .route(web::get().to(download_report)),
+                        )
                         .service(
                             web::resource("metrics/sdk_events")
                                 .route(web::post().to(get_sdk_event_metrics)),
@@ -179,6 +205,14 @@ pub mod routes {
                                     web::resource("filters/refunds")
                                         .route(web::post().to(get_merchant_refund_filters)),
                                 )
+                                .service(
+                                    web::resource("metrics/payouts")
+                                        .route(web::post().to(get_merchant_payout_metrics)),
+                                )
+                                .service(
+                                    web::resource("filters/payouts")
+                                        .route(web::post().to(get_merchant_payout_filters)),
+                                )
                                 .service(
                                     web::resource("{domain}/info").route(web::get().to(get_info)),
                                 )
@@ -199,6 +233,20 @@ pub mod routes {
                                         web::post().to(generate_merchant_authentication_report),
                                     ),
                                 )
+                                .service(
+                                    web::resource("report/status/{report_id}")
+                                        .route(web::get().to(retrieve_merchant_report)),
+                                )
+                                .service(
+                                    web::resource("digest_subscriptions")
+                                        .route(web::post().to(create_digest_subscription))
+                                        .route(web::get().to(list_digest_subscriptions)),
+                                )
+                                .service(
+                                    web::resource("digest_subscriptions/{subscription_id}")
+                                        .route(web::post().to(update_digest_subscription))
+                                        .route(web::delete().to(delete_digest_subscription)),
+                                )
                                 .service(
                                     web::resource("metrics/api_events")
                                         .route(web::post().to(get_merchant_api_events_metrics)),
@@ -241,6 +289,14 @@ pub mod routes {
                                     web::resource("filters/refunds")
                                         .route(web::post().to(get_org_refund_filters)),
                                 )
+                                .service(
+                                    web::resource("metrics/payouts")
+                                        .route(web::post().to(get_org_payout_metrics)),
+                                )
+                                .service(
+                                    web::resource("filters/payouts")
+                                        .route(web::post().to(get_org_payout_filters)),
+                                )
                                 .service(
                                     web::resource("metrics/disputes")
                                         .route(web::post().to(get_org_dispute_metrics)),
@@ -265,6 +321,10 @@ pub mod routes {
                                     web::resource("report/authentications")
                                         .route(web::post().to(generate_org_authentication_report)),
                                 )
+                                .service(
+                                    web::resource("report/status/{report_id}")
+                                        .route(web::get().to(retrieve_org_report)),
+                                )
                                 .service(
                                     web::resource("metrics/sankey")
                                         .route(web::post().to(get_org_sankey)),
@@ -291,6 +351,14 @@ pub mod routes {
                                     web::resource("filters/refunds")
                                         .route(web::post().to(get_profile_refund_filters)),
                                 )
+                                .service(
+                                    web::resource("metrics/payouts")
+                                        .route(web::post().to(get_profile_payout_metrics)),
+                                )
+                                .service(
+                                    web::resource("filters/payouts")
+                                        .route(web::post().to(get_profile_payout_filters)),
+                                )
                                 .service(
                                     web::resource("metrics/disputes")
                                         .route(web::post().to(get_profile_dispute_metrics)),
@@ -324,6 +392,10 @@ pub mod routes {
                                         web::post().to(generate_profile_authentication_report),
                                     ),
                                 )
+                                .service(
+                                    web::resource("report/status/{report_id}")
+                                        .route(web::get().to(retrieve_profile_report)),
+                                )
                                 .service(
                                     web::resource("api_event_logs")
                                         .route(web::get().to(get_profile_api_events)),
@@ -1302,6 +1374,289 @@ pub mod routes {
         .await
     }
 
+    /// Payouts are not stored along with the organization they belong to, so organization level
+    /// access is resolved into the merchants of the organization.
+    #[cfg(feature = "v1")]
+    async fn get_org_payout_auth_info(
+        state: &SessionState,
+        org_id: &common_utils::id_type::OrganizationId,
+    ) -> AnalyticsResult<AuthInfo> {
+        let merchant_ids = state
+            .store
+            .list_merchant_accounts_by_organization_id(&state.into(), org_id)
+            .await
+            .change_context(AnalyticsError::UnknownError)
+            .attach_printable("Failed to list merchant accounts of the organization")?
+            .iter()
+            .map(|merchant_account| merchant_account.get_id().clone())
+            .collect();
+
+        Ok(AuthInfo::MerchantLevel {
+            org_id: org_id.clone(),
+            merchant_ids,
+        })
+    }
+
+    /// # Panics
+    ///
+    /// Panics if `json_payload` array does not contain one `GetPayoutMetricRequest` element.
+    pub async fn get_merchant_payout_metrics(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<[GetPayoutMetricRequest; 1]>,
+    ) -> impl Responder {
+        #[allow(clippy::expect_used)]
+        // safety: This shouldn't panic owing to the data type
+        let payload = json_payload
+            .into_inner()
+            .to_vec()
+            .pop()
+            .expect("Couldn't get GetPayoutMetricRequest");
+        let flow = AnalyticsFlow::GetPayoutMetrics;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            payload,
+            |state, auth: AuthenticationData, req, _| async move {
+                let org_id = auth.merchant_account.get_org_id();
+                let merchant_id = auth.merchant_account.get_id();
+                let auth: AuthInfo = AuthInfo::MerchantLevel {
+                    org_id: org_id.clone(),
+                    merchant_ids: vec![merchant_id.clone()],
+                };
+
+                let validator_response = request_validator(
+                    AnalyticsRequest {
+                        payout: Some(req.clone()),
+                        ..Default::default()
+                    },
+                    &state,
+                )
+                .await?;
+                let ex_rates = validator_response;
+                analytics::payouts::get_metrics(&state.pool, &ex_rates, &auth, req)
+                    .await
+                    .map(ApplicationResponse::Json)
+            },
+            &auth::JWTAuth {
+                permission: Permission::MerchantAnalyticsRead,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    #[cfg(feature = "v1")]
+    /// # Panics
+    ///
+    /// Panics if `json_payload` array does not contain one `GetPayoutMetricRequest` element.
+    pub async fn get_org_payout_metrics(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<[GetPayoutMetricRequest; 1]>,
+    ) -> impl Responder {
+        #[allow(clippy::expect_used)]
+        // safety: This shouldn't panic owing to the data type
+        let payload = json_payload
+            .into_inner()
+            .to_vec()
+            .pop()
+            .expect("Couldn't get GetPayoutMetricRequest");
+        let flow = AnalyticsFlow::GetPayoutMetrics;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            payload,
+            |state, auth: AuthenticationData, req, _| async move {
+                let org_id = auth.merchant_account.get_org_id();
+                let auth = get_org_payout_auth_info(&state, org_id).await?;
+
+                let validator_response = request_validator(
+                    AnalyticsRequest {
+                        payout: Some(req.clone()),
+                        ..Default::default()
+                    },
+                    &state,
+                )
+                .await?;
+                let ex_rates = validator_response;
+                analytics::payouts::get_metrics(&state.pool, &ex_rates, &auth, req)
+                    .await
+                    .map(ApplicationResponse::Json)
+            },
+            auth::auth_type(
+                &auth::PlatformOrgAdminAuth {
+                    is_admin_auth_allowed: false,
+                    organization_id: None,
+                },
+                &auth::JWTAuth {
+                    permission: Permission::OrganizationAnalyticsRead,
+                },
+                req.headers(),
+            ),
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    #[cfg(feature = "v1")]
+    /// # Panics
+    ///
+    /// Panics if `json_payload` array does not contain one `GetPayoutMetricRequest` element.
+    pub async fn get_profile_payout_metrics(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<[GetPayoutMetricRequest; 1]>,
+    ) -> impl Responder {
+        #[allow(clippy::expect_used)]
+        // safety: This shouldn't panic owing to the data type
+        let payload = json_payload
+            .into_inner()
+            .to_vec()
+            .pop()
+            .expect("Couldn't get GetPayoutMetricRequest");
+        let flow = AnalyticsFlow::GetPayoutMetrics;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            payload,
+            |state, auth: AuthenticationData, req, _| async move {
+                let org_id = auth.merchant_account.get_org_id();
+                let merchant_id = auth.merchant_account.get_id();
+                let profile_id = auth
+                    .profile_id
+                    .ok_or(report!(UserErrors::JwtProfileIdMissing))
+                    .change_context(AnalyticsError::AccessForbiddenError)?;
+                let auth: AuthInfo = AuthInfo::ProfileLevel {
+                    org_id: org_id.clone(),
+                    merchant_id: merchant_id.clone(),
+                    profile_ids: vec![profile_id.clone()],
+                };
+
+                let validator_response = request_validator(
+                    AnalyticsRequest {
+                        payout: Some(req.clone()),
+                        ..Default::default()
+                    },
+                    &state,
+                )
+                .await?;
+                let ex_rates = validator_response;
+                analytics::payouts::get_metrics(&state.pool, &ex_rates, &auth, req)
+                    .await
+                    .map(ApplicationResponse::Json)
+            },
+            &auth::JWTAuth {
+                permission: Permission::ProfileAnalyticsRead,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    pub async fn get_merchant_payout_filters(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<GetPayoutFilterRequest>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::GetPayoutFilters;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            json_payload.into_inner(),
+            |state, auth: AuthenticationData, req: GetPayoutFilterRequest, _| async move {
+                let org_id = auth.merchant_account.get_org_id();
+                let merchant_id = auth.merchant_account.get_id();
+                let auth: AuthInfo = AuthInfo::MerchantLevel {
+                    org_id: org_id.clone(),
+                    merchant_ids: vec![merchant_id.clone()],
+                };
+                analytics::payouts::get_filters(&state.pool, req, &auth)
+                    .await
+                    .map(ApplicationResponse::Json)
+            },
+            &auth::JWTAuth {
+                permission: Permission::MerchantAnalyticsRead,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    #[cfg(feature = "v1")]
+    pub async fn get_org_payout_filters(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<GetPayoutFilterRequest>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::GetPayoutFilters;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            json_payload.into_inner(),
+            |state, auth: AuthenticationData, req: GetPayoutFilterRequest, _| async move {
+                let org_id = auth.merchant_account.get_org_id();
+                let auth = get_org_payout_auth_info(&state, org_id).await?;
+                analytics::payouts::get_filters(&state.pool, req, &auth)
+                    .await
+                    .map(ApplicationResponse::Json)
+            },
+            auth::auth_type(
+                &auth::PlatformOrgAdminAuth {
+                    is_admin_auth_allowed: false,
+                    organization_id: None,
+                },
+                &auth::JWTAuth {
+                    permission: Permission::OrganizationAnalyticsRead,
+                },
+                req.headers(),
+            ),
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    #[cfg(feature = "v1")]
+    pub async fn get_profile_payout_filters(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<GetPayoutFilterRequest>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::GetPayoutFilters;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            json_payload.into_inner(),
+            |state, auth: AuthenticationData, req: GetPayoutFilterRequest, _| async move {
+                let org_id = auth.merchant_account.get_org_id();
+                let merchant_id = auth.merchant_account.get_id();
+                let profile_id = auth
+                    .profile_id
+                    .ok_or(report!(UserErrors::JwtProfileIdMissing))
+                    .change_context(AnalyticsError::AccessForbiddenError)?;
+                let auth: AuthInfo = AuthInfo::ProfileLevel {
+                    org_id: org_id.clone(),
+                    merchant_id: merchant_id.clone(),
+                    profile_ids: vec![profile_id.clone()],
+                };
+                analytics::payouts::get_filters(&state.pool, req, &auth)
+                    .await
+                    .map(ApplicationResponse::Json)
+            },
+            &auth::JWTAuth {
+                permission: Permission::ProfileAnalyticsRead,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
     pub async fn get_frm_filters(
         state: web::Data<AppState>,
         req: actix_web::HttpRequest,
@@ -1485,15 +1840,13 @@ pub mod routes {
                     email: user_email,
                 };
 
-                let json_bytes =
-                    serde_json::to_vec(&lambda_req).map_err(|_| AnalyticsError::UnknownError)?;
-                invoke_lambda(
+                generate_report(
+                    &state,
+                    ReportType::Refund,
                     &state.conf.report_download_config.refund_function,
-                    &state.conf.report_download_config.region,
-                    &json_bytes,
+                    lambda_req,
                 )
                 .await
-                .map(ApplicationResponse::Json)
             },
             &auth::JWTAuth {
                 permission: Permission::MerchantReportRead,
@@ -1534,15 +1887,13 @@ pub mod routes {
                     email: user_email,
                 };
 
-                let json_bytes =
-                    serde_json::to_vec(&lambda_req).map_err(|_| AnalyticsError::UnknownError)?;
-                invoke_lambda(
+                generate_report(
+                    &state,
+                    ReportType::Refund,
                     &state.conf.report_download_config.refund_function,
-                    &state.conf.report_download_config.region,
-                    &json_bytes,
+                    lambda_req,
                 )
                 .await
-                .map(ApplicationResponse::Json)
             },
             &auth::JWTAuth {
                 permission: Permission::OrganizationReportRead,
@@ -1590,15 +1941,13 @@ pub mod routes {
                     email: user_email,
                 };
 
-                let json_bytes =
-                    serde_json::to_vec(&lambda_req).map_err(|_| AnalyticsError::UnknownError)?;
-                invoke_lambda(
+                generate_report(
+                    &state,
+                    ReportType::Refund,
                     &state.conf.report_download_config.refund_function,
-                    &state.conf.report_download_config.region,
-                    &json_bytes,
+                    lambda_req,
                 )
                 .await
-                .map(ApplicationResponse::Json)
             },
             &auth::JWTAuth {
                 permission: Permission::ProfileReportRead,
@@ -1640,15 +1989,13 @@ pub mod routes {
                     email: user_email,
                 };
 
-                let json_bytes =
-                    serde_json::to_vec(&lambda_req).map_err(|_| AnalyticsError::UnknownError)?;
-                invoke_lambda(
+                generate_report(
+                    &state,
+                    ReportType::Dispute,
                     &state.conf.report_download_config.dispute_function,
-                    &state.conf.report_download_config.region,
-                    &json_bytes,
+                    lambda_req,
                 )
                 .await
-                .map(ApplicationResponse::Json)
             },
             &auth::JWTAuth {
                 permission: Permission::MerchantReportRead,
@@ -1688,15 +2035,13 @@ pub mod routes {
                     email: user_email,
                 };
 
-                let json_bytes =
-                    serde_json::to_vec(&lambda_req).map_err(|_| AnalyticsError::UnknownError)?;
-                invoke_lambda(
+                generate_report(
+                    &state,
+                    ReportType::Dispute,
                     &state.conf.report_download_config.dispute_function,
-                    &state.conf.report_download_config.region,
-                    &json_bytes,
+                    lambda_req,
                 )
                 .await
-                .map(ApplicationResponse::Json)
             },
             &auth::JWTAuth {
                 permission: Permission::OrganizationReportRead,
@@ -1744,15 +2089,13 @@ pub mod routes {
                     email: user_email,
                 };
 
-                let json_bytes =
-                    serde_json::to_vec(&lambda_req).map_err(|_| AnalyticsError::UnknownError)?;
-                invoke_lambda(
+                generate_report(
+                    &state,
+                    ReportType::Dispute,
                     &state.conf.report_download_config.dispute_function,
-                    &state.conf.report_download_config.region,
-                    &json_bytes,
+                    lambda_req,
                 )
                 .await
-                .map(ApplicationResponse::Json)
             },
             &auth::JWTAuth {
                 permission: Permission::ProfileReportRead,
@@ -1795,15 +2138,13 @@ pub mod routes {
                     email: user_email,
                 };
 
-                let json_bytes =
-                    serde_json::to_vec(&lambda_req).map_err(|_| AnalyticsError::UnknownError)?;
-                invoke_lambda(
+                generate_report(
+                    &state,
+                    ReportType::Payment,
                     &state.conf.report_download_config.payment_function,
-                    &state.conf.report_download_config.region,
-                    &json_bytes,
+                    lambda_req,
                 )
                 .await
-                .map(ApplicationResponse::Json)
             },
             &auth::JWTAuth {
                 permission: Permission::MerchantReportRead,
@@ -1844,15 +2185,13 @@ pub mod routes {
                     email: user_email,
                 };
 
-                let json_bytes =
-                    serde_json::to_vec(&lambda_req).map_err(|_| AnalyticsError::UnknownError)?;
-                invoke_lambda(
+                generate_report(
+                    &state,
+                    ReportType::Payment,
                     &state.conf.report_download_config.payment_function,
-                    &state.conf.report_download_config.region,
-                    &json_bytes,
+                    lambda_req,
                 )
                 .await
-                .map(ApplicationResponse::Json)
             },
             &auth::JWTAuth {
                 permission: Permission::OrganizationReportRead,
@@ -1899,15 +2238,13 @@ pub mod routes {
                     email: user_email,
                 };
 
-                let json_bytes =
-                    serde_json::to_vec(&lambda_req).map_err(|_| AnalyticsError::UnknownError)?;
-                invoke_lambda(
+                generate_report(
+                    &state,
+                    ReportType::Payment,
                     &state.conf.report_download_config.payment_function,
-                    &state.conf.report_download_config.region,
-                    &json_bytes,
+                    lambda_req,
                 )
                 .await
-                .map(ApplicationResponse::Json)
             },
             &auth::JWTAuth {
                 permission: Permission::ProfileReportRead,
@@ -2072,6 +2409,179 @@ pub mod routes {
         .await
     }
 
+    #[cfg(feature = "v1")]
+    pub async fn retrieve_merchant_report(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        path: web::Path<String>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::RetrieveReport;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            path.into_inner(),
+            |state, auth: AuthenticationData, report_id, _| async move {
+                let org_id = auth.merchant_account.get_org_id();
+                let merchant_id = auth.merchant_account.get_id();
+                let auth = AuthInfo::MerchantLevel {
+                    org_id: org_id.clone(),
+                    merchant_ids: vec![merchant_id.clone()],
+                };
+                reports::retrieve_report(&state, &auth, report_id)
+                    .await
+                    .map(ApplicationResponse::Json)
+            },
+            &auth::JWTAuth {
+                permission: Permission::MerchantReportRead,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    #[cfg(feature = "v1")]
+    pub async fn retrieve_org_report(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        path: web::Path<String>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::RetrieveReport;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            path.into_inner(),
+            |state, auth: AuthenticationData, report_id, _| async move {
+                let org_id = auth.merchant_account.get_org_id();
+                let auth = AuthInfo::OrgLevel {
+                    org_id: org_id.clone(),
+                };
+                reports::retrieve_report(&state, &auth, report_id)
+                    .await
+                    .map(ApplicationResponse::Json)
+            },
+            &auth::JWTAuth {
+                permission: Permission::OrganizationReportRead,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    #[cfg(feature = "v1")]
+    pub async fn retrieve_profile_report(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        path: web::Path<String>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::RetrieveReport;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            path.into_inner(),
+            |state, auth: AuthenticationData, report_id, _| async move {
+                let org_id = auth.merchant_account.get_org_id();
+                let merchant_id = auth.merchant_account.get_id();
+                let profile_id = auth
+                    .profile_id
+                    .ok_or(report!(UserErrors::JwtProfileIdMissing))
+                    .change_context(AnalyticsError::AccessForbiddenError)?;
+                let auth = AuthInfo::ProfileLevel {
+                    org_id: org_id.clone(),
+                    merchant_id: merchant_id.clone(),
+                    profile_ids: vec![profile_id],
+                };
+                reports::retrieve_report(&state, &auth, report_id)
+                    .await
+                    .map(ApplicationResponse::Json)
+            },
+            &auth::JWTAuth {
+                permission: Permission::ProfileReportRead,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    /// Downloads a generated report. The token in the path is issued along with the report status
+    /// and expires after the configured duration, so the request itself is not authenticated.
+    #[cfg(feature = "v1")]
+    pub async fn download_report(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        path: web::Path<String>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::DownloadReport;
+        let report_download = OnceLock::new();
+        let response = Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            path.into_inner(),
+            |state, _: (), token, _| {
+                let report_download = &report_download;
+                async move {
+                    let download = reports::get_report_download(&state, token).await?;
+                    report_download
+                        .set(download)
+                        .map_err(|_| AnalyticsError::UnknownError)?;
+                    AnalyticsResult::Ok(ApplicationResponse::<()>::StatusOk)
+                }
+            },
+            &auth::NoAuth,
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await;
+
+        // The report is streamed once the token is resolved, as it may be too large to buffer
+        match report_download.into_inner() {
+            Some(download) => HttpResponse::Ok()
+                .content_type(download.get_content_type())
+                .streaming(download.into_stream()),
+            None => response,
+        }
+    }
+
+    /// Generates the report in-process when native report generation is configured, and invokes
+    /// the report lambda function otherwise.
+    #[cfg(feature = "v1")]
+    async fn generate_report(
+        state: &SessionState,
+        report_type: ReportType,
+        lambda_function: &str,
+        report_req: GenerateReportRequest,
+    ) -> AnalyticsResult<ApplicationResponse<Option<GenerateReportResponse>>> {
+        match state.conf.report_download_config.generator {
+            ReportGenerator::Lambda => {
+                if report_req.request.format != ReportFormat::Csv {
+                    return Err(report!(AnalyticsError::NotImplemented(
+                        "Generating reports in formats other than CSV using the report lambda"
+                    )));
+                }
+                let json_bytes =
+                    serde_json::to_vec(&report_req).map_err(|_| AnalyticsError::UnknownError)?;
+                invoke_lambda(
+                    lambda_function,
+                    &state.conf.report_download_config.region,
+                    &json_bytes,
+                )
+                .await
+                .map(|()| ApplicationResponse::Json(None))
+            }
+            ReportGenerator::Native => reports::create_report(
+                state,
+                report_type,
+                report_req.request.format,
+                report_req.auth,
+                report_req.request.time_range,
+            )
+            .await
+            .map(|response| ApplicationResponse::Json(Some(response))),
+        }
+    }
+
     /// # Panics
     ///
     /// Panics if `json_payload` array does not contain one `GetApiEventMetricRequest` element.
@@ -2800,4 +3310,114 @@ pub mod routes {
         ))
         .await
     }
+
+    /// Subscribes the users having a role in the merchant to scheduled analytics digest emails.
+    #[cfg(feature = "v1")]
+    pub async fn create_digest_subscription(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<CreateDigestSubscriptionRequest>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::CreateDigestSubscription;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            json_payload.into_inner(),
+            |state, auth: UserFromToken, req, _| async move {
+                analytics_digest::create_subscription(&state, auth, req)
+                    .await
+                    .map(ApplicationResponse::Json)
+            },
+            &auth::JWTAuth {
+                permission: Permission::MerchantAccountWrite,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    #[cfg(feature = "v1")]
+    pub async fn list_digest_subscriptions(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::ListDigestSubscriptions;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            (),
+            |state, auth: UserFromToken, _, _| async move {
+                analytics_digest::list_subscriptions(&state, &auth.merchant_id)
+                    .await
+                    .map(ApplicationResponse::Json)
+            },
+            &auth::JWTAuth {
+                permission: Permission::MerchantAnalyticsRead,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    #[cfg(feature = "v1")]
+    pub async fn update_digest_subscription(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        path: web::Path<String>,
+        json_payload: web::Json<UpdateDigestSubscriptionRequest>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::UpdateDigestSubscription;
+        let subscription_id = path.into_inner();
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            json_payload.into_inner(),
+            |state, auth: UserFromToken, req, _| {
+                let subscription_id = subscription_id.clone();
+                async move {
+                    analytics_digest::update_subscription(
+                        &state,
+                        &auth.merchant_id,
+                        subscription_id,
+                        req,
+                    )
+                    .await
+                    .map(ApplicationResponse::Json)
+                }
+            },
+            &auth::JWTAuth {
+                permission: Permission::MerchantAccountWrite,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    #[cfg(feature = "v1")]
+    pub async fn delete_digest_subscription(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        path: web::Path<String>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::DeleteDigestSubscription;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            path.into_inner(),
+            |state, auth: UserFromToken, subscription_id, _| async move {
+                analytics_digest::delete_subscription(&state, &auth.merchant_id, subscription_id)
+                    .await
+                    .map(ApplicationResponse::Json)
+            },
+            &auth::JWTAuth {
+                permission: Permission::MerchantAccountWrite,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
 }
@@ -324,6 +324,127 @@ impl ProcessTrackerWorkflows<routes::SessionState> for WorkflowRunner {
                 storage::ProcessTrackerRunner::PassiveRecoveryWorkflow => {
                     Ok(Box::new(workflows::revenue_recovery::ExecutePcrWorkflow))
                 }
+                storage::ProcessTrackerRunner::BlocklistEntryExpiryWorkflow => Ok(Box::new(
+                    workflows::blocklist_entry_expiry::BlocklistEntryExpiryWorkflow,
+                )),
+                storage::ProcessTrackerRunner::CardAccountUpdaterWorkflow => {
+                    #[cfg(feature = "v1")]
+                    {
+                        Ok(Box::new(
+                            workflows::card_account_updater::CardAccountUpdaterWorkflow,
+                        ))
+                    }
+                    #[cfg(not(feature = "v1"))]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable(
+                            "Cannot run card account updater workflow when v1 feature is disabled",
+                        )
+                    }
+                }
+                storage::ProcessTrackerRunner::PayoutBatchWorkflow => {
+                    #[cfg(all(feature = "payouts", feature = "v1"))]
+                    {
+                        Ok(Box::new(workflows::payout_batch::PayoutBatchWorkflow))
+                    }
+                    #[cfg(not(all(feature = "payouts", feature = "v1")))]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable(
+                                "Cannot run payout batch workflow when payouts or v1 feature is disabled",
+                            )
+                    }
+                }
+                storage::ProcessTrackerRunner::ScheduledPayoutWorkflow => {
+                    #[cfg(all(feature = "payouts", feature = "v1"))]
+                    {
+                        Ok(Box::new(
+                            workflows::scheduled_payout::ScheduledPayoutWorkflow,
+                        ))
+                    }
+                    #[cfg(not(all(feature = "payouts", feature = "v1")))]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable(
+                                "Cannot run scheduled payout workflow when payouts or v1 feature is disabled",
+                            )
+                    }
+                }
+                storage::ProcessTrackerRunner::SubscriptionBillingWorkflow => {
+                    #[cfg(feature = "v1")]
+                    {
+                        Ok(Box::new(
+                            workflows::subscription_billing::SubscriptionBillingWorkflow,
+                        ))
+                    }
+                    #[cfg(not(feature = "v1"))]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable(
+                            "Cannot run subscription billing workflow when v1 feature is disabled",
+                        )
+                    }
+                }
+                storage::ProcessTrackerRunner::PaymentMethodMigrationWorkflow => {
+                    #[cfg(feature = "v1")]
+                    {
+                        Ok(Box::new(
+                            workflows::payment_method_migration::PaymentMethodMigrationWorkflow,
+                        ))
+                    }
+                    #[cfg(not(feature = "v1"))]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable(
+                            "Cannot run payment method migration workflow when v1 feature is disabled",
+                        )
+                    }
+                }
+                storage::ProcessTrackerRunner::WebhookEventReplayWorkflow => {
+                    #[cfg(feature = "v1")]
+                    {
+                        Ok(Box::new(
+                            workflows::webhook_event_replay::WebhookEventReplayWorkflow,
+                        ))
+                    }
+                    #[cfg(not(feature = "v1"))]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable(
+                            "Cannot run webhook event replay workflow when v1 feature is disabled",
+                        )
+                    }
+                }
+                storage::ProcessTrackerRunner::ReportGenerationWorkflow => {
+                    #[cfg(feature = "olap")]
+                    {
+                        Ok(Box::new(
+                            workflows::report_generation::ReportGenerationWorkflow,
+                        ))
+                    }
+                    #[cfg(not(feature = "olap"))]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable(
+                            "Cannot run report generation workflow when olap feature is disabled",
+                        )
+                    }
+                }
+                storage::ProcessTrackerRunner::AnalyticsDigestWorkflow => {
+                    #[cfg(feature = "email")]
+                    {
+                        Ok(Box::new(
+                            workflows::analytics_digest::AnalyticsDigestWorkflow,
+                        ))
+                    }
+                    #[cfg(not(feature = "email"))]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable(
+                            "Cannot run analytics digest workflow when email feature is disabled",
+                        )
+                    }
+                }
             }
         };
 
@@ -0,0 +1,71 @@
+use error_stack::ResultExt;
+use router::{
+    configs::settings::{CmdLineConf, Settings},
+    core::{
+        errors::{CustomResult, WebhooksFlowError},
+        webhooks::delivery_queue,
+    },
+    logger, routes, services,
+};
+use router_env::tracing::Instrument;
+use tokio::sync::{mpsc, oneshot};
+
+const WEBHOOK_DELIVERY_SERVICE: &str = "webhook_delivery";
+
+#[tokio::main]
+async fn main() -> CustomResult<(), WebhooksFlowError> {
+    let cmd_line = <CmdLineConf as clap::Parser>::parse();
+
+    #[allow(clippy::expect_used)]
+    let conf = Settings::with_config_path(cmd_line.config_path)
+        .expect("Unable to construct application configuration");
+    #[allow(clippy::expect_used)]
+    conf.validate()
+        .expect("Failed to validate webhook delivery worker configuration");
+
+    assert!(
+        conf.webhooks.delivery_queue.enabled,
+        "Webhook delivery queue is not enabled in the configuration"
+    );
+
+    let api_client = Box::new(
+        services::ProxyClient::new(&conf.proxy)
+            .change_context(WebhooksFlowError::WebhookDeliveryQueueOperationFailed)
+            .attach_printable("Failed to construct the api client")?,
+    );
+    // channel for listening to redis disconnect events
+    let (redis_shutdown_signal_tx, redis_shutdown_signal_rx) = oneshot::channel();
+    let state = Box::pin(routes::AppState::new(
+        conf,
+        redis_shutdown_signal_tx,
+        api_client,
+    ))
+    .await;
+    // channel to shutdown the worker gracefully
+    let (tx, rx) = mpsc::channel(1);
+    let _task_handle = tokio::spawn(
+        router::receiver_for_error(redis_shutdown_signal_rx, tx.clone()).in_current_span(),
+    );
+
+    #[allow(clippy::print_stdout)] // The logger has not yet been initialized
+    #[cfg(feature = "vergen")]
+    {
+        println!(
+            "Starting webhook delivery worker (Version: {})",
+            router_env::git_tag!()
+        );
+    }
+
+    let _guard = router_env::setup(
+        &state.conf.log,
+        WEBHOOK_DELIVERY_SERVICE,
+        [router_env::service_name!()],
+    );
+
+    logger::debug!(startup_config=?state.conf);
+
+    delivery_queue::start_webhook_delivery_worker(&state, (tx, rx)).await?;
+
+    logger::error!("Webhook delivery worker shut down");
+    Ok(())
+}
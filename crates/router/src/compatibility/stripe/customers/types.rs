@@ -198,7 +198,7 @@ pub struct CustomerPaymentMethodListResponse {
     pub data: Vec<PaymentMethodData>,
 }
 
-#[derive(Default, Serialize, PartialEq, Eq)]
+#[derive(Debug, Default, Serialize, PartialEq, Eq)]
 pub struct PaymentMethodData {
     pub id: Option<String>,
     pub object: &'static str,
@@ -206,7 +206,7 @@ pub struct PaymentMethodData {
     pub created: Option<time::PrimitiveDateTime>,
 }
 
-#[derive(Default, Serialize, PartialEq, Eq)]
+#[derive(Debug, Default, Serialize, PartialEq, Eq)]
 pub struct CardDetails {
     pub country: Option<String>,
     pub last4: Option<String>,
@@ -249,6 +249,22 @@ impl From<api_types::CustomerPaymentMethod> for PaymentMethodData {
     }
 }
 
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+impl From<api_types::PaymentMethodResponse> for PaymentMethodData {
+    fn from(item: api_types::PaymentMethodResponse) -> Self {
+        let card = item.card.map(From::from);
+        Self {
+            id: Some(item.payment_method_id),
+            object: "payment_method",
+            card,
+            created: item.created,
+        }
+    }
+}
+
 #[cfg(all(
     any(feature = "v1", feature = "v2"),
     not(feature = "payment_methods_v2")
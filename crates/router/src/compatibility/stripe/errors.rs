@@ -261,6 +261,8 @@ pub enum StripeErrorCode {
     PaymentLinkNotFound,
     #[error(error_type = StripeErrorType::HyperswitchError, code = "", message = "Resource Busy. Please try again later")]
     LockTimeout,
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "rate_limit", message = "Too many requests hit the API too quickly")]
+    RateLimitExceeded { retry_after: u64 },
     #[error(error_type = StripeErrorType::InvalidRequestError, code = "", message = "Merchant connector account is configured with invalid {config}")]
     InvalidConnectorConfiguration { config: String },
     #[error(error_type = StripeErrorType::HyperswitchError, code = "HE_01", message = "Failed to convert currency to minor unit")]
@@ -628,6 +630,18 @@ impl From<errors::ApiErrorResponse> for StripeErrorCode {
                 object: "poll".to_owned(),
                 id,
             },
+            errors::ApiErrorResponse::WebhookEndpointNotFound { id } => Self::ResourceMissing {
+                object: "webhook_endpoint".to_owned(),
+                id,
+            },
+            errors::ApiErrorResponse::SubscriptionPlanNotFound { id } => Self::ResourceMissing {
+                object: "plan".to_owned(),
+                id,
+            },
+            errors::ApiErrorResponse::SubscriptionNotFound { id } => Self::ResourceMissing {
+                object: "subscription".to_owned(),
+                id,
+            },
             errors::ApiErrorResponse::DisputeStatusValidationFailed { reason: _ } => {
                 Self::InternalServerError
             }
@@ -688,6 +702,17 @@ impl From<errors::ApiErrorResponse> for StripeErrorCode {
             }
             errors::ApiErrorResponse::PlatformAccountAuthNotSupported => Self::PlatformBadRequest,
             errors::ApiErrorResponse::InvalidPlatformOperation => Self::PlatformUnauthorizedRequest,
+            errors::ApiErrorResponse::IdempotencyKeyInUse => Self::GenericDuplicateError {
+                message: "A request with the same idempotency key is currently being processed"
+                    .to_string(),
+            },
+            errors::ApiErrorResponse::IdempotencyKeyMismatch => Self::GenericDuplicateError {
+                message: "Idempotency key has already been used with a different request body"
+                    .to_string(),
+            },
+            errors::ApiErrorResponse::RateLimitExceeded { retry_after } => {
+                Self::RateLimitExceeded { retry_after }
+            }
         }
     }
 }
@@ -782,15 +807,19 @@ impl actix_web::ResponseError for StripeErrorCode {
                 StatusCode::from_u16(*code).unwrap_or(StatusCode::OK)
             }
             Self::LockTimeout => StatusCode::LOCKED,
+            Self::RateLimitExceeded { .. } => StatusCode::TOO_MANY_REQUESTS,
         }
     }
 
     fn error_response(&self) -> actix_web::HttpResponse {
         use actix_web::http::header;
 
-        actix_web::HttpResponseBuilder::new(self.status_code())
-            .insert_header((header::CONTENT_TYPE, mime::APPLICATION_JSON))
-            .body(self.to_string())
+        let mut response_builder = actix_web::HttpResponseBuilder::new(self.status_code());
+        response_builder.insert_header((header::CONTENT_TYPE, mime::APPLICATION_JSON));
+        if let Self::RateLimitExceeded { retry_after } = self {
+            response_builder.insert_header((header::RETRY_AFTER, retry_after.to_string()));
+        }
+        response_builder.body(self.to_string())
     }
 }
 
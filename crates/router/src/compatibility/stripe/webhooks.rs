@@ -5,13 +5,14 @@ use api_models::{
     webhooks::{self as api},
 };
 #[cfg(feature = "payouts")]
-use common_utils::pii::{self, Email};
-use common_utils::{crypto::SignMessage, date_time, ext_traits::Encode};
+use common_utils::pii::Email;
+use common_utils::{crypto::SignMessage, date_time, ext_traits::Encode, pii};
 use error_stack::ResultExt;
 use router_env::logger;
 use serde::Serialize;
 
 use super::{
+    customers::types::PaymentMethodData as StripePaymentMethodResponse,
     payment_intents::types::StripePaymentIntentResponse, refunds::types::StripeRefundResponse,
 };
 use crate::{
@@ -87,6 +88,8 @@ pub enum StripeWebhookObject {
     Mandate(StripeMandateResponse),
     #[cfg(feature = "payouts")]
     Payout(StripePayoutResponse),
+    PaymentMethod(StripePaymentMethodResponse),
+    Subscription(StripeSubscriptionResponse),
 }
 
 #[derive(Serialize, Debug)]
@@ -156,7 +159,8 @@ impl From<common_enums::PayoutStatus> for StripePayoutStatus {
             | common_enums::PayoutStatus::RequiresFulfillment
             | common_enums::PayoutStatus::RequiresPayoutMethodData
             | common_enums::PayoutStatus::RequiresVendorAccountCreation
-            | common_enums::PayoutStatus::RequiresConfirmation => Self::PayoutProcessing,
+            | common_enums::PayoutStatus::RequiresConfirmation
+            | common_enums::PayoutStatus::Scheduled => Self::PayoutProcessing,
         }
     }
 }
@@ -193,6 +197,61 @@ impl From<payout_models::PayoutCreateResponse> for StripePayoutResponse {
     }
 }
 
+#[derive(Serialize, Debug)]
+pub struct StripeSubscriptionResponse {
+    pub id: String,
+    pub customer: common_utils::id_type::CustomerId,
+    pub plan: String,
+    pub status: StripeSubscriptionStatus,
+    pub current_period_start: i64,
+    pub current_period_end: i64,
+    pub trial_end: Option<i64>,
+    pub cancel_at_period_end: bool,
+    pub canceled_at: Option<i64>,
+    pub latest_invoice: Option<String>,
+    pub metadata: Option<pii::SecretSerdeValue>,
+    pub created: i64,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum StripeSubscriptionStatus {
+    Trialing,
+    Active,
+    PastDue,
+    Canceled,
+}
+
+impl From<common_enums::SubscriptionStatus> for StripeSubscriptionStatus {
+    fn from(status: common_enums::SubscriptionStatus) -> Self {
+        match status {
+            common_enums::SubscriptionStatus::Trialing => Self::Trialing,
+            common_enums::SubscriptionStatus::Active => Self::Active,
+            common_enums::SubscriptionStatus::PastDue => Self::PastDue,
+            common_enums::SubscriptionStatus::Cancelled => Self::Canceled,
+        }
+    }
+}
+
+impl From<api_models::subscriptions::SubscriptionResponse> for StripeSubscriptionResponse {
+    fn from(res: api_models::subscriptions::SubscriptionResponse) -> Self {
+        Self {
+            id: res.subscription_id,
+            customer: res.customer_id,
+            plan: res.plan_id,
+            status: StripeSubscriptionStatus::from(res.status),
+            current_period_start: res.current_period_start.assume_utc().unix_timestamp(),
+            current_period_end: res.current_period_end.assume_utc().unix_timestamp(),
+            trial_end: res.trial_end.map(|t| t.assume_utc().unix_timestamp()),
+            cancel_at_period_end: res.cancel_at_period_end,
+            canceled_at: res.cancelled_at.map(|t| t.assume_utc().unix_timestamp()),
+            latest_invoice: res.latest_invoice.map(|invoice| invoice.invoice_id),
+            metadata: res.metadata,
+            created: res.created_at.assume_utc().unix_timestamp(),
+        }
+    }
+}
+
 #[derive(Serialize, Debug)]
 #[serde(rename_all = "snake_case")]
 pub enum StripeMandateStatus {
@@ -282,6 +341,7 @@ fn get_stripe_event_type(event_type: api_models::enums::EventType) -> &'static s
         api_models::enums::EventType::DisputeLost => "dispute.lost",
         api_models::enums::EventType::MandateActive => "mandate.active",
         api_models::enums::EventType::MandateRevoked => "mandate.revoked",
+        api_models::enums::EventType::PaymentMethodClosed => "payment_method.closed",
 
         // as per this doc https://stripe.com/docs/api/events/types#event_types-payment_intent.amount_capturable_updated
         api_models::enums::EventType::PaymentAuthorized => {
@@ -296,6 +356,15 @@ fn get_stripe_event_type(event_type: api_models::enums::EventType) -> &'static s
         api_models::enums::EventType::PayoutProcessing => "payout.created",
         api_models::enums::EventType::PayoutExpired => "payout.failed",
         api_models::enums::EventType::PayoutReversed => "payout.reconciliation_completed",
+        // as per this doc https://docs.stripe.com/api/events/types#event_types-payment_method.automatically_updated
+        api_models::enums::EventType::PaymentMethodUpdated => {
+            "payment_method.automatically_updated"
+        }
+        api_models::enums::EventType::SubscriptionCreated => "customer.subscription.created",
+        api_models::enums::EventType::SubscriptionRenewed => "invoice.paid",
+        api_models::enums::EventType::SubscriptionUpdated => "customer.subscription.updated",
+        api_models::enums::EventType::SubscriptionPaymentFailed => "invoice.payment_failed",
+        api_models::enums::EventType::SubscriptionCancelled => "customer.subscription.deleted",
     }
 }
 
@@ -338,6 +407,14 @@ impl From<api::OutgoingWebhookContent> for StripeWebhookObject {
             }
             #[cfg(feature = "payouts")]
             api::OutgoingWebhookContent::PayoutDetails(payout) => Self::Payout((*payout).into()),
+            #[cfg(feature = "v1")]
+            api::OutgoingWebhookContent::PaymentMethodDetails(payment_method) => {
+                Self::PaymentMethod((*payment_method).into())
+            }
+            #[cfg(feature = "v1")]
+            api::OutgoingWebhookContent::SubscriptionDetails(subscription) => {
+                Self::Subscription((*subscription).into())
+            }
         }
     }
 }
@@ -10,14 +10,16 @@ use crate::{
     events::api_logs::ApiEventMetric,
     routes::{
         app::{AppStateInfo, ReqState},
-        AppState, SessionState,
+        lock_utils, AppState, SessionState,
     },
-    services::{self, api, authentication as auth, logger},
+    services::{self, api, authentication as auth, authorization, logger},
 };
 
 #[instrument(skip(request, payload, state, func, api_authentication))]
 pub async fn compatibility_api_wrap<'a, 'b, U, T, Q, F, Fut, S, E, E2>(
-    flow: impl router_env::types::FlowMetric,
+    flow: impl router_env::types::FlowMetric
+        + Into<lock_utils::ApiIdentifier>
+        + authorization::api_keys::ApiKeyPermission,
     state: Arc<AppState>,
     request: &'a HttpRequest,
     payload: T,
@@ -110,6 +112,9 @@ where
         Ok(api::ApplicationResponse::FileData((file_data, content_type))) => {
             api::http_response_file_data(file_data, content_type)
         }
+        Ok(api::ApplicationResponse::StoredResponse(stored_response)) => {
+            api::http_response_stored(*stored_response)
+        }
         Ok(api::ApplicationResponse::JsonForRedirection(response)) => {
             match serde_json::to_string(&response) {
                 Ok(res) => api::http_redirect_response(res, response),
@@ -101,6 +101,80 @@ impl Default for super::settings::EphemeralConfig {
     }
 }
 
+impl Default for super::settings::CardAccountUpdaterSettings {
+    fn default() -> Self {
+        Self {
+            expiry_window_in_days: 30,
+            // 1 day
+            schedule_interval_in_seconds: 86400,
+            batch_size: 100,
+            connector: None,
+        }
+    }
+}
+
+impl Default for super::settings::ApiRateLimitSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_requests: 1000,
+            window_size: 60,
+        }
+    }
+}
+
+impl Default for super::settings::WebhookDeliveryQueueSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            stream_name: "WEBHOOK_DELIVERY_STREAM".into(),
+            consumer_group: "WEBHOOK_DELIVERY_GROUP".into(),
+            consumer_name: "webhook_delivery_consumer".into(),
+            max_in_flight_deliveries: 100,
+            loop_interval: 500,
+            max_concurrent_deliveries_per_endpoint: 5,
+            rate_limit: super::settings::WebhookEndpointRateLimitSettings::default(),
+            circuit_breaker: super::settings::WebhookEndpointCircuitBreakerSettings::default(),
+        }
+    }
+}
+
+impl Default for super::settings::WebhookEndpointRateLimitSettings {
+    fn default() -> Self {
+        Self {
+            bucket_size: 20,
+            refill_rate: 10,
+        }
+    }
+}
+
+impl Default for super::settings::WebhookEndpointCircuitBreakerSettings {
+    fn default() -> Self {
+        Self {
+            failure_threshold: 10,
+            failure_window: 60,
+            open_duration: 300,
+        }
+    }
+}
+
+impl Default for super::settings::ForexApi {
+    fn default() -> Self {
+        Self {
+            api_key: masking::Secret::default(),
+            fallback_api_key: masking::Secret::default(),
+            data_expiration_delay_in_seconds: 0,
+            redis_lock_timeout_in_seconds: 0,
+            redis_ttl_in_seconds: 0,
+            providers: vec![
+                super::settings::ForexProviderType::OpenExchangeRates,
+                super::settings::ForexProviderType::ApiLayer,
+            ],
+            rates_file_path: None,
+        }
+    }
+}
+
 #[cfg(feature = "kv_store")]
 impl Default for super::settings::DrainerSettings {
     fn default() -> Self {
@@ -123,6 +123,8 @@ pub struct Settings<S: SecretState> {
     pub multiple_api_version_supported_connectors: MultipleApiVersionSupportedConnectors,
     pub applepay_merchant_configs: SecretStateContainer<ApplepayMerchantConfigs, S>,
     pub lock_settings: LockSettings,
+    pub card_account_updater: CardAccountUpdaterSettings,
+    pub api_rate_limit: ApiRateLimitSettings,
     pub temp_locker_enable_config: TempLockerEnableConfig,
     pub generic_link: GenericLink,
     pub payment_link: PaymentLink,
@@ -459,7 +461,7 @@ impl Default for PaymentLink {
     }
 }
 
-#[derive(Debug, Deserialize, Clone, Default)]
+#[derive(Debug, Deserialize, Clone)]
 #[serde(default)]
 pub struct ForexApi {
     pub api_key: Secret<String>,
@@ -467,6 +469,25 @@ pub struct ForexApi {
     pub data_expiration_delay_in_seconds: u32,
     pub redis_lock_timeout_in_seconds: u32,
     pub redis_ttl_in_seconds: u32,
+    /// The providers from which forex rates are fetched, in order of preference. A provider is
+    /// used only if all the providers before it failed to supply rates.
+    pub providers: Vec<ForexProviderType>,
+    /// Path to the TOML or JSON file containing the rates supplied by the `rates_file` provider
+    pub rates_file_path: Option<PathBuf>,
+}
+
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ForexProviderType {
+    /// The Open Exchange Rates API, authenticated using `api_key`
+    OpenExchangeRates,
+    /// The apilayer currency API, authenticated using `fallback_api_key`
+    ApiLayer,
+    /// Rates read from the file at `rates_file_path`
+    RatesFile,
+    /// Rates set by an admin in the `configs` table
+    Manual,
 }
 
 #[derive(Debug, Deserialize, Clone, Default)]
@@ -821,6 +842,42 @@ pub struct WebhooksSettings {
     pub outgoing_enabled: bool,
     pub ignore_error: WebhookIgnoreErrorSettings,
     pub redis_lock_expiry_seconds: u32,
+    pub delivery_queue: WebhookDeliveryQueueSettings,
+}
+
+/// Settings for delivering outgoing webhooks through a Redis stream, consumed by the webhook
+/// delivery worker, instead of delivering them from the flow which raised the event.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct WebhookDeliveryQueueSettings {
+    pub enabled: bool,
+    pub stream_name: String,
+    pub consumer_group: String,
+    /// Must be unique for every worker instance, entries pending on this consumer are processed
+    /// again when the worker starts up
+    pub consumer_name: String,
+    pub max_in_flight_deliveries: usize,
+    pub loop_interval: u64, // in milliseconds
+    pub max_concurrent_deliveries_per_endpoint: u32,
+    pub rate_limit: WebhookEndpointRateLimitSettings,
+    pub circuit_breaker: WebhookEndpointCircuitBreakerSettings,
+}
+
+/// Token bucket rate limit applied to each webhook endpoint
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct WebhookEndpointRateLimitSettings {
+    pub bucket_size: u32,
+    pub refill_rate: u32, // tokens per second
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct WebhookEndpointCircuitBreakerSettings {
+    /// Number of failed deliveries within `failure_window` after which the circuit is opened
+    pub failure_threshold: usize,
+    pub failure_window: i64, // in seconds
+    pub open_duration: i64,  // in seconds
 }
 
 #[derive(Debug, Clone, Deserialize, Default)]
@@ -959,6 +1016,7 @@ impl Settings<SecuredSecret> {
                     .with_list_parse_key("log.telemetry.route_to_trace")
                     .with_list_parse_key("redis.cluster_urls")
                     .with_list_parse_key("events.kafka.brokers")
+                    .with_list_parse_key("forex_api.providers")
                     .with_list_parse_key("connectors.supported.wallets")
                     .with_list_parse_key("connector_request_reference_id_config.merchant_ids_send_payment_id_as_connector_request_id"),
 
@@ -1014,6 +1072,7 @@ impl Settings<SecuredSecret> {
         #[cfg(feature = "kv_store")]
         self.drainer.validate()?;
         self.api_keys.get_inner().validate()?;
+        self.forex_api.get_inner().validate()?;
 
         self.file_storage
             .validate()
@@ -1024,11 +1083,17 @@ impl Settings<SecuredSecret> {
             .map_err(|err| ApplicationError::InvalidConfigurationValueError(err.to_string()))?;
 
         self.lock_settings.validate()?;
+        self.card_account_updater.validate()?;
+        self.api_rate_limit.validate()?;
         self.events.validate()?;
+        self.webhooks.delivery_queue.validate()?;
 
         #[cfg(feature = "olap")]
         self.opensearch.validate()?;
 
+        #[cfg(feature = "olap")]
+        self.report_download_config.validate()?;
+
         self.encryption_management
             .validate()
             .map_err(|err| ApplicationError::InvalidConfigurationValueError(err.into()))?;
@@ -1091,6 +1156,44 @@ pub struct Payouts {
     pub required_fields: PayoutRequiredFields,
 }
 
+/// Settings for the workflow which refreshes the details of stored cards which are about to
+/// expire, through an account updater service
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct CardAccountUpdaterSettings {
+    /// Cards expiring within these many days from the time the workflow runs are submitted to the
+    /// account updater
+    pub expiry_window_in_days: u16,
+    /// Interval between two runs of the workflow for a merchant
+    pub schedule_interval_in_seconds: u32,
+    /// Number of payment methods read from the database and submitted to the account updater at
+    /// once
+    pub batch_size: u16,
+    /// The account updater service to use, the card account updater cannot be enabled for a
+    /// merchant when this is not configured
+    pub connector: Option<AccountUpdaterConnectorType>,
+}
+
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AccountUpdaterConnectorType {
+    /// Returns canned results based on the card number, meant for testing
+    #[cfg(feature = "dummy_connector")]
+    Mock,
+}
+
+/// Sliding window rate limit applied to the requests made with each API key of a merchant, to
+/// each group of APIs. Can be overridden for a merchant through the `configs` table.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct ApiRateLimitSettings {
+    pub enabled: bool,
+    /// Maximum number of requests allowed within a window
+    pub max_requests: u64,
+    pub window_size: u64, // in seconds
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct LockSettings {
     pub redis_lock_expiry_seconds: u32,
@@ -193,6 +193,132 @@ impl super::settings::WebhooksSettings {
     }
 }
 
+impl super::settings::CardAccountUpdaterSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.schedule_interval_in_seconds == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "card account updater schedule interval must be greater than 0".into(),
+            ))
+        })?;
+
+        when(self.batch_size == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "card account updater batch size must be greater than 0".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::ApiRateLimitSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        if !self.enabled {
+            return Ok(());
+        }
+
+        when(self.max_requests == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "api rate limit max requests must be greater than 0".into(),
+            ))
+        })?;
+
+        when(self.window_size == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "api rate limit window size must be greater than 0".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::WebhookDeliveryQueueSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        if !self.enabled {
+            return Ok(());
+        }
+
+        when(self.stream_name.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "webhook delivery queue stream name must not be empty".into(),
+            ))
+        })?;
+
+        when(self.consumer_group.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "webhook delivery queue consumer group must not be empty".into(),
+            ))
+        })?;
+
+        when(self.consumer_name.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "webhook delivery queue consumer name must not be empty".into(),
+            ))
+        })?;
+
+        when(self.max_in_flight_deliveries == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "max_in_flight_deliveries must be greater than 0".into(),
+            ))
+        })?;
+
+        when(self.max_concurrent_deliveries_per_endpoint == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "max_concurrent_deliveries_per_endpoint must be greater than 0".into(),
+            ))
+        })?;
+
+        when(
+            self.rate_limit.bucket_size == 0 || self.rate_limit.refill_rate == 0,
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "webhook endpoint rate limit bucket size and refill rate must be greater than 0"
+                        .into(),
+                ))
+            },
+        )?;
+
+        when(
+            self.circuit_breaker.failure_threshold == 0
+                || self.circuit_breaker.failure_window <= 0
+                || self.circuit_breaker.open_duration <= 0,
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "webhook endpoint circuit breaker thresholds and durations must be greater than 0"
+                        .into(),
+                ))
+            },
+        )
+    }
+}
+
+impl super::settings::ForexApi {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.providers.is_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "at least one forex provider must be configured".into(),
+            ))
+        })?;
+
+        when(
+            self.providers
+                .contains(&super::settings::ForexProviderType::RatesFile)
+                && self.rates_file_path.is_none(),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "forex rates_file_path must be provided when the rates_file provider is used"
+                        .into(),
+                ))
+            },
+        )
+    }
+}
+
 impl super::settings::GenericLinkEnvConfig {
     pub fn validate(&self) -> Result<(), ApplicationError> {
         use common_utils::fp_utils::when;
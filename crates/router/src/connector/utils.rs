@@ -2211,6 +2211,7 @@ impl FrmTransactionRouterDataRequest for fraud_check::FrmTransactionRouterData {
             storage_enums::AttemptStatus::AuthenticationSuccessful
             | storage_enums::AttemptStatus::PartialChargedAndChargeable
             | storage_enums::AttemptStatus::Authorized
+            | storage_enums::AttemptStatus::PartiallyAuthorized
             | storage_enums::AttemptStatus::Charged => Some(true),
 
             storage_enums::AttemptStatus::Started
@@ -2242,6 +2243,7 @@ pub fn is_payment_failure(status: enums::AttemptStatus) -> bool {
         | common_enums::AttemptStatus::AuthenticationPending
         | common_enums::AttemptStatus::AuthenticationSuccessful
         | common_enums::AttemptStatus::Authorized
+        | common_enums::AttemptStatus::PartiallyAuthorized
         | common_enums::AttemptStatus::Charged
         | common_enums::AttemptStatus::Authorizing
         | common_enums::AttemptStatus::CodInitiated
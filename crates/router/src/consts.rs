@@ -79,6 +79,7 @@ pub const EMAIL_TOKEN_TIME_IN_SECS: u64 = 60 * 60 * 24; // 1 day
 pub const EMAIL_TOKEN_BLACKLIST_PREFIX: &str = "BET_";
 
 pub const EMAIL_SUBJECT_API_KEY_EXPIRY: &str = "API Key Expiry Notice";
+pub const EMAIL_SUBJECT_ANALYTICS_DIGEST: &str = "Analytics Digest for";
 pub const EMAIL_SUBJECT_DASHBOARD_FEATURE_REQUEST: &str = "Dashboard Pro Feature Request by";
 pub const EMAIL_SUBJECT_APPROVAL_RECON_REQUEST: &str =
     "Approval of Recon Request - Access Granted to Recon Dashboard";
@@ -243,3 +244,25 @@ pub const IRRELEVANT_PAYMENT_ATTEMPT_ID: &str = "irrelevant_payment_attempt_id";
 
 // Default payment method storing TTL in redis in seconds
 pub const DEFAULT_PAYMENT_METHOD_STORE_TTL: i64 = 86400; // 1 day
+
+/// Maximum length of the `Idempotency-Key` header
+pub const MAX_IDEMPOTENCY_KEY_LENGTH: usize = 255;
+
+/// Time for which the response of an idempotent request is retained in redis
+pub const IDEMPOTENCY_KEY_EXPIRY_IN_SECONDS: i64 = 60 * 60 * 24; // 24 hours
+
+/// Time after which a request which never completed, for instance because the server crashed,
+/// no longer holds its idempotency key. The expiry is extended while the request is processed.
+pub const IDEMPOTENCY_KEY_IN_PROGRESS_EXPIRY_IN_SECONDS: i64 = 60 * 5; // 5 minutes
+
+/// Interval at which the expiry of the idempotency key of a request being processed is extended
+pub const IDEMPOTENCY_KEY_IN_PROGRESS_REFRESH_INTERVAL_IN_SECONDS: u64 = 60; // 1 minute
+
+/// Length of the key used to sign outgoing webhooks and payment responses
+pub const PAYMENT_RESPONSE_HASH_KEY_LENGTH: usize = 64;
+
+/// Default duration for which outgoing webhooks are also signed with the previous key after rotation
+pub const DEFAULT_WEBHOOK_SECRET_ROTATION_WINDOW_IN_SECONDS: u32 = 60 * 60 * 24; // 24 hours
+
+/// Maximum duration for which outgoing webhooks are also signed with the previous key after rotation
+pub const MAX_WEBHOOK_SECRET_ROTATION_WINDOW_IN_SECONDS: u32 = 60 * 60 * 24 * 7; // 7 days
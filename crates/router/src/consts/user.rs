@@ -20,6 +20,8 @@ pub const TOTP_TOLERANCE: u8 = 1;
 pub const TOTP_MAX_ATTEMPTS: u8 = 4;
 /// Number of maximum attempts user has for recovery code
 pub const RECOVERY_CODE_MAX_ATTEMPTS: u8 = 4;
+/// Number of maximum attempts user has for passkey
+pub const PASSKEY_MAX_ATTEMPTS: u8 = 4;
 /// The default number of organizations to fetch for a tenant-level user
 pub const ORG_LIST_LIMIT_FOR_TENANT: u32 = 20;
 
@@ -35,6 +37,15 @@ pub const REDIS_RECOVERY_CODE_ATTEMPTS_PREFIX: &str = "RC_ATTEMPTS_";
 pub const REDIS_TOTP_ATTEMPTS_TTL_IN_SECS: i64 = 5 * 60; // 5 mins
 pub const REDIS_RECOVERY_CODE_ATTEMPTS_TTL_IN_SECS: i64 = 10 * 60; // 10 mins
 
+pub const REDIS_PASSKEY_PREFIX: &str = "PASSKEY_";
+pub const REDIS_PASSKEY_ATTEMPTS_PREFIX: &str = "PASSKEY_ATTEMPTS_";
+pub const REDIS_PASSKEY_ATTEMPTS_TTL_IN_SECS: i64 = 5 * 60; // 5 mins
+pub const REDIS_PASSKEY_REGISTRATION_PREFIX: &str = "PASSKEY_REG_";
+pub const REDIS_PASSKEY_AUTHENTICATION_PREFIX: &str = "PASSKEY_AUTH_";
+pub const REDIS_PASSKEY_SIGNIN_PREFIX: &str = "PASSKEY_SIGNIN_";
+pub const REDIS_PASSKEY_CHALLENGE_TTL_IN_SECS: i64 = 5 * 60; // 5 mins
+pub const PASSKEY_ID_PREFIX: &str = "passkey";
+
 pub const REDIS_SSO_PREFIX: &str = "SSO_";
 pub const REDIS_SSO_TTL: i64 = 5 * 60; // 5 minutes
 
@@ -1,4 +1,6 @@
 pub mod admin;
+#[cfg(feature = "olap")]
+pub mod analytics_digest;
 pub mod api_keys;
 pub mod api_locking;
 #[cfg(feature = "v1")]
@@ -44,10 +46,14 @@ pub mod recon;
 pub mod refunds;
 #[cfg(feature = "v2")]
 pub mod refunds_v2;
+#[cfg(feature = "olap")]
+pub mod reports;
 
 #[cfg(feature = "v1")]
 pub mod debit_routing;
 pub mod routing;
+#[cfg(feature = "v1")]
+pub mod subscriptions;
 pub mod surcharge_decision_config;
 #[cfg(feature = "olap")]
 pub mod user;
@@ -4068,6 +4068,14 @@ impl ProfileCreateBridge for api::ProfileCreate {
             })
             .transpose()?;
 
+        if let Some(retry_policy) = &self.revenue_recovery_retry_policy {
+            retry_policy.validate().map_err(|err| {
+                error_stack::report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message: err.to_string()
+                })
+            })?;
+        }
+
         let key = key_store.key.clone().into_inner();
         let key_manager_state = state.into();
 
@@ -4165,6 +4173,7 @@ impl ProfileCreateBridge for api::ProfileCreate {
             external_vault_connector_details: self
                 .external_vault_connector_details
                 .map(ForeignInto::foreign_into),
+            revenue_recovery_retry_policy: self.revenue_recovery_retry_policy,
         }))
     }
 }
@@ -4530,6 +4539,14 @@ impl ProfileUpdateBridge for api::ProfileUpdate {
             })
             .transpose()?;
 
+        if let Some(retry_policy) = &self.revenue_recovery_retry_policy {
+            retry_policy.validate().map_err(|err| {
+                report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message: err.to_string()
+                })
+            })?;
+        }
+
         let key = key_store.key.clone().into_inner();
         let key_manager_state = state.into();
 
@@ -4606,6 +4623,7 @@ impl ProfileUpdateBridge for api::ProfileUpdate {
                 external_vault_connector_details: self
                     .external_vault_connector_details
                     .map(ForeignInto::foreign_into),
+                revenue_recovery_retry_policy: self.revenue_recovery_retry_policy,
             },
         )))
     }
@@ -4926,6 +4944,81 @@ pub async fn connector_agnostic_mit_toggle(
     ))
 }
 
+#[cfg(feature = "v1")]
+pub async fn rotate_profile_webhook_secret(
+    state: SessionState,
+    key_store: domain::MerchantKeyStore,
+    profile_id: &id_type::ProfileId,
+    request: admin_types::ProfileWebhookSecretRotateRequest,
+) -> RouterResponse<admin_types::ProfileWebhookSecretRotateResponse> {
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+
+    let rotation_window_in_seconds = request
+        .rotation_window_in_seconds
+        .unwrap_or(consts::DEFAULT_WEBHOOK_SECRET_ROTATION_WINDOW_IN_SECONDS);
+    if rotation_window_in_seconds > consts::MAX_WEBHOOK_SECRET_ROTATION_WINDOW_IN_SECONDS {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "`rotation_window_in_seconds` must be less than or equal to {}",
+                consts::MAX_WEBHOOK_SECRET_ROTATION_WINDOW_IN_SECONDS
+            ),
+        }
+        .into());
+    }
+
+    let business_profile = db
+        .find_business_profile_by_merchant_id_profile_id(
+            key_manager_state,
+            &key_store,
+            &key_store.merchant_id,
+            profile_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: profile_id.get_string_repr().to_owned(),
+        })?;
+
+    let payment_response_hash_key =
+        common_utils::crypto::generate_cryptographically_secure_random_string(
+            consts::PAYMENT_RESPONSE_HASH_KEY_LENGTH,
+        );
+    // The previous key remains valid for signing outgoing webhooks until the rotation window
+    // elapses, allowing merchants to switch over to the new key without rejecting webhooks.
+    let previous_payment_response_hash_key = business_profile.payment_response_hash_key.clone();
+    let previous_payment_response_hash_key_expires_at =
+        previous_payment_response_hash_key.as_ref().map(|_| {
+            date_time::now().saturating_add(time::Duration::seconds(i64::from(
+                rotation_window_in_seconds,
+            )))
+        });
+
+    let profile_update = domain::ProfileUpdate::PaymentResponseHashKeyRotation {
+        payment_response_hash_key: payment_response_hash_key.clone(),
+        previous_payment_response_hash_key,
+        previous_payment_response_hash_key_expires_at,
+    };
+
+    db.update_profile_by_profile_id(
+        key_manager_state,
+        &key_store,
+        business_profile,
+        profile_update,
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+        id: profile_id.get_string_repr().to_owned(),
+    })?;
+
+    Ok(service_api::ApplicationResponse::Json(
+        admin_types::ProfileWebhookSecretRotateResponse {
+            profile_id: profile_id.to_owned(),
+            payment_response_hash_key,
+            previous_payment_response_hash_key_expires_at,
+        },
+    ))
+}
+
 pub async fn transfer_key_store_to_key_manager(
     state: SessionState,
     req: admin_types::MerchantKeyTransferRequest,
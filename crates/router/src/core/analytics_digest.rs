@@ -0,0 +1,586 @@
+use std::collections::{HashMap, HashSet};
+
+use analytics::{
+    enums::AuthInfo,
+    errors::{AnalyticsError, AnalyticsResult},
+};
+use api_models::analytics::{
+    disputes::DisputeMetrics,
+    payments::{ErrorResult, PaymentDimensions, PaymentDistributions, PaymentMetrics},
+    refunds::{RefundDimensions, RefundMetrics},
+    AnalyticsRequest, CreateDigestSubscriptionRequest, DigestSubscriptionResponse,
+    GetDisputeMetricRequest, GetPaymentMetricRequest, GetRefundMetricRequest,
+    PaymentDistributionBody, QueryLimit, TimeRange, UpdateDigestSubscriptionRequest,
+};
+use common_enums::{AnalyticsDigestFrequency, Currency, EntityType};
+use common_utils::{id_type, pii};
+use error_stack::{report, ResultExt};
+use router_env::{instrument, tracing};
+
+use crate::{
+    analytics_validator::request_validator,
+    db::user_role::ListUserRolesByOrgIdPayload,
+    routes::{metrics, SessionState},
+    services::{
+        authentication::UserFromToken,
+        authorization::{permissions::Permission, roles::RoleInfo},
+    },
+    types::{
+        storage::{self, enums as storage_enums},
+        transformers::ForeignFrom,
+    },
+};
+
+const ANALYTICS_DIGEST_TASK: &str = "ANALYTICS_DIGEST";
+const ANALYTICS_DIGEST_TAG: &str = "ANALYTICS_DIGEST";
+const ANALYTICS_DIGEST_RUNNER: storage::ProcessTrackerRunner =
+    storage::ProcessTrackerRunner::AnalyticsDigestWorkflow;
+const DIGEST_SUBSCRIPTION_ID_PREFIX: &str = "digest_sub";
+
+/// Tracking data of the task sending the digests of a subscription.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub(crate) struct AnalyticsDigestTrackingData {
+    pub(crate) subscription_id: String,
+    pub(crate) merchant_id: id_type::MerchantId,
+}
+
+/// Metrics of a merchant over the period covered by a digest.
+#[derive(Debug)]
+pub(crate) struct AnalyticsDigest {
+    pub(crate) time_range: TimeRange,
+    pub(crate) payment_count: u64,
+    pub(crate) payment_success_count: u64,
+    /// Processed amount in the lowest denomination of each currency
+    pub(crate) payment_processed_amount: Vec<(Currency, u64)>,
+    pub(crate) payment_processed_amount_in_usd: Option<u64>,
+    pub(crate) refund_count: u64,
+    /// Refunded amount in the lowest denomination of each currency
+    pub(crate) refund_processed_amount: Vec<(Currency, u64)>,
+    pub(crate) disputes_opened: u64,
+    pub(crate) top_decline_reasons: Vec<ErrorResult>,
+}
+
+impl AnalyticsDigest {
+    /// Percentage of payment attempts which succeeded, if any payment was attempted
+    pub(crate) fn get_authorization_rate(&self) -> Option<f64> {
+        if self.payment_count == 0 {
+            return None;
+        }
+        Some(
+            f64::from(u32::try_from(self.payment_success_count).ok()?) * 100.0
+                / f64::from(u32::try_from(self.payment_count).ok()?),
+        )
+    }
+}
+
+/// Subscribes the users having the role in the merchant to the analytics digests, and schedules
+/// the first digest.
+#[instrument(skip(state, user_from_token))]
+pub async fn create_subscription(
+    state: &SessionState,
+    user_from_token: UserFromToken,
+    request: CreateDigestSubscriptionRequest,
+) -> AnalyticsResult<DigestSubscriptionResponse> {
+    let tenant_id = user_from_token
+        .tenant_id
+        .as_ref()
+        .unwrap_or(&state.tenant.tenant_id);
+    let role_info = RoleInfo::from_role_id_org_id_tenant_id(
+        state,
+        &request.role_id,
+        &user_from_token.org_id,
+        tenant_id,
+    )
+    .await
+    .map_err(|error| {
+        if error.current_context().is_db_not_found() {
+            error.change_context(AnalyticsError::InvalidDigestSubscriptionRole)
+        } else {
+            error.change_context(AnalyticsError::UnknownError)
+        }
+    })?;
+
+    if !is_digest_subscription_role(&role_info) {
+        return Err(report!(AnalyticsError::InvalidDigestSubscriptionRole));
+    }
+
+    let now = common_utils::date_time::now();
+    let subscription = state
+        .store
+        .insert_analytics_digest_subscription(storage::AnalyticsDigestSubscriptionNew {
+            subscription_id: common_utils::generate_id_with_default_len(
+                DIGEST_SUBSCRIPTION_ID_PREFIX,
+            ),
+            merchant_id: user_from_token.merchant_id.clone(),
+            org_id: user_from_token.org_id.clone(),
+            role_id: request.role_id,
+            frequency: request.frequency,
+            created_by: user_from_token.user_id,
+            created_at: now,
+            last_modified_at: now,
+            last_sent_at: None,
+        })
+        .await
+        .map_err(|error| {
+            if error.current_context().is_db_unique_violation() {
+                error.change_context(AnalyticsError::DuplicateDigestSubscription)
+            } else {
+                error.change_context(AnalyticsError::UnknownError)
+            }
+        })?;
+
+    let tracking_data = AnalyticsDigestTrackingData {
+        subscription_id: subscription.subscription_id.clone(),
+        merchant_id: subscription.merchant_id.clone(),
+    };
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        get_digest_process_tracker_id(&subscription.subscription_id),
+        ANALYTICS_DIGEST_TASK,
+        ANALYTICS_DIGEST_RUNNER,
+        [ANALYTICS_DIGEST_TAG],
+        tracking_data,
+        None,
+        get_next_digest_time(subscription.frequency, now),
+        common_types::consts::API_VERSION,
+    )
+    .change_context(AnalyticsError::UnknownError)
+    .attach_printable("Failed to construct analytics digest process tracker task")?;
+
+    state
+        .store
+        .insert_process(process_tracker_entry)
+        .await
+        .change_context(AnalyticsError::UnknownError)
+        .attach_printable("Failed while inserting analytics digest task to process_tracker")?;
+    metrics::TASKS_ADDED_COUNT.add(
+        1,
+        router_env::metric_attributes!(("flow", "AnalyticsDigest")),
+    );
+
+    Ok(DigestSubscriptionResponse::foreign_from(subscription))
+}
+
+#[instrument(skip(state))]
+pub async fn list_subscriptions(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+) -> AnalyticsResult<Vec<DigestSubscriptionResponse>> {
+    let subscriptions = state
+        .store
+        .list_analytics_digest_subscriptions_by_merchant_id(merchant_id)
+        .await
+        .change_context(AnalyticsError::UnknownError)
+        .attach_printable("Failed to list analytics digest subscriptions")?;
+
+    Ok(subscriptions
+        .into_iter()
+        .map(DigestSubscriptionResponse::foreign_from)
+        .collect())
+}
+
+/// Updates the frequency of the digests, and reschedules the next digest as per the new frequency.
+#[instrument(skip(state))]
+pub async fn update_subscription(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    subscription_id: String,
+    request: UpdateDigestSubscriptionRequest,
+) -> AnalyticsResult<DigestSubscriptionResponse> {
+    let subscription = state
+        .store
+        .update_analytics_digest_subscription_by_merchant_id_subscription_id(
+            merchant_id,
+            &subscription_id,
+            storage::AnalyticsDigestSubscriptionUpdate::FrequencyUpdate {
+                frequency: request.frequency,
+            },
+        )
+        .await
+        .map_err(|error| {
+            if error.current_context().is_db_not_found() {
+                error.change_context(AnalyticsError::DigestSubscriptionNotFound)
+            } else {
+                error.change_context(AnalyticsError::UnknownError)
+            }
+        })?;
+
+    if let Some(process) = find_digest_process(state, &subscription_id).await? {
+        state
+            .store
+            .as_scheduler()
+            .reset_process(
+                process,
+                get_next_digest_time(subscription.frequency, common_utils::date_time::now()),
+            )
+            .await
+            .change_context(AnalyticsError::UnknownError)
+            .attach_printable("Failed to reschedule analytics digest task")?;
+    }
+
+    Ok(DigestSubscriptionResponse::foreign_from(subscription))
+}
+
+/// Deletes the subscription, and revokes the task sending its digests.
+#[instrument(skip(state))]
+pub async fn delete_subscription(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    subscription_id: String,
+) -> AnalyticsResult<DigestSubscriptionResponse> {
+    let subscription = state
+        .store
+        .delete_analytics_digest_subscription_by_merchant_id_subscription_id(
+            merchant_id,
+            &subscription_id,
+        )
+        .await
+        .map_err(|error| {
+            if error.current_context().is_db_not_found() {
+                error.change_context(AnalyticsError::DigestSubscriptionNotFound)
+            } else {
+                error.change_context(AnalyticsError::UnknownError)
+            }
+        })?;
+
+    if let Some(process) = find_digest_process(state, &subscription_id).await? {
+        state
+            .store
+            .as_scheduler()
+            .finish_process_with_business_status(process, storage::business_status::REVOKED)
+            .await
+            .change_context(AnalyticsError::UnknownError)
+            .attach_printable("Failed to revoke analytics digest task")?;
+    }
+
+    Ok(DigestSubscriptionResponse::foreign_from(subscription))
+}
+
+/// Computes the payment, refund and dispute metrics of the merchant over the time range.
+#[instrument(skip(state))]
+pub(crate) async fn get_analytics_digest(
+    state: &SessionState,
+    subscription: &storage::AnalyticsDigestSubscription,
+    time_range: TimeRange,
+) -> AnalyticsResult<AnalyticsDigest> {
+    let auth = AuthInfo::MerchantLevel {
+        org_id: subscription.org_id.clone(),
+        merchant_ids: vec![subscription.merchant_id.clone()],
+    };
+
+    let payment_request = GetPaymentMetricRequest {
+        time_series: None,
+        time_range,
+        group_by_names: vec![PaymentDimensions::Currency],
+        filters: Default::default(),
+        metrics: HashSet::from([
+            PaymentMetrics::PaymentCount,
+            PaymentMetrics::PaymentSuccessCount,
+            PaymentMetrics::PaymentProcessedAmount,
+        ]),
+        distribution: None,
+        delta: false,
+    };
+    let decline_reasons_request = GetPaymentMetricRequest {
+        time_series: None,
+        time_range,
+        group_by_names: vec![],
+        filters: Default::default(),
+        metrics: HashSet::new(),
+        distribution: Some(PaymentDistributionBody {
+            distribution_for: PaymentDistributions::PaymentErrorMessage,
+            distribution_cardinality: QueryLimit::Top5,
+        }),
+        delta: false,
+    };
+    let refund_request = GetRefundMetricRequest {
+        time_series: None,
+        time_range,
+        group_by_names: vec![RefundDimensions::Currency],
+        filters: Default::default(),
+        metrics: HashSet::from([
+            RefundMetrics::RefundCount,
+            RefundMetrics::RefundProcessedAmount,
+        ]),
+        distribution: None,
+        delta: false,
+    };
+    let dispute_request = GetDisputeMetricRequest {
+        time_series: None,
+        time_range,
+        group_by_names: vec![],
+        filters: Default::default(),
+        metrics: HashSet::from([DisputeMetrics::DisputeStatusMetric]),
+        delta: false,
+    };
+
+    let ex_rates = request_validator(
+        AnalyticsRequest {
+            payment: Some(payment_request.clone()),
+            refund: Some(refund_request.clone()),
+            ..Default::default()
+        },
+        state,
+    )
+    .await?;
+
+    let payments =
+        analytics::payments::get_metrics(&state.pool, &ex_rates, &auth, payment_request).await?;
+    let decline_reasons =
+        analytics::payments::get_metrics(&state.pool, &ex_rates, &auth, decline_reasons_request)
+            .await?;
+    let refunds =
+        analytics::refunds::get_metrics(&state.pool, &ex_rates, &auth, refund_request).await?;
+    let disputes = analytics::disputes::get_metrics(&state.pool, &auth, dispute_request).await?;
+
+    let mut payment_processed_amount = HashMap::new();
+    let (mut payment_count, mut payment_success_count) = (0, 0);
+    for bucket in payments.query_data {
+        payment_count += bucket.values.payment_count.unwrap_or_default();
+        payment_success_count += bucket.values.payment_success_count.unwrap_or_default();
+        if let (Some(currency), Some(amount)) = (
+            bucket.dimensions.currency,
+            bucket.values.payment_processed_amount,
+        ) {
+            *payment_processed_amount.entry(currency).or_default() += amount;
+        }
+    }
+
+    let mut refund_processed_amount = HashMap::new();
+    let mut refund_count = 0;
+    for bucket in refunds.query_data {
+        refund_count += bucket.values.refund_count.unwrap_or_default();
+        if let (Some(currency), Some(amount)) = (
+            bucket.dimensions.currency,
+            bucket.values.refund_processed_amount,
+        ) {
+            *refund_processed_amount.entry(currency).or_default() += amount;
+        }
+    }
+
+    Ok(AnalyticsDigest {
+        time_range,
+        payment_count,
+        payment_success_count,
+        payment_processed_amount: sort_by_amount(payment_processed_amount),
+        payment_processed_amount_in_usd: payments
+            .meta_data
+            .first()
+            .and_then(|meta_data| meta_data.total_payment_processed_amount_in_usd),
+        refund_count,
+        refund_processed_amount: sort_by_amount(refund_processed_amount),
+        disputes_opened: disputes
+            .query_data
+            .iter()
+            .filter_map(|bucket| bucket.values.total_dispute)
+            .sum(),
+        top_decline_reasons: decline_reasons
+            .query_data
+            .into_iter()
+            .filter_map(|bucket| bucket.values.payment_error_message)
+            .flatten()
+            .collect(),
+    })
+}
+
+/// Emails of the active users having the subscribed role in the merchant.
+#[instrument(skip(state))]
+pub(crate) async fn get_digest_recipients(
+    state: &SessionState,
+    subscription: &storage::AnalyticsDigestSubscription,
+) -> AnalyticsResult<Vec<pii::Email>> {
+    let user_ids = state
+        .global_store
+        .list_user_roles_by_org_id(ListUserRolesByOrgIdPayload {
+            user_id: None,
+            tenant_id: &state.tenant.tenant_id,
+            org_id: &subscription.org_id,
+            merchant_id: Some(&subscription.merchant_id),
+            profile_id: None,
+            version: None,
+            limit: None,
+        })
+        .await
+        .change_context(AnalyticsError::UnknownError)
+        .attach_printable("Failed to list user roles of the merchant")?
+        .into_iter()
+        .filter(|user_role| {
+            user_role.role_id == subscription.role_id
+                && user_role.entity_type == Some(EntityType::Merchant)
+                && user_role.status == storage_enums::UserStatus::Active
+        })
+        .map(|user_role| user_role.user_id)
+        .collect::<HashSet<_>>();
+
+    if user_ids.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    Ok(state
+        .global_store
+        .find_users_by_user_ids(user_ids.into_iter().collect())
+        .await
+        .change_context(AnalyticsError::UnknownError)
+        .attach_printable("Failed to find users subscribed to analytics digest")?
+        .into_iter()
+        .map(|user| user.email)
+        .collect())
+}
+
+/// The period covered by the latest digest due at the specified time.
+///
+/// Daily digests cover the previous day, and weekly digests cover the previous week starting
+/// on Monday, both in UTC.
+pub(crate) fn get_digest_period(
+    frequency: AnalyticsDigestFrequency,
+    at: time::PrimitiveDateTime,
+) -> TimeRange {
+    let (end_date, period) = match frequency {
+        AnalyticsDigestFrequency::Daily => (at.date(), time::Duration::days(1)),
+        AnalyticsDigestFrequency::Weekly => {
+            let end_date = if at.weekday() == time::Weekday::Monday {
+                at.date()
+            } else {
+                at.date().prev_occurrence(time::Weekday::Monday)
+            };
+            (end_date, time::Duration::weeks(1))
+        }
+    };
+    let end_time = end_date.midnight();
+
+    TimeRange {
+        start_time: end_time.saturating_sub(period),
+        end_time: Some(end_time),
+    }
+}
+
+/// The time at which the first digest after the specified time is due.
+pub(crate) fn get_next_digest_time(
+    frequency: AnalyticsDigestFrequency,
+    after: time::PrimitiveDateTime,
+) -> time::PrimitiveDateTime {
+    match frequency {
+        AnalyticsDigestFrequency::Daily => after.date().saturating_add(time::Duration::days(1)),
+        AnalyticsDigestFrequency::Weekly => after.date().next_occurrence(time::Weekday::Monday),
+    }
+    .midnight()
+}
+
+/// Digests contain the metrics of the whole merchant, so only the merchant level roles which can
+/// view the analytics of the merchant can be subscribed to them.
+fn is_digest_subscription_role(role_info: &RoleInfo) -> bool {
+    !role_info.is_internal()
+        && role_info.get_entity_type() == EntityType::Merchant
+        && role_info.check_permission_exists(Permission::MerchantAnalyticsRead)
+}
+
+async fn find_digest_process(
+    state: &SessionState,
+    subscription_id: &str,
+) -> AnalyticsResult<Option<storage::ProcessTracker>> {
+    state
+        .store
+        .find_process_by_id(&get_digest_process_tracker_id(subscription_id))
+        .await
+        .change_context(AnalyticsError::UnknownError)
+        .attach_printable("Failed to find analytics digest task")
+}
+
+fn sort_by_amount(amounts: HashMap<Currency, u64>) -> Vec<(Currency, u64)> {
+    let mut amounts = amounts.into_iter().collect::<Vec<_>>();
+    amounts.sort_by(|(_, left), (_, right)| right.cmp(left));
+    amounts
+}
+
+fn get_digest_process_tracker_id(subscription_id: &str) -> String {
+    format!("{ANALYTICS_DIGEST_RUNNER}_{ANALYTICS_DIGEST_TASK}_{subscription_id}")
+}
+
+impl ForeignFrom<storage::AnalyticsDigestSubscription> for DigestSubscriptionResponse {
+    fn foreign_from(subscription: storage::AnalyticsDigestSubscription) -> Self {
+        Self {
+            subscription_id: subscription.subscription_id,
+            role_id: subscription.role_id,
+            frequency: subscription.frequency,
+            created_at: subscription.created_at,
+            last_sent_at: subscription.last_sent_at,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+    use crate::consts::user_role;
+
+    #[test]
+    fn test_is_digest_subscription_role() {
+        let cases = [
+            (user_role::ROLE_ID_MERCHANT_ADMIN, true),
+            (user_role::ROLE_ID_MERCHANT_VIEW_ONLY, true),
+            (user_role::ROLE_ID_MERCHANT_CUSTOMER_SUPPORT, true),
+            (user_role::ROLE_ID_PROFILE_ADMIN, false),
+            (user_role::ROLE_ID_PROFILE_VIEW_ONLY, false),
+            (common_utils::consts::ROLE_ID_ORGANIZATION_ADMIN, false),
+            (common_utils::consts::ROLE_ID_INTERNAL_ADMIN, false),
+        ];
+
+        for (role_id, expected) in cases {
+            let role_info = RoleInfo::from_predefined_roles(role_id).unwrap();
+            assert_eq!(
+                is_digest_subscription_role(&role_info),
+                expected,
+                "role: {role_id}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_is_digest_subscription_role_requires_analytics_permission() {
+        let get_role_info = |groups: serde_json::Value| -> RoleInfo {
+            serde_json::from_value(serde_json::json!({
+                "role_id": "custom_role",
+                "role_name": "custom_role",
+                "groups": groups,
+                "scope": "merchant",
+                "entity_type": "merchant",
+                "is_invitable": true,
+                "is_deletable": true,
+                "is_updatable": true,
+                "is_internal": false,
+            }))
+            .unwrap()
+        };
+
+        assert!(is_digest_subscription_role(&get_role_info(
+            serde_json::json!(["analytics_view"])
+        )));
+        assert!(!is_digest_subscription_role(&get_role_info(
+            serde_json::json!(["operations_view", "users_manage"])
+        )));
+    }
+
+    #[test]
+    fn test_get_digest_period() {
+        let at = time::macros::datetime!(2024-05-15 00:00);
+
+        let period = get_digest_period(AnalyticsDigestFrequency::Daily, at);
+        assert_eq!(period.start_time, time::macros::datetime!(2024-05-14 00:00));
+        assert_eq!(period.end_time, Some(at));
+
+        // 2024-05-15 is a Wednesday, the previous week starts on Monday 2024-05-06
+        let period = get_digest_period(AnalyticsDigestFrequency::Weekly, at);
+        assert_eq!(period.start_time, time::macros::datetime!(2024-05-06 00:00));
+        assert_eq!(
+            period.end_time,
+            Some(time::macros::datetime!(2024-05-13 00:00))
+        );
+
+        let period = get_digest_period(
+            AnalyticsDigestFrequency::Weekly,
+            time::macros::datetime!(2024-05-13 00:00),
+        );
+        assert_eq!(period.start_time, time::macros::datetime!(2024-05-06 00:00));
+    }
+}
@@ -1,6 +1,9 @@
+use std::collections::HashSet;
+
 use common_utils::date_time;
 #[cfg(feature = "email")]
-use diesel_models::{api_keys::ApiKey, enums as storage_enums};
+use diesel_models::api_keys::ApiKey;
+use diesel_models::enums as storage_enums;
 use error_stack::{report, ResultExt};
 use masking::{PeekInterface, StrongSecret};
 use router_env::{instrument, tracing};
@@ -119,6 +122,24 @@ pub async fn create_api_key(
 
     let merchant_id = key_store.merchant_id.clone();
 
+    if let Some(permission_groups) = api_key.permission_groups.as_deref() {
+        validate_permission_groups(permission_groups)?;
+    }
+
+    if let Some(profile_id) = api_key.profile_id.as_ref() {
+        store
+            .find_business_profile_by_merchant_id_profile_id(
+                &(&state).into(),
+                &key_store,
+                &merchant_id,
+                profile_id,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+                id: profile_id.get_string_repr().to_owned(),
+            })?;
+    }
+
     let hash_key = api_key_config.get_hash_key()?;
     let plaintext_api_key = PlaintextApiKey::new(consts::API_KEY_LENGTH);
     let api_key = storage::ApiKeyNew {
@@ -131,6 +152,8 @@ pub async fn create_api_key(
         created_at: date_time::now(),
         expires_at: api_key.expiration.into(),
         last_used: None,
+        permission_groups: api_key.permission_groups,
+        profile_id: api_key.profile_id,
     };
 
     let api_key = store
@@ -250,6 +273,35 @@ pub async fn add_api_key_expiry_task(
     Ok(())
 }
 
+fn validate_permission_groups(
+    permission_groups: &[storage_enums::PermissionGroup],
+) -> errors::RouterResult<()> {
+    if permission_groups.is_empty() {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "`permission_groups` cannot be empty".to_string(),
+        }));
+    }
+
+    let unique_groups: HashSet<_> = permission_groups.iter().copied().collect();
+
+    if unique_groups.contains(&storage_enums::PermissionGroup::OrganizationManage)
+        || unique_groups.contains(&storage_enums::PermissionGroup::InternalManage)
+    {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "`permission_groups` contains groups that cannot be assigned to API keys"
+                .to_string(),
+        }));
+    }
+
+    if unique_groups.len() != permission_groups.len() {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "`permission_groups` contains duplicate groups".to_string(),
+        }));
+    }
+
+    Ok(())
+}
+
 #[instrument(skip_all)]
 pub async fn retrieve_api_key(
     state: SessionState,
@@ -276,6 +328,10 @@ pub async fn update_api_key(
     let key_id = api_key.key_id.clone();
     let store = state.store.as_ref();
 
+    if let Some(permission_groups) = api_key.permission_groups.as_deref() {
+        validate_permission_groups(permission_groups)?;
+    }
+
     let api_key = store
         .update_api_key(
             merchant_id.to_owned(),
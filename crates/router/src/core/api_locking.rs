@@ -1,15 +1,28 @@
 use std::fmt::Debug;
 
-use actix_web::rt::time as actix_time;
+use actix_web::{http::header::HeaderMap, rt::time as actix_time};
+use common_utils::{
+    crypto::{GenerateDigest, Sha256},
+    errors::CustomResult,
+};
 use error_stack::{report, ResultExt};
+use hyperswitch_domain_models::api::StoredResponse;
+use masking::Maskable;
 use redis_interface as redis;
 use router_env::{instrument, logger, tracing};
+use serde::{Deserialize, Serialize};
 
 use super::errors::{self, RouterResult};
-use crate::routes::{app::SessionStateInfo, lock_utils};
+use crate::{
+    consts, headers,
+    routes::{app::SessionStateInfo, lock_utils},
+    services::{authentication as auth, ApplicationResponse},
+};
 
 pub const API_LOCK_PREFIX: &str = "API_LOCK";
 
+pub const IDEMPOTENCY_PREFIX: &str = "IDEMPOTENCY";
+
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum LockStatus {
     // status when the lock is acquired by the caller
@@ -21,13 +34,20 @@ pub enum LockStatus {
 #[derive(Clone, Debug)]
 pub enum LockAction {
     // Sleep until the lock is acquired
-    Hold { input: LockingInput },
+    Hold {
+        input: LockingInput,
+    },
     // Queue it but return response as 2xx, could be used for webhooks
     QueueWithOk,
     // Return Error
     Drop,
     // Locking Not applicable
     NotApplicable,
+    // Replay the stored response for a reused idempotency key, hold the lock (if any) otherwise
+    Idempotent {
+        input: IdempotencyInput,
+        locking_input: Option<LockingInput>,
+    },
 }
 
 #[derive(Clone, Debug)]
@@ -49,7 +69,410 @@ impl LockingInput {
     }
 }
 
+#[derive(Clone, Debug)]
+pub struct IdempotencyInput {
+    pub idempotency_key: String,
+    pub api_identifier: lock_utils::ApiIdentifier,
+    pub request_fingerprint: String,
+}
+
+/// Outcome of checking the idempotency key of an incoming request
+#[derive(Clone, Debug)]
+pub enum IdempotencyStatus {
+    /// The request has to be processed, either because it carries a fresh idempotency key
+    /// or because idempotency is not applicable to it
+    Proceed,
+    /// The request is a retry of an already processed request, holds the stored response
+    Replay(StoredResponse),
+}
+
+/// State of an idempotency key as stored in redis
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+enum IdempotencyRecord {
+    InProgress {
+        request_fingerprint: String,
+    },
+    Completed {
+        request_fingerprint: String,
+        status_code: u16,
+        headers: Vec<IdempotencyRecordHeader>,
+        response: String,
+    },
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct IdempotencyRecordHeader {
+    name: String,
+    value: String,
+    is_sensitive: bool,
+}
+
+impl IdempotencyRecord {
+    fn get_request_fingerprint(&self) -> &str {
+        match self {
+            Self::InProgress {
+                request_fingerprint,
+            }
+            | Self::Completed {
+                request_fingerprint,
+                ..
+            } => request_fingerprint,
+        }
+    }
+}
+
+impl IdempotencyInput {
+    /// Builds the idempotency input from the `Idempotency-Key` header, if present.
+    /// The request fingerprint is computed over the payload as received from the client,
+    /// so this has to be called before any server generated identifiers are populated.
+    pub fn from_headers<T, F>(
+        headers: &HeaderMap,
+        payload: &T,
+        flow: F,
+    ) -> RouterResult<Option<Self>>
+    where
+        T: Serialize,
+        F: router_env::types::FlowMetric,
+        lock_utils::ApiIdentifier: From<F>,
+    {
+        auth::get_header_value_by_key(headers::IDEMPOTENCY_KEY.to_string(), headers)?
+            .map(|idempotency_key| {
+                let idempotency_key = idempotency_key.trim();
+                if idempotency_key.is_empty()
+                    || idempotency_key.len() > consts::MAX_IDEMPOTENCY_KEY_LENGTH
+                {
+                    return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                        message: format!(
+                            "`{}` header must be a non-empty string of at most {} characters",
+                            headers::IDEMPOTENCY_KEY,
+                            consts::MAX_IDEMPOTENCY_KEY_LENGTH
+                        ),
+                    }));
+                }
+
+                let serialized_payload = serde_json::to_vec(payload)
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to serialize request for idempotency fingerprint")?;
+                let request_fingerprint = Sha256
+                    .generate_digest(&serialized_payload)
+                    .map(hex::encode)
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to generate idempotency fingerprint")?;
+
+                Ok(Self {
+                    idempotency_key: idempotency_key.to_owned(),
+                    api_identifier: lock_utils::ApiIdentifier::from(flow),
+                    request_fingerprint,
+                })
+            })
+            .transpose()
+    }
+
+    fn get_redis_idempotency_key(&self, merchant_id: &common_utils::id_type::MerchantId) -> String {
+        format!(
+            "{}_{}_{}_{}",
+            IDEMPOTENCY_PREFIX,
+            merchant_id.get_string_repr(),
+            self.api_identifier,
+            self.idempotency_key
+        )
+    }
+}
+
 impl LockAction {
+    /// Wraps the lock action so that the request is deduplicated using the given idempotency input
+    pub fn with_idempotency(self, idempotency_input: Option<IdempotencyInput>) -> Self {
+        match idempotency_input {
+            Some(input) => {
+                let locking_input = match self {
+                    Self::Hold {
+                        input: locking_input,
+                    }
+                    | Self::Idempotent {
+                        locking_input: Some(locking_input),
+                        ..
+                    } => Some(locking_input),
+                    Self::Idempotent {
+                        locking_input: None,
+                        ..
+                    }
+                    | Self::QueueWithOk
+                    | Self::Drop
+                    | Self::NotApplicable => None,
+                };
+                Self::Idempotent {
+                    input,
+                    locking_input,
+                }
+            }
+            None => self,
+        }
+    }
+
+    #[instrument(skip_all)]
+    pub async fn perform_idempotency_check<A>(
+        &self,
+        state: &A,
+        merchant_id: &common_utils::id_type::MerchantId,
+    ) -> RouterResult<IdempotencyStatus>
+    where
+        A: SessionStateInfo,
+    {
+        match self {
+            Self::Idempotent { input, .. } => {
+                let redis_conn = state
+                    .store()
+                    .get_redis_conn()
+                    .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+                let redis_idempotency_key = input.get_redis_idempotency_key(merchant_id);
+                let setnx_reply = redis_conn
+                    .serialize_and_set_key_if_not_exist(
+                        &redis_idempotency_key.as_str().into(),
+                        IdempotencyRecord::InProgress {
+                            request_fingerprint: input.request_fingerprint.clone(),
+                        },
+                        Some(consts::IDEMPOTENCY_KEY_IN_PROGRESS_EXPIRY_IN_SECONDS),
+                    )
+                    .await
+                    .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+                match setnx_reply {
+                    redis::SetnxReply::KeySet => {
+                        logger::info!("Idempotency key acquired for input {:?}", input);
+                        Ok(IdempotencyStatus::Proceed)
+                    }
+                    redis::SetnxReply::KeyNotSet => {
+                        let record = redis_conn
+                            .get_and_deserialize_key::<IdempotencyRecord>(
+                                &redis_idempotency_key.as_str().into(),
+                                "IdempotencyRecord",
+                            )
+                            .await
+                            .change_context(errors::ApiErrorResponse::InternalServerError)
+                            .attach_printable("Failed to fetch the stored idempotency record")?;
+
+                        if record.get_request_fingerprint() != input.request_fingerprint {
+                            return Err(report!(errors::ApiErrorResponse::IdempotencyKeyMismatch));
+                        }
+
+                        match record {
+                            IdempotencyRecord::InProgress { .. } => {
+                                Err(report!(errors::ApiErrorResponse::IdempotencyKeyInUse))
+                            }
+                            IdempotencyRecord::Completed {
+                                status_code,
+                                headers,
+                                response,
+                                ..
+                            } => {
+                                logger::info!("Replaying stored response for input {:?}", input);
+                                Ok(IdempotencyStatus::Replay(StoredResponse {
+                                    status_code,
+                                    headers: headers
+                                        .into_iter()
+                                        .map(|header| {
+                                            let value = if header.is_sensitive {
+                                                Maskable::new_masked(header.value.into())
+                                            } else {
+                                                Maskable::new_normal(header.value)
+                                            };
+                                            (header.name, value)
+                                        })
+                                        .collect(),
+                                    body: response.into_bytes(),
+                                }))
+                            }
+                        }
+                    }
+                }
+            }
+            Self::Hold { .. } | Self::QueueWithOk | Self::Drop | Self::NotApplicable => {
+                Ok(IdempotencyStatus::Proceed)
+            }
+        }
+    }
+
+    /// Processes the request, extending the expiry of its in-progress idempotency record until the
+    /// request completes. Retries are rejected as conflicts for as long as the request runs, instead
+    /// of being processed again once the record expires.
+    #[instrument(skip_all)]
+    pub async fn hold_idempotency_key_while<A, Fut>(
+        &self,
+        state: &A,
+        merchant_id: &common_utils::id_type::MerchantId,
+        request: Fut,
+    ) -> Fut::Output
+    where
+        A: SessionStateInfo,
+        Fut: std::future::Future,
+    {
+        let Self::Idempotent { input, .. } = self else {
+            return request.await;
+        };
+
+        let redis_idempotency_key = input.get_redis_idempotency_key(merchant_id);
+        let refresh_interval = tokio::time::Duration::from_secs(
+            consts::IDEMPOTENCY_KEY_IN_PROGRESS_REFRESH_INTERVAL_IN_SECONDS,
+        );
+        let mut request = std::pin::pin!(request);
+
+        loop {
+            tokio::select! {
+                output = &mut request => return output,
+                () = actix_time::sleep(refresh_interval) => {
+                    let refresh_result = match state.store().get_redis_conn() {
+                        Ok(redis_conn) => redis_conn
+                            .set_expiry(
+                                &redis_idempotency_key.as_str().into(),
+                                consts::IDEMPOTENCY_KEY_IN_PROGRESS_EXPIRY_IN_SECONDS,
+                            )
+                            .await,
+                        Err(error) => Err(error),
+                    };
+
+                    if let Err(error) = refresh_result {
+                        logger::error!(
+                            ?error,
+                            "Failed to extend the idempotency key expiry for input {:?}",
+                            input
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    /// Stores the outcome of the request against the idempotency key so that retries are replayed.
+    /// Error responses are stored as well, with their original status code, since the request may
+    /// have had side effects before failing. Only request validation errors, which are raised
+    /// before any side effects, release the idempotency key so that the client can retry.
+    #[instrument(skip_all)]
+    pub async fn store_idempotent_response<A, Q, E>(
+        &self,
+        state: &A,
+        merchant_id: &common_utils::id_type::MerchantId,
+        response: &CustomResult<ApplicationResponse<Q>, E>,
+    ) where
+        A: SessionStateInfo,
+        Q: Serialize,
+        E: actix_web::ResponseError,
+    {
+        let Self::Idempotent { input, .. } = self else {
+            return;
+        };
+
+        let completed_response = match response {
+            // Json responses are always served with a 200 status code
+            Ok(ApplicationResponse::Json(data)) => serde_json::to_string(data)
+                .ok()
+                .map(|data| (actix_web::http::StatusCode::OK.as_u16(), Vec::new(), data)),
+            Ok(ApplicationResponse::JsonWithHeaders((data, headers))) => {
+                serde_json::to_string(data).ok().map(|data| {
+                    let headers = headers
+                        .iter()
+                        // Latency of the original request is not relevant to the replayed response
+                        .filter(|(name, _)| name != common_utils::consts::X_HS_LATENCY)
+                        .map(|(name, value)| IdempotencyRecordHeader {
+                            name: name.clone(),
+                            is_sensitive: value.is_masked(),
+                            value: value.clone().into_inner(),
+                        })
+                        .collect();
+                    (actix_web::http::StatusCode::OK.as_u16(), headers, data)
+                })
+            }
+            Ok(ApplicationResponse::StatusOk) => Some((
+                actix_web::http::StatusCode::OK.as_u16(),
+                Vec::new(),
+                String::new(),
+            )),
+            Err(error) if is_request_validation_error(error) => None,
+            Err(error) => get_stored_error_response(error.current_context()).await,
+            // Redirections, forms and files are not served by idempotent flows
+            Ok(_) => {
+                logger::warn!(
+                    "Response for input {:?} cannot be stored, releasing the idempotency key",
+                    input
+                );
+                None
+            }
+        };
+
+        match completed_response {
+            Some((status_code, headers, response)) => {
+                let redis_conn = match state.store().get_redis_conn() {
+                    Ok(redis_conn) => redis_conn,
+                    Err(error) => {
+                        logger::error!(
+                            ?error,
+                            "Failed to get redis connection for idempotency key"
+                        );
+                        return;
+                    }
+                };
+                let redis_idempotency_key = input.get_redis_idempotency_key(merchant_id);
+
+                if let Err(error) = redis_conn
+                    .serialize_and_set_key_with_expiry(
+                        &redis_idempotency_key.as_str().into(),
+                        IdempotencyRecord::Completed {
+                            request_fingerprint: input.request_fingerprint.clone(),
+                            status_code,
+                            headers,
+                            response,
+                        },
+                        consts::IDEMPOTENCY_KEY_EXPIRY_IN_SECONDS,
+                    )
+                    .await
+                {
+                    logger::error!(
+                        ?error,
+                        "Failed to store idempotency record for input {:?}",
+                        input
+                    );
+                }
+            }
+            None => self.release_idempotency_key(state, merchant_id).await,
+        }
+    }
+
+    /// Releases the idempotency key of a request which was not processed,
+    /// so that the client can retry it with the same key
+    #[instrument(skip_all)]
+    pub async fn release_idempotency_key<A>(
+        &self,
+        state: &A,
+        merchant_id: &common_utils::id_type::MerchantId,
+    ) where
+        A: SessionStateInfo,
+    {
+        let Self::Idempotent { input, .. } = self else {
+            return;
+        };
+
+        let redis_conn = match state.store().get_redis_conn() {
+            Ok(redis_conn) => redis_conn,
+            Err(error) => {
+                logger::error!(?error, "Failed to get redis connection for idempotency key");
+                return;
+            }
+        };
+        let redis_idempotency_key = input.get_redis_idempotency_key(merchant_id);
+
+        if let Err(error) = redis_conn
+            .delete_key(&redis_idempotency_key.as_str().into())
+            .await
+        {
+            logger::error!(
+                ?error,
+                "Failed to release idempotency key for input {:?}",
+                input
+            );
+        }
+    }
+
     #[instrument(skip_all)]
     pub async fn perform_locking_action<A>(
         self,
@@ -60,7 +483,11 @@ impl LockAction {
         A: SessionStateInfo,
     {
         match self {
-            Self::Hold { input } => {
+            Self::Hold { input }
+            | Self::Idempotent {
+                locking_input: Some(input),
+                ..
+            } => {
                 let redis_conn = state
                     .store()
                     .get_redis_conn()
@@ -111,7 +538,13 @@ impl LockAction {
 
                 Err(report!(errors::ApiErrorResponse::ResourceBusy))
             }
-            Self::QueueWithOk | Self::Drop | Self::NotApplicable => Ok(()),
+            Self::QueueWithOk
+            | Self::Drop
+            | Self::NotApplicable
+            | Self::Idempotent {
+                locking_input: None,
+                ..
+            } => Ok(()),
         }
     }
 
@@ -125,7 +558,11 @@ impl LockAction {
         A: SessionStateInfo,
     {
         match self {
-            Self::Hold { input } => {
+            Self::Hold { input }
+            | Self::Idempotent {
+                locking_input: Some(input),
+                ..
+            } => {
                 let redis_conn = state
                     .store()
                     .get_redis_conn()
@@ -168,14 +605,189 @@ impl LockAction {
                     }
                 }
             }
-            Self::QueueWithOk | Self::Drop | Self::NotApplicable => Ok(()),
+            Self::QueueWithOk
+            | Self::Drop
+            | Self::NotApplicable
+            | Self::Idempotent {
+                locking_input: None,
+                ..
+            } => Ok(()),
         }
     }
 }
 
+/// Builds the status code, headers and body of an error response, as it is served to the client
+async fn get_stored_error_response<E>(
+    error: &E,
+) -> Option<(u16, Vec<IdempotencyRecordHeader>, String)>
+where
+    E: actix_web::ResponseError,
+{
+    let error_response = error.error_response();
+    let status_code = error_response.status().as_u16();
+    let headers = error_response
+        .headers()
+        .iter()
+        // The content type is set when the stored response is served
+        .filter(|(name, _)| **name != actix_web::http::header::CONTENT_TYPE)
+        .filter_map(|(name, value)| {
+            value.to_str().ok().map(|value| IdempotencyRecordHeader {
+                name: name.to_string(),
+                value: value.to_owned(),
+                is_sensitive: false,
+            })
+        })
+        .collect();
+    let body = actix_web::body::to_bytes(error_response.into_body())
+        .await
+        .map_err(|error| logger::error!(?error, "Failed to read the error response body"))
+        .ok()?;
+    let response = String::from_utf8(body.to_vec())
+        .map_err(|error| logger::error!(?error, "Error response body is not valid utf-8"))
+        .ok()?;
+
+    Some((status_code, headers, response))
+}
+
+/// Checks the error as raised by the core, which is retained in the report when the error is
+/// switched to the response type of the API
+fn is_request_validation_error<E>(error: &error_stack::Report<E>) -> bool {
+    error
+        .downcast_ref::<errors::ApiErrorResponse>()
+        .is_some_and(errors::ApiErrorResponse::is_request_validation_error)
+}
+
 pub trait GetLockingInput {
     fn get_locking_input<F>(&self, flow: F) -> LockAction
     where
         F: router_env::types::FlowMetric,
         lock_utils::ApiIdentifier: From<F>;
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::unwrap_used)]
+    use actix_web::http::header::{HeaderName, HeaderValue};
+    use router_env::Flow;
+
+    use super::*;
+
+    fn get_headers(idempotency_key: &'static str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            HeaderName::from_static("idempotency-key"),
+            HeaderValue::from_static(idempotency_key),
+        );
+        headers
+    }
+
+    #[test]
+    fn test_idempotency_input_is_absent_without_header() {
+        let input = IdempotencyInput::from_headers(
+            &HeaderMap::new(),
+            &serde_json::json!({ "amount": 100 }),
+            Flow::PaymentsCreate,
+        )
+        .unwrap();
+
+        assert!(input.is_none());
+    }
+
+    #[test]
+    fn test_idempotency_input_rejects_blank_key() {
+        let input = IdempotencyInput::from_headers(
+            &get_headers("  "),
+            &serde_json::json!({ "amount": 100 }),
+            Flow::PaymentsCreate,
+        );
+
+        assert!(input.is_err());
+    }
+
+    #[test]
+    fn test_idempotency_fingerprint_depends_on_payload() {
+        let headers = get_headers("key_1");
+        let get_fingerprint = |payload: serde_json::Value| {
+            IdempotencyInput::from_headers(&headers, &payload, Flow::PaymentsCreate)
+                .unwrap()
+                .unwrap()
+                .request_fingerprint
+        };
+
+        assert_eq!(
+            get_fingerprint(serde_json::json!({ "amount": 100 })),
+            get_fingerprint(serde_json::json!({ "amount": 100 }))
+        );
+        assert_ne!(
+            get_fingerprint(serde_json::json!({ "amount": 100 })),
+            get_fingerprint(serde_json::json!({ "amount": 200 }))
+        );
+    }
+
+    #[test]
+    fn test_completed_idempotency_record_keeps_status_and_headers() {
+        let record = IdempotencyRecord::Completed {
+            request_fingerprint: "fingerprint".to_string(),
+            status_code: 200,
+            headers: vec![IdempotencyRecordHeader {
+                name: "x-request-id".to_string(),
+                value: "request_id".to_string(),
+                is_sensitive: false,
+            }],
+            response: r#"{"status":"succeeded"}"#.to_string(),
+        };
+
+        let deserialized_record: IdempotencyRecord =
+            serde_json::from_str(&serde_json::to_string(&record).unwrap()).unwrap();
+
+        assert!(matches!(
+            deserialized_record,
+            IdempotencyRecord::Completed {
+                status_code: 200,
+                ref headers,
+                ref response,
+                ..
+            } if headers.first().map(|header| header.name.as_str()) == Some("x-request-id")
+                && response == r#"{"status":"succeeded"}"#
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_stored_error_response_keeps_status_and_body() {
+        let (status_code, _, response) =
+            get_stored_error_response(&errors::ApiErrorResponse::InternalServerError)
+                .await
+                .unwrap();
+
+        assert_eq!(status_code, 500);
+        assert!(response.contains("HE_00"));
+    }
+
+    #[test]
+    fn test_only_request_validation_errors_release_idempotency_key() {
+        use common_utils::errors::ReportSwitchExt;
+
+        let get_switched_error = |error: errors::ApiErrorResponse| {
+            let result: Result<
+                (),
+                error_stack::Report<api_models::errors::types::ApiErrorResponse>,
+            > = Err(report!(error)).switch();
+            result.unwrap_err()
+        };
+
+        assert!(is_request_validation_error(&get_switched_error(
+            errors::ApiErrorResponse::MissingRequiredField {
+                field_name: "amount",
+            }
+        )));
+        assert!(!is_request_validation_error(&get_switched_error(
+            errors::ApiErrorResponse::PaymentNotFound
+        )));
+        assert!(!is_request_validation_error(
+            &report!(errors::ApiErrorResponse::MissingRequiredField {
+                field_name: "amount",
+            })
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+        ));
+    }
+}
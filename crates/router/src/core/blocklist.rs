@@ -1,6 +1,7 @@
 pub mod transformers;
 pub mod utils;
 
+use actix_web::http::header::HeaderMap;
 use api_models::blocklist as api_blocklist;
 
 use crate::{
@@ -14,14 +15,14 @@ pub async fn add_entry_to_blocklist(
     state: SessionState,
     merchant_context: domain::MerchantContext,
     body: api_blocklist::AddToBlocklistRequest,
+    request_headers: &HeaderMap,
 ) -> RouterResponse<api_blocklist::AddToBlocklistResponse> {
-    utils::insert_entry_into_blocklist(
-        &state,
-        merchant_context.get_merchant_account().get_id(),
-        body,
-    )
-    .await
-    .map(services::ApplicationResponse::Json)
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    let created_by = utils::get_blocklist_entry_creator(&state, request_headers).await?;
+
+    utils::insert_entry_into_blocklist(&state, merchant_id, body, created_by)
+        .await
+        .map(services::ApplicationResponse::Json)
 }
 
 pub async fn remove_entry_from_blocklist(
@@ -24,10 +24,19 @@ const LOCKER_FINGERPRINT_PATH: &str = "/cards/fingerprint";
 
 impl ForeignFrom<storage::Blocklist> for blocklist::AddToBlocklistResponse {
     fn foreign_from(from: storage::Blocklist) -> Self {
+        let status = if super::utils::is_blocklist_entry_active(&from) {
+            api_enums::BlocklistEntryStatus::Active
+        } else {
+            api_enums::BlocklistEntryStatus::Expired
+        };
         Self {
             fingerprint_id: from.fingerprint_id,
             data_kind: from.data_kind,
             created_at: from.created_at,
+            expires_at: from.expires_at,
+            reason: from.reason,
+            created_by: from.created_by,
+            status,
         }
     }
 }
@@ -1,6 +1,7 @@
+use actix_web::http::header::HeaderMap;
 use api_models::blocklist as api_blocklist;
 use common_enums::MerchantDecision;
-use common_utils::errors::CustomResult;
+use common_utils::{date_time, errors::CustomResult, types::CreatedBy};
 use diesel_models::configs;
 use error_stack::ResultExt;
 use masking::StrongSecret;
@@ -13,10 +14,18 @@ use crate::{
         payments::PaymentData,
     },
     logger,
+    routes::metrics,
+    services::authentication,
     types::{domain, storage, transformers::ForeignInto},
     utils,
 };
 
+const BLOCKLIST_ENTRY_EXPIRY_TASK: &str = "BLOCKLIST_ENTRY_EXPIRY";
+const BLOCKLIST_ENTRY_EXPIRY_TAG: &str = "BLOCKLIST";
+const BLOCKLIST_ENTRY_EXPIRY_RUNNER: storage::ProcessTrackerRunner =
+    storage::ProcessTrackerRunner::BlocklistEntryExpiryWorkflow;
+const BLOCKLIST_ENTRY_REASON_MAX_LENGTH: usize = 255;
+
 pub async fn delete_entry_from_blocklist(
     state: &SessionState,
     merchant_id: &common_utils::id_type::MerchantId,
@@ -94,9 +103,10 @@ pub async fn list_blocklist_entries_for_merchant(
 ) -> RouterResult<Vec<api_blocklist::BlocklistResponse>> {
     state
         .store
-        .list_blocklist_entries_by_merchant_id_data_kind(
+        .list_blocklist_entries_by_merchant_id_constraints(
             merchant_id,
             query.data_kind,
+            query.status,
             query.limit.into(),
             query.offset.into(),
         )
@@ -131,75 +141,169 @@ fn validate_extended_card_bin(bin: &str) -> RouterResult<()> {
     }
 }
 
+fn validate_blocklist_entry_expiry_and_reason(
+    request: &api_blocklist::AddToBlocklistRequest,
+) -> RouterResult<()> {
+    if request
+        .expires_at
+        .is_some_and(|expires_at| expires_at <= date_time::now())
+    {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "expires_at must be a time in the future".to_string(),
+        }
+        .into());
+    }
+
+    if request
+        .reason
+        .as_ref()
+        .is_some_and(|reason| reason.len() > BLOCKLIST_ENTRY_REASON_MAX_LENGTH)
+    {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "reason must not exceed {BLOCKLIST_ENTRY_REASON_MAX_LENGTH} characters"
+            ),
+        }
+        .into());
+    }
+
+    Ok(())
+}
+
+/// An entry is blocked until it expires, even if the expiry workflow is yet to mark it as expired.
+pub fn is_blocklist_entry_active(blocklist_entry: &storage::Blocklist) -> bool {
+    blocklist_entry.status == common_enums::BlocklistEntryStatus::Active
+        && blocklist_entry
+            .expires_at
+            .map_or(true, |expires_at| expires_at > date_time::now())
+}
+
+/// Identify the creator of a blocklist entry from the credentials used to authenticate the
+/// request, which have already been verified by the time the entry is created.
+pub async fn get_blocklist_entry_creator(
+    state: &SessionState,
+    request_headers: &HeaderMap,
+) -> RouterResult<CreatedBy> {
+    if authentication::is_jwt_auth(request_headers) {
+        let payload = authentication::parse_jwt_payload::<_, authentication::AuthToken>(
+            request_headers,
+            state,
+        )
+        .await?;
+        Ok(CreatedBy::Jwt {
+            user_id: payload.user_id,
+        })
+    } else {
+        let key_id = authentication::get_api_key_id(request_headers, state).await?;
+        Ok(CreatedBy::ApiKey {
+            key_id: key_id.get_string_repr().to_owned(),
+        })
+    }
+}
+
 pub async fn insert_entry_into_blocklist(
     state: &SessionState,
     merchant_id: &common_utils::id_type::MerchantId,
     to_block: api_blocklist::AddToBlocklistRequest,
+    created_by: CreatedBy,
 ) -> RouterResult<api_blocklist::AddToBlocklistResponse> {
-    let blocklist_entry = match &to_block {
-        api_blocklist::AddToBlocklistRequest::CardBin(bin) => {
-            validate_card_bin(bin)?;
-            duplicate_check_insert_bin(
-                bin,
-                state,
-                merchant_id,
-                common_enums::BlocklistDataKind::CardBin,
-            )
-            .await?
+    validate_blocklist_entry_expiry_and_reason(&to_block)?;
+
+    let fingerprint_id = to_block.data;
+    let data_kind = match to_block.entry_type {
+        api_blocklist::BlocklistRequestType::CardBin => {
+            validate_card_bin(&fingerprint_id)?;
+            common_enums::BlocklistDataKind::CardBin
         }
 
-        api_blocklist::AddToBlocklistRequest::ExtendedCardBin(bin) => {
-            validate_extended_card_bin(bin)?;
-            duplicate_check_insert_bin(
-                bin,
-                state,
-                merchant_id,
-                common_enums::BlocklistDataKind::ExtendedCardBin,
-            )
-            .await?
+        api_blocklist::BlocklistRequestType::ExtendedCardBin => {
+            validate_extended_card_bin(&fingerprint_id)?;
+            common_enums::BlocklistDataKind::ExtendedCardBin
         }
 
-        api_blocklist::AddToBlocklistRequest::Fingerprint(fingerprint_id) => {
-            let blocklist_entry_result = state
-                .store
-                .find_blocklist_entry_by_merchant_id_fingerprint_id(merchant_id, fingerprint_id)
-                .await;
-
-            match blocklist_entry_result {
-                Ok(_) => {
-                    return Err(errors::ApiErrorResponse::PreconditionFailed {
-                        message: "data associated with the given fingerprint is already blocked"
-                            .to_string(),
-                    }
-                    .into());
-                }
+        api_blocklist::BlocklistRequestType::Fingerprint => {
+            common_enums::BlocklistDataKind::PaymentMethod
+        }
+    };
 
-                // if it is a db not found error, we can proceed as normal
-                Err(inner) if inner.current_context().is_db_not_found() => {}
+    let blocklist_entry = duplicate_check_insert_entry(
+        state,
+        storage::BlocklistNew {
+            merchant_id: merchant_id.to_owned(),
+            fingerprint_id,
+            data_kind,
+            metadata: None,
+            created_at: date_time::now(),
+            expires_at: to_block.expires_at,
+            reason: to_block.reason,
+            created_by: Some(created_by.to_string()),
+            status: common_enums::BlocklistEntryStatus::Active,
+        },
+    )
+    .await?;
 
-                err @ Err(_) => {
-                    err.change_context(errors::ApiErrorResponse::InternalServerError)
-                        .attach_printable("error fetching blocklist entry from table")?;
-                }
-            }
+    if let Some(expires_at) = blocklist_entry.expires_at {
+        // The entry stops being blocked once it expires even if the task could not be added, the
+        // task only updates the status of the entry
+        add_blocklist_entry_expiry_task(state, &blocklist_entry, expires_at)
+            .await
+            .map_err(|error| {
+                logger::error!(?error, "Failed to add blocklist entry expiry task");
+            })
+            .ok();
+    }
 
-            state
-                .store
-                .insert_blocklist_entry(storage::BlocklistNew {
-                    merchant_id: merchant_id.to_owned(),
-                    fingerprint_id: fingerprint_id.clone(),
-                    data_kind: api_models::enums::enums::BlocklistDataKind::PaymentMethod,
-                    metadata: None,
-                    created_at: common_utils::date_time::now(),
-                })
-                .await
-                .change_context(errors::ApiErrorResponse::InternalServerError)
-                .attach_printable("failed to add fingerprint to blocklist")?
-        }
-    };
     Ok(blocklist_entry.foreign_into())
 }
 
+async fn add_blocklist_entry_expiry_task(
+    state: &SessionState,
+    blocklist_entry: &storage::Blocklist,
+    expires_at: time::PrimitiveDateTime,
+) -> RouterResult<()> {
+    let tracking_data = storage::BlocklistEntryExpiryTrackingData {
+        merchant_id: blocklist_entry.merchant_id.clone(),
+        fingerprint_id: blocklist_entry.fingerprint_id.clone(),
+        expires_at,
+    };
+
+    // An entry can be blocked again once it expires, the expiry time keeps the task IDs of the
+    // entries unique
+    let process_tracker_id = format!(
+        "{BLOCKLIST_ENTRY_EXPIRY_RUNNER}_{BLOCKLIST_ENTRY_EXPIRY_TASK}_{}_{}_{}",
+        blocklist_entry.merchant_id.get_string_repr(),
+        blocklist_entry.fingerprint_id,
+        expires_at.assume_utc().unix_timestamp()
+    );
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        BLOCKLIST_ENTRY_EXPIRY_TASK,
+        BLOCKLIST_ENTRY_EXPIRY_RUNNER,
+        [BLOCKLIST_ENTRY_EXPIRY_TAG],
+        tracking_data,
+        None,
+        expires_at,
+        common_types::consts::API_VERSION,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct blocklist entry expiry process tracker task")?;
+
+    state
+        .store
+        .insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable(
+            "Failed while inserting blocklist entry expiry task to process_tracker",
+        )?;
+    metrics::TASKS_ADDED_COUNT.add(
+        1,
+        router_env::metric_attributes!(("flow", "BlocklistEntryExpiry")),
+    );
+
+    Ok(())
+}
+
 pub async fn get_merchant_fingerprint_secret(
     state: &SessionState,
     merchant_id: &common_utils::id_type::MerchantId,
@@ -234,25 +338,49 @@ pub async fn get_merchant_fingerprint_secret(
     }
 }
 
-async fn duplicate_check_insert_bin(
-    bin: &str,
+async fn duplicate_check_insert_entry(
     state: &SessionState,
-    merchant_id: &common_utils::id_type::MerchantId,
-    data_kind: common_enums::BlocklistDataKind,
+    blocklist_new: storage::BlocklistNew,
 ) -> RouterResult<storage::Blocklist> {
     let blocklist_entry_result = state
         .store
-        .find_blocklist_entry_by_merchant_id_fingerprint_id(merchant_id, bin)
+        .find_blocklist_entry_by_merchant_id_fingerprint_id(
+            &blocklist_new.merchant_id,
+            &blocklist_new.fingerprint_id,
+        )
         .await;
 
     match blocklist_entry_result {
-        Ok(_) => {
+        Ok(blocklist_entry) if is_blocklist_entry_active(&blocklist_entry) => {
+            let message = match blocklist_new.data_kind {
+                common_enums::BlocklistDataKind::PaymentMethod => {
+                    "data associated with the given fingerprint is already blocked"
+                }
+                common_enums::BlocklistDataKind::CardBin
+                | common_enums::BlocklistDataKind::ExtendedCardBin => {
+                    "provided bin is already blocked"
+                }
+            };
             return Err(errors::ApiErrorResponse::PreconditionFailed {
-                message: "provided bin is already blocked".to_string(),
+                message: message.to_string(),
             }
             .into());
         }
 
+        // An expired entry is replaced by the new entry
+        Ok(_) => {
+            state
+                .store
+                .delete_blocklist_entry_by_merchant_id_fingerprint_id(
+                    &blocklist_new.merchant_id,
+                    &blocklist_new.fingerprint_id,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("unable to delete expired blocklist entry")?;
+        }
+
+        // if it is a db not found error, we can proceed as normal
         Err(e) if e.current_context().is_db_not_found() => {}
 
         err @ Err(_) => {
@@ -264,16 +392,10 @@ async fn duplicate_check_insert_bin(
 
     state
         .store
-        .insert_blocklist_entry(storage::BlocklistNew {
-            merchant_id: merchant_id.to_owned(),
-            fingerprint_id: bin.to_string(),
-            data_kind,
-            metadata: None,
-            created_at: common_utils::date_time::now(),
-        })
+        .insert_blocklist_entry(blocklist_new)
         .await
         .change_context(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable("error inserting pm blocklist item")
+        .attach_printable("error inserting blocklist entry")
 }
 
 async fn delete_card_bin_blocklist_entry(
@@ -377,8 +499,8 @@ where
     let mut should_payment_be_blocked = false;
     for lookup in blocklist_lookups {
         match lookup {
-            Ok(_) => {
-                should_payment_be_blocked = true;
+            Ok(blocklist_entry) => {
+                should_payment_be_blocked |= is_blocklist_entry_active(&blocklist_entry);
             }
             Err(e) => {
                 logger::error!(blocklist_db_error=?e, "failed db operations for blocklist");
@@ -477,3 +599,68 @@ pub async fn generate_payment_fingerprint(
         },
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_blocklist_entry(
+        status: common_enums::BlocklistEntryStatus,
+        expires_at: Option<time::PrimitiveDateTime>,
+    ) -> storage::Blocklist {
+        storage::Blocklist {
+            merchant_id: common_utils::id_type::MerchantId::default(),
+            fingerprint_id: String::from("411111"),
+            data_kind: common_enums::BlocklistDataKind::CardBin,
+            metadata: None,
+            created_at: date_time::now(),
+            expires_at,
+            reason: None,
+            created_by: None,
+            status,
+        }
+    }
+
+    #[test]
+    fn test_blocklist_entry_is_active_until_expiry() {
+        let past_time = date_time::now() - time::Duration::minutes(1);
+        let future_time = date_time::now() + time::Duration::days(1);
+
+        assert!(is_blocklist_entry_active(&get_blocklist_entry(
+            common_enums::BlocklistEntryStatus::Active,
+            None
+        )));
+        assert!(is_blocklist_entry_active(&get_blocklist_entry(
+            common_enums::BlocklistEntryStatus::Active,
+            Some(future_time)
+        )));
+        // Elapsed entries are not blocked even before the expiry workflow updates their status
+        assert!(!is_blocklist_entry_active(&get_blocklist_entry(
+            common_enums::BlocklistEntryStatus::Active,
+            Some(past_time)
+        )));
+        assert!(!is_blocklist_entry_active(&get_blocklist_entry(
+            common_enums::BlocklistEntryStatus::Expired,
+            Some(past_time)
+        )));
+    }
+
+    #[test]
+    fn test_blocklist_entry_creator_format() {
+        let created_by = CreatedBy::ApiKey {
+            key_id: String::from("dev_ZbwXJzUWtJxOIk7tcaNt"),
+        };
+
+        assert_eq!(created_by.to_string(), "ApiKey:dev_ZbwXJzUWtJxOIk7tcaNt");
+        assert_eq!(
+            "ApiKey:dev_ZbwXJzUWtJxOIk7tcaNt".parse::<CreatedBy>(),
+            Ok(created_by)
+        );
+        assert_eq!(
+            "Jwt:user_123".parse::<CreatedBy>(),
+            Ok(CreatedBy::Jwt {
+                user_id: String::from("user_123")
+            })
+        );
+    }
+}
@@ -1,6 +1,6 @@
 use analytics::errors::AnalyticsError;
 use common_utils::errors::CustomResult;
-use currency_conversion::types::ExchangeRates;
+use currency_conversion::{conversion::MAX_MARKUP_BPS, types::ExchangeRates};
 use error_stack::ResultExt;
 use router_env::logger;
 
@@ -8,7 +8,10 @@ use crate::{
     consts::DEFAULT_ANALYTICS_FOREX_RETRY_ATTEMPTS,
     core::errors::ApiErrorResponse,
     services::ApplicationResponse,
-    utils::currency::{self, convert_currency, get_forex_rates, ForexError as ForexCacheError},
+    utils::currency::{
+        self, convert_currency, convert_currency_with_rounding, get_forex_rates,
+        ForexError as ForexCacheError,
+    },
     SessionState,
 };
 
@@ -46,6 +49,30 @@ pub async fn convert_forex(
     ))
 }
 
+pub async fn convert_forex_with_rounding(
+    state: SessionState,
+    params: api_models::currency::RoundedCurrencyConversionParams,
+) -> CustomResult<
+    ApplicationResponse<api_models::currency::RoundedCurrencyConversionResponse>,
+    ApiErrorResponse,
+> {
+    if let Some(markup_bps) = params
+        .markup_bps
+        .filter(|markup_bps| *markup_bps > MAX_MARKUP_BPS)
+    {
+        return Err(ApiErrorResponse::InvalidRequestData {
+            message: format!("`markup_bps` must be at most {MAX_MARKUP_BPS}, found {markup_bps}"),
+        }
+        .into());
+    }
+
+    Ok(ApplicationResponse::Json(
+        Box::pin(convert_currency_with_rounding(state.clone(), params))
+            .await
+            .change_context(ApiErrorResponse::InternalServerError)?,
+    ))
+}
+
 pub async fn get_forex_exchange_rates(
     state: SessionState,
 ) -> CustomResult<ExchangeRates, AnalyticsError> {
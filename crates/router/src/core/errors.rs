@@ -156,6 +156,16 @@ pub enum WebhooksFlowError {
     OutgoingWebhookRetrySchedulingFailed,
     #[error("Outgoing webhook response encoding failed")]
     OutgoingWebhookResponseEncodingFailed,
+    #[error("Webhook endpoint associated with the event not found")]
+    WebhookEndpointNotFound,
+    #[error("Webhook endpoint associated with the event is disabled")]
+    WebhookEndpointDisabled,
+    #[error("Failed to add outgoing webhook to the delivery queue")]
+    WebhookDeliveryEnqueueFailed,
+    #[error("Failed to perform webhook delivery queue operation")]
+    WebhookDeliveryQueueOperationFailed,
+    #[error("Failed to fetch the details required to deliver the outgoing webhook")]
+    WebhookDeliveryDetailsNotFound,
 }
 
 impl WebhooksFlowError {
@@ -164,7 +174,9 @@ impl WebhooksFlowError {
             Self::MerchantConfigNotFound
             | Self::MerchantWebhookDetailsNotFound
             | Self::MerchantWebhookUrlNotConfigured
-            | Self::OutgoingWebhookResponseEncodingFailed => false,
+            | Self::OutgoingWebhookResponseEncodingFailed
+            | Self::WebhookEndpointNotFound
+            | Self::WebhookEndpointDisabled => false,
 
             Self::WebhookEventUpdationFailed
             | Self::OutgoingWebhookSigningFailed
@@ -173,7 +185,10 @@ impl WebhooksFlowError {
             | Self::DisputeWebhookValidationFailed
             | Self::OutgoingWebhookEncodingFailed
             | Self::OutgoingWebhookProcessTrackerTaskUpdateFailed
-            | Self::OutgoingWebhookRetrySchedulingFailed => true,
+            | Self::OutgoingWebhookRetrySchedulingFailed
+            | Self::WebhookDeliveryEnqueueFailed
+            | Self::WebhookDeliveryQueueOperationFailed
+            | Self::WebhookDeliveryDetailsNotFound => true,
         }
     }
 }
@@ -114,6 +114,18 @@ pub enum UserErrors {
     InvalidCloneConnectorOperation(String),
     #[error("Error cloning connector: {0}")]
     ErrorCloningConnector(String),
+    #[error("PasskeyNotSetup")]
+    PasskeyNotSetup,
+    #[error("InvalidPasskey")]
+    InvalidPasskey,
+    #[error("PasskeyChallengeNotFound")]
+    PasskeyChallengeNotFound,
+    #[error("Maximum attempts reached for Passkey")]
+    MaxPasskeyAttemptsReached,
+    #[error("PasskeyNotFound")]
+    PasskeyNotFound,
+    #[error("PasskeyAlreadyRegistered")]
+    PasskeyAlreadyRegistered,
 }
 
 impl common_utils::errors::ErrorSwitch<api_models::errors::types::ApiErrorResponse> for UserErrors {
@@ -298,6 +310,24 @@ impl common_utils::errors::ErrorSwitch<api_models::errors::types::ApiErrorRespon
                 self.get_error_message(),
                 None,
             )),
+            Self::PasskeyNotSetup => {
+                AER::BadRequest(ApiError::new(sub_code, 60, self.get_error_message(), None))
+            }
+            Self::InvalidPasskey => {
+                AER::BadRequest(ApiError::new(sub_code, 61, self.get_error_message(), None))
+            }
+            Self::PasskeyChallengeNotFound => {
+                AER::BadRequest(ApiError::new(sub_code, 62, self.get_error_message(), None))
+            }
+            Self::MaxPasskeyAttemptsReached => {
+                AER::BadRequest(ApiError::new(sub_code, 63, self.get_error_message(), None))
+            }
+            Self::PasskeyNotFound => {
+                AER::NotFound(ApiError::new(sub_code, 64, self.get_error_message(), None))
+            }
+            Self::PasskeyAlreadyRegistered => {
+                AER::BadRequest(ApiError::new(sub_code, 65, self.get_error_message(), None))
+            }
         }
     }
 }
@@ -374,6 +404,12 @@ impl UserErrors {
             Self::ErrorCloningConnector(error_message) => {
                 format!("Error cloning connector: {}", error_message)
             }
+            Self::PasskeyNotSetup => "Passkey not setup".to_string(),
+            Self::InvalidPasskey => "Invalid Passkey".to_string(),
+            Self::PasskeyChallengeNotFound => "Passkey challenge not found or expired".to_string(),
+            Self::MaxPasskeyAttemptsReached => "Maximum attempts reached for Passkey".to_string(),
+            Self::PasskeyNotFound => "Passkey not found".to_string(),
+            Self::PasskeyAlreadyRegistered => "Passkey already registered".to_string(),
         }
     }
 }
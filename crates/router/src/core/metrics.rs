@@ -44,6 +44,10 @@ counter_metric!(WEBHOOK_OUTGOING_COUNT, GLOBAL_METER);
 counter_metric!(WEBHOOK_OUTGOING_RECEIVED_COUNT, GLOBAL_METER);
 counter_metric!(WEBHOOK_OUTGOING_NOT_RECEIVED_COUNT, GLOBAL_METER);
 counter_metric!(WEBHOOK_PAYMENT_NOT_FOUND, GLOBAL_METER);
+counter_metric!(WEBHOOK_DELIVERY_ENQUEUED_COUNT, GLOBAL_METER); // No. of outgoing webhooks added to the delivery queue
+counter_metric!(WEBHOOK_DELIVERY_DEFERRED_COUNT, GLOBAL_METER); // No. of queued deliveries deferred due to endpoint limits
+counter_metric!(WEBHOOK_DELIVERY_CIRCUIT_OPEN_SKIPPED_COUNT, GLOBAL_METER); // No. of queued deliveries skipped as the endpoint circuit is open
+counter_metric!(WEBHOOK_ENDPOINT_CIRCUIT_OPENED_COUNT, GLOBAL_METER);
 counter_metric!(
     WEBHOOK_EVENT_TYPE_IDENTIFICATION_FAILURE_COUNT,
     GLOBAL_METER
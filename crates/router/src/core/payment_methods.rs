@@ -1,4 +1,8 @@
+#[cfg(feature = "v1")]
+pub mod account_updater;
 pub mod cards;
+#[cfg(feature = "v1")]
+pub mod migration_job;
 pub mod network_tokenization;
 pub mod surcharge_decision_configs;
 #[cfg(all(
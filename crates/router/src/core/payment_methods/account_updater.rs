@@ -0,0 +1,883 @@
+//! Card account updater
+//!
+//! Stored cards which are about to expire are submitted to an account updater service, which
+//! reports the new expiry date or card number issued for a card, or that the account backing the
+//! card was closed. The results are applied to the card in the vault and to the payment method,
+//! and an outgoing webhook is raised for every updated or closed card.
+
+use std::collections::HashMap;
+
+use ::payment_methods::controller::PaymentMethodsController;
+use api_models::payment_methods::{
+    Card, CardDetailsPaymentMethod, PaymentMethodsData, ToggleCardAccountUpdaterQuery,
+    ToggleCardAccountUpdaterResponse,
+};
+use common_utils::{date_time, generate_id, id_type};
+use diesel_models::process_tracker::business_status;
+use error_stack::ResultExt;
+use masking::{ExposeInterface, PeekInterface, Secret};
+use router_env::{instrument, logger, tracing};
+
+use super::cards;
+use crate::{
+    configs::settings::AccountUpdaterConnectorType,
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        webhooks,
+    },
+    routes::{metrics, SessionState},
+    services,
+    types::{
+        api::{self, webhooks as webhook_types},
+        domain,
+        storage::{self, enums},
+    },
+};
+
+const CARD_ACCOUNT_UPDATER_TASK: &str = "CARD_ACCOUNT_UPDATER";
+const CARD_ACCOUNT_UPDATER_TAG: &str = "CARD_ACCOUNT_UPDATER";
+const CARD_ACCOUNT_UPDATER_RUNNER: storage::ProcessTrackerRunner =
+    storage::ProcessTrackerRunner::CardAccountUpdaterWorkflow;
+
+/// Card number for which the mock account updater reports the account as closed
+#[cfg(feature = "dummy_connector")]
+pub const MOCK_CLOSED_ACCOUNT_CARD_NUMBER: &str = "4000000000000002";
+/// Card number which the mock account updater reports as replaced by
+/// [`MOCK_REPLACEMENT_CARD_NUMBER`]
+#[cfg(feature = "dummy_connector")]
+pub const MOCK_REPLACED_CARD_NUMBER: &str = "4000000000000069";
+#[cfg(feature = "dummy_connector")]
+pub const MOCK_REPLACEMENT_CARD_NUMBER: &str = "4242424242424242";
+/// Card number for which the mock account updater reports no updates
+#[cfg(feature = "dummy_connector")]
+pub const MOCK_NO_UPDATE_CARD_NUMBER: &str = "4000000000000077";
+/// The mock account updater extends the expiry of every other card by these many years
+#[cfg(feature = "dummy_connector")]
+const MOCK_EXPIRY_EXTENSION_IN_YEARS: u16 = 3;
+
+/// A stored card submitted to the account updater
+#[derive(Debug, Clone)]
+pub struct AccountUpdaterCard {
+    pub payment_method_id: String,
+    pub card_number: ::cards::CardNumber,
+    pub card_exp_month: Secret<String>,
+    pub card_exp_year: Secret<String>,
+}
+
+/// The result reported by the account updater for a card
+#[derive(Debug, Clone)]
+pub enum AccountUpdaterResult {
+    /// The issuer extended the validity of the card
+    NewExpiry {
+        card_exp_month: Secret<String>,
+        card_exp_year: Secret<String>,
+    },
+    /// The issuer replaced the card with a card having a different number
+    NewAccountNumber {
+        card_number: ::cards::CardNumber,
+        card_exp_month: Secret<String>,
+        card_exp_year: Secret<String>,
+    },
+    /// The account backing the card was closed
+    AccountClosed,
+    /// The issuer has no updates for the card
+    NoUpdate,
+}
+
+/// An account updater service
+#[async_trait::async_trait]
+pub trait AccountUpdaterConnector: Send + Sync {
+    fn connector_type(&self) -> AccountUpdaterConnectorType;
+
+    /// Returns the results for the submitted cards, keyed by the payment method ID of the card.
+    /// Cards without a result are treated as not updated.
+    async fn get_card_updates(
+        &self,
+        state: &SessionState,
+        cards: &[AccountUpdaterCard],
+    ) -> RouterResult<HashMap<String, AccountUpdaterResult>>;
+}
+
+pub fn get_account_updater_connector(
+    connector_type: Option<AccountUpdaterConnectorType>,
+) -> RouterResult<Box<dyn AccountUpdaterConnector>> {
+    let connector_type = connector_type.ok_or(errors::ApiErrorResponse::PreconditionFailed {
+        message: "The card account updater is not configured".to_string(),
+    })?;
+
+    match connector_type {
+        #[cfg(feature = "dummy_connector")]
+        AccountUpdaterConnectorType::Mock => Ok(Box::new(MockAccountUpdater)),
+    }
+}
+
+/// Reports canned results based on the card number, without calling any service
+#[cfg(feature = "dummy_connector")]
+pub struct MockAccountUpdater;
+
+#[cfg(feature = "dummy_connector")]
+#[async_trait::async_trait]
+impl AccountUpdaterConnector for MockAccountUpdater {
+    fn connector_type(&self) -> AccountUpdaterConnectorType {
+        AccountUpdaterConnectorType::Mock
+    }
+
+    async fn get_card_updates(
+        &self,
+        _state: &SessionState,
+        cards: &[AccountUpdaterCard],
+    ) -> RouterResult<HashMap<String, AccountUpdaterResult>> {
+        cards
+            .iter()
+            .map(|card| {
+                get_mock_account_updater_result(card)
+                    .map(|result| (card.payment_method_id.clone(), result))
+            })
+            .collect()
+    }
+}
+
+#[cfg(feature = "dummy_connector")]
+fn get_mock_account_updater_result(
+    card: &AccountUpdaterCard,
+) -> RouterResult<AccountUpdaterResult> {
+    let card_exp_year =
+        extend_card_expiry_year(&card.card_exp_year, MOCK_EXPIRY_EXTENSION_IN_YEARS)
+            .ok_or(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable(
+                "Invalid expiry year for the card submitted to the account updater",
+            )?;
+
+    match card.card_number.get_card_no().as_str() {
+        MOCK_CLOSED_ACCOUNT_CARD_NUMBER => Ok(AccountUpdaterResult::AccountClosed),
+        MOCK_NO_UPDATE_CARD_NUMBER => Ok(AccountUpdaterResult::NoUpdate),
+        MOCK_REPLACED_CARD_NUMBER => Ok(AccountUpdaterResult::NewAccountNumber {
+            card_number: MOCK_REPLACEMENT_CARD_NUMBER
+                .parse()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Invalid replacement card number in the mock account updater")?,
+            card_exp_month: card.card_exp_month.clone(),
+            card_exp_year,
+        }),
+        _ => Ok(AccountUpdaterResult::NewExpiry {
+            card_exp_month: card.card_exp_month.clone(),
+            card_exp_year,
+        }),
+    }
+}
+
+/// Parses the expiry year of a card, which may be in the two or the four digit format
+fn get_card_expiry_year(card_exp_year: &Secret<String>) -> Option<u16> {
+    let year = card_exp_year.peek().trim();
+    let parsed_year = year.parse::<u16>().ok()?;
+    match year.len() {
+        2 => Some(2000 + parsed_year),
+        4 => Some(parsed_year),
+        _ => None,
+    }
+}
+
+/// Adds the given number of years to the expiry year of a card, retaining the format of the year
+#[cfg(feature = "dummy_connector")]
+fn extend_card_expiry_year(card_exp_year: &Secret<String>, years: u16) -> Option<Secret<String>> {
+    let extended_year = get_card_expiry_year(card_exp_year)?.checked_add(years)?;
+    let extended_year = if card_exp_year.peek().trim().len() == 2 {
+        format!("{:02}", extended_year % 100)
+    } else {
+        extended_year.to_string()
+    };
+    Some(Secret::new(extended_year))
+}
+
+/// Whether the card expires in or before the month in which `expiry_cutoff` falls. Cards which
+/// have already expired are included, since issuers report updates for them as well.
+fn is_card_expiring_by(
+    card_exp_month: &Secret<String>,
+    card_exp_year: &Secret<String>,
+    expiry_cutoff: time::PrimitiveDateTime,
+) -> bool {
+    let expiry_month = card_exp_month.peek().trim().parse::<u8>().ok();
+    let expiry_year = get_card_expiry_year(card_exp_year);
+
+    match (expiry_year, expiry_month) {
+        (Some(year), Some(month)) => {
+            let cutoff_year = u16::try_from(expiry_cutoff.year()).unwrap_or(u16::MAX);
+            (year, month) <= (cutoff_year, u8::from(expiry_cutoff.month()))
+        }
+        _ => false,
+    }
+}
+
+fn get_card_details(payment_method: &domain::PaymentMethod) -> Option<CardDetailsPaymentMethod> {
+    payment_method
+        .payment_method_data
+        .clone()
+        .map(|payment_method_data| payment_method_data.into_inner().expose())
+        .and_then(|value| serde_json::from_value::<PaymentMethodsData>(value).ok())
+        .and_then(|payment_method_data| match payment_method_data {
+            PaymentMethodsData::Card(card_details) => Some(card_details),
+            _ => None,
+        })
+}
+
+/// Builds the payment method object sent in the outgoing webhooks, from the card details stored
+/// with the payment method rather than the vault, since the card of a closed account is removed
+/// from the vault
+pub fn get_payment_method_response(
+    payment_method: &domain::PaymentMethod,
+) -> api::PaymentMethodResponse {
+    let card = get_card_details(payment_method).map(|card_details| {
+        let mut card = api::CardDetailFromLocker::from(card_details);
+        card.scheme.clone_from(&payment_method.scheme);
+        card
+    });
+
+    api::PaymentMethodResponse {
+        merchant_id: payment_method.merchant_id.clone(),
+        customer_id: Some(payment_method.customer_id.clone()),
+        payment_method_id: payment_method.payment_method_id.clone(),
+        payment_method: payment_method.get_payment_method_type(),
+        payment_method_type: payment_method.get_payment_method_subtype(),
+        #[cfg(feature = "payouts")]
+        bank_transfer: None,
+        card,
+        metadata: payment_method.metadata.clone(),
+        created: Some(payment_method.created_at),
+        recurring_enabled: false,
+        installment_payment_enabled: false,
+        payment_experience: Some(vec![api_models::enums::PaymentExperience::RedirectToUrl]),
+        last_used_at: Some(payment_method.last_used_at),
+        client_secret: None,
+    }
+}
+
+/// Counts of the cards processed by a run of the card account updater for a merchant
+#[derive(Debug, Default)]
+pub struct CardAccountUpdaterSummary {
+    pub submitted: usize,
+    pub updated: usize,
+    pub closed: usize,
+    pub failed: usize,
+}
+
+struct ExpiringCard {
+    payment_method: domain::PaymentMethod,
+    card_details: CardDetailsPaymentMethod,
+    card: Card,
+}
+
+/// Submits the active cards of the merchant which expire within the configured window to the
+/// account updater, and applies the results to the cards. A failure to apply the result for a
+/// card does not stop the remaining cards from being processed.
+#[instrument(skip_all)]
+pub async fn update_expiring_cards(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    business_profile: &domain::Profile,
+) -> RouterResult<CardAccountUpdaterSummary> {
+    let config = &state.conf.card_account_updater;
+    let connector = get_account_updater_connector(config.connector)?;
+    let expiry_cutoff = date_time::now().saturating_add(time::Duration::days(i64::from(
+        config.expiry_window_in_days,
+    )));
+    let batch_size = i64::from(config.batch_size);
+    let db = &*state.store;
+    let key_manager_state = &state.into();
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+
+    let mut summary = CardAccountUpdaterSummary::default();
+    let mut offset = 0;
+
+    loop {
+        let payment_methods = match db
+            .find_payment_method_by_merchant_id_payment_method_status(
+                key_manager_state,
+                merchant_context.get_merchant_key_store(),
+                merchant_id,
+                enums::PaymentMethod::Card,
+                enums::PaymentMethodStatus::Active,
+                Some(batch_size),
+                Some(offset),
+            )
+            .await
+        {
+            Ok(payment_methods) => payment_methods,
+            Err(error) if error.current_context().is_db_not_found() => Vec::new(),
+            Err(error) => {
+                return Err(error)
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to fetch the stored cards of the merchant")
+            }
+        };
+        let fetched_count = payment_methods.len();
+
+        let mut expiring_cards = Vec::new();
+        for payment_method in payment_methods {
+            let Some(card_details) = get_card_details(&payment_method) else {
+                continue;
+            };
+            let is_card_expiring = card_details
+                .expiry_month
+                .as_ref()
+                .zip(card_details.expiry_year.as_ref())
+                .is_some_and(|(card_exp_month, card_exp_year)| {
+                    is_card_expiring_by(card_exp_month, card_exp_year, expiry_cutoff)
+                });
+            if !is_card_expiring {
+                continue;
+            }
+
+            match cards::get_card_from_locker(
+                state,
+                &payment_method.customer_id,
+                &payment_method.merchant_id,
+                payment_method
+                    .locker_id
+                    .as_ref()
+                    .unwrap_or(&payment_method.payment_method_id),
+            )
+            .await
+            {
+                Ok(card) => expiring_cards.push(ExpiringCard {
+                    payment_method,
+                    card_details,
+                    card,
+                }),
+                Err(error) => {
+                    logger::error!(
+                        ?error,
+                        payment_method_id = %payment_method.payment_method_id,
+                        "Failed to fetch the card to be submitted to the account updater"
+                    );
+                    summary.failed += 1;
+                }
+            }
+        }
+
+        let mut closed_count = 0;
+        if !expiring_cards.is_empty() {
+            let account_updater_cards = expiring_cards
+                .iter()
+                .map(|expiring_card| AccountUpdaterCard {
+                    payment_method_id: expiring_card.payment_method.payment_method_id.clone(),
+                    card_number: expiring_card.card.card_number.clone(),
+                    card_exp_month: expiring_card.card.card_exp_month.clone(),
+                    card_exp_year: expiring_card.card.card_exp_year.clone(),
+                })
+                .collect::<Vec<_>>();
+            summary.submitted += account_updater_cards.len();
+
+            let mut results = connector
+                .get_card_updates(state, &account_updater_cards)
+                .await
+                .attach_printable_lazy(|| {
+                    format!(
+                        "Failed to get the card updates from the {} account updater",
+                        connector.connector_type()
+                    )
+                })?;
+
+            for expiring_card in expiring_cards {
+                let payment_method_id = expiring_card.payment_method.payment_method_id.clone();
+                let result = results
+                    .remove(&payment_method_id)
+                    .unwrap_or(AccountUpdaterResult::NoUpdate);
+
+                match apply_account_updater_result(
+                    state,
+                    merchant_context,
+                    business_profile,
+                    expiring_card,
+                    result,
+                )
+                .await
+                {
+                    Ok(Some(enums::EventType::PaymentMethodClosed)) => {
+                        summary.closed += 1;
+                        closed_count += 1;
+                    }
+                    Ok(Some(_)) => summary.updated += 1,
+                    Ok(None) => {}
+                    Err(error) => {
+                        logger::error!(
+                            ?error,
+                            %payment_method_id,
+                            "Failed to apply the account updater result to the card"
+                        );
+                        summary.failed += 1;
+                    }
+                }
+            }
+        }
+
+        if i64::try_from(fetched_count).unwrap_or(i64::MAX) < batch_size {
+            break;
+        }
+        // Closed cards are no longer active, and are not a part of the following pages
+        offset += i64::try_from(fetched_count - closed_count)
+            .change_context(errors::ApiErrorResponse::InternalServerError)?;
+    }
+
+    Ok(summary)
+}
+
+/// Applies the result to the card, returning the event raised for the payment method if it was
+/// updated
+async fn apply_account_updater_result(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    business_profile: &domain::Profile,
+    expiring_card: ExpiringCard,
+    result: AccountUpdaterResult,
+) -> RouterResult<Option<enums::EventType>> {
+    let (payment_method, event_type) = match result {
+        AccountUpdaterResult::NoUpdate => return Ok(None),
+        AccountUpdaterResult::AccountClosed => (
+            close_card_account(state, merchant_context, expiring_card.payment_method).await?,
+            enums::EventType::PaymentMethodClosed,
+        ),
+        AccountUpdaterResult::NewExpiry {
+            card_exp_month,
+            card_exp_year,
+        } => (
+            update_card(
+                state,
+                merchant_context,
+                expiring_card,
+                None,
+                card_exp_month,
+                card_exp_year,
+            )
+            .await?,
+            enums::EventType::PaymentMethodUpdated,
+        ),
+        AccountUpdaterResult::NewAccountNumber {
+            card_number,
+            card_exp_month,
+            card_exp_year,
+        } => (
+            update_card(
+                state,
+                merchant_context,
+                expiring_card,
+                Some(card_number),
+                card_exp_month,
+                card_exp_year,
+            )
+            .await?,
+            enums::EventType::PaymentMethodUpdated,
+        ),
+    };
+
+    // The card has been updated by now, a failure to raise the webhook is not a failure to
+    // update the card
+    let webhook_result = Box::pin(webhooks::create_event_and_trigger_outgoing_webhook(
+        state.clone(),
+        merchant_context.clone(),
+        business_profile.clone(),
+        event_type,
+        enums::EventClass::PaymentMethods,
+        payment_method.payment_method_id.clone(),
+        enums::EventObjectType::PaymentMethodDetails,
+        webhook_types::OutgoingWebhookContent::PaymentMethodDetails(Box::new(
+            get_payment_method_response(&payment_method),
+        )),
+        Some(payment_method.created_at),
+    ))
+    .await;
+    if let Err(error) = webhook_result {
+        logger::error!(
+            ?error,
+            payment_method_id = %payment_method.payment_method_id,
+            "Failed to trigger the outgoing webhook for the card updated by the account updater"
+        );
+    }
+
+    Ok(Some(event_type))
+}
+
+/// Stores the card having the new number or expiry in the vault and switches the payment method
+/// over to it, before removing the previous card from the vault. The payment method keeps pointing
+/// to a card in the vault if any of the steps fail.
+async fn update_card(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    expiring_card: ExpiringCard,
+    new_card_number: Option<::cards::CardNumber>,
+    card_exp_month: Secret<String>,
+    card_exp_year: Secret<String>,
+) -> RouterResult<domain::PaymentMethod> {
+    let ExpiringCard {
+        payment_method,
+        card_details,
+        card,
+    } = expiring_card;
+    let locker_id = payment_method
+        .locker_id
+        .clone()
+        .unwrap_or(payment_method.payment_method_id.clone());
+
+    let updated_card = api::CardDetail {
+        card_number: new_card_number.clone().unwrap_or(card.card_number),
+        card_exp_month: card_exp_month.clone(),
+        card_exp_year: card_exp_year.clone(),
+        card_holder_name: card.name_on_card,
+        nick_name: card.nick_name.map(Secret::new),
+        card_issuing_country: None,
+        card_network: None,
+        card_issuer: None,
+        card_type: None,
+    };
+    let new_pm = api::PaymentMethodCreate {
+        payment_method: payment_method.get_payment_method_type(),
+        payment_method_type: payment_method.get_payment_method_subtype(),
+        payment_method_issuer: payment_method.payment_method_issuer.clone(),
+        payment_method_issuer_code: payment_method.payment_method_issuer_code,
+        #[cfg(feature = "payouts")]
+        bank_transfer: None,
+        card: Some(updated_card.clone()),
+        #[cfg(feature = "payouts")]
+        wallet: None,
+        metadata: None,
+        customer_id: Some(payment_method.customer_id.clone()),
+        client_secret: None,
+        payment_method_data: None,
+        card_network: None,
+        billing: None,
+        connector_mandate_details: None,
+        network_transaction_id: None,
+    };
+
+    let pm_cards = cards::PmCards {
+        state,
+        merchant_context,
+    };
+    let new_locker_id = generate_id(consts::ID_LENGTH, "card");
+    let (add_card_response, _) = Box::pin(pm_cards.add_card_to_locker(
+        new_pm,
+        &updated_card,
+        &payment_method.customer_id,
+        Some(&new_locker_id),
+    ))
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to add the updated card to the locker")?;
+    let new_locker_id = add_card_response.payment_method_id;
+
+    let updated_card_details = match new_card_number {
+        Some(card_number) => CardDetailsPaymentMethod {
+            last4_digits: Some(card_number.get_last4()),
+            card_isin: Some(card_number.get_card_isin()),
+            expiry_month: Some(card_exp_month),
+            expiry_year: Some(card_exp_year),
+            co_badged_card_data: None,
+            ..card_details
+        },
+        None => CardDetailsPaymentMethod {
+            expiry_month: Some(card_exp_month),
+            expiry_year: Some(card_exp_year),
+            ..card_details
+        },
+    };
+    let key_manager_state = &state.into();
+    let payment_method_data = cards::create_encrypted_data(
+        key_manager_state,
+        merchant_context.get_merchant_key_store(),
+        PaymentMethodsData::Card(updated_card_details),
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Unable to encrypt payment method data")?;
+
+    let payment_method = state
+        .store
+        .update_payment_method(
+            key_manager_state,
+            merchant_context.get_merchant_key_store(),
+            payment_method,
+            storage::PaymentMethodUpdate::AdditionalDataUpdate {
+                payment_method_data: Some(payment_method_data.into()),
+                status: None,
+                locker_id: Some(new_locker_id.clone()),
+                payment_method: None,
+                payment_method_type: None,
+                payment_method_issuer: None,
+                network_token_requestor_reference_id: None,
+                network_token_locker_id: None,
+                network_token_payment_method_data: None,
+            },
+            merchant_context.get_merchant_account().storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update payment method in db")?;
+
+    // The payment method no longer refers to the previous card, which is only removed from the
+    // vault since it is of no further use. The locker returns the reference of the previous card
+    // when it considers the updated card a duplicate of it.
+    if new_locker_id != locker_id {
+        let delete_result = pm_cards
+            .delete_card_from_locker(
+                &payment_method.customer_id,
+                &payment_method.merchant_id,
+                &locker_id,
+            )
+            .await;
+        if let Err(error) = delete_result {
+            logger::error!(
+                ?error,
+                payment_method_id = %payment_method.payment_method_id,
+                "Failed to delete the previous card from the locker"
+            );
+        }
+    }
+
+    Ok(payment_method)
+}
+
+/// Marks the payment method inactive and removes the card from the vault
+async fn close_card_account(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    payment_method: domain::PaymentMethod,
+) -> RouterResult<domain::PaymentMethod> {
+    let locker_id = payment_method
+        .locker_id
+        .clone()
+        .unwrap_or(payment_method.payment_method_id.clone());
+
+    let payment_method = state
+        .store
+        .update_payment_method(
+            &state.into(),
+            merchant_context.get_merchant_key_store(),
+            payment_method,
+            storage::PaymentMethodUpdate::StatusUpdate {
+                status: Some(enums::PaymentMethodStatus::Inactive),
+            },
+            merchant_context.get_merchant_account().storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update payment method in db")?;
+
+    // The payment method can no longer be used for payments once it is inactive, the card is
+    // removed from the vault only since it is of no further use
+    let delete_result = cards::PmCards {
+        state,
+        merchant_context,
+    }
+    .delete_card_from_locker(
+        &payment_method.customer_id,
+        &payment_method.merchant_id,
+        &locker_id,
+    )
+    .await;
+    if let Err(error) = delete_result {
+        logger::error!(
+            ?error,
+            payment_method_id = %payment_method.payment_method_id,
+            "Failed to delete the card of the closed account from the locker"
+        );
+    }
+
+    Ok(payment_method)
+}
+
+fn generate_task_id_for_card_account_updater_workflow(merchant_id: &id_type::MerchantId) -> String {
+    format!(
+        "{CARD_ACCOUNT_UPDATER_RUNNER}_{CARD_ACCOUNT_UPDATER_TASK}_{}",
+        merchant_id.get_string_repr()
+    )
+}
+
+/// Enables or disables the recurring card account updater task of the merchant. Enabling the
+/// task schedules a run right away.
+#[instrument(skip_all)]
+pub async fn toggle_card_account_updater(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    query: ToggleCardAccountUpdaterQuery,
+) -> RouterResponse<ToggleCardAccountUpdaterResponse> {
+    let db = &*state.store;
+    let merchant_account = merchant_context.get_merchant_account();
+    let merchant_id = merchant_account.get_id();
+    let process_tracker_id = generate_task_id_for_card_account_updater_workflow(merchant_id);
+
+    let existing_process = db
+        .find_process_by_id(&process_tracker_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to retrieve the card account updater task")?;
+
+    if query.status {
+        // The task would fail on every run without an account updater to submit the cards to
+        get_account_updater_connector(state.conf.card_account_updater.connector)?;
+
+        let profile_id = query
+            .profile_id
+            .or(merchant_account.default_profile.clone())
+            .ok_or(errors::ApiErrorResponse::MissingRequiredField {
+                field_name: "profile_id",
+            })?;
+        db.find_business_profile_by_merchant_id_profile_id(
+            &(&state).into(),
+            merchant_context.get_merchant_key_store(),
+            merchant_id,
+            &profile_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: profile_id.get_string_repr().to_owned(),
+        })?;
+
+        let tracking_data = storage::CardAccountUpdaterTrackingData {
+            merchant_id: merchant_id.clone(),
+            profile_id,
+        };
+        let schedule_time = date_time::now();
+
+        match existing_process {
+            Some(process) => {
+                let tracking_data = serde_json::to_value(&tracking_data)
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable(
+                        "Failed to serialize the card account updater tracking data",
+                    )?;
+                db.update_process(
+                    process,
+                    storage::ProcessTrackerUpdate::Update {
+                        name: None,
+                        retry_count: Some(0),
+                        schedule_time: Some(schedule_time),
+                        tracking_data: Some(tracking_data),
+                        business_status: Some(String::from(business_status::PENDING)),
+                        status: Some(enums::ProcessTrackerStatus::New),
+                        updated_at: Some(schedule_time),
+                    },
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to enable the card account updater task")?;
+            }
+            None => {
+                let process_tracker_entry = storage::ProcessTrackerNew::new(
+                    process_tracker_id,
+                    CARD_ACCOUNT_UPDATER_TASK,
+                    CARD_ACCOUNT_UPDATER_RUNNER,
+                    [CARD_ACCOUNT_UPDATER_TAG],
+                    tracking_data,
+                    None,
+                    schedule_time,
+                    common_types::consts::API_VERSION,
+                )
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable(
+                    "Failed to construct card account updater process tracker task",
+                )?;
+
+                db.insert_process(process_tracker_entry)
+                    .await
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable(
+                        "Failed while inserting card account updater task to process_tracker",
+                    )?;
+                metrics::TASKS_ADDED_COUNT.add(
+                    1,
+                    router_env::metric_attributes!(("flow", "CardAccountUpdater")),
+                );
+            }
+        }
+    } else if let Some(process) =
+        existing_process.filter(|process| process.status != enums::ProcessTrackerStatus::Finish)
+    {
+        db.as_scheduler()
+            .finish_process_with_business_status(process, business_status::REVOKED)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to disable the card account updater task")?;
+    }
+
+    let card_account_updater_status = if query.status { "enabled" } else { "disabled" };
+    Ok(services::ApplicationResponse::Json(
+        ToggleCardAccountUpdaterResponse {
+            card_account_updater_status: card_account_updater_status.to_string(),
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_card_expiring_by_cutoff() {
+        let expiry_cutoff = time::macros::datetime!(2025-07-10 0:00);
+
+        assert!(is_card_expiring_by(
+            &Secret::new("07".to_string()),
+            &Secret::new("25".to_string()),
+            expiry_cutoff
+        ));
+        assert!(is_card_expiring_by(
+            &Secret::new("12".to_string()),
+            &Secret::new("2024".to_string()),
+            expiry_cutoff
+        ));
+        assert!(!is_card_expiring_by(
+            &Secret::new("08".to_string()),
+            &Secret::new("2025".to_string()),
+            expiry_cutoff
+        ));
+        assert!(!is_card_expiring_by(
+            &Secret::new("1".to_string()),
+            &Secret::new("202".to_string()),
+            expiry_cutoff
+        ));
+    }
+
+    #[cfg(feature = "dummy_connector")]
+    #[test]
+    fn test_extend_card_expiry_year_retains_format() {
+        let extended_year = extend_card_expiry_year(&Secret::new("25".to_string()), 3).unwrap();
+        assert_eq!(extended_year.peek(), "28");
+
+        let extended_year = extend_card_expiry_year(&Secret::new("2099".to_string()), 3).unwrap();
+        assert_eq!(extended_year.peek(), "2102");
+    }
+
+    #[cfg(feature = "dummy_connector")]
+    #[test]
+    fn test_mock_account_updater_results() {
+        let get_result = |card_number: &str| {
+            get_mock_account_updater_result(&AccountUpdaterCard {
+                payment_method_id: "pm_123".to_string(),
+                card_number: card_number.parse().unwrap(),
+                card_exp_month: Secret::new("07".to_string()),
+                card_exp_year: Secret::new("2025".to_string()),
+            })
+            .unwrap()
+        };
+
+        assert!(matches!(
+            get_result(MOCK_CLOSED_ACCOUNT_CARD_NUMBER),
+            AccountUpdaterResult::AccountClosed
+        ));
+        assert!(matches!(
+            get_result(MOCK_NO_UPDATE_CARD_NUMBER),
+            AccountUpdaterResult::NoUpdate
+        ));
+        assert!(matches!(
+            get_result(MOCK_REPLACED_CARD_NUMBER),
+            AccountUpdaterResult::NewAccountNumber { card_number, .. }
+                if card_number.get_card_no() == MOCK_REPLACEMENT_CARD_NUMBER
+        ));
+        assert!(matches!(
+            get_result("4111111111111111"),
+            AccountUpdaterResult::NewExpiry { card_exp_year, .. }
+                if card_exp_year.peek() == "2028"
+        ));
+    }
+}
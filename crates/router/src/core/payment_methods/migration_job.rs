@@ -0,0 +1,1036 @@
+//! Payment method migration jobs
+//!
+//! A CSV export of the payment methods stored with another processor is uploaded as a migration
+//! job. Every row of the file is validated upfront, covering the card number, the card expiry, the
+//! customer and the connector mandate reference of the row, and is stored as an item of the job
+//! along with the reason it failed validation. Rows are first validated without any lookups, the
+//! customers of the valid rows are then looked up once each, and the records of the valid rows are
+//! encrypted in chunks, so that large files do not take a call per row. A dry run job stops after validation, so that the
+//! result of every row can be reviewed before the job is executed. The payment methods of the
+//! valid rows are migrated asynchronously by the payment method migration workflow.
+
+use std::{
+    collections::{HashMap, HashSet},
+    str::FromStr,
+};
+
+use ::payment_methods::core::migration;
+use actix_multipart::form::{bytes::Bytes, text::Text, MultipartForm};
+use api_models::payment_methods::{
+    PaymentMethodMigrate, PaymentMethodMigrationJobReportRecord, PaymentMethodMigrationJobRequest,
+    PaymentMethodMigrationJobResponse, PaymentMethodRecord,
+};
+use common_utils::{
+    encryption::Encryption,
+    events::ApiEventMetric,
+    ext_traits::Encode,
+    id_type, type_name,
+    types::keymanager::{Identifier, KeyManagerState},
+};
+use error_stack::{report, ResultExt};
+use futures::stream::{StreamExt, TryStreamExt};
+use masking::{PeekInterface, Secret};
+use router_env::{instrument, logger, tracing};
+use rustc_hash::FxHashMap;
+
+use super::cards;
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payments::helpers,
+        utils::bulk_job,
+    },
+    routes::{metrics, SessionState},
+    services,
+    types::{
+        domain,
+        storage::{self, enums as storage_enums},
+    },
+};
+
+const PAYMENT_METHOD_MIGRATION_TASK: &str = "PAYMENT_METHOD_MIGRATION";
+const PAYMENT_METHOD_MIGRATION_TAG: &str = "PAYMENT_METHOD_MIGRATION";
+const PAYMENT_METHOD_MIGRATION_RUNNER: storage::ProcessTrackerRunner =
+    storage::ProcessTrackerRunner::PaymentMethodMigrationWorkflow;
+
+/// Maximum number of rows accepted in a migration file
+const MIGRATION_JOB_MAX_ROWS: usize = 100_000;
+
+/// Number of items inserted in a single query, kept well within the bind parameter limit of
+/// Postgres
+const MIGRATION_JOB_INSERT_CHUNK_SIZE: usize = 1_000;
+
+/// Number of valid items fetched at a time by the workflow
+const MIGRATION_JOB_PROCESS_CHUNK_SIZE: i64 = 100;
+
+/// Number of payment methods of a job migrated concurrently
+const MIGRATION_JOB_CONCURRENCY: usize = 10;
+
+const INTERRUPTED_MIGRATION_ERROR_MESSAGE: &str =
+    "Migration of the row was interrupted, verify whether the payment method was migrated before retrying the row";
+
+#[derive(Debug, MultipartForm)]
+pub struct PaymentMethodMigrationJobForm {
+    #[multipart(limit = "25MB")]
+    pub file: Bytes,
+    pub merchant_id: Text<id_type::MerchantId>,
+    /// The connector to which the `payment_instrument_id` of the rows belong
+    pub merchant_connector_id: Option<Text<id_type::MerchantConnectorAccountId>>,
+    /// Only validate the rows of the file, the job is executed separately
+    pub dry_run: Option<Text<bool>>,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct PaymentMethodMigrationJobCreateRequest {
+    pub merchant_id: id_type::MerchantId,
+    pub merchant_connector_id: Option<id_type::MerchantConnectorAccountId>,
+    pub dry_run: bool,
+    pub file_name: Option<String>,
+    pub rows: Vec<PaymentMethodMigrationJobRow>,
+}
+
+impl ApiEventMetric for PaymentMethodMigrationJobCreateRequest {}
+
+#[derive(Debug, serde::Serialize)]
+pub struct PaymentMethodMigrationJobRow {
+    pub line_number: i32,
+    /// The record of the row, or the reason the row could not be parsed
+    pub record: Result<PaymentMethodRecord, String>,
+}
+
+pub fn parse_csv(data: &[u8]) -> csv::Result<Vec<Result<PaymentMethodRecord, String>>> {
+    bulk_job::parse_csv_rows::<PaymentMethodRecord>(data)
+}
+
+pub fn get_migration_job_form_request(
+    form: PaymentMethodMigrationJobForm,
+) -> Result<PaymentMethodMigrationJobCreateRequest, errors::ApiErrorResponse> {
+    let rows = parse_csv(form.file.data.as_ref()).map_err(|error| {
+        logger::error!("Failed to parse payment method migration file: {error}");
+        errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("Unable to parse the payment method migration file: {error}"),
+        }
+    })?;
+
+    bulk_job::validate_row_count(
+        rows.len(),
+        MIGRATION_JOB_MAX_ROWS,
+        "payment method migration",
+    )?;
+    logger::info!(
+        "Parsed a total of {} payment method migration rows",
+        rows.len()
+    );
+
+    Ok(PaymentMethodMigrationJobCreateRequest {
+        merchant_id: form.merchant_id.0,
+        merchant_connector_id: form
+            .merchant_connector_id
+            .map(|merchant_connector_id| merchant_connector_id.0),
+        dry_run: form.dry_run.is_some_and(|dry_run| dry_run.0),
+        file_name: form.file.file_name,
+        rows: rows
+            .into_iter()
+            .zip(1..)
+            .map(|(record, line_number)| PaymentMethodMigrationJobRow {
+                line_number,
+                record,
+            })
+            .collect(),
+    })
+}
+
+/// Validates a row of the file the way it would be migrated, so that the rows which would fail
+/// to migrate, or would be migrated without the card being stored, are reported before execution.
+/// The customer of the row is looked up separately, for all the rows at once.
+fn validate_payment_method_record(
+    merchant_id: &id_type::MerchantId,
+    merchant_connector_id: Option<&id_type::MerchantConnectorAccountId>,
+    record: &PaymentMethodRecord,
+) -> RouterResult<()> {
+    if record
+        .merchant_id
+        .as_ref()
+        .is_some_and(|record_merchant_id| record_merchant_id != merchant_id)
+    {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "merchant_id of the row does not match the merchant_id of the job".to_string(),
+        }));
+    }
+
+    match &record.raw_card_number {
+        Some(raw_card_number) => {
+            ::cards::CardNumber::from_str(raw_card_number.peek()).map_err(|error| {
+                report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!("Invalid raw_card_number: {error}"),
+                })
+            })?;
+        }
+        // A masked card cannot be stored in the vault, and is usable only through a connector
+        // mandate reference or a network token
+        None if record.payment_instrument_id.is_none() && record.network_token_number.is_none() => {
+            return Err(report!(errors::ApiErrorResponse::MissingRequiredField {
+                field_name: "raw_card_number",
+            }));
+        }
+        None => (),
+    }
+
+    helpers::validate_card_expiry(&record.card_expiry_month, &record.card_expiry_year)?;
+
+    // The connector mandate reference of the row is built from the payment instrument ID and the
+    // connector of the job
+    if let Some(payment_instrument_id) = &record.payment_instrument_id {
+        if merchant_connector_id.is_none() {
+            return Err(report!(errors::ApiErrorResponse::MissingRequiredField {
+                field_name: "merchant_connector_id",
+            }));
+        }
+        if payment_instrument_id.peek().is_empty() {
+            return Err(report!(errors::ApiErrorResponse::InvalidDataValue {
+                field_name: "payment_instrument_id",
+            }));
+        }
+        if record.original_transaction_amount.is_some()
+            != record.original_transaction_currency.is_some()
+        {
+            return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "original_transaction_amount and original_transaction_currency must be passed together".to_string(),
+            }));
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns the customers out of the given ones which exist, looking up a few of them concurrently
+async fn find_existing_customer_ids(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    customer_ids: HashSet<id_type::CustomerId>,
+) -> RouterResult<HashSet<id_type::CustomerId>> {
+    let key_manager_state = &state.into();
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+
+    futures::stream::iter(customer_ids)
+        .map(|customer_id| async move {
+            state
+                .store
+                .find_customer_optional_by_customer_id_merchant_id(
+                    key_manager_state,
+                    &customer_id,
+                    merchant_id,
+                    merchant_context.get_merchant_key_store(),
+                    merchant_context.get_merchant_account().storage_scheme,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to find customer")
+                .map(|customer| customer.map(|_| customer_id))
+        })
+        .buffer_unordered(MIGRATION_JOB_CONCURRENCY)
+        .try_collect::<Vec<_>>()
+        .await
+        .map(|customer_ids| customer_ids.into_iter().flatten().collect())
+}
+
+/// Encrypts the records of the valid rows of a job, a chunk of rows per call to the key manager,
+/// and returns them by their line number
+async fn encrypt_migration_records<'a>(
+    key_manager_state: &KeyManagerState,
+    key_store: &domain::MerchantKeyStore,
+    records: impl Iterator<Item = (i32, &'a PaymentMethodRecord)>,
+) -> RouterResult<HashMap<i32, Encryption>> {
+    let records = records.collect::<Vec<_>>();
+    let mut encrypted_records = HashMap::with_capacity(records.len());
+
+    for chunk in records.chunks(MIGRATION_JOB_INSERT_CHUNK_SIZE) {
+        let encoded_records = chunk
+            .iter()
+            .map(|(line_number, record)| {
+                record.encode_to_value().map(|encoded_record| {
+                    (
+                        line_number.to_string(),
+                        Secret::<_, masking::WithType>::new(encoded_record),
+                    )
+                })
+            })
+            .collect::<Result<FxHashMap<_, _>, _>>()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Unable to encode the payment method migration records")?;
+
+        let encrypted_chunk = domain::types::crypto_operation(
+            key_manager_state,
+            type_name!(PaymentMethodRecord),
+            domain::types::CryptoOperation::BatchEncrypt(encoded_records),
+            Identifier::Merchant(key_store.merchant_id.clone()),
+            key_store.key.get_inner().peek(),
+        )
+        .await
+        .and_then(|val| val.try_into_batchoperation())
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to encrypt the payment method migration records")?;
+
+        for (line_number, encrypted_record) in encrypted_chunk {
+            let line_number = line_number
+                .parse::<i32>()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Invalid line number of an encrypted migration record")?;
+            encrypted_records.insert(line_number, encrypted_record.into());
+        }
+    }
+
+    Ok(encrypted_records)
+}
+
+/// A dry run job waits for execution once its rows are validated, while any other job is picked up
+/// for migration right away
+fn get_created_job_status(
+    valid_count: usize,
+    dry_run: bool,
+) -> storage_enums::PaymentMethodMigrationJobStatus {
+    if valid_count == 0 {
+        storage_enums::PaymentMethodMigrationJobStatus::Failed
+    } else if dry_run {
+        storage_enums::PaymentMethodMigrationJobStatus::Validated
+    } else {
+        storage_enums::PaymentMethodMigrationJobStatus::Pending
+    }
+}
+
+fn get_migration_job_response(
+    job: storage::PaymentMethodMigrationJob,
+) -> PaymentMethodMigrationJobResponse {
+    PaymentMethodMigrationJobResponse {
+        pending_count: job.valid_count - job.migrated_count - job.failed_count,
+        job_id: job.job_id,
+        merchant_id: job.merchant_id,
+        merchant_connector_id: job.merchant_connector_id,
+        status: job.status,
+        dry_run: job.dry_run,
+        file_name: job.file_name,
+        total_count: job.total_count,
+        valid_count: job.valid_count,
+        invalid_count: job.invalid_count,
+        migrated_count: job.migrated_count,
+        failed_count: job.failed_count,
+        created_at: job.created_at,
+        last_modified_at: job.last_modified_at,
+    }
+}
+
+async fn find_migration_job(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    job_id: &str,
+) -> RouterResult<storage::PaymentMethodMigrationJob> {
+    state
+        .store
+        .find_payment_method_migration_job_by_merchant_id_job_id(merchant_id, job_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Payment method migration job not found".to_string(),
+        })
+}
+
+/// Validates and stores the rows of a migration file. Invalid rows are recorded as invalid items
+/// of the job along with the reason, and do not fail the upload. Unless the job is a dry run, the
+/// migration of the valid rows is scheduled right away.
+#[instrument(skip_all)]
+pub async fn create_migration_job(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    req: PaymentMethodMigrationJobCreateRequest,
+) -> RouterResponse<PaymentMethodMigrationJobResponse> {
+    let db = &*state.store;
+    let key_manager_state = &(&state).into();
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+
+    if let Some(merchant_connector_id) = &req.merchant_connector_id {
+        db.find_by_merchant_connector_account_merchant_id_merchant_connector_id(
+            key_manager_state,
+            merchant_id,
+            merchant_connector_id,
+            merchant_context.get_merchant_key_store(),
+        )
+        .await
+        .to_not_found_response(
+            errors::ApiErrorResponse::MerchantConnectorAccountNotFound {
+                id: merchant_connector_id.get_string_repr().to_string(),
+            },
+        )?;
+    }
+
+    let job_id = common_utils::generate_id(consts::ID_LENGTH, "pm_migration");
+    let now = common_utils::date_time::now();
+    let total_count = i32::try_from(req.rows.len())
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Number of payment method migration rows exceeds i32")?;
+
+    let validated_rows = req
+        .rows
+        .into_iter()
+        .map(|row| {
+            let validated_record = row
+                .record
+                .map_err(|message| {
+                    report!(errors::ApiErrorResponse::InvalidRequestData { message })
+                })
+                .and_then(|record| {
+                    validate_payment_method_record(
+                        merchant_id,
+                        req.merchant_connector_id.as_ref(),
+                        &record,
+                    )
+                    .map(|()| record)
+                });
+            (row.line_number, validated_record)
+        })
+        .collect::<Vec<_>>();
+
+    let customer_ids = validated_rows
+        .iter()
+        .filter_map(|(_, record)| record.as_ref().ok())
+        .map(|record| record.customer_id.clone())
+        .collect();
+    let existing_customer_ids =
+        find_existing_customer_ids(&state, &merchant_context, customer_ids).await?;
+    let validated_rows = validated_rows
+        .into_iter()
+        .map(|(line_number, validated_record)| {
+            let validated_record = validated_record.and_then(|record| {
+                if existing_customer_ids.contains(&record.customer_id) {
+                    Ok(record)
+                } else {
+                    Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                        message: "Customer does not exist, customers must be migrated before their payment methods".to_string(),
+                    }))
+                }
+            });
+            (line_number, validated_record)
+        })
+        .collect::<Vec<_>>();
+
+    let mut encrypted_records = encrypt_migration_records(
+        key_manager_state,
+        merchant_context.get_merchant_key_store(),
+        validated_rows.iter().filter_map(|(line_number, record)| {
+            record.as_ref().ok().map(|record| (*line_number, record))
+        }),
+    )
+    .await?;
+
+    let job_items = validated_rows
+        .into_iter()
+        .map(|(line_number, validated_record)| match validated_record {
+            Ok(record) => {
+                let encrypted_record = encrypted_records
+                    .remove(&line_number)
+                    .ok_or(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Encrypted payment method migration record not found")?;
+
+                Ok(storage::PaymentMethodMigrationJobItemNew {
+                    job_id: job_id.clone(),
+                    line_number,
+                    merchant_id: merchant_id.clone(),
+                    status: storage_enums::PaymentMethodMigrationJobItemStatus::Valid,
+                    record: Some(encrypted_record),
+                    customer_id: Some(record.customer_id),
+                    payment_method_id: None,
+                    error_message: None,
+                    created_at: now,
+                    last_modified_at: now,
+                })
+            }
+            Err(error) => Ok(storage::PaymentMethodMigrationJobItemNew {
+                job_id: job_id.clone(),
+                line_number,
+                merchant_id: merchant_id.clone(),
+                status: storage_enums::PaymentMethodMigrationJobItemStatus::Invalid,
+                record: None,
+                customer_id: None,
+                payment_method_id: None,
+                error_message: Some(error.current_context().error_message()),
+                created_at: now,
+                last_modified_at: now,
+            }),
+        })
+        .collect::<RouterResult<Vec<_>>>()?;
+
+    let invalid_count = job_items
+        .iter()
+        .filter(|item| item.status == storage_enums::PaymentMethodMigrationJobItemStatus::Invalid)
+        .count();
+    let valid_count = job_items.len() - invalid_count;
+    let status = get_created_job_status(valid_count, req.dry_run);
+
+    let job = db
+        .insert_payment_method_migration_job(storage::PaymentMethodMigrationJobNew {
+            job_id: job_id.clone(),
+            merchant_id: merchant_id.clone(),
+            merchant_connector_id: req.merchant_connector_id,
+            status,
+            dry_run: req.dry_run,
+            file_name: req.file_name,
+            total_count,
+            valid_count: i32::try_from(valid_count)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Number of valid payment method migration rows exceeds i32")?,
+            invalid_count: i32::try_from(invalid_count)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Number of invalid payment method migration rows exceeds i32")?,
+            migrated_count: 0,
+            failed_count: 0,
+            created_at: now,
+            last_modified_at: now,
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert payment method migration job")?;
+
+    bulk_job::insert_in_chunks(
+        job_items,
+        MIGRATION_JOB_INSERT_CHUNK_SIZE,
+        |chunk| async move {
+            db.insert_payment_method_migration_job_items(chunk)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to insert payment method migration job items")
+        },
+    )
+    .await?;
+
+    if status == storage_enums::PaymentMethodMigrationJobStatus::Pending {
+        add_payment_method_migration_task(db, &job).await?;
+    }
+
+    Ok(services::ApplicationResponse::Json(
+        get_migration_job_response(job),
+    ))
+}
+
+/// Schedules the migration of the valid rows of a dry run job
+#[instrument(skip_all)]
+pub async fn execute_migration_job(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    req: PaymentMethodMigrationJobRequest,
+) -> RouterResponse<PaymentMethodMigrationJobResponse> {
+    let db = &*state.store;
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    let job = find_migration_job(&state, merchant_id, &req.job_id).await?;
+
+    if job.status != storage_enums::PaymentMethodMigrationJobStatus::Validated {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!(
+                "Payment method migration job in the {} status cannot be executed",
+                job.status
+            ),
+        }));
+    }
+
+    let job = db
+        .update_payment_method_migration_job_by_merchant_id_job_id(
+            merchant_id,
+            &job.job_id,
+            storage::PaymentMethodMigrationJobUpdate::StatusUpdate {
+                status: storage_enums::PaymentMethodMigrationJobStatus::Pending,
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update payment method migration job")?;
+
+    add_payment_method_migration_task(db, &job).await?;
+
+    Ok(services::ApplicationResponse::Json(
+        get_migration_job_response(job),
+    ))
+}
+
+async fn add_payment_method_migration_task(
+    db: &dyn crate::db::StorageInterface,
+    job: &storage::PaymentMethodMigrationJob,
+) -> RouterResult<()> {
+    let process_tracker_id = format!(
+        "{runner}_{task}_{job_id}",
+        runner = PAYMENT_METHOD_MIGRATION_RUNNER,
+        task = PAYMENT_METHOD_MIGRATION_TASK,
+        job_id = job.job_id
+    );
+    let tracking_data = storage::PaymentMethodMigrationJobTrackingData {
+        merchant_id: job.merchant_id.clone(),
+        job_id: job.job_id.clone(),
+    };
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        PAYMENT_METHOD_MIGRATION_TASK,
+        PAYMENT_METHOD_MIGRATION_RUNNER,
+        [PAYMENT_METHOD_MIGRATION_TAG],
+        tracking_data,
+        None,
+        common_utils::date_time::now(),
+        common_types::consts::API_VERSION,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct payment method migration process tracker task")?;
+
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable(
+            "Failed while inserting payment method migration task to process_tracker",
+        )?;
+    metrics::TASKS_ADDED_COUNT.add(
+        1,
+        router_env::metric_attributes!(("flow", "PaymentMethodMigration")),
+    );
+
+    Ok(())
+}
+
+/// Migrates the payment methods of the valid items of the job, and updates the job with the final
+/// counts once every item is processed. Items are updated as soon as their payment method is
+/// migrated, so that a retry of the task resumes from the items which are still pending.
+///
+/// An item is marked as being migrated before its payment method is migrated. An item which is
+/// still marked so when the task is retried may have had its payment method migrated already, and
+/// is failed instead of being migrated again, so that the payment method is never duplicated.
+#[instrument(skip_all, fields(job_id = %job_id))]
+pub async fn process_migration_job(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    job_id: &str,
+) -> RouterResult<storage::PaymentMethodMigrationJob> {
+    let db = &*state.store;
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+
+    let job = db
+        .update_payment_method_migration_job_by_merchant_id_job_id(
+            merchant_id,
+            job_id,
+            storage::PaymentMethodMigrationJobUpdate::StatusUpdate {
+                status: storage_enums::PaymentMethodMigrationJobStatus::Processing,
+            },
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Payment method migration job not found".to_string(),
+        })?;
+
+    fail_interrupted_migration_job_items(state, job_id).await?;
+
+    loop {
+        let pending_items = db
+            .find_payment_method_migration_job_items_by_job_id_status(
+                job_id,
+                Some(storage_enums::PaymentMethodMigrationJobItemStatus::Valid),
+                Some(MIGRATION_JOB_PROCESS_CHUNK_SIZE),
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch pending payment method migration job items")?;
+        if pending_items.is_empty() {
+            break;
+        }
+
+        futures::stream::iter(pending_items)
+            .map(|item| process_migration_job_item(state, merchant_context, &job, item))
+            .buffer_unordered(MIGRATION_JOB_CONCURRENCY)
+            .collect::<Vec<_>>()
+            .await
+            .into_iter()
+            .collect::<RouterResult<Vec<_>>>()?;
+    }
+
+    let get_item_count = |status| async move {
+        db.get_payment_method_migration_job_item_count_by_job_id_status(job_id, status)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to count payment method migration job items")
+            .and_then(|count| {
+                i32::try_from(count)
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Number of payment method migration job items exceeds i32")
+            })
+    };
+    let migrated_count =
+        get_item_count(storage_enums::PaymentMethodMigrationJobItemStatus::Migrated).await?;
+    let failed_count =
+        get_item_count(storage_enums::PaymentMethodMigrationJobItemStatus::Failed).await?;
+    let status = if failed_count == 0 {
+        storage_enums::PaymentMethodMigrationJobStatus::Completed
+    } else if migrated_count == 0 {
+        storage_enums::PaymentMethodMigrationJobStatus::Failed
+    } else {
+        storage_enums::PaymentMethodMigrationJobStatus::PartiallyCompleted
+    };
+
+    db.update_payment_method_migration_job_by_merchant_id_job_id(
+        merchant_id,
+        job_id,
+        storage::PaymentMethodMigrationJobUpdate::CountsUpdate {
+            status,
+            migrated_count,
+            failed_count,
+        },
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to update payment method migration job")
+}
+
+/// Fails the items whose migration was interrupted by a previous attempt of the task
+async fn fail_interrupted_migration_job_items(
+    state: &SessionState,
+    job_id: &str,
+) -> RouterResult<()> {
+    let interrupted_items = state
+        .store
+        .find_payment_method_migration_job_items_by_job_id_status(
+            job_id,
+            Some(storage_enums::PaymentMethodMigrationJobItemStatus::Migrating),
+            None,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch interrupted payment method migration job items")?;
+
+    for item in interrupted_items {
+        logger::warn!(
+            line_number = item.line_number,
+            "Payment method migration row was interrupted"
+        );
+        state
+            .store
+            .update_payment_method_migration_job_item_by_job_id_line_number(
+                &item.job_id,
+                item.line_number,
+                storage::PaymentMethodMigrationJobItemUpdate::ResultUpdate {
+                    status: storage_enums::PaymentMethodMigrationJobItemStatus::Failed,
+                    payment_method_id: None,
+                    error_message: Some(INTERRUPTED_MIGRATION_ERROR_MESSAGE.to_string()),
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update payment method migration job item")?;
+    }
+
+    Ok(())
+}
+
+async fn process_migration_job_item(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    job: &storage::PaymentMethodMigrationJob,
+    item: storage::PaymentMethodMigrationJobItem,
+) -> RouterResult<()> {
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+
+    let record: Option<PaymentMethodRecord> = cards::decrypt_generic_data(
+        state,
+        item.record.clone(),
+        merchant_context.get_merchant_key_store(),
+    )
+    .await?;
+
+    state
+        .store
+        .update_payment_method_migration_job_item_by_job_id_line_number(
+            &item.job_id,
+            item.line_number,
+            storage::PaymentMethodMigrationJobItemUpdate::ResultUpdate {
+                status: storage_enums::PaymentMethodMigrationJobItemStatus::Migrating,
+                payment_method_id: None,
+                error_message: None,
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to mark payment method migration job item as migrating")?;
+
+    let migration_result = match record {
+        Some(record) => {
+            let migrate_request = PaymentMethodMigrate::try_from((
+                record,
+                merchant_id.clone(),
+                job.merchant_connector_id.clone(),
+            ))
+            .change_context(errors::ApiErrorResponse::InvalidRequestData {
+                message: "Invalid connector mandate reference".to_string(),
+            });
+            match migrate_request {
+                Ok(migrate_request) => Box::pin(migration::migrate_payment_method(
+                    &state.into(),
+                    migrate_request,
+                    merchant_id,
+                    merchant_context,
+                    &cards::PmCards {
+                        state,
+                        merchant_context,
+                    },
+                ))
+                .await
+                .and_then(|response| match response {
+                    services::ApplicationResponse::Json(response) => {
+                        Ok(response.payment_method_response.payment_method_id)
+                    }
+                    _ => Err(report!(errors::ApiErrorResponse::InternalServerError))
+                        .attach_printable("Unexpected response from payment method migration"),
+                }),
+                Err(error) => Err(error),
+            }
+        }
+        None => Err(report!(errors::ApiErrorResponse::InternalServerError))
+            .attach_printable("Payment method migration record not found"),
+    };
+
+    let job_item_update = match migration_result {
+        Ok(payment_method_id) => storage::PaymentMethodMigrationJobItemUpdate::ResultUpdate {
+            status: storage_enums::PaymentMethodMigrationJobItemStatus::Migrated,
+            payment_method_id: Some(payment_method_id),
+            error_message: None,
+        },
+        Err(error) => {
+            logger::error!(
+                line_number = item.line_number,
+                ?error,
+                "Payment method migration row failed"
+            );
+            storage::PaymentMethodMigrationJobItemUpdate::ResultUpdate {
+                status: storage_enums::PaymentMethodMigrationJobItemStatus::Failed,
+                payment_method_id: None,
+                error_message: Some(error.current_context().error_message()),
+            }
+        }
+    };
+
+    state
+        .store
+        .update_payment_method_migration_job_item_by_job_id_line_number(
+            &item.job_id,
+            item.line_number,
+            job_item_update,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update payment method migration job item")?;
+
+    Ok(())
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_migration_job(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    req: PaymentMethodMigrationJobRequest,
+) -> RouterResponse<PaymentMethodMigrationJobResponse> {
+    let job = find_migration_job(
+        &state,
+        merchant_context.get_merchant_account().get_id(),
+        &req.job_id,
+    )
+    .await?;
+
+    Ok(services::ApplicationResponse::Json(
+        get_migration_job_response(job),
+    ))
+}
+
+/// The validation and migration result of every row of the job, as a CSV file
+#[instrument(skip_all)]
+pub async fn get_migration_job_report(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    req: PaymentMethodMigrationJobRequest,
+) -> RouterResponse<serde_json::Value> {
+    let job = find_migration_job(
+        &state,
+        merchant_context.get_merchant_account().get_id(),
+        &req.job_id,
+    )
+    .await?;
+
+    let job_items = state
+        .store
+        .find_payment_method_migration_job_items_by_job_id_status(&job.job_id, None, None)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch payment method migration job items")?;
+
+    Ok(services::ApplicationResponse::FileData((
+        get_migration_job_report_file(job_items)?,
+        mime::TEXT_CSV,
+    )))
+}
+
+fn get_migration_job_report_file(
+    job_items: Vec<storage::PaymentMethodMigrationJobItem>,
+) -> RouterResult<Vec<u8>> {
+    bulk_job::write_csv_report(job_items.into_iter().map(|item| {
+        PaymentMethodMigrationJobReportRecord {
+            line_number: item.line_number,
+            status: item.status,
+            customer_id: item.customer_id,
+            payment_method_id: item.payment_method_id,
+            error_message: item.error_message,
+        }
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::unwrap_used)]
+
+    use super::*;
+
+    fn get_record(fields: serde_json::Value) -> PaymentMethodRecord {
+        let mut record = serde_json::json!({
+            "customer_id": "cus_1",
+            "nick_name": "John",
+            "card_number_masked": "424242XXXXXX4242",
+            "card_expiry_month": "12",
+            "card_expiry_year": "2099",
+            "billing_address_zip": "94122",
+            "billing_address_state": "California",
+            "billing_address_first_name": "John",
+            "billing_address_last_name": "Doe",
+            "billing_address_city": "San Francisco",
+            "billing_address_line1": "1467 Harrison Street",
+            "raw_card_number": "4242424242424242",
+        });
+        record
+            .as_object_mut()
+            .unwrap()
+            .extend(fields.as_object().unwrap().clone());
+        serde_json::from_value(record).unwrap()
+    }
+
+    fn get_validation_error(
+        record: &PaymentMethodRecord,
+        merchant_connector_id: Option<&id_type::MerchantConnectorAccountId>,
+    ) -> Option<String> {
+        let merchant_id =
+            id_type::MerchantId::try_from(std::borrow::Cow::from("merchant_1")).unwrap();
+        validate_payment_method_record(&merchant_id, merchant_connector_id, record)
+            .err()
+            .map(|error| error.current_context().error_message())
+    }
+
+    #[test]
+    fn test_valid_record() {
+        assert_eq!(
+            get_validation_error(&get_record(serde_json::json!({})), None),
+            None
+        );
+    }
+
+    #[test]
+    fn test_record_validation_errors() {
+        assert!(get_validation_error(
+            &get_record(serde_json::json!({ "merchant_id": "merchant_2" })),
+            None
+        )
+        .is_some_and(|error| error.contains("merchant_id")));
+        assert!(get_validation_error(
+            &get_record(serde_json::json!({ "raw_card_number": "4242424242424241" })),
+            None
+        )
+        .is_some_and(|error| error.contains("raw_card_number")));
+        assert!(get_validation_error(
+            &get_record(serde_json::json!({ "raw_card_number": null })),
+            None
+        )
+        .is_some_and(|error| error.contains("raw_card_number")));
+        assert!(get_validation_error(
+            &get_record(serde_json::json!({ "card_expiry_year": "2020" })),
+            None
+        )
+        .is_some_and(|error| error.contains("Card Expired")));
+    }
+
+    #[test]
+    fn test_record_with_payment_instrument_id_requires_connector() {
+        let record = get_record(serde_json::json!({
+            "raw_card_number": null,
+            "payment_instrument_id": "pi_1",
+        }));
+        let merchant_connector_id =
+            id_type::MerchantConnectorAccountId::wrap("mca_1".to_string()).unwrap();
+
+        assert!(get_validation_error(&record, None)
+            .is_some_and(|error| error.contains("merchant_connector_id")));
+        assert_eq!(
+            get_validation_error(&record, Some(&merchant_connector_id)),
+            None
+        );
+
+        let record = get_record(serde_json::json!({
+            "payment_instrument_id": "pi_1",
+            "original_transaction_amount": 1000,
+        }));
+        assert!(get_validation_error(&record, Some(&merchant_connector_id))
+            .is_some_and(|error| error.contains("original_transaction_currency")));
+    }
+
+    #[test]
+    fn test_created_job_status() {
+        assert_eq!(
+            get_created_job_status(2, true),
+            storage_enums::PaymentMethodMigrationJobStatus::Validated
+        );
+        assert_eq!(
+            get_created_job_status(2, false),
+            storage_enums::PaymentMethodMigrationJobStatus::Pending
+        );
+        assert_eq!(
+            get_created_job_status(0, true),
+            storage_enums::PaymentMethodMigrationJobStatus::Failed
+        );
+    }
+
+    #[test]
+    fn test_dry_run_report() {
+        let now = common_utils::date_time::now();
+        let get_job_item = |line_number, status, error_message: Option<&str>| {
+            storage::PaymentMethodMigrationJobItem {
+                job_id: "pm_migration_1".to_string(),
+                line_number,
+                merchant_id: id_type::MerchantId::try_from(std::borrow::Cow::from("merchant_1"))
+                    .unwrap(),
+                status,
+                record: None,
+                customer_id: error_message.is_none().then(|| {
+                    id_type::CustomerId::try_from(std::borrow::Cow::from("cus_1")).unwrap()
+                }),
+                payment_method_id: None,
+                error_message: error_message.map(ToOwned::to_owned),
+                created_at: now,
+                last_modified_at: now,
+            }
+        };
+
+        let report = get_migration_job_report_file(vec![
+            get_job_item(
+                1,
+                storage_enums::PaymentMethodMigrationJobItemStatus::Valid,
+                None,
+            ),
+            get_job_item(
+                2,
+                storage_enums::PaymentMethodMigrationJobItemStatus::Invalid,
+                Some("Card Expired"),
+            ),
+        ])
+        .unwrap();
+
+        assert_eq!(
+            String::from_utf8(report).unwrap(),
+            "line_number,status,customer_id,payment_method_id,error_message\n\
+             1,valid,cus_1,,\n\
+             2,invalid,,,Card Expired\n"
+        );
+    }
+}
@@ -3700,6 +3700,8 @@ mod tests {
             force_3ds_challenge: None,
             force_3ds_challenge_trigger: None,
             is_iframe_redirection_enabled: None,
+            enable_partial_authorization: None,
+            l2_l3_data: None,
         };
         let req_cs = Some("1".to_string());
         assert!(authenticate_client_secret(req_cs.as_ref(), &payment_intent).is_ok());
@@ -3776,6 +3778,8 @@ mod tests {
             force_3ds_challenge: None,
             force_3ds_challenge_trigger: None,
             is_iframe_redirection_enabled: None,
+            enable_partial_authorization: None,
+            l2_l3_data: None,
         };
         let req_cs = Some("1".to_string());
         assert!(authenticate_client_secret(req_cs.as_ref(), &payment_intent,).is_err())
@@ -3850,6 +3854,8 @@ mod tests {
             force_3ds_challenge: None,
             force_3ds_challenge_trigger: None,
             is_iframe_redirection_enabled: None,
+            enable_partial_authorization: None,
+            l2_l3_data: None,
         };
         let req_cs = Some("1".to_string());
         assert!(authenticate_client_secret(req_cs.as_ref(), &payment_intent).is_err())
@@ -4204,6 +4210,7 @@ pub fn get_attempt_type(
                     | enums::AttemptStatus::AuthenticationPending
                     | enums::AttemptStatus::AuthenticationSuccessful
                     | enums::AttemptStatus::Authorized
+                    | enums::AttemptStatus::PartiallyAuthorized
                     | enums::AttemptStatus::Charged
                     | enums::AttemptStatus::Authorizing
                     | enums::AttemptStatus::CodInitiated
@@ -4503,6 +4510,7 @@ pub fn is_manual_retry_allowed(
             | enums::AttemptStatus::AuthenticationPending
             | enums::AttemptStatus::AuthenticationSuccessful
             | enums::AttemptStatus::Authorized
+            | enums::AttemptStatus::PartiallyAuthorized
             | enums::AttemptStatus::Charged
             | enums::AttemptStatus::Authorizing
             | enums::AttemptStatus::CodInitiated
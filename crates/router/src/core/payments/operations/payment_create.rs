@@ -1047,6 +1047,15 @@ impl<F: Send + Clone + Sync> ValidateRequest<F, api::PaymentsRequest, PaymentDat
             )?;
         };
 
+        request
+            .l2_l3_data
+            .as_ref()
+            .map(|l2_l3_data| l2_l3_data.validate())
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InvalidRequestData {
+                message: "Invalid l2_l3_data".to_string(),
+            })?;
+
         let _request_straight_through: Option<api::routing::StraightThroughAlgorithm> = request
             .routing
             .clone()
@@ -1598,6 +1607,8 @@ impl PaymentCreate {
             is_iframe_redirection_enabled: request
                 .is_iframe_redirection_enabled
                 .or(business_profile.is_iframe_redirection_enabled),
+            enable_partial_authorization: request.enable_partial_authorization,
+            l2_l3_data: request.l2_l3_data.clone(),
         })
     }
 
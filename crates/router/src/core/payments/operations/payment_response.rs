@@ -1652,6 +1652,7 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
                             connector_response_reference_id,
                             incremental_authorization_allowed,
                             charges,
+                            authorized_amount,
                             ..
                         } => {
                             payment_data
@@ -1696,7 +1697,9 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
                             // update connector_mandate_details in case of Authorized/Charged Payment Status
                             if matches!(
                                 router_data.status,
-                                enums::AttemptStatus::Charged | enums::AttemptStatus::Authorized
+                                enums::AttemptStatus::Charged
+                                    | enums::AttemptStatus::Authorized
+                                    | enums::AttemptStatus::PartiallyAuthorized
                             ) {
                                 payment_data
                                     .payment_intent
@@ -1789,6 +1792,20 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
                                     )
                                 })
                                 .unwrap_or((None, None));
+                            let amount_capturable =
+                                get_amount_capturable_for_partial_authorization(
+                                    updated_attempt_status,
+                                    authorized_amount,
+                                )
+                                .or_else(|| {
+                                    router_data
+                                        .request
+                                        .get_amount_capturable(
+                                            &payment_data,
+                                            updated_attempt_status,
+                                        )
+                                        .map(MinorUnit::new)
+                                });
                             let (capture_updates, payment_attempt_update) = match payment_data
                                 .multiple_capture_data
                             {
@@ -1829,13 +1846,7 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
                                         connector: None,
                                         connector_transaction_id: connector_transaction_id.cloned(),
                                         authentication_type: auth_update,
-                                        amount_capturable: router_data
-                                            .request
-                                            .get_amount_capturable(
-                                                &payment_data,
-                                                updated_attempt_status,
-                                            )
-                                            .map(MinorUnit::new),
+                                        amount_capturable,
                                         payment_method_id,
                                         mandate_id: payment_data.payment_attempt.mandate_id.clone(),
                                         connector_metadata,
@@ -1965,6 +1976,7 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
     // Stage 1
 
     let payment_attempt = payment_data.payment_attempt.clone();
+    let previous_attempt_status = payment_attempt.status;
 
     let m_db = state.clone().store;
     let m_payment_attempt_update = payment_attempt_update.clone();
@@ -2023,12 +2035,22 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
         None => None,
     };
 
+    let partially_authorized_amount = get_partially_authorized_amount(&router_data);
     let amount_captured = get_total_amount_captured(
         &router_data.request,
-        router_data.amount_captured.map(MinorUnit::new),
+        router_data
+            .amount_captured
+            .map(MinorUnit::new)
+            .or(partially_authorized_amount),
         router_data.status,
         &payment_data,
     );
+    let intent_status = get_intent_status_for_partial_authorization(
+        payment_data.payment_attempt.status,
+        is_partially_authorized(previous_attempt_status, partially_authorized_amount),
+        amount_captured,
+        payment_data.payment_attempt.get_total_amount(),
+    );
 
     let payment_intent_update = match &router_data.response {
         Err(_) => storage::PaymentIntentUpdate::PGStatusUpdate {
@@ -2040,9 +2062,7 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
             incremental_authorization_allowed: Some(false),
         },
         Ok(_) => storage::PaymentIntentUpdate::ResponseUpdate {
-            status: api_models::enums::IntentStatus::foreign_from(
-                payment_data.payment_attempt.status,
-            ),
+            status: intent_status,
             amount_captured,
             updated_by: storage_scheme.to_string(),
             fingerprint_id: payment_data.payment_attempt.fingerprint_id.clone(),
@@ -2760,7 +2780,9 @@ impl<F: Clone> PostUpdateTracker<F, PaymentConfirmData<F>, types::SetupMandateRe
             (Some(token), Some(payment_method_id)) => {
                 if !matches!(
                     router_data.status,
-                    enums::AttemptStatus::Charged | enums::AttemptStatus::Authorized
+                    enums::AttemptStatus::Charged
+                        | enums::AttemptStatus::Authorized
+                        | enums::AttemptStatus::PartiallyAuthorized
                 ) {
                     return Ok(());
                 }
@@ -2920,6 +2942,63 @@ fn get_capture_update_for_unmapped_capture_responses(
     Ok(result)
 }
 
+/// Amount that can be captured on an attempt which the issuer approved only partially
+#[cfg(feature = "v1")]
+fn get_amount_capturable_for_partial_authorization(
+    attempt_status: enums::AttemptStatus,
+    authorized_amount: Option<MinorUnit>,
+) -> Option<MinorUnit> {
+    match attempt_status {
+        enums::AttemptStatus::PartiallyAuthorized => authorized_amount,
+        _ => None,
+    }
+}
+
+/// Amount approved by the issuer when a charge was authorized only partially
+#[cfg(feature = "v1")]
+fn get_partially_authorized_amount<F, T>(
+    router_data: &types::RouterData<F, T, types::PaymentsResponseData>,
+) -> Option<MinorUnit> {
+    match &router_data.response {
+        Ok(types::PaymentsResponseData::TransactionResponse {
+            authorized_amount, ..
+        }) if router_data.status == enums::AttemptStatus::Charged => *authorized_amount,
+        _ => None,
+    }
+}
+
+/// Whether the issuer approved the charge only partially, either in the response to an automatically
+/// captured charge, or before a manually captured charge was captured
+#[cfg(feature = "v1")]
+fn is_partially_authorized(
+    previous_attempt_status: enums::AttemptStatus,
+    partially_authorized_amount: Option<MinorUnit>,
+) -> bool {
+    partially_authorized_amount.is_some()
+        || previous_attempt_status == enums::AttemptStatus::PartiallyAuthorized
+}
+
+/// Status of the intent of an attempt. A charge for less than the amount of the attempt, when the
+/// issuer approved the charge only partially, leaves the intent partially captured. This is the
+/// case whether the charge was captured automatically or manually.
+#[cfg(feature = "v1")]
+fn get_intent_status_for_partial_authorization(
+    attempt_status: enums::AttemptStatus,
+    is_partially_authorized: bool,
+    amount_captured: Option<MinorUnit>,
+    attempt_amount: MinorUnit,
+) -> enums::IntentStatus {
+    match attempt_status {
+        enums::AttemptStatus::Charged
+            if is_partially_authorized
+                && amount_captured.is_some_and(|amount| amount < attempt_amount) =>
+        {
+            enums::IntentStatus::PartiallyCaptured
+        }
+        _ => enums::IntentStatus::foreign_from(attempt_status),
+    }
+}
+
 fn get_total_amount_captured<F: Clone, T: types::Capturable>(
     request: &T,
     amount_captured: Option<MinorUnit>,
@@ -2946,3 +3025,94 @@ fn get_total_amount_captured<F: Clone, T: types::Capturable>(
         }
     }
 }
+
+#[cfg(all(test, feature = "v1"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_intent_status_for_partial_authorization() {
+        let attempt_amount = MinorUnit::new(1000);
+
+        assert_eq!(
+            get_intent_status_for_partial_authorization(
+                enums::AttemptStatus::Charged,
+                true,
+                Some(MinorUnit::new(600)),
+                attempt_amount
+            ),
+            enums::IntentStatus::PartiallyCaptured
+        );
+        assert_eq!(
+            get_intent_status_for_partial_authorization(
+                enums::AttemptStatus::Charged,
+                true,
+                Some(attempt_amount),
+                attempt_amount
+            ),
+            enums::IntentStatus::Succeeded
+        );
+        // A partial capture of an attempt which was authorized in full
+        assert_eq!(
+            get_intent_status_for_partial_authorization(
+                enums::AttemptStatus::Charged,
+                false,
+                Some(MinorUnit::new(600)),
+                attempt_amount
+            ),
+            enums::IntentStatus::Succeeded
+        );
+        assert_eq!(
+            get_intent_status_for_partial_authorization(
+                enums::AttemptStatus::PartiallyAuthorized,
+                true,
+                None,
+                attempt_amount
+            ),
+            enums::IntentStatus::foreign_from(enums::AttemptStatus::PartiallyAuthorized)
+        );
+    }
+
+    #[test]
+    fn test_manual_capture_of_partial_authorization_is_partially_captured() {
+        let attempt_amount = MinorUnit::new(1000);
+        // The capture defaults to the amount capturable, which is the partially authorized amount
+        let amount_captured = get_amount_capturable_for_partial_authorization(
+            enums::AttemptStatus::PartiallyAuthorized,
+            Some(MinorUnit::new(600)),
+        );
+
+        // The capture response does not carry the partially authorized amount
+        let is_partially_authorized =
+            is_partially_authorized(enums::AttemptStatus::PartiallyAuthorized, None);
+
+        assert!(is_partially_authorized);
+        assert_eq!(
+            get_intent_status_for_partial_authorization(
+                enums::AttemptStatus::Charged,
+                is_partially_authorized,
+                amount_captured,
+                attempt_amount
+            ),
+            enums::IntentStatus::PartiallyCaptured
+        );
+    }
+
+    #[test]
+    fn test_get_amount_capturable_for_partial_authorization() {
+        assert_eq!(
+            get_amount_capturable_for_partial_authorization(
+                enums::AttemptStatus::PartiallyAuthorized,
+                Some(MinorUnit::new(600))
+            ),
+            Some(MinorUnit::new(600))
+        );
+        assert_eq!(
+            get_amount_capturable_for_partial_authorization(
+                enums::AttemptStatus::Authorized,
+                Some(MinorUnit::new(600))
+            ),
+            None
+        );
+    }
+}
@@ -753,6 +753,7 @@ impl<F: Send + Clone + Sync, FData: Send + Sync>
                 | storage_enums::AttemptStatus::AuthenticationPending
                 | storage_enums::AttemptStatus::AuthenticationSuccessful
                 | storage_enums::AttemptStatus::Authorized
+                | storage_enums::AttemptStatus::PartiallyAuthorized
                 | storage_enums::AttemptStatus::Charged
                 | storage_enums::AttemptStatus::Authorizing
                 | storage_enums::AttemptStatus::CodInitiated
@@ -5,7 +5,10 @@ use async_trait::async_trait;
 use common_utils::id_type;
 use diesel_models::{enums, routing_algorithm};
 use error_stack::ResultExt;
-use euclid::{backend::BackendInput, frontend::ast};
+use euclid::{
+    backend::BackendInput,
+    frontend::{ast, dnf},
+};
 use serde::{Deserialize, Serialize};
 
 use super::RoutingResult;
@@ -713,38 +716,54 @@ impl From<RoutingAlgorithmRecord> for routing_algorithm::RoutingProfileMetadata
     }
 }
 use api_models::routing::{ConnectorSelection, RoutableConnectorChoice};
-impl From<ast::Program<ConnectorSelection>> for Program {
-    fn from(p: ast::Program<ConnectorSelection>) -> Self {
-        Self {
+impl TryFrom<ast::Program<ConnectorSelection>> for Program {
+    type Error = dnf::TooManyAlternatives;
+
+    fn try_from(p: ast::Program<ConnectorSelection>) -> Result<Self, Self::Error> {
+        Ok(Self {
             globals: HashMap::new(),
             default_selection: convert_output(p.default_selection),
-            rules: p.rules.into_iter().map(convert_rule).collect(),
+            rules: p
+                .rules
+                .into_iter()
+                .map(convert_rule)
+                .collect::<Result<_, _>>()?,
             metadata: Some(p.metadata),
-        }
+        })
     }
 }
 
-fn convert_rule(rule: ast::Rule<ConnectorSelection>) -> Rule {
+fn convert_rule(rule: ast::Rule<ConnectorSelection>) -> Result<Rule, dnf::TooManyAlternatives> {
     let routing_type = match &rule.connector_selection {
         ConnectorSelection::Priority(_) => RoutingType::Priority,
         ConnectorSelection::VolumeSplit(_) => RoutingType::VolumeSplit,
     };
 
-    Rule {
+    Ok(Rule {
         name: rule.name,
         routing_type,
         output: convert_output(rule.connector_selection),
-        statements: rule.statements.into_iter().map(convert_if_stmt).collect(),
-    }
+        statements: convert_if_stmts(&rule.statements)?,
+    })
 }
 
-fn convert_if_stmt(stmt: ast::IfStatement) -> IfStatement {
-    IfStatement {
-        condition: stmt.condition.into_iter().map(convert_comparison).collect(),
-        nested: stmt
-            .nested
-            .map(|v| v.into_iter().map(convert_if_stmt).collect()),
-    }
+/// The decision engine only supports conjunctions of comparisons, so condition groups are
+/// expanded into one statement per alternative of the disjunctive normal form of the condition.
+fn convert_if_stmts(
+    stmts: &[ast::IfStatement],
+) -> Result<Vec<IfStatement>, dnf::TooManyAlternatives> {
+    stmts.iter().try_fold(Vec::new(), |mut converted, stmt| {
+        let nested = stmt.nested.as_deref().map(convert_if_stmts).transpose()?;
+        converted.extend(
+            dnf::expand_all(&stmt.condition)?
+                .into_iter()
+                .map(|comparisons| IfStatement {
+                    condition: comparisons.into_iter().map(convert_comparison).collect(),
+                    nested: nested.clone(),
+                }),
+        );
+        Ok(converted)
+    })
 }
 
 fn convert_comparison(c: ast::Comparison) -> Comparison {
@@ -295,6 +295,8 @@ pub async fn construct_payment_router_data_for_authorize<'a>(
         customer_id: None,
         surcharge_details: None,
         request_extended_authorization: None,
+        enable_partial_authorization: None,
+        l2_l3_data: None,
         request_incremental_authorization: matches!(
             payment_data
                 .payment_intent
@@ -1110,6 +1112,7 @@ where
         connector_response_reference_id: None,
         incremental_authorization_allowed: None,
         charges: None,
+        authorized_amount: None,
     });
 
     let additional_data = PaymentAdditionalData {
@@ -2857,6 +2860,7 @@ where
             issuer_error_code: payment_attempt.issuer_error_code,
             issuer_error_message: payment_attempt.issuer_error_message,
             is_iframe_redirection_enabled: payment_intent.is_iframe_redirection_enabled,
+            enable_partial_authorization: payment_intent.enable_partial_authorization,
             whole_connector_response: payment_data.get_whole_connector_response(),
         };
 
@@ -3151,7 +3155,8 @@ impl ForeignFrom<(storage::PaymentIntent, storage::PaymentAttempt)> for api::Pay
             whole_connector_response: None,
             issuer_error_code: pa.issuer_error_code,
             issuer_error_message: pa.issuer_error_message,
-            is_iframe_redirection_enabled:pi.is_iframe_redirection_enabled
+            is_iframe_redirection_enabled:pi.is_iframe_redirection_enabled,
+            enable_partial_authorization: pi.enable_partial_authorization,
         }
     }
 }
@@ -3568,6 +3573,8 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsAuthoriz
                 .transpose()?,
             customer_acceptance: payment_data.customer_acceptance,
             request_extended_authorization: attempt.request_extended_authorization,
+            enable_partial_authorization: payment_data.payment_intent.enable_partial_authorization,
+            l2_l3_data: payment_data.payment_intent.l2_l3_data.clone(),
             split_payments,
             merchant_order_reference_id,
             integrity_object: None,
@@ -3734,10 +3741,14 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsCaptureD
             api::GetToken::Connector,
             payment_data.payment_attempt.merchant_connector_id.clone(),
         )?;
-        let amount_to_capture = payment_data
-            .payment_attempt
-            .amount_to_capture
-            .unwrap_or(payment_data.payment_attempt.get_total_amount());
+        let amount_to_capture = payment_data.payment_attempt.amount_to_capture.unwrap_or(
+            // Only the amount approved by the issuer can be captured on a partial authorization
+            if payment_data.payment_attempt.status == enums::AttemptStatus::PartiallyAuthorized {
+                payment_data.payment_attempt.amount_capturable
+            } else {
+                payment_data.payment_attempt.get_total_amount()
+            },
+        );
         let browser_info: Option<types::BrowserInformation> = payment_data
             .payment_attempt
             .browser_info
@@ -1,4 +1,6 @@
 pub mod access_token;
+#[cfg(feature = "v1")]
+pub mod batch;
 pub mod helpers;
 #[cfg(feature = "payout_retry")]
 pub mod retry;
@@ -372,6 +374,7 @@ pub async fn payouts_confirm_core(
     merchant_context: domain::MerchantContext,
     req: payouts::PayoutCreateRequest,
 ) -> RouterResponse<payouts::PayoutCreateResponse> {
+    validate_scheduled_at_not_updated(&req)?;
     let mut payout_data = make_payout_data(
         &state,
         &merchant_context,
@@ -393,6 +396,7 @@ pub async fn payouts_confirm_core(
             storage_enums::PayoutStatus::Ineligible,
             storage_enums::PayoutStatus::RequiresFulfillment,
             storage_enums::PayoutStatus::RequiresVendorAccountCreation,
+            storage_enums::PayoutStatus::Scheduled,
         ],
         "confirm",
     )?;
@@ -429,12 +433,23 @@ pub async fn payouts_confirm_core(
     response_handler(&state, &merchant_context, &payout_data).await
 }
 
+fn validate_scheduled_at_not_updated(
+    req: &payouts::PayoutCreateRequest,
+) -> Result<(), errors::ApiErrorResponse> {
+    utils::when(req.scheduled_at.is_some(), || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "scheduled_at can be passed only during Payout Create".to_string(),
+        })
+    })
+}
+
 pub async fn payouts_update_core(
     state: SessionState,
     merchant_context: domain::MerchantContext,
     req: payouts::PayoutCreateRequest,
 ) -> RouterResponse<payouts::PayoutCreateResponse> {
     let payout_id = req.payout_id.clone().get_required_value("payout_id")?;
+    validate_scheduled_at_not_updated(&req)?;
     let mut payout_data = make_payout_data(
         &state,
         &merchant_context,
@@ -632,9 +647,44 @@ pub async fn payouts_cancel_core(
             .attach_printable("Payout cancellation failed for given Payout request")?;
     }
 
+    if status == storage_enums::PayoutStatus::Scheduled
+        && payout_data.payout_attempt.status != storage_enums::PayoutStatus::Scheduled
+    {
+        revoke_scheduled_payout_task(&*state.store, &payout_data.payouts)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to revoke scheduled payout task in process tracker")?;
+    }
+
     response_handler(&state, &merchant_context, &payout_data).await
 }
 
+/// Source of the payout method data with which a payout is fulfilled
+#[derive(Debug)]
+enum FulfillmentPayoutMethodDataSource<'a> {
+    /// The temporary locker entry referred to by the payout token of the payout attempt
+    TemporaryLocker,
+    /// The saved payout method of the payout
+    SavedPayoutMethod(&'a PaymentMethod),
+}
+
+/// The temporary locker entry of the payout method data expires before a scheduled payout is
+/// fulfilled, so the data of a scheduled payout is fetched only from its saved payout method
+fn get_fulfillment_payout_method_data_source(
+    status: api_enums::PayoutStatus,
+    payment_method: Option<&PaymentMethod>,
+) -> RouterResult<FulfillmentPayoutMethodDataSource<'_>> {
+    match status {
+        api_enums::PayoutStatus::Scheduled => payment_method
+            .map(FulfillmentPayoutMethodDataSource::SavedPayoutMethod)
+            .ok_or(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "A scheduled payout can be fulfilled only with a saved payout method"
+                    .to_string(),
+            })),
+        _ => Ok(FulfillmentPayoutMethodDataSource::TemporaryLocker),
+    }
+}
+
 #[instrument(skip_all)]
 pub async fn payouts_fulfill_core(
     state: SessionState,
@@ -655,7 +705,10 @@ pub async fn payouts_fulfill_core(
 
     // Verify if fulfillment can be triggered
     if helpers::is_payout_terminal_state(status)
-        || status != api_enums::PayoutStatus::RequiresFulfillment
+        || !matches!(
+            status,
+            api_enums::PayoutStatus::RequiresFulfillment | api_enums::PayoutStatus::Scheduled
+        )
     {
         return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
             message: format!(
@@ -690,21 +743,41 @@ pub async fn payouts_fulfill_core(
         .customer_id
         .clone()
         .get_required_value("customer_id")?;
-    payout_data.payout_method_data = Some(
-        helpers::make_payout_method_data(
-            &state,
-            None,
-            payout_attempt.payout_token.as_deref(),
-            &customer_id,
-            &payout_attempt.merchant_id,
-            payout_data.payouts.payout_type,
-            merchant_context.get_merchant_key_store(),
-            Some(&mut payout_data),
-            merchant_context.get_merchant_account().storage_scheme,
-        )
-        .await?
-        .get_required_value("payout_method_data")?,
-    );
+    let payout_method_data = match get_fulfillment_payout_method_data_source(
+        status,
+        payout_data.payment_method.as_ref(),
+    )? {
+        FulfillmentPayoutMethodDataSource::SavedPayoutMethod(payment_method) => {
+            let payout_method_data = validator::get_payout_method_data_from_payment_method(
+                &state,
+                &merchant_context,
+                payment_method,
+            )
+            .await?
+            .ok_or(errors::ApiErrorResponse::PreconditionFailed {
+                message:
+                    "The saved payout method of the scheduled payout has no payout method data"
+                        .to_string(),
+            })?;
+            Some(payout_method_data)
+        }
+        FulfillmentPayoutMethodDataSource::TemporaryLocker => {
+            helpers::make_payout_method_data(
+                &state,
+                None,
+                payout_attempt.payout_token.as_deref(),
+                &customer_id,
+                &payout_attempt.merchant_id,
+                payout_data.payouts.payout_type,
+                merchant_context.get_merchant_key_store(),
+                Some(&mut payout_data),
+                merchant_context.get_merchant_account().storage_scheme,
+            )
+            .await?
+        }
+    };
+    payout_data.payout_method_data =
+        Some(payout_method_data.get_required_value("payout_method_data")?);
     Box::pin(fulfill_payout(
         &state,
         &merchant_context,
@@ -1112,9 +1185,14 @@ pub async fn call_connector_payout(
     ))
     .await?;
 
-    // Auto fulfillment flow
     let status = payout_data.payout_attempt.status;
-    if payouts.auto_fulfill && status == storage_enums::PayoutStatus::RequiresFulfillment {
+    // Scheduled fulfillment flow
+    if let (Some(scheduled_at), storage_enums::PayoutStatus::RequiresFulfillment) =
+        (payouts.scheduled_at, status)
+    {
+        schedule_payout_fulfillment(state, merchant_context, payout_data, scheduled_at).await?;
+    // Auto fulfillment flow
+    } else if payouts.auto_fulfill && status == storage_enums::PayoutStatus::RequiresFulfillment {
         Box::pin(fulfill_payout(
             state,
             merchant_context,
@@ -2573,6 +2651,7 @@ pub async fn response_handler(
         created: Some(payouts.created_at),
         connector_transaction_id: payout_attempt.connector_payout_id,
         priority: payouts.priority,
+        scheduled_at: payouts.scheduled_at,
         attempts: None,
         unified_code: payout_attempt.unified_code,
         unified_message: translated_unified_message,
@@ -2734,6 +2813,7 @@ pub async fn payout_create_db_entries(
             .map(|link_data| link_data.link_id.clone()),
         client_secret: Some(client_secret),
         priority: req.priority,
+        scheduled_at: req.scheduled_at,
         status,
         created_at: common_utils::date_time::now(),
         last_modified_at: common_utils::date_time::now(),
@@ -3075,6 +3155,111 @@ pub async fn add_external_account_addition_task(
     Ok(())
 }
 
+const SCHEDULED_PAYOUT_TASK: &str = "SCHEDULED_PAYOUT_FULFILL";
+
+fn get_scheduled_payout_process_tracker_id(payouts: &storage::Payouts) -> String {
+    pt_utils::get_process_tracker_id(
+        storage::ProcessTrackerRunner::ScheduledPayoutWorkflow,
+        SCHEDULED_PAYOUT_TASK,
+        &payouts.payout_id,
+        &payouts.merchant_id,
+    )
+}
+
+pub async fn add_scheduled_payout_task(
+    db: &dyn StorageInterface,
+    payout_data: &PayoutData,
+    schedule_time: time::PrimitiveDateTime,
+) -> CustomResult<(), errors::StorageError> {
+    let runner = storage::ProcessTrackerRunner::ScheduledPayoutWorkflow;
+    let tag = ["PAYOUTS", "SCHEDULED", "FULFILL"];
+    let process_tracker_id = get_scheduled_payout_process_tracker_id(&payout_data.payouts);
+    let tracking_data = api::PayoutRetrieveRequest {
+        payout_id: payout_data.payouts.payout_id.to_owned(),
+        force_sync: None,
+        merchant_id: Some(payout_data.payouts.merchant_id.to_owned()),
+    };
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        SCHEDULED_PAYOUT_TASK,
+        runner,
+        tag,
+        tracking_data,
+        None,
+        schedule_time,
+        common_types::consts::API_VERSION,
+    )
+    .map_err(errors::StorageError::from)?;
+
+    db.insert_process(process_tracker_entry).await?;
+    Ok(())
+}
+
+/// Marks the pending fulfillment task of a scheduled payout as finished, so that the payout is not
+/// fulfilled at the scheduled time
+pub async fn revoke_scheduled_payout_task(
+    db: &dyn StorageInterface,
+    payouts: &storage::Payouts,
+) -> CustomResult<(), errors::StorageError> {
+    let process_tracker_update = storage::ProcessTrackerUpdate::StatusUpdate {
+        status: storage_enums::ProcessTrackerStatus::Finish,
+        business_status: Some(String::from(diesel_models::business_status::REVOKED)),
+    };
+    db.process_tracker_update_process_status_by_ids(
+        vec![get_scheduled_payout_process_tracker_id(payouts)],
+        process_tracker_update,
+    )
+    .await?;
+    Ok(())
+}
+
+async fn schedule_payout_fulfillment(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    payout_data: &mut PayoutData,
+    scheduled_at: time::PrimitiveDateTime,
+) -> RouterResult<()> {
+    let db = &*state.store;
+    add_scheduled_payout_task(db, payout_data, scheduled_at)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while adding scheduled payout task to process tracker")?;
+
+    // Update payout status in DB
+    let status = storage_enums::PayoutStatus::Scheduled;
+    let updated_payout_attempt = storage::PayoutAttemptUpdate::StatusUpdate {
+        connector_payout_id: payout_data.payout_attempt.connector_payout_id.to_owned(),
+        status,
+        error_code: None,
+        error_message: None,
+        is_eligible: None,
+        unified_code: None,
+        unified_message: None,
+    };
+    payout_data.payout_attempt = db
+        .update_payout_attempt(
+            &payout_data.payout_attempt,
+            updated_payout_attempt,
+            &payout_data.payouts,
+            merchant_context.get_merchant_account().storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error updating payout_attempt in db")?;
+    payout_data.payouts = db
+        .update_payout(
+            &payout_data.payouts,
+            storage::PayoutsUpdate::StatusUpdate { status },
+            &payout_data.payout_attempt,
+            merchant_context.get_merchant_account().storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error updating payouts in db")?;
+
+    Ok(())
+}
+
 async fn validate_and_get_business_profile(
     state: &SessionState,
     merchant_key_store: &domain::MerchantKeyStore,
@@ -3283,3 +3468,97 @@ pub async fn get_mca_from_profile_id(
 
     Ok(merchant_connector_account)
 }
+
+#[cfg(all(test, feature = "v1"))]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn get_saved_payout_method() -> PaymentMethod {
+        PaymentMethod {
+            customer_id: common_utils::id_type::CustomerId::default(),
+            merchant_id: common_utils::id_type::MerchantId::default(),
+            payment_method_id: String::from("pm_123"),
+            accepted_currency: None,
+            scheme: None,
+            token: None,
+            cardholder_name: None,
+            issuer_name: None,
+            issuer_country: None,
+            payer_country: None,
+            is_stored: None,
+            swift_code: None,
+            direct_debit_token: None,
+            created_at: common_utils::date_time::now(),
+            last_modified: common_utils::date_time::now(),
+            payment_method: Some(api_enums::PaymentMethod::Card),
+            payment_method_type: None,
+            payment_method_issuer: None,
+            payment_method_issuer_code: None,
+            metadata: None,
+            payment_method_data: None,
+            locker_id: Some(String::from("locker_123")),
+            last_used_at: common_utils::date_time::now(),
+            connector_mandate_details: None,
+            customer_acceptance: None,
+            status: api_enums::PaymentMethodStatus::Active,
+            network_transaction_id: None,
+            client_secret: None,
+            payment_method_billing_address: None,
+            updated_by: None,
+            version: common_enums::ApiVersion::V1,
+            network_token_requestor_reference_id: None,
+            network_token_locker_id: None,
+            network_token_payment_method_data: None,
+        }
+    }
+
+    #[test]
+    fn test_scheduled_payout_is_fulfilled_with_saved_payout_method() {
+        let payment_method = get_saved_payout_method();
+
+        let source = get_fulfillment_payout_method_data_source(
+            api_enums::PayoutStatus::Scheduled,
+            Some(&payment_method),
+        )
+        .unwrap();
+
+        assert!(matches!(
+            source,
+            FulfillmentPayoutMethodDataSource::SavedPayoutMethod(saved_payment_method)
+                if saved_payment_method.payment_method_id == "pm_123"
+        ));
+    }
+
+    #[test]
+    fn test_scheduled_payout_without_saved_payout_method_is_not_fulfilled_from_expired_locker() {
+        // The temporary locker entry of the payout token has expired by the scheduled time, so the
+        // fulfillment fails instead of falling back to the locker
+        let error =
+            get_fulfillment_payout_method_data_source(api_enums::PayoutStatus::Scheduled, None)
+                .unwrap_err();
+
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::PreconditionFailed { .. }
+        ));
+    }
+
+    #[test]
+    fn test_payout_requiring_fulfillment_is_fulfilled_from_temporary_locker() {
+        let payment_method = get_saved_payout_method();
+
+        for payment_method in [None, Some(&payment_method)] {
+            let source = get_fulfillment_payout_method_data_source(
+                api_enums::PayoutStatus::RequiresFulfillment,
+                payment_method,
+            )
+            .unwrap();
+
+            assert!(matches!(
+                source,
+                FulfillmentPayoutMethodDataSource::TemporaryLocker
+            ));
+        }
+    }
+}
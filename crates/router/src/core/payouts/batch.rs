@@ -0,0 +1,869 @@
+//! Bulk payouts
+//!
+//! A batch file of payout create requests is uploaded in the CSV or the JSON format. Every row of
+//! the file is validated upfront on the checks which need no lookups, and stored as an item of the
+//! batch. The payouts of the valid rows are created asynchronously by the payout batch workflow,
+//! which validates the rest of each row, such as the uniqueness of its payout ID, as the payout is
+//! created. The result of every row is tracked on its item, and can be downloaded as a report once
+//! the batch is processed.
+
+use std::collections::{HashMap, HashSet};
+
+use actix_multipart::form::{bytes::Bytes, text::Text, MultipartForm};
+use api_models::payouts::{
+    PayoutBatchRecord, PayoutBatchReportRecord, PayoutBatchResponse, PayoutBatchRetrieveRequest,
+    PayoutCreateRequest, PayoutCreateResponse,
+};
+use common_utils::{
+    encryption::Encryption,
+    events::ApiEventMetric,
+    ext_traits::Encode,
+    id_type, type_name,
+    types::keymanager::{Identifier, KeyManagerState},
+};
+use error_stack::{report, ResultExt};
+use futures::stream::StreamExt;
+use masking::{PeekInterface, Secret};
+use router_env::{instrument, logger, tracing};
+use rustc_hash::FxHashMap;
+
+use super::{helpers, payouts_create_core, validator};
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payment_methods::cards,
+        utils::{self as core_utils, bulk_job},
+    },
+    routes::{metrics, SessionState},
+    services,
+    types::{
+        api::payouts,
+        domain,
+        storage::{self, enums as storage_enums},
+    },
+};
+
+const PAYOUT_BATCH_TASK: &str = "PAYOUT_BATCH";
+const PAYOUT_BATCH_TAG: &str = "PAYOUT_BATCH";
+const PAYOUT_BATCH_RUNNER: storage::ProcessTrackerRunner =
+    storage::ProcessTrackerRunner::PayoutBatchWorkflow;
+
+/// Maximum number of rows accepted in a batch file
+const PAYOUT_BATCH_MAX_ROWS: usize = 10_000;
+
+/// Number of items inserted in a single query, kept well within the bind parameter limit of
+/// Postgres
+const PAYOUT_BATCH_INSERT_CHUNK_SIZE: usize = 1_000;
+
+/// Number of pending items fetched at a time by the workflow
+const PAYOUT_BATCH_PROCESS_CHUNK_SIZE: i64 = 100;
+
+/// Number of payouts of a batch created concurrently
+const PAYOUT_BATCH_CONCURRENCY: usize = 10;
+
+#[derive(Debug, MultipartForm)]
+pub struct PayoutBatchForm {
+    #[multipart(limit = "5MB")]
+    pub file: Bytes,
+    /// The profile used for the rows which do not specify a profile
+    pub profile_id: Option<Text<id_type::ProfileId>>,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct PayoutBatchCreateRequest {
+    pub profile_id: Option<id_type::ProfileId>,
+    pub file_name: Option<String>,
+    pub rows: Vec<PayoutBatchRow>,
+}
+
+impl ApiEventMetric for PayoutBatchCreateRequest {}
+
+#[derive(Debug, serde::Serialize)]
+pub struct PayoutBatchRow {
+    pub row_number: i32,
+    /// The payout create request of the row, or the reason the row could not be parsed
+    pub request: Result<PayoutCreateRequest, String>,
+}
+
+fn try_get_payout_create_request(record: PayoutBatchRecord) -> Result<PayoutCreateRequest, String> {
+    let payout_method_data = match (
+        record.card_number,
+        record.card_exp_month,
+        record.card_exp_year,
+    ) {
+        (Some(card_number), Some(expiry_month), Some(expiry_year)) => {
+            Some(payouts::PayoutMethodData::Card(payouts::CardPayout {
+                card_number,
+                expiry_month,
+                expiry_year,
+                card_holder_name: record.card_holder_name,
+            }))
+        }
+        (None, None, None) => None,
+        _ => {
+            return Err(
+                "card_number, card_exp_month and card_exp_year must be passed together".to_string(),
+            )
+        }
+    };
+
+    Ok(PayoutCreateRequest {
+        payout_id: record.payout_id,
+        amount: Some(record.amount.into()),
+        currency: Some(record.currency),
+        customer_id: record.customer_id,
+        profile_id: record.profile_id,
+        payout_type: record.payout_type,
+        payout_method_id: record.payout_method_id,
+        payout_token: record.payout_token,
+        payout_method_data,
+        connector: record.connector.map(|connector| vec![connector]),
+        confirm: record.confirm,
+        auto_fulfill: record.auto_fulfill,
+        description: record.description,
+        entity_type: record.entity_type,
+        priority: record.priority,
+        business_country: record.business_country,
+        business_label: record.business_label,
+        email: record.email,
+        name: record.name,
+        phone: record.phone,
+        phone_country_code: record.phone_country_code,
+        ..Default::default()
+    })
+}
+
+pub fn parse_csv(data: &[u8]) -> csv::Result<Vec<Result<PayoutCreateRequest, String>>> {
+    Ok(bulk_job::parse_csv_rows::<PayoutBatchRecord>(data)?
+        .into_iter()
+        .map(|row| row.and_then(try_get_payout_create_request))
+        .collect())
+}
+
+pub fn parse_json(data: &[u8]) -> serde_json::Result<Vec<Result<PayoutCreateRequest, String>>> {
+    bulk_job::parse_json_rows(data)
+}
+
+pub fn get_payout_batch_form_request(
+    form: PayoutBatchForm,
+) -> Result<PayoutBatchCreateRequest, errors::ApiErrorResponse> {
+    let file_name = form.file.file_name;
+    let is_json_file = form.file.content_type.as_ref().is_some_and(|content_type| {
+        content_type.essence_str() == mime::APPLICATION_JSON.essence_str()
+    }) || file_name
+        .as_ref()
+        .is_some_and(|file_name| file_name.to_lowercase().ends_with(".json"));
+
+    let data = form.file.data.as_ref();
+    let parsed_rows = if is_json_file {
+        parse_json(data).map_err(|error| error.to_string())
+    } else {
+        parse_csv(data).map_err(|error| error.to_string())
+    };
+    let rows = parsed_rows.map_err(|message| {
+        logger::error!("Failed to parse payout batch file: {message}");
+        errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("Unable to parse the payout batch file: {message}"),
+        }
+    })?;
+
+    bulk_job::validate_row_count(rows.len(), PAYOUT_BATCH_MAX_ROWS, "payout batch")?;
+    logger::info!("Parsed a total of {} payout batch rows", rows.len());
+
+    Ok(PayoutBatchCreateRequest {
+        profile_id: form.profile_id.map(|profile_id| profile_id.0),
+        file_name,
+        rows: rows
+            .into_iter()
+            .zip(1..)
+            .map(|(request, row_number)| PayoutBatchRow {
+                row_number,
+                request,
+            })
+            .collect(),
+    })
+}
+
+fn get_failed_item_update(error: &errors::ApiErrorResponse) -> storage::PayoutBatchItemUpdate {
+    storage::PayoutBatchItemUpdate::ResultUpdate {
+        status: storage_enums::PayoutBatchItemStatus::Failed,
+        payout_status: None,
+        error_code: Some(error.error_code()),
+        error_message: Some(error.error_message()),
+    }
+}
+
+fn get_payout_batch_response(payout_batch: storage::PayoutBatch) -> PayoutBatchResponse {
+    PayoutBatchResponse {
+        pending_count: payout_batch.total_count
+            - payout_batch.succeeded_count
+            - payout_batch.failed_count,
+        batch_id: payout_batch.batch_id,
+        merchant_id: payout_batch.merchant_id,
+        profile_id: payout_batch.profile_id,
+        status: payout_batch.status,
+        file_name: payout_batch.file_name,
+        total_count: payout_batch.total_count,
+        succeeded_count: payout_batch.succeeded_count,
+        failed_count: payout_batch.failed_count,
+        created_at: payout_batch.created_at,
+        last_modified_at: payout_batch.last_modified_at,
+    }
+}
+
+/// Validates and stores the rows of a batch file, and schedules the creation of the payouts of the
+/// valid rows. Invalid rows are recorded as failed items of the batch, and do not fail the upload.
+#[instrument(skip_all)]
+pub async fn create_payout_batch(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    req: PayoutBatchCreateRequest,
+) -> RouterResponse<PayoutBatchResponse> {
+    let db = &*state.store;
+    let key_manager_state = &(&state).into();
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+
+    core_utils::validate_and_get_business_profile(
+        db,
+        key_manager_state,
+        merchant_context.get_merchant_key_store(),
+        req.profile_id.as_ref(),
+        merchant_id,
+    )
+    .await?;
+
+    let batch_id = common_utils::generate_id(consts::ID_LENGTH, "payout_batch");
+    let now = common_utils::date_time::now();
+    let total_count = i32::try_from(req.rows.len())
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Number of payout batch rows exceeds i32")?;
+
+    let mut payout_ids_in_file = HashSet::new();
+    let validated_rows = req
+        .rows
+        .into_iter()
+        .map(|row| {
+            let validated_request = validate_payout_batch_row(
+                &merchant_context,
+                req.profile_id.as_ref(),
+                &mut payout_ids_in_file,
+                row.request,
+            );
+            (row.row_number, validated_request)
+        })
+        .collect::<Vec<_>>();
+
+    let mut encrypted_requests = encrypt_payout_requests(
+        key_manager_state,
+        merchant_context.get_merchant_key_store(),
+        validated_rows
+            .iter()
+            .filter_map(|(row_number, validated_request)| {
+                validated_request
+                    .as_ref()
+                    .ok()
+                    .map(|payout_request| (*row_number, payout_request))
+            }),
+    )
+    .await?;
+
+    let payout_batch_items = validated_rows
+        .into_iter()
+        .map(|(row_number, validated_request)| match validated_request {
+            Ok(payout_request) => {
+                let encrypted_request = encrypted_requests
+                    .remove(&row_number)
+                    .ok_or(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Missing the encrypted payout request of the batch row")?;
+
+                Ok(storage::PayoutBatchItemNew {
+                    batch_id: batch_id.clone(),
+                    row_number,
+                    merchant_id: merchant_id.clone(),
+                    status: storage_enums::PayoutBatchItemStatus::Pending,
+                    payout_request: Some(encrypted_request),
+                    payout_id: payout_request.payout_id,
+                    payout_status: None,
+                    error_code: None,
+                    error_message: None,
+                    created_at: now,
+                    last_modified_at: now,
+                })
+            }
+            Err(error) => {
+                let error = error.current_context();
+                Ok(storage::PayoutBatchItemNew {
+                    batch_id: batch_id.clone(),
+                    row_number,
+                    merchant_id: merchant_id.clone(),
+                    status: storage_enums::PayoutBatchItemStatus::Failed,
+                    payout_request: None,
+                    payout_id: None,
+                    payout_status: None,
+                    error_code: Some(error.error_code()),
+                    error_message: Some(error.error_message()),
+                    created_at: now,
+                    last_modified_at: now,
+                })
+            }
+        })
+        .collect::<RouterResult<Vec<_>>>()?;
+
+    let failed_count = payout_batch_items
+        .iter()
+        .filter(|item| item.status == storage_enums::PayoutBatchItemStatus::Failed)
+        .count();
+    let has_pending_items = failed_count < payout_batch_items.len();
+    let status = if has_pending_items {
+        storage_enums::PayoutBatchStatus::Pending
+    } else {
+        storage_enums::PayoutBatchStatus::Failed
+    };
+
+    let payout_batch = db
+        .insert_payout_batch(storage::PayoutBatchNew {
+            batch_id: batch_id.clone(),
+            merchant_id: merchant_id.clone(),
+            profile_id: req.profile_id,
+            status,
+            file_name: req.file_name,
+            total_count,
+            succeeded_count: 0,
+            failed_count: i32::try_from(failed_count)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Number of failed payout batch rows exceeds i32")?,
+            created_at: now,
+            last_modified_at: now,
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert payout batch")?;
+
+    bulk_job::insert_in_chunks(
+        payout_batch_items,
+        PAYOUT_BATCH_INSERT_CHUNK_SIZE,
+        |chunk| async move {
+            db.insert_payout_batch_items(chunk)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to insert payout batch items")
+        },
+    )
+    .await?;
+
+    if has_pending_items {
+        add_payout_batch_task(db, &payout_batch).await?;
+    }
+
+    Ok(services::ApplicationResponse::Json(
+        get_payout_batch_response(payout_batch),
+    ))
+}
+
+/// Validates a row of a batch file on the checks which need no lookups, and assigns the payout ID
+/// of the row
+fn validate_payout_batch_row(
+    merchant_context: &domain::MerchantContext,
+    profile_id: Option<&id_type::ProfileId>,
+    payout_ids_in_file: &mut HashSet<String>,
+    request: Result<PayoutCreateRequest, String>,
+) -> RouterResult<PayoutCreateRequest> {
+    let mut payout_request = request
+        .map_err(|message| report!(errors::ApiErrorResponse::InvalidRequestData { message }))?;
+    if payout_request.profile_id.is_none() {
+        payout_request.profile_id = profile_id.cloned();
+    }
+
+    if let Some(payout_id) = payout_request.payout_id.as_ref() {
+        if !payout_ids_in_file.insert(payout_id.clone()) {
+            return Err(report!(errors::ApiErrorResponse::DuplicatePayout {
+                payout_id: payout_id.clone(),
+            }));
+        }
+    }
+    validator::validate_batch_payout_request(merchant_context, &payout_request)?;
+
+    // The payout ID is assigned upfront, so that a retried creation of the payout of the row does
+    // not create a duplicate payout
+    payout_request.payout_id = Some(core_utils::get_or_generate_uuid(
+        "payout_id",
+        payout_request.payout_id.as_ref(),
+    )?);
+
+    Ok(payout_request)
+}
+
+/// Encrypts the payout requests of the valid rows of a batch, a chunk of rows per call to the key
+/// manager, and returns them by their row number
+async fn encrypt_payout_requests<'a>(
+    key_manager_state: &KeyManagerState,
+    key_store: &domain::MerchantKeyStore,
+    payout_requests: impl Iterator<Item = (i32, &'a PayoutCreateRequest)>,
+) -> RouterResult<HashMap<i32, Encryption>> {
+    let payout_requests = payout_requests.collect::<Vec<_>>();
+    let mut encrypted_requests = HashMap::with_capacity(payout_requests.len());
+
+    for chunk in payout_requests.chunks(PAYOUT_BATCH_INSERT_CHUNK_SIZE) {
+        let encoded_requests = chunk
+            .iter()
+            .map(|(row_number, payout_request)| {
+                payout_request.encode_to_value().map(|encoded_request| {
+                    (
+                        row_number.to_string(),
+                        Secret::<_, masking::WithType>::new(encoded_request),
+                    )
+                })
+            })
+            .collect::<Result<FxHashMap<_, _>, _>>()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Unable to encode the payout requests of the batch rows")?;
+
+        let encrypted_chunk = domain::types::crypto_operation(
+            key_manager_state,
+            type_name!(PayoutCreateRequest),
+            domain::types::CryptoOperation::BatchEncrypt(encoded_requests),
+            Identifier::Merchant(key_store.merchant_id.clone()),
+            key_store.key.get_inner().peek(),
+        )
+        .await
+        .and_then(|val| val.try_into_batchoperation())
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to encrypt the payout requests of the batch rows")?;
+
+        for (row_number, encrypted_request) in encrypted_chunk {
+            let row_number = row_number
+                .parse::<i32>()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Invalid row number of an encrypted payout request")?;
+            encrypted_requests.insert(row_number, encrypted_request.into());
+        }
+    }
+
+    Ok(encrypted_requests)
+}
+
+async fn add_payout_batch_task(
+    db: &dyn crate::db::StorageInterface,
+    payout_batch: &storage::PayoutBatch,
+) -> RouterResult<()> {
+    let process_tracker_id = format!(
+        "{runner}_{task}_{batch_id}",
+        runner = PAYOUT_BATCH_RUNNER,
+        task = PAYOUT_BATCH_TASK,
+        batch_id = payout_batch.batch_id
+    );
+    let tracking_data = storage::PayoutBatchTrackingData {
+        merchant_id: payout_batch.merchant_id.clone(),
+        batch_id: payout_batch.batch_id.clone(),
+    };
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        PAYOUT_BATCH_TASK,
+        PAYOUT_BATCH_RUNNER,
+        [PAYOUT_BATCH_TAG],
+        tracking_data,
+        None,
+        common_utils::date_time::now(),
+        common_types::consts::API_VERSION,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct payout batch process tracker task")?;
+
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while inserting payout batch task to process_tracker")?;
+    metrics::TASKS_ADDED_COUNT.add(1, router_env::metric_attributes!(("flow", "PayoutBatch")));
+
+    Ok(())
+}
+
+/// Creates the payouts of the pending items of the batch, and updates the batch with the final
+/// counts once every item is processed. Items are updated as soon as their payout is created, so
+/// that a retry of the task resumes from the items which are still pending.
+#[instrument(skip_all, fields(batch_id = %batch_id))]
+pub async fn process_payout_batch(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    batch_id: &str,
+) -> RouterResult<storage::PayoutBatch> {
+    let db = &*state.store;
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+
+    db.update_payout_batch_by_merchant_id_batch_id(
+        merchant_id,
+        batch_id,
+        storage::PayoutBatchUpdate::StatusUpdate {
+            status: storage_enums::PayoutBatchStatus::Processing,
+        },
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+        message: "Payout batch not found".to_string(),
+    })?;
+
+    loop {
+        let pending_items = db
+            .find_payout_batch_items_by_batch_id_status(
+                batch_id,
+                Some(storage_enums::PayoutBatchItemStatus::Pending),
+                Some(PAYOUT_BATCH_PROCESS_CHUNK_SIZE),
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch pending payout batch items")?;
+        if pending_items.is_empty() {
+            break;
+        }
+
+        futures::stream::iter(pending_items)
+            .map(|item| process_payout_batch_item(state, merchant_context, item))
+            .buffer_unordered(PAYOUT_BATCH_CONCURRENCY)
+            .collect::<Vec<_>>()
+            .await
+            .into_iter()
+            .collect::<RouterResult<Vec<_>>>()?;
+    }
+
+    update_payout_batch_counts(db, merchant_id, batch_id, None).await
+}
+
+/// Fails the items of the batch which are still pending along with the batch, once the retries of
+/// the task are exhausted
+#[instrument(skip_all, fields(batch_id = %batch_id))]
+pub async fn fail_payout_batch(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    batch_id: &str,
+) -> RouterResult<storage::PayoutBatch> {
+    let db = &*state.store;
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+
+    db.update_payout_batch_items_by_batch_id_status(
+        batch_id,
+        storage_enums::PayoutBatchItemStatus::Pending,
+        get_failed_item_update(&errors::ApiErrorResponse::InternalServerError),
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to fail pending payout batch items")?;
+
+    update_payout_batch_counts(
+        db,
+        merchant_id,
+        batch_id,
+        Some(storage_enums::PayoutBatchStatus::Failed),
+    )
+    .await
+}
+
+/// Updates the batch with the counts of its succeeded and failed items, and with the given status
+/// or the status derived from the counts
+async fn update_payout_batch_counts(
+    db: &dyn crate::db::StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    batch_id: &str,
+    status: Option<storage_enums::PayoutBatchStatus>,
+) -> RouterResult<storage::PayoutBatch> {
+    let get_item_count = |status| async move {
+        db.get_payout_batch_item_count_by_batch_id_status(batch_id, status)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to count payout batch items")
+            .and_then(|count| {
+                i32::try_from(count)
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Number of payout batch items exceeds i32")
+            })
+    };
+    let succeeded_count = get_item_count(storage_enums::PayoutBatchItemStatus::Succeeded).await?;
+    let failed_count = get_item_count(storage_enums::PayoutBatchItemStatus::Failed).await?;
+    let status = status.unwrap_or(if failed_count == 0 {
+        storage_enums::PayoutBatchStatus::Completed
+    } else if succeeded_count == 0 {
+        storage_enums::PayoutBatchStatus::Failed
+    } else {
+        storage_enums::PayoutBatchStatus::PartiallyCompleted
+    });
+
+    db.update_payout_batch_by_merchant_id_batch_id(
+        merchant_id,
+        batch_id,
+        storage::PayoutBatchUpdate::CountsUpdate {
+            status,
+            succeeded_count,
+            failed_count,
+        },
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to update payout batch")
+}
+
+async fn process_payout_batch_item(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    item: storage::PayoutBatchItem,
+) -> RouterResult<()> {
+    let payout_request: Option<PayoutCreateRequest> = cards::decrypt_generic_data(
+        state,
+        item.payout_request.clone(),
+        merchant_context.get_merchant_key_store(),
+    )
+    .await?;
+
+    let payout_batch_item_update = match payout_request {
+        Some(payout_request) => {
+            let payout_id = payout_request.payout_id.clone();
+            let amount = payout_request.amount;
+            let currency = payout_request.currency;
+            let profile_id = payout_request.profile_id.clone();
+            match Box::pin(payouts_create_core(
+                state.clone(),
+                merchant_context.clone(),
+                payout_request,
+            ))
+            .await
+            {
+                Ok(services::ApplicationResponse::Json(payout_response)) => {
+                    get_payout_item_update(&payout_response)
+                }
+                Ok(_) => Err(report!(errors::ApiErrorResponse::InternalServerError))
+                    .attach_printable("Unexpected response from payouts create")?,
+                // The payout may have been created by an earlier run of the task, which failed
+                // before updating the item. Any other payout with the ID, including one created
+                // before the batch was uploaded, was created by a separate request of the merchant.
+                Err(error)
+                    if matches!(
+                        error.current_context(),
+                        errors::ApiErrorResponse::DuplicatePayout { .. }
+                    ) =>
+                {
+                    let payout = state
+                        .store
+                        .find_payout_by_merchant_id_payout_id(
+                            merchant_context.get_merchant_account().get_id(),
+                            &payout_id.unwrap_or_default(),
+                            merchant_context.get_merchant_account().storage_scheme,
+                        )
+                        .await
+                        .to_not_found_response(errors::ApiErrorResponse::PayoutNotFound)?;
+                    let is_payout_of_item = payout.created_at >= item.created_at
+                        && amount.map(common_utils::types::MinorUnit::from) == Some(payout.amount)
+                        && currency == Some(payout.destination_currency)
+                        && profile_id
+                            .as_ref()
+                            .map_or(true, |profile_id| *profile_id == payout.profile_id);
+
+                    if is_payout_of_item {
+                        storage::PayoutBatchItemUpdate::ResultUpdate {
+                            status: get_payout_item_status(payout.status),
+                            payout_status: Some(payout.status),
+                            error_code: None,
+                            error_message: None,
+                        }
+                    } else {
+                        logger::error!(
+                            row_number = item.row_number,
+                            payout_id = %payout.payout_id,
+                            "Payout of the batch row was created by a separate request"
+                        );
+                        get_failed_item_update(error.current_context())
+                    }
+                }
+                Err(error) => {
+                    logger::error!(
+                        row_number = item.row_number,
+                        ?error,
+                        "Payout batch row failed"
+                    );
+                    get_failed_item_update(error.current_context())
+                }
+            }
+        }
+        None => get_failed_item_update(&errors::ApiErrorResponse::InternalServerError),
+    };
+
+    state
+        .store
+        .update_payout_batch_item_by_batch_id_row_number(
+            &item.batch_id,
+            item.row_number,
+            payout_batch_item_update,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update payout batch item")?;
+
+    Ok(())
+}
+
+fn get_payout_item_status(
+    payout_status: storage_enums::PayoutStatus,
+) -> storage_enums::PayoutBatchItemStatus {
+    if helpers::is_payout_err_state(payout_status) {
+        storage_enums::PayoutBatchItemStatus::Failed
+    } else {
+        storage_enums::PayoutBatchItemStatus::Succeeded
+    }
+}
+
+fn get_payout_item_update(
+    payout_response: &PayoutCreateResponse,
+) -> storage::PayoutBatchItemUpdate {
+    storage::PayoutBatchItemUpdate::ResultUpdate {
+        status: get_payout_item_status(payout_response.status),
+        payout_status: Some(payout_response.status),
+        error_code: payout_response.error_code.clone(),
+        error_message: payout_response.error_message.clone(),
+    }
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_payout_batch(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    req: PayoutBatchRetrieveRequest,
+) -> RouterResponse<PayoutBatchResponse> {
+    let payout_batch = state
+        .store
+        .find_payout_batch_by_merchant_id_batch_id(
+            merchant_context.get_merchant_account().get_id(),
+            &req.batch_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Payout batch not found".to_string(),
+        })?;
+
+    Ok(services::ApplicationResponse::Json(
+        get_payout_batch_response(payout_batch),
+    ))
+}
+
+/// The result of every row of the batch, as a CSV file
+#[instrument(skip_all)]
+pub async fn get_payout_batch_report(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    req: PayoutBatchRetrieveRequest,
+) -> RouterResponse<serde_json::Value> {
+    let db = &*state.store;
+    let payout_batch = db
+        .find_payout_batch_by_merchant_id_batch_id(
+            merchant_context.get_merchant_account().get_id(),
+            &req.batch_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Payout batch not found".to_string(),
+        })?;
+
+    let payout_batch_items = db
+        .find_payout_batch_items_by_batch_id_status(&payout_batch.batch_id, None, None)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch payout batch items")?;
+
+    let report = bulk_job::write_csv_report(payout_batch_items.into_iter().map(|item| {
+        PayoutBatchReportRecord {
+            row_number: item.row_number,
+            status: item.status,
+            payout_id: item.payout_id,
+            payout_status: item.payout_status,
+            error_code: item.error_code,
+            error_message: item.error_message,
+        }
+    }))?;
+
+    Ok(services::ApplicationResponse::FileData((
+        report,
+        mime::TEXT_CSV,
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    #[test]
+    fn test_parse_csv_reports_invalid_rows() {
+        let data = b"payout_id,amount,currency,card_number,card_exp_month,card_exp_year\n\
+            payout_1,100,USD,,,\n\
+            payout_2,200,USD,4111111111111111,10,2030\n\
+            payout_3,300,USD,4111111111111111,10,\n\
+            payout_4,abc,USD,,,\n";
+
+        let rows = parse_csv(data).unwrap();
+
+        assert_eq!(
+            rows.iter().map(Result::is_ok).collect::<Vec<_>>(),
+            vec![true, true, false, false]
+        );
+        let first_request = rows.first().unwrap().as_ref().unwrap();
+        assert_eq!(first_request.payout_id.as_deref(), Some("payout_1"));
+        assert!(first_request.payout_method_data.is_none());
+        assert!(matches!(
+            rows.get(1).unwrap().as_ref().unwrap().payout_method_data,
+            Some(payouts::PayoutMethodData::Card(_))
+        ));
+    }
+
+    #[test]
+    fn test_parse_json_reports_invalid_rows() {
+        let data = br#"[
+            {"payout_id": "payout_1", "amount": 100, "currency": "USD"},
+            {"payout_id": "payout_2", "amount": "abc", "currency": "USD"}
+        ]"#;
+
+        let rows = parse_json(data).unwrap();
+
+        assert_eq!(
+            rows.iter().map(Result::is_ok).collect::<Vec<_>>(),
+            vec![true, false]
+        );
+        assert!(parse_json(br#"{"payout_id": "payout_1"}"#).is_err());
+    }
+
+    #[test]
+    fn test_try_get_payout_create_request_requires_all_card_fields() {
+        let record = |card_exp_year: Option<&str>| PayoutBatchRecord {
+            payout_id: Some("payout_1".to_string()),
+            amount: common_utils::types::MinorUnit::new(100),
+            currency: storage_enums::Currency::USD,
+            customer_id: None,
+            profile_id: None,
+            payout_type: None,
+            payout_method_id: None,
+            payout_token: None,
+            connector: None,
+            confirm: None,
+            auto_fulfill: None,
+            description: None,
+            entity_type: None,
+            priority: None,
+            business_country: None,
+            business_label: None,
+            email: None,
+            name: None,
+            phone: None,
+            phone_country_code: None,
+            card_number: Some("4111111111111111".parse().unwrap()),
+            card_exp_month: Some(masking::Secret::new("10".to_string())),
+            card_exp_year: card_exp_year.map(|year| masking::Secret::new(year.to_string())),
+            card_holder_name: None,
+        };
+
+        let request = try_get_payout_create_request(record(Some("2030"))).unwrap();
+
+        assert!(matches!(
+            request.payout_method_data,
+            Some(payouts::PayoutMethodData::Card(_))
+        ));
+        assert_eq!(request.currency, Some(storage_enums::Currency::USD));
+        assert!(try_get_payout_create_request(record(None)).is_err());
+    }
+}
@@ -98,6 +98,7 @@ impl
             created: Some(payout.created_at),
             connector_transaction_id: attempt.connector_transaction_id.clone(),
             priority: payout.priority,
+            scheduled_at: payout.scheduled_at,
             billing: address,
             payout_method_data: payout_attempt.additional_payout_method_data.map(From::from),
             client_secret: None,
@@ -222,38 +222,16 @@ pub async fn validate_create_request(
             .await
         }
         (_, Some(_), Some(payment_method)) => {
-            match get_pm_list_context(
-                state,
-                payment_method
-                    .payment_method
-                    .as_ref()
-                    .get_required_value("payment_method_id")?,
-                merchant_context.get_merchant_key_store(),
-                payment_method,
-                None,
-                false,
-                merchant_context,
-            )
-            .await?
-            {
-                Some(pm) => match (pm.card_details, pm.bank_transfer_details) {
-                    (Some(card), _) => Ok(Some(payouts::PayoutMethodData::Card(
-                        api_models::payouts::CardPayout {
-                            card_number: card.card_number.get_required_value("card_number")?,
-                            card_holder_name: card.card_holder_name,
-                            expiry_month: card.expiry_month.get_required_value("expiry_month")?,
-                            expiry_year: card.expiry_year.get_required_value("expiry_month")?,
-                        },
-                    ))),
-                    (_, Some(bank)) => Ok(Some(payouts::PayoutMethodData::Bank(bank))),
-                    _ => Ok(None),
-                },
-                None => Ok(None),
-            }
+            get_payout_method_data_from_payment_method(state, merchant_context, payment_method)
+                .await
         }
         _ => Ok(None),
     }?;
 
+    if let Some(scheduled_at) = req.scheduled_at {
+        validate_payout_schedule(req, scheduled_at)?;
+    }
+
     Ok((
         payout_id,
         payout_method_data,
@@ -263,6 +241,132 @@ pub async fn validate_create_request(
     ))
 }
 
+/// Validates a row of a payout batch on the below checks, which do not need any lookups. The row is
+/// validated with [`validate_create_request`], including the uniqueness of its payout_id, once its
+/// payout is created.
+/// - merchant_id passed is same as the one in merchant_account table
+/// - amount and currency are passed
+/// - only one of payout_method_id or payout_token is passed
+#[cfg(feature = "v1")]
+pub fn validate_batch_payout_request(
+    merchant_context: &domain::MerchantContext,
+    req: &payouts::PayoutCreateRequest,
+) -> RouterResult<()> {
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+
+    if req.payout_link.unwrap_or(false) {
+        validate_payout_link_request(req)?;
+    }
+
+    let predicate = req.merchant_id.as_ref().map(|mid| mid != merchant_id);
+    utils::when(predicate.unwrap_or(false), || {
+        Err(report!(errors::ApiErrorResponse::InvalidDataFormat {
+            field_name: "merchant_id".to_string(),
+            expected_format: "merchant_id from merchant account".to_string(),
+        })
+        .attach_printable("invalid merchant_id in request"))
+    })?;
+
+    req.amount.get_required_value("amount")?;
+    req.currency.get_required_value("currency")?;
+
+    if let Some(scheduled_at) = req.scheduled_at {
+        validate_payout_schedule(req, scheduled_at)?;
+    }
+
+    utils::when(
+        req.payout_token.is_some() && req.payout_method_id.is_some(),
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "Only one of payout_method_id or payout_token should be provided."
+                    .to_string(),
+            }))
+        },
+    )?;
+
+    Ok(())
+}
+
+/// Fetches the payout method data of a saved payout method
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+pub async fn get_payout_method_data_from_payment_method(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    payment_method: &PaymentMethod,
+) -> RouterResult<Option<payouts::PayoutMethodData>> {
+    match get_pm_list_context(
+        state,
+        payment_method
+            .payment_method
+            .as_ref()
+            .get_required_value("payment_method_id")?,
+        merchant_context.get_merchant_key_store(),
+        payment_method,
+        None,
+        false,
+        merchant_context,
+    )
+    .await?
+    {
+        Some(pm) => match (pm.card_details, pm.bank_transfer_details) {
+            (Some(card), _) => Ok(Some(payouts::PayoutMethodData::Card(
+                api_models::payouts::CardPayout {
+                    card_number: card.card_number.get_required_value("card_number")?,
+                    card_holder_name: card.card_holder_name,
+                    expiry_month: card.expiry_month.get_required_value("expiry_month")?,
+                    expiry_year: card.expiry_year.get_required_value("expiry_year")?,
+                },
+            ))),
+            (_, Some(bank)) => Ok(Some(payouts::PayoutMethodData::Bank(bank))),
+            _ => Ok(None),
+        },
+        None => Ok(None),
+    }
+}
+
+#[cfg(all(feature = "v2", feature = "customer_v2"))]
+pub async fn get_payout_method_data_from_payment_method(
+    _state: &SessionState,
+    _merchant_context: &domain::MerchantContext,
+    _payment_method: &PaymentMethod,
+) -> RouterResult<Option<payouts::PayoutMethodData>> {
+    Err(errors::ApiErrorResponse::NotImplemented {
+        message: errors::NotImplementedMessage::Reason(
+            "Fetching the payout method data of a saved payout method".to_string(),
+        ),
+    }
+    .into())
+}
+
+/// Scheduled payouts are fulfilled after the payout method data in the temporary locker expires,
+/// so the payout method is required to be saved, for its data to be fetched when fulfilling.
+pub fn validate_payout_schedule(
+    req: &payouts::PayoutCreateRequest,
+    scheduled_at: time::PrimitiveDateTime,
+) -> Result<(), errors::ApiErrorResponse> {
+    if scheduled_at <= common_utils::date_time::now() {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "scheduled_at should be a time in the future".to_string(),
+        });
+    }
+
+    if req.payout_method_id.is_none() {
+        return Err(errors::ApiErrorResponse::MissingRequiredField {
+            field_name: "payout_method_id when scheduled_at is passed",
+        });
+    }
+
+    if req.payout_method_data.is_some() || req.payout_token.is_some() {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "payout_method_data and payout_token cannot be passed with scheduled_at, \
+                the payout method should be saved and passed as payout_method_id"
+                .to_string(),
+        });
+    }
+
+    Ok(())
+}
+
 pub fn validate_payout_link_request(
     req: &payouts::PayoutCreateRequest,
 ) -> Result<(), errors::ApiErrorResponse> {
@@ -420,3 +524,49 @@ pub fn validate_payout_link_render_request_and_get_allowed_domains(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_scheduled_payout_request(
+        payout_method_id: Option<&str>,
+        payout_token: Option<&str>,
+    ) -> payouts::PayoutCreateRequest {
+        payouts::PayoutCreateRequest {
+            payout_method_id: payout_method_id.map(str::to_string),
+            payout_token: payout_token.map(str::to_string),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_validate_payout_schedule() {
+        let future_time = common_utils::date_time::now() + time::Duration::days(1);
+        let past_time = common_utils::date_time::now() - time::Duration::minutes(1);
+
+        assert!(validate_payout_schedule(
+            &get_scheduled_payout_request(Some("pm_123"), None),
+            future_time
+        )
+        .is_ok());
+        assert!(matches!(
+            validate_payout_schedule(
+                &get_scheduled_payout_request(Some("pm_123"), None),
+                past_time
+            ),
+            Err(errors::ApiErrorResponse::InvalidRequestData { .. })
+        ));
+        assert!(matches!(
+            validate_payout_schedule(&get_scheduled_payout_request(None, None), future_time),
+            Err(errors::ApiErrorResponse::MissingRequiredField { .. })
+        ));
+        assert!(matches!(
+            validate_payout_schedule(
+                &get_scheduled_payout_request(Some("pm_123"), Some("token_123")),
+                future_time
+            ),
+            Err(errors::ApiErrorResponse::InvalidRequestData { .. })
+        ));
+    }
+}
@@ -0,0 +1,714 @@
+use std::sync::Arc;
+
+use analytics::{
+    enums::AuthInfo,
+    errors::{AnalyticsError, AnalyticsResult},
+    reports::{
+        get_report_rows,
+        parquet::{encode_report_part, ParquetReportWriter},
+        rows::ReportCursor,
+        ReportRecord,
+    },
+};
+use api_models::analytics::{
+    GenerateReportResponse, ReportFormat, ReportStatus, ReportStatusResponse, ReportType, TimeRange,
+};
+use common_utils::ext_traits::{Encode, StringExt, ValueExt};
+use error_stack::ResultExt;
+use external_services::file_storage::FileStorageInterface;
+use futures::{Stream, StreamExt};
+use redis_interface::errors::RedisError;
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    routes::{metrics, SessionState},
+    types::storage,
+};
+
+const REPORT_GENERATION_TASK: &str = "REPORT_GENERATION";
+const REPORT_GENERATION_TAG: &str = "REPORT";
+const REPORT_GENERATION_RUNNER: storage::ProcessTrackerRunner =
+    storage::ProcessTrackerRunner::ReportGenerationWorkflow;
+const REPORT_DOWNLOAD_TOKEN_KEY_PREFIX: &str = "report_download_token";
+const REPORT_DOWNLOAD_LINK_KEY_PREFIX: &str = "report_download_link";
+
+/// Tracking data of a report generation task, which also records the status of the report.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub(crate) struct ReportGenerationTrackingData {
+    pub(crate) report_id: String,
+    pub(crate) report_type: ReportType,
+    #[serde(default)]
+    pub(crate) format: ReportFormat,
+    pub(crate) auth: AuthInfo,
+    pub(crate) time_range: TimeRange,
+    pub(crate) status: ReportStatus,
+    /// Keys of the parts of the report uploaded so far. CSV parts are concatenated on download,
+    /// while Parquet parts are merged into a single file once all of them are uploaded.
+    #[serde(default)]
+    pub(crate) file_keys: Vec<String>,
+    /// Position of the last row written to the report
+    pub(crate) cursor: Option<ReportCursor>,
+    pub(crate) row_count: Option<u64>,
+}
+
+/// Files of a generated report referred to by a download token.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+struct ReportFiles {
+    format: ReportFormat,
+    file_keys: Vec<String>,
+}
+
+/// Download link issued for a generated report, which is shared by the polls of the report.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+struct ReportDownloadLink {
+    token: String,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    expires_at: time::PrimitiveDateTime,
+}
+
+/// Schedules the generation of a report, which is picked up by the scheduler.
+#[instrument(skip_all)]
+pub async fn create_report(
+    state: &SessionState,
+    report_type: ReportType,
+    format: ReportFormat,
+    auth: AuthInfo,
+    time_range: TimeRange,
+) -> AnalyticsResult<GenerateReportResponse> {
+    let now = common_utils::date_time::now();
+    let report_id = common_utils::generate_time_ordered_id("rpt");
+
+    // The end time is fixed while scheduling, so that retries of the task generate the same report
+    let tracking_data = ReportGenerationTrackingData {
+        report_id: report_id.clone(),
+        report_type,
+        format,
+        auth,
+        time_range: TimeRange {
+            start_time: time_range.start_time,
+            end_time: Some(time_range.end_time.unwrap_or(now)),
+        },
+        status: ReportStatus::Pending,
+        file_keys: Vec::new(),
+        cursor: None,
+        row_count: None,
+    };
+
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        get_report_process_tracker_id(&report_id),
+        REPORT_GENERATION_TASK,
+        REPORT_GENERATION_RUNNER,
+        [REPORT_GENERATION_TAG],
+        tracking_data,
+        None,
+        now,
+        common_types::consts::API_VERSION,
+    )
+    .change_context(AnalyticsError::UnknownError)
+    .attach_printable("Failed to construct report generation process tracker task")?;
+
+    state
+        .store
+        .insert_process(process_tracker_entry)
+        .await
+        .change_context(AnalyticsError::UnknownError)
+        .attach_printable("Failed while inserting report generation task to process_tracker")?;
+    metrics::TASKS_ADDED_COUNT.add(
+        1,
+        router_env::metric_attributes!(("flow", "ReportGeneration")),
+    );
+
+    Ok(GenerateReportResponse {
+        report_id,
+        status: ReportStatus::Pending,
+    })
+}
+
+/// Retrieves the status of a report, along with a download link once the report is generated.
+#[instrument(skip(state))]
+pub async fn retrieve_report(
+    state: &SessionState,
+    auth: &AuthInfo,
+    report_id: String,
+) -> AnalyticsResult<ReportStatusResponse> {
+    let process = state
+        .store
+        .find_process_by_id(&get_report_process_tracker_id(&report_id))
+        .await
+        .change_context(AnalyticsError::UnknownError)
+        .attach_printable("Failed to find report generation task")?
+        .ok_or(AnalyticsError::ReportNotFound)?;
+    let tracking_data: ReportGenerationTrackingData = process
+        .tracking_data
+        .parse_value("ReportGenerationTrackingData")
+        .change_context(AnalyticsError::UnknownError)?;
+
+    if !is_report_accessible(auth, &tracking_data.auth) {
+        return Err(AnalyticsError::ReportNotFound.into());
+    }
+
+    let (download_url, download_url_expires_at, row_count) = match tracking_data.status {
+        ReportStatus::Completed => {
+            let (download_url, download_url_expires_at) =
+                get_download_link(state, &tracking_data).await?;
+            (
+                Some(download_url),
+                Some(download_url_expires_at),
+                tracking_data.row_count,
+            )
+        }
+        ReportStatus::Pending | ReportStatus::Failed => (None, None, None),
+    };
+
+    Ok(ReportStatusResponse {
+        report_id: tracking_data.report_id,
+        report_type: tracking_data.report_type,
+        format: tracking_data.format,
+        status: tracking_data.status,
+        start_time: tracking_data.time_range.start_time,
+        end_time: tracking_data.time_range.end_time,
+        row_count,
+        download_url,
+        download_url_expires_at,
+    })
+}
+
+/// Parts of a generated report referred to by a download token.
+pub struct ReportDownload {
+    file_storage_client: Arc<dyn FileStorageInterface>,
+    format: ReportFormat,
+    file_keys: Vec<String>,
+}
+
+impl ReportDownload {
+    pub fn get_content_type(&self) -> &'static str {
+        match self.format {
+            ReportFormat::Csv => "text/csv",
+            ReportFormat::Parquet => "application/vnd.apache.parquet",
+        }
+    }
+
+    /// Streams the parts of the report in order, retrieving one part at a time from the file
+    /// storage so that the whole report is never held in memory.
+    pub fn into_stream(self) -> impl Stream<Item = Result<bytes::Bytes, std::io::Error>> {
+        let Self {
+            file_storage_client,
+            file_keys,
+            ..
+        } = self;
+
+        futures::stream::iter(file_keys).then(move |file_key| {
+            let file_storage_client = file_storage_client.clone();
+            async move {
+                file_storage_client
+                    .retrieve_file(&file_key)
+                    .await
+                    .map(bytes::Bytes::from)
+                    .map_err(|error| {
+                        logger::error!(?error, %file_key, "Failed to retrieve report part");
+                        std::io::Error::other("Failed to retrieve report part from file storage")
+                    })
+            }
+        })
+    }
+}
+
+/// Resolves a download token to the parts of the generated report, if the token is valid.
+#[instrument(skip_all)]
+pub async fn get_report_download(
+    state: &SessionState,
+    token: String,
+) -> AnalyticsResult<ReportDownload> {
+    let report_files = state
+        .store
+        .get_redis_conn()
+        .change_context(AnalyticsError::UnknownError)
+        .attach_printable("Failed to get redis connection")?
+        .get_key::<Option<String>>(&get_download_token_key(&token).into())
+        .await
+        .change_context(AnalyticsError::UnknownError)
+        .attach_printable("Failed to fetch report download token from redis")?
+        .ok_or(AnalyticsError::ReportNotFound)
+        .attach_printable("Report download token is invalid or expired")?
+        .parse_struct::<ReportFiles>("ReportFiles")
+        .change_context(AnalyticsError::UnknownError)?;
+
+    Ok(ReportDownload {
+        file_storage_client: state.file_storage_client.clone(),
+        format: report_files.format,
+        file_keys: report_files.file_keys,
+    })
+}
+
+/// Fetches the next page of rows after the cursor from the analytics data source, and uploads
+/// it to the file storage as the next part of the report. The file key, the cursor and the row
+/// count are updated in the tracking data, so that the generation can resume from the next page
+/// after a failure. Returns `true` once all the rows of the report have been written.
+#[instrument(skip_all, fields(report_id = %tracking_data.report_id))]
+pub(crate) async fn generate_next_report_part(
+    state: &SessionState,
+    tracking_data: &mut ReportGenerationTrackingData,
+) -> AnalyticsResult<bool> {
+    let page_size = state.conf.report_download_config.native.page_size;
+    let rows = get_report_rows(
+        &state.pool,
+        tracking_data.report_type,
+        &tracking_data.auth,
+        &tracking_data.time_range,
+        tracking_data.cursor.as_ref(),
+        page_size,
+    )
+    .await?;
+    let fetched_count = u64::try_from(rows.len()).change_context(AnalyticsError::UnknownError)?;
+    let is_last_part = fetched_count < page_size;
+
+    // An empty page is skipped, unless the report has no rows at all
+    if fetched_count > 0 || tracking_data.file_keys.is_empty() {
+        let cursor = rows.last().map(|row| row.get_cursor());
+        let report_part = match tracking_data.format {
+            ReportFormat::Csv => encode_csv_report_part(rows, tracking_data.file_keys.is_empty())?,
+            ReportFormat::Parquet => encode_report_part(tracking_data.report_type, &rows)?,
+        };
+
+        let file_key = get_report_part_file_key(tracking_data, tracking_data.file_keys.len());
+        state
+            .file_storage_client
+            .upload_file(&file_key, report_part)
+            .await
+            .change_context(AnalyticsError::UnknownError)
+            .attach_printable("Failed to upload report to file storage")?;
+
+        tracking_data.file_keys.push(file_key);
+        tracking_data.cursor = cursor;
+        tracking_data.row_count = Some(tracking_data.row_count.unwrap_or_default() + fetched_count);
+    }
+
+    if is_last_part && tracking_data.format == ReportFormat::Parquet {
+        merge_parquet_report_parts(state, tracking_data).await?;
+    }
+
+    Ok(is_last_part)
+}
+
+/// Only the first part of a CSV report contains the header.
+fn encode_csv_report_part(rows: Vec<ReportRecord>, has_headers: bool) -> AnalyticsResult<Vec<u8>> {
+    let mut csv_writer = csv::WriterBuilder::new()
+        .has_headers(has_headers)
+        .from_writer(Vec::new());
+    for row in rows {
+        csv_writer
+            .serialize(row)
+            .change_context(AnalyticsError::UnknownError)
+            .attach_printable("Failed to write report row")?;
+    }
+
+    csv_writer
+        .into_inner()
+        .map_err(|error| error.into_error())
+        .change_context(AnalyticsError::UnknownError)
+        .attach_printable("Failed to write report")
+}
+
+/// Parquet files cannot be concatenated, so the parts of a Parquet report are merged into a
+/// single file once all of them are uploaded. The parts are left in place, so that the merge
+/// can be retried if the generation fails before it is completed.
+async fn merge_parquet_report_parts(
+    state: &SessionState,
+    tracking_data: &mut ReportGenerationTrackingData,
+) -> AnalyticsResult<()> {
+    let mut report_writer = ParquetReportWriter::new(tracking_data.report_type)?;
+    for file_key in &tracking_data.file_keys {
+        let report_part = state
+            .file_storage_client
+            .retrieve_file(file_key)
+            .await
+            .change_context(AnalyticsError::UnknownError)
+            .attach_printable("Failed to retrieve report part from file storage")?;
+        report_writer.append_part(report_part)?;
+    }
+    let report = report_writer.finish()?;
+
+    let file_key = get_report_file_key(tracking_data);
+    state
+        .file_storage_client
+        .upload_file(&file_key, report)
+        .await
+        .change_context(AnalyticsError::UnknownError)
+        .attach_printable("Failed to upload report to file storage")?;
+
+    tracking_data.file_keys = vec![file_key];
+
+    Ok(())
+}
+
+/// Returns the download link of a generated report, reusing the link issued to an earlier poll
+/// for the first half of its lifetime, so that a reused link is never about to expire.
+async fn get_download_link(
+    state: &SessionState,
+    tracking_data: &ReportGenerationTrackingData,
+) -> AnalyticsResult<(String, time::PrimitiveDateTime)> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(AnalyticsError::UnknownError)
+        .attach_printable("Failed to get redis connection")?;
+    let download_link_key = get_download_link_key(&tracking_data.report_id);
+
+    let download_link = match redis_conn
+        .get_and_deserialize_key::<ReportDownloadLink>(
+            &download_link_key.as_str().into(),
+            "ReportDownloadLink",
+        )
+        .await
+    {
+        Ok(download_link) => download_link,
+        Err(error) if error.current_context() == &RedisError::NotFound => {
+            let expiry = state
+                .conf
+                .report_download_config
+                .native
+                .download_link_expiry_in_secs;
+            let download_link = ReportDownloadLink {
+                token: common_utils::generate_id_with_default_len("rpt_dl"),
+                expires_at: common_utils::date_time::now()
+                    .saturating_add(time::Duration::seconds(expiry)),
+            };
+            let report_files = ReportFiles {
+                format: tracking_data.format,
+                file_keys: tracking_data.file_keys.clone(),
+            }
+            .encode_to_string_of_json()
+            .change_context(AnalyticsError::UnknownError)
+            .attach_printable("Failed to serialize report files")?;
+
+            redis_conn
+                .set_key_with_expiry(
+                    &get_download_token_key(&download_link.token).into(),
+                    report_files,
+                    expiry,
+                )
+                .await
+                .change_context(AnalyticsError::UnknownError)
+                .attach_printable("Failed to store report download token in redis")?;
+            redis_conn
+                .serialize_and_set_key_with_expiry(
+                    &download_link_key.as_str().into(),
+                    &download_link,
+                    (expiry / 2).max(1),
+                )
+                .await
+                .change_context(AnalyticsError::UnknownError)
+                .attach_printable("Failed to store report download link in redis")?;
+
+            download_link
+        }
+        Err(error) => {
+            return Err(error)
+                .change_context(AnalyticsError::UnknownError)
+                .attach_printable("Failed to fetch report download link from redis")
+        }
+    };
+
+    Ok((
+        format!(
+            "{}/analytics/v1/report/download/{}",
+            state.base_url, download_link.token
+        ),
+        download_link.expires_at,
+    ))
+}
+
+/// A report is accessible if the entities it was generated for are a subset of the entities the
+/// caller has access to.
+fn is_report_accessible(auth: &AuthInfo, report_auth: &AuthInfo) -> bool {
+    match (auth, report_auth) {
+        (
+            AuthInfo::OrgLevel { org_id },
+            AuthInfo::OrgLevel {
+                org_id: report_org_id,
+            },
+        )
+        | (
+            AuthInfo::OrgLevel { org_id },
+            AuthInfo::MerchantLevel {
+                org_id: report_org_id,
+                ..
+            },
+        )
+        | (
+            AuthInfo::OrgLevel { org_id },
+            AuthInfo::ProfileLevel {
+                org_id: report_org_id,
+                ..
+            },
+        ) => org_id == report_org_id,
+        (
+            AuthInfo::MerchantLevel {
+                org_id,
+                merchant_ids,
+            },
+            AuthInfo::MerchantLevel {
+                org_id: report_org_id,
+                merchant_ids: report_merchant_ids,
+            },
+        ) => {
+            org_id == report_org_id
+                && report_merchant_ids
+                    .iter()
+                    .all(|merchant_id| merchant_ids.contains(merchant_id))
+        }
+        (
+            AuthInfo::MerchantLevel {
+                org_id,
+                merchant_ids,
+            },
+            AuthInfo::ProfileLevel {
+                org_id: report_org_id,
+                merchant_id: report_merchant_id,
+                ..
+            },
+        ) => org_id == report_org_id && merchant_ids.contains(report_merchant_id),
+        (
+            AuthInfo::ProfileLevel {
+                org_id,
+                merchant_id,
+                profile_ids,
+            },
+            AuthInfo::ProfileLevel {
+                org_id: report_org_id,
+                merchant_id: report_merchant_id,
+                profile_ids: report_profile_ids,
+            },
+        ) => {
+            org_id == report_org_id
+                && merchant_id == report_merchant_id
+                && report_profile_ids
+                    .iter()
+                    .all(|profile_id| profile_ids.contains(profile_id))
+        }
+        (AuthInfo::MerchantLevel { .. }, AuthInfo::OrgLevel { .. })
+        | (AuthInfo::ProfileLevel { .. }, AuthInfo::OrgLevel { .. })
+        | (AuthInfo::ProfileLevel { .. }, AuthInfo::MerchantLevel { .. }) => false,
+    }
+}
+
+fn get_report_process_tracker_id(report_id: &str) -> String {
+    format!("{REPORT_GENERATION_RUNNER}_{REPORT_GENERATION_TASK}_{report_id}")
+}
+
+fn get_report_part_file_key(
+    tracking_data: &ReportGenerationTrackingData,
+    part_number: usize,
+) -> String {
+    format!(
+        "reports/{}/{}/part_{part_number:05}.{}",
+        tracking_data.report_type, tracking_data.report_id, tracking_data.format
+    )
+}
+
+fn get_report_file_key(tracking_data: &ReportGenerationTrackingData) -> String {
+    format!(
+        "reports/{}/{}/report.{}",
+        tracking_data.report_type, tracking_data.report_id, tracking_data.format
+    )
+}
+
+fn get_download_token_key(token: &str) -> String {
+    format!("{REPORT_DOWNLOAD_TOKEN_KEY_PREFIX}_{token}")
+}
+
+fn get_download_link_key(report_id: &str) -> String {
+    format!("{REPORT_DOWNLOAD_LINK_KEY_PREFIX}_{report_id}")
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use std::borrow::Cow;
+
+    use common_utils::id_type;
+
+    use super::*;
+
+    fn org_id(id: &'static str) -> id_type::OrganizationId {
+        id_type::OrganizationId::try_from(Cow::from(id)).unwrap()
+    }
+
+    fn merchant_id(id: &'static str) -> id_type::MerchantId {
+        id_type::MerchantId::try_from(Cow::from(id)).unwrap()
+    }
+
+    fn profile_id(id: &'static str) -> id_type::ProfileId {
+        id_type::ProfileId::try_from(Cow::from(id)).unwrap()
+    }
+
+    fn org_level(org: &'static str) -> AuthInfo {
+        AuthInfo::OrgLevel {
+            org_id: org_id(org),
+        }
+    }
+
+    fn merchant_level(org: &'static str, merchants: &[&'static str]) -> AuthInfo {
+        AuthInfo::MerchantLevel {
+            org_id: org_id(org),
+            merchant_ids: merchants.iter().map(|id| merchant_id(id)).collect(),
+        }
+    }
+
+    fn profile_level(
+        org: &'static str,
+        merchant: &'static str,
+        profiles: &[&'static str],
+    ) -> AuthInfo {
+        AuthInfo::ProfileLevel {
+            org_id: org_id(org),
+            merchant_id: merchant_id(merchant),
+            profile_ids: profiles.iter().map(|id| profile_id(id)).collect(),
+        }
+    }
+
+    #[test]
+    fn test_is_report_accessible() {
+        // (caller, report, is accessible)
+        let cases = [
+            (org_level("org_1"), org_level("org_1"), true),
+            (org_level("org_1"), org_level("org_2"), false),
+            (
+                org_level("org_1"),
+                merchant_level("org_1", &["mer_1"]),
+                true,
+            ),
+            (
+                org_level("org_1"),
+                merchant_level("org_2", &["mer_1"]),
+                false,
+            ),
+            (
+                org_level("org_1"),
+                profile_level("org_1", "mer_1", &["pro_1"]),
+                true,
+            ),
+            (
+                org_level("org_1"),
+                profile_level("org_2", "mer_1", &["pro_1"]),
+                false,
+            ),
+            (
+                merchant_level("org_1", &["mer_1"]),
+                org_level("org_1"),
+                false,
+            ),
+            (
+                merchant_level("org_1", &["mer_1"]),
+                merchant_level("org_1", &["mer_1"]),
+                true,
+            ),
+            (
+                merchant_level("org_1", &["mer_1", "mer_2"]),
+                merchant_level("org_1", &["mer_1"]),
+                true,
+            ),
+            (
+                merchant_level("org_1", &["mer_1"]),
+                merchant_level("org_1", &["mer_1", "mer_2"]),
+                false,
+            ),
+            (
+                merchant_level("org_1", &["mer_1"]),
+                merchant_level("org_2", &["mer_1"]),
+                false,
+            ),
+            (
+                merchant_level("org_1", &["mer_1"]),
+                profile_level("org_1", "mer_1", &["pro_1"]),
+                true,
+            ),
+            (
+                merchant_level("org_1", &["mer_1"]),
+                profile_level("org_1", "mer_2", &["pro_1"]),
+                false,
+            ),
+            (
+                merchant_level("org_1", &["mer_1"]),
+                profile_level("org_2", "mer_1", &["pro_1"]),
+                false,
+            ),
+            (
+                profile_level("org_1", "mer_1", &["pro_1"]),
+                org_level("org_1"),
+                false,
+            ),
+            (
+                profile_level("org_1", "mer_1", &["pro_1"]),
+                merchant_level("org_1", &["mer_1"]),
+                false,
+            ),
+            (
+                profile_level("org_1", "mer_1", &["pro_1"]),
+                profile_level("org_1", "mer_1", &["pro_1"]),
+                true,
+            ),
+            (
+                profile_level("org_1", "mer_1", &["pro_1", "pro_2"]),
+                profile_level("org_1", "mer_1", &["pro_1"]),
+                true,
+            ),
+            (
+                profile_level("org_1", "mer_1", &["pro_1"]),
+                profile_level("org_1", "mer_1", &["pro_1", "pro_2"]),
+                false,
+            ),
+            (
+                profile_level("org_1", "mer_1", &["pro_1"]),
+                profile_level("org_1", "mer_2", &["pro_1"]),
+                false,
+            ),
+        ];
+
+        for (auth, report_auth, is_accessible) in cases {
+            assert_eq!(
+                is_report_accessible(&auth, &report_auth),
+                is_accessible,
+                "caller: {auth:?}, report: {report_auth:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_get_report_part_file_key() {
+        let mut tracking_data = ReportGenerationTrackingData {
+            report_id: "rpt_1".to_string(),
+            report_type: ReportType::Payment,
+            format: ReportFormat::Csv,
+            auth: org_level("org_1"),
+            time_range: TimeRange {
+                start_time: common_utils::date_time::now(),
+                end_time: None,
+            },
+            status: ReportStatus::Pending,
+            file_keys: Vec::new(),
+            cursor: None,
+            row_count: None,
+        };
+
+        assert_eq!(
+            get_report_part_file_key(&tracking_data, 0),
+            "reports/payment/rpt_1/part_00000.csv"
+        );
+        assert_eq!(
+            get_report_part_file_key(&tracking_data, 12),
+            "reports/payment/rpt_1/part_00012.csv"
+        );
+
+        tracking_data.format = ReportFormat::Parquet;
+        assert_eq!(
+            get_report_part_file_key(&tracking_data, 12),
+            "reports/payment/rpt_1/part_00012.parquet"
+        );
+        assert_eq!(
+            get_report_file_key(&tracking_data),
+            "reports/payment/rpt_1/report.parquet"
+        );
+    }
+}
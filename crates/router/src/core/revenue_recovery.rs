@@ -218,6 +218,7 @@ async fn insert_psync_pcr_task_to_pt(
         profile_id,
         payment_attempt_id,
         revenue_recovery_retry,
+        retry_decisions: Vec::new(),
     };
     let tag = ["REVENUE_RECOVERY"];
     let process_tracker_entry = storage::ProcessTrackerNew::new(
@@ -31,6 +31,7 @@ impl ForeignFrom<AttemptStatus> for RevenueRecoveryPaymentsAttemptStatus {
             | AttemptStatus::ConfirmationAwaited
             | AttemptStatus::PartialCharged
             | AttemptStatus::PartialChargedAndChargeable
+            | AttemptStatus::PartiallyAuthorized
             | AttemptStatus::PaymentMethodAwaited
             | AttemptStatus::AuthenticationPending
             | AttemptStatus::DeviceDataCollectionPending
@@ -9,7 +9,7 @@ use api_models::{
 };
 use common_utils::{
     self,
-    ext_traits::{OptionExt, ValueExt},
+    ext_traits::{Encode, OptionExt, ValueExt},
     id_type,
 };
 use diesel_models::{enums, process_tracker::business_status, types as diesel_types};
@@ -34,6 +34,7 @@ use crate::{
         errors::{self, RouterResult},
         payments::{self, helpers, operations::Operation},
         revenue_recovery::{self as revenue_recovery_core},
+        utils as core_utils,
     },
     db::StorageInterface,
     logger,
@@ -42,11 +43,21 @@ use crate::{
     types::{
         self, api as api_types, api::payments as payments_types, storage, transformers::ForeignInto,
     },
-    workflows::{payment_sync, revenue_recovery::get_schedule_time_to_retry_mit_payments},
+    workflows::payment_sync,
 };
 
 type RecoveryResult<T> = error_stack::Result<T, errors::RecoveryError>;
 
+/// Gets the name of the flow in which the payment attempt failed, with which the GSM record of its
+/// error is looked up
+fn get_failed_attempt_flow_name(attempt_status: enums::AttemptStatus) -> RouterResult<String> {
+    match attempt_status {
+        enums::AttemptStatus::CaptureFailed => core_utils::get_flow_name::<api_types::Capture>(),
+        enums::AttemptStatus::VoidFailed => core_utils::get_flow_name::<api_types::Void>(),
+        _ => core_utils::get_flow_name::<api_types::Authorize>(),
+    }
+}
+
 /// The status of Passive Churn Payments
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
 pub enum RevenueRecoveryPaymentsAttemptStatus {
@@ -131,19 +142,18 @@ impl RevenueRecoveryPaymentsAttemptStatus {
                     )
                     .await?;
 
-                // get a reschedule time
-                let schedule_time = get_schedule_time_to_retry_mit_payments(
-                    db,
-                    &revenue_recovery_payment_data
-                        .merchant_account
-                        .get_id()
-                        .clone(),
-                    process_tracker.retry_count + 1,
+                // decide whether the payment can be retried
+                let action = Action::decide_retry_failure_action(
+                    state,
+                    revenue_recovery_payment_data.merchant_account.get_id(),
+                    process_tracker.clone(),
+                    revenue_recovery_payment_data,
+                    &payment_attempt,
                 )
-                .await;
+                .await?;
 
                 // check if retry is possible
-                if let Some(schedule_time) = schedule_time {
+                if let Action::RetryPayment(schedule_time) = action {
                     // schedule a retry
                     // TODO: Update connecter called field and active attempt
 
@@ -283,7 +293,6 @@ impl Action {
         revenue_recovery_payment_data: &storage::revenue_recovery::RevenueRecoveryPaymentData,
         revenue_recovery_metadata: &PaymentRevenueRecoveryMetadata,
     ) -> RecoveryResult<Self> {
-        let db = &*state.store;
         let response = revenue_recovery_core::api::call_proxy_api(
             state,
             payment_intent,
@@ -299,7 +308,7 @@ impl Action {
                 )),
                 RevenueRecoveryPaymentsAttemptStatus::Failed => {
                     Self::decide_retry_failure_action(
-                        db,
+                        state,
                         merchant_id,
                         process.clone(),
                         revenue_recovery_payment_data,
@@ -483,7 +492,6 @@ impl Action {
             revenue_recovery_payment_data,
         )
         .await;
-        let db = &*state.store;
         match response {
             Ok(_payment_data) => match payment_attempt.status.foreign_into() {
                 RevenueRecoveryPaymentsAttemptStatus::Succeeded => {
@@ -491,7 +499,7 @@ impl Action {
                 }
                 RevenueRecoveryPaymentsAttemptStatus::Failed => {
                     Self::decide_retry_failure_action(
-                        db,
+                        state,
                         revenue_recovery_payment_data.merchant_account.get_id(),
                         process.clone(),
                         revenue_recovery_payment_data,
@@ -684,16 +692,68 @@ impl Action {
     }
 
     pub(crate) async fn decide_retry_failure_action(
-        db: &dyn StorageInterface,
+        state: &SessionState,
         merchant_id: &id_type::MerchantId,
         pt: storage::ProcessTracker,
         revenue_recovery_payment_data: &storage::revenue_recovery::RevenueRecoveryPaymentData,
         payment_attempt: &payment_attempt::PaymentAttempt,
     ) -> RecoveryResult<Self> {
+        let db = &*state.store;
         let next_retry_count = pt.retry_count + 1;
-        let schedule_time = revenue_recovery_payment_data
-            .get_schedule_time_based_on_retry_type(db, merchant_id, next_retry_count)
-            .await;
+
+        let error = payment_attempt.error.as_ref();
+        let flow_name = get_failed_attempt_flow_name(payment_attempt.status).ok();
+        let gsm_record = match (error, payment_attempt.connector.as_ref(), flow_name) {
+            (Some(error), Some(connector), Some(flow_name)) => {
+                helpers::get_gsm_record(
+                    state,
+                    Some(error.code.clone()),
+                    Some(error.message.clone()),
+                    connector.to_string(),
+                    flow_name,
+                )
+                .await
+            }
+            _ => None,
+        };
+        let decline_type = storage::revenue_recovery::RevenueRecoveryDeclineType::from_gsm_record(
+            gsm_record.as_ref(),
+        );
+
+        let schedule_time = match decline_type {
+            // a hard decline would fail again with the same payment method, so stop retrying
+            storage::revenue_recovery::RevenueRecoveryDeclineType::Hard => None,
+            storage::revenue_recovery::RevenueRecoveryDeclineType::Soft
+            | storage::revenue_recovery::RevenueRecoveryDeclineType::Unknown => {
+                revenue_recovery_payment_data
+                    .get_schedule_time_based_on_retry_type(db, merchant_id, next_retry_count)
+                    .await
+            }
+        };
+
+        let retry_decision = storage::revenue_recovery::RevenueRecoveryRetryDecision {
+            payment_attempt_id: payment_attempt.get_id().clone(),
+            retry_count: next_retry_count,
+            error_code: error.map(|error| error.code.clone()),
+            error_message: error.map(|error| error.message.clone()),
+            gsm_decision: gsm_record.as_ref().map(|gsm| gsm.decision.clone()),
+            error_category: gsm_record.as_ref().and_then(|gsm| gsm.error_category),
+            decline_type,
+            retry_scheduled_at: schedule_time,
+            decided_at: common_utils::date_time::now(),
+        };
+        logger::info!(?retry_decision, "Revenue recovery retry decision");
+
+        // Recording the decision is best effort and should not block the retry
+        record_retry_decision_in_process_tracker(db, pt, retry_decision)
+            .await
+            .inspect_err(|error| {
+                logger::error!(
+                    ?error,
+                    "Failed to record the retry decision in process tracker"
+                )
+            })
+            .ok();
 
         match schedule_time {
             Some(schedule_time) => Ok(Self::RetryPayment(schedule_time)),
@@ -703,6 +763,43 @@ impl Action {
     }
 }
 
+async fn record_retry_decision_in_process_tracker(
+    db: &dyn StorageInterface,
+    process: storage::ProcessTracker,
+    retry_decision: storage::revenue_recovery::RevenueRecoveryRetryDecision,
+) -> RecoveryResult<()> {
+    let mut tracking_data = process
+        .tracking_data
+        .clone()
+        .parse_value::<storage::revenue_recovery::RevenueRecoveryWorkflowTrackingData>(
+            "PCRWorkflowTrackingData",
+        )
+        .change_context(errors::RecoveryError::ValueNotFound)
+        .attach_printable("unable to deserialize Pcr Workflow Tracking Data")?;
+    tracking_data.retry_decisions.push(retry_decision);
+
+    let tracking_data = tracking_data
+        .encode_to_value()
+        .change_context(errors::RecoveryError::ProcessTrackerFailure)
+        .attach_printable("Failed to serialize Pcr Workflow Tracking Data")?;
+
+    let pt_update = storage::ProcessTrackerUpdate::Update {
+        name: None,
+        retry_count: None,
+        schedule_time: None,
+        tracking_data: Some(tracking_data),
+        business_status: None,
+        status: None,
+        updated_at: Some(common_utils::date_time::now()),
+    };
+    db.as_scheduler()
+        .update_process(process, pt_update)
+        .await
+        .change_context(errors::RecoveryError::ProcessTrackerFailure)
+        .attach_printable("Failed to update the process tracker")?;
+    Ok(())
+}
+
 // TODO: Move these to impl based functions
 async fn record_back_to_billing_connector(
     state: &SessionState,
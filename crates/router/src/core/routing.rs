@@ -1,4 +1,6 @@
 pub mod helpers;
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub mod simulation;
 pub mod transformers;
 use std::collections::HashSet;
 
@@ -341,8 +343,19 @@ pub async fn create_routing_algorithm_under_profile(
 
     let mut decision_engine_routing_id: Option<String> = None;
 
-    if let Some(EuclidAlgorithm::Advanced(program)) = request.algorithm.clone() {
-        let internal_program: Program = program.into();
+    if let Some(internal_program) =
+        request
+            .algorithm
+            .clone()
+            .and_then(|algorithm| match algorithm {
+                EuclidAlgorithm::Advanced(program) => Program::try_from(program)
+                    .map_err(|error| {
+                        logger::error!(decision_engine_error=?error, "decision_engine_euclid");
+                    })
+                    .ok(),
+                _ => None,
+            })
+    {
         let routing_rule = RoutingRule {
             name: name.clone(),
             description: Some(description.clone()),
@@ -0,0 +1,669 @@
+//! Simulation of routing algorithms over the payments made in the past
+//!
+//! The payment data required by the routing DSL is rebuilt from the stored payment intents and
+//! attempts, which only retain a subset of the data available while routing a live payment. The
+//! billing country is not considered, and the card details are limited to the additional payment
+//! method data stored against the attempt.
+
+use std::collections::HashMap;
+
+use api_models::{
+    enums as api_enums,
+    routing::{self as routing_types, ConnectorSelection},
+};
+use error_stack::ResultExt;
+use euclid::{
+    backend::{inputs as dsl_inputs, BackendOutput, EuclidBackend, VirInterpreterBackend},
+    enums as euclid_enums,
+};
+use hyperswitch_domain_models::{
+    mandates,
+    payments::payment_intent::{PaymentIntentFetchConstraints, PaymentIntentListParams},
+};
+use masking::PeekInterface;
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    core::{
+        errors::{self, CustomResult, RouterResponse, StorageErrorExt},
+        payments::routing as payments_routing,
+        utils as core_utils,
+    },
+    routes::SessionState,
+    services::api as service_api,
+    types::{domain, storage, transformers::ForeignInto},
+    utils::{self, OptionExt, ValueExt},
+};
+
+const DEFAULT_SIMULATION_LIMIT: u32 = 1000;
+const MAX_SIMULATION_LIMIT: u32 = 10000;
+/// The number of payments fetched from the database at once
+const SIMULATION_BATCH_SIZE: u32 = 100;
+
+type SimulationResult<O> = CustomResult<O, errors::RoutingError>;
+
+/// Evaluate a routing algorithm against the payments created under the profile within a time
+/// range, and compare the connectors chosen by the algorithm with the connectors that were
+/// actually used. The routing of live payments is not affected.
+#[instrument(skip_all)]
+pub async fn simulate_routing_algorithm(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    authentication_profile_id: Option<common_utils::id_type::ProfileId>,
+    request: routing_types::RoutingSimulationRequest,
+) -> RouterResponse<routing_types::RoutingSimulationResponse> {
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+
+    let limit = request.limit.unwrap_or(DEFAULT_SIMULATION_LIMIT);
+    utils::when(!(1..=MAX_SIMULATION_LIMIT).contains(&limit), || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("limit should be in between 1 and {MAX_SIMULATION_LIMIT}"),
+        })
+    })?;
+    utils::when(
+        request
+            .time_range
+            .end_time
+            .is_some_and(|end_time| end_time < request.time_range.start_time),
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "`time_range.end_time` must not be before `time_range.start_time`"
+                    .to_string(),
+            })
+        },
+    )?;
+
+    let business_profile = core_utils::validate_and_get_business_profile(
+        db,
+        key_manager_state,
+        merchant_context.get_merchant_key_store(),
+        Some(&request.profile_id),
+        merchant_id,
+    )
+    .await?
+    .get_required_value("Profile")?;
+
+    core_utils::validate_profile_id_from_auth_layer(authentication_profile_id, &business_profile)?;
+
+    let (algorithm_id, algorithm) = match request.algorithm {
+        routing_types::RoutingSimulationAlgorithm::AlgorithmId(algorithm_id) => {
+            let record = db
+                .find_routing_algorithm_by_profile_id_algorithm_id(
+                    business_profile.get_id(),
+                    &algorithm_id,
+                )
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
+
+            utils::when(
+                record.algorithm_for != api_enums::TransactionType::Payment,
+                || {
+                    Err(errors::ApiErrorResponse::InvalidRequestData {
+                        message: "Only routing algorithms for payments can be simulated"
+                            .to_string(),
+                    })
+                },
+            )?;
+
+            let algorithm: routing_types::StaticRoutingAlgorithm = record
+                .algorithm_data
+                .parse_value("RoutingAlgorithm")
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Unable to parse the routing algorithm")?;
+
+            (Some(algorithm_id), algorithm)
+        }
+        routing_types::RoutingSimulationAlgorithm::Advanced(program) => (
+            None,
+            routing_types::StaticRoutingAlgorithm::Advanced(program),
+        ),
+    };
+
+    let simulator = RoutingSimulator::try_from(algorithm)?;
+    let mut summary = RoutingSimulationSummary::new(simulator.rule_names());
+
+    let mut offset = 0;
+    while offset < limit {
+        let batch_size = SIMULATION_BATCH_SIZE.min(limit - offset);
+        let constraints = PaymentIntentFetchConstraints::List(Box::new(PaymentIntentListParams {
+            offset,
+            starting_at: Some(request.time_range.start_time),
+            ending_at: request.time_range.end_time,
+            amount_filter: None,
+            connector: None,
+            currency: None,
+            status: None,
+            payment_method: None,
+            payment_method_type: None,
+            authentication_type: None,
+            merchant_connector_id: None,
+            profile_id: Some(vec![business_profile.get_id().to_owned()]),
+            customer_id: None,
+            starting_after_id: None,
+            ending_before_id: None,
+            limit: Some(batch_size),
+            order: Default::default(),
+            card_network: None,
+            card_discovery: None,
+            merchant_order_reference_id: None,
+        }));
+
+        let payments = db
+            .get_filtered_payment_intents_attempt(
+                key_manager_state,
+                merchant_id,
+                &constraints,
+                merchant_context.get_merchant_key_store(),
+                merchant_context.get_merchant_account().storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch payments for routing simulation")?;
+
+        let fetched_count = payments.len();
+        for (payment_intent, payment_attempt) in payments {
+            let outcome = make_dsl_input_from_stored_payment(&payment_intent, &payment_attempt)
+                .and_then(|backend_input| simulator.execute(backend_input));
+
+            match outcome {
+                Ok(outcome) => summary.record(outcome, payment_attempt.connector.as_deref()),
+                Err(error) => {
+                    logger::warn!(
+                        payment_id = ?payment_intent.payment_id,
+                        ?error,
+                        "Unable to simulate routing for payment"
+                    );
+                    summary.record_failure(payment_attempt.connector.as_deref());
+                }
+            }
+        }
+
+        if u32::try_from(fetched_count).unwrap_or(u32::MAX) < batch_size {
+            break;
+        }
+        offset += batch_size;
+    }
+
+    Ok(service_api::ApplicationResponse::Json(
+        summary.into_response(
+            business_profile.get_id().to_owned(),
+            algorithm_id,
+            request.time_range,
+        ),
+    ))
+}
+
+enum RoutingSimulator {
+    /// Algorithms which choose the connectors irrespective of the payment
+    Static(ConnectorSelection),
+    Advanced {
+        rule_names: Vec<String>,
+        interpreter: Box<VirInterpreterBackend<ConnectorSelection>>,
+    },
+}
+
+struct SimulationOutcome {
+    rule_name: Option<String>,
+    connector: api_enums::RoutableConnectors,
+}
+
+impl TryFrom<routing_types::StaticRoutingAlgorithm> for RoutingSimulator {
+    type Error = error_stack::Report<errors::ApiErrorResponse>;
+
+    fn try_from(algorithm: routing_types::StaticRoutingAlgorithm) -> Result<Self, Self::Error> {
+        match algorithm {
+            routing_types::StaticRoutingAlgorithm::Single(choice) => {
+                Ok(Self::Static(ConnectorSelection::Priority(vec![*choice])))
+            }
+            routing_types::StaticRoutingAlgorithm::Priority(choices) => {
+                Ok(Self::Static(ConnectorSelection::Priority(choices)))
+            }
+            routing_types::StaticRoutingAlgorithm::VolumeSplit(splits) => {
+                Ok(Self::Static(ConnectorSelection::VolumeSplit(splits)))
+            }
+            routing_types::StaticRoutingAlgorithm::Advanced(program) => {
+                let rule_names = program.rules.iter().map(|rule| rule.name.clone()).collect();
+                let interpreter =
+                    VirInterpreterBackend::with_program(program).map_err(|error| {
+                        errors::ApiErrorResponse::InvalidRequestData {
+                            message: format!("Invalid routing program: {error:?}"),
+                        }
+                    })?;
+
+                Ok(Self::Advanced {
+                    rule_names,
+                    interpreter: Box::new(interpreter),
+                })
+            }
+            routing_types::StaticRoutingAlgorithm::ThreeDsDecisionRule(_) => {
+                Err(errors::ApiErrorResponse::InvalidRequestData {
+                    message: "3DS decision rules cannot be simulated".to_string(),
+                }
+                .into())
+            }
+        }
+    }
+}
+
+impl RoutingSimulator {
+    fn rule_names(&self) -> &[String] {
+        match self {
+            Self::Static(_) => &[],
+            Self::Advanced { rule_names, .. } => rule_names,
+        }
+    }
+
+    fn execute(
+        &self,
+        backend_input: dsl_inputs::BackendInput,
+    ) -> SimulationResult<SimulationOutcome> {
+        let BackendOutput {
+            rule_name,
+            connector_selection,
+        } = match self {
+            Self::Static(connector_selection) => BackendOutput {
+                rule_name: None,
+                connector_selection: connector_selection.clone(),
+            },
+            Self::Advanced { interpreter, .. } => interpreter
+                .execute(backend_input)
+                .change_context(errors::RoutingError::DslExecutionError)?,
+        };
+
+        // Volume splits are sampled in the same manner as while routing live payments
+        let connectors = match connector_selection {
+            ConnectorSelection::Priority(choices) => choices,
+            ConnectorSelection::VolumeSplit(splits) => {
+                payments_routing::perform_volume_split(splits)?
+            }
+        };
+
+        let connector = connectors
+            .into_iter()
+            .next()
+            .ok_or(errors::RoutingError::DslFinalConnectorSelectionFailed)
+            .attach_printable("Routing algorithm did not choose any connector")?
+            .connector;
+
+        Ok(SimulationOutcome {
+            rule_name,
+            connector,
+        })
+    }
+}
+
+#[derive(Default)]
+struct ConnectorCount {
+    simulated_count: usize,
+    actual_count: usize,
+}
+
+#[derive(Default)]
+struct RuleHitCount {
+    hit_count: usize,
+    matched_count: usize,
+}
+
+struct RoutingSimulationSummary {
+    total_count: usize,
+    matched_count: usize,
+    failed_count: usize,
+    default_selection_count: usize,
+    connectors: HashMap<String, ConnectorCount>,
+    rule_names: Vec<String>,
+    rules: HashMap<String, RuleHitCount>,
+}
+
+impl RoutingSimulationSummary {
+    fn new(rule_names: &[String]) -> Self {
+        Self {
+            total_count: 0,
+            matched_count: 0,
+            failed_count: 0,
+            default_selection_count: 0,
+            connectors: HashMap::new(),
+            rule_names: rule_names.to_vec(),
+            rules: HashMap::new(),
+        }
+    }
+
+    fn record_actual_connector(&mut self, actual_connector: Option<&str>) {
+        self.total_count += 1;
+        if let Some(actual_connector) = actual_connector {
+            self.connectors
+                .entry(actual_connector.to_owned())
+                .or_default()
+                .actual_count += 1;
+        }
+    }
+
+    fn record(&mut self, outcome: SimulationOutcome, actual_connector: Option<&str>) {
+        self.record_actual_connector(actual_connector);
+
+        let simulated_connector = outcome.connector.to_string();
+        let is_match = actual_connector == Some(simulated_connector.as_str());
+        if is_match {
+            self.matched_count += 1;
+        }
+
+        self.connectors
+            .entry(simulated_connector)
+            .or_default()
+            .simulated_count += 1;
+
+        match outcome.rule_name {
+            Some(rule_name) => {
+                let rule = self.rules.entry(rule_name).or_default();
+                rule.hit_count += 1;
+                if is_match {
+                    rule.matched_count += 1;
+                }
+            }
+            None if !self.rule_names.is_empty() => self.default_selection_count += 1,
+            None => {}
+        }
+    }
+
+    fn record_failure(&mut self, actual_connector: Option<&str>) {
+        self.record_actual_connector(actual_connector);
+        self.failed_count += 1;
+    }
+
+    fn into_response(
+        mut self,
+        profile_id: common_utils::id_type::ProfileId,
+        algorithm_id: Option<common_utils::id_type::RoutingId>,
+        time_range: common_utils::types::TimeRange,
+    ) -> routing_types::RoutingSimulationResponse {
+        let total_count = self.total_count;
+        let mut connector_distribution = self
+            .connectors
+            .into_iter()
+            .map(
+                |(connector, count)| routing_types::RoutingSimulationConnectorCount {
+                    connector,
+                    simulated_count: count.simulated_count,
+                    actual_count: count.actual_count,
+                    simulated_percentage: get_percentage(count.simulated_count, total_count),
+                    actual_percentage: get_percentage(count.actual_count, total_count),
+                },
+            )
+            .collect::<Vec<_>>();
+        connector_distribution.sort_by(|a, b| {
+            b.simulated_count
+                .cmp(&a.simulated_count)
+                .then_with(|| b.actual_count.cmp(&a.actual_count))
+                .then_with(|| a.connector.cmp(&b.connector))
+        });
+
+        let rule_hits = self
+            .rule_names
+            .into_iter()
+            .map(|rule_name| {
+                let count = self.rules.remove(&rule_name).unwrap_or_default();
+                routing_types::RoutingSimulationRuleHitCount {
+                    rule_name,
+                    hit_count: count.hit_count,
+                    matched_count: count.matched_count,
+                }
+            })
+            .collect();
+
+        routing_types::RoutingSimulationResponse {
+            profile_id,
+            algorithm_id,
+            time_range,
+            total_count: self.total_count,
+            matched_count: self.matched_count,
+            failed_count: self.failed_count,
+            connector_distribution,
+            rule_hits,
+            default_selection_count: self.default_selection_count,
+        }
+    }
+}
+
+/// The share of the evaluated payments as a percentage, rounded to two decimal places
+fn get_percentage(count: usize, total_count: usize) -> f64 {
+    match (u32::try_from(count), u32::try_from(total_count)) {
+        (Ok(count), Ok(total_count)) if total_count > 0 => {
+            let percentage = f64::from(count) * 100.0 / f64::from(total_count);
+            (percentage * 100.0).round() / 100.0
+        }
+        _ => 0.0,
+    }
+}
+
+/// Rebuild the input to the routing DSL from a stored payment intent and its active attempt.
+fn make_dsl_input_from_stored_payment(
+    payment_intent: &storage::PaymentIntent,
+    payment_attempt: &storage::PaymentAttempt,
+) -> SimulationResult<dsl_inputs::BackendInput> {
+    let card_info = payment_attempt
+        .payment_method_data
+        .clone()
+        .and_then(|data| match data {
+            serde_json::Value::Null => None,
+            _ => data
+                .parse_value::<api_models::payments::AdditionalPaymentData>("AdditionalPaymentData")
+                .ok(),
+        })
+        .and_then(|data| match data {
+            api_models::payments::AdditionalPaymentData::Card(card_info) => Some(card_info),
+            _ => None,
+        });
+
+    let customer_acceptance =
+        payment_attempt
+            .customer_acceptance
+            .as_ref()
+            .and_then(|customer_acceptance| {
+                customer_acceptance
+                    .peek()
+                    .clone()
+                    .parse_value::<mandates::CustomerAcceptance>("CustomerAcceptance")
+                    .ok()
+            });
+
+    let mandate_data = dsl_inputs::MandateData {
+        mandate_acceptance_type: customer_acceptance.map(|customer_acceptance| {
+            match customer_acceptance.acceptance_type {
+                mandates::AcceptanceType::Online => euclid_enums::MandateAcceptanceType::Online,
+                mandates::AcceptanceType::Offline => euclid_enums::MandateAcceptanceType::Offline,
+            }
+        }),
+        mandate_type: payment_attempt.mandate_details.as_ref().map(
+            |mandate_type| match mandate_type {
+                mandates::MandateDataType::SingleUse(_) => euclid_enums::MandateType::SingleUse,
+                mandates::MandateDataType::MultiUse(_) => euclid_enums::MandateType::MultiUse,
+            },
+        ),
+        payment_type: Some(if payment_attempt.mandate_details.is_some() {
+            euclid_enums::PaymentType::SetupMandate
+        } else {
+            euclid_enums::PaymentType::NonMandate
+        }),
+    };
+
+    let payment_method_input = dsl_inputs::PaymentMethodInput {
+        payment_method: payment_attempt.payment_method,
+        payment_method_type: payment_attempt.payment_method_type,
+        card_network: card_info
+            .as_ref()
+            .and_then(|card_info| card_info.card_network.clone()),
+    };
+
+    let currency = payment_attempt.currency.or(payment_intent.currency).ok_or(
+        errors::RoutingError::DslMissingRequiredField {
+            field_name: "currency".to_string(),
+        },
+    )?;
+
+    let payment_input = dsl_inputs::PaymentInput {
+        amount: payment_attempt.get_total_amount(),
+        card_bin: card_info.and_then(|card_info| card_info.card_isin),
+        currency,
+        authentication_type: payment_attempt.authentication_type,
+        capture_method: payment_attempt
+            .capture_method
+            .and_then(|capture_method| capture_method.foreign_into()),
+        business_country: payment_intent
+            .business_country
+            .map(api_enums::Country::from_alpha2),
+        billing_country: None,
+        business_label: payment_intent.business_label.clone(),
+        setup_future_usage: payment_intent.setup_future_usage,
+    };
+
+    let metadata = payment_intent
+        .parse_and_get_metadata("routing_parameters")
+        .change_context(errors::RoutingError::MetadataParsingError)
+        .attach_printable("Unable to parse routing_parameters from metadata of payment_intent")
+        .unwrap_or(None);
+
+    Ok(dsl_inputs::BackendInput {
+        metadata,
+        payment: payment_input,
+        payment_method: payment_method_input,
+        mandate: mandate_data,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::float_cmp)]
+    use super::*;
+
+    fn get_outcome(
+        rule_name: Option<&str>,
+        connector: api_enums::RoutableConnectors,
+    ) -> SimulationOutcome {
+        SimulationOutcome {
+            rule_name: rule_name.map(String::from),
+            connector,
+        }
+    }
+
+    fn get_response(summary: RoutingSimulationSummary) -> routing_types::RoutingSimulationResponse {
+        summary.into_response(
+            common_utils::id_type::ProfileId::generate(),
+            None,
+            common_utils::types::TimeRange {
+                start_time: common_utils::date_time::now(),
+                end_time: None,
+            },
+        )
+    }
+
+    fn get_connector_count<'a>(
+        response: &'a routing_types::RoutingSimulationResponse,
+        connector: &str,
+    ) -> &'a routing_types::RoutingSimulationConnectorCount {
+        response
+            .connector_distribution
+            .iter()
+            .find(|count| count.connector == connector)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_summary_connector_counts_and_percentages() {
+        let mut summary = RoutingSimulationSummary::new(&[]);
+        summary.record(
+            get_outcome(None, api_enums::RoutableConnectors::Stripe),
+            Some("stripe"),
+        );
+        summary.record(
+            get_outcome(None, api_enums::RoutableConnectors::Stripe),
+            Some("adyen"),
+        );
+        summary.record(
+            get_outcome(None, api_enums::RoutableConnectors::Adyen),
+            Some("adyen"),
+        );
+        summary.record_failure(Some("checkout"));
+        summary.record_failure(None);
+        summary.record(
+            get_outcome(None, api_enums::RoutableConnectors::Stripe),
+            None,
+        );
+
+        let response = get_response(summary);
+
+        assert_eq!(response.total_count, 6);
+        assert_eq!(response.matched_count, 2);
+        assert_eq!(response.failed_count, 2);
+        assert_eq!(response.default_selection_count, 0);
+        assert!(response.rule_hits.is_empty());
+
+        // Connectors are ordered by the number of payments the algorithm routed to them
+        let connectors = response
+            .connector_distribution
+            .iter()
+            .map(|count| count.connector.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(connectors, vec!["stripe", "adyen", "checkout"]);
+
+        let stripe = get_connector_count(&response, "stripe");
+        assert_eq!((stripe.simulated_count, stripe.actual_count), (3, 1));
+        assert_eq!(stripe.simulated_percentage, 50.0);
+        assert_eq!(stripe.actual_percentage, 16.67);
+
+        let adyen = get_connector_count(&response, "adyen");
+        assert_eq!((adyen.simulated_count, adyen.actual_count), (1, 2));
+        assert_eq!(adyen.simulated_percentage, 16.67);
+        assert_eq!(adyen.actual_percentage, 33.33);
+
+        let checkout = get_connector_count(&response, "checkout");
+        assert_eq!((checkout.simulated_count, checkout.actual_count), (0, 1));
+        assert_eq!(checkout.simulated_percentage, 0.0);
+        assert_eq!(checkout.actual_percentage, 16.67);
+    }
+
+    #[test]
+    fn test_summary_rule_hits() {
+        let rule_names = vec!["card_rule".to_string(), "amount_rule".to_string()];
+        let mut summary = RoutingSimulationSummary::new(&rule_names);
+        summary.record(
+            get_outcome(Some("card_rule"), api_enums::RoutableConnectors::Stripe),
+            Some("stripe"),
+        );
+        summary.record(
+            get_outcome(Some("card_rule"), api_enums::RoutableConnectors::Stripe),
+            Some("adyen"),
+        );
+        summary.record(
+            get_outcome(None, api_enums::RoutableConnectors::Adyen),
+            Some("adyen"),
+        );
+
+        let response = get_response(summary);
+
+        assert_eq!(response.default_selection_count, 1);
+        // Every rule of the algorithm is reported, in the order of the algorithm
+        let rule_hits = response
+            .rule_hits
+            .iter()
+            .map(|rule| (rule.rule_name.as_str(), rule.hit_count, rule.matched_count))
+            .collect::<Vec<_>>();
+        assert_eq!(rule_hits, vec![("card_rule", 2, 1), ("amount_rule", 0, 0)]);
+    }
+
+    #[test]
+    fn test_summary_without_payments() {
+        let response = get_response(RoutingSimulationSummary::new(&[]));
+
+        assert_eq!(response.total_count, 0);
+        assert!(response.connector_distribution.is_empty());
+    }
+
+    #[test]
+    fn test_get_percentage() {
+        assert_eq!(get_percentage(1, 3), 33.33);
+        assert_eq!(get_percentage(2, 3), 66.67);
+        assert_eq!(get_percentage(3, 3), 100.0);
+        assert_eq!(get_percentage(0, 0), 0.0);
+    }
+}
@@ -0,0 +1,1820 @@
+//! Subscriptions
+//!
+//! A subscription bills a customer for a plan at every billing period, by charging the mandate or
+//! the stored payment method of the customer through merchant initiated payments. Every charge is
+//! tracked as an invoice of the subscription. The renewals of a subscription are driven by a
+//! process tracker task, which is rescheduled to the end of every billing period and retries the
+//! invoices which could not be charged.
+
+use api_models::{
+    mandates::RecurringDetails,
+    payments::{PaymentIdType, PaymentsRequest},
+    subscriptions::{
+        SubscriptionCancelRequest, SubscriptionCreateRequest, SubscriptionInvoiceResponse,
+        SubscriptionListConstraints, SubscriptionPlanCreateRequest,
+        SubscriptionPlanListConstraints, SubscriptionPlanResponse, SubscriptionPlanRetrieveRequest,
+        SubscriptionPlanUpdateRequest, SubscriptionResponse, SubscriptionRetrieveRequest,
+        SubscriptionUpdateRequest,
+    },
+};
+use common_utils::{ext_traits::OptionExt, id_type, types::MinorUnit};
+use error_stack::{report, ResultExt};
+use router_env::{instrument, logger, tracing};
+use scheduler::{consumer::types::process_data, utils as pt_utils};
+use time::PrimitiveDateTime;
+
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payments, webhooks,
+    },
+    db::StorageInterface,
+    routes::{app::ReqState, metrics, SessionState},
+    services,
+    types::{
+        api::{self, webhooks as webhook_types},
+        domain,
+        storage::{self, enums as storage_enums},
+    },
+};
+
+const SUBSCRIPTION_BILLING_TASK: &str = "SUBSCRIPTION_BILLING";
+const SUBSCRIPTION_BILLING_TAG: &str = "SUBSCRIPTION";
+const SUBSCRIPTION_BILLING_RUNNER: storage::ProcessTrackerRunner =
+    storage::ProcessTrackerRunner::SubscriptionBillingWorkflow;
+
+const SUBSCRIPTION_LIST_DEFAULT_LIMIT: u32 = 10;
+const SUBSCRIPTION_LIST_MAX_LIMIT: u32 = 100;
+
+/// Retry schedule of the invoices which could not be charged. The charge is retried after an hour,
+/// twice more at intervals of 6 hours and then thrice at intervals of a day, after which the
+/// subscription is cancelled as unpaid.
+pub fn get_billing_retry_mapping() -> process_data::RetryMapping {
+    process_data::RetryMapping {
+        start_after: 60 * 60,
+        frequencies: vec![(60 * 60 * 6, 2), (60 * 60 * 24, 3)],
+    }
+}
+
+/// The outcome of charging an invoice of a subscription
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum InvoiceChargeStatus {
+    Paid,
+    /// The payment of the invoice has not reached a terminal status yet
+    Processing,
+    Failed,
+}
+
+/// The outcome of a run of the billing task of a subscription
+#[derive(Debug)]
+pub enum SubscriptionBillingOutcome {
+    /// The invoice of the current period was paid, the subscription is billed next at the end of
+    /// the period
+    Renewed { next_billing_at: PrimitiveDateTime },
+    /// The payment of the invoice of the current period has not reached a terminal status yet
+    PaymentProcessing,
+    /// The invoice of the current period could not be charged
+    PaymentFailed,
+    /// The subscription was cancelled at the end of its period
+    Cancelled,
+}
+
+fn get_list_limit_and_offset(
+    limit: Option<u32>,
+    offset: Option<u32>,
+) -> RouterResult<(Option<i64>, Option<i64>)> {
+    let limit = limit.unwrap_or(SUBSCRIPTION_LIST_DEFAULT_LIMIT);
+    if limit > SUBSCRIPTION_LIST_MAX_LIMIT {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("limit should not exceed {SUBSCRIPTION_LIST_MAX_LIMIT}"),
+        }));
+    }
+    Ok((Some(i64::from(limit)), offset.map(i64::from)))
+}
+
+/// Adds `interval_count` billing intervals to `start`. The day of the month is clamped to the
+/// last day of the resulting month for monthly and yearly intervals.
+fn add_billing_interval(
+    start: PrimitiveDateTime,
+    billing_interval: storage_enums::SubscriptionInterval,
+    interval_count: i32,
+) -> Option<PrimitiveDateTime> {
+    let add_months = |months: i32| {
+        let total_months = start.year() * 12 + i32::from(u8::from(start.month())) - 1 + months;
+        let year = total_months.div_euclid(12);
+        let month =
+            time::Month::try_from(u8::try_from(total_months.rem_euclid(12) + 1).ok()?).ok()?;
+        let day = start.day().min(time::util::days_in_year_month(year, month));
+        time::Date::from_calendar_date(year, month, day)
+            .ok()
+            .map(|date| date.with_time(start.time()))
+    };
+
+    match billing_interval {
+        storage_enums::SubscriptionInterval::Day => {
+            start.checked_add(time::Duration::days(i64::from(interval_count)))
+        }
+        storage_enums::SubscriptionInterval::Week => {
+            start.checked_add(time::Duration::weeks(i64::from(interval_count)))
+        }
+        storage_enums::SubscriptionInterval::Month => add_months(interval_count),
+        storage_enums::SubscriptionInterval::Year => add_months(interval_count.checked_mul(12)?),
+    }
+}
+
+fn get_subscription_plan_response(plan: storage::SubscriptionPlan) -> SubscriptionPlanResponse {
+    SubscriptionPlanResponse {
+        plan_id: plan.plan_id,
+        merchant_id: plan.merchant_id,
+        name: plan.name,
+        description: plan.description,
+        amount: plan.amount,
+        currency: plan.currency,
+        billing_interval: plan.billing_interval,
+        interval_count: plan.interval_count,
+        trial_period_days: plan.trial_period_days,
+        is_active: plan.is_active,
+        metadata: plan.metadata,
+        created_at: plan.created_at,
+    }
+}
+
+fn get_subscription_invoice_response(
+    invoice: storage::SubscriptionInvoice,
+) -> SubscriptionInvoiceResponse {
+    SubscriptionInvoiceResponse {
+        invoice_id: invoice.invoice_id,
+        subscription_id: invoice.subscription_id,
+        plan_id: invoice.plan_id,
+        billing_reason: invoice.billing_reason,
+        status: invoice.status,
+        amount: invoice.amount,
+        currency: invoice.currency,
+        period_start: invoice.period_start,
+        period_end: invoice.period_end,
+        payment_id: invoice.payment_id,
+        attempt_count: invoice.attempt_count,
+        error_code: invoice.error_code,
+        error_message: invoice.error_message,
+        created_at: invoice.created_at,
+    }
+}
+
+fn get_subscription_response(
+    subscription: storage::Subscription,
+    latest_invoice: Option<storage::SubscriptionInvoice>,
+) -> SubscriptionResponse {
+    SubscriptionResponse {
+        subscription_id: subscription.subscription_id,
+        merchant_id: subscription.merchant_id,
+        profile_id: subscription.profile_id,
+        customer_id: subscription.customer_id,
+        plan_id: subscription.plan_id,
+        status: subscription.status,
+        mandate_id: subscription.mandate_id,
+        payment_method_id: subscription.payment_method_id,
+        current_period_start: subscription.current_period_start,
+        current_period_end: subscription.current_period_end,
+        trial_end: subscription.trial_end,
+        cancel_at_period_end: subscription.cancel_at_period_end,
+        cancelled_at: subscription.cancelled_at,
+        credit_balance: subscription.credit_balance,
+        latest_invoice: latest_invoice.map(get_subscription_invoice_response),
+        metadata: subscription.metadata,
+        created_at: subscription.created_at,
+    }
+}
+
+pub async fn create_subscription_plan(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    req: SubscriptionPlanCreateRequest,
+) -> RouterResponse<SubscriptionPlanResponse> {
+    let db = &*state.store;
+
+    if req.amount < MinorUnit::zero() {
+        return Err(report!(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "amount"
+        }));
+    }
+    let interval_count = req.interval_count.unwrap_or(1);
+    if interval_count == 0 {
+        return Err(report!(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "interval_count"
+        }));
+    }
+
+    let now = common_utils::date_time::now();
+    let plan = db
+        .insert_subscription_plan(storage::SubscriptionPlanNew {
+            plan_id: common_utils::generate_id(consts::ID_LENGTH, "plan"),
+            merchant_id: merchant_context.get_merchant_account().get_id().clone(),
+            name: req.name,
+            description: req.description,
+            amount: req.amount,
+            currency: req.currency,
+            billing_interval: req.billing_interval,
+            interval_count: i32::from(interval_count),
+            trial_period_days: req.trial_period_days.map(i32::from),
+            is_active: true,
+            metadata: req.metadata,
+            created_at: now,
+            last_modified_at: now,
+        })
+        .await
+        .to_duplicate_response(errors::ApiErrorResponse::GenericDuplicateError {
+            message: "Subscription plan already exists".to_string(),
+        })?;
+
+    Ok(services::ApplicationResponse::Json(
+        get_subscription_plan_response(plan),
+    ))
+}
+
+pub async fn retrieve_subscription_plan(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    req: SubscriptionPlanRetrieveRequest,
+) -> RouterResponse<SubscriptionPlanResponse> {
+    let plan = state
+        .store
+        .find_subscription_plan_by_merchant_id_plan_id(
+            merchant_context.get_merchant_account().get_id(),
+            &req.plan_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::SubscriptionPlanNotFound {
+            id: req.plan_id,
+        })?;
+
+    Ok(services::ApplicationResponse::Json(
+        get_subscription_plan_response(plan),
+    ))
+}
+
+pub async fn list_subscription_plans(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    constraints: SubscriptionPlanListConstraints,
+) -> RouterResponse<Vec<SubscriptionPlanResponse>> {
+    let (limit, offset) = get_list_limit_and_offset(constraints.limit, constraints.offset)?;
+    let plans = state
+        .store
+        .list_subscription_plans_by_merchant_id(
+            merchant_context.get_merchant_account().get_id(),
+            limit,
+            offset,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list subscription plans")?;
+
+    Ok(services::ApplicationResponse::Json(
+        plans
+            .into_iter()
+            .map(get_subscription_plan_response)
+            .collect(),
+    ))
+}
+
+pub async fn update_subscription_plan(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    req: SubscriptionPlanUpdateRequest,
+) -> RouterResponse<SubscriptionPlanResponse> {
+    let plan = state
+        .store
+        .update_subscription_plan_by_merchant_id_plan_id(
+            merchant_context.get_merchant_account().get_id(),
+            &req.plan_id,
+            storage::SubscriptionPlanUpdate::Update {
+                name: req.name,
+                description: req.description,
+                is_active: req.is_active,
+                metadata: req.metadata,
+            },
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::SubscriptionPlanNotFound {
+            id: req.plan_id,
+        })?;
+
+    Ok(services::ApplicationResponse::Json(
+        get_subscription_plan_response(plan),
+    ))
+}
+
+/// Validates that the mandate or the payment method with which a subscription is charged belongs
+/// to the customer of the subscription, and can be charged
+async fn validate_payment_instrument(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    customer_id: &id_type::CustomerId,
+    mandate_id: Option<&String>,
+    payment_method_id: Option<&String>,
+) -> RouterResult<()> {
+    let db = &*state.store;
+    let merchant_account = merchant_context.get_merchant_account();
+
+    match (mandate_id, payment_method_id) {
+        (Some(mandate_id), _) => {
+            let mandate = db
+                .find_mandate_by_merchant_id_mandate_id(
+                    merchant_account.get_id(),
+                    mandate_id,
+                    merchant_account.storage_scheme,
+                )
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::MandateNotFound)?;
+            if &mandate.customer_id != customer_id {
+                return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message: "The mandate does not belong to the customer".to_string(),
+                }));
+            }
+            if mandate.mandate_status != storage_enums::MandateStatus::Active {
+                return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                    message: "The mandate is not active".to_string(),
+                }));
+            }
+        }
+        (None, Some(payment_method_id)) => {
+            let payment_method = db
+                .find_payment_method(
+                    &state.into(),
+                    merchant_context.get_merchant_key_store(),
+                    payment_method_id,
+                    merchant_account.storage_scheme,
+                )
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
+            if &payment_method.customer_id != customer_id {
+                return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message: "The payment method does not belong to the customer".to_string(),
+                }));
+            }
+            if payment_method.status != storage_enums::PaymentMethodStatus::Active {
+                return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                    message: "The payment method is not active".to_string(),
+                }));
+            }
+        }
+        (None, None) => {
+            return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "Either mandate_id or payment_method_id is required".to_string(),
+            }));
+        }
+    }
+
+    Ok(())
+}
+
+#[instrument(skip_all)]
+pub async fn create_subscription(
+    state: SessionState,
+    req_state: ReqState,
+    merchant_context: domain::MerchantContext,
+    req: SubscriptionCreateRequest,
+) -> RouterResponse<SubscriptionResponse> {
+    let db = &*state.store;
+    let merchant_account = merchant_context.get_merchant_account();
+    let merchant_id = merchant_account.get_id();
+
+    let plan = db
+        .find_subscription_plan_by_merchant_id_plan_id(merchant_id, &req.plan_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::SubscriptionPlanNotFound {
+            id: req.plan_id.clone(),
+        })?;
+    if !plan.is_active {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "The subscription plan is not active".to_string(),
+        }));
+    }
+
+    let profile_id = req
+        .profile_id
+        .as_ref()
+        .or(merchant_account.default_profile.as_ref())
+        .get_required_value("profile_id")?;
+    db.find_business_profile_by_merchant_id_profile_id(
+        &(&state).into(),
+        merchant_context.get_merchant_key_store(),
+        merchant_id,
+        profile_id,
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+        id: profile_id.get_string_repr().to_owned(),
+    })?;
+
+    validate_payment_instrument(
+        &state,
+        &merchant_context,
+        &req.customer_id,
+        req.mandate_id.as_ref(),
+        req.payment_method_id.as_ref(),
+    )
+    .await?;
+
+    let now = common_utils::date_time::now();
+    let trial_period_days = req
+        .trial_period_days
+        .map(i32::from)
+        .or(plan.trial_period_days)
+        .filter(|days| *days > 0);
+    let (status, trial_end, current_period_end) = match trial_period_days {
+        Some(days) => {
+            let trial_end = now + time::Duration::days(i64::from(days));
+            (
+                storage_enums::SubscriptionStatus::Trialing,
+                Some(trial_end),
+                trial_end,
+            )
+        }
+        None => (
+            storage_enums::SubscriptionStatus::Active,
+            None,
+            add_billing_interval(now, plan.billing_interval, plan.interval_count)
+                .ok_or(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to compute the end of the billing period")?,
+        ),
+    };
+
+    let mut subscription = db
+        .insert_subscription(storage::SubscriptionNew {
+            subscription_id: common_utils::generate_id(consts::ID_LENGTH, "sub"),
+            merchant_id: merchant_id.clone(),
+            profile_id: profile_id.clone(),
+            customer_id: req.customer_id,
+            plan_id: plan.plan_id.clone(),
+            status,
+            payment_method_id: req.payment_method_id,
+            mandate_id: req.mandate_id,
+            current_period_start: now,
+            current_period_end,
+            trial_end,
+            cancel_at_period_end: false,
+            cancelled_at: None,
+            credit_balance: MinorUnit::zero(),
+            metadata: req.metadata,
+            created_at: now,
+            last_modified_at: now,
+        })
+        .await
+        .to_duplicate_response(errors::ApiErrorResponse::GenericDuplicateError {
+            message: "Subscription already exists".to_string(),
+        })?;
+
+    // Subscriptions without a trial are charged for the first billing period upfront, a failed
+    // charge is retried by the billing task
+    let (latest_invoice, next_billing_at) = if trial_end.is_some() {
+        (None, current_period_end)
+    } else {
+        let invoice = create_subscription_invoice(
+            db,
+            &subscription,
+            &plan,
+            storage_enums::SubscriptionBillingReason::SubscriptionCreate,
+            plan.amount,
+            subscription.current_period_start,
+            subscription.current_period_end,
+        )
+        .await?;
+        let (invoice, charge_status) = charge_subscription_invoice(
+            &state,
+            req_state,
+            &merchant_context,
+            &subscription,
+            invoice,
+        )
+        .await?;
+
+        let next_billing_at = match charge_status {
+            InvoiceChargeStatus::Paid => subscription.current_period_end,
+            InvoiceChargeStatus::Processing | InvoiceChargeStatus::Failed => {
+                pt_utils::get_retry_schedule_time(&get_billing_retry_mapping(), 0).unwrap_or(now)
+            }
+        };
+        if charge_status == InvoiceChargeStatus::Failed {
+            subscription = update_subscription_status(
+                db,
+                &subscription,
+                storage_enums::SubscriptionStatus::PastDue,
+            )
+            .await?;
+        }
+        (Some(invoice), next_billing_at)
+    };
+
+    add_subscription_billing_task(db, &subscription, next_billing_at)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while adding subscription billing task to process tracker")?;
+
+    trigger_subscription_webhook(
+        &state,
+        &merchant_context,
+        &subscription,
+        latest_invoice.as_ref(),
+        storage_enums::EventType::SubscriptionCreated,
+    )
+    .await;
+    if subscription.status == storage_enums::SubscriptionStatus::PastDue {
+        trigger_subscription_webhook(
+            &state,
+            &merchant_context,
+            &subscription,
+            latest_invoice.as_ref(),
+            storage_enums::EventType::SubscriptionPaymentFailed,
+        )
+        .await;
+    }
+
+    Ok(services::ApplicationResponse::Json(
+        get_subscription_response(subscription, latest_invoice),
+    ))
+}
+
+async fn find_subscription(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    subscription_id: &str,
+) -> RouterResult<storage::Subscription> {
+    db.find_subscription_by_merchant_id_subscription_id(merchant_id, subscription_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::SubscriptionNotFound {
+            id: subscription_id.to_owned(),
+        })
+}
+
+async fn find_latest_subscription_invoice(
+    db: &dyn StorageInterface,
+    subscription: &storage::Subscription,
+) -> RouterResult<Option<storage::SubscriptionInvoice>> {
+    db.list_subscription_invoices_by_merchant_id_subscription_id(
+        &subscription.merchant_id,
+        &subscription.subscription_id,
+        Some(1),
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to fetch the latest invoice of the subscription")
+    .map(|invoices| invoices.into_iter().next())
+}
+
+pub async fn retrieve_subscription(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    req: SubscriptionRetrieveRequest,
+) -> RouterResponse<SubscriptionResponse> {
+    let db = &*state.store;
+    let subscription = find_subscription(
+        db,
+        merchant_context.get_merchant_account().get_id(),
+        &req.subscription_id,
+    )
+    .await?;
+    let latest_invoice = find_latest_subscription_invoice(db, &subscription).await?;
+
+    Ok(services::ApplicationResponse::Json(
+        get_subscription_response(subscription, latest_invoice),
+    ))
+}
+
+pub async fn list_subscriptions(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    constraints: SubscriptionListConstraints,
+) -> RouterResponse<Vec<SubscriptionResponse>> {
+    let (limit, offset) = get_list_limit_and_offset(constraints.limit, constraints.offset)?;
+    let subscriptions = state
+        .store
+        .list_subscriptions_by_merchant_id_customer_id(
+            merchant_context.get_merchant_account().get_id(),
+            constraints.customer_id.as_ref(),
+            limit,
+            offset,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list subscriptions")?;
+
+    Ok(services::ApplicationResponse::Json(
+        subscriptions
+            .into_iter()
+            .map(|subscription| get_subscription_response(subscription, None))
+            .collect(),
+    ))
+}
+
+pub async fn list_subscription_invoices(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    req: SubscriptionRetrieveRequest,
+) -> RouterResponse<Vec<SubscriptionInvoiceResponse>> {
+    let db = &*state.store;
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    find_subscription(db, merchant_id, &req.subscription_id).await?;
+
+    let invoices = db
+        .list_subscription_invoices_by_merchant_id_subscription_id(
+            merchant_id,
+            &req.subscription_id,
+            None,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the invoices of the subscription")?;
+
+    Ok(services::ApplicationResponse::Json(
+        invoices
+            .into_iter()
+            .map(get_subscription_invoice_response)
+            .collect(),
+    ))
+}
+
+fn get_non_negative_amount(amount: MinorUnit) -> MinorUnit {
+    if amount < MinorUnit::zero() {
+        MinorUnit::zero()
+    } else {
+        amount
+    }
+}
+
+/// Computes the amount by which moving to a plan with a different amount changes the cost of the
+/// remaining time of the current billing period. A negative amount is credited to the subscription.
+fn get_proration_amount(
+    subscription: &storage::Subscription,
+    current_plan: &storage::SubscriptionPlan,
+    new_plan: &storage::SubscriptionPlan,
+    now: PrimitiveDateTime,
+) -> MinorUnit {
+    let period_length =
+        (subscription.current_period_end - subscription.current_period_start).whole_seconds();
+    if period_length <= 0 {
+        return MinorUnit::zero();
+    }
+    let remaining = (subscription.current_period_end - now)
+        .whole_seconds()
+        .clamp(0, period_length);
+
+    let difference = i128::from(new_plan.amount.get_amount_as_i64())
+        - i128::from(current_plan.amount.get_amount_as_i64());
+    let prorated = difference * i128::from(remaining) / i128::from(period_length);
+    MinorUnit::new(i64::try_from(prorated).unwrap_or_default())
+}
+
+#[instrument(skip_all)]
+pub async fn update_subscription(
+    state: SessionState,
+    req_state: ReqState,
+    merchant_context: domain::MerchantContext,
+    req: SubscriptionUpdateRequest,
+) -> RouterResponse<SubscriptionResponse> {
+    let db = &*state.store;
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    let mut subscription = find_subscription(db, merchant_id, &req.subscription_id).await?;
+    if subscription.status == storage_enums::SubscriptionStatus::Cancelled {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "A cancelled subscription cannot be updated".to_string(),
+        }));
+    }
+
+    if req.mandate_id.is_some() || req.payment_method_id.is_some() {
+        validate_payment_instrument(
+            &state,
+            &merchant_context,
+            &subscription.customer_id,
+            req.mandate_id.as_ref(),
+            req.payment_method_id.as_ref(),
+        )
+        .await?;
+        subscription = db
+            .update_subscription_by_merchant_id_subscription_id(
+                merchant_id,
+                &subscription.subscription_id,
+                storage::SubscriptionUpdate::PaymentInstrumentUpdate {
+                    mandate_id: req.mandate_id,
+                    payment_method_id: req.payment_method_id,
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update the payment instrument of the subscription")?;
+    }
+
+    let mut plan_id = None;
+    let mut credit_balance = None;
+    let mut proration_invoice = None;
+    if let Some(new_plan_id) = req
+        .plan_id
+        .filter(|plan_id| plan_id != &subscription.plan_id)
+    {
+        let current_plan = db
+            .find_subscription_plan_by_merchant_id_plan_id(merchant_id, &subscription.plan_id)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the current plan of the subscription")?;
+        let new_plan = db
+            .find_subscription_plan_by_merchant_id_plan_id(merchant_id, &new_plan_id)
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::SubscriptionPlanNotFound {
+                id: new_plan_id.clone(),
+            })?;
+        if !new_plan.is_active {
+            return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "The subscription plan is not active".to_string(),
+            }));
+        }
+        if new_plan.currency != current_plan.currency
+            || new_plan.billing_interval != current_plan.billing_interval
+            || new_plan.interval_count != current_plan.interval_count
+        {
+            return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "The plan must have the same currency and billing period as the current plan of the subscription".to_string(),
+            }));
+        }
+
+        let has_pending_plan_change = db
+            .find_subscription_invoices_by_subscription_id_status(
+                &subscription.subscription_id,
+                storage_enums::SubscriptionInvoiceStatus::Open,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the open invoices of the subscription")?
+            .iter()
+            .any(|invoice| {
+                invoice.billing_reason
+                    == storage_enums::SubscriptionBillingReason::SubscriptionUpdate
+            });
+        if has_pending_plan_change {
+            return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "The payment of a previous plan change of the subscription is processing"
+                    .to_string(),
+            }));
+        }
+
+        let mut is_plan_change_pending = false;
+        let proration_behavior = req.proration_behavior.unwrap_or_default();
+        let is_prorated = subscription.status != storage_enums::SubscriptionStatus::Trialing
+            && proration_behavior == storage_enums::ProrationBehavior::CreateProrations;
+        if is_prorated {
+            let now = common_utils::date_time::now();
+            let proration_amount =
+                get_proration_amount(&subscription, &current_plan, &new_plan, now);
+
+            if proration_amount > MinorUnit::zero() {
+                let amount_due =
+                    get_non_negative_amount(proration_amount - subscription.credit_balance);
+                let invoice = create_subscription_invoice(
+                    db,
+                    &subscription,
+                    &new_plan,
+                    storage_enums::SubscriptionBillingReason::SubscriptionUpdate,
+                    amount_due,
+                    now,
+                    subscription.current_period_end,
+                )
+                .await?;
+                let (invoice, charge_status) = charge_subscription_invoice(
+                    &state,
+                    req_state,
+                    &merchant_context,
+                    &subscription,
+                    invoice,
+                )
+                .await?;
+
+                // The plan is not changed unless the proration is paid
+                match charge_status {
+                    InvoiceChargeStatus::Paid => {
+                        credit_balance = Some(get_non_negative_amount(
+                            subscription.credit_balance - proration_amount,
+                        ));
+                    }
+                    // The plan is changed by the billing task once the payment succeeds
+                    InvoiceChargeStatus::Processing => is_plan_change_pending = true,
+                    InvoiceChargeStatus::Failed => {
+                        db.update_subscription_invoice_by_merchant_id_invoice_id(
+                            merchant_id,
+                            &invoice.invoice_id,
+                            storage::SubscriptionInvoiceUpdate::StatusUpdate {
+                                status: storage_enums::SubscriptionInvoiceStatus::Failed,
+                            },
+                        )
+                        .await
+                        .change_context(errors::ApiErrorResponse::InternalServerError)
+                        .attach_printable("Failed to update the subscription invoice")?;
+                        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                            message: format!(
+                                "The proration of the plan change could not be charged: {}",
+                                invoice.error_message.unwrap_or_default()
+                            ),
+                        }));
+                    }
+                }
+                proration_invoice = Some(invoice);
+            } else if proration_amount < MinorUnit::zero() {
+                credit_balance = Some(subscription.credit_balance - proration_amount);
+            }
+        }
+        if !is_plan_change_pending {
+            plan_id = Some(new_plan.plan_id);
+        }
+    }
+
+    let subscription = db
+        .update_subscription_by_merchant_id_subscription_id(
+            merchant_id,
+            &subscription.subscription_id,
+            storage::SubscriptionUpdate::Update {
+                plan_id,
+                cancel_at_period_end: None,
+                credit_balance,
+                metadata: req.metadata,
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update the subscription")?;
+
+    let latest_invoice = match proration_invoice {
+        Some(invoice) => Some(invoice),
+        None => find_latest_subscription_invoice(db, &subscription).await?,
+    };
+    trigger_subscription_webhook(
+        &state,
+        &merchant_context,
+        &subscription,
+        latest_invoice.as_ref(),
+        storage_enums::EventType::SubscriptionUpdated,
+    )
+    .await;
+
+    Ok(services::ApplicationResponse::Json(
+        get_subscription_response(subscription, latest_invoice),
+    ))
+}
+
+pub async fn cancel_subscription(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    req: SubscriptionCancelRequest,
+) -> RouterResponse<SubscriptionResponse> {
+    let db = &*state.store;
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    let subscription = find_subscription(db, merchant_id, &req.subscription_id).await?;
+    if subscription.status == storage_enums::SubscriptionStatus::Cancelled {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "The subscription is already cancelled".to_string(),
+        }));
+    }
+
+    let (subscription, event_type) = if req.cancel_at_period_end.unwrap_or(false) {
+        let subscription = db
+            .update_subscription_by_merchant_id_subscription_id(
+                merchant_id,
+                &subscription.subscription_id,
+                storage::SubscriptionUpdate::Update {
+                    plan_id: None,
+                    cancel_at_period_end: Some(true),
+                    credit_balance: None,
+                    metadata: None,
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update the subscription")?;
+        (subscription, storage_enums::EventType::SubscriptionUpdated)
+    } else {
+        let subscription = cancel_subscription_and_invoices(
+            db,
+            &subscription,
+            storage_enums::SubscriptionInvoiceStatus::Void,
+        )
+        .await?;
+        revoke_subscription_billing_task(db, &subscription)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to revoke the subscription billing task")?;
+        (
+            subscription,
+            storage_enums::EventType::SubscriptionCancelled,
+        )
+    };
+
+    let latest_invoice = find_latest_subscription_invoice(db, &subscription).await?;
+    trigger_subscription_webhook(
+        &state,
+        &merchant_context,
+        &subscription,
+        latest_invoice.as_ref(),
+        event_type,
+    )
+    .await;
+
+    Ok(services::ApplicationResponse::Json(
+        get_subscription_response(subscription, latest_invoice),
+    ))
+}
+
+/// Cancels the subscription, and moves the open invoices of the subscription to
+/// `invoice_status`
+async fn cancel_subscription_and_invoices(
+    db: &dyn StorageInterface,
+    subscription: &storage::Subscription,
+    invoice_status: storage_enums::SubscriptionInvoiceStatus,
+) -> RouterResult<storage::Subscription> {
+    let open_invoices = db
+        .find_subscription_invoices_by_subscription_id_status(
+            &subscription.subscription_id,
+            storage_enums::SubscriptionInvoiceStatus::Open,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the open invoices of the subscription")?;
+    for invoice in open_invoices {
+        db.update_subscription_invoice_by_merchant_id_invoice_id(
+            &invoice.merchant_id,
+            &invoice.invoice_id,
+            storage::SubscriptionInvoiceUpdate::StatusUpdate {
+                status: invoice_status,
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update the subscription invoice")?;
+    }
+
+    db.update_subscription_by_merchant_id_subscription_id(
+        &subscription.merchant_id,
+        &subscription.subscription_id,
+        storage::SubscriptionUpdate::Cancel {
+            cancelled_at: common_utils::date_time::now(),
+        },
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to cancel the subscription")
+}
+
+async fn update_subscription_status(
+    db: &dyn StorageInterface,
+    subscription: &storage::Subscription,
+    status: storage_enums::SubscriptionStatus,
+) -> RouterResult<storage::Subscription> {
+    db.update_subscription_by_merchant_id_subscription_id(
+        &subscription.merchant_id,
+        &subscription.subscription_id,
+        storage::SubscriptionUpdate::StatusUpdate { status },
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to update the status of the subscription")
+}
+
+async fn create_subscription_invoice(
+    db: &dyn StorageInterface,
+    subscription: &storage::Subscription,
+    plan: &storage::SubscriptionPlan,
+    billing_reason: storage_enums::SubscriptionBillingReason,
+    amount: MinorUnit,
+    period_start: PrimitiveDateTime,
+    period_end: PrimitiveDateTime,
+) -> RouterResult<storage::SubscriptionInvoice> {
+    let now = common_utils::date_time::now();
+    db.insert_subscription_invoice(storage::SubscriptionInvoiceNew {
+        invoice_id: common_utils::generate_id(consts::ID_LENGTH, "inv"),
+        subscription_id: subscription.subscription_id.clone(),
+        merchant_id: subscription.merchant_id.clone(),
+        customer_id: subscription.customer_id.clone(),
+        plan_id: plan.plan_id.clone(),
+        billing_reason,
+        status: storage_enums::SubscriptionInvoiceStatus::Open,
+        amount,
+        currency: plan.currency,
+        period_start,
+        period_end,
+        payment_id: None,
+        attempt_count: 0,
+        error_code: None,
+        error_message: None,
+        created_at: now,
+        last_modified_at: now,
+    })
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to insert subscription invoice")
+}
+
+/// Charges the invoice through a merchant initiated payment with the mandate or the stored payment
+/// method of the subscription, and records the payment and its outcome on the invoice
+#[instrument(skip_all, fields(invoice_id = %invoice.invoice_id))]
+async fn charge_subscription_invoice(
+    state: &SessionState,
+    req_state: ReqState,
+    merchant_context: &domain::MerchantContext,
+    subscription: &storage::Subscription,
+    invoice: storage::SubscriptionInvoice,
+) -> RouterResult<(storage::SubscriptionInvoice, InvoiceChargeStatus)> {
+    let db = &*state.store;
+
+    // Invoices which are fully covered by the credit balance are not charged
+    if invoice.amount == MinorUnit::zero() {
+        let invoice = db
+            .update_subscription_invoice_by_merchant_id_invoice_id(
+                &invoice.merchant_id,
+                &invoice.invoice_id,
+                storage::SubscriptionInvoiceUpdate::StatusUpdate {
+                    status: storage_enums::SubscriptionInvoiceStatus::Paid,
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update the subscription invoice")?;
+        return Ok((invoice, InvoiceChargeStatus::Paid));
+    }
+
+    let recurring_details = match (&subscription.mandate_id, &subscription.payment_method_id) {
+        (Some(mandate_id), _) => RecurringDetails::MandateId(mandate_id.clone()),
+        (None, Some(payment_method_id)) => {
+            RecurringDetails::PaymentMethodId(payment_method_id.clone())
+        }
+        (None, None) => Err(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Subscription does not have a mandate or a payment method")?,
+    };
+    // The payment of an attempt is identified by the invoice and the attempt, and is recorded on the
+    // invoice before it is created, so that a payment created by an interrupted charge is found by
+    // the next run of the billing task instead of the invoice being charged again
+    let attempt_count = invoice.attempt_count + 1;
+    let payment_id = id_type::PaymentId::wrap(format!("{}_{attempt_count}", invoice.invoice_id))
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to generate the payment id of the subscription invoice")?;
+    let invoice = db
+        .update_subscription_invoice_by_merchant_id_invoice_id(
+            &invoice.merchant_id,
+            &invoice.invoice_id,
+            storage::SubscriptionInvoiceUpdate::PaymentUpdate {
+                status: storage_enums::SubscriptionInvoiceStatus::Open,
+                payment_id: Some(payment_id.clone()),
+                attempt_count,
+                error_code: None,
+                error_message: None,
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update the subscription invoice")?;
+
+    let payment_request = PaymentsRequest {
+        payment_id: Some(PaymentIdType::PaymentIntentId(payment_id.clone())),
+        amount: Some(invoice.amount.into()),
+        currency: Some(invoice.currency),
+        customer_id: Some(subscription.customer_id.clone()),
+        profile_id: Some(subscription.profile_id.clone()),
+        confirm: Some(true),
+        off_session: Some(true),
+        recurring_details: Some(recurring_details),
+        description: Some(format!(
+            "Invoice {} of subscription {}",
+            invoice.invoice_id, subscription.subscription_id
+        )),
+        ..Default::default()
+    };
+
+    let payment_result = Box::pin(payments::payments_core::<
+        api::Authorize,
+        api::PaymentsResponse,
+        _,
+        _,
+        _,
+        payments::PaymentData<api::Authorize>,
+    >(
+        state.clone(),
+        req_state,
+        merchant_context.clone(),
+        Some(subscription.profile_id.clone()),
+        payments::PaymentCreate,
+        payment_request,
+        services::AuthFlow::Merchant,
+        payments::CallConnectorAction::Trigger,
+        None,
+        hyperswitch_domain_models::payments::HeaderPayload::default(),
+    ))
+    .await;
+
+    let (charge_status, error_code, error_message) = match payment_result {
+        Ok(services::ApplicationResponse::Json(payment_response))
+        | Ok(services::ApplicationResponse::JsonWithHeaders((payment_response, _))) => {
+            match payment_response.status {
+                storage_enums::IntentStatus::Succeeded => (InvoiceChargeStatus::Paid, None, None),
+                storage_enums::IntentStatus::Processing => {
+                    (InvoiceChargeStatus::Processing, None, None)
+                }
+                _ => (
+                    InvoiceChargeStatus::Failed,
+                    payment_response.error_code,
+                    payment_response.error_message,
+                ),
+            }
+        }
+        Ok(_) => Err(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Unexpected response from the payments core")?,
+        Err(error) => {
+            logger::error!(?error, "Failed to charge the subscription invoice");
+            let error = error.current_context();
+            (
+                InvoiceChargeStatus::Failed,
+                Some(error.error_code()),
+                Some(error.error_message()),
+            )
+        }
+    };
+    let status = if charge_status == InvoiceChargeStatus::Paid {
+        storage_enums::SubscriptionInvoiceStatus::Paid
+    } else {
+        storage_enums::SubscriptionInvoiceStatus::Open
+    };
+    let invoice = db
+        .update_subscription_invoice_by_merchant_id_invoice_id(
+            &invoice.merchant_id,
+            &invoice.invoice_id,
+            storage::SubscriptionInvoiceUpdate::PaymentUpdate {
+                status,
+                payment_id: Some(payment_id),
+                attempt_count,
+                error_code,
+                error_message,
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update the subscription invoice")?;
+
+    Ok((invoice, charge_status))
+}
+
+/// Resolves the outcome of the latest payment of an invoice which was not known when the invoice
+/// was last charged. `None` is returned if the charge of the invoice was interrupted before the
+/// payment was created.
+async fn get_invoice_payment_status(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    payment_id: &id_type::PaymentId,
+) -> RouterResult<Option<InvoiceChargeStatus>> {
+    let payment_intent = match state
+        .store
+        .find_payment_intent_by_payment_id_merchant_id(
+            &state.into(),
+            payment_id,
+            merchant_context.get_merchant_account().get_id(),
+            merchant_context.get_merchant_key_store(),
+            merchant_context.get_merchant_account().storage_scheme,
+        )
+        .await
+    {
+        Ok(payment_intent) => payment_intent,
+        Err(error) if error.current_context().is_db_not_found() => return Ok(None),
+        Err(error) => {
+            return Err(error)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch the payment of the subscription invoice")
+        }
+    };
+
+    Ok(Some(match payment_intent.status {
+        storage_enums::IntentStatus::Succeeded => InvoiceChargeStatus::Paid,
+        storage_enums::IntentStatus::Processing => InvoiceChargeStatus::Processing,
+        _ => InvoiceChargeStatus::Failed,
+    }))
+}
+
+/// Resolves the proration invoice of a plan change whose payment was processing when the plan was
+/// changed. The plan of the subscription is changed once the invoice is paid.
+async fn reconcile_plan_change_invoice(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    subscription: storage::Subscription,
+    invoice: storage::SubscriptionInvoice,
+) -> RouterResult<storage::Subscription> {
+    let db = &*state.store;
+    let payment_status = match &invoice.payment_id {
+        Some(payment_id) => get_invoice_payment_status(state, merchant_context, payment_id).await?,
+        None => None,
+    };
+    let invoice_status = match payment_status {
+        Some(InvoiceChargeStatus::Processing) => return Ok(subscription),
+        Some(InvoiceChargeStatus::Paid) => storage_enums::SubscriptionInvoiceStatus::Paid,
+        Some(InvoiceChargeStatus::Failed) | None => {
+            storage_enums::SubscriptionInvoiceStatus::Failed
+        }
+    };
+    db.update_subscription_invoice_by_merchant_id_invoice_id(
+        &invoice.merchant_id,
+        &invoice.invoice_id,
+        storage::SubscriptionInvoiceUpdate::StatusUpdate {
+            status: invoice_status,
+        },
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to update the subscription invoice")?;
+
+    if invoice_status != storage_enums::SubscriptionInvoiceStatus::Paid {
+        return Ok(subscription);
+    }
+    // A proration is charged only when it exceeds the credit balance, which is used up by it
+    db.update_subscription_by_merchant_id_subscription_id(
+        &subscription.merchant_id,
+        &subscription.subscription_id,
+        storage::SubscriptionUpdate::Update {
+            plan_id: Some(invoice.plan_id),
+            cancel_at_period_end: None,
+            credit_balance: Some(MinorUnit::zero()),
+            metadata: None,
+        },
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to change the plan of the subscription")
+}
+
+/// Bills the current period of the subscription. The next billing period is started with a new
+/// invoice once the current period has ended, and the open invoice of the period is charged until
+/// it is paid.
+#[instrument(skip_all, fields(subscription_id = %subscription.subscription_id))]
+pub async fn bill_subscription(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    subscription: storage::Subscription,
+) -> RouterResult<SubscriptionBillingOutcome> {
+    let db = &*state.store;
+    let now = common_utils::date_time::now();
+
+    if subscription.cancel_at_period_end && now >= subscription.current_period_end {
+        let subscription = cancel_subscription_and_invoices(
+            db,
+            &subscription,
+            storage_enums::SubscriptionInvoiceStatus::Void,
+        )
+        .await?;
+        trigger_subscription_webhook(
+            state,
+            merchant_context,
+            &subscription,
+            None,
+            storage_enums::EventType::SubscriptionCancelled,
+        )
+        .await;
+        return Ok(SubscriptionBillingOutcome::Cancelled);
+    }
+
+    // Invoices of plan changes are charged when the plan is changed and are not retried, the
+    // plan changes whose payment was processing are completed before the next period is started
+    let (plan_change_invoices, open_invoices): (Vec<_>, Vec<_>) = db
+        .find_subscription_invoices_by_subscription_id_status(
+            &subscription.subscription_id,
+            storage_enums::SubscriptionInvoiceStatus::Open,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the open invoices of the subscription")?
+        .into_iter()
+        .partition(|invoice| {
+            invoice.billing_reason == storage_enums::SubscriptionBillingReason::SubscriptionUpdate
+        });
+    let mut subscription = subscription;
+    for invoice in plan_change_invoices {
+        subscription =
+            reconcile_plan_change_invoice(state, merchant_context, subscription, invoice).await?;
+    }
+    let open_invoice = open_invoices.into_iter().next();
+
+    let (subscription, invoice) = match open_invoice {
+        Some(invoice) => (subscription, invoice),
+        None => {
+            let plan = db
+                .find_subscription_plan_by_merchant_id_plan_id(
+                    &subscription.merchant_id,
+                    &subscription.plan_id,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch the plan of the subscription")?;
+            let period_start = subscription.current_period_end;
+            let period_end =
+                add_billing_interval(period_start, plan.billing_interval, plan.interval_count)
+                    .ok_or(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to compute the end of the billing period")?;
+            let amount_due = get_non_negative_amount(plan.amount - subscription.credit_balance);
+            let credit_balance = get_non_negative_amount(subscription.credit_balance - plan.amount);
+
+            let invoice = create_subscription_invoice(
+                db,
+                &subscription,
+                &plan,
+                storage_enums::SubscriptionBillingReason::SubscriptionCycle,
+                amount_due,
+                period_start,
+                period_end,
+            )
+            .await?;
+            let subscription = db
+                .update_subscription_by_merchant_id_subscription_id(
+                    &subscription.merchant_id,
+                    &subscription.subscription_id,
+                    storage::SubscriptionUpdate::PeriodUpdate {
+                        current_period_start: period_start,
+                        current_period_end: period_end,
+                        credit_balance,
+                    },
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to start the next period of the subscription")?;
+            (subscription, invoice)
+        }
+    };
+
+    let previous_payment_status = match &invoice.payment_id {
+        Some(payment_id) => get_invoice_payment_status(state, merchant_context, payment_id).await?,
+        None => None,
+    };
+    let (invoice, charge_status) = match previous_payment_status {
+        Some(InvoiceChargeStatus::Processing) => {
+            return Ok(SubscriptionBillingOutcome::PaymentProcessing)
+        }
+        Some(InvoiceChargeStatus::Paid) => {
+            let invoice = db
+                .update_subscription_invoice_by_merchant_id_invoice_id(
+                    &invoice.merchant_id,
+                    &invoice.invoice_id,
+                    storage::SubscriptionInvoiceUpdate::StatusUpdate {
+                        status: storage_enums::SubscriptionInvoiceStatus::Paid,
+                    },
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to update the subscription invoice")?;
+            (invoice, InvoiceChargeStatus::Paid)
+        }
+        Some(InvoiceChargeStatus::Failed) | None => {
+            charge_subscription_invoice(
+                state,
+                state.get_req_state(),
+                merchant_context,
+                &subscription,
+                invoice,
+            )
+            .await?
+        }
+    };
+
+    match charge_status {
+        InvoiceChargeStatus::Paid => {
+            let subscription = if subscription.status != storage_enums::SubscriptionStatus::Active {
+                update_subscription_status(
+                    db,
+                    &subscription,
+                    storage_enums::SubscriptionStatus::Active,
+                )
+                .await?
+            } else {
+                subscription
+            };
+            trigger_subscription_webhook(
+                state,
+                merchant_context,
+                &subscription,
+                Some(&invoice),
+                storage_enums::EventType::SubscriptionRenewed,
+            )
+            .await;
+            Ok(SubscriptionBillingOutcome::Renewed {
+                next_billing_at: subscription.current_period_end,
+            })
+        }
+        InvoiceChargeStatus::Processing => Ok(SubscriptionBillingOutcome::PaymentProcessing),
+        InvoiceChargeStatus::Failed => {
+            let subscription = if subscription.status != storage_enums::SubscriptionStatus::PastDue
+            {
+                update_subscription_status(
+                    db,
+                    &subscription,
+                    storage_enums::SubscriptionStatus::PastDue,
+                )
+                .await?
+            } else {
+                subscription
+            };
+            trigger_subscription_webhook(
+                state,
+                merchant_context,
+                &subscription,
+                Some(&invoice),
+                storage_enums::EventType::SubscriptionPaymentFailed,
+            )
+            .await;
+            Ok(SubscriptionBillingOutcome::PaymentFailed)
+        }
+    }
+}
+
+/// Cancels a subscription whose open invoice could not be charged within the retries of the
+/// billing task
+pub async fn cancel_unpaid_subscription(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    subscription: &storage::Subscription,
+) -> RouterResult<()> {
+    let db = &*state.store;
+    let subscription = cancel_subscription_and_invoices(
+        db,
+        subscription,
+        storage_enums::SubscriptionInvoiceStatus::Failed,
+    )
+    .await?;
+    let latest_invoice = find_latest_subscription_invoice(db, &subscription).await?;
+    trigger_subscription_webhook(
+        state,
+        merchant_context,
+        &subscription,
+        latest_invoice.as_ref(),
+        storage_enums::EventType::SubscriptionCancelled,
+    )
+    .await;
+    Ok(())
+}
+
+fn get_subscription_billing_process_tracker_id(subscription: &storage::Subscription) -> String {
+    pt_utils::get_process_tracker_id(
+        SUBSCRIPTION_BILLING_RUNNER,
+        SUBSCRIPTION_BILLING_TASK,
+        &subscription.subscription_id,
+        &subscription.merchant_id,
+    )
+}
+
+async fn add_subscription_billing_task(
+    db: &dyn StorageInterface,
+    subscription: &storage::Subscription,
+    schedule_time: PrimitiveDateTime,
+) -> errors::CustomResult<(), errors::StorageError> {
+    let tracking_data = storage::SubscriptionTrackingData {
+        merchant_id: subscription.merchant_id.clone(),
+        subscription_id: subscription.subscription_id.clone(),
+    };
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        get_subscription_billing_process_tracker_id(subscription),
+        SUBSCRIPTION_BILLING_TASK,
+        SUBSCRIPTION_BILLING_RUNNER,
+        [SUBSCRIPTION_BILLING_TAG],
+        tracking_data,
+        None,
+        schedule_time,
+        common_types::consts::API_VERSION,
+    )
+    .map_err(errors::StorageError::from)?;
+
+    db.insert_process(process_tracker_entry).await?;
+    metrics::TASKS_ADDED_COUNT.add(
+        1,
+        router_env::metric_attributes!(("flow", "SubscriptionBilling")),
+    );
+    Ok(())
+}
+
+/// Marks the billing task of a cancelled subscription as finished, so that the subscription is not
+/// billed anymore
+async fn revoke_subscription_billing_task(
+    db: &dyn StorageInterface,
+    subscription: &storage::Subscription,
+) -> errors::CustomResult<(), errors::StorageError> {
+    let process_tracker_update = storage::ProcessTrackerUpdate::StatusUpdate {
+        status: storage_enums::ProcessTrackerStatus::Finish,
+        business_status: Some(String::from(diesel_models::business_status::REVOKED)),
+    };
+    db.process_tracker_update_process_status_by_ids(
+        vec![get_subscription_billing_process_tracker_id(subscription)],
+        process_tracker_update,
+    )
+    .await?;
+    Ok(())
+}
+
+/// Raises an outgoing webhook for the subscription. The subscription has been updated by now, a
+/// failure to raise the webhook is only logged.
+async fn trigger_subscription_webhook(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    subscription: &storage::Subscription,
+    latest_invoice: Option<&storage::SubscriptionInvoice>,
+    event_type: storage_enums::EventType,
+) {
+    let business_profile = match state
+        .store
+        .find_business_profile_by_merchant_id_profile_id(
+            &state.into(),
+            merchant_context.get_merchant_key_store(),
+            &subscription.merchant_id,
+            &subscription.profile_id,
+        )
+        .await
+    {
+        Ok(business_profile) => business_profile,
+        Err(error) => {
+            logger::error!(
+                ?error,
+                subscription_id = %subscription.subscription_id,
+                "Failed to fetch the business profile of the subscription"
+            );
+            return;
+        }
+    };
+
+    let webhook_result = Box::pin(webhooks::create_event_and_trigger_outgoing_webhook(
+        state.clone(),
+        merchant_context.clone(),
+        business_profile,
+        event_type,
+        storage_enums::EventClass::Subscriptions,
+        get_subscription_webhook_object_id(subscription, latest_invoice, event_type),
+        storage_enums::EventObjectType::SubscriptionDetails,
+        webhook_types::OutgoingWebhookContent::SubscriptionDetails(Box::new(
+            get_subscription_response(subscription.clone(), latest_invoice.cloned()),
+        )),
+        Some(subscription.created_at),
+    ))
+    .await;
+    if let Err(error) = webhook_result {
+        logger::error!(
+            ?error,
+            subscription_id = %subscription.subscription_id,
+            "Failed to trigger the outgoing webhook for the subscription"
+        );
+    }
+}
+
+/// The ID of the object an outgoing webhook of the subscription is raised for. The ID of the event
+/// is derived from the object ID and the event type, so the events which recur for a subscription
+/// are raised for the payment of the charge of the invoice, or for the update of the subscription.
+fn get_subscription_webhook_object_id(
+    subscription: &storage::Subscription,
+    latest_invoice: Option<&storage::SubscriptionInvoice>,
+    event_type: storage_enums::EventType,
+) -> String {
+    let payment_id = latest_invoice.and_then(|invoice| invoice.payment_id.as_ref());
+    match (event_type, payment_id) {
+        (
+            storage_enums::EventType::SubscriptionRenewed
+            | storage_enums::EventType::SubscriptionPaymentFailed,
+            Some(payment_id),
+        ) => payment_id.get_string_repr().to_owned(),
+        (storage_enums::EventType::SubscriptionUpdated, _) => format!(
+            "{}_{}",
+            subscription.subscription_id,
+            subscription
+                .last_modified_at
+                .assume_utc()
+                .unix_timestamp_nanos()
+                / 1_000
+        ),
+        _ => subscription.subscription_id.clone(),
+    }
+}
+
+/// Builds the content of the outgoing webhook of a subscription, used to retry the delivery of
+/// the webhook with the current state of the subscription
+pub async fn get_subscription_webhook_content(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    subscription_id: &str,
+) -> RouterResult<SubscriptionResponse> {
+    let db = &*state.store;
+    let subscription = find_subscription(db, merchant_id, subscription_id).await?;
+    let latest_invoice = find_latest_subscription_invoice(db, &subscription).await?;
+    Ok(get_subscription_response(subscription, latest_invoice))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use std::borrow::Cow;
+
+    use time::macros::datetime;
+
+    use super::*;
+
+    fn get_plan(amount: i64) -> storage::SubscriptionPlan {
+        let now = common_utils::date_time::now();
+        storage::SubscriptionPlan {
+            plan_id: format!("plan_{amount}"),
+            merchant_id: id_type::MerchantId::default(),
+            name: "Plan".to_string(),
+            description: None,
+            amount: MinorUnit::new(amount),
+            currency: storage_enums::Currency::USD,
+            billing_interval: storage_enums::SubscriptionInterval::Month,
+            interval_count: 1,
+            trial_period_days: None,
+            is_active: true,
+            metadata: None,
+            created_at: now,
+            last_modified_at: now,
+        }
+    }
+
+    fn get_subscription(
+        current_period_start: PrimitiveDateTime,
+        current_period_end: PrimitiveDateTime,
+    ) -> storage::Subscription {
+        storage::Subscription {
+            subscription_id: "sub_test".to_string(),
+            merchant_id: id_type::MerchantId::default(),
+            profile_id: id_type::ProfileId::try_from(Cow::from("pro_test")).unwrap(),
+            customer_id: id_type::CustomerId::default(),
+            plan_id: "plan_1000".to_string(),
+            status: storage_enums::SubscriptionStatus::Active,
+            payment_method_id: None,
+            mandate_id: None,
+            current_period_start,
+            current_period_end,
+            trial_end: None,
+            cancel_at_period_end: false,
+            cancelled_at: None,
+            credit_balance: MinorUnit::zero(),
+            metadata: None,
+            created_at: current_period_start,
+            last_modified_at: current_period_start,
+        }
+    }
+
+    fn get_invoice(invoice_id: &str, attempt_count: i32) -> storage::SubscriptionInvoice {
+        let now = common_utils::date_time::now();
+        storage::SubscriptionInvoice {
+            invoice_id: invoice_id.to_string(),
+            subscription_id: "sub_test".to_string(),
+            merchant_id: id_type::MerchantId::default(),
+            customer_id: id_type::CustomerId::default(),
+            plan_id: "plan_1000".to_string(),
+            billing_reason: storage_enums::SubscriptionBillingReason::SubscriptionCycle,
+            status: storage_enums::SubscriptionInvoiceStatus::Paid,
+            amount: MinorUnit::new(1000),
+            currency: storage_enums::Currency::USD,
+            period_start: now,
+            period_end: now,
+            payment_id: Some(
+                id_type::PaymentId::wrap(format!("{invoice_id}_{attempt_count}")).unwrap(),
+            ),
+            attempt_count,
+            error_code: None,
+            error_message: None,
+            created_at: now,
+            last_modified_at: now,
+        }
+    }
+
+    fn get_webhook_event_id(
+        subscription: &storage::Subscription,
+        latest_invoice: Option<&storage::SubscriptionInvoice>,
+        event_type: storage_enums::EventType,
+    ) -> String {
+        webhooks::utils::get_idempotent_event_id(
+            &get_subscription_webhook_object_id(subscription, latest_invoice, event_type),
+            event_type,
+            storage_enums::WebhookDeliveryAttempt::InitialAttempt,
+        )
+    }
+
+    #[test]
+    fn test_subscription_webhook_event_ids_are_unique_per_occurrence() {
+        let subscription = get_subscription(datetime!(2024-01-01 0:00), datetime!(2024-01-31 0:00));
+
+        // Every renewal is raised for the payment of the invoice of its billing period
+        let first_renewal = get_webhook_event_id(
+            &subscription,
+            Some(&get_invoice("inv_1", 1)),
+            storage_enums::EventType::SubscriptionRenewed,
+        );
+        let second_renewal = get_webhook_event_id(
+            &subscription,
+            Some(&get_invoice("inv_2", 1)),
+            storage_enums::EventType::SubscriptionRenewed,
+        );
+        assert_ne!(first_renewal, second_renewal);
+
+        // Every failed attempt to charge an invoice is raised for the payment of the attempt
+        let first_failure = get_webhook_event_id(
+            &subscription,
+            Some(&get_invoice("inv_2", 1)),
+            storage_enums::EventType::SubscriptionPaymentFailed,
+        );
+        let second_failure = get_webhook_event_id(
+            &subscription,
+            Some(&get_invoice("inv_2", 2)),
+            storage_enums::EventType::SubscriptionPaymentFailed,
+        );
+        assert_ne!(first_failure, second_failure);
+
+        // Every update is raised for the time the subscription was updated at
+        let updated_subscription = storage::Subscription {
+            last_modified_at: datetime!(2024-01-02 0:00),
+            ..subscription.clone()
+        };
+        assert_ne!(
+            get_webhook_event_id(
+                &subscription,
+                None,
+                storage_enums::EventType::SubscriptionUpdated
+            ),
+            get_webhook_event_id(
+                &updated_subscription,
+                None,
+                storage_enums::EventType::SubscriptionUpdated
+            )
+        );
+
+        assert_eq!(
+            get_webhook_event_id(
+                &subscription,
+                Some(&get_invoice("inv_1", 1)),
+                storage_enums::EventType::SubscriptionCreated
+            ),
+            "sub_test_subscription_created"
+        );
+    }
+
+    #[test]
+    fn test_add_billing_interval() {
+        let start = datetime!(2024-01-31 10:00);
+
+        assert_eq!(
+            add_billing_interval(start, storage_enums::SubscriptionInterval::Day, 2),
+            Some(datetime!(2024-02-02 10:00))
+        );
+        assert_eq!(
+            add_billing_interval(start, storage_enums::SubscriptionInterval::Week, 1),
+            Some(datetime!(2024-02-07 10:00))
+        );
+        // The day of the month is clamped to the last day of the month
+        assert_eq!(
+            add_billing_interval(start, storage_enums::SubscriptionInterval::Month, 1),
+            Some(datetime!(2024-02-29 10:00))
+        );
+        assert_eq!(
+            add_billing_interval(start, storage_enums::SubscriptionInterval::Month, 12),
+            Some(datetime!(2025-01-31 10:00))
+        );
+        assert_eq!(
+            add_billing_interval(
+                datetime!(2024-02-29 10:00),
+                storage_enums::SubscriptionInterval::Year,
+                1
+            ),
+            Some(datetime!(2025-02-28 10:00))
+        );
+        assert_eq!(
+            add_billing_interval(start, storage_enums::SubscriptionInterval::Year, i32::MAX),
+            None
+        );
+    }
+
+    #[test]
+    fn test_get_proration_amount() {
+        let subscription = get_subscription(datetime!(2024-01-01 0:00), datetime!(2024-01-31 0:00));
+        let current_plan = get_plan(1000);
+        let halfway = datetime!(2024-01-16 0:00);
+
+        assert_eq!(
+            get_proration_amount(&subscription, &current_plan, &get_plan(3000), halfway),
+            MinorUnit::new(1000)
+        );
+        assert_eq!(
+            get_proration_amount(&subscription, &current_plan, &get_plan(0), halfway),
+            MinorUnit::new(-500)
+        );
+        // The remaining time is clamped to the billing period
+        assert_eq!(
+            get_proration_amount(
+                &subscription,
+                &current_plan,
+                &get_plan(3000),
+                datetime!(2023-12-01 0:00)
+            ),
+            MinorUnit::new(2000)
+        );
+        assert_eq!(
+            get_proration_amount(
+                &subscription,
+                &current_plan,
+                &get_plan(3000),
+                datetime!(2024-02-01 0:00)
+            ),
+            MinorUnit::zero()
+        );
+    }
+}
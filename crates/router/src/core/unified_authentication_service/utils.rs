@@ -289,7 +289,9 @@ pub fn get_checkout_event_status_and_reason(
     attempt_status: common_enums::AttemptStatus,
 ) -> (Option<String>, Option<String>) {
     match attempt_status {
-        common_enums::AttemptStatus::Charged | common_enums::AttemptStatus::Authorized => (
+        common_enums::AttemptStatus::Charged
+        | common_enums::AttemptStatus::Authorized
+        | common_enums::AttemptStatus::PartiallyAuthorized => (
             Some("02".to_string()),
             Some("Approval Code received".to_string()),
         ),
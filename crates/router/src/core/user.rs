@@ -19,6 +19,7 @@ use diesel_models::{
     organization::OrganizationBridge,
     user as storage_user,
     user_authentication_method::{UserAuthenticationMethodNew, UserAuthenticationMethodUpdate},
+    user_passkey::UserPasskeyNew,
 };
 use error_stack::{report, ResultExt};
 use masking::{ExposeInterface, PeekInterface, Secret};
@@ -46,7 +47,7 @@ use crate::{
     types::{domain, transformers::ForeignInto},
     utils::{
         self,
-        user::{theme as theme_utils, two_factor_auth as tfa_utils},
+        user::{passkey as passkey_utils, theme as theme_utils, two_factor_auth as tfa_utils},
     },
 };
 #[cfg(feature = "email")]
@@ -420,6 +421,7 @@ pub async fn signout(
 ) -> UserResponse<()> {
     tfa_utils::delete_totp_from_redis(&state, &user_from_token.user_id).await?;
     tfa_utils::delete_recovery_code_from_redis(&state, &user_from_token.user_id).await?;
+    tfa_utils::delete_passkey_from_redis(&state, &user_from_token.user_id).await?;
     tfa_utils::delete_totp_secret_from_redis(&state, &user_from_token.user_id).await?;
 
     auth::blacklist::insert_user_in_blacklist(&state, &user_from_token.user_id).await?;
@@ -2070,6 +2072,15 @@ pub async fn begin_totp(
         }));
     }
 
+    if tfa_utils::is_two_factor_auth_required_for_totp_setup(
+        !passkey_utils::get_passkeys_for_user(&state, &user_token.user_id)
+            .await?
+            .is_empty(),
+        tfa_utils::check_two_factor_auth_in_redis(&state, &user_token.user_id).await?,
+    ) {
+        return Err(UserErrors::TwoFactorAuthRequired.into());
+    }
+
     let totp = tfa_utils::generate_default_totp(
         user_from_db.get_email(),
         None,
@@ -2101,9 +2112,7 @@ pub async fn reset_totp(
         return Err(UserErrors::TotpNotSetup.into());
     }
 
-    if !tfa_utils::check_totp_in_redis(&state, &user_token.user_id).await?
-        && !tfa_utils::check_recovery_code_in_redis(&state, &user_token.user_id).await?
-    {
+    if !tfa_utils::check_two_factor_auth_in_redis(&state, &user_token.user_id).await? {
         return Err(UserErrors::TwoFactorAuthRequired.into());
     }
 
@@ -2255,7 +2264,9 @@ pub async fn generate_recovery_codes(
     state: SessionState,
     user_token: auth::UserIdFromAuth,
 ) -> UserResponse<user_api::RecoveryCodes> {
-    if !tfa_utils::check_totp_in_redis(&state, &user_token.user_id).await? {
+    if !tfa_utils::check_totp_in_redis(&state, &user_token.user_id).await?
+        && !tfa_utils::check_passkey_in_redis(&state, &user_token.user_id).await?
+    {
         return Err(UserErrors::TotpRequired.into());
     }
 
@@ -2320,7 +2331,11 @@ pub async fn verify_recovery_code(
         .change_context(UserErrors::InternalServerError)?
         .into();
 
-    if user_from_db.get_totp_status() != TotpStatus::Set {
+    if user_from_db.get_totp_status() != TotpStatus::Set
+        && passkey_utils::get_passkeys_for_user(&state, &user_token.user_id)
+            .await?
+            .is_empty()
+    {
         return Err(UserErrors::TwoFactorAuthNotSetup.into());
     }
 
@@ -2382,9 +2397,7 @@ pub async fn terminate_two_factor_auth(
         .into();
 
     if state.conf.user.force_two_factor_auth || !skip_two_factor_auth {
-        if !tfa_utils::check_totp_in_redis(&state, &user_token.user_id).await?
-            && !tfa_utils::check_recovery_code_in_redis(&state, &user_token.user_id).await?
-        {
+        if !tfa_utils::check_two_factor_auth_in_redis(&state, &user_token.user_id).await? {
             return Err(UserErrors::TwoFactorAuthRequired.into());
         }
 
@@ -2392,7 +2405,10 @@ pub async fn terminate_two_factor_auth(
             return Err(UserErrors::TwoFactorAuthNotSetup.into());
         }
 
-        if user_from_db.get_totp_status() != TotpStatus::Set {
+        if tfa_utils::should_set_totp_status(
+            user_from_db.get_totp_status(),
+            tfa_utils::check_totp_in_redis(&state, &user_token.user_id).await?,
+        ) {
             state
                 .global_store
                 .update_user_by_user_id(
@@ -2418,6 +2434,9 @@ pub async fn terminate_two_factor_auth(
     let _ = tfa_utils::delete_recovery_code_attempts_from_redis(&state, &user_token.user_id)
         .await
         .inspect_err(|error| logger::error!(?error));
+    let _ = tfa_utils::delete_passkey_attempts_from_redis(&state, &user_token.user_id)
+        .await
+        .inspect_err(|error| logger::error!(?error));
 
     auth::cookies::set_cookie_response(
         user_api::TokenResponse {
@@ -2437,6 +2456,7 @@ pub async fn check_two_factor_auth_status(
             totp: tfa_utils::check_totp_in_redis(&state, &user_token.user_id).await?,
             recovery_code: tfa_utils::check_recovery_code_in_redis(&state, &user_token.user_id)
                 .await?,
+            passkey: tfa_utils::check_passkey_in_redis(&state, &user_token.user_id).await?,
         },
     ))
 }
@@ -2453,7 +2473,11 @@ pub async fn check_two_factor_auth_status_with_attempts(
         .into();
 
     let is_skippable = state.conf.user.force_two_factor_auth.not();
-    if user_from_db.get_totp_status() == TotpStatus::NotSet {
+    if user_from_db.get_totp_status() == TotpStatus::NotSet
+        && passkey_utils::get_passkeys_for_user(&state, &user_token.user_id)
+            .await?
+            .is_empty()
+    {
         return Ok(ApplicationResponse::Json(user_api::TwoFactorStatus {
             status: None,
             is_skippable,
@@ -2470,15 +2494,275 @@ pub async fn check_two_factor_auth_status_with_attempts(
         remaining_attempts: consts::user::RECOVERY_CODE_MAX_ATTEMPTS
             - tfa_utils::get_recovery_code_attempts_from_redis(&state, &user_token.user_id).await?,
     };
+    let passkey = user_api::TwoFactorAuthAttempts {
+        is_completed: tfa_utils::check_passkey_in_redis(&state, &user_token.user_id).await?,
+        remaining_attempts: consts::user::PASSKEY_MAX_ATTEMPTS
+            - tfa_utils::get_passkey_attempts_from_redis(&state, &user_token.user_id).await?,
+    };
     Ok(ApplicationResponse::Json(user_api::TwoFactorStatus {
         status: Some(user_api::TwoFactorAuthStatusResponseWithAttempts {
             totp,
             recovery_code,
+            passkey,
         }),
         is_skippable,
     }))
 }
 
+pub async fn begin_passkey_registration(
+    state: SessionState,
+    user_token: auth::UserIdFromAuth,
+) -> UserResponse<user_api::PasskeyChallengeResponse> {
+    let user_from_db: domain::UserFromStorage = state
+        .global_store
+        .find_user_by_id(&user_token.user_id)
+        .await
+        .change_context(UserErrors::InternalServerError)?
+        .into();
+
+    let existing_passkeys = passkey_utils::get_passkeys_for_user(&state, &user_token.user_id)
+        .await?
+        .into_iter()
+        .map(|(_, passkey)| passkey)
+        .collect::<Vec<_>>();
+
+    // Users who have already setup a second factor have to complete it before adding a passkey
+    if (user_from_db.get_totp_status() == TotpStatus::Set || !existing_passkeys.is_empty())
+        && !tfa_utils::check_two_factor_auth_in_redis(&state, &user_token.user_id).await?
+    {
+        return Err(UserErrors::TwoFactorAuthRequired.into());
+    }
+
+    let options = passkey_utils::start_registration(
+        &state,
+        &user_token.user_id,
+        user_from_db.get_email().peek(),
+        user_from_db.get_name().peek(),
+        &existing_passkeys,
+    )
+    .await?;
+
+    Ok(ApplicationResponse::Json(
+        user_api::PasskeyChallengeResponse { options },
+    ))
+}
+
+pub async fn finish_passkey_registration(
+    state: SessionState,
+    user_token: auth::UserIdFromAuth,
+    req: user_api::FinishPasskeyRegistrationRequest,
+) -> UserResponse<user_api::PasskeyResponse> {
+    let name = req.name.trim();
+    if name.is_empty() || name.len() > 255 {
+        return Err(UserErrors::InvalidAuthMethodOperationWithMessage(
+            "Passkey name should be between 1 and 255 characters".to_string(),
+        )
+        .into());
+    }
+
+    let passkey =
+        passkey_utils::finish_registration(&state, &user_token.user_id, req.credential).await?;
+
+    let now = common_utils::date_time::now();
+    let user_passkey = state
+        .global_store
+        .insert_user_passkey(UserPasskeyNew {
+            passkey_id: common_utils::generate_id_with_default_len(consts::user::PASSKEY_ID_PREFIX),
+            user_id: user_token.user_id.clone(),
+            credential_id: passkey_utils::get_credential_id(&passkey),
+            name: name.to_string(),
+            passkey: serde_json::to_value(&passkey)
+                .change_context(UserErrors::InternalServerError)?,
+            created_at: now,
+            last_modified_at: now,
+            last_used_at: None,
+        })
+        .await
+        .map_err(|e| {
+            if e.current_context().is_db_unique_violation() {
+                e.change_context(UserErrors::PasskeyAlreadyRegistered)
+            } else {
+                e.change_context(UserErrors::InternalServerError)
+            }
+        })?;
+
+    // Registering the passkey proves the possession of it, so it is considered as verified for the
+    // current session, similar to how TOTP is treated after it is updated.
+    let _ = tfa_utils::insert_passkey_in_redis(&state, &user_token.user_id)
+        .await
+        .map_err(|error| logger::error!(?error));
+
+    Ok(ApplicationResponse::Json(user_passkey.foreign_into()))
+}
+
+pub async fn begin_passkey_authentication(
+    state: SessionState,
+    user_token: auth::UserIdFromAuth,
+) -> UserResponse<user_api::PasskeyChallengeResponse> {
+    let options = passkey_utils::start_authentication(&state, &user_token.user_id).await?;
+
+    Ok(ApplicationResponse::Json(
+        user_api::PasskeyChallengeResponse { options },
+    ))
+}
+
+pub async fn verify_passkey(
+    state: SessionState,
+    user_token: auth::UserIdFromAuth,
+    req: user_api::VerifyPasskeyRequest,
+) -> UserResponse<()> {
+    let user_passkey_attempts =
+        tfa_utils::get_passkey_attempts_from_redis(&state, &user_token.user_id).await?;
+
+    if user_passkey_attempts >= consts::user::PASSKEY_MAX_ATTEMPTS {
+        return Err(UserErrors::MaxPasskeyAttemptsReached.into());
+    }
+
+    if let Err(error) =
+        passkey_utils::finish_authentication(&state, &user_token.user_id, req.credential).await
+    {
+        if matches!(error.current_context(), UserErrors::InvalidPasskey) {
+            let _ = tfa_utils::insert_passkey_attempts_in_redis(
+                &state,
+                &user_token.user_id,
+                user_passkey_attempts + 1,
+            )
+            .await
+            .inspect_err(|error| logger::error!(?error));
+        }
+        return Err(error);
+    }
+
+    tfa_utils::insert_passkey_in_redis(&state, &user_token.user_id).await?;
+
+    Ok(ApplicationResponse::StatusOk)
+}
+
+pub async fn list_passkeys(
+    state: SessionState,
+    user_token: auth::UserFromToken,
+) -> UserResponse<Vec<user_api::PasskeyResponse>> {
+    let passkeys = state
+        .global_store
+        .list_user_passkeys_by_user_id(&user_token.user_id)
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+
+    Ok(ApplicationResponse::Json(
+        passkeys
+            .into_iter()
+            .map(ForeignInto::foreign_into)
+            .collect(),
+    ))
+}
+
+pub async fn delete_passkey(
+    state: SessionState,
+    user_token: auth::UserFromToken,
+    passkey_id: String,
+) -> UserResponse<()> {
+    if !tfa_utils::check_two_factor_auth_in_redis(&state, &user_token.user_id).await? {
+        return Err(UserErrors::TwoFactorAuthRequired.into());
+    }
+
+    state
+        .global_store
+        .delete_user_passkey_by_user_id_passkey_id(&user_token.user_id, &passkey_id)
+        .await
+        .to_not_found_response(UserErrors::PasskeyNotFound)?;
+
+    Ok(ApplicationResponse::StatusOk)
+}
+
+pub async fn begin_passkey_signin(
+    state: SessionState,
+) -> UserResponse<user_api::PasskeySignInChallengeResponse> {
+    let (challenge_id, options) = passkey_utils::start_signin(&state).await?;
+
+    Ok(ApplicationResponse::Json(
+        user_api::PasskeySignInChallengeResponse {
+            challenge_id,
+            options,
+        },
+    ))
+}
+
+pub async fn passkey_signin(
+    state: SessionState,
+    request: user_api::PasskeySignInRequest,
+) -> UserResponse<user_api::TokenResponse> {
+    let user_email = domain::UserEmail::from_pii_email(request.email)?;
+
+    utils::user::validate_email_domain_auth_type_using_db(
+        &state,
+        &user_email,
+        UserAuthType::Passkey,
+    )
+    .await?;
+
+    let user_from_db: domain::UserFromStorage = state
+        .global_store
+        .find_user_by_email(&user_email)
+        .await
+        .to_not_found_response(UserErrors::InvalidCredentials)?
+        .into();
+
+    let user_passkey_attempts =
+        tfa_utils::get_passkey_attempts_from_redis(&state, user_from_db.get_user_id()).await?;
+
+    // The same error is returned for locked out users, so that it does not reveal which users exist
+    if user_passkey_attempts >= consts::user::PASSKEY_MAX_ATTEMPTS {
+        return Err(report!(UserErrors::InvalidCredentials))
+            .attach_printable("Maximum attempts reached for passkey sign in");
+    }
+
+    // Only the failures of the passkeys of the user are counted, so that other users can not lock
+    // the user out of passkey sign in and 2FA
+    if let Err(error) = passkey_utils::finish_signin(
+        &state,
+        user_from_db.get_user_id(),
+        &request.challenge_id,
+        request.credential,
+    )
+    .await
+    {
+        if matches!(error.current_context(), UserErrors::InvalidPasskey) {
+            let _ = tfa_utils::insert_passkey_attempts_in_redis(
+                &state,
+                user_from_db.get_user_id(),
+                user_passkey_attempts + 1,
+            )
+            .await
+            .inspect_err(|error| logger::error!(?error));
+
+            // The same error is returned for all the passkeys which can not sign in the user
+            return Err(error.change_context(UserErrors::InvalidCredentials));
+        }
+        return Err(error);
+    }
+
+    let _ = tfa_utils::delete_passkey_attempts_from_redis(&state, user_from_db.get_user_id())
+        .await
+        .inspect_err(|error| logger::error!(?error));
+
+    // Passkey is also a second factor, so the APIs which require 2FA in this session are allowed
+    let _ = tfa_utils::insert_passkey_in_redis(&state, user_from_db.get_user_id())
+        .await
+        .map_err(|error| logger::error!(?error));
+
+    let next_flow =
+        domain::NextFlow::from_origin(domain::Origin::SignInWithPasskey, user_from_db, &state)
+            .await?;
+
+    let token = next_flow.get_token(&state).await?;
+
+    let response = user_api::TokenResponse {
+        token: token.clone(),
+        token_type: next_flow.get_flow().into(),
+    };
+    auth::cookies::set_cookie_response(response, token)
+}
+
 pub async fn create_user_authentication_method(
     state: SessionState,
     req: user_api::CreateUserAuthenticationMethodRequest,
@@ -2552,7 +2836,9 @@ pub async fn create_user_authentication_method(
                 let req_auth_name = public_config.name;
                 db_auth_name.is_some_and(|name| name == req_auth_name)
             }
-            user_api::AuthConfig::Password | user_api::AuthConfig::MagicLink => true,
+            user_api::AuthConfig::Password
+            | user_api::AuthConfig::MagicLink
+            | user_api::AuthConfig::Passkey => true,
         };
         if is_type_same && is_extra_identifier_same {
             return Err(report!(UserErrors::UserAuthMethodAlreadyExists));
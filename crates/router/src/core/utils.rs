@@ -1,3 +1,4 @@
+pub mod bulk_job;
 pub mod refunds_transformers;
 pub mod refunds_validator;
 
@@ -0,0 +1,153 @@
+//! Helpers shared by the jobs which process a file of rows uploaded by the merchant, like payout
+//! batches and payment method migration jobs. Every row of the file is parsed separately, so that
+//! an invalid row is reported against the row instead of failing the upload.
+
+use error_stack::ResultExt;
+
+use crate::core::errors::{self, RouterResult};
+
+/// Parses every row of a CSV file with a header row. Errors which are specific to a row are
+/// returned against the row, while errors which are not, like an invalid encoding, fail the file.
+pub fn parse_csv_rows<T: serde::de::DeserializeOwned>(
+    data: &[u8],
+) -> csv::Result<Vec<Result<T, String>>> {
+    let mut csv_reader = csv::ReaderBuilder::new()
+        .has_headers(true)
+        .trim(csv::Trim::All)
+        .from_reader(data);
+    let mut rows = Vec::new();
+    for result in csv_reader.deserialize::<T>() {
+        let row = match result {
+            Ok(record) => Ok(record),
+            Err(error)
+                if matches!(
+                    error.kind(),
+                    csv::ErrorKind::Deserialize { .. } | csv::ErrorKind::UnequalLengths { .. }
+                ) =>
+            {
+                Err(error.to_string())
+            }
+            Err(error) => return Err(error),
+        };
+        rows.push(row);
+    }
+    Ok(rows)
+}
+
+/// Parses every element of a JSON array separately, only a file which is not a JSON array fails
+pub fn parse_json_rows<T: serde::de::DeserializeOwned>(
+    data: &[u8],
+) -> serde_json::Result<Vec<Result<T, String>>> {
+    let values = serde_json::from_slice::<Vec<serde_json::Value>>(data)?;
+    Ok(values
+        .into_iter()
+        .map(|value| serde_json::from_value(value).map_err(|error| error.to_string()))
+        .collect())
+}
+
+/// Rejects a file which has no rows, or more rows than the job accepts
+pub fn validate_row_count(
+    row_count: usize,
+    max_rows: usize,
+    file_description: &str,
+) -> Result<(), errors::ApiErrorResponse> {
+    if row_count == 0 {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("The {file_description} file has no rows"),
+        });
+    }
+    if row_count > max_rows {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("The {file_description} file can have at most {max_rows} rows"),
+        });
+    }
+    Ok(())
+}
+
+/// Inserts the items in chunks of the given size, so that a single query stays well within the
+/// bind parameter limit of Postgres
+pub async fn insert_in_chunks<T, F, Fut>(
+    items: Vec<T>,
+    chunk_size: usize,
+    insert_chunk: F,
+) -> RouterResult<()>
+where
+    F: Fn(Vec<T>) -> Fut,
+    Fut: std::future::Future<Output = RouterResult<()>>,
+{
+    let mut items = items.into_iter().peekable();
+    while items.peek().is_some() {
+        insert_chunk(items.by_ref().take(chunk_size).collect()).await?;
+    }
+    Ok(())
+}
+
+/// Writes the records as a CSV file with a header row
+pub fn write_csv_report<T: serde::Serialize>(
+    records: impl IntoIterator<Item = T>,
+) -> RouterResult<Vec<u8>> {
+    let mut csv_writer = csv::Writer::from_writer(Vec::new());
+    for record in records {
+        csv_writer
+            .serialize(record)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to write the report")?;
+    }
+    csv_writer
+        .into_inner()
+        .map_err(|error| error.into_error())
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to write the report")
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    #[derive(Debug, PartialEq, serde::Deserialize)]
+    struct Record {
+        id: String,
+        amount: i64,
+    }
+
+    #[test]
+    fn test_parse_csv_rows_reports_invalid_rows() {
+        let data = b"id,amount\nrow_1,100\nrow_2,abc\nrow_3\n row_4 , 400 \n";
+
+        let rows = parse_csv_rows::<Record>(data).unwrap();
+
+        assert_eq!(
+            rows.iter().map(Result::is_ok).collect::<Vec<_>>(),
+            vec![true, false, false, true]
+        );
+        assert_eq!(
+            rows.last(),
+            Some(&Ok(Record {
+                id: "row_4".to_string(),
+                amount: 400
+            }))
+        );
+    }
+
+    #[test]
+    fn test_parse_json_rows_reports_invalid_rows() {
+        let data = br#"[{"id": "row_1", "amount": 100}, {"id": "row_2"}]"#;
+
+        let rows = parse_json_rows::<Record>(data).unwrap();
+
+        assert_eq!(
+            rows.iter().map(Result::is_ok).collect::<Vec<_>>(),
+            vec![true, false]
+        );
+        assert!(parse_json_rows::<Record>(br#"{"id": "row_1"}"#).is_err());
+    }
+
+    #[test]
+    fn test_validate_row_count() {
+        assert!(validate_row_count(0, 10, "test").is_err());
+        assert!(validate_row_count(10, 10, "test").is_ok());
+        assert!(validate_row_count(11, 10, "test").is_err());
+    }
+}
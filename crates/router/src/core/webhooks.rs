@@ -1,4 +1,6 @@
 #[cfg(feature = "v1")]
+pub mod delivery_queue;
+#[cfg(feature = "v1")]
 mod incoming;
 #[cfg(feature = "v2")]
 mod incoming_v2;
@@ -10,6 +12,8 @@ mod outgoing_v2;
 pub mod recovery_incoming;
 pub mod types;
 pub mod utils;
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub mod webhook_endpoints;
 #[cfg(feature = "olap")]
 pub mod webhook_events;
 
@@ -0,0 +1,1127 @@
+use std::{collections::HashMap, sync::Arc, time::Duration};
+
+use api_models::webhook_events::OutgoingWebhookRequestContent;
+use common_utils::{ext_traits::StringExt, id_type, signals::get_allowed_signals};
+use error_stack::{report, ResultExt};
+use masking::PeekInterface;
+use redis_interface::{errors::RedisError, RedisConnectionPool, RedisEntryId};
+use router_env::{
+    instrument,
+    tracing::{self, Instrument},
+};
+use tokio::sync::{mpsc, OwnedSemaphorePermit, Semaphore};
+
+use crate::{
+    configs::settings::WebhookDeliveryQueueSettings,
+    core::{
+        errors::{self, CustomResult},
+        metrics,
+    },
+    logger,
+    routes::{AppState, SessionState},
+    types::{
+        domain,
+        storage::{self, enums},
+    },
+};
+
+const MERCHANT_ID_FIELD: &str = "merchant_id";
+const EVENT_ID_FIELD: &str = "event_id";
+const ENDPOINT_KEY_FIELD: &str = "endpoint_key";
+const PROCESS_TRACKER_ID_FIELD: &str = "process_tracker_id";
+const FAILED_ATTEMPTS_FIELD: &str = "failed_attempts";
+
+/// Delivery slots held by a worker which crashed mid-delivery are released once the in-flight
+/// counter of the endpoint has not been touched for this long.
+const DELIVERY_SLOT_EXPIRY_MILLISECONDS: u64 = 60_000;
+
+/// Increments the number of in-flight deliveries for the endpoint, unless it would exceed the
+/// limit. Returns `1` if a delivery slot was acquired, `0` otherwise.
+const ACQUIRE_DELIVERY_SLOT_SCRIPT: &str = r#"
+local in_flight = redis.call("INCR", KEYS[1])
+redis.call("PEXPIRE", KEYS[1], ARGV[2])
+if in_flight > tonumber(ARGV[1]) then
+    redis.call("DECR", KEYS[1])
+    return 0
+end
+return 1
+"#;
+
+const RELEASE_DELIVERY_SLOT_SCRIPT: &str = r#"
+local in_flight = redis.call("DECR", KEYS[1])
+if in_flight <= 0 then
+    redis.call("DEL", KEYS[1])
+end
+return 1
+"#;
+
+/// Refills the token bucket of the endpoint based on the time elapsed since it was last refilled,
+/// and takes a token from it. Returns `1` if a token was available, `0` otherwise.
+const TAKE_RATE_LIMIT_TOKEN_SCRIPT: &str = r#"
+local bucket_size = tonumber(ARGV[1])
+local refill_rate = tonumber(ARGV[2])
+local now = tonumber(ARGV[3])
+local bucket = redis.call("HMGET", KEYS[1], "tokens", "refilled_at")
+local tokens = tonumber(bucket[1]) or bucket_size
+local refilled_at = tonumber(bucket[2]) or now
+tokens = math.min(bucket_size, tokens + math.max(0, now - refilled_at) * refill_rate / 1000)
+local allowed = 0
+if tokens >= 1 then
+    tokens = tokens - 1
+    allowed = 1
+end
+redis.call("HSET", KEYS[1], "tokens", tostring(tokens), "refilled_at", ARGV[3])
+redis.call("PEXPIRE", KEYS[1], math.ceil(bucket_size * 1000 / refill_rate) + 1000)
+return allowed
+"#;
+
+/// Adds a deferred entry to the sorted set of deferred entries, scored by the time (in
+/// milliseconds since the epoch) after which it is due for delivery.
+const DEFER_ENTRY_SCRIPT: &str = r#"
+redis.call("ZADD", KEYS[1], ARGV[1], ARGV[2])
+return 1
+"#;
+
+/// Returns the deferred entries which are due, and leases them by moving their score to the end of
+/// the lease, so that other workers do not pick them up while they are added back to the stream.
+/// Entries leased by a worker which crashed become due again once the lease ends.
+const LEASE_DUE_ENTRIES_SCRIPT: &str = r#"
+local due = redis.call("ZRANGEBYSCORE", KEYS[1], "-inf", ARGV[1], "LIMIT", 0, tonumber(ARGV[2]))
+for _, member in ipairs(due) do
+    redis.call("ZADD", KEYS[1], ARGV[3], member)
+end
+return due
+"#;
+
+const REMOVE_DEFERRED_ENTRY_SCRIPT: &str = r#"
+return redis.call("ZREM", KEYS[1], ARGV[1])
+"#;
+
+/// Returns the remaining time to live of the key in milliseconds, or a negative value if the key
+/// does not exist.
+const GET_TTL_SCRIPT: &str = r#"
+return redis.call("PTTL", KEYS[1])
+"#;
+
+/// Duration for which deferred entries which are due are leased by a worker
+const DEFERRED_ENTRY_LEASE_MILLISECONDS: u64 = 30_000;
+
+/// Entries whose delivery failed with an error are deferred for this long, multiplied by the
+/// number of times their delivery has failed so far.
+const FAILED_DELIVERY_RETRY_DELAY_MILLISECONDS: u64 = 30_000;
+
+/// Number of times the delivery of an entry may fail with an error before it is given up on
+const MAX_FAILED_DELIVERY_ATTEMPTS: u32 = 5;
+
+/// Add the event to the webhook delivery stream, to be delivered by the webhook delivery worker.
+#[instrument(skip_all)]
+pub(crate) async fn enqueue_outgoing_webhook_delivery(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    event: &domain::Event,
+    process_tracker: Option<&storage::ProcessTracker>,
+) -> CustomResult<(), errors::WebhooksFlowError> {
+    let settings = &state.conf.webhooks.delivery_queue;
+    let endpoint_key = get_endpoint_key(merchant_id, event)?;
+
+    let mut fields = vec![
+        (MERCHANT_ID_FIELD, merchant_id.get_string_repr().to_owned()),
+        (EVENT_ID_FIELD, event.event_id.clone()),
+        (ENDPOINT_KEY_FIELD, endpoint_key),
+    ];
+    if let Some(process_tracker) = process_tracker {
+        fields.push((PROCESS_TRACKER_ID_FIELD, process_tracker.id.clone()));
+    }
+
+    state
+        .store
+        .get_redis_conn()
+        .change_context(errors::WebhooksFlowError::WebhookDeliveryEnqueueFailed)?
+        .stream_append_entry(
+            &settings.stream_name.as_str().into(),
+            &RedisEntryId::AutoGeneratedID,
+            fields,
+        )
+        .await
+        .change_context(errors::WebhooksFlowError::WebhookDeliveryEnqueueFailed)
+        .attach_printable("Failed to append outgoing webhook to the delivery stream")?;
+
+    metrics::WEBHOOK_DELIVERY_ENQUEUED_COUNT.add(
+        1,
+        router_env::metric_attributes!((super::MERCHANT_ID, merchant_id.clone())),
+    );
+
+    Ok(())
+}
+
+/// Rate limits and circuit breakers are tracked per webhook endpoint, with events which are
+/// delivered to the webhook URL of the business profile sharing the state of the profile.
+pub(crate) fn get_endpoint_key(
+    merchant_id: &id_type::MerchantId,
+    event: &domain::Event,
+) -> CustomResult<String, errors::WebhooksFlowError> {
+    let endpoint = match (&event.webhook_endpoint_id, &event.business_profile_id) {
+        (Some(webhook_endpoint_id), _) => webhook_endpoint_id.as_str(),
+        (None, Some(profile_id)) => profile_id.get_string_repr(),
+        (None, None) => Err(report!(
+            errors::WebhooksFlowError::WebhookDeliveryDetailsNotFound
+        ))
+        .attach_printable("Event is associated with neither a webhook endpoint nor a profile")?,
+    };
+
+    Ok(format!("{}_{endpoint}", merchant_id.get_string_repr()))
+}
+
+#[derive(Debug, Clone)]
+struct WebhookDeliveryEntry {
+    entry_id: String,
+    merchant_id: id_type::MerchantId,
+    event_id: String,
+    endpoint_key: String,
+    process_tracker_id: Option<String>,
+    /// Number of times the delivery of the entry failed with an error
+    failed_attempts: u32,
+    /// The fields of the stream entry, retained to add the entry back to the stream when the
+    /// delivery is deferred
+    fields: Vec<(String, String)>,
+}
+
+impl WebhookDeliveryEntry {
+    fn from_stream_entry(
+        entry_id: String,
+        fields: HashMap<String, Option<String>>,
+    ) -> CustomResult<Self, errors::WebhooksFlowError> {
+        let fields = fields
+            .into_iter()
+            .filter_map(|(field, value)| value.map(|value| (field, value)))
+            .collect::<HashMap<_, _>>();
+
+        let get_field = |field: &str| {
+            fields.get(field).cloned().ok_or_else(|| {
+                report!(errors::WebhooksFlowError::WebhookDeliveryDetailsNotFound)
+                    .attach_printable(format!("Missing field `{field}` in delivery stream entry"))
+            })
+        };
+
+        let merchant_id = id_type::MerchantId::wrap(get_field(MERCHANT_ID_FIELD)?)
+            .change_context(errors::WebhooksFlowError::WebhookDeliveryDetailsNotFound)?;
+        let event_id = get_field(EVENT_ID_FIELD)?;
+        let endpoint_key = get_field(ENDPOINT_KEY_FIELD)?;
+        let process_tracker_id = fields.get(PROCESS_TRACKER_ID_FIELD).cloned();
+        let failed_attempts = fields
+            .get(FAILED_ATTEMPTS_FIELD)
+            .and_then(|failed_attempts| failed_attempts.parse().ok())
+            .unwrap_or_default();
+
+        Ok(Self {
+            entry_id,
+            merchant_id,
+            event_id,
+            endpoint_key,
+            process_tracker_id,
+            failed_attempts,
+            fields: fields.into_iter().collect(),
+        })
+    }
+
+    /// Returns the entry with one more failed delivery attempt recorded in its fields
+    fn with_failed_attempt(&self) -> Self {
+        let failed_attempts = self.failed_attempts.saturating_add(1);
+        let mut fields = self
+            .fields
+            .iter()
+            .filter(|(field, _)| field != FAILED_ATTEMPTS_FIELD)
+            .cloned()
+            .collect::<Vec<_>>();
+        fields.push((
+            FAILED_ATTEMPTS_FIELD.to_string(),
+            failed_attempts.to_string(),
+        ));
+
+        Self {
+            failed_attempts,
+            fields,
+            ..self.clone()
+        }
+    }
+
+    /// Deferred entries are stored as the JSON serialized fields of the stream entry
+    fn get_deferred_member(&self) -> CustomResult<String, errors::WebhooksFlowError> {
+        let mut fields = self.fields.clone();
+        fields.sort();
+        serde_json::to_string(&fields)
+            .change_context(errors::WebhooksFlowError::WebhookDeliveryQueueOperationFailed)
+    }
+}
+
+#[derive(Debug)]
+enum DeliveryOutcome {
+    Delivered,
+    /// The delivery was not attempted, and the process tracker retry task of the event will take
+    /// care of delivering it
+    LeftToRetryTask,
+    /// The delivery was not attempted, and the entry is added back to the stream once the delay
+    /// elapses
+    Deferred(DeferralReason, Duration),
+}
+
+/// Reasons for which a delivery to an endpoint is not attempted right away
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DeferralReason {
+    CircuitOpen,
+    ConcurrencyLimit,
+    RateLimit,
+}
+
+impl DeferralReason {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::CircuitOpen => "circuit_open",
+            Self::ConcurrencyLimit => "concurrency_limit",
+            Self::RateLimit => "rate_limit",
+        }
+    }
+}
+
+/// Time after which a token is available again in a rate limit bucket which was found empty
+fn get_rate_limit_delay(settings: &WebhookDeliveryQueueSettings) -> Duration {
+    Duration::from_millis(1000_u64.div_ceil(u64::from(settings.rate_limit.refill_rate.max(1))))
+}
+
+/// Time after which a delivery slot of the endpoint is expected to be released
+fn get_concurrency_limit_delay(settings: &WebhookDeliveryQueueSettings) -> Duration {
+    Duration::from_millis(settings.loop_interval)
+}
+
+/// Time after which the delivery of an entry which failed with an error is attempted again, or
+/// `None` if the entry has failed too many times already
+fn get_failed_delivery_retry_delay(failed_attempts: u32) -> Option<Duration> {
+    (failed_attempts < MAX_FAILED_DELIVERY_ATTEMPTS).then(|| {
+        Duration::from_millis(
+            FAILED_DELIVERY_RETRY_DELAY_MILLISECONDS
+                .saturating_mul(u64::from(failed_attempts).saturating_add(1)),
+        )
+    })
+}
+
+/// Consume the webhook delivery stream of every tenant until a shutdown signal is received.
+///
+/// The worker holds at most `max_in_flight_deliveries` entries at a time. Deliveries to a single
+/// endpoint are limited by a concurrency limit and a token bucket rate limit, both of which are
+/// tracked in Redis so that they are shared by all worker instances. An endpoint which keeps
+/// failing has its circuit opened, and deliveries to it are skipped until the circuit closes.
+#[instrument(skip_all)]
+pub async fn start_webhook_delivery_worker(
+    state: &AppState,
+    (tx, mut rx): (mpsc::Sender<()>, mpsc::Receiver<()>),
+) -> CustomResult<(), errors::WebhooksFlowError> {
+    let settings = Arc::new(state.conf.webhooks.delivery_queue.clone());
+    let app_state = Arc::new(state.clone());
+    let session_states = state
+        .conf
+        .multitenancy
+        .get_tenant_ids()
+        .iter()
+        .map(|tenant| {
+            app_state.clone().get_session_state(tenant, None, || {
+                report!(errors::WebhooksFlowError::WebhookDeliveryQueueOperationFailed)
+                    .attach_printable("Tenant not found")
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let in_flight = Arc::new(Semaphore::new(settings.max_in_flight_deliveries));
+
+    for session_state in &session_states {
+        create_consumer_group(session_state, &settings).await?;
+
+        // Entries which were read but not acknowledged before the worker was last shut down
+        let pending_entries = read_entries(
+            session_state,
+            &settings,
+            "0",
+            settings.max_in_flight_deliveries,
+        )
+        .await?;
+        spawn_deliveries(session_state, &settings, &in_flight, pending_entries).await?;
+    }
+
+    let signal = get_allowed_signals()
+        .change_context(errors::WebhooksFlowError::WebhookDeliveryQueueOperationFailed)
+        .attach_printable("Failed while creating a signals handler")?;
+    let handle = signal.handle();
+    let task_handle =
+        tokio::spawn(common_utils::signals::signal_handler(signal, tx).in_current_span());
+
+    let mut interval = tokio::time::interval(Duration::from_millis(settings.loop_interval));
+    let mut first_tenant_index: usize = 0;
+
+    loop {
+        match rx.try_recv() {
+            Err(mpsc::error::TryRecvError::Empty) => {
+                interval.tick().await;
+
+                // The tenant which is read first is rotated on every tick, so that the in flight
+                // deliveries are not always taken up by the same tenants
+                for session_state in session_states
+                    .iter()
+                    .cycle()
+                    .skip(first_tenant_index)
+                    .take(session_states.len())
+                {
+                    if let Err(error) = requeue_due_deferred_entries(session_state, &settings).await
+                    {
+                        logger::error!(?error, "Failed to add deferred webhook deliveries back");
+                    }
+
+                    let available = in_flight.available_permits();
+                    if available == 0 {
+                        continue;
+                    }
+
+                    let result = match read_entries(session_state, &settings, ">", available).await
+                    {
+                        Ok(entries) => {
+                            spawn_deliveries(session_state, &settings, &in_flight, entries).await
+                        }
+                        Err(error) => Err(error),
+                    };
+
+                    if let Err(error) = result {
+                        logger::error!(
+                            ?error,
+                            tenant_id = ?session_state.tenant.tenant_id,
+                            "Failed to process the webhook delivery stream"
+                        );
+                    }
+                }
+
+                first_tenant_index = first_tenant_index
+                    .saturating_add(1)
+                    .checked_rem(session_states.len())
+                    .unwrap_or_default();
+            }
+            Ok(()) | Err(mpsc::error::TryRecvError::Disconnected) => {
+                logger::debug!("Awaiting shutdown!");
+                rx.close();
+                while in_flight.available_permits() < settings.max_in_flight_deliveries {
+                    tokio::time::sleep(Duration::from_millis(settings.loop_interval)).await;
+                }
+                logger::info!("Terminating webhook delivery worker");
+                break;
+            }
+        }
+    }
+
+    handle.close();
+    task_handle
+        .await
+        .change_context(errors::WebhooksFlowError::WebhookDeliveryQueueOperationFailed)?;
+
+    Ok(())
+}
+
+async fn create_consumer_group(
+    state: &SessionState,
+    settings: &WebhookDeliveryQueueSettings,
+) -> CustomResult<(), errors::WebhooksFlowError> {
+    let redis_conn = get_redis_conn(state)?;
+
+    match redis_conn
+        .consumer_group_create(
+            &settings.stream_name.as_str().into(),
+            &settings.consumer_group,
+            &RedisEntryId::AfterLastID,
+        )
+        .await
+    {
+        Ok(()) => Ok(()),
+        // The consumer group would already exist if the worker was started before
+        Err(error) if error.current_context() == &RedisError::ConsumerGroupCreateFailed => {
+            logger::debug!(?error, "Webhook delivery consumer group was not created");
+            Ok(())
+        }
+        Err(error) => {
+            Err(error
+                .change_context(errors::WebhooksFlowError::WebhookDeliveryQueueOperationFailed))
+        }
+    }
+}
+
+async fn read_entries(
+    state: &SessionState,
+    settings: &WebhookDeliveryQueueSettings,
+    id: &str,
+    count: usize,
+) -> CustomResult<Vec<WebhookDeliveryEntry>, errors::WebhooksFlowError> {
+    let redis_conn = get_redis_conn(state)?;
+
+    let response = match redis_conn
+        .stream_read_with_options(
+            settings.stream_name.as_str(),
+            id,
+            u64::try_from(count).ok(),
+            None,
+            Some((
+                settings.consumer_group.as_str(),
+                settings.consumer_name.as_str(),
+            )),
+        )
+        .await
+    {
+        Ok(response) => response,
+        Err(error) if error.current_context() == &RedisError::StreamEmptyOrNotAvailable => {
+            return Ok(Vec::new())
+        }
+        Err(error) => {
+            return Err(error
+                .change_context(errors::WebhooksFlowError::WebhookDeliveryQueueOperationFailed))
+        }
+    };
+
+    let mut entries = Vec::new();
+    for (entry_id, fields) in response.into_values().flatten() {
+        match WebhookDeliveryEntry::from_stream_entry(entry_id.clone(), fields) {
+            Ok(entry) => entries.push(entry),
+            Err(error) => {
+                logger::error!(?error, %entry_id, "Discarding invalid webhook delivery entry");
+                acknowledge_entry(&redis_conn, settings, &entry_id).await?;
+            }
+        }
+    }
+
+    Ok(entries)
+}
+
+async fn spawn_deliveries(
+    state: &SessionState,
+    settings: &Arc<WebhookDeliveryQueueSettings>,
+    in_flight: &Arc<Semaphore>,
+    entries: Vec<WebhookDeliveryEntry>,
+) -> CustomResult<(), errors::WebhooksFlowError> {
+    for entry in entries {
+        let permit = in_flight
+            .clone()
+            .acquire_owned()
+            .await
+            .change_context(errors::WebhooksFlowError::WebhookDeliveryQueueOperationFailed)?;
+
+        tokio::spawn(
+            process_entry(state.clone(), settings.clone(), entry, permit).in_current_span(),
+        );
+    }
+
+    Ok(())
+}
+
+#[instrument(skip_all, fields(event_id = %entry.event_id, endpoint = %entry.endpoint_key))]
+async fn process_entry(
+    state: SessionState,
+    settings: Arc<WebhookDeliveryQueueSettings>,
+    entry: WebhookDeliveryEntry,
+    _permit: OwnedSemaphorePermit,
+) {
+    let result = async {
+        let redis_conn = get_redis_conn(&state)?;
+
+        match deliver_entry(&state, &redis_conn, &settings, &entry).await {
+            Ok(DeliveryOutcome::Deferred(reason, delay)) => {
+                metrics::WEBHOOK_DELIVERY_DEFERRED_COUNT.add(
+                    1,
+                    router_env::metric_attributes!(("reason", reason.as_str())),
+                );
+                defer_entry(&redis_conn, &settings, &entry, delay).await?;
+            }
+            Ok(outcome @ (DeliveryOutcome::Delivered | DeliveryOutcome::LeftToRetryTask)) => {
+                logger::debug!(?outcome, "Processed webhook delivery entry");
+            }
+            Err(error) => {
+                logger::error!(?error, "Failed to deliver queued outgoing webhook");
+                match get_failed_delivery_retry_delay(entry.failed_attempts) {
+                    Some(delay) => {
+                        defer_entry(&redis_conn, &settings, &entry.with_failed_attempt(), delay)
+                            .await?
+                    }
+                    // The process tracker retry task of the event would deliver it later, if one
+                    // exists
+                    None => logger::error!(
+                        failed_attempts = entry.failed_attempts,
+                        process_tracker_id = ?entry.process_tracker_id,
+                        "Giving up on queued outgoing webhook"
+                    ),
+                }
+            }
+        }
+
+        // An entry which could not be deferred is left pending, and is read again when the worker
+        // is restarted
+        acknowledge_entry(&redis_conn, &settings, &entry.entry_id).await
+    }
+    .await;
+
+    if let Err(error) = result {
+        logger::error!(?error, "Failed to process webhook delivery entry");
+    }
+}
+
+async fn deliver_entry(
+    state: &SessionState,
+    redis_conn: &RedisConnectionPool,
+    settings: &WebhookDeliveryQueueSettings,
+    entry: &WebhookDeliveryEntry,
+) -> CustomResult<DeliveryOutcome, errors::WebhooksFlowError> {
+    if let Some(remaining) = get_circuit_open_remaining(redis_conn, &entry.endpoint_key).await? {
+        metrics::WEBHOOK_DELIVERY_CIRCUIT_OPEN_SKIPPED_COUNT.add(
+            1,
+            router_env::metric_attributes!((super::MERCHANT_ID, entry.merchant_id.clone())),
+        );
+        // Without a retry task, the entry is held back until the circuit closes
+        return Ok(match entry.process_tracker_id {
+            Some(_) => DeliveryOutcome::LeftToRetryTask,
+            None => DeliveryOutcome::Deferred(DeferralReason::CircuitOpen, remaining),
+        });
+    }
+
+    let in_flight_key = get_in_flight_key(redis_conn, &entry.endpoint_key);
+    let slot_acquired: i64 = redis_conn
+        .evaluate_redis_script(
+            ACQUIRE_DELIVERY_SLOT_SCRIPT,
+            vec![in_flight_key.clone()],
+            vec![
+                settings.max_concurrent_deliveries_per_endpoint.to_string(),
+                DELIVERY_SLOT_EXPIRY_MILLISECONDS.to_string(),
+            ],
+        )
+        .await
+        .change_context(errors::WebhooksFlowError::WebhookDeliveryQueueOperationFailed)?;
+    if slot_acquired == 0 {
+        return Ok(DeliveryOutcome::Deferred(
+            DeferralReason::ConcurrencyLimit,
+            get_concurrency_limit_delay(settings),
+        ));
+    }
+
+    let outcome = deliver_entry_with_rate_limit(state, redis_conn, settings, entry).await;
+
+    redis_conn
+        .evaluate_redis_script::<_, i64>(
+            RELEASE_DELIVERY_SLOT_SCRIPT,
+            vec![in_flight_key],
+            Vec::<String>::new(),
+        )
+        .await
+        .change_context(errors::WebhooksFlowError::WebhookDeliveryQueueOperationFailed)?;
+
+    outcome
+}
+
+async fn deliver_entry_with_rate_limit(
+    state: &SessionState,
+    redis_conn: &RedisConnectionPool,
+    settings: &WebhookDeliveryQueueSettings,
+    entry: &WebhookDeliveryEntry,
+) -> CustomResult<DeliveryOutcome, errors::WebhooksFlowError> {
+    if !take_rate_limit_token(redis_conn, settings, &entry.endpoint_key).await? {
+        return Ok(DeliveryOutcome::Deferred(
+            DeferralReason::RateLimit,
+            get_rate_limit_delay(settings),
+        ));
+    }
+
+    let db = &*state.store;
+    let key_manager_state = &state.into();
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            &entry.merchant_id,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await
+        .change_context(errors::WebhooksFlowError::WebhookDeliveryDetailsNotFound)
+        .attach_printable("Failed to fetch merchant key store")?;
+    let event = db
+        .find_event_by_merchant_id_event_id(
+            key_manager_state,
+            &entry.merchant_id,
+            &entry.event_id,
+            &key_store,
+        )
+        .await
+        .change_context(errors::WebhooksFlowError::WebhookDeliveryDetailsNotFound)
+        .attach_printable("Failed to fetch event")?;
+
+    let process_tracker = match &entry.process_tracker_id {
+        Some(process_tracker_id) => db
+            .find_process_by_id(process_tracker_id)
+            .await
+            .change_context(errors::WebhooksFlowError::WebhookDeliveryDetailsNotFound)
+            .attach_printable("Failed to fetch outgoing webhook retry task")?,
+        None => None,
+    };
+    // The retry task has already delivered the event, or has given up on it
+    if process_tracker
+        .as_ref()
+        .is_some_and(|process| process.status == enums::ProcessTrackerStatus::Finish)
+    {
+        return Ok(DeliveryOutcome::LeftToRetryTask);
+    }
+
+    let profile_id = event
+        .business_profile_id
+        .clone()
+        .ok_or(errors::WebhooksFlowError::WebhookDeliveryDetailsNotFound)
+        .attach_printable("Event is not associated with a profile")?;
+    let business_profile = db
+        .find_business_profile_by_profile_id(key_manager_state, &key_store, &profile_id)
+        .await
+        .change_context(errors::WebhooksFlowError::WebhookDeliveryDetailsNotFound)
+        .attach_printable("Failed to fetch business profile")?;
+
+    let request_content: OutgoingWebhookRequestContent = event
+        .request
+        .as_ref()
+        .ok_or(errors::WebhooksFlowError::WebhookDeliveryDetailsNotFound)
+        .attach_printable("Event does not contain the webhook request content")?
+        .get_inner()
+        .peek()
+        .parse_struct("OutgoingWebhookRequestContent")
+        .change_context(errors::WebhooksFlowError::WebhookDeliveryDetailsNotFound)?;
+
+    let status_code = Box::pin(super::outgoing::trigger_webhook_and_raise_event(
+        state.clone(),
+        business_profile,
+        &key_store,
+        event,
+        request_content,
+        enums::WebhookDeliveryAttempt::InitialAttempt,
+        None,
+        process_tracker,
+    ))
+    .await;
+
+    record_delivery_result(
+        redis_conn,
+        settings,
+        &entry.merchant_id,
+        &entry.endpoint_key,
+        status_code,
+    )
+    .await?;
+
+    Ok(DeliveryOutcome::Delivered)
+}
+
+/// Checks the circuit breaker and the rate limit of the endpoint before an automatic retry of an
+/// outgoing webhook, so that retries are subject to the same limits as queued deliveries. Returns
+/// the reason and the time after which the retry should be attempted, if it cannot be attempted
+/// right away.
+#[instrument(skip_all)]
+pub(crate) async fn check_retry_delivery_gate(
+    state: &SessionState,
+    endpoint_key: &str,
+) -> CustomResult<Option<(DeferralReason, Duration)>, errors::WebhooksFlowError> {
+    let settings = &state.conf.webhooks.delivery_queue;
+    let redis_conn = get_redis_conn(state)?;
+
+    if let Some(remaining) = get_circuit_open_remaining(&redis_conn, endpoint_key).await? {
+        return Ok(Some((DeferralReason::CircuitOpen, remaining)));
+    }
+
+    if !take_rate_limit_token(&redis_conn, settings, endpoint_key).await? {
+        return Ok(Some((
+            DeferralReason::RateLimit,
+            get_rate_limit_delay(settings),
+        )));
+    }
+
+    Ok(None)
+}
+
+/// Records the result of an automatic retry of an outgoing webhook towards the circuit breaker of
+/// the endpoint.
+#[instrument(skip_all)]
+pub(crate) async fn record_retry_delivery_result(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    endpoint_key: &str,
+    status_code: Option<u16>,
+) -> CustomResult<(), errors::WebhooksFlowError> {
+    let redis_conn = get_redis_conn(state)?;
+    record_delivery_result(
+        &redis_conn,
+        &state.conf.webhooks.delivery_queue,
+        merchant_id,
+        endpoint_key,
+        status_code,
+    )
+    .await
+}
+
+/// Returns `true` if a token was taken from the rate limit bucket of the endpoint.
+async fn take_rate_limit_token(
+    redis_conn: &RedisConnectionPool,
+    settings: &WebhookDeliveryQueueSettings,
+    endpoint_key: &str,
+) -> CustomResult<bool, errors::WebhooksFlowError> {
+    let token_taken: i64 = redis_conn
+        .evaluate_redis_script(
+            TAKE_RATE_LIMIT_TOKEN_SCRIPT,
+            vec![redis_conn.add_prefix(&format!("WEBHOOK_DELIVERY_RATE_LIMIT_{endpoint_key}"))],
+            vec![
+                settings.rate_limit.bucket_size.to_string(),
+                settings.rate_limit.refill_rate.to_string(),
+                get_current_time_in_milliseconds().to_string(),
+            ],
+        )
+        .await
+        .change_context(errors::WebhooksFlowError::WebhookDeliveryQueueOperationFailed)?;
+
+    Ok(token_taken != 0)
+}
+
+/// Returns the time until the circuit of the endpoint closes, if it is open.
+async fn get_circuit_open_remaining(
+    redis_conn: &RedisConnectionPool,
+    endpoint_key: &str,
+) -> CustomResult<Option<Duration>, errors::WebhooksFlowError> {
+    let remaining_milliseconds: i64 = redis_conn
+        .evaluate_redis_script(
+            GET_TTL_SCRIPT,
+            vec![redis_conn.add_prefix(&get_circuit_open_key(endpoint_key))],
+            Vec::<String>::new(),
+        )
+        .await
+        .change_context(errors::WebhooksFlowError::WebhookDeliveryQueueOperationFailed)?;
+
+    Ok(u64::try_from(remaining_milliseconds)
+        .ok()
+        .map(Duration::from_millis))
+}
+
+/// Holds the entry back in the sorted set of deferred entries until the delay elapses, instead of
+/// adding it back to the stream right away where it would be picked up again immediately.
+async fn defer_entry(
+    redis_conn: &RedisConnectionPool,
+    settings: &WebhookDeliveryQueueSettings,
+    entry: &WebhookDeliveryEntry,
+    delay: Duration,
+) -> CustomResult<(), errors::WebhooksFlowError> {
+    let due_at = get_current_time_in_milliseconds()
+        .saturating_add(i128::try_from(delay.as_millis()).unwrap_or(i128::MAX));
+
+    redis_conn
+        .evaluate_redis_script::<_, i64>(
+            DEFER_ENTRY_SCRIPT,
+            vec![get_deferred_entries_key(redis_conn, settings)],
+            vec![due_at.to_string(), entry.get_deferred_member()?],
+        )
+        .await
+        .change_context(errors::WebhooksFlowError::WebhookDeliveryEnqueueFailed)
+        .map(|_| ())
+}
+
+/// Adds deferred entries which are due back to the delivery stream.
+async fn requeue_due_deferred_entries(
+    state: &SessionState,
+    settings: &WebhookDeliveryQueueSettings,
+) -> CustomResult<(), errors::WebhooksFlowError> {
+    let redis_conn = get_redis_conn(state)?;
+    let deferred_entries_key = get_deferred_entries_key(&redis_conn, settings);
+    let now = get_current_time_in_milliseconds();
+
+    let due_members: Vec<String> = redis_conn
+        .evaluate_redis_script(
+            LEASE_DUE_ENTRIES_SCRIPT,
+            vec![deferred_entries_key.clone()],
+            vec![
+                now.to_string(),
+                settings.max_in_flight_deliveries.to_string(),
+                now.saturating_add(i128::from(DEFERRED_ENTRY_LEASE_MILLISECONDS))
+                    .to_string(),
+            ],
+        )
+        .await
+        .change_context(errors::WebhooksFlowError::WebhookDeliveryQueueOperationFailed)?;
+
+    for member in due_members {
+        match serde_json::from_str::<Vec<(String, String)>>(&member) {
+            Ok(fields) => redis_conn
+                .stream_append_entry(
+                    &settings.stream_name.as_str().into(),
+                    &RedisEntryId::AutoGeneratedID,
+                    fields,
+                )
+                .await
+                .change_context(errors::WebhooksFlowError::WebhookDeliveryEnqueueFailed)?,
+            Err(error) => {
+                logger::error!(?error, "Discarding invalid deferred webhook delivery entry")
+            }
+        }
+
+        redis_conn
+            .evaluate_redis_script::<_, i64>(
+                REMOVE_DEFERRED_ENTRY_SCRIPT,
+                vec![deferred_entries_key.clone()],
+                vec![member],
+            )
+            .await
+            .change_context(errors::WebhooksFlowError::WebhookDeliveryQueueOperationFailed)?;
+    }
+
+    Ok(())
+}
+
+/// Server errors and deliveries which did not receive a response count towards opening the
+/// circuit of the endpoint, while any other response resets the failure count.
+async fn record_delivery_result(
+    redis_conn: &RedisConnectionPool,
+    settings: &WebhookDeliveryQueueSettings,
+    merchant_id: &id_type::MerchantId,
+    endpoint_key: &str,
+    status_code: Option<u16>,
+) -> CustomResult<(), errors::WebhooksFlowError> {
+    let failures_key = get_circuit_failures_key(endpoint_key);
+
+    if status_code.is_some_and(|status_code| status_code < 500) {
+        redis_conn
+            .delete_key(&failures_key.as_str().into())
+            .await
+            .change_context(errors::WebhooksFlowError::WebhookDeliveryQueueOperationFailed)?;
+        return Ok(());
+    }
+
+    let failures = redis_conn
+        .increment_fields_in_hash(&failures_key.as_str().into(), &[("count", 1)])
+        .await
+        .change_context(errors::WebhooksFlowError::WebhookDeliveryQueueOperationFailed)?
+        .first()
+        .copied()
+        .unwrap_or_default();
+
+    // The failure window starts at the first failure
+    if failures == 1 {
+        redis_conn
+            .set_expiry(
+                &failures_key.as_str().into(),
+                settings.circuit_breaker.failure_window,
+            )
+            .await
+            .change_context(errors::WebhooksFlowError::WebhookDeliveryQueueOperationFailed)?;
+    }
+
+    if failures >= settings.circuit_breaker.failure_threshold {
+        redis_conn
+            .set_key_with_expiry(
+                &get_circuit_open_key(endpoint_key).as_str().into(),
+                "open",
+                settings.circuit_breaker.open_duration,
+            )
+            .await
+            .change_context(errors::WebhooksFlowError::WebhookDeliveryQueueOperationFailed)?;
+        redis_conn
+            .delete_key(&failures_key.as_str().into())
+            .await
+            .change_context(errors::WebhooksFlowError::WebhookDeliveryQueueOperationFailed)?;
+
+        logger::warn!(
+            endpoint = %endpoint_key,
+            failures,
+            "Opened circuit for webhook endpoint"
+        );
+        metrics::WEBHOOK_ENDPOINT_CIRCUIT_OPENED_COUNT.add(
+            1,
+            router_env::metric_attributes!((super::MERCHANT_ID, merchant_id.clone())),
+        );
+    }
+
+    Ok(())
+}
+
+async fn acknowledge_entry(
+    redis_conn: &RedisConnectionPool,
+    settings: &WebhookDeliveryQueueSettings,
+    entry_id: &str,
+) -> CustomResult<(), errors::WebhooksFlowError> {
+    redis_conn
+        .stream_acknowledge_entries(
+            &settings.stream_name.as_str().into(),
+            &settings.consumer_group,
+            entry_id,
+        )
+        .await
+        .change_context(errors::WebhooksFlowError::WebhookDeliveryQueueOperationFailed)
+        .map(|_| ())
+}
+
+fn get_redis_conn(
+    state: &SessionState,
+) -> CustomResult<Arc<RedisConnectionPool>, errors::WebhooksFlowError> {
+    state
+        .store
+        .get_redis_conn()
+        .change_context(errors::WebhooksFlowError::WebhookDeliveryQueueOperationFailed)
+        .attach_printable("Failed to get redis connection")
+}
+
+/// Keys passed to scripts are not prefixed with the tenant by the connection pool.
+fn get_in_flight_key(redis_conn: &RedisConnectionPool, endpoint_key: &str) -> String {
+    redis_conn.add_prefix(&format!("WEBHOOK_DELIVERY_IN_FLIGHT_{endpoint_key}"))
+}
+
+fn get_deferred_entries_key(
+    redis_conn: &RedisConnectionPool,
+    settings: &WebhookDeliveryQueueSettings,
+) -> String {
+    redis_conn.add_prefix(&format!("{}_DEFERRED", settings.stream_name))
+}
+
+fn get_current_time_in_milliseconds() -> i128 {
+    time::OffsetDateTime::now_utc().unix_timestamp_nanos() / 1_000_000
+}
+
+fn get_circuit_failures_key(endpoint_key: &str) -> String {
+    format!("WEBHOOK_CIRCUIT_FAILURES_{endpoint_key}")
+}
+
+fn get_circuit_open_key(endpoint_key: &str) -> String {
+    format!("WEBHOOK_CIRCUIT_OPEN_{endpoint_key}")
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::unwrap_used)]
+
+    use super::*;
+
+    fn get_stream_entry_fields(
+        process_tracker_id: Option<&str>,
+    ) -> HashMap<String, Option<String>> {
+        HashMap::from([
+            (
+                MERCHANT_ID_FIELD.to_string(),
+                Some("merchant_1".to_string()),
+            ),
+            (EVENT_ID_FIELD.to_string(), Some("evt_1".to_string())),
+            (
+                ENDPOINT_KEY_FIELD.to_string(),
+                Some("merchant_1_whe_1".to_string()),
+            ),
+            (
+                PROCESS_TRACKER_ID_FIELD.to_string(),
+                process_tracker_id.map(ToOwned::to_owned),
+            ),
+        ])
+    }
+
+    #[test]
+    fn test_from_stream_entry() {
+        let entry = WebhookDeliveryEntry::from_stream_entry(
+            "1-0".to_string(),
+            get_stream_entry_fields(Some("pt_1")),
+        )
+        .unwrap();
+
+        assert_eq!(entry.merchant_id.get_string_repr(), "merchant_1");
+        assert_eq!(entry.event_id, "evt_1");
+        assert_eq!(entry.endpoint_key, "merchant_1_whe_1");
+        assert_eq!(entry.process_tracker_id.as_deref(), Some("pt_1"));
+
+        let entry = WebhookDeliveryEntry::from_stream_entry(
+            "1-0".to_string(),
+            get_stream_entry_fields(None),
+        )
+        .unwrap();
+        assert_eq!(entry.process_tracker_id, None);
+        assert_eq!(entry.fields.len(), 3);
+    }
+
+    #[test]
+    fn test_from_stream_entry_with_missing_field() {
+        let mut fields = get_stream_entry_fields(None);
+        fields.remove(EVENT_ID_FIELD);
+
+        assert!(WebhookDeliveryEntry::from_stream_entry("1-0".to_string(), fields).is_err());
+    }
+
+    #[test]
+    fn test_deferred_member_round_trip() {
+        let entry = WebhookDeliveryEntry::from_stream_entry(
+            "1-0".to_string(),
+            get_stream_entry_fields(Some("pt_1")),
+        )
+        .unwrap();
+        let member = entry.get_deferred_member().unwrap();
+
+        // The member does not depend on the order of the fields, so that an entry deferred more
+        // than once is held only once
+        let mut reordered_entry = entry.clone();
+        reordered_entry.fields.reverse();
+        assert_eq!(reordered_entry.get_deferred_member().unwrap(), member);
+
+        let fields: Vec<(String, String)> = serde_json::from_str(&member).unwrap();
+        let requeued_entry = WebhookDeliveryEntry::from_stream_entry(
+            "2-0".to_string(),
+            fields
+                .into_iter()
+                .map(|(field, value)| (field, Some(value)))
+                .collect(),
+        )
+        .unwrap();
+        assert_eq!(requeued_entry.event_id, entry.event_id);
+        assert_eq!(requeued_entry.process_tracker_id, entry.process_tracker_id);
+    }
+
+    #[test]
+    fn test_failed_delivery_is_deferred_with_attempt_count() {
+        let entry = WebhookDeliveryEntry::from_stream_entry(
+            "1-0".to_string(),
+            get_stream_entry_fields(None),
+        )
+        .unwrap();
+        assert_eq!(entry.failed_attempts, 0);
+        assert_eq!(
+            get_failed_delivery_retry_delay(entry.failed_attempts),
+            Some(Duration::from_millis(30_000))
+        );
+
+        let failed_entry = entry.with_failed_attempt().with_failed_attempt();
+        let fields: Vec<(String, String)> =
+            serde_json::from_str(&failed_entry.get_deferred_member().unwrap()).unwrap();
+        let requeued_entry = WebhookDeliveryEntry::from_stream_entry(
+            "2-0".to_string(),
+            fields
+                .into_iter()
+                .map(|(field, value)| (field, Some(value)))
+                .collect(),
+        )
+        .unwrap();
+        assert_eq!(requeued_entry.failed_attempts, 2);
+        assert_eq!(requeued_entry.fields.len(), 4);
+        assert_eq!(
+            get_failed_delivery_retry_delay(requeued_entry.failed_attempts),
+            Some(Duration::from_millis(90_000))
+        );
+
+        assert_eq!(
+            get_failed_delivery_retry_delay(MAX_FAILED_DELIVERY_ATTEMPTS),
+            None
+        );
+    }
+
+    #[test]
+    fn test_deferral_delays() {
+        let mut settings = WebhookDeliveryQueueSettings::default();
+
+        settings.rate_limit.refill_rate = 10;
+        assert_eq!(get_rate_limit_delay(&settings), Duration::from_millis(100));
+
+        settings.rate_limit.refill_rate = 3;
+        assert_eq!(get_rate_limit_delay(&settings), Duration::from_millis(334));
+
+        settings.loop_interval = 250;
+        assert_eq!(
+            get_concurrency_limit_delay(&settings),
+            Duration::from_millis(250)
+        );
+    }
+}
@@ -60,24 +60,109 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
     content: api::OutgoingWebhookContent,
     primary_object_created_at: Option<time::PrimitiveDateTime>,
 ) -> CustomResult<(), errors::ApiErrorResponse> {
-    let delivery_attempt = enums::WebhookDeliveryAttempt::InitialAttempt;
-    let idempotent_event_id =
-        utils::get_idempotent_event_id(&primary_object_id, event_type, delivery_attempt);
-    let webhook_url_result = get_webhook_url_from_business_profile(&business_profile);
-
-    if !state.conf.webhooks.outgoing_enabled
-        || webhook_url_result.is_err()
-        || webhook_url_result.as_ref().is_ok_and(String::is_empty)
-    {
+    if !state.conf.webhooks.outgoing_enabled {
         logger::debug!(
             business_profile_id=?business_profile.get_id(),
-            %idempotent_event_id,
-            "Outgoing webhooks are disabled in application configuration, or merchant webhook URL \
-             could not be obtained; skipping outgoing webhooks for event"
+            %primary_object_id,
+            "Outgoing webhooks are disabled in application configuration; skipping outgoing \
+             webhooks for event"
         );
         return Ok(());
     }
 
+    let webhook_url_result = get_webhook_url_from_business_profile(&business_profile);
+    let is_profile_webhook_url_configured = webhook_url_result
+        .as_ref()
+        .is_ok_and(|webhook_url| !webhook_url.is_empty());
+    if !is_profile_webhook_url_configured {
+        logger::debug!(
+            business_profile_id=?business_profile.get_id(),
+            %primary_object_id,
+            "Merchant webhook URL could not be obtained; skipping delivery to profile webhook URL"
+        );
+    }
+
+    let webhook_endpoints = state
+        .store
+        .list_webhook_endpoints_by_merchant_id_profile_id(
+            &business_profile.merchant_id,
+            business_profile.get_id(),
+        )
+        .await
+        .inspect_err(|error| {
+            logger::error!(?error, "Failed to list webhook endpoints for profile");
+        })
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|webhook_endpoint| webhook_endpoint.is_subscribed_to(event_type))
+        .map(Some);
+
+    // `None` denotes delivery to the webhook URL configured on the business profile
+    let delivery_targets = is_profile_webhook_url_configured
+        .then_some(None)
+        .into_iter()
+        .chain(webhook_endpoints);
+
+    // The webhook is delivered to every target, and the error of the first target which failed is
+    // returned once all targets are attempted
+    let mut result = Ok(());
+    for webhook_endpoint in delivery_targets {
+        let webhook_endpoint_id = webhook_endpoint
+            .as_ref()
+            .map(|webhook_endpoint| webhook_endpoint.endpoint_id.clone());
+        if let Err(error) = Box::pin(create_event_and_trigger_outgoing_webhook_for_endpoint(
+            state.clone(),
+            merchant_context.clone(),
+            business_profile.clone(),
+            webhook_endpoint,
+            event_type,
+            event_class,
+            primary_object_id.clone(),
+            primary_object_type,
+            content.clone(),
+            primary_object_created_at,
+        ))
+        .await
+        {
+            logger::error!(
+                ?error,
+                ?webhook_endpoint_id,
+                "Failed to trigger outgoing webhook for event"
+            );
+            if result.is_ok() {
+                result = Err(error);
+            }
+        }
+    }
+
+    result
+}
+
+#[allow(clippy::too_many_arguments)]
+#[instrument(skip_all)]
+async fn create_event_and_trigger_outgoing_webhook_for_endpoint(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    business_profile: domain::Profile,
+    webhook_endpoint: Option<storage::WebhookEndpoint>,
+    event_type: enums::EventType,
+    event_class: enums::EventClass,
+    primary_object_id: String,
+    primary_object_type: enums::EventObjectType,
+    content: api::OutgoingWebhookContent,
+    primary_object_created_at: Option<time::PrimitiveDateTime>,
+) -> CustomResult<(), errors::ApiErrorResponse> {
+    let delivery_attempt = enums::WebhookDeliveryAttempt::InitialAttempt;
+    let idempotent_event_id =
+        utils::get_idempotent_event_id(&primary_object_id, event_type, delivery_attempt);
+    let idempotent_event_id = match &webhook_endpoint {
+        Some(webhook_endpoint) => utils::get_idempotent_event_id_for_endpoint(
+            &idempotent_event_id,
+            &webhook_endpoint.endpoint_id,
+        ),
+        None => idempotent_event_id,
+    };
+
     let event_id = utils::generate_event_id();
     let merchant_id = business_profile.merchant_id.clone();
     let now = common_utils::date_time::now();
@@ -90,10 +175,14 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
         timestamp: now,
     };
 
-    let request_content =
-        get_outgoing_webhook_request(&merchant_context, outgoing_webhook, &business_profile)
-            .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
-            .attach_printable("Failed to construct outgoing webhook request content")?;
+    let request_content = get_outgoing_webhook_request(
+        &merchant_context,
+        outgoing_webhook,
+        &business_profile,
+        webhook_endpoint.as_ref(),
+    )
+    .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
+    .attach_printable("Failed to construct outgoing webhook request content")?;
 
     let event_metadata = storage::EventMetadata::foreign_from(&content);
     let key_manager_state = &(&state).into();
@@ -142,6 +231,7 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
         delivery_attempt: Some(delivery_attempt),
         metadata: Some(event_metadata),
         is_overall_delivery_successful: Some(false),
+        webhook_endpoint_id: webhook_endpoint.map(|webhook_endpoint| webhook_endpoint.endpoint_id),
     };
 
     let lock_value = utils::perform_redis_lock(
@@ -220,6 +310,23 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
     })
     .ok();
 
+    if state.conf.webhooks.delivery_queue.enabled {
+        match super::delivery_queue::enqueue_outgoing_webhook_delivery(
+            &state,
+            &merchant_id,
+            &event,
+            process_tracker.as_ref(),
+        )
+        .await
+        {
+            Ok(()) => return Ok(()),
+            Err(error) => logger::error!(
+                ?error,
+                "Failed to add outgoing webhook to the delivery queue, delivering it inline"
+            ),
+        }
+    }
+
     let cloned_key_store = merchant_context.get_merchant_key_store().clone();
     // Using a tokio spawn here and not arbiter because not all caller of this function
     // may have an actix arbiter
@@ -254,7 +361,7 @@ pub(crate) async fn trigger_webhook_and_raise_event(
     delivery_attempt: enums::WebhookDeliveryAttempt,
     content: Option<api::OutgoingWebhookContent>,
     process_tracker: Option<storage::ProcessTracker>,
-) {
+) -> Option<u16> {
     logger::debug!(
         event_id=%event.event_id,
         idempotent_event_id=?event.idempotent_event_id,
@@ -274,7 +381,7 @@ pub(crate) async fn trigger_webhook_and_raise_event(
     )
     .await;
 
-    let _ = raise_webhooks_analytics_event(
+    raise_webhooks_analytics_event(
         state,
         trigger_webhook_result,
         content,
@@ -282,7 +389,7 @@ pub(crate) async fn trigger_webhook_and_raise_event(
         event,
         merchant_key_store,
     )
-    .await;
+    .await
 }
 
 async fn trigger_webhook_to_merchant(
@@ -294,11 +401,11 @@ async fn trigger_webhook_to_merchant(
     delivery_attempt: enums::WebhookDeliveryAttempt,
     process_tracker: Option<storage::ProcessTracker>,
 ) -> CustomResult<(), errors::WebhooksFlowError> {
-    let webhook_url = match (
-        get_webhook_url_from_business_profile(&business_profile),
+    let delivery_details = match (
+        get_webhook_delivery_details(&state, &business_profile, &event).await,
         process_tracker.clone(),
     ) {
-        (Ok(webhook_url), _) => Ok(webhook_url),
+        (Ok(delivery_details), _) => Ok(delivery_details),
         (Err(error), Some(process_tracker)) => {
             if !error
                 .current_context()
@@ -321,14 +428,28 @@ async fn trigger_webhook_to_merchant(
 
     let event_id = event.event_id;
 
-    let headers = request_content
+    // The timestamped signature is computed for every delivery attempt, so that retried
+    // deliveries carry a fresh timestamp
+    let timestamped_signature = types::get_timestamped_webhook_signature(
+        &delivery_details.signing_keys,
+        request_content.body.peek(),
+        common_utils::date_time::now_unix_timestamp(),
+    )?;
+
+    let mut headers: Vec<_> = request_content
         .headers
         .into_iter()
         .map(|(name, value)| (name, value.into_masked()))
         .collect();
+    if let Some(signature) = timestamped_signature {
+        headers.push((
+            crate::headers::X_WEBHOOK_SIGNATURE_V2.to_string(),
+            signature.into_masked(),
+        ));
+    }
     let request = services::RequestBuilder::new()
         .method(services::Method::Post)
-        .url(&webhook_url)
+        .url(&delivery_details.url)
         .attach_default_headers()
         .headers(headers)
         .set_body(RequestContent::RawBytes(
@@ -511,7 +632,7 @@ async fn raise_webhooks_analytics_event(
     merchant_id: common_utils::id_type::MerchantId,
     event: domain::Event,
     merchant_key_store: &domain::MerchantKeyStore,
-) {
+) -> Option<u16> {
     let key_manager_state: &KeyManagerState = &(&state).into();
     let event_id = event.event_id;
 
@@ -577,6 +698,8 @@ async fn raise_webhooks_analytics_event(
         event.delivery_attempt,
     );
     state.event_handler().log_event(&webhook_event);
+
+    status_code
 }
 
 pub(crate) async fn add_outgoing_webhook_retry_task_to_process_tracker(
@@ -655,15 +778,59 @@ fn get_webhook_url_from_business_profile(
         .map(ExposeInterface::expose)
 }
 
+struct WebhookDeliveryDetails {
+    url: String,
+    signing_keys: Vec<Secret<String>>,
+}
+
+/// Obtain the URL to deliver the event to and the keys to sign the webhook with. These are
+/// obtained from the webhook endpoint the event is associated with, or from the business profile
+/// otherwise.
+async fn get_webhook_delivery_details(
+    state: &SessionState,
+    business_profile: &domain::Profile,
+    event: &domain::Event,
+) -> CustomResult<WebhookDeliveryDetails, errors::WebhooksFlowError> {
+    let Some(webhook_endpoint_id) = event.webhook_endpoint_id.as_deref() else {
+        let url = get_webhook_url_from_business_profile(business_profile)?;
+        let signing_keys = business_profile
+            .get_active_payment_response_hash_keys()
+            .into_iter()
+            .map(|key| Secret::new(key.to_owned()))
+            .collect();
+        return Ok(WebhookDeliveryDetails { url, signing_keys });
+    };
+
+    let webhook_endpoint = state
+        .store
+        .find_webhook_endpoint_by_merchant_id_endpoint_id(
+            &business_profile.merchant_id,
+            webhook_endpoint_id,
+        )
+        .await
+        .change_context(errors::WebhooksFlowError::WebhookEndpointNotFound)?;
+
+    if !webhook_endpoint.is_active {
+        return Err(report!(errors::WebhooksFlowError::WebhookEndpointDisabled));
+    }
+
+    Ok(WebhookDeliveryDetails {
+        url: webhook_endpoint.url.expose(),
+        signing_keys: webhook_endpoint.get_active_secrets(),
+    })
+}
+
 pub(crate) fn get_outgoing_webhook_request(
     merchant_context: &domain::MerchantContext,
     outgoing_webhook: api::OutgoingWebhook,
     business_profile: &domain::Profile,
+    webhook_endpoint: Option<&storage::WebhookEndpoint>,
 ) -> CustomResult<OutgoingWebhookRequestContent, errors::WebhooksFlowError> {
     #[inline]
     fn get_outgoing_webhook_request_inner<WebhookType: types::OutgoingWebhookType>(
         outgoing_webhook: api::OutgoingWebhook,
         business_profile: &domain::Profile,
+        webhook_endpoint: Option<&storage::WebhookEndpoint>,
     ) -> CustomResult<OutgoingWebhookRequestContent, errors::WebhooksFlowError> {
         let mut headers = vec![
             (
@@ -677,7 +844,20 @@ pub(crate) fn get_outgoing_webhook_request(
         ];
 
         let transformed_outgoing_webhook = WebhookType::from(outgoing_webhook);
-        let payment_response_hash_key = business_profile.payment_response_hash_key.clone();
+        // Webhooks delivered to a webhook endpoint are signed using the secret of the endpoint.
+        // This signature is kept on the previous secret during a rotation window, since it cannot
+        // carry signatures from both the secrets.
+        let payment_response_hash_key = match webhook_endpoint {
+            Some(webhook_endpoint) => Some(
+                webhook_endpoint
+                    .get_single_signature_secret()
+                    .clone()
+                    .expose(),
+            ),
+            None => business_profile
+                .get_single_signature_payment_response_hash_key()
+                .map(ToOwned::to_owned),
+        };
         let custom_headers = business_profile
             .outgoing_webhook_custom_http_headers
             .clone()
@@ -717,12 +897,17 @@ pub(crate) fn get_outgoing_webhook_request(
         .get_compatible_connector()
     {
         #[cfg(feature = "stripe")]
-        Some(api_models::enums::Connector::Stripe) => get_outgoing_webhook_request_inner::<
-            stripe_webhooks::StripeOutgoingWebhook,
-        >(outgoing_webhook, business_profile),
+        Some(api_models::enums::Connector::Stripe) => {
+            get_outgoing_webhook_request_inner::<stripe_webhooks::StripeOutgoingWebhook>(
+                outgoing_webhook,
+                business_profile,
+                webhook_endpoint,
+            )
+        }
         _ => get_outgoing_webhook_request_inner::<webhooks::OutgoingWebhook>(
             outgoing_webhook,
             business_profile,
+            webhook_endpoint,
         ),
     }
 }
@@ -1026,6 +1211,16 @@ impl ForeignFrom<&api::OutgoingWebhookContent> for storage::EventMetadata {
             webhooks::OutgoingWebhookContent::PayoutDetails(payout_response) => Self::Payout {
                 payout_id: payout_response.payout_id.clone(),
             },
+            webhooks::OutgoingWebhookContent::PaymentMethodDetails(payment_method_response) => {
+                Self::PaymentMethod {
+                    payment_method_id: payment_method_response.payment_method_id.clone(),
+                }
+            }
+            webhooks::OutgoingWebhookContent::SubscriptionDetails(subscription_response) => {
+                Self::Subscription {
+                    subscription_id: subscription_response.subscription_id.clone(),
+                }
+            }
         }
     }
 }
@@ -1070,5 +1265,17 @@ fn get_outgoing_webhook_event_content_from_event_metadata(
             mandate_id,
             content: serde_json::Value::Null,
         },
+        diesel_models::EventMetadata::PaymentMethod { payment_method_id } => {
+            OutgoingWebhookEventContent::PaymentMethod {
+                payment_method_id,
+                content: serde_json::Value::Null,
+            }
+        }
+        diesel_models::EventMetadata::Subscription { subscription_id } => {
+            OutgoingWebhookEventContent::Subscription {
+                subscription_id,
+                content: serde_json::Value::Null,
+            }
+        }
     })
 }
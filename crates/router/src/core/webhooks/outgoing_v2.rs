@@ -115,6 +115,7 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
         delivery_attempt: Some(delivery_attempt),
         metadata: Some(event_metadata),
         is_overall_delivery_successful: Some(false),
+        webhook_endpoint_id: None,
     };
 
     let event_insert_result = state
@@ -662,6 +663,16 @@ impl ForeignFrom<storage::EventMetadata> for outgoing_webhook_logs::OutgoingWebh
                 mandate_id,
                 content: serde_json::Value::Null,
             },
+            diesel_models::EventMetadata::PaymentMethod { payment_method_id } => {
+                Self::PaymentMethod {
+                    payment_method_id,
+                    content: serde_json::Value::Null,
+                }
+            }
+            diesel_models::EventMetadata::Subscription { subscription_id } => Self::Subscription {
+                subscription_id,
+                content: serde_json::Value::Null,
+            },
         }
     }
 }
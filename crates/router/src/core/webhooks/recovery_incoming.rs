@@ -786,6 +786,7 @@ impl RevenueRecoveryAttempt {
                 profile_id,
                 payment_attempt_id,
                 revenue_recovery_retry,
+                retry_decisions: Vec::new(),
             };
 
         let tag = ["PCR"];
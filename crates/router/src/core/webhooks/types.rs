@@ -1,7 +1,9 @@
+use std::collections::HashSet;
+
 use api_models::{webhook_events, webhooks};
 use common_utils::{crypto::SignMessage, ext_traits::Encode};
 use error_stack::ResultExt;
-use masking::Secret;
+use masking::{PeekInterface, Secret};
 use serde::Serialize;
 
 use crate::{
@@ -69,6 +71,38 @@ impl OutgoingWebhookType for webhooks::OutgoingWebhook {
     }
 }
 
+/// Compute the versioned, timestamped signature of an outgoing webhook.
+///
+/// The signed payload is `{timestamp}.{payload}`, signed using HMAC-SHA512. The signature contains
+/// one `v2` entry per signing key, so that merchants can verify webhooks using either the current
+/// or the previous key during a key rotation window, and looks like `t={timestamp},v2={signature}`.
+pub(crate) fn get_timestamped_webhook_signature(
+    signing_keys: &[Secret<String>],
+    payload: &str,
+    timestamp: i64,
+) -> errors::CustomResult<Option<String>, errors::WebhooksFlowError> {
+    if signing_keys.is_empty() {
+        return Ok(None);
+    }
+
+    let signature_payload = format!("{timestamp}.{payload}");
+    let signatures = signing_keys
+        .iter()
+        .map(|key| {
+            common_utils::crypto::HmacSha512::sign_message(
+                &common_utils::crypto::HmacSha512,
+                key.peek().as_bytes(),
+                signature_payload.as_bytes(),
+            )
+            .map(|signature| format!("v2={}", hex::encode(signature)))
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .change_context(errors::WebhooksFlowError::OutgoingWebhookSigningFailed)
+        .attach_printable("Failed to sign the message")?;
+
+    Ok(Some(format!("t={timestamp},{}", signatures.join(","))))
+}
+
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 pub(crate) struct OutgoingWebhookTrackingData {
     pub(crate) merchant_id: common_utils::id_type::MerchantId,
@@ -80,6 +114,25 @@ pub(crate) struct OutgoingWebhookTrackingData {
     pub(crate) initial_attempt_id: Option<String>,
 }
 
+/// Tracking data of a webhook event replay job, which also records the progress of the job.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub(crate) struct WebhookEventReplayTrackingData {
+    pub(crate) replay_id: String,
+    pub(crate) merchant_id: common_utils::id_type::MerchantId,
+    pub(crate) profile_id: common_utils::id_type::ProfileId,
+    pub(crate) created_after: time::PrimitiveDateTime,
+    pub(crate) created_before: time::PrimitiveDateTime,
+    pub(crate) event_types: HashSet<enums::EventType>,
+    pub(crate) is_delivered: Option<bool>,
+    pub(crate) batch_size: i64,
+    pub(crate) total_count: i64,
+    /// Number of events processed so far, used as the offset for fetching the next batch
+    pub(crate) processed_count: i64,
+    pub(crate) replayed_count: i64,
+    pub(crate) skipped_count: i64,
+    pub(crate) failed_count: i64,
+}
+
 pub struct WebhookResponse {
     pub response: reqwest::Response,
 }
@@ -126,3 +179,70 @@ impl WebhookResponse {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn sign(key: &str, message: &str) -> String {
+        hex::encode(
+            common_utils::crypto::HmacSha512::sign_message(
+                &common_utils::crypto::HmacSha512,
+                key.as_bytes(),
+                message.as_bytes(),
+            )
+            .unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_timestamped_webhook_signature_without_signing_keys() {
+        let signature = get_timestamped_webhook_signature(&[], r#"{"id":1}"#, 1700000000).unwrap();
+
+        assert_eq!(signature, None);
+    }
+
+    #[test]
+    fn test_timestamped_webhook_signature_signs_timestamp_and_payload() {
+        let payload = r#"{"id":1}"#;
+        let signature = get_timestamped_webhook_signature(
+            &[Secret::new("current_key".to_string())],
+            payload,
+            1700000000,
+        )
+        .unwrap();
+
+        assert_eq!(
+            signature,
+            Some(format!(
+                "t=1700000000,v2={}",
+                sign("current_key", r#"1700000000.{"id":1}"#)
+            ))
+        );
+    }
+
+    #[test]
+    fn test_timestamped_webhook_signature_with_previous_key() {
+        let payload = r#"{"id":1}"#;
+        let signature = get_timestamped_webhook_signature(
+            &[
+                Secret::new("current_key".to_string()),
+                Secret::new("previous_key".to_string()),
+            ],
+            payload,
+            1700000000,
+        )
+        .unwrap();
+
+        // One entry per signing key, with the current key first
+        assert_eq!(
+            signature,
+            Some(format!(
+                "t=1700000000,v2={},v2={}",
+                sign("current_key", r#"1700000000.{"id":1}"#),
+                sign("previous_key", r#"1700000000.{"id":1}"#)
+            ))
+        );
+    }
+}
@@ -157,6 +157,15 @@ pub(crate) fn get_idempotent_event_id(
     }
 }
 
+/// Events delivered to the webhook endpoints of a profile are made idempotent per endpoint.
+#[inline]
+pub(crate) fn get_idempotent_event_id_for_endpoint(
+    idempotent_event_id: &str,
+    endpoint_id: &str,
+) -> String {
+    format!("{idempotent_event_id}_{endpoint_id}")
+}
+
 #[inline]
 pub(crate) fn generate_event_id() -> String {
     common_utils::generate_time_ordered_id("evt")
@@ -301,3 +310,53 @@ where
             .attach_printable("Error while deleting redis key"),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::storage::enums::{EventType, WebhookDeliveryAttempt};
+
+    /// Length of `events.idempotent_event_id`
+    const IDEMPOTENT_EVENT_ID_MAX_LENGTH: usize = 255;
+
+    #[test]
+    fn test_idempotent_event_id_for_endpoint_fits_column() {
+        // Object and endpoint ids are stored as `VARCHAR(64)`
+        let primary_object_id = "p".repeat(64);
+        let endpoint_id = "w".repeat(64);
+
+        for delivery_attempt in [
+            WebhookDeliveryAttempt::InitialAttempt,
+            WebhookDeliveryAttempt::AutomaticRetry,
+            WebhookDeliveryAttempt::ManualRetry,
+        ] {
+            let idempotent_event_id = get_idempotent_event_id(
+                &primary_object_id,
+                EventType::PaymentAuthorized,
+                delivery_attempt,
+            );
+            let idempotent_event_id =
+                get_idempotent_event_id_for_endpoint(&idempotent_event_id, &endpoint_id);
+
+            assert!(
+                idempotent_event_id.len() <= IDEMPOTENT_EVENT_ID_MAX_LENGTH,
+                "{delivery_attempt:?}: {} characters",
+                idempotent_event_id.len()
+            );
+        }
+    }
+
+    #[test]
+    fn test_idempotent_event_id_for_endpoint() {
+        let idempotent_event_id = get_idempotent_event_id(
+            "pay_abcdefghijklmnopqrst",
+            EventType::PaymentSucceeded,
+            WebhookDeliveryAttempt::InitialAttempt,
+        );
+
+        assert_eq!(
+            get_idempotent_event_id_for_endpoint(&idempotent_event_id, "whe_abcdefghijklmnopqrst"),
+            "pay_abcdefghijklmnopqrst_payment_succeeded_whe_abcdefghijklmnopqrst"
+        );
+    }
+}
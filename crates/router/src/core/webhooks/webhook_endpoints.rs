@@ -0,0 +1,303 @@
+use api_models::webhook_endpoints as webhook_endpoint_api;
+use common_utils::{crypto::generate_cryptographically_secure_random_string, id_type};
+use error_stack::ResultExt;
+use masking::Secret;
+use router_env::{instrument, tracing};
+
+use crate::{
+    consts,
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    routes::SessionState,
+    services::ApplicationResponse,
+    types::{domain, storage, transformers::ForeignFrom},
+};
+
+const WEBHOOK_ENDPOINT_ID_PREFIX: &str = "whe";
+const WEBHOOK_ENDPOINT_SECRET_LENGTH: usize = 64;
+const WEBHOOK_ENDPOINTS_PER_PROFILE_MAX_LIMIT: usize = 16;
+
+#[instrument(skip(state, key_store))]
+pub async fn create_webhook_endpoint(
+    state: SessionState,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+    request: webhook_endpoint_api::WebhookEndpointCreateRequest,
+) -> RouterResponse<webhook_endpoint_api::WebhookEndpointResponse> {
+    let db = state.store.as_ref();
+    let merchant_id = &key_store.merchant_id;
+    validate_profile(&state, &key_store, &profile_id).await?;
+    validate_enabled_events(&request.enabled_events)?;
+
+    let existing_endpoints = db
+        .list_webhook_endpoints_by_merchant_id_profile_id(merchant_id, &profile_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list webhook endpoints for profile")?;
+    if existing_endpoints.len() >= WEBHOOK_ENDPOINTS_PER_PROFILE_MAX_LIMIT {
+        return Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!(
+                "A profile can have at most {WEBHOOK_ENDPOINTS_PER_PROFILE_MAX_LIMIT} webhook endpoints"
+            ),
+        }
+        .into());
+    }
+
+    let now = common_utils::date_time::now();
+    let new_endpoint = storage::WebhookEndpointNew {
+        endpoint_id: common_utils::generate_id_with_default_len(WEBHOOK_ENDPOINT_ID_PREFIX),
+        merchant_id: merchant_id.to_owned(),
+        profile_id,
+        url: Secret::new(request.url.to_string()),
+        secret: Secret::new(generate_cryptographically_secure_random_string(
+            WEBHOOK_ENDPOINT_SECRET_LENGTH,
+        )),
+        enabled_events: request.enabled_events,
+        is_active: request.is_active.unwrap_or(true),
+        description: request.description,
+        created_at: now,
+        last_modified_at: now,
+    };
+
+    let endpoint = db
+        .insert_webhook_endpoint(new_endpoint)
+        .await
+        .to_duplicate_response(errors::ApiErrorResponse::GenericDuplicateError {
+            message: "Webhook endpoint with the given id already exists".to_string(),
+        })?;
+
+    // The secret is returned only once, when the endpoint is created
+    let secret = endpoint.secret.clone();
+    Ok(ApplicationResponse::Json(
+        webhook_endpoint_api::WebhookEndpointResponse {
+            secret: Some(secret),
+            ..webhook_endpoint_api::WebhookEndpointResponse::foreign_from(endpoint)
+        },
+    ))
+}
+
+#[instrument(skip(state, key_store))]
+pub async fn retrieve_webhook_endpoint(
+    state: SessionState,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+    endpoint_id: String,
+) -> RouterResponse<webhook_endpoint_api::WebhookEndpointResponse> {
+    let endpoint = find_webhook_endpoint(&state, &key_store, &profile_id, &endpoint_id).await?;
+
+    Ok(ApplicationResponse::Json(
+        webhook_endpoint_api::WebhookEndpointResponse::foreign_from(endpoint),
+    ))
+}
+
+#[instrument(skip(state, key_store))]
+pub async fn list_webhook_endpoints(
+    state: SessionState,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+) -> RouterResponse<Vec<webhook_endpoint_api::WebhookEndpointResponse>> {
+    validate_profile(&state, &key_store, &profile_id).await?;
+
+    let endpoints = state
+        .store
+        .list_webhook_endpoints_by_merchant_id_profile_id(&key_store.merchant_id, &profile_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list webhook endpoints for profile")?;
+
+    Ok(ApplicationResponse::Json(
+        endpoints
+            .into_iter()
+            .map(webhook_endpoint_api::WebhookEndpointResponse::foreign_from)
+            .collect(),
+    ))
+}
+
+#[instrument(skip(state, key_store))]
+pub async fn update_webhook_endpoint(
+    state: SessionState,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+    endpoint_id: String,
+    request: webhook_endpoint_api::WebhookEndpointUpdateRequest,
+) -> RouterResponse<webhook_endpoint_api::WebhookEndpointResponse> {
+    find_webhook_endpoint(&state, &key_store, &profile_id, &endpoint_id).await?;
+    if let Some(enabled_events) = &request.enabled_events {
+        validate_enabled_events(enabled_events)?;
+    }
+
+    let endpoint_update = storage::WebhookEndpointUpdate::Update {
+        url: request.url.map(|url| Secret::new(url.to_string())),
+        enabled_events: request.enabled_events,
+        is_active: request.is_active,
+        description: request.description,
+    };
+
+    let endpoint = state
+        .store
+        .update_webhook_endpoint_by_merchant_id_endpoint_id(
+            &key_store.merchant_id,
+            &endpoint_id,
+            endpoint_update,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::WebhookEndpointNotFound {
+            id: endpoint_id,
+        })?;
+
+    Ok(ApplicationResponse::Json(
+        webhook_endpoint_api::WebhookEndpointResponse::foreign_from(endpoint),
+    ))
+}
+
+#[instrument(skip(state, key_store))]
+pub async fn rotate_webhook_endpoint_secret(
+    state: SessionState,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+    endpoint_id: String,
+    request: webhook_endpoint_api::WebhookEndpointSecretRotateRequest,
+) -> RouterResponse<webhook_endpoint_api::WebhookEndpointSecretRotateResponse> {
+    let rotation_window_in_seconds = request
+        .rotation_window_in_seconds
+        .unwrap_or(consts::DEFAULT_WEBHOOK_SECRET_ROTATION_WINDOW_IN_SECONDS);
+    if rotation_window_in_seconds > consts::MAX_WEBHOOK_SECRET_ROTATION_WINDOW_IN_SECONDS {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "`rotation_window_in_seconds` must be less than or equal to {}",
+                consts::MAX_WEBHOOK_SECRET_ROTATION_WINDOW_IN_SECONDS
+            ),
+        }
+        .into());
+    }
+
+    let endpoint = find_webhook_endpoint(&state, &key_store, &profile_id, &endpoint_id).await?;
+
+    // The previous secret remains valid for signing webhooks until the rotation window elapses,
+    // allowing the consumer of the endpoint to switch over to the new secret.
+    let endpoint_update = storage::WebhookEndpointUpdate::SecretRotation {
+        secret: Secret::new(generate_cryptographically_secure_random_string(
+            WEBHOOK_ENDPOINT_SECRET_LENGTH,
+        )),
+        previous_secret: Some(endpoint.secret),
+        previous_secret_expires_at: Some(common_utils::date_time::now().saturating_add(
+            time::Duration::seconds(i64::from(rotation_window_in_seconds)),
+        )),
+    };
+
+    let endpoint = state
+        .store
+        .update_webhook_endpoint_by_merchant_id_endpoint_id(
+            &key_store.merchant_id,
+            &endpoint_id,
+            endpoint_update,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::WebhookEndpointNotFound {
+            id: endpoint_id,
+        })?;
+
+    Ok(ApplicationResponse::Json(
+        webhook_endpoint_api::WebhookEndpointSecretRotateResponse {
+            endpoint_id: endpoint.endpoint_id,
+            merchant_id: endpoint.merchant_id,
+            secret: endpoint.secret,
+            previous_secret_expires_at: endpoint.previous_secret_expires_at,
+        },
+    ))
+}
+
+#[instrument(skip(state, key_store))]
+pub async fn delete_webhook_endpoint(
+    state: SessionState,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+    endpoint_id: String,
+) -> RouterResponse<webhook_endpoint_api::WebhookEndpointDeleteResponse> {
+    find_webhook_endpoint(&state, &key_store, &profile_id, &endpoint_id).await?;
+
+    let endpoint = state
+        .store
+        .delete_webhook_endpoint_by_merchant_id_endpoint_id(&key_store.merchant_id, &endpoint_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::WebhookEndpointNotFound {
+            id: endpoint_id,
+        })?;
+
+    Ok(ApplicationResponse::Json(
+        webhook_endpoint_api::WebhookEndpointDeleteResponse {
+            endpoint_id: endpoint.endpoint_id,
+            merchant_id: endpoint.merchant_id,
+            deleted: true,
+        },
+    ))
+}
+
+async fn validate_profile(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    profile_id: &id_type::ProfileId,
+) -> RouterResult<()> {
+    state
+        .store
+        .find_business_profile_by_merchant_id_profile_id(
+            &state.into(),
+            key_store,
+            &key_store.merchant_id,
+            profile_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: profile_id.get_string_repr().to_owned(),
+        })
+        .map(|_| ())
+}
+
+fn validate_enabled_events(enabled_events: &[storage::enums::EventType]) -> RouterResult<()> {
+    common_utils::fp_utils::when(enabled_events.is_empty(), || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "`enabled_events` must contain at least one event type".to_string(),
+        }
+        .into())
+    })
+}
+
+/// Fetch the webhook endpoint, ensuring that it belongs to the specified profile.
+async fn find_webhook_endpoint(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    profile_id: &id_type::ProfileId,
+    endpoint_id: &str,
+) -> RouterResult<storage::WebhookEndpoint> {
+    let endpoint_not_found = || errors::ApiErrorResponse::WebhookEndpointNotFound {
+        id: endpoint_id.to_owned(),
+    };
+
+    let endpoint = state
+        .store
+        .find_webhook_endpoint_by_merchant_id_endpoint_id(&key_store.merchant_id, endpoint_id)
+        .await
+        .to_not_found_response(endpoint_not_found())?;
+
+    if endpoint.profile_id != *profile_id {
+        return Err(endpoint_not_found().into());
+    }
+
+    Ok(endpoint)
+}
+
+impl ForeignFrom<storage::WebhookEndpoint> for webhook_endpoint_api::WebhookEndpointResponse {
+    fn foreign_from(item: storage::WebhookEndpoint) -> Self {
+        Self {
+            endpoint_id: item.endpoint_id,
+            merchant_id: item.merchant_id,
+            profile_id: item.profile_id,
+            url: item.url,
+            secret: None,
+            enabled_events: item.enabled_events,
+            is_active: item.is_active,
+            description: item.description,
+            created_at: item.created_at,
+            last_modified_at: item.last_modified_at,
+        }
+    }
+}
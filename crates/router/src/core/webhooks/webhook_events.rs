@@ -1,10 +1,20 @@
 use std::collections::HashSet;
 
+#[cfg(feature = "v1")]
+use common_utils::ext_traits::{Encode, ValueExt};
 use common_utils::{self, errors::CustomResult, fp_utils};
+#[cfg(feature = "v1")]
+use diesel_models::process_tracker::business_status;
 use error_stack::ResultExt;
 use masking::PeekInterface;
+#[cfg(feature = "v1")]
+use router_env::logger;
 use router_env::{instrument, tracing};
 
+#[cfg(feature = "v1")]
+use super::types;
+#[cfg(feature = "v1")]
+use crate::routes::metrics;
 use crate::{
     core::errors::{self, RouterResponse, StorageErrorExt},
     routes::SessionState,
@@ -15,6 +25,20 @@ use crate::{
 
 const INITIAL_DELIVERY_ATTEMPTS_LIST_MAX_LIMIT: i64 = 100;
 const INITIAL_DELIVERY_ATTEMPTS_LIST_MAX_DAYS: i64 = 90;
+#[cfg(feature = "v1")]
+const WEBHOOK_EVENT_REPLAY_DEFAULT_BATCH_SIZE: i64 = 50;
+#[cfg(feature = "v1")]
+const WEBHOOK_EVENT_REPLAY_MAX_BATCH_SIZE: i64 = 500;
+#[cfg(feature = "v1")]
+const WEBHOOK_EVENT_REPLAY_TASK: &str = "WEBHOOK_EVENT_REPLAY";
+#[cfg(feature = "v1")]
+const WEBHOOK_EVENT_REPLAY_TAG: &str = "WEBHOOK_EVENT_REPLAY";
+#[cfg(feature = "v1")]
+const WEBHOOK_EVENT_REPLAY_RUNNER: storage::ProcessTrackerRunner =
+    storage::ProcessTrackerRunner::WebhookEventReplayWorkflow;
+/// Delay between two batches of a replay job, to rate limit the redeliveries to the merchant
+#[cfg(feature = "v1")]
+pub(crate) const WEBHOOK_EVENT_REPLAY_BATCH_INTERVAL_SECS: i64 = 60;
 
 #[derive(Debug)]
 enum MerchantAccountOrProfile {
@@ -272,6 +296,7 @@ pub async fn retry_delivery_attempt(
 
     let business_profile_id = event_to_retry
         .business_profile_id
+        .clone()
         .get_required_value("business_profile_id")
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Failed to read business profile ID from event to retry")?;
@@ -281,6 +306,371 @@ pub async fn retry_delivery_attempt(
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Failed to find business profile")?;
 
+    let new_event_id = Box::pin(trigger_manual_retry_delivery_attempt(
+        &state,
+        &key_store,
+        business_profile,
+        event_to_retry,
+    ))
+    .await?;
+
+    let updated_event = store
+        .find_event_by_merchant_id_event_id(
+            key_manager_state,
+            &key_store.merchant_id,
+            &new_event_id,
+            &key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::EventNotFound)?;
+
+    Ok(ApplicationResponse::Json(
+        api::webhook_events::EventRetrieveResponse::try_from(updated_event)?,
+    ))
+}
+
+#[instrument(skip(state))]
+#[cfg(feature = "v1")]
+pub async fn create_replay_job(
+    state: SessionState,
+    merchant_id: common_utils::id_type::MerchantId,
+    request: api::webhook_events::WebhookEventReplayRequest,
+) -> RouterResponse<api::webhook_events::WebhookEventReplayResponse> {
+    let store = state.store.as_ref();
+
+    // Validates that the business profile belongs to the merchant
+    get_account_and_key_store(
+        state.clone(),
+        merchant_id.clone(),
+        Some(request.profile_id.clone()),
+    )
+    .await?;
+
+    let now = common_utils::date_time::now();
+    let events_list_begin_time =
+        (now.date() - time::Duration::days(INITIAL_DELIVERY_ATTEMPTS_LIST_MAX_DAYS)).midnight();
+    let created_after = request.created_after;
+    let created_before = request.created_before.unwrap_or(now);
+
+    fp_utils::when(created_after > created_before, || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "The `created_after` timestamp must be an earlier timestamp compared to the `created_before` timestamp".to_string(),
+        })
+    })?;
+    fp_utils::when(created_after < events_list_begin_time, || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("`created_after` must be a timestamp within the past {INITIAL_DELIVERY_ATTEMPTS_LIST_MAX_DAYS} days."),
+        })
+    })?;
+    fp_utils::when(created_before > now, || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "`created_before` must not be a timestamp in the future".to_string(),
+        })
+    })?;
+
+    let batch_size = match request.batch_size.map(i64::from) {
+        Some(batch_size) if (1..=WEBHOOK_EVENT_REPLAY_MAX_BATCH_SIZE).contains(&batch_size) => {
+            Ok(batch_size)
+        }
+        Some(_) => Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "`batch_size` must be a number between 1 and {WEBHOOK_EVENT_REPLAY_MAX_BATCH_SIZE}"
+            ),
+        }),
+        None => Ok(WEBHOOK_EVENT_REPLAY_DEFAULT_BATCH_SIZE),
+    }?;
+    let event_types = request.event_types.unwrap_or_default();
+
+    // The delivery status filter is applied while replaying, since the redeliveries can change
+    // the delivery status of the events which are yet to be processed
+    let total_count = store
+        .count_initial_events_by_constraints(
+            &merchant_id,
+            Some(request.profile_id.clone()),
+            created_after,
+            created_before,
+            event_types.clone(),
+            None,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get total events count")?;
+
+    let replay_id = common_utils::generate_time_ordered_id("whr");
+    let tracking_data = types::WebhookEventReplayTrackingData {
+        replay_id: replay_id.clone(),
+        merchant_id,
+        profile_id: request.profile_id,
+        created_after,
+        created_before,
+        event_types,
+        is_delivered: request.is_delivered,
+        batch_size,
+        total_count,
+        processed_count: 0,
+        replayed_count: 0,
+        skipped_count: 0,
+        failed_count: 0,
+    };
+
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        get_replay_process_tracker_id(&replay_id),
+        WEBHOOK_EVENT_REPLAY_TASK,
+        WEBHOOK_EVENT_REPLAY_RUNNER,
+        [WEBHOOK_EVENT_REPLAY_TAG],
+        tracking_data.clone(),
+        None,
+        now,
+        common_types::consts::API_VERSION,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct webhook event replay process tracker task")?;
+
+    let process = store
+        .insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while inserting webhook event replay task to process_tracker")?;
+    metrics::TASKS_ADDED_COUNT.add(
+        1,
+        router_env::metric_attributes!(("flow", "WebhookEventReplay")),
+    );
+
+    Ok(ApplicationResponse::Json(get_replay_job_response(
+        &process,
+        tracking_data,
+    )))
+}
+
+#[instrument(skip(state))]
+#[cfg(feature = "v1")]
+pub async fn retrieve_replay_job(
+    state: SessionState,
+    merchant_id: common_utils::id_type::MerchantId,
+    replay_id: String,
+) -> RouterResponse<api::webhook_events::WebhookEventReplayResponse> {
+    let replay_not_found = || errors::ApiErrorResponse::GenericNotFoundError {
+        message: format!("Webhook event replay job `{replay_id}` not found"),
+    };
+
+    let process = state
+        .store
+        .find_process_by_id(&get_replay_process_tracker_id(&replay_id))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to find webhook event replay task")?
+        .ok_or_else(replay_not_found)?;
+    let tracking_data: types::WebhookEventReplayTrackingData = process
+        .tracking_data
+        .clone()
+        .parse_value("WebhookEventReplayTrackingData")
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    fp_utils::when(tracking_data.merchant_id != merchant_id, || {
+        Err(replay_not_found())
+    })?;
+
+    Ok(ApplicationResponse::Json(get_replay_job_response(
+        &process,
+        tracking_data,
+    )))
+}
+
+/// Redelivers the next batch of events of a replay job and updates the progress in the tracking
+/// data. A new delivery attempt is created for each event, the events themselves are never
+/// duplicated. The progress is persisted in the process tracker task after each event, so that
+/// a retry of the task resumes after the last processed event instead of redelivering the events
+/// of the batch again. Returns `true` once all the events of the job have been processed.
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(replay_id = %tracking_data.replay_id))]
+pub(crate) async fn replay_next_batch(
+    state: &SessionState,
+    process: &mut storage::ProcessTracker,
+    tracking_data: &mut types::WebhookEventReplayTrackingData,
+) -> errors::RouterResult<bool> {
+    let store = state.store.as_ref();
+    let key_manager_state = &state.into();
+
+    let key_store = store
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            &tracking_data.merchant_id,
+            &store.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+    let business_profile = store
+        .find_business_profile_by_profile_id(
+            key_manager_state,
+            &key_store,
+            &tracking_data.profile_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: tracking_data.profile_id.get_string_repr().to_owned(),
+        })?;
+
+    // Events are listed without the delivery status filter so that the offset remains stable
+    let events = store
+        .list_initial_events_by_profile_id_constraints(
+            key_manager_state,
+            &tracking_data.profile_id,
+            tracking_data.created_after,
+            tracking_data.created_before,
+            Some(tracking_data.batch_size),
+            Some(tracking_data.processed_count),
+            tracking_data.event_types.clone(),
+            None,
+            &key_store,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list events to be replayed")?;
+    let is_last_batch = i64::try_from(events.len())
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error while converting from usize to i64")?
+        < tracking_data.batch_size;
+
+    for event in events {
+        if is_delivery_status_matching(
+            tracking_data.is_delivered,
+            event.is_overall_delivery_successful,
+        ) {
+            let event_id = event.event_id.clone();
+            match Box::pin(trigger_manual_retry_delivery_attempt(
+                state,
+                &key_store,
+                business_profile.clone(),
+                event,
+            ))
+            .await
+            {
+                Ok(_) => tracking_data.replayed_count += 1,
+                Err(error) => {
+                    logger::error!(?error, %event_id, "Failed to replay webhook event");
+                    tracking_data.failed_count += 1;
+                }
+            }
+        } else {
+            tracking_data.skipped_count += 1;
+        }
+        tracking_data.processed_count += 1;
+
+        *process = save_replay_progress(state, process.clone(), tracking_data).await?;
+    }
+
+    Ok(is_last_batch)
+}
+
+/// Persists the progress of a replay job in the tracking data of its process tracker task.
+#[cfg(feature = "v1")]
+async fn save_replay_progress(
+    state: &SessionState,
+    process: storage::ProcessTracker,
+    tracking_data: &types::WebhookEventReplayTrackingData,
+) -> errors::RouterResult<storage::ProcessTracker> {
+    let tracking_data = tracking_data
+        .encode_to_value()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize webhook event replay tracking data")?;
+
+    state
+        .store
+        .update_process(
+            process,
+            storage::ProcessTrackerUpdate::Update {
+                name: None,
+                retry_count: None,
+                schedule_time: None,
+                tracking_data: Some(tracking_data),
+                business_status: None,
+                status: None,
+                updated_at: Some(common_utils::date_time::now()),
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to save the progress of the webhook event replay job")
+}
+
+/// Checks whether an event matches the delivery status filter of a replay job. Events are
+/// matched only if their overall delivery status is known, when a filter is provided.
+#[cfg(feature = "v1")]
+fn is_delivery_status_matching(
+    is_delivered_filter: Option<bool>,
+    is_overall_delivery_successful: Option<bool>,
+) -> bool {
+    is_delivered_filter
+        .map(|is_delivered| is_overall_delivery_successful == Some(is_delivered))
+        .unwrap_or(true)
+}
+
+#[cfg(feature = "v1")]
+fn get_replay_process_tracker_id(replay_id: &str) -> String {
+    format!("{WEBHOOK_EVENT_REPLAY_RUNNER}_{WEBHOOK_EVENT_REPLAY_TASK}_{replay_id}")
+}
+
+#[cfg(feature = "v1")]
+fn get_replay_job_response(
+    process: &storage::ProcessTracker,
+    tracking_data: types::WebhookEventReplayTrackingData,
+) -> api::webhook_events::WebhookEventReplayResponse {
+    let status = get_replay_job_status(
+        process.status,
+        &process.business_status,
+        tracking_data.processed_count,
+    );
+
+    api::webhook_events::WebhookEventReplayResponse {
+        replay_id: tracking_data.replay_id,
+        merchant_id: tracking_data.merchant_id,
+        profile_id: tracking_data.profile_id,
+        status,
+        created_after: tracking_data.created_after,
+        created_before: tracking_data.created_before,
+        event_types: tracking_data.event_types,
+        is_delivered: tracking_data.is_delivered,
+        total_count: tracking_data.total_count,
+        processed_count: tracking_data.processed_count,
+        replayed_count: tracking_data.replayed_count,
+        skipped_count: tracking_data.skipped_count,
+        failed_count: tracking_data.failed_count,
+        created: process.created_at,
+    }
+}
+
+#[cfg(feature = "v1")]
+fn get_replay_job_status(
+    process_status: storage::enums::ProcessTrackerStatus,
+    process_business_status: &str,
+    processed_count: i64,
+) -> api::webhook_events::WebhookEventReplayStatus {
+    use api::webhook_events::WebhookEventReplayStatus;
+
+    match process_status {
+        storage::enums::ProcessTrackerStatus::Finish
+            if process_business_status == business_status::COMPLETED_BY_PT =>
+        {
+            WebhookEventReplayStatus::Completed
+        }
+        storage::enums::ProcessTrackerStatus::Finish => WebhookEventReplayStatus::Failed,
+        _ if processed_count == 0 => WebhookEventReplayStatus::Pending,
+        _ => WebhookEventReplayStatus::InProgress,
+    }
+}
+
+/// Creates a new delivery attempt for the specified event and triggers the webhook for it,
+/// returning the ID of the new delivery attempt.
+#[cfg(feature = "v1")]
+async fn trigger_manual_retry_delivery_attempt(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    business_profile: domain::Profile,
+    event_to_retry: domain::Event,
+) -> errors::RouterResult<String> {
+    let store = state.store.as_ref();
+    let key_manager_state = &state.into();
+
     let delivery_attempt = storage::enums::WebhookDeliveryAttempt::ManualRetry;
     let new_event_id = super::utils::generate_event_id();
     let idempotent_event_id = super::utils::get_idempotent_event_id(
@@ -308,10 +698,11 @@ pub async fn retry_delivery_attempt(
         delivery_attempt: Some(delivery_attempt),
         metadata: event_to_retry.metadata,
         is_overall_delivery_successful: Some(false),
+        webhook_endpoint_id: event_to_retry.webhook_endpoint_id,
     };
 
     let event = store
-        .insert_event(key_manager_state, new_event, &key_store)
+        .insert_event(key_manager_state, new_event, key_store)
         .await
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Failed to insert event")?;
@@ -330,7 +721,7 @@ pub async fn retry_delivery_attempt(
     Box::pin(super::outgoing::trigger_webhook_and_raise_event(
         state.clone(),
         business_profile,
-        &key_store,
+        key_store,
         event,
         request_content,
         delivery_attempt,
@@ -339,19 +730,7 @@ pub async fn retry_delivery_attempt(
     ))
     .await;
 
-    let updated_event = store
-        .find_event_by_merchant_id_event_id(
-            key_manager_state,
-            &key_store.merchant_id,
-            &new_event_id,
-            &key_store,
-        )
-        .await
-        .to_not_found_response(errors::ApiErrorResponse::EventNotFound)?;
-
-    Ok(ApplicationResponse::Json(
-        api::webhook_events::EventRetrieveResponse::try_from(updated_event)?,
-    ))
+    Ok(new_event_id)
 }
 
 async fn get_account_and_key_store(
@@ -461,3 +840,85 @@ async fn finalize_event_types(
 
     Ok(event_types.clone())
 }
+
+#[cfg(all(test, feature = "v1"))]
+mod tests {
+    use api_models::webhook_events::WebhookEventReplayStatus;
+
+    use super::*;
+
+    #[test]
+    fn test_is_delivery_status_matching() {
+        let cases = [
+            (None, None, true),
+            (None, Some(true), true),
+            (None, Some(false), true),
+            (Some(true), Some(true), true),
+            (Some(true), Some(false), false),
+            (Some(true), None, false),
+            (Some(false), Some(false), true),
+            (Some(false), Some(true), false),
+            (Some(false), None, false),
+        ];
+
+        for (is_delivered_filter, is_overall_delivery_successful, expected) in cases {
+            assert_eq!(
+                is_delivery_status_matching(is_delivered_filter, is_overall_delivery_successful),
+                expected,
+                "filter: {is_delivered_filter:?}, delivery status: {is_overall_delivery_successful:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_get_replay_job_status() {
+        use storage::enums::ProcessTrackerStatus;
+
+        let cases = [
+            (
+                ProcessTrackerStatus::New,
+                business_status::PENDING,
+                0,
+                WebhookEventReplayStatus::Pending,
+            ),
+            (
+                ProcessTrackerStatus::Pending,
+                business_status::PENDING,
+                10,
+                WebhookEventReplayStatus::InProgress,
+            ),
+            (
+                ProcessTrackerStatus::ProcessStarted,
+                business_status::PENDING,
+                10,
+                WebhookEventReplayStatus::InProgress,
+            ),
+            (
+                ProcessTrackerStatus::Finish,
+                business_status::COMPLETED_BY_PT,
+                10,
+                WebhookEventReplayStatus::Completed,
+            ),
+            (
+                ProcessTrackerStatus::Finish,
+                business_status::COMPLETED_BY_PT,
+                0,
+                WebhookEventReplayStatus::Completed,
+            ),
+            (
+                ProcessTrackerStatus::Finish,
+                business_status::RETRIES_EXCEEDED,
+                10,
+                WebhookEventReplayStatus::Failed,
+            ),
+        ];
+
+        for (process_status, process_business_status, processed_count, expected) in cases {
+            assert_eq!(
+                get_replay_job_status(process_status, process_business_status, processed_count),
+                expected,
+                "status: {process_status:?}, business status: {process_business_status}, processed: {processed_count}"
+            );
+        }
+    }
+}
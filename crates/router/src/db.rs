@@ -1,4 +1,5 @@
 pub mod address;
+pub mod analytics_digest_subscription;
 pub mod api_keys;
 pub mod authentication;
 pub mod authorization;
@@ -28,17 +29,22 @@ pub mod merchant_connector_account;
 pub mod merchant_key_store;
 pub mod organization;
 pub mod payment_link;
+pub mod payment_method_migration_job;
 pub mod payment_method_session;
+pub mod payout_batch;
 pub mod refund;
 pub mod relay;
 pub mod reverse_lookup;
 pub mod role;
 pub mod routing_algorithm;
+pub mod subscription;
 pub mod unified_translations;
 pub mod user;
 pub mod user_authentication_method;
 pub mod user_key_store;
+pub mod user_passkey;
 pub mod user_role;
+pub mod webhook_endpoint;
 
 use ::payment_methods::state::PaymentMethodsStorageInterface;
 use common_utils::id_type;
@@ -142,6 +148,11 @@ pub trait StorageInterface:
     + user::theme::ThemeInterface
     + payment_method_session::PaymentMethodsSessionInterface
     + tokenization::TokenizationInterface
+    + webhook_endpoint::WebhookEndpointInterface
+    + payout_batch::PayoutBatchInterface
+    + subscription::SubscriptionInterface
+    + payment_method_migration_job::PaymentMethodMigrationJobInterface
+    + analytics_digest_subscription::AnalyticsDigestSubscriptionInterface
     + 'static
 {
     fn get_scheduler_db(&self) -> Box<dyn scheduler::SchedulerInterface>;
@@ -157,6 +168,7 @@ pub trait GlobalStorageInterface:
     + user::UserInterface
     + user_role::UserRoleInterface
     + user_key_store::UserKeyStoreInterface
+    + user_passkey::UserPasskeyInterface
     + role::RoleInterface
     + RedisConnInterface
     + 'static
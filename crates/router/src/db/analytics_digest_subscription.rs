@@ -0,0 +1,228 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait AnalyticsDigestSubscriptionInterface {
+    async fn insert_analytics_digest_subscription(
+        &self,
+        subscription: storage::AnalyticsDigestSubscriptionNew,
+    ) -> CustomResult<storage::AnalyticsDigestSubscription, errors::StorageError>;
+
+    async fn find_analytics_digest_subscription_by_merchant_id_subscription_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        subscription_id: &str,
+    ) -> CustomResult<storage::AnalyticsDigestSubscription, errors::StorageError>;
+
+    async fn list_analytics_digest_subscriptions_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+    ) -> CustomResult<Vec<storage::AnalyticsDigestSubscription>, errors::StorageError>;
+
+    async fn update_analytics_digest_subscription_by_merchant_id_subscription_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        subscription_id: &str,
+        subscription_update: storage::AnalyticsDigestSubscriptionUpdate,
+    ) -> CustomResult<storage::AnalyticsDigestSubscription, errors::StorageError>;
+
+    async fn delete_analytics_digest_subscription_by_merchant_id_subscription_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        subscription_id: &str,
+    ) -> CustomResult<storage::AnalyticsDigestSubscription, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl AnalyticsDigestSubscriptionInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_analytics_digest_subscription(
+        &self,
+        subscription: storage::AnalyticsDigestSubscriptionNew,
+    ) -> CustomResult<storage::AnalyticsDigestSubscription, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        subscription
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_analytics_digest_subscription_by_merchant_id_subscription_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        subscription_id: &str,
+    ) -> CustomResult<storage::AnalyticsDigestSubscription, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::AnalyticsDigestSubscription::find_by_merchant_id_subscription_id(
+            &conn,
+            merchant_id,
+            subscription_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_analytics_digest_subscriptions_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+    ) -> CustomResult<Vec<storage::AnalyticsDigestSubscription>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::AnalyticsDigestSubscription::list_by_merchant_id(&conn, merchant_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_analytics_digest_subscription_by_merchant_id_subscription_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        subscription_id: &str,
+        subscription_update: storage::AnalyticsDigestSubscriptionUpdate,
+    ) -> CustomResult<storage::AnalyticsDigestSubscription, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::AnalyticsDigestSubscription::update_by_merchant_id_subscription_id(
+            &conn,
+            merchant_id,
+            subscription_id,
+            subscription_update,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_analytics_digest_subscription_by_merchant_id_subscription_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        subscription_id: &str,
+    ) -> CustomResult<storage::AnalyticsDigestSubscription, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::AnalyticsDigestSubscription::delete_by_merchant_id_subscription_id(
+            &conn,
+            merchant_id,
+            subscription_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl AnalyticsDigestSubscriptionInterface for MockDb {
+    async fn insert_analytics_digest_subscription(
+        &self,
+        _subscription: storage::AnalyticsDigestSubscriptionNew,
+    ) -> CustomResult<storage::AnalyticsDigestSubscription, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_analytics_digest_subscription_by_merchant_id_subscription_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _subscription_id: &str,
+    ) -> CustomResult<storage::AnalyticsDigestSubscription, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_analytics_digest_subscriptions_by_merchant_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+    ) -> CustomResult<Vec<storage::AnalyticsDigestSubscription>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_analytics_digest_subscription_by_merchant_id_subscription_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _subscription_id: &str,
+        _subscription_update: storage::AnalyticsDigestSubscriptionUpdate,
+    ) -> CustomResult<storage::AnalyticsDigestSubscription, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn delete_analytics_digest_subscription_by_merchant_id_subscription_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _subscription_id: &str,
+    ) -> CustomResult<storage::AnalyticsDigestSubscription, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl AnalyticsDigestSubscriptionInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn insert_analytics_digest_subscription(
+        &self,
+        subscription: storage::AnalyticsDigestSubscriptionNew,
+    ) -> CustomResult<storage::AnalyticsDigestSubscription, errors::StorageError> {
+        self.diesel_store
+            .insert_analytics_digest_subscription(subscription)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_analytics_digest_subscription_by_merchant_id_subscription_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        subscription_id: &str,
+    ) -> CustomResult<storage::AnalyticsDigestSubscription, errors::StorageError> {
+        self.diesel_store
+            .find_analytics_digest_subscription_by_merchant_id_subscription_id(
+                merchant_id,
+                subscription_id,
+            )
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_analytics_digest_subscriptions_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+    ) -> CustomResult<Vec<storage::AnalyticsDigestSubscription>, errors::StorageError> {
+        self.diesel_store
+            .list_analytics_digest_subscriptions_by_merchant_id(merchant_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn update_analytics_digest_subscription_by_merchant_id_subscription_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        subscription_id: &str,
+        subscription_update: storage::AnalyticsDigestSubscriptionUpdate,
+    ) -> CustomResult<storage::AnalyticsDigestSubscription, errors::StorageError> {
+        self.diesel_store
+            .update_analytics_digest_subscription_by_merchant_id_subscription_id(
+                merchant_id,
+                subscription_id,
+                subscription_update,
+            )
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_analytics_digest_subscription_by_merchant_id_subscription_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        subscription_id: &str,
+    ) -> CustomResult<storage::AnalyticsDigestSubscription, errors::StorageError> {
+        self.diesel_store
+            .delete_analytics_digest_subscription_by_merchant_id_subscription_id(
+                merchant_id,
+                subscription_id,
+            )
+            .await
+    }
+}
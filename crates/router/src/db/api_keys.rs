@@ -233,6 +233,8 @@ impl ApiKeyInterface for MockDb {
             created_at: api_key.created_at,
             expires_at: api_key.expires_at,
             last_used: api_key.last_used,
+            permission_groups: api_key.permission_groups,
+            profile_id: api_key.profile_id,
         };
         locked_api_keys.push(stored_key.clone());
 
@@ -258,6 +260,7 @@ impl ApiKeyInterface for MockDb {
                 description,
                 expires_at,
                 last_used,
+                permission_groups,
             } => {
                 if let Some(name) = name {
                     key_to_update.name = name;
@@ -272,6 +275,9 @@ impl ApiKeyInterface for MockDb {
                 if last_used.is_some() {
                     key_to_update.last_used = last_used
                 }
+                if permission_groups.is_some() {
+                    key_to_update.permission_groups = permission_groups;
+                }
             }
             storage::ApiKeyUpdate::LastUsedUpdate { last_used } => {
                 key_to_update.last_used = Some(last_used);
@@ -417,6 +423,8 @@ mod tests {
                 created_at: datetime!(2023-02-01 0:00),
                 expires_at: Some(datetime!(2023-03-01 0:00)),
                 last_used: None,
+                permission_groups: None,
+                profile_id: None,
             })
             .await
             .unwrap();
@@ -432,6 +440,8 @@ mod tests {
                 created_at: datetime!(2023-03-01 0:00),
                 expires_at: None,
                 last_used: None,
+                permission_groups: None,
+                profile_id: None,
             })
             .await
             .unwrap();
@@ -513,6 +523,8 @@ mod tests {
             created_at: datetime!(2023-06-01 0:00),
             expires_at: None,
             last_used: None,
+            permission_groups: None,
+            profile_id: None,
         };
 
         let api = db.insert_api_key(api).await.unwrap();
@@ -34,13 +34,21 @@ pub trait BlocklistInterface {
         merchant_id: &common_utils::id_type::MerchantId,
     ) -> CustomResult<Vec<storage::Blocklist>, errors::StorageError>;
 
-    async fn list_blocklist_entries_by_merchant_id_data_kind(
+    async fn list_blocklist_entries_by_merchant_id_constraints(
         &self,
         merchant_id: &common_utils::id_type::MerchantId,
-        data_kind: common_enums::BlocklistDataKind,
+        data_kind: Option<common_enums::BlocklistDataKind>,
+        status: Option<common_enums::BlocklistEntryStatus>,
         limit: i64,
         offset: i64,
     ) -> CustomResult<Vec<storage::Blocklist>, errors::StorageError>;
+
+    async fn update_blocklist_entry_by_merchant_id_fingerprint_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        fingerprint_id: &str,
+        blocklist_update: storage::BlocklistUpdate,
+    ) -> CustomResult<storage::Blocklist, errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -81,18 +89,20 @@ impl BlocklistInterface for Store {
     }
 
     #[instrument(skip_all)]
-    async fn list_blocklist_entries_by_merchant_id_data_kind(
+    async fn list_blocklist_entries_by_merchant_id_constraints(
         &self,
         merchant_id: &common_utils::id_type::MerchantId,
-        data_kind: common_enums::BlocklistDataKind,
+        data_kind: Option<common_enums::BlocklistDataKind>,
+        status: Option<common_enums::BlocklistEntryStatus>,
         limit: i64,
         offset: i64,
     ) -> CustomResult<Vec<storage::Blocklist>, errors::StorageError> {
         let conn = connection::pg_connection_write(self).await?;
-        storage::Blocklist::list_by_merchant_id_data_kind(
+        storage::Blocklist::list_by_merchant_id_constraints(
             &conn,
             merchant_id,
             data_kind,
+            status,
             limit,
             offset,
         )
@@ -100,6 +110,24 @@ impl BlocklistInterface for Store {
         .map_err(|error| report!(errors::StorageError::from(error)))
     }
 
+    #[instrument(skip_all)]
+    async fn update_blocklist_entry_by_merchant_id_fingerprint_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        fingerprint_id: &str,
+        blocklist_update: storage::BlocklistUpdate,
+    ) -> CustomResult<storage::Blocklist, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::Blocklist::update_by_merchant_id_fingerprint_id(
+            &conn,
+            merchant_id,
+            fingerprint_id,
+            blocklist_update,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
     #[instrument(skip_all)]
     async fn delete_blocklist_entry_by_merchant_id_fingerprint_id(
         &self,
@@ -138,16 +166,26 @@ impl BlocklistInterface for MockDb {
         Err(errors::StorageError::MockDbError)?
     }
 
-    async fn list_blocklist_entries_by_merchant_id_data_kind(
+    async fn list_blocklist_entries_by_merchant_id_constraints(
         &self,
         _merchant_id: &common_utils::id_type::MerchantId,
-        _data_kind: common_enums::BlocklistDataKind,
+        _data_kind: Option<common_enums::BlocklistDataKind>,
+        _status: Option<common_enums::BlocklistEntryStatus>,
         _limit: i64,
         _offset: i64,
     ) -> CustomResult<Vec<storage::Blocklist>, errors::StorageError> {
         Err(errors::StorageError::MockDbError)?
     }
 
+    async fn update_blocklist_entry_by_merchant_id_fingerprint_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _fingerprint_id: &str,
+        _blocklist_update: storage::BlocklistUpdate,
+    ) -> CustomResult<storage::Blocklist, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
     async fn delete_blocklist_entry_by_merchant_id_fingerprint_id(
         &self,
         _merchant_id: &common_utils::id_type::MerchantId,
@@ -190,15 +228,38 @@ impl BlocklistInterface for KafkaStore {
     }
 
     #[instrument(skip_all)]
-    async fn list_blocklist_entries_by_merchant_id_data_kind(
+    async fn list_blocklist_entries_by_merchant_id_constraints(
         &self,
         merchant_id: &common_utils::id_type::MerchantId,
-        data_kind: common_enums::BlocklistDataKind,
+        data_kind: Option<common_enums::BlocklistDataKind>,
+        status: Option<common_enums::BlocklistEntryStatus>,
         limit: i64,
         offset: i64,
     ) -> CustomResult<Vec<storage::Blocklist>, errors::StorageError> {
         self.diesel_store
-            .list_blocklist_entries_by_merchant_id_data_kind(merchant_id, data_kind, limit, offset)
+            .list_blocklist_entries_by_merchant_id_constraints(
+                merchant_id,
+                data_kind,
+                status,
+                limit,
+                offset,
+            )
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn update_blocklist_entry_by_merchant_id_fingerprint_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        fingerprint_id: &str,
+        blocklist_update: storage::BlocklistUpdate,
+    ) -> CustomResult<storage::Blocklist, errors::StorageError> {
+        self.diesel_store
+            .update_blocklist_entry_by_merchant_id_fingerprint_id(
+                merchant_id,
+                fingerprint_id,
+                blocklist_update,
+            )
             .await
     }
 
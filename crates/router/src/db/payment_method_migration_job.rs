@@ -0,0 +1,306 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage::{self, enums},
+};
+
+#[async_trait::async_trait]
+pub trait PaymentMethodMigrationJobInterface {
+    async fn insert_payment_method_migration_job(
+        &self,
+        payment_method_migration_job: storage::PaymentMethodMigrationJobNew,
+    ) -> CustomResult<storage::PaymentMethodMigrationJob, errors::StorageError>;
+
+    async fn find_payment_method_migration_job_by_merchant_id_job_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        job_id: &str,
+    ) -> CustomResult<storage::PaymentMethodMigrationJob, errors::StorageError>;
+
+    async fn update_payment_method_migration_job_by_merchant_id_job_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        job_id: &str,
+        job_update: storage::PaymentMethodMigrationJobUpdate,
+    ) -> CustomResult<storage::PaymentMethodMigrationJob, errors::StorageError>;
+
+    async fn insert_payment_method_migration_job_items(
+        &self,
+        job_items: Vec<storage::PaymentMethodMigrationJobItemNew>,
+    ) -> CustomResult<(), errors::StorageError>;
+
+    /// Items of the job in the order of their lines, optionally only the items with the status
+    async fn find_payment_method_migration_job_items_by_job_id_status(
+        &self,
+        job_id: &str,
+        status: Option<enums::PaymentMethodMigrationJobItemStatus>,
+        limit: Option<i64>,
+    ) -> CustomResult<Vec<storage::PaymentMethodMigrationJobItem>, errors::StorageError>;
+
+    async fn get_payment_method_migration_job_item_count_by_job_id_status(
+        &self,
+        job_id: &str,
+        status: enums::PaymentMethodMigrationJobItemStatus,
+    ) -> CustomResult<i64, errors::StorageError>;
+
+    async fn update_payment_method_migration_job_item_by_job_id_line_number(
+        &self,
+        job_id: &str,
+        line_number: i32,
+        job_item_update: storage::PaymentMethodMigrationJobItemUpdate,
+    ) -> CustomResult<storage::PaymentMethodMigrationJobItem, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl PaymentMethodMigrationJobInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_payment_method_migration_job(
+        &self,
+        payment_method_migration_job: storage::PaymentMethodMigrationJobNew,
+    ) -> CustomResult<storage::PaymentMethodMigrationJob, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        payment_method_migration_job
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_payment_method_migration_job_by_merchant_id_job_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        job_id: &str,
+    ) -> CustomResult<storage::PaymentMethodMigrationJob, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::PaymentMethodMigrationJob::find_by_merchant_id_job_id(&conn, merchant_id, job_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_payment_method_migration_job_by_merchant_id_job_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        job_id: &str,
+        job_update: storage::PaymentMethodMigrationJobUpdate,
+    ) -> CustomResult<storage::PaymentMethodMigrationJob, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::PaymentMethodMigrationJob::update_by_merchant_id_job_id(
+            &conn,
+            merchant_id,
+            job_id,
+            job_update,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn insert_payment_method_migration_job_items(
+        &self,
+        job_items: Vec<storage::PaymentMethodMigrationJobItemNew>,
+    ) -> CustomResult<(), errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::PaymentMethodMigrationJobItemNew::batch_insert(job_items, &conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_payment_method_migration_job_items_by_job_id_status(
+        &self,
+        job_id: &str,
+        status: Option<enums::PaymentMethodMigrationJobItemStatus>,
+        limit: Option<i64>,
+    ) -> CustomResult<Vec<storage::PaymentMethodMigrationJobItem>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::PaymentMethodMigrationJobItem::find_by_job_id_status(&conn, job_id, status, limit)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn get_payment_method_migration_job_item_count_by_job_id_status(
+        &self,
+        job_id: &str,
+        status: enums::PaymentMethodMigrationJobItemStatus,
+    ) -> CustomResult<i64, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::PaymentMethodMigrationJobItem::get_count_by_job_id_status(&conn, job_id, status)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_payment_method_migration_job_item_by_job_id_line_number(
+        &self,
+        job_id: &str,
+        line_number: i32,
+        job_item_update: storage::PaymentMethodMigrationJobItemUpdate,
+    ) -> CustomResult<storage::PaymentMethodMigrationJobItem, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::PaymentMethodMigrationJobItem::update_by_job_id_line_number(
+            &conn,
+            job_id,
+            line_number,
+            job_item_update,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl PaymentMethodMigrationJobInterface for MockDb {
+    async fn insert_payment_method_migration_job(
+        &self,
+        _payment_method_migration_job: storage::PaymentMethodMigrationJobNew,
+    ) -> CustomResult<storage::PaymentMethodMigrationJob, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_payment_method_migration_job_by_merchant_id_job_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _job_id: &str,
+    ) -> CustomResult<storage::PaymentMethodMigrationJob, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_payment_method_migration_job_by_merchant_id_job_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _job_id: &str,
+        _job_update: storage::PaymentMethodMigrationJobUpdate,
+    ) -> CustomResult<storage::PaymentMethodMigrationJob, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn insert_payment_method_migration_job_items(
+        &self,
+        _job_items: Vec<storage::PaymentMethodMigrationJobItemNew>,
+    ) -> CustomResult<(), errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_payment_method_migration_job_items_by_job_id_status(
+        &self,
+        _job_id: &str,
+        _status: Option<enums::PaymentMethodMigrationJobItemStatus>,
+        _limit: Option<i64>,
+    ) -> CustomResult<Vec<storage::PaymentMethodMigrationJobItem>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn get_payment_method_migration_job_item_count_by_job_id_status(
+        &self,
+        _job_id: &str,
+        _status: enums::PaymentMethodMigrationJobItemStatus,
+    ) -> CustomResult<i64, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_payment_method_migration_job_item_by_job_id_line_number(
+        &self,
+        _job_id: &str,
+        _line_number: i32,
+        _job_item_update: storage::PaymentMethodMigrationJobItemUpdate,
+    ) -> CustomResult<storage::PaymentMethodMigrationJobItem, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl PaymentMethodMigrationJobInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn insert_payment_method_migration_job(
+        &self,
+        payment_method_migration_job: storage::PaymentMethodMigrationJobNew,
+    ) -> CustomResult<storage::PaymentMethodMigrationJob, errors::StorageError> {
+        self.diesel_store
+            .insert_payment_method_migration_job(payment_method_migration_job)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_payment_method_migration_job_by_merchant_id_job_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        job_id: &str,
+    ) -> CustomResult<storage::PaymentMethodMigrationJob, errors::StorageError> {
+        self.diesel_store
+            .find_payment_method_migration_job_by_merchant_id_job_id(merchant_id, job_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn update_payment_method_migration_job_by_merchant_id_job_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        job_id: &str,
+        job_update: storage::PaymentMethodMigrationJobUpdate,
+    ) -> CustomResult<storage::PaymentMethodMigrationJob, errors::StorageError> {
+        self.diesel_store
+            .update_payment_method_migration_job_by_merchant_id_job_id(
+                merchant_id,
+                job_id,
+                job_update,
+            )
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn insert_payment_method_migration_job_items(
+        &self,
+        job_items: Vec<storage::PaymentMethodMigrationJobItemNew>,
+    ) -> CustomResult<(), errors::StorageError> {
+        self.diesel_store
+            .insert_payment_method_migration_job_items(job_items)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_payment_method_migration_job_items_by_job_id_status(
+        &self,
+        job_id: &str,
+        status: Option<enums::PaymentMethodMigrationJobItemStatus>,
+        limit: Option<i64>,
+    ) -> CustomResult<Vec<storage::PaymentMethodMigrationJobItem>, errors::StorageError> {
+        self.diesel_store
+            .find_payment_method_migration_job_items_by_job_id_status(job_id, status, limit)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn get_payment_method_migration_job_item_count_by_job_id_status(
+        &self,
+        job_id: &str,
+        status: enums::PaymentMethodMigrationJobItemStatus,
+    ) -> CustomResult<i64, errors::StorageError> {
+        self.diesel_store
+            .get_payment_method_migration_job_item_count_by_job_id_status(job_id, status)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn update_payment_method_migration_job_item_by_job_id_line_number(
+        &self,
+        job_id: &str,
+        line_number: i32,
+        job_item_update: storage::PaymentMethodMigrationJobItemUpdate,
+    ) -> CustomResult<storage::PaymentMethodMigrationJobItem, errors::StorageError> {
+        self.diesel_store
+            .update_payment_method_migration_job_item_by_job_id_line_number(
+                job_id,
+                line_number,
+                job_item_update,
+            )
+            .await
+    }
+}
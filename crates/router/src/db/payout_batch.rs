@@ -0,0 +1,350 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage::{self, enums},
+};
+
+#[async_trait::async_trait]
+pub trait PayoutBatchInterface {
+    async fn insert_payout_batch(
+        &self,
+        payout_batch: storage::PayoutBatchNew,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError>;
+
+    async fn find_payout_batch_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        batch_id: &str,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError>;
+
+    async fn update_payout_batch_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        batch_id: &str,
+        payout_batch_update: storage::PayoutBatchUpdate,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError>;
+
+    async fn insert_payout_batch_items(
+        &self,
+        payout_batch_items: Vec<storage::PayoutBatchItemNew>,
+    ) -> CustomResult<(), errors::StorageError>;
+
+    /// Items of the batch in the order of their rows, optionally only the items with the status
+    async fn find_payout_batch_items_by_batch_id_status(
+        &self,
+        batch_id: &str,
+        status: Option<enums::PayoutBatchItemStatus>,
+        limit: Option<i64>,
+    ) -> CustomResult<Vec<storage::PayoutBatchItem>, errors::StorageError>;
+
+    async fn get_payout_batch_item_count_by_batch_id_status(
+        &self,
+        batch_id: &str,
+        status: enums::PayoutBatchItemStatus,
+    ) -> CustomResult<i64, errors::StorageError>;
+
+    async fn update_payout_batch_item_by_batch_id_row_number(
+        &self,
+        batch_id: &str,
+        row_number: i32,
+        payout_batch_item_update: storage::PayoutBatchItemUpdate,
+    ) -> CustomResult<storage::PayoutBatchItem, errors::StorageError>;
+
+    async fn update_payout_batch_items_by_batch_id_status(
+        &self,
+        batch_id: &str,
+        status: enums::PayoutBatchItemStatus,
+        payout_batch_item_update: storage::PayoutBatchItemUpdate,
+    ) -> CustomResult<usize, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl PayoutBatchInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_payout_batch(
+        &self,
+        payout_batch: storage::PayoutBatchNew,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        payout_batch
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_payout_batch_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        batch_id: &str,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::PayoutBatch::find_by_merchant_id_batch_id(&conn, merchant_id, batch_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_payout_batch_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        batch_id: &str,
+        payout_batch_update: storage::PayoutBatchUpdate,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::PayoutBatch::update_by_merchant_id_batch_id(
+            &conn,
+            merchant_id,
+            batch_id,
+            payout_batch_update,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn insert_payout_batch_items(
+        &self,
+        payout_batch_items: Vec<storage::PayoutBatchItemNew>,
+    ) -> CustomResult<(), errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::PayoutBatchItemNew::batch_insert(payout_batch_items, &conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_payout_batch_items_by_batch_id_status(
+        &self,
+        batch_id: &str,
+        status: Option<enums::PayoutBatchItemStatus>,
+        limit: Option<i64>,
+    ) -> CustomResult<Vec<storage::PayoutBatchItem>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::PayoutBatchItem::find_by_batch_id_status(&conn, batch_id, status, limit)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn get_payout_batch_item_count_by_batch_id_status(
+        &self,
+        batch_id: &str,
+        status: enums::PayoutBatchItemStatus,
+    ) -> CustomResult<i64, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::PayoutBatchItem::get_count_by_batch_id_status(&conn, batch_id, status)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_payout_batch_item_by_batch_id_row_number(
+        &self,
+        batch_id: &str,
+        row_number: i32,
+        payout_batch_item_update: storage::PayoutBatchItemUpdate,
+    ) -> CustomResult<storage::PayoutBatchItem, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::PayoutBatchItem::update_by_batch_id_row_number(
+            &conn,
+            batch_id,
+            row_number,
+            payout_batch_item_update,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_payout_batch_items_by_batch_id_status(
+        &self,
+        batch_id: &str,
+        status: enums::PayoutBatchItemStatus,
+        payout_batch_item_update: storage::PayoutBatchItemUpdate,
+    ) -> CustomResult<usize, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::PayoutBatchItem::update_by_batch_id_status(
+            &conn,
+            batch_id,
+            status,
+            payout_batch_item_update,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl PayoutBatchInterface for MockDb {
+    async fn insert_payout_batch(
+        &self,
+        _payout_batch: storage::PayoutBatchNew,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_payout_batch_by_merchant_id_batch_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _batch_id: &str,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_payout_batch_by_merchant_id_batch_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _batch_id: &str,
+        _payout_batch_update: storage::PayoutBatchUpdate,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn insert_payout_batch_items(
+        &self,
+        _payout_batch_items: Vec<storage::PayoutBatchItemNew>,
+    ) -> CustomResult<(), errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_payout_batch_items_by_batch_id_status(
+        &self,
+        _batch_id: &str,
+        _status: Option<enums::PayoutBatchItemStatus>,
+        _limit: Option<i64>,
+    ) -> CustomResult<Vec<storage::PayoutBatchItem>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn get_payout_batch_item_count_by_batch_id_status(
+        &self,
+        _batch_id: &str,
+        _status: enums::PayoutBatchItemStatus,
+    ) -> CustomResult<i64, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_payout_batch_item_by_batch_id_row_number(
+        &self,
+        _batch_id: &str,
+        _row_number: i32,
+        _payout_batch_item_update: storage::PayoutBatchItemUpdate,
+    ) -> CustomResult<storage::PayoutBatchItem, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_payout_batch_items_by_batch_id_status(
+        &self,
+        _batch_id: &str,
+        _status: enums::PayoutBatchItemStatus,
+        _payout_batch_item_update: storage::PayoutBatchItemUpdate,
+    ) -> CustomResult<usize, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl PayoutBatchInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn insert_payout_batch(
+        &self,
+        payout_batch: storage::PayoutBatchNew,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        self.diesel_store.insert_payout_batch(payout_batch).await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_payout_batch_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        batch_id: &str,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        self.diesel_store
+            .find_payout_batch_by_merchant_id_batch_id(merchant_id, batch_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn update_payout_batch_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        batch_id: &str,
+        payout_batch_update: storage::PayoutBatchUpdate,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        self.diesel_store
+            .update_payout_batch_by_merchant_id_batch_id(merchant_id, batch_id, payout_batch_update)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn insert_payout_batch_items(
+        &self,
+        payout_batch_items: Vec<storage::PayoutBatchItemNew>,
+    ) -> CustomResult<(), errors::StorageError> {
+        self.diesel_store
+            .insert_payout_batch_items(payout_batch_items)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_payout_batch_items_by_batch_id_status(
+        &self,
+        batch_id: &str,
+        status: Option<enums::PayoutBatchItemStatus>,
+        limit: Option<i64>,
+    ) -> CustomResult<Vec<storage::PayoutBatchItem>, errors::StorageError> {
+        self.diesel_store
+            .find_payout_batch_items_by_batch_id_status(batch_id, status, limit)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn get_payout_batch_item_count_by_batch_id_status(
+        &self,
+        batch_id: &str,
+        status: enums::PayoutBatchItemStatus,
+    ) -> CustomResult<i64, errors::StorageError> {
+        self.diesel_store
+            .get_payout_batch_item_count_by_batch_id_status(batch_id, status)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn update_payout_batch_item_by_batch_id_row_number(
+        &self,
+        batch_id: &str,
+        row_number: i32,
+        payout_batch_item_update: storage::PayoutBatchItemUpdate,
+    ) -> CustomResult<storage::PayoutBatchItem, errors::StorageError> {
+        self.diesel_store
+            .update_payout_batch_item_by_batch_id_row_number(
+                batch_id,
+                row_number,
+                payout_batch_item_update,
+            )
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn update_payout_batch_items_by_batch_id_status(
+        &self,
+        batch_id: &str,
+        status: enums::PayoutBatchItemStatus,
+        payout_batch_item_update: storage::PayoutBatchItemUpdate,
+    ) -> CustomResult<usize, errors::StorageError> {
+        self.diesel_store
+            .update_payout_batch_items_by_batch_id_status(
+                batch_id,
+                status,
+                payout_batch_item_update,
+            )
+            .await
+    }
+}
@@ -0,0 +1,531 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage::{self, enums},
+};
+
+#[async_trait::async_trait]
+pub trait SubscriptionInterface {
+    async fn insert_subscription_plan(
+        &self,
+        subscription_plan: storage::SubscriptionPlanNew,
+    ) -> CustomResult<storage::SubscriptionPlan, errors::StorageError>;
+
+    async fn find_subscription_plan_by_merchant_id_plan_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        plan_id: &str,
+    ) -> CustomResult<storage::SubscriptionPlan, errors::StorageError>;
+
+    async fn list_subscription_plans_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::SubscriptionPlan>, errors::StorageError>;
+
+    async fn update_subscription_plan_by_merchant_id_plan_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        plan_id: &str,
+        subscription_plan_update: storage::SubscriptionPlanUpdate,
+    ) -> CustomResult<storage::SubscriptionPlan, errors::StorageError>;
+
+    async fn insert_subscription(
+        &self,
+        subscription: storage::SubscriptionNew,
+    ) -> CustomResult<storage::Subscription, errors::StorageError>;
+
+    async fn find_subscription_by_merchant_id_subscription_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        subscription_id: &str,
+    ) -> CustomResult<storage::Subscription, errors::StorageError>;
+
+    async fn list_subscriptions_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: Option<&common_utils::id_type::CustomerId>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::Subscription>, errors::StorageError>;
+
+    async fn update_subscription_by_merchant_id_subscription_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        subscription_id: &str,
+        subscription_update: storage::SubscriptionUpdate,
+    ) -> CustomResult<storage::Subscription, errors::StorageError>;
+
+    async fn insert_subscription_invoice(
+        &self,
+        subscription_invoice: storage::SubscriptionInvoiceNew,
+    ) -> CustomResult<storage::SubscriptionInvoice, errors::StorageError>;
+
+    async fn list_subscription_invoices_by_merchant_id_subscription_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        subscription_id: &str,
+        limit: Option<i64>,
+    ) -> CustomResult<Vec<storage::SubscriptionInvoice>, errors::StorageError>;
+
+    /// Invoices of the subscription with the status, the latest invoice first
+    async fn find_subscription_invoices_by_subscription_id_status(
+        &self,
+        subscription_id: &str,
+        status: enums::SubscriptionInvoiceStatus,
+    ) -> CustomResult<Vec<storage::SubscriptionInvoice>, errors::StorageError>;
+
+    async fn update_subscription_invoice_by_merchant_id_invoice_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        invoice_id: &str,
+        subscription_invoice_update: storage::SubscriptionInvoiceUpdate,
+    ) -> CustomResult<storage::SubscriptionInvoice, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl SubscriptionInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_subscription_plan(
+        &self,
+        subscription_plan: storage::SubscriptionPlanNew,
+    ) -> CustomResult<storage::SubscriptionPlan, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        subscription_plan
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_subscription_plan_by_merchant_id_plan_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        plan_id: &str,
+    ) -> CustomResult<storage::SubscriptionPlan, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::SubscriptionPlan::find_by_merchant_id_plan_id(&conn, merchant_id, plan_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_subscription_plans_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::SubscriptionPlan>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::SubscriptionPlan::list_by_merchant_id(&conn, merchant_id, limit, offset)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_subscription_plan_by_merchant_id_plan_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        plan_id: &str,
+        subscription_plan_update: storage::SubscriptionPlanUpdate,
+    ) -> CustomResult<storage::SubscriptionPlan, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::SubscriptionPlan::update_by_merchant_id_plan_id(
+            &conn,
+            merchant_id,
+            plan_id,
+            subscription_plan_update,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn insert_subscription(
+        &self,
+        subscription: storage::SubscriptionNew,
+    ) -> CustomResult<storage::Subscription, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        subscription
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_subscription_by_merchant_id_subscription_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        subscription_id: &str,
+    ) -> CustomResult<storage::Subscription, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Subscription::find_by_merchant_id_subscription_id(
+            &conn,
+            merchant_id,
+            subscription_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_subscriptions_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: Option<&common_utils::id_type::CustomerId>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::Subscription>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Subscription::list_by_merchant_id_customer_id(
+            &conn,
+            merchant_id,
+            customer_id,
+            limit,
+            offset,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_subscription_by_merchant_id_subscription_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        subscription_id: &str,
+        subscription_update: storage::SubscriptionUpdate,
+    ) -> CustomResult<storage::Subscription, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::Subscription::update_by_merchant_id_subscription_id(
+            &conn,
+            merchant_id,
+            subscription_id,
+            subscription_update,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn insert_subscription_invoice(
+        &self,
+        subscription_invoice: storage::SubscriptionInvoiceNew,
+    ) -> CustomResult<storage::SubscriptionInvoice, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        subscription_invoice
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_subscription_invoices_by_merchant_id_subscription_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        subscription_id: &str,
+        limit: Option<i64>,
+    ) -> CustomResult<Vec<storage::SubscriptionInvoice>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::SubscriptionInvoice::list_by_merchant_id_subscription_id(
+            &conn,
+            merchant_id,
+            subscription_id,
+            limit,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_subscription_invoices_by_subscription_id_status(
+        &self,
+        subscription_id: &str,
+        status: enums::SubscriptionInvoiceStatus,
+    ) -> CustomResult<Vec<storage::SubscriptionInvoice>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::SubscriptionInvoice::find_by_subscription_id_status(&conn, subscription_id, status)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_subscription_invoice_by_merchant_id_invoice_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        invoice_id: &str,
+        subscription_invoice_update: storage::SubscriptionInvoiceUpdate,
+    ) -> CustomResult<storage::SubscriptionInvoice, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::SubscriptionInvoice::update_by_merchant_id_invoice_id(
+            &conn,
+            merchant_id,
+            invoice_id,
+            subscription_invoice_update,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl SubscriptionInterface for MockDb {
+    async fn insert_subscription_plan(
+        &self,
+        _subscription_plan: storage::SubscriptionPlanNew,
+    ) -> CustomResult<storage::SubscriptionPlan, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_subscription_plan_by_merchant_id_plan_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _plan_id: &str,
+    ) -> CustomResult<storage::SubscriptionPlan, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_subscription_plans_by_merchant_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _limit: Option<i64>,
+        _offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::SubscriptionPlan>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_subscription_plan_by_merchant_id_plan_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _plan_id: &str,
+        _subscription_plan_update: storage::SubscriptionPlanUpdate,
+    ) -> CustomResult<storage::SubscriptionPlan, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn insert_subscription(
+        &self,
+        _subscription: storage::SubscriptionNew,
+    ) -> CustomResult<storage::Subscription, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_subscription_by_merchant_id_subscription_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _subscription_id: &str,
+    ) -> CustomResult<storage::Subscription, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_subscriptions_by_merchant_id_customer_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _customer_id: Option<&common_utils::id_type::CustomerId>,
+        _limit: Option<i64>,
+        _offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::Subscription>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_subscription_by_merchant_id_subscription_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _subscription_id: &str,
+        _subscription_update: storage::SubscriptionUpdate,
+    ) -> CustomResult<storage::Subscription, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn insert_subscription_invoice(
+        &self,
+        _subscription_invoice: storage::SubscriptionInvoiceNew,
+    ) -> CustomResult<storage::SubscriptionInvoice, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_subscription_invoices_by_merchant_id_subscription_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _subscription_id: &str,
+        _limit: Option<i64>,
+    ) -> CustomResult<Vec<storage::SubscriptionInvoice>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_subscription_invoices_by_subscription_id_status(
+        &self,
+        _subscription_id: &str,
+        _status: enums::SubscriptionInvoiceStatus,
+    ) -> CustomResult<Vec<storage::SubscriptionInvoice>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_subscription_invoice_by_merchant_id_invoice_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _invoice_id: &str,
+        _subscription_invoice_update: storage::SubscriptionInvoiceUpdate,
+    ) -> CustomResult<storage::SubscriptionInvoice, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl SubscriptionInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn insert_subscription_plan(
+        &self,
+        subscription_plan: storage::SubscriptionPlanNew,
+    ) -> CustomResult<storage::SubscriptionPlan, errors::StorageError> {
+        self.diesel_store
+            .insert_subscription_plan(subscription_plan)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_subscription_plan_by_merchant_id_plan_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        plan_id: &str,
+    ) -> CustomResult<storage::SubscriptionPlan, errors::StorageError> {
+        self.diesel_store
+            .find_subscription_plan_by_merchant_id_plan_id(merchant_id, plan_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_subscription_plans_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::SubscriptionPlan>, errors::StorageError> {
+        self.diesel_store
+            .list_subscription_plans_by_merchant_id(merchant_id, limit, offset)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn update_subscription_plan_by_merchant_id_plan_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        plan_id: &str,
+        subscription_plan_update: storage::SubscriptionPlanUpdate,
+    ) -> CustomResult<storage::SubscriptionPlan, errors::StorageError> {
+        self.diesel_store
+            .update_subscription_plan_by_merchant_id_plan_id(
+                merchant_id,
+                plan_id,
+                subscription_plan_update,
+            )
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn insert_subscription(
+        &self,
+        subscription: storage::SubscriptionNew,
+    ) -> CustomResult<storage::Subscription, errors::StorageError> {
+        self.diesel_store.insert_subscription(subscription).await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_subscription_by_merchant_id_subscription_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        subscription_id: &str,
+    ) -> CustomResult<storage::Subscription, errors::StorageError> {
+        self.diesel_store
+            .find_subscription_by_merchant_id_subscription_id(merchant_id, subscription_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_subscriptions_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: Option<&common_utils::id_type::CustomerId>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::Subscription>, errors::StorageError> {
+        self.diesel_store
+            .list_subscriptions_by_merchant_id_customer_id(merchant_id, customer_id, limit, offset)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn update_subscription_by_merchant_id_subscription_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        subscription_id: &str,
+        subscription_update: storage::SubscriptionUpdate,
+    ) -> CustomResult<storage::Subscription, errors::StorageError> {
+        self.diesel_store
+            .update_subscription_by_merchant_id_subscription_id(
+                merchant_id,
+                subscription_id,
+                subscription_update,
+            )
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn insert_subscription_invoice(
+        &self,
+        subscription_invoice: storage::SubscriptionInvoiceNew,
+    ) -> CustomResult<storage::SubscriptionInvoice, errors::StorageError> {
+        self.diesel_store
+            .insert_subscription_invoice(subscription_invoice)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_subscription_invoices_by_merchant_id_subscription_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        subscription_id: &str,
+        limit: Option<i64>,
+    ) -> CustomResult<Vec<storage::SubscriptionInvoice>, errors::StorageError> {
+        self.diesel_store
+            .list_subscription_invoices_by_merchant_id_subscription_id(
+                merchant_id,
+                subscription_id,
+                limit,
+            )
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_subscription_invoices_by_subscription_id_status(
+        &self,
+        subscription_id: &str,
+        status: enums::SubscriptionInvoiceStatus,
+    ) -> CustomResult<Vec<storage::SubscriptionInvoice>, errors::StorageError> {
+        self.diesel_store
+            .find_subscription_invoices_by_subscription_id_status(subscription_id, status)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn update_subscription_invoice_by_merchant_id_invoice_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        invoice_id: &str,
+        subscription_invoice_update: storage::SubscriptionInvoiceUpdate,
+    ) -> CustomResult<storage::SubscriptionInvoice, errors::StorageError> {
+        self.diesel_store
+            .update_subscription_invoice_by_merchant_id_invoice_id(
+                merchant_id,
+                invoice_id,
+                subscription_invoice_update,
+            )
+            .await
+    }
+}
@@ -0,0 +1,171 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait UserPasskeyInterface {
+    async fn insert_user_passkey(
+        &self,
+        passkey: storage::UserPasskeyNew,
+    ) -> CustomResult<storage::UserPasskey, errors::StorageError>;
+
+    async fn list_user_passkeys_by_user_id(
+        &self,
+        user_id: &str,
+    ) -> CustomResult<Vec<storage::UserPasskey>, errors::StorageError>;
+
+    async fn update_user_passkey_by_user_id_passkey_id(
+        &self,
+        user_id: &str,
+        passkey_id: &str,
+        passkey_update: storage::UserPasskeyUpdate,
+    ) -> CustomResult<storage::UserPasskey, errors::StorageError>;
+
+    async fn delete_user_passkey_by_user_id_passkey_id(
+        &self,
+        user_id: &str,
+        passkey_id: &str,
+    ) -> CustomResult<storage::UserPasskey, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl UserPasskeyInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_user_passkey(
+        &self,
+        passkey: storage::UserPasskeyNew,
+    ) -> CustomResult<storage::UserPasskey, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        passkey
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_user_passkeys_by_user_id(
+        &self,
+        user_id: &str,
+    ) -> CustomResult<Vec<storage::UserPasskey>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::UserPasskey::list_by_user_id(&conn, user_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_user_passkey_by_user_id_passkey_id(
+        &self,
+        user_id: &str,
+        passkey_id: &str,
+        passkey_update: storage::UserPasskeyUpdate,
+    ) -> CustomResult<storage::UserPasskey, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::UserPasskey::update_by_user_id_passkey_id(
+            &conn,
+            user_id,
+            passkey_id,
+            passkey_update,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_user_passkey_by_user_id_passkey_id(
+        &self,
+        user_id: &str,
+        passkey_id: &str,
+    ) -> CustomResult<storage::UserPasskey, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::UserPasskey::delete_by_user_id_passkey_id(&conn, user_id, passkey_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl UserPasskeyInterface for MockDb {
+    async fn insert_user_passkey(
+        &self,
+        _passkey: storage::UserPasskeyNew,
+    ) -> CustomResult<storage::UserPasskey, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_user_passkeys_by_user_id(
+        &self,
+        _user_id: &str,
+    ) -> CustomResult<Vec<storage::UserPasskey>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_user_passkey_by_user_id_passkey_id(
+        &self,
+        _user_id: &str,
+        _passkey_id: &str,
+        _passkey_update: storage::UserPasskeyUpdate,
+    ) -> CustomResult<storage::UserPasskey, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn delete_user_passkey_by_user_id_passkey_id(
+        &self,
+        _user_id: &str,
+        _passkey_id: &str,
+    ) -> CustomResult<storage::UserPasskey, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl UserPasskeyInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn insert_user_passkey(
+        &self,
+        passkey: storage::UserPasskeyNew,
+    ) -> CustomResult<storage::UserPasskey, errors::StorageError> {
+        self.diesel_store.insert_user_passkey(passkey).await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_user_passkeys_by_user_id(
+        &self,
+        user_id: &str,
+    ) -> CustomResult<Vec<storage::UserPasskey>, errors::StorageError> {
+        self.diesel_store
+            .list_user_passkeys_by_user_id(user_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn update_user_passkey_by_user_id_passkey_id(
+        &self,
+        user_id: &str,
+        passkey_id: &str,
+        passkey_update: storage::UserPasskeyUpdate,
+    ) -> CustomResult<storage::UserPasskey, errors::StorageError> {
+        self.diesel_store
+            .update_user_passkey_by_user_id_passkey_id(user_id, passkey_id, passkey_update)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_user_passkey_by_user_id_passkey_id(
+        &self,
+        user_id: &str,
+        passkey_id: &str,
+    ) -> CustomResult<storage::UserPasskey, errors::StorageError> {
+        self.diesel_store
+            .delete_user_passkey_by_user_id_passkey_id(user_id, passkey_id)
+            .await
+    }
+}
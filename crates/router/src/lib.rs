@@ -75,6 +75,7 @@ pub mod headers {
     pub const X_ACCEPT_VERSION: &str = "X-Accept-Version";
     pub const X_DATE: &str = "X-Date";
     pub const X_WEBHOOK_SIGNATURE: &str = "X-Webhook-Signature-512";
+    pub const X_WEBHOOK_SIGNATURE_V2: &str = "X-Webhook-Signature-V2";
     pub const X_REQUEST_ID: &str = "X-Request-Id";
     pub const X_PROFILE_ID: &str = "X-Profile-Id";
     pub const STRIPE_COMPATIBLE_WEBHOOK_SIGNATURE: &str = "Stripe-Signature";
@@ -174,6 +175,10 @@ pub fn mk_app(
     {
         server_app = server_app.service(routes::EphemeralKey::server(state.clone()))
     }
+    #[cfg(all(feature = "oltp", feature = "v1"))]
+    {
+        server_app = server_app.service(routes::Subscriptions::server(state.clone()));
+    }
     #[cfg(all(
         feature = "oltp",
         any(feature = "v1", feature = "v2"),
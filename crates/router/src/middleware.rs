@@ -1,11 +1,39 @@
-use common_utils::consts::TENANT_HEADER;
+use common_utils::{consts::TENANT_HEADER, ext_traits::StringExt, id_type};
+use error_stack::{report, ResultExt};
 use futures::StreamExt;
 use router_env::{
     logger,
     tracing::{field::Empty, Instrument},
 };
 
-use crate::{headers, routes::metrics};
+use crate::{
+    core::errors::{ApiErrorResponse, RouterResult},
+    headers,
+    routes::{lock_utils, metrics, SessionState},
+    services::authentication::AuthenticationType,
+};
+
+/// Adds the request to the sliding window of the rate limit key, unless the number of requests
+/// in the window would exceed the limit. The number of requests in the window is approximated by
+/// weighting the count of the previous fixed window by its overlap with the sliding window.
+/// Returns `0` if the request is allowed, otherwise the approximate number of milliseconds after
+/// which a request would be allowed.
+const TAKE_API_RATE_LIMIT_SLOT_SCRIPT: &str = r#"
+local max_requests = tonumber(ARGV[1])
+local window_size = tonumber(ARGV[2])
+local elapsed = tonumber(ARGV[3])
+local current = tonumber(redis.call("GET", KEYS[1])) or 0
+local previous = tonumber(redis.call("GET", KEYS[2])) or 0
+if previous * (window_size - elapsed) / window_size + current < max_requests then
+    redis.call("INCR", KEYS[1])
+    redis.call("PEXPIRE", KEYS[1], window_size * 2)
+    return 0
+end
+if current < max_requests and previous > 0 then
+    return math.max(1, math.ceil(window_size * (1 - (max_requests - current) / previous) - elapsed))
+end
+return window_size - elapsed
+"#;
 
 /// Middleware to include request ID in response header.
 pub struct RequestId;
@@ -482,3 +510,247 @@ where
         })
     }
 }
+
+/// Merchant specific overrides of the API rate limit settings, stored in the `configs` table
+#[derive(Debug, Default, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ApiRateLimitOverride {
+    max_requests: Option<u64>,
+    window_size: Option<u64>, // in seconds
+}
+
+/// Rate limits the requests made with an API key, to each group of APIs identified by the
+/// [`lock_utils::ApiIdentifier`]. Since the merchant and the API key are known only once the
+/// request is authenticated, this is invoked by the API wrapper rather than as an actix
+/// middleware. Requests authenticated by other means are not rate limited, and requests are
+/// allowed if the limit could not be checked.
+pub async fn check_api_rate_limit(
+    state: &SessionState,
+    auth_type: &AuthenticationType,
+    api_identifier: lock_utils::ApiIdentifier,
+) -> RouterResult<()> {
+    if !state.conf.api_rate_limit.enabled {
+        return Ok(());
+    }
+    let AuthenticationType::ApiKey {
+        merchant_id,
+        key_id,
+        ..
+    } = auth_type
+    else {
+        return Ok(());
+    };
+
+    let (max_requests, window_size) = get_api_rate_limit(state, merchant_id).await;
+    let rate_limit_key = get_api_rate_limit_key(merchant_id, key_id, &api_identifier);
+
+    match take_api_rate_limit_slot(state, &rate_limit_key, max_requests, window_size).await {
+        Ok(None) => Ok(()),
+        Ok(Some(retry_after)) => {
+            metrics::API_RATE_LIMIT_EXCEEDED_COUNT.add(
+                1,
+                router_env::metric_attributes!(("api", api_identifier.to_string())),
+            );
+            Err(report!(ApiErrorResponse::RateLimitExceeded { retry_after }))
+        }
+        Err(error) => {
+            logger::error!(?error, %rate_limit_key, "Failed to check the API rate limit");
+            Ok(())
+        }
+    }
+}
+
+/// Returns the maximum number of requests and the window size in seconds applicable to the
+/// merchant, falling back to the configured settings for the values which are not overridden.
+async fn get_api_rate_limit(state: &SessionState, merchant_id: &id_type::MerchantId) -> (u64, u64) {
+    let settings = &state.conf.api_rate_limit;
+
+    // The default value is cached along with the configs, so that merchants without overrides do
+    // not query the database on every request
+    let rate_limit_override = state
+        .store
+        .find_config_by_key_unwrap_or(
+            &merchant_id.get_api_rate_limit_key(),
+            Some("{}".to_string()),
+        )
+        .await
+        .change_context(ApiErrorResponse::InternalServerError)
+        .and_then(|config| parse_api_rate_limit_override(config.config))
+        .map_err(|error| {
+            logger::error!(
+                ?error,
+                "Failed to fetch the API rate limit override of the merchant"
+            );
+        })
+        .unwrap_or_default();
+
+    rate_limit_override.apply(settings)
+}
+
+fn parse_api_rate_limit_override(config: String) -> RouterResult<ApiRateLimitOverride> {
+    config
+        .parse_struct::<ApiRateLimitOverride>("ApiRateLimitOverride")
+        .change_context(ApiErrorResponse::InternalServerError)
+}
+
+impl ApiRateLimitOverride {
+    /// Returns the maximum number of requests and the window size in seconds, using the settings
+    /// for the values which are not overridden or are overridden with `0`.
+    fn apply(self, settings: &crate::configs::settings::ApiRateLimitSettings) -> (u64, u64) {
+        (
+            self.max_requests
+                .filter(|max_requests| *max_requests > 0)
+                .unwrap_or(settings.max_requests),
+            self.window_size
+                .filter(|window_size| *window_size > 0)
+                .unwrap_or(settings.window_size),
+        )
+    }
+}
+
+/// The rate limit is tracked separately for each API key of the merchant and each group of APIs.
+fn get_api_rate_limit_key(
+    merchant_id: &id_type::MerchantId,
+    key_id: &id_type::ApiKeyId,
+    api_identifier: &lock_utils::ApiIdentifier,
+) -> String {
+    format!(
+        "{}_{}_{api_identifier}",
+        merchant_id.get_string_repr(),
+        key_id.get_string_repr()
+    )
+}
+
+/// Returns the keys holding the request counts of the current and the previous fixed windows.
+fn get_api_rate_limit_window_keys(rate_limit_key: &str, window: u64) -> (String, String) {
+    (
+        format!("API_RATE_LIMIT_{rate_limit_key}_{window}"),
+        format!(
+            "API_RATE_LIMIT_{rate_limit_key}_{}",
+            window.saturating_sub(1)
+        ),
+    )
+}
+
+/// Returns the number of seconds after which the request can be retried, if the rate limit has
+/// been exceeded.
+async fn take_api_rate_limit_slot(
+    state: &SessionState,
+    rate_limit_key: &str,
+    max_requests: u64,
+    window_size: u64,
+) -> RouterResult<Option<u64>> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    let window_size_in_milliseconds = window_size.saturating_mul(1000);
+    let now = u64::try_from(time::OffsetDateTime::now_utc().unix_timestamp_nanos() / 1_000_000)
+        .change_context(ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get the current timestamp in milliseconds")?;
+    let (current_window_key, previous_window_key) =
+        get_api_rate_limit_window_keys(rate_limit_key, now / window_size_in_milliseconds);
+
+    // Keys passed to scripts are not prefixed with the tenant by the connection pool
+    let retry_after_in_milliseconds: u64 = redis_conn
+        .evaluate_redis_script(
+            TAKE_API_RATE_LIMIT_SLOT_SCRIPT,
+            vec![
+                redis_conn.add_prefix(&current_window_key),
+                redis_conn.add_prefix(&previous_window_key),
+            ],
+            vec![
+                max_requests.to_string(),
+                window_size_in_milliseconds.to_string(),
+                (now % window_size_in_milliseconds).to_string(),
+            ],
+        )
+        .await
+        .change_context(ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to evaluate the API rate limit script")?;
+
+    Ok((retry_after_in_milliseconds > 0).then(|| retry_after_in_milliseconds.div_ceil(1000)))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use std::borrow::Cow;
+
+    use super::*;
+    use crate::configs::settings::ApiRateLimitSettings;
+
+    fn get_settings() -> ApiRateLimitSettings {
+        ApiRateLimitSettings {
+            enabled: true,
+            max_requests: 100,
+            window_size: 60,
+        }
+    }
+
+    #[test]
+    fn test_parse_api_rate_limit_override() {
+        let rate_limit_override = parse_api_rate_limit_override("{}".to_string()).unwrap();
+        assert_eq!(rate_limit_override.max_requests, None);
+        assert_eq!(rate_limit_override.window_size, None);
+
+        let rate_limit_override =
+            parse_api_rate_limit_override(r#"{"max_requests":10,"window_size":1}"#.to_string())
+                .unwrap();
+        assert_eq!(rate_limit_override.max_requests, Some(10));
+        assert_eq!(rate_limit_override.window_size, Some(1));
+
+        assert!(parse_api_rate_limit_override(r#"{"max_request":10}"#.to_string()).is_err());
+        assert!(parse_api_rate_limit_override(r#"{"max_requests":-1}"#.to_string()).is_err());
+        assert!(parse_api_rate_limit_override("10".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_apply_api_rate_limit_override() {
+        let settings = get_settings();
+        let cases = [
+            (None, None, (100, 60)),
+            (Some(10), None, (10, 60)),
+            (None, Some(1), (100, 1)),
+            (Some(10), Some(1), (10, 1)),
+            (Some(0), Some(0), (100, 60)),
+        ];
+
+        for (max_requests, window_size, expected) in cases {
+            let rate_limit_override = ApiRateLimitOverride {
+                max_requests,
+                window_size,
+            };
+            assert_eq!(rate_limit_override.apply(&settings), expected);
+        }
+    }
+
+    #[test]
+    fn test_get_api_rate_limit_key() {
+        let merchant_id = id_type::MerchantId::try_from(Cow::from("merchant_1")).unwrap();
+        let key_id = id_type::ApiKeyId::try_from(Cow::from("key_1")).unwrap();
+
+        assert_eq!(
+            get_api_rate_limit_key(&merchant_id, &key_id, &lock_utils::ApiIdentifier::Payments),
+            "merchant_1_key_1_payments"
+        );
+        assert_eq!(
+            merchant_id.get_api_rate_limit_key(),
+            "api_rate_limit_merchant_1"
+        );
+    }
+
+    #[test]
+    fn test_get_api_rate_limit_window_keys() {
+        assert_eq!(
+            get_api_rate_limit_window_keys("merchant_1_key_1_payments", 10),
+            (
+                "API_RATE_LIMIT_merchant_1_key_1_payments_10".to_string(),
+                "API_RATE_LIMIT_merchant_1_key_1_payments_9".to_string()
+            )
+        );
+    }
+}
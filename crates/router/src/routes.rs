@@ -47,6 +47,8 @@ pub mod recon;
 pub mod refunds;
 #[cfg(feature = "olap")]
 pub mod routing;
+#[cfg(all(feature = "oltp", feature = "v1"))]
+pub mod subscriptions;
 pub mod tokenization;
 #[cfg(feature = "olap")]
 pub mod user;
@@ -57,6 +59,8 @@ pub mod verification;
 #[cfg(feature = "olap")]
 pub mod verify_connector;
 #[cfg(all(feature = "olap", feature = "v1"))]
+pub mod webhook_endpoints;
+#[cfg(all(feature = "olap", feature = "v1"))]
 pub mod webhook_events;
 pub mod webhooks;
 
@@ -85,8 +89,8 @@ pub use self::app::{
     ApiKeys, AppState, ApplePayCertificatesMigration, Cache, Cards, Configs, ConnectorOnboarding,
     Customers, Disputes, EphemeralKey, FeatureMatrix, Files, Forex, Gsm, Health, Hypersense,
     Mandates, MerchantAccount, MerchantConnectorAccount, PaymentLink, PaymentMethods, Payments,
-    Poll, ProcessTracker, Profile, ProfileNew, Refunds, Relay, RelayWebhooks, SessionState, User,
-    Webhooks,
+    Poll, ProcessTracker, Profile, ProfileNew, Refunds, Relay, RelayWebhooks, SessionState,
+    Subscriptions, User, Webhooks,
 };
 #[cfg(feature = "olap")]
 pub use self::app::{Blocklist, Organization, Routing, Verify, WebhookEvents};
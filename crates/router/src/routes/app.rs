@@ -55,10 +55,14 @@ use super::recovery_webhooks::*;
 use super::refunds;
 #[cfg(feature = "olap")]
 use super::routing;
+#[cfg(all(feature = "oltp", feature = "v1"))]
+use super::subscriptions;
 #[cfg(all(feature = "oltp", feature = "v2"))]
 use super::tokenization as tokenization_routes;
 #[cfg(all(feature = "olap", feature = "v1"))]
 use super::verification::{apple_pay_merchant_registration, retrieve_apple_pay_verified_domains};
+#[cfg(all(feature = "olap", feature = "v1"))]
+use super::webhook_endpoints;
 #[cfg(feature = "oltp")]
 use super::webhooks::*;
 use super::{
@@ -828,6 +832,10 @@ impl Forex {
             .service(
                 web::resource("/convert_from_minor").route(web::get().to(currency::convert_forex)),
             )
+            .service(
+                web::resource("/convert_from_minor_with_rounding")
+                    .route(web::get().to(currency::convert_forex_with_rounding)),
+            )
     }
 }
 
@@ -921,7 +929,8 @@ impl Routing {
                 web::resource("/default/profile").route(web::get().to(|state, req| {
                     routing::routing_retrieve_default_config(state, req, &TransactionType::Payment)
                 })),
-            );
+            )
+            .service(web::resource("/simulate").route(web::post().to(routing::routing_simulate)));
 
         #[cfg(feature = "payouts")]
         {
@@ -1213,7 +1222,16 @@ pub struct Payouts;
 impl Payouts {
     pub fn server(state: AppState) -> Scope {
         let mut route = web::scope("/payouts").app_data(web::Data::new(state));
-        route = route.service(web::resource("/create").route(web::post().to(payouts_create)));
+        route = route
+            .service(web::resource("/create").route(web::post().to(payouts_create)))
+            .service(web::resource("/batch").route(web::post().to(payouts_batch_create)))
+            .service(
+                web::resource("/batch/{batch_id}").route(web::get().to(payouts_batch_retrieve)),
+            )
+            .service(
+                web::resource("/batch/{batch_id}/report")
+                    .route(web::get().to(payouts_batch_report)),
+            );
 
         #[cfg(feature = "olap")]
         {
@@ -1321,6 +1339,22 @@ impl PaymentMethods {
                     web::resource("/migrate-batch")
                         .route(web::post().to(payment_methods::migrate_payment_methods)),
                 )
+                .service(
+                    web::resource("/migrate-job")
+                        .route(web::post().to(payment_methods::migration_job_create)),
+                )
+                .service(
+                    web::resource("/migrate-job/{merchant_id}/{job_id}")
+                        .route(web::get().to(payment_methods::migration_job_retrieve)),
+                )
+                .service(
+                    web::resource("/migrate-job/{merchant_id}/{job_id}/execute")
+                        .route(web::post().to(payment_methods::migration_job_execute)),
+                )
+                .service(
+                    web::resource("/migrate-job/{merchant_id}/{job_id}/report")
+                        .route(web::get().to(payment_methods::migration_job_report)),
+                )
                 .service(
                     web::resource("/tokenize-card")
                         .route(web::post().to(payment_methods::tokenize_card_api)),
@@ -1337,6 +1371,10 @@ impl PaymentMethods {
                     web::resource("/collect/{merchant_id}/{collect_id}")
                         .route(web::get().to(payment_methods::render_pm_collect_link)),
                 )
+                .service(
+                    web::resource("/account_updater/toggle")
+                        .route(web::post().to(payment_methods::toggle_card_account_updater_api)),
+                )
                 .service(
                     web::resource("/{payment_method_id}")
                         .route(web::get().to(payment_methods::payment_method_retrieve_api))
@@ -1687,6 +1725,50 @@ impl Mandates {
     }
 }
 
+pub struct Subscriptions;
+
+#[cfg(all(feature = "oltp", feature = "v1"))]
+impl Subscriptions {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/subscriptions")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("")
+                    .route(web::post().to(subscriptions::subscription_create))
+                    .route(web::get().to(subscriptions::subscription_list)),
+            )
+            .service(
+                web::scope("/plans")
+                    .service(
+                        web::resource("")
+                            .route(web::post().to(subscriptions::subscription_plan_create))
+                            .route(web::get().to(subscriptions::subscription_plan_list)),
+                    )
+                    .service(
+                        web::resource("/{plan_id}")
+                            .route(web::get().to(subscriptions::subscription_plan_retrieve))
+                            .route(web::post().to(subscriptions::subscription_plan_update)),
+                    ),
+            )
+            .service(
+                web::scope("/{subscription_id}")
+                    .service(
+                        web::resource("")
+                            .route(web::get().to(subscriptions::subscription_retrieve))
+                            .route(web::post().to(subscriptions::subscription_update)),
+                    )
+                    .service(
+                        web::resource("/cancel")
+                            .route(web::post().to(subscriptions::subscription_cancel)),
+                    )
+                    .service(
+                        web::resource("/invoices")
+                            .route(web::get().to(subscriptions::subscription_invoice_list)),
+                    ),
+            )
+    }
+}
+
 pub struct Webhooks;
 
 #[cfg(all(feature = "oltp", feature = "v1"))]
@@ -2117,6 +2199,29 @@ impl Profile {
                 .service(
                     web::resource("/toggle_connector_agnostic_mit")
                         .route(web::post().to(profiles::toggle_connector_agnostic_mit)),
+                )
+                .service(
+                    web::resource("/rotate_webhook_secret")
+                        .route(web::post().to(profiles::rotate_webhook_secret)),
+                )
+                .service(
+                    web::scope("/webhook_endpoints")
+                        .service(
+                            web::resource("")
+                                .route(web::post().to(webhook_endpoints::create_webhook_endpoint))
+                                .route(web::get().to(webhook_endpoints::list_webhook_endpoints)),
+                        )
+                        .service(
+                            web::resource("/{endpoint_id}")
+                                .route(web::get().to(webhook_endpoints::retrieve_webhook_endpoint))
+                                .route(web::post().to(webhook_endpoints::update_webhook_endpoint))
+                                .route(
+                                    web::delete().to(webhook_endpoints::delete_webhook_endpoint),
+                                ),
+                        )
+                        .service(web::resource("/{endpoint_id}/rotate_secret").route(
+                            web::post().to(webhook_endpoints::rotate_webhook_endpoint_secret),
+                        )),
                 ),
         );
 
@@ -2232,6 +2337,13 @@ impl User {
             .service(web::resource("").route(web::get().to(user::get_user_details)))
             .service(web::resource("/signin").route(web::post().to(user::user_signin)))
             .service(web::resource("/v2/signin").route(web::post().to(user::user_signin)))
+            .service(
+                web::scope("/signin/passkey")
+                    .service(web::resource("").route(web::post().to(user::passkey_signin)))
+                    .service(
+                        web::resource("/begin").route(web::post().to(user::passkey_signin_begin)),
+                    ),
+            )
             // signin/signup with sso using openidconnect
             .service(web::resource("/oidc").route(web::post().to(user::sso_sign)))
             .service(web::resource("/signout").route(web::post().to(user::signout)))
@@ -2336,6 +2448,26 @@ impl User {
                                 .route(web::get().to(user::generate_recovery_codes)),
                         ),
                 )
+                .service(
+                    web::scope("/passkey")
+                        .service(
+                            web::resource("/register/begin")
+                                .route(web::get().to(user::passkey_registration_begin)),
+                        )
+                        .service(
+                            web::resource("/register/finish")
+                                .route(web::post().to(user::passkey_registration_finish)),
+                        )
+                        .service(web::resource("/begin").route(web::get().to(user::passkey_begin)))
+                        .service(
+                            web::resource("/verify").route(web::post().to(user::passkey_verify)),
+                        )
+                        .service(web::resource("/list").route(web::get().to(user::list_passkeys)))
+                        .service(
+                            web::resource("/{passkey_id}")
+                                .route(web::delete().to(user::delete_passkey)),
+                        ),
+                )
                 .service(
                     web::resource("/terminate")
                         .route(web::get().to(user::terminate_two_factor_auth)),
@@ -2562,6 +2694,17 @@ impl WebhookEvents {
                     .service(web::resource("").route(
                         web::post().to(webhook_events::list_initial_webhook_delivery_attempts),
                     ))
+                    .service(
+                        web::scope("/replay")
+                            .service(
+                                web::resource("").route(
+                                    web::post().to(webhook_events::create_webhook_event_replay),
+                                ),
+                            )
+                            .service(web::resource("/{replay_id}").route(
+                                web::get().to(webhook_events::retrieve_webhook_event_replay),
+                            )),
+                    )
                     .service(
                         web::scope("/{event_id}")
                             .service(web::resource("attempts").route(
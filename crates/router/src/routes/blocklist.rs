@@ -12,7 +12,7 @@ use crate::{
 #[utoipa::path(
     post,
     path = "/blocklist",
-    request_body = BlocklistRequest,
+    request_body = AddToBlocklistRequest,
     responses(
         (status = 200, description = "Fingerprint Blocked", body = BlocklistResponse),
         (status = 400, description = "Invalid Data")
@@ -27,6 +27,7 @@ pub async fn add_entry_to_blocklist(
     json_payload: web::Json<api_blocklist::AddToBlocklistRequest>,
 ) -> HttpResponse {
     let flow = Flow::AddToBlocklist;
+    let headers = req.headers();
     Box::pin(api::server_wrap(
         flow,
         state,
@@ -36,7 +37,7 @@ pub async fn add_entry_to_blocklist(
             let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
                 domain::Context(auth.merchant_account, auth.key_store),
             ));
-            blocklist::add_entry_to_blocklist(state, merchant_context, body)
+            blocklist::add_entry_to_blocklist(state, merchant_context, body, headers)
         },
         auth::auth_type(
             &auth::HeaderAuth(auth::ApiKeyAuth {
@@ -101,7 +102,10 @@ pub async fn remove_entry_from_blocklist(
     get,
     path = "/blocklist",
     params (
-        ("data_kind" = BlocklistDataKind, Query, description = "Kind of the fingerprint list requested"),
+        ("data_kind" = Option<BlocklistDataKind>, Query, description = "Kind of the fingerprint list requested"),
+        ("status" = Option<BlocklistEntryStatus>, Query, description = "Status of the entries requested"),
+        ("limit" = Option<u16>, Query, description = "The maximum number of entries to include in the response"),
+        ("offset" = Option<u16>, Query, description = "The number of entries to skip"),
     ),
     responses(
         (status = 200, description = "Blocked Fingerprints", body = BlocklistResponse),
@@ -64,3 +64,32 @@ pub async fn convert_forex(
     ))
     .await
 }
+
+#[cfg(feature = "v1")]
+pub async fn convert_forex_with_rounding(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    params: web::Query<api_models::currency::RoundedCurrencyConversionParams>,
+) -> HttpResponse {
+    let flow = Flow::RetrieveForexFlow;
+    let params = params.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state.clone(),
+        &req,
+        (),
+        |state, _: auth::AuthenticationData, _, _| {
+            currency::convert_forex_with_rounding(state, params.clone())
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::DashboardNoPermissionAuth,
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
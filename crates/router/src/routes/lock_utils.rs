@@ -17,6 +17,7 @@ pub enum ApiIdentifier {
     PaymentMethods,
     PaymentMethodAuth,
     Payouts,
+    Subscriptions,
     Disputes,
     CardsInfo,
     Files,
@@ -45,6 +46,8 @@ pub enum ApiIdentifier {
     ProcessTracker,
     Proxy,
     GenericTokenization,
+    Analytics,
+    DummyConnector,
 }
 
 impl From<Flow> for ApiIdentifier {
@@ -72,6 +75,7 @@ impl From<Flow> for ApiIdentifier {
             | Flow::RoutingUpdateConfig
             | Flow::RoutingUpdateDefaultConfig
             | Flow::RoutingDeleteConfig
+            | Flow::RoutingSimulate
             | Flow::DecisionManagerDeleteConfig
             | Flow::DecisionManagerRetrieveConfig
             | Flow::ToggleDynamicRouting
@@ -112,6 +116,10 @@ impl From<Flow> for ApiIdentifier {
 
             Flow::PaymentMethodsCreate
             | Flow::PaymentMethodsMigrate
+            | Flow::PaymentMethodsMigrationJobCreate
+            | Flow::PaymentMethodsMigrationJobExecute
+            | Flow::PaymentMethodsMigrationJobRetrieve
+            | Flow::PaymentMethodsMigrationJobReport
             | Flow::PaymentMethodsList
             | Flow::CustomerPaymentMethodsList
             | Flow::GetPaymentMethodTokenData
@@ -123,7 +131,8 @@ impl From<Flow> for ApiIdentifier {
             | Flow::ListCountriesCurrencies
             | Flow::DefaultPaymentMethodsSet
             | Flow::PaymentMethodSave
-            | Flow::TotalPaymentMethodCount => Self::PaymentMethods,
+            | Flow::TotalPaymentMethodCount
+            | Flow::ToggleCardAccountUpdater => Self::PaymentMethods,
 
             Flow::PmAuthLinkTokenCreate | Flow::PmAuthExchangeToken => Self::PaymentMethodAuth,
 
@@ -169,8 +178,22 @@ impl From<Flow> for ApiIdentifier {
             | Flow::PayoutsFilter
             | Flow::PayoutsAccounts
             | Flow::PayoutsConfirm
+            | Flow::PayoutsBatchCreate
+            | Flow::PayoutsBatchRetrieve
+            | Flow::PayoutsBatchReport
             | Flow::PayoutLinkInitiate => Self::Payouts,
 
+            Flow::SubscriptionPlanCreate
+            | Flow::SubscriptionPlanRetrieve
+            | Flow::SubscriptionPlanList
+            | Flow::SubscriptionPlanUpdate
+            | Flow::SubscriptionCreate
+            | Flow::SubscriptionRetrieve
+            | Flow::SubscriptionList
+            | Flow::SubscriptionUpdate
+            | Flow::SubscriptionCancel
+            | Flow::SubscriptionInvoiceList => Self::Subscriptions,
+
             Flow::RefundsCreate
             | Flow::RefundsRetrieve
             | Flow::RefundsRetrieveForceSync
@@ -187,6 +210,14 @@ impl From<Flow> for ApiIdentifier {
             | Flow::WebhookEventInitialDeliveryAttemptList
             | Flow::WebhookEventDeliveryAttemptList
             | Flow::WebhookEventDeliveryRetry
+            | Flow::WebhookEventReplay
+            | Flow::WebhookEventReplayRetrieve
+            | Flow::WebhookEndpointCreate
+            | Flow::WebhookEndpointRetrieve
+            | Flow::WebhookEndpointList
+            | Flow::WebhookEndpointUpdate
+            | Flow::WebhookEndpointDelete
+            | Flow::WebhookEndpointSecretRotate
             | Flow::RecoveryIncomingWebhookReceive => Self::Webhooks,
 
             Flow::ApiKeyCreate
@@ -219,7 +250,8 @@ impl From<Flow> for ApiIdentifier {
             | Flow::ProfileDelete
             | Flow::ProfileList
             | Flow::ToggleExtendedCardInfo
-            | Flow::ToggleConnectorAgnosticMit => Self::Profile,
+            | Flow::ToggleConnectorAgnosticMit
+            | Flow::ProfileWebhookSecretRotate => Self::Profile,
 
             Flow::PaymentLinkRetrieve
             | Flow::PaymentLinkInitiate
@@ -275,6 +307,14 @@ impl From<Flow> for ApiIdentifier {
             | Flow::RecoveryCodesGenerate
             | Flow::TerminateTwoFactorAuth
             | Flow::TwoFactorAuthStatus
+            | Flow::PasskeyRegistrationBegin
+            | Flow::PasskeyRegistrationFinish
+            | Flow::PasskeyBegin
+            | Flow::PasskeyVerify
+            | Flow::PasskeyList
+            | Flow::PasskeyDelete
+            | Flow::PasskeySignInBegin
+            | Flow::PasskeySignIn
             | Flow::CreateUserAuthenticationMethod
             | Flow::UpdateUserAuthenticationMethod
             | Flow::ListUserAuthenticationMethods
@@ -348,3 +388,17 @@ impl From<Flow> for ApiIdentifier {
         }
     }
 }
+
+#[cfg(feature = "olap")]
+impl From<analytics::AnalyticsFlow> for ApiIdentifier {
+    fn from(_flow: analytics::AnalyticsFlow) -> Self {
+        Self::Analytics
+    }
+}
+
+#[cfg(feature = "dummy_connector")]
+impl From<super::dummy_connector::types::Flow> for ApiIdentifier {
+    fn from(_flow: super::dummy_connector::types::Flow) -> Self {
+        Self::DummyConnector
+    }
+}
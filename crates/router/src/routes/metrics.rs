@@ -11,6 +11,7 @@ counter_metric!(KV_MISS, GLOBAL_METER); // No. of KV misses
 // API Level Metrics
 counter_metric!(REQUESTS_RECEIVED, GLOBAL_METER);
 histogram_metric_f64!(REQUEST_TIME, GLOBAL_METER);
+counter_metric!(API_RATE_LIMIT_EXCEEDED_COUNT, GLOBAL_METER); // No. of requests rejected by the API rate limiter
 
 // Operation Level Metrics
 counter_metric!(PAYMENT_OPS_COUNT, GLOBAL_METER);
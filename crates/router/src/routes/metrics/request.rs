@@ -11,5 +11,6 @@ pub fn track_response_status_code<Q>(response: &ApplicationResponse<Q>) -> i64 {
         | ApplicationResponse::FileData(_)
         | ApplicationResponse::JsonWithHeaders(_) => 200,
         ApplicationResponse::JsonForRedirection(_) => 302,
+        ApplicationResponse::StoredResponse(response) => response.status_code.into(),
     }
 }
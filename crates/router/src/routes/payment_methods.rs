@@ -399,6 +399,160 @@ pub async fn migrate_payment_methods(
     .await
 }
 
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentMethodsMigrationJobCreate))]
+pub async fn migration_job_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    MultipartForm(form): MultipartForm<
+        payment_methods_routes::migration_job::PaymentMethodMigrationJobForm,
+    >,
+) -> HttpResponse {
+    let flow = Flow::PaymentMethodsMigrationJobCreate;
+    let payload = match payment_methods_routes::migration_job::get_migration_job_form_request(form)
+    {
+        Ok(payload) => payload,
+        Err(error) => return api::log_and_return_error_response(error.into()),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, req, _| async move {
+            let (key_store, merchant_account) =
+                get_merchant_account(&state, &req.merchant_id).await?;
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(merchant_account, key_store),
+            ));
+            payment_methods_routes::migration_job::create_migration_job(
+                state,
+                merchant_context,
+                req,
+            )
+            .await
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentMethodsMigrationJobExecute))]
+pub async fn migration_job_execute(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(id_type::MerchantId, String)>,
+) -> HttpResponse {
+    let flow = Flow::PaymentMethodsMigrationJobExecute;
+    let (merchant_id, job_id) = path.into_inner();
+    let payload = payment_methods::PaymentMethodMigrationJobRequest {
+        merchant_id,
+        job_id,
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, req, _| async move {
+            let (key_store, merchant_account) =
+                get_merchant_account(&state, &req.merchant_id).await?;
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(merchant_account, key_store),
+            ));
+            payment_methods_routes::migration_job::execute_migration_job(
+                state,
+                merchant_context,
+                req,
+            )
+            .await
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentMethodsMigrationJobRetrieve))]
+pub async fn migration_job_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(id_type::MerchantId, String)>,
+) -> HttpResponse {
+    let flow = Flow::PaymentMethodsMigrationJobRetrieve;
+    let (merchant_id, job_id) = path.into_inner();
+    let payload = payment_methods::PaymentMethodMigrationJobRequest {
+        merchant_id,
+        job_id,
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, req, _| async move {
+            let (key_store, merchant_account) =
+                get_merchant_account(&state, &req.merchant_id).await?;
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(merchant_account, key_store),
+            ));
+            payment_methods_routes::migration_job::retrieve_migration_job(
+                state,
+                merchant_context,
+                req,
+            )
+            .await
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentMethodsMigrationJobReport))]
+pub async fn migration_job_report(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(id_type::MerchantId, String)>,
+) -> HttpResponse {
+    let flow = Flow::PaymentMethodsMigrationJobReport;
+    let (merchant_id, job_id) = path.into_inner();
+    let payload = payment_methods::PaymentMethodMigrationJobRequest {
+        merchant_id,
+        job_id,
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, req, _| async move {
+            let (key_store, merchant_account) =
+                get_merchant_account(&state, &req.merchant_id).await?;
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(merchant_account, key_store),
+            ));
+            payment_methods_routes::migration_job::get_migration_job_report(
+                state,
+                merchant_context,
+                req,
+            )
+            .await
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 #[cfg(all(
     any(feature = "v1", feature = "v2"),
     not(feature = "payment_methods_v2")
@@ -1003,6 +1157,44 @@ pub async fn default_payment_method_set_api(
     .await
 }
 
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::ToggleCardAccountUpdater))]
+pub async fn toggle_card_account_updater_api(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query_payload: web::Query<payment_methods::ToggleCardAccountUpdaterQuery>,
+) -> HttpResponse {
+    let flow = Flow::ToggleCardAccountUpdater;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, query, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            payment_methods_routes::account_updater::toggle_card_account_updater(
+                state,
+                merchant_context,
+                query,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::MerchantAccountWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 #[cfg(test)]
 mod tests {
     #![allow(clippy::unwrap_used)]
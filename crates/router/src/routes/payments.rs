@@ -44,6 +44,12 @@ pub async fn payments_create(
         return api::log_and_return_error_response(err.into());
     };
 
+    let idempotency_input =
+        match api_locking::IdempotencyInput::from_headers(req.headers(), &payload, flow.clone()) {
+            Ok(idempotency_input) => idempotency_input,
+            Err(err) => return api::log_and_return_error_response(err),
+        };
+
     if let Some(api_enums::CaptureMethod::Scheduled) = payload.capture_method {
         return http_not_implemented();
     };
@@ -72,7 +78,9 @@ pub async fn payments_create(
             .unwrap_or_default(),
     );
 
-    let locking_action = payload.get_locking_input(flow.clone());
+    let locking_action = payload
+        .get_locking_input(flow.clone())
+        .with_idempotency(idempotency_input);
 
     Box::pin(api::server_wrap(
         flow,
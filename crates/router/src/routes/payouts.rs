@@ -1,3 +1,5 @@
+#[cfg(feature = "v1")]
+use actix_multipart::form::MultipartForm;
 use actix_web::{
     body::{BoxBody, MessageBody},
     web, HttpRequest, HttpResponse, Responder,
@@ -23,12 +25,18 @@ pub async fn payouts_create(
     json_payload: web::Json<payout_types::PayoutCreateRequest>,
 ) -> HttpResponse {
     let flow = Flow::PayoutsCreate;
+    let payload = json_payload.into_inner();
+    let idempotency_input =
+        match api_locking::IdempotencyInput::from_headers(req.headers(), &payload, flow.clone()) {
+            Ok(idempotency_input) => idempotency_input,
+            Err(err) => return api::log_and_return_error_response(err),
+        };
 
     Box::pin(api::server_wrap(
         flow,
         state,
         &req,
-        json_payload.into_inner(),
+        payload,
         |state, auth: auth::AuthenticationData, req, _| {
             let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
                 domain::Context(auth.merchant_account, auth.key_store),
@@ -39,7 +47,7 @@ pub async fn payouts_create(
             is_connected_allowed: false,
             is_platform_allowed: false,
         }),
-        api_locking::LockAction::NotApplicable,
+        api_locking::LockAction::NotApplicable.with_idempotency(idempotency_input),
     ))
     .await
 }
@@ -455,6 +463,118 @@ pub async fn payouts_list_available_filters_for_profile(
     .await
 }
 
+/// Payouts - Batch Create
+#[cfg(all(feature = "v1", feature = "payouts"))]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutsBatchCreate))]
+pub async fn payouts_batch_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    MultipartForm(form): MultipartForm<batch::PayoutBatchForm>,
+) -> HttpResponse {
+    let flow = Flow::PayoutsBatchCreate;
+    let payload = match batch::get_payout_batch_form_request(form) {
+        Ok(payload) => payload,
+        Err(error) => return api::log_and_return_error_response(error.into()),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            batch::create_payout_batch(state, merchant_context, req)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Payouts - Batch Retrieve
+#[cfg(all(feature = "v1", feature = "payouts"))]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutsBatchRetrieve))]
+pub async fn payouts_batch_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::PayoutsBatchRetrieve;
+    let payload = payout_types::PayoutBatchRetrieveRequest {
+        batch_id: path.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            batch::retrieve_payout_batch(state, merchant_context, req)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::MerchantPayoutRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Payouts - Batch Report
+#[cfg(all(feature = "v1", feature = "payouts"))]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutsBatchReport))]
+pub async fn payouts_batch_report(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::PayoutsBatchReport;
+    let payload = payout_types::PayoutBatchRetrieveRequest {
+        batch_id: path.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            batch::get_payout_batch_report(state, merchant_context, req)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::MerchantPayoutRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 #[instrument(skip_all, fields(flow = ?Flow::PayoutsAccounts))]
 // #[get("/accounts")]
 pub async fn payouts_accounts() -> impl Responder {
@@ -68,11 +68,18 @@ pub async fn refunds_create(
     json_payload: web::Json<refunds::RefundRequest>,
 ) -> HttpResponse {
     let flow = Flow::RefundsCreate;
+    let payload = json_payload.into_inner();
+    let idempotency_input =
+        match api_locking::IdempotencyInput::from_headers(req.headers(), &payload, flow.clone()) {
+            Ok(idempotency_input) => idempotency_input,
+            Err(err) => return api::log_and_return_error_response(err),
+        };
+
     Box::pin(api::server_wrap(
         flow,
         state,
         &req,
-        json_payload.into_inner(),
+        payload,
         |state, auth: auth::AuthenticationData, req, _| {
             let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
                 domain::Context(auth.merchant_account, auth.key_store),
@@ -89,7 +96,7 @@ pub async fn refunds_create(
             },
             req.headers(),
         ),
-        api_locking::LockAction::NotApplicable,
+        api_locking::LockAction::NotApplicable.with_idempotency(idempotency_input),
     ))
     .await
 }
@@ -107,6 +114,11 @@ pub async fn refunds_create(
     let global_refund_id =
         common_utils::id_type::GlobalRefundId::generate(&state.conf.cell_information.id);
     let payload = json_payload.into_inner();
+    let idempotency_input =
+        match api_locking::IdempotencyInput::from_headers(req.headers(), &payload, flow.clone()) {
+            Ok(idempotency_input) => idempotency_input,
+            Err(err) => return api::log_and_return_error_response(err),
+        };
 
     let internal_refund_create_payload =
         internal_payload_types::RefundsGenericRequestWithResourceId {
@@ -141,7 +153,7 @@ pub async fn refunds_create(
             },
             req.headers(),
         ),
-        api_locking::LockAction::NotApplicable,
+        api_locking::LockAction::NotApplicable.with_idempotency(idempotency_input),
     ))
     .await
 }
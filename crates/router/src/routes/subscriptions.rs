@@ -0,0 +1,308 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use api_models::subscriptions as subscription_types;
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::{api_locking, subscriptions::*},
+    services::{api, authentication as auth},
+    types::domain,
+};
+
+/// Subscription Plans - Create
+#[instrument(skip_all, fields(flow = ?Flow::SubscriptionPlanCreate))]
+pub async fn subscription_plan_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<subscription_types::SubscriptionPlanCreateRequest>,
+) -> HttpResponse {
+    let flow = Flow::SubscriptionPlanCreate;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            create_subscription_plan(state, merchant_context, req)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Subscription Plans - Retrieve
+#[instrument(skip_all, fields(flow = ?Flow::SubscriptionPlanRetrieve))]
+pub async fn subscription_plan_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::SubscriptionPlanRetrieve;
+    let payload = subscription_types::SubscriptionPlanRetrieveRequest {
+        plan_id: path.into_inner(),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            retrieve_subscription_plan(state, merchant_context, req)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Subscription Plans - List
+#[instrument(skip_all, fields(flow = ?Flow::SubscriptionPlanList))]
+pub async fn subscription_plan_list(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query_payload: web::Query<subscription_types::SubscriptionPlanListConstraints>,
+) -> HttpResponse {
+    let flow = Flow::SubscriptionPlanList;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            list_subscription_plans(state, merchant_context, req)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Subscription Plans - Update
+#[instrument(skip_all, fields(flow = ?Flow::SubscriptionPlanUpdate))]
+pub async fn subscription_plan_update(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<subscription_types::SubscriptionPlanUpdateRequest>,
+) -> HttpResponse {
+    let flow = Flow::SubscriptionPlanUpdate;
+    let mut payload = json_payload.into_inner();
+    payload.plan_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            update_subscription_plan(state, merchant_context, req)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Subscriptions - Create
+#[instrument(skip_all, fields(flow = ?Flow::SubscriptionCreate))]
+pub async fn subscription_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<subscription_types::SubscriptionCreateRequest>,
+) -> HttpResponse {
+    let flow = Flow::SubscriptionCreate;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, req_state| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            create_subscription(state, req_state, merchant_context, req)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Subscriptions - Retrieve
+#[instrument(skip_all, fields(flow = ?Flow::SubscriptionRetrieve))]
+pub async fn subscription_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::SubscriptionRetrieve;
+    let payload = subscription_types::SubscriptionRetrieveRequest {
+        subscription_id: path.into_inner(),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            retrieve_subscription(state, merchant_context, req)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Subscriptions - List
+#[instrument(skip_all, fields(flow = ?Flow::SubscriptionList))]
+pub async fn subscription_list(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query_payload: web::Query<subscription_types::SubscriptionListConstraints>,
+) -> HttpResponse {
+    let flow = Flow::SubscriptionList;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            list_subscriptions(state, merchant_context, req)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Subscriptions - Update
+#[instrument(skip_all, fields(flow = ?Flow::SubscriptionUpdate))]
+pub async fn subscription_update(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<subscription_types::SubscriptionUpdateRequest>,
+) -> HttpResponse {
+    let flow = Flow::SubscriptionUpdate;
+    let mut payload = json_payload.into_inner();
+    payload.subscription_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, req_state| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            update_subscription(state, req_state, merchant_context, req)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Subscriptions - Cancel
+#[instrument(skip_all, fields(flow = ?Flow::SubscriptionCancel))]
+pub async fn subscription_cancel(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<subscription_types::SubscriptionCancelRequest>,
+) -> HttpResponse {
+    let flow = Flow::SubscriptionCancel;
+    let mut payload = json_payload.into_inner();
+    payload.subscription_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            cancel_subscription(state, merchant_context, req)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Subscriptions - List Invoices
+#[instrument(skip_all, fields(flow = ?Flow::SubscriptionInvoiceList))]
+pub async fn subscription_invoice_list(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::SubscriptionInvoiceList;
+    let payload = subscription_types::SubscriptionRetrieveRequest {
+        subscription_id: path.into_inner(),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            list_subscription_invoices(state, merchant_context, req)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -11,7 +11,8 @@ use crate::{
     },
     types::api::webhook_events::{
         EventListConstraints, EventListRequestInternal, WebhookDeliveryAttemptListRequestInternal,
-        WebhookDeliveryRetryRequestInternal,
+        WebhookDeliveryRetryRequestInternal, WebhookEventReplayRequest,
+        WebhookEventReplayRequestInternal, WebhookEventReplayRetrieveRequestInternal,
     },
 };
 
@@ -174,3 +175,84 @@ pub async fn retry_webhook_delivery_attempt(
     ))
     .await
 }
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEventReplay))]
+#[cfg(feature = "v1")]
+pub async fn create_webhook_event_replay(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+    json_payload: web::Json<WebhookEventReplayRequest>,
+) -> impl Responder {
+    let flow = Flow::WebhookEventReplay;
+    let merchant_id = path.into_inner();
+
+    let request_internal = WebhookEventReplayRequestInternal {
+        merchant_id: merchant_id.clone(),
+        request: json_payload.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        request_internal,
+        |state, _, request_internal, _| {
+            webhook_events::create_replay_job(
+                state,
+                request_internal.merchant_id,
+                request_internal.request,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id,
+                required_permission: Permission::MerchantWebhookEventWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEventReplayRetrieve))]
+#[cfg(feature = "v1")]
+pub async fn retrieve_webhook_event_replay(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(common_utils::id_type::MerchantId, String)>,
+) -> impl Responder {
+    let flow = Flow::WebhookEventReplayRetrieve;
+    let (merchant_id, replay_id) = path.into_inner();
+
+    let request_internal = WebhookEventReplayRetrieveRequestInternal {
+        merchant_id: merchant_id.clone(),
+        replay_id,
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        request_internal,
+        |state, _, request_internal, _| {
+            webhook_events::retrieve_replay_job(
+                state,
+                request_internal.merchant_id,
+                request_internal.replay_id,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id,
+                required_permission: Permission::MerchantWebhookEventRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
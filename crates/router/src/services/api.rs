@@ -30,7 +30,7 @@ pub use hyperswitch_domain_models::{
     api::{
         ApplicationResponse, GenericExpiredLinkData, GenericLinkFormData, GenericLinkStatusData,
         GenericLinks, PaymentLinkAction, PaymentLinkFormData, PaymentLinkStatusData,
-        RedirectionFormData,
+        RedirectionFormData, StoredResponse,
     },
     payment_method_data::PaymentMethodData,
     router_response_types::RedirectForm,
@@ -67,13 +67,13 @@ use crate::{
         api_logs::{ApiEvent, ApiEventMetric, ApiEventsType},
         connector_api_logs::ConnectorEvent,
     },
-    headers, logger,
+    headers, logger, middleware,
     routes::{
         app::{AppStateInfo, ReqState, SessionStateInfo},
-        metrics, AppState, SessionState,
+        lock_utils, metrics, AppState, SessionState,
     },
     services::{
-        connector_integration_interface::RouterDataConversion,
+        authorization, connector_integration_interface::RouterDataConversion,
         generic_link_response::build_generic_link_html,
     },
     types::{self, api, ErrorResponse},
@@ -538,7 +538,9 @@ pub enum AuthFlow {
     fields(merchant_id)
 )]
 pub async fn server_wrap_util<'a, 'b, U, T, Q, F, Fut, E, OErr>(
-    flow: &'a impl router_env::types::FlowMetric,
+    flow: &'a (impl router_env::types::FlowMetric
+             + Into<lock_utils::ApiIdentifier>
+             + authorization::api_keys::ApiKeyPermission),
     state: web::Data<AppState>,
     incoming_request_header: &HeaderMap,
     request: &'a HttpRequest,
@@ -641,20 +643,64 @@ where
 
     tracing::Span::current().record("merchant_id", merchant_id.get_string_repr().to_owned());
 
-    let output = {
-        lock_action
+    middleware::check_api_rate_limit(&session_state, &auth_type, flow.clone().into())
+        .await
+        .switch()?;
+
+    // Unlike authentication failures, rejections of scoped API keys are recorded as API events
+    let api_key_permission_check = authorization::api_keys::check_api_key_permission(
+        &auth_type,
+        flow,
+        incoming_request_header,
+    )
+    .switch();
+
+    let idempotency_status = match api_key_permission_check {
+        Ok(()) => Ok(lock_action
+            .perform_idempotency_check(&session_state, &merchant_id)
+            .await
+            .switch()?),
+        Err(error) => Err(error),
+    };
+
+    let output = match idempotency_status {
+        Err(error) => Err(error),
+        Ok(api_locking::IdempotencyStatus::Replay(stored_response)) => Ok(
+            ApplicationResponse::StoredResponse(Box::new(stored_response)),
+        ),
+        Ok(api_locking::IdempotencyStatus::Proceed) => match lock_action
             .clone()
             .perform_locking_action(&session_state, merchant_id.to_owned())
             .await
-            .switch()?;
-        let res = func(session_state.clone(), auth_out, payload, request_state)
-            .await
-            .switch();
-        lock_action
-            .free_lock_action(&session_state, merchant_id.to_owned())
-            .await
-            .switch()?;
-        res
+            .switch()
+        {
+            Err(error) => {
+                // The request was not processed, so the client can retry with the same key
+                lock_action
+                    .release_idempotency_key(&session_state, &merchant_id)
+                    .await;
+                Err(error)
+            }
+            Ok(()) => {
+                let res: CustomResult<ApplicationResponse<Q>, OErr> = lock_action
+                    .hold_idempotency_key_while(
+                        &session_state,
+                        &merchant_id,
+                        func(session_state.clone(), auth_out, payload, request_state),
+                    )
+                    .await
+                    .switch();
+                lock_action
+                    .store_idempotent_response(&session_state, &merchant_id, &res)
+                    .await;
+                lock_action
+                    .clone()
+                    .free_lock_action(&session_state, merchant_id.to_owned())
+                    .await
+                    .switch()
+                    .and(res)
+            }
+        },
     };
     let request_duration = Instant::now()
         .saturating_duration_since(start_instant)
@@ -727,7 +773,9 @@ where
     fields(request_method, request_url_path, status_code)
 )]
 pub async fn server_wrap<'a, T, U, Q, F, Fut, E>(
-    flow: impl router_env::types::FlowMetric,
+    flow: impl router_env::types::FlowMetric
+        + Into<lock_utils::ApiIdentifier>
+        + authorization::api_keys::ApiKeyPermission,
     state: web::Data<AppState>,
     request: &'a HttpRequest,
     payload: T,
@@ -804,6 +852,9 @@ where
         Ok(ApplicationResponse::FileData((file_data, content_type))) => {
             http_response_file_data(file_data, content_type)
         }
+        Ok(ApplicationResponse::StoredResponse(stored_response)) => {
+            http_response_stored(*stored_response)
+        }
         Ok(ApplicationResponse::JsonForRedirection(response)) => {
             match serde_json::to_string(&response) {
                 Ok(res) => http_redirect_response(res, response),
@@ -1017,6 +1068,32 @@ pub fn http_response_json_with_headers<T: body::MessageBody + 'static>(
         .body(response)
 }
 
+/// Builds the http response for a response served earlier, with its original status and headers
+pub fn http_response_stored(stored_response: StoredResponse) -> HttpResponse {
+    let status_code =
+        http::StatusCode::from_u16(stored_response.status_code).unwrap_or(http::StatusCode::OK);
+    let mut response_builder = HttpResponse::build(status_code);
+    for (header_name, header_value) in stored_response.headers {
+        let is_sensitive_header = header_value.is_masked();
+        let mut header_value = match HeaderValue::from_str(header_value.into_inner().as_str()) {
+            Ok(header_value) => header_value,
+            Err(error) => {
+                logger::error!(?error);
+                return http_server_error_json_response("Something Went Wrong");
+            }
+        };
+
+        if is_sensitive_header {
+            header_value.set_sensitive(true);
+        }
+        response_builder.append_header((header_name, header_value));
+    }
+
+    response_builder
+        .content_type(mime::APPLICATION_JSON)
+        .body(stored_response.body)
+}
+
 pub fn http_response_plaintext<T: body::MessageBody + 'static>(res: T) -> HttpResponse {
     HttpResponse::Ok().content_type(mime::TEXT_PLAIN).body(res)
 }
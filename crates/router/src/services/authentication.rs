@@ -12,7 +12,7 @@ use api_models::payment_methods::PaymentMethodIntentConfirm;
 use api_models::payouts;
 use api_models::{payment_methods::PaymentMethodListRequest, payments};
 use async_trait::async_trait;
-use common_enums::TokenPurpose;
+use common_enums::{PermissionGroup, TokenPurpose};
 use common_utils::{date_time, fp_utils, id_type};
 #[cfg(feature = "v2")]
 use diesel_models::ephemeral_key;
@@ -119,6 +119,8 @@ pub enum AuthenticationType {
     ApiKey {
         merchant_id: id_type::MerchantId,
         key_id: id_type::ApiKeyId,
+        permission_groups: Option<Vec<PermissionGroup>>,
+        profile_id: Option<id_type::ProfileId>,
     },
     AdminApiKey,
     AdminApiAuthWithMerchantId {
@@ -175,10 +177,7 @@ impl events::EventInfo for AuthenticationType {
 impl AuthenticationType {
     pub fn get_merchant_id(&self) -> Option<&id_type::MerchantId> {
         match self {
-            Self::ApiKey {
-                merchant_id,
-                key_id: _,
-            }
+            Self::ApiKey { merchant_id, .. }
             | Self::AdminApiAuthWithMerchantId { merchant_id }
             | Self::MerchantId { merchant_id }
             | Self::PublishableKey { merchant_id }
@@ -529,6 +528,8 @@ where
             AuthenticationType::ApiKey {
                 merchant_id: auth.merchant_account.get_id().clone(),
                 key_id: stored_api_key.key_id,
+                permission_groups: stored_api_key.permission_groups,
+                profile_id: stored_api_key.profile_id,
             },
         ))
     }
@@ -598,7 +599,9 @@ where
                 .change_context(errors::ValidationError::IncorrectValueProvided {
                     field_name: "X-Profile-Id",
                 })
-                .change_context(errors::ApiErrorResponse::Unauthorized)?;
+                .change_context(errors::ApiErrorResponse::Unauthorized)?
+                // Requests made using API keys restricted to a profile default to that profile
+                .or_else(|| stored_api_key.profile_id.clone());
 
         let merchant = state
             .store()
@@ -649,6 +652,8 @@ where
             AuthenticationType::ApiKey {
                 merchant_id: auth.merchant_account.get_id().clone(),
                 key_id: stored_api_key.key_id,
+                permission_groups: stored_api_key.permission_groups,
+                profile_id: stored_api_key.profile_id,
             },
         ))
     }
@@ -696,6 +701,42 @@ where
     }
 }
 
+/// API key authentication for routes with both the merchant and the profile in the path, which
+/// rejects API keys restricted to a profile other than the one in the path.
+#[derive(Debug)]
+pub struct ApiKeyAuthWithMerchantAndProfileFromRoute {
+    pub merchant_id: id_type::MerchantId,
+    pub profile_id: id_type::ProfileId,
+}
+
+#[cfg(feature = "partial-auth")]
+impl GetAuthType for ApiKeyAuthWithMerchantAndProfileFromRoute {
+    fn get_auth_type(&self) -> detached::PayloadType {
+        detached::PayloadType::ApiKey
+    }
+}
+
+#[cfg(feature = "v1")]
+#[async_trait]
+impl<A> AuthenticateAndFetch<AuthenticationData, A> for ApiKeyAuthWithMerchantAndProfileFromRoute
+where
+    A: SessionStateInfo + Sync,
+{
+    async fn authenticate_and_fetch(
+        &self,
+        request_headers: &HeaderMap,
+        state: &A,
+    ) -> RouterResult<(AuthenticationData, AuthenticationType)> {
+        let (auth_data, auth_type) = ApiKeyAuthWithMerchantIdFromRoute(self.merchant_id.clone())
+            .authenticate_and_fetch(request_headers, state)
+            .await?;
+
+        authorization::api_keys::check_api_key_profile(&auth_type, &self.profile_id)?;
+
+        Ok((auth_data, auth_type))
+    }
+}
+
 #[derive(Debug, Default)]
 pub struct PlatformOrgAdminAuth {
     pub is_admin_auth_allowed: bool,
@@ -803,6 +844,8 @@ where
             AuthenticationType::ApiKey {
                 merchant_id: merchant_account.get_id().clone(),
                 key_id: stored_api_key.key_id,
+                permission_groups: stored_api_key.permission_groups,
+                profile_id: stored_api_key.profile_id,
             },
         ))
     }
@@ -897,6 +940,8 @@ where
             AuthenticationType::ApiKey {
                 merchant_id: auth.merchant_account.get_id().clone(),
                 key_id: stored_api_key.key_id,
+                permission_groups: stored_api_key.permission_groups,
+                profile_id: stored_api_key.profile_id,
             },
         ))
     }
@@ -1027,6 +1072,8 @@ where
             AuthenticationType::ApiKey {
                 merchant_id: platform_merchant.get_id().clone(),
                 key_id: stored_api_key.key_id,
+                permission_groups: stored_api_key.permission_groups,
+                profile_id: stored_api_key.profile_id,
             },
         ))
     }
@@ -1100,11 +1147,20 @@ where
                     merchant_id: Some(merchant_id),
                     key_id: Some(key_id),
                 } => {
+                    // The checksum only vouches for the API key, its scope has to be looked up
+                    let stored_api_key = state
+                        .store()
+                        .find_api_key_by_merchant_id_key_id_optional(&merchant_id, &key_id)
+                        .await
+                        .change_context(errors::ApiErrorResponse::InternalServerError)
+                        .attach_printable("Failed to retrieve API key")?
+                        .ok_or(report!(errors::ApiErrorResponse::Unauthorized))
+                        .attach_printable("API key not found")?;
                     let auth = construct_authentication_data(
                         state,
                         &merchant_id,
                         request_headers,
-                        profile_id,
+                        profile_id.or_else(|| stored_api_key.profile_id.clone()),
                     )
                     .await?;
                     Ok((
@@ -1112,6 +1168,8 @@ where
                         AuthenticationType::ApiKey {
                             merchant_id: auth.merchant_account.get_id().clone(),
                             key_id,
+                            permission_groups: stored_api_key.permission_groups,
+                            profile_id: stored_api_key.profile_id,
                         },
                     ))
                 }
@@ -1753,6 +1811,8 @@ where
                 AuthenticationType::ApiKey {
                     merchant_id: stored_api_key.merchant_id,
                     key_id: stored_api_key.key_id,
+                    permission_groups: stored_api_key.permission_groups,
+                    profile_id: stored_api_key.profile_id,
                 },
             ));
         }
@@ -2544,6 +2604,8 @@ where
             AuthenticationType::ApiKey {
                 merchant_id: auth.merchant_account.get_id().clone(),
                 key_id: stored_api_key.key_id,
+                permission_groups: stored_api_key.permission_groups,
+                profile_id: stored_api_key.profile_id,
             },
         ))
     }
@@ -4313,6 +4375,27 @@ pub fn get_api_key(headers: &HeaderMap) -> RouterResult<&str> {
     get_header_value_by_key("api-key".into(), headers)?.get_required_value("api_key")
 }
 
+/// Find the ID of the API key with which a request was authenticated, to identify the actor of the
+/// request.
+pub async fn get_api_key_id<A>(headers: &HeaderMap, state: &A) -> RouterResult<id_type::ApiKeyId>
+where
+    A: SessionStateInfo + Sync,
+{
+    let api_key = api_keys::PlaintextApiKey::from(get_api_key(headers)?.trim());
+    let hash_key = state.conf().api_keys.get_inner().get_hash_key()?;
+    let hashed_api_key = api_key.keyed_hash(hash_key.peek());
+
+    state
+        .store()
+        .find_api_key_by_hash_optional(hashed_api_key.into())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to retrieve API key")?
+        .map(|stored_api_key| stored_api_key.key_id)
+        .ok_or(report!(errors::ApiErrorResponse::Unauthorized))
+        .attach_printable("API key not found")
+}
+
 pub fn get_header_value_by_key(key: String, headers: &HeaderMap) -> RouterResult<Option<&str>> {
     headers
         .get(&key)
@@ -12,6 +12,7 @@ use crate::{
     routes::app::SessionStateInfo,
 };
 
+pub mod api_keys;
 #[cfg(feature = "olap")]
 pub mod info;
 pub mod permission_groups;
@@ -0,0 +1,613 @@
+use actix_web::http::header::HeaderMap;
+use common_enums::EntityType;
+use common_utils::id_type;
+use error_stack::{report, ResultExt};
+use router_env::{types::FlowMetric, Flow};
+
+use super::{permission_groups::PermissionGroupExt, permissions::Permission};
+use crate::{
+    core::errors::{ApiErrorResponse, RouterResult},
+    headers,
+    services::authentication::{AuthenticationType, HeaderMapStruct},
+};
+
+/// Permission required by a scoped API key to access an API.
+pub trait ApiKeyPermission {
+    /// Returns `None` if the API cannot be accessed using scoped API keys.
+    fn get_required_permission(&self) -> Option<Permission>;
+}
+
+impl ApiKeyPermission for Flow {
+    fn get_required_permission(&self) -> Option<Permission> {
+        match self {
+            Self::PaymentsCreate
+            | Self::PaymentsUpdate
+            | Self::PaymentsConfirm
+            | Self::PaymentsCapture
+            | Self::PaymentsCancel
+            | Self::PaymentsApprove
+            | Self::PaymentsReject
+            | Self::PaymentsIncrementalAuthorization
+            | Self::PaymentsUpdateMetadata
+            | Self::PaymentsCreateIntent
+            | Self::PaymentsUpdateIntent
+            | Self::PaymentsConfirmIntent
+            | Self::PaymentsCreateAndConfirmIntent
+            | Self::ProxyConfirmIntent
+            | Self::Relay => Some(Permission::ProfilePaymentWrite),
+
+            Self::PaymentsRetrieve
+            | Self::PaymentsRetrieveForceSync
+            | Self::PaymentsRetrieveUsingMerchantReferenceId
+            | Self::PaymentsGetIntent
+            | Self::GetExtendedCardInfo
+            | Self::RelayRetrieve => Some(Permission::ProfilePaymentRead),
+
+            Self::PaymentsList
+            | Self::PaymentsFilters
+            | Self::PaymentsAggregate
+            | Self::PaymentLinkList
+            | Self::SubscriptionPlanRetrieve
+            | Self::SubscriptionPlanList
+            | Self::SubscriptionRetrieve
+            | Self::SubscriptionList
+            | Self::SubscriptionInvoiceList => Some(Permission::MerchantPaymentRead),
+
+            Self::SubscriptionPlanCreate
+            | Self::SubscriptionPlanUpdate
+            | Self::SubscriptionCreate
+            | Self::SubscriptionUpdate
+            | Self::SubscriptionCancel => Some(Permission::MerchantPaymentWrite),
+
+            Self::RefundsCreate | Self::RefundsUpdate => Some(Permission::ProfileRefundWrite),
+
+            Self::RefundsRetrieve | Self::RefundsRetrieveForceSync => {
+                Some(Permission::ProfileRefundRead)
+            }
+
+            Self::RefundsList | Self::RefundsFilters | Self::RefundsAggregate => {
+                Some(Permission::MerchantRefundRead)
+            }
+
+            Self::DisputesEvidenceSubmit
+            | Self::AttachDisputeEvidence
+            | Self::DeleteDisputeEvidence
+            | Self::CreateFile
+            | Self::DeleteFile => Some(Permission::ProfileDisputeWrite),
+
+            Self::DisputesRetrieve | Self::RetrieveDisputeEvidence | Self::RetrieveFile => {
+                Some(Permission::ProfileDisputeRead)
+            }
+
+            Self::DisputesList | Self::DisputesFilters | Self::DisputesAggregate => {
+                Some(Permission::MerchantDisputeRead)
+            }
+
+            Self::MandatesRevoke => Some(Permission::MerchantMandateWrite),
+
+            Self::MandatesRetrieve | Self::MandatesList | Self::CustomersGetMandates => {
+                Some(Permission::MerchantMandateRead)
+            }
+
+            Self::CustomersCreate
+            | Self::CustomersUpdate
+            | Self::CustomersDelete
+            | Self::EphemeralKeyCreate
+            | Self::EphemeralKeyDelete
+            | Self::PaymentMethodsCreate
+            | Self::PaymentMethodsMigrate
+            | Self::PaymentMethodsMigrationJobCreate
+            | Self::PaymentMethodsMigrationJobExecute
+            | Self::PaymentMethodsUpdate
+            | Self::PaymentMethodsDelete
+            | Self::PaymentMethodSave
+            | Self::PaymentMethodCollectLink
+            | Self::DefaultPaymentMethodsSet
+            | Self::TokenizeCard
+            | Self::TokenizeCardUsingPaymentMethodId
+            | Self::TokenizeCardBatch
+            | Self::PaymentMethodSessionCreate
+            | Self::PaymentMethodSessionUpdate
+            | Self::PaymentMethodSessionUpdateSavedPaymentMethod
+            | Self::PaymentMethodSessionDeleteSavedPaymentMethod
+            | Self::TokenizationCreate => Some(Permission::MerchantCustomerWrite),
+
+            Self::CustomersRetrieve
+            | Self::CustomersList
+            | Self::PaymentMethodsMigrationJobRetrieve
+            | Self::PaymentMethodsMigrationJobReport
+            | Self::PaymentMethodsRetrieve
+            | Self::PaymentMethodsList
+            | Self::CustomerPaymentMethodsList
+            | Self::GetPaymentMethodTokenData
+            | Self::TotalPaymentMethodCount
+            | Self::PaymentMethodSessionRetrieve
+            | Self::TokenizationRetrieve => Some(Permission::MerchantCustomerRead),
+
+            Self::PayoutsCreate
+            | Self::PayoutsUpdate
+            | Self::PayoutsConfirm
+            | Self::PayoutsCancel
+            | Self::PayoutsFulfill
+            | Self::PayoutsBatchCreate => Some(Permission::ProfilePayoutWrite),
+
+            Self::PayoutsRetrieve | Self::PayoutsBatchRetrieve | Self::PayoutsBatchReport => {
+                Some(Permission::ProfilePayoutRead)
+            }
+
+            Self::PayoutsList | Self::PayoutsFilter | Self::PayoutsAccounts => {
+                Some(Permission::MerchantPayoutRead)
+            }
+
+            Self::ApiKeyCreate | Self::ApiKeyUpdate | Self::ApiKeyRevoke => {
+                Some(Permission::MerchantApiKeyWrite)
+            }
+
+            Self::ApiKeyRetrieve | Self::ApiKeyList => Some(Permission::MerchantApiKeyRead),
+
+            Self::MerchantsAccountUpdate
+            | Self::MerchantsAccountDelete
+            | Self::ProfileCreate
+            | Self::AddToBlocklist
+            | Self::DeleteFromBlocklist
+            | Self::ToggleBlocklistGuard
+            | Self::ToggleCardAccountUpdater => Some(Permission::MerchantAccountWrite),
+
+            Self::MerchantsAccountRetrieve | Self::ProfileList | Self::ListBlocklist => {
+                Some(Permission::MerchantAccountRead)
+            }
+
+            Self::ProfileUpdate
+            | Self::ProfileDelete
+            | Self::ToggleExtendedCardInfo
+            | Self::ToggleConnectorAgnosticMit
+            | Self::ProfileWebhookSecretRotate
+            | Self::WebhookEndpointCreate
+            | Self::WebhookEndpointUpdate
+            | Self::WebhookEndpointDelete
+            | Self::WebhookEndpointSecretRotate => Some(Permission::ProfileAccountWrite),
+
+            Self::ProfileRetrieve | Self::WebhookEndpointRetrieve | Self::WebhookEndpointList => {
+                Some(Permission::ProfileAccountRead)
+            }
+
+            Self::MerchantConnectorsCreate
+            | Self::MerchantConnectorsUpdate
+            | Self::MerchantConnectorsDelete => Some(Permission::ProfileConnectorWrite),
+
+            Self::MerchantConnectorsRetrieve => Some(Permission::ProfileConnectorRead),
+
+            Self::MerchantConnectorsList => Some(Permission::MerchantConnectorRead),
+
+            Self::RoutingCreateConfig
+            | Self::RoutingLinkConfig
+            | Self::RoutingUnlinkConfig
+            | Self::RoutingUpdateConfig
+            | Self::RoutingUpdateDefaultConfig
+            | Self::RoutingDeleteConfig
+            | Self::ToggleDynamicRouting
+            | Self::UpdateDynamicRoutingConfigs
+            | Self::VolumeSplitOnRoutingType => Some(Permission::ProfileRoutingWrite),
+
+            Self::RoutingRetrieveConfig
+            | Self::RoutingRetrieveActiveConfig
+            | Self::RoutingRetrieveDefaultConfig
+            | Self::RoutingSimulate => Some(Permission::ProfileRoutingRead),
+
+            Self::RoutingRetrieveDictionary => Some(Permission::MerchantRoutingRead),
+
+            Self::DecisionManagerUpsertConfig | Self::DecisionManagerDeleteConfig => {
+                Some(Permission::MerchantThreeDsDecisionManagerWrite)
+            }
+
+            Self::DecisionManagerRetrieveConfig => {
+                Some(Permission::MerchantThreeDsDecisionManagerRead)
+            }
+
+            Self::WebhookEventDeliveryRetry | Self::WebhookEventReplay => {
+                Some(Permission::MerchantWebhookEventWrite)
+            }
+
+            Self::WebhookEventInitialDeliveryAttemptList
+            | Self::WebhookEventDeliveryAttemptList
+            | Self::WebhookEventReplayRetrieve => Some(Permission::MerchantWebhookEventRead),
+
+            Self::RevenueRecoveryRetrieve => Some(Permission::ProfileRevenueRecoveryRead),
+
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "olap")]
+impl ApiKeyPermission for analytics::AnalyticsFlow {
+    fn get_required_permission(&self) -> Option<Permission> {
+        match self {
+            Self::GetInfo
+            | Self::GetPaymentMetrics
+            | Self::GetPaymentIntentMetrics
+            | Self::GetRefundsMetrics
+            | Self::GetFrmMetrics
+            | Self::GetSdkMetrics
+            | Self::GetAuthMetrics
+            | Self::GetActivePaymentsMetrics
+            | Self::GetApiEventMetrics
+            | Self::GetDisputeMetrics
+            | Self::GetPayoutMetrics
+            | Self::GetAuthEventFilters
+            | Self::GetPaymentFilters
+            | Self::GetPaymentIntentFilters
+            | Self::GetRefundFilters
+            | Self::GetFrmFilters
+            | Self::GetSdkEventFilters
+            | Self::GetApiEventFilters
+            | Self::GetDisputeFilters
+            | Self::GetPayoutFilters
+            | Self::GetSankey
+            | Self::ListDigestSubscriptions => Some(Permission::MerchantAnalyticsRead),
+
+            Self::GeneratePaymentReport
+            | Self::GenerateDisputeReport
+            | Self::GenerateRefundReport
+            | Self::GenerateAuthenticationReport
+            | Self::RetrieveReport
+            | Self::DownloadReport => Some(Permission::MerchantReportRead),
+
+            Self::CreateDigestSubscription
+            | Self::UpdateDigestSubscription
+            | Self::DeleteDigestSubscription => Some(Permission::MerchantAccountWrite),
+
+            // Events and search results expose the raw request and response payloads, these are
+            // not accessible using scoped API keys
+            Self::GetApiEvents
+            | Self::GetSdkEvents
+            | Self::GetConnectorEvents
+            | Self::GetOutgoingWebhookEvents
+            | Self::GetGlobalSearchResults
+            | Self::GetSearchResults => None,
+        }
+    }
+}
+
+#[cfg(feature = "dummy_connector")]
+impl ApiKeyPermission for crate::routes::dummy_connector::types::Flow {
+    fn get_required_permission(&self) -> Option<Permission> {
+        None
+    }
+}
+
+/// Checks whether the API key used to authenticate the request is allowed to access the API.
+///
+/// API keys without permission groups or a profile restriction can access every API available to
+/// the merchant account. Scoped API keys can only access the APIs their permission groups grant,
+/// and profile restricted API keys can only access profile level APIs of their own profile.
+pub fn check_api_key_permission(
+    auth_type: &AuthenticationType,
+    flow: &(impl FlowMetric + ApiKeyPermission),
+    request_headers: &HeaderMap,
+) -> RouterResult<()> {
+    let AuthenticationType::ApiKey {
+        permission_groups,
+        profile_id,
+        ..
+    } = auth_type
+    else {
+        return Ok(());
+    };
+
+    if permission_groups.is_none() && profile_id.is_none() {
+        return Ok(());
+    }
+
+    let forbidden = || ApiErrorResponse::AccessForbidden {
+        resource: flow.to_string(),
+    };
+
+    let required_permission = flow
+        .get_required_permission()
+        .ok_or_else(|| report!(forbidden()))
+        .attach_printable("API is not accessible using scoped API keys")?;
+
+    if profile_id.is_some() {
+        let requested_profile_id = HeaderMapStruct::new(request_headers)
+            .get_id_type_from_header_if_present::<id_type::ProfileId>(headers::X_PROFILE_ID)?;
+
+        if let Some(requested_profile_id) = requested_profile_id {
+            check_api_key_profile(auth_type, &requested_profile_id)?;
+        }
+    }
+
+    let entity_type = if profile_id.is_some() {
+        EntityType::Profile
+    } else {
+        EntityType::Merchant
+    };
+
+    let has_permission = required_permission.entity_type() <= entity_type
+        && permission_groups
+            .as_ref()
+            .map_or(true, |permission_groups| {
+                permission_groups
+                    .iter()
+                    .flat_map(|group| group.accessible_groups())
+                    .any(|group| {
+                        required_permission.scope() <= group.scope()
+                            && group.resources().contains(&required_permission.resource())
+                    })
+            });
+
+    if !has_permission {
+        return Err(report!(forbidden())).attach_printable(format!(
+            "API key does not have the required permission: {required_permission}"
+        ));
+    }
+
+    Ok(())
+}
+
+/// Checks whether the API key used to authenticate the request is allowed to access the requested
+/// profile. API keys which are not restricted to a profile can access every profile of the
+/// merchant account.
+pub fn check_api_key_profile(
+    auth_type: &AuthenticationType,
+    requested_profile_id: &id_type::ProfileId,
+) -> RouterResult<()> {
+    match auth_type {
+        AuthenticationType::ApiKey {
+            profile_id: Some(profile_id),
+            ..
+        } if profile_id != requested_profile_id => {
+            Err(report!(ApiErrorResponse::AccessForbidden {
+                resource: requested_profile_id.get_string_repr().to_owned(),
+            }))
+            .attach_printable("API key is restricted to a different profile")
+        }
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use std::borrow::Cow;
+
+    use actix_web::http::header::HeaderValue;
+    use common_enums::PermissionGroup;
+
+    use super::*;
+
+    fn get_auth_type(
+        permission_groups: Option<Vec<PermissionGroup>>,
+        profile_id: Option<&str>,
+    ) -> AuthenticationType {
+        AuthenticationType::ApiKey {
+            merchant_id: id_type::MerchantId::try_from(Cow::from("merchant_1")).unwrap(),
+            key_id: id_type::ApiKeyId::try_from(Cow::from("key_1")).unwrap(),
+            permission_groups,
+            profile_id: profile_id
+                .map(|profile_id| id_type::ProfileId::try_from(Cow::from(profile_id)).unwrap()),
+        }
+    }
+
+    #[test]
+    fn test_check_api_key_permission() {
+        use PermissionGroup::{AccountManage, OperationsManage, OperationsView};
+
+        // (flow, permission groups, restricted profile, is allowed)
+        let cases = [
+            // Unscoped API keys can access every API
+            (Flow::PaymentsCreate, None, None, true),
+            (Flow::HealthCheck, None, None, true),
+            // Profile level read permission
+            (
+                Flow::PaymentsRetrieve,
+                Some(vec![OperationsView]),
+                None,
+                true,
+            ),
+            (
+                Flow::PaymentsRetrieve,
+                Some(vec![OperationsView]),
+                Some("pro_1"),
+                true,
+            ),
+            (Flow::PaymentsRetrieve, None, Some("pro_1"), true),
+            // Profile level write permission
+            (
+                Flow::PaymentsCreate,
+                Some(vec![OperationsView]),
+                None,
+                false,
+            ),
+            (
+                Flow::PaymentsCreate,
+                Some(vec![OperationsView]),
+                Some("pro_1"),
+                false,
+            ),
+            (
+                Flow::PaymentsCreate,
+                Some(vec![OperationsManage]),
+                None,
+                true,
+            ),
+            (
+                Flow::PaymentsCreate,
+                Some(vec![OperationsManage]),
+                Some("pro_1"),
+                true,
+            ),
+            (Flow::PaymentsCreate, None, Some("pro_1"), true),
+            // Merchant level read permission
+            (Flow::PaymentsList, Some(vec![OperationsView]), None, true),
+            (
+                Flow::PaymentsList,
+                Some(vec![OperationsView]),
+                Some("pro_1"),
+                false,
+            ),
+            (Flow::PaymentsList, None, Some("pro_1"), false),
+            // Merchant level write permission
+            (
+                Flow::SubscriptionCreate,
+                Some(vec![OperationsView]),
+                None,
+                false,
+            ),
+            (
+                Flow::SubscriptionCreate,
+                Some(vec![OperationsManage]),
+                None,
+                true,
+            ),
+            (
+                Flow::SubscriptionCreate,
+                Some(vec![OperationsManage]),
+                Some("pro_1"),
+                false,
+            ),
+            (Flow::SubscriptionCreate, None, Some("pro_1"), false),
+            // Permission groups granting a different resource
+            (
+                Flow::ApiKeyCreate,
+                Some(vec![OperationsManage]),
+                None,
+                false,
+            ),
+            (Flow::ApiKeyCreate, Some(vec![AccountManage]), None, true),
+            (Flow::PaymentsCreate, Some(vec![AccountManage]), None, false),
+            (
+                Flow::ApiKeyCreate,
+                Some(vec![OperationsManage, AccountManage]),
+                None,
+                true,
+            ),
+            // APIs which are not accessible using scoped API keys
+            (Flow::HealthCheck, Some(vec![AccountManage]), None, false),
+            (Flow::HealthCheck, None, Some("pro_1"), false),
+        ];
+
+        for (flow, permission_groups, profile_id, is_allowed) in cases {
+            let auth_type = get_auth_type(permission_groups.clone(), profile_id);
+            assert_eq!(
+                check_api_key_permission(&auth_type, &flow, &HeaderMap::new()).is_ok(),
+                is_allowed,
+                "flow: {flow}, permission groups: {permission_groups:?}, profile: {profile_id:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_check_api_key_permission_for_requested_profile() {
+        let auth_type = get_auth_type(Some(vec![PermissionGroup::OperationsView]), Some("pro_1"));
+
+        let mut request_headers = HeaderMap::new();
+        request_headers.insert(headers::X_PROFILE_ID, HeaderValue::from_static("pro_1"));
+        assert!(
+            check_api_key_permission(&auth_type, &Flow::PaymentsRetrieve, &request_headers).is_ok()
+        );
+
+        request_headers.insert(headers::X_PROFILE_ID, HeaderValue::from_static("pro_2"));
+        assert!(
+            check_api_key_permission(&auth_type, &Flow::PaymentsRetrieve, &request_headers)
+                .is_err()
+        );
+
+        // Merchant API keys are not restricted to the profile in the request
+        let auth_type = get_auth_type(Some(vec![PermissionGroup::OperationsView]), None);
+        assert!(
+            check_api_key_permission(&auth_type, &Flow::PaymentsRetrieve, &request_headers).is_ok()
+        );
+    }
+
+    #[test]
+    fn test_check_api_key_profile() {
+        let profile_id = |id: &'static str| id_type::ProfileId::try_from(Cow::from(id)).unwrap();
+
+        let auth_type = get_auth_type(Some(vec![PermissionGroup::AccountManage]), Some("pro_1"));
+        assert!(check_api_key_profile(&auth_type, &profile_id("pro_1")).is_ok());
+        assert!(check_api_key_profile(&auth_type, &profile_id("pro_2")).is_err());
+
+        // Merchant API keys can access every profile of the merchant account
+        let auth_type = get_auth_type(Some(vec![PermissionGroup::AccountManage]), None);
+        assert!(check_api_key_profile(&auth_type, &profile_id("pro_2")).is_ok());
+        let auth_type = get_auth_type(None, None);
+        assert!(check_api_key_profile(&auth_type, &profile_id("pro_2")).is_ok());
+    }
+
+    #[cfg(feature = "olap")]
+    #[test]
+    fn test_check_api_key_permission_for_analytics() {
+        use analytics::AnalyticsFlow;
+        use PermissionGroup::{AccountManage, AnalyticsView, OperationsView};
+
+        // (flow, permission groups, restricted profile, is allowed)
+        let cases = [
+            (
+                AnalyticsFlow::GetPaymentMetrics,
+                Some(vec![AnalyticsView]),
+                None,
+                true,
+            ),
+            (
+                AnalyticsFlow::GetPaymentMetrics,
+                Some(vec![OperationsView]),
+                None,
+                false,
+            ),
+            (
+                AnalyticsFlow::GetPaymentMetrics,
+                Some(vec![AnalyticsView]),
+                Some("pro_1"),
+                false,
+            ),
+            (
+                AnalyticsFlow::GeneratePaymentReport,
+                Some(vec![AnalyticsView]),
+                None,
+                true,
+            ),
+            (
+                AnalyticsFlow::DownloadReport,
+                Some(vec![OperationsView]),
+                None,
+                false,
+            ),
+            (
+                AnalyticsFlow::CreateDigestSubscription,
+                Some(vec![AnalyticsView]),
+                None,
+                false,
+            ),
+            (
+                AnalyticsFlow::CreateDigestSubscription,
+                Some(vec![AccountManage]),
+                None,
+                true,
+            ),
+            (
+                AnalyticsFlow::GetApiEvents,
+                Some(vec![AnalyticsView]),
+                None,
+                false,
+            ),
+            (
+                AnalyticsFlow::GetGlobalSearchResults,
+                Some(vec![AnalyticsView]),
+                None,
+                false,
+            ),
+            (AnalyticsFlow::GetApiEvents, None, None, true),
+        ];
+
+        for (flow, permission_groups, profile_id, is_allowed) in cases {
+            let auth_type = get_auth_type(permission_groups.clone(), profile_id);
+            assert_eq!(
+                check_api_key_permission(&auth_type, &flow, &HeaderMap::new()).is_ok(),
+                is_allowed,
+                "flow: {flow}, permission groups: {permission_groups:?}, profile: {profile_id:?}"
+            );
+        }
+    }
+}
@@ -24,7 +24,7 @@ generate_permissions! {
             entities: [Merchant]
         },
         Payout: {
-            scopes: [Read],
+            scopes: [Read, Write],
             entities: [Profile, Merchant]
         },
         ApiKey: {
@@ -81,6 +81,12 @@ pub enum EmailBody {
         prefix: String,
     },
     WelcomeToCommunity,
+    AnalyticsDigest {
+        merchant_name: String,
+        period: String,
+        metrics: Vec<(String, String)>,
+        decline_reasons: Vec<(String, String)>,
+    },
 }
 
 pub mod html {
@@ -242,8 +248,43 @@ Email         : {user_email}
             EmailBody::WelcomeToCommunity => {
                 include_str!("assets/welcome_to_community.html").to_string()
             }
+            EmailBody::AnalyticsDigest {
+                merchant_name,
+                period,
+                metrics,
+                decline_reasons,
+            } => {
+                let metrics = get_digest_table_rows(&metrics, "No metrics available");
+                let decline_reasons =
+                    get_digest_table_rows(&decline_reasons, "No payments were declined");
+                format!(
+                    include_str!("assets/analytics_digest.html"),
+                    merchant_name = maud::html! { (merchant_name) }.into_string(),
+                    period = period,
+                    metrics = metrics,
+                    decline_reasons = decline_reasons,
+                )
+            }
         }
     }
+
+    /// Renders the label and value pairs as the rows of a table, escaping their contents.
+    fn get_digest_table_rows(rows: &[(String, String)], placeholder: &str) -> String {
+        maud::html! {
+            @for (label, value) in rows {
+                tr {
+                    td style="padding: 6px 0; color: #666666;" { (label) }
+                    td style="padding: 6px 0; text-align: end; color: #111326;" { (value) }
+                }
+            }
+            @if rows.is_empty() {
+                tr {
+                    td colspan="2" style="padding: 6px 0; color: #666666;" { (placeholder) }
+                }
+            }
+        }
+        .into_string()
+    }
 }
 
 #[derive(serde::Serialize, serde::Deserialize)]
@@ -678,3 +719,30 @@ impl EmailData for WelcomeToCommunity {
         })
     }
 }
+
+pub struct AnalyticsDigest {
+    pub recipient_email: domain::UserEmail,
+    pub subject: String,
+    pub merchant_name: String,
+    pub period: String,
+    pub metrics: Vec<(String, String)>,
+    pub decline_reasons: Vec<(String, String)>,
+}
+
+#[async_trait::async_trait]
+impl EmailData for AnalyticsDigest {
+    async fn get_email_data(&self, _base_url: &str) -> CustomResult<EmailContents, EmailError> {
+        let body = html::get_html_body(EmailBody::AnalyticsDigest {
+            merchant_name: self.merchant_name.clone(),
+            period: self.period.clone(),
+            metrics: self.metrics.clone(),
+            decline_reasons: self.decline_reasons.clone(),
+        });
+
+        Ok(EmailContents {
+            subject: self.subject.clone(),
+            body: external_services::email::IntermediateString::new(body),
+            recipient: self.recipient_email.clone().into_inner(),
+        })
+    }
+}
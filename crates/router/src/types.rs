@@ -905,6 +905,8 @@ impl ForeignFrom<&SetupMandateRouterData> for PaymentsAuthorizeData {
             request_incremental_authorization: data.request.request_incremental_authorization,
             metadata: None,
             request_extended_authorization: None,
+            enable_partial_authorization: None,
+            l2_l3_data: None,
             authentication_data: None,
             customer_acceptance: data.request.customer_acceptance.clone(),
             split_payments: None, // TODO: allow charges on mandates?
@@ -281,6 +281,7 @@ impl ForeignTryFrom<domain::Profile> for ProfileResponse {
             external_vault_connector_details: item
                 .external_vault_connector_details
                 .map(ForeignInto::foreign_into),
+            revenue_recovery_retry_policy: item.revenue_recovery_retry_policy,
         })
     }
 }
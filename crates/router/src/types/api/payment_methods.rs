@@ -27,7 +27,9 @@ pub use api_models::payment_methods::{
     PaymentMethodCollectLinkRenderRequest, PaymentMethodCollectLinkRequest, PaymentMethodCreate,
     PaymentMethodCreateData, PaymentMethodDeleteResponse, PaymentMethodId,
     PaymentMethodListRequest, PaymentMethodListResponse, PaymentMethodMigrate,
-    PaymentMethodMigrateResponse, PaymentMethodResponse, PaymentMethodUpdate, PaymentMethodsData,
+    PaymentMethodMigrateResponse, PaymentMethodMigrationJobRequest,
+    PaymentMethodMigrationJobResponse, PaymentMethodResponse, PaymentMethodUpdate,
+    PaymentMethodsData, ToggleCardAccountUpdaterQuery, ToggleCardAccountUpdaterResponse,
     TokenizeCardRequest, TokenizeDataRequest, TokenizePayloadEncrypted, TokenizePayloadRequest,
     TokenizePaymentMethodRequest, TokenizedCardValue1, TokenizedCardValue2, TokenizedWalletValue1,
     TokenizedWalletValue2,
@@ -53,6 +53,8 @@ impl VerifyConnectorData {
             related_transaction_id: None,
             statement_descriptor_suffix: None,
             request_extended_authorization: None,
+            enable_partial_authorization: None,
+            l2_l3_data: None,
             request_incremental_authorization: false,
             authentication_data: None,
             customer_acceptance: None,
@@ -2,5 +2,7 @@ pub use api_models::webhook_events::{
     EventListConstraints, EventListConstraintsInternal, EventListItemResponse,
     EventListRequestInternal, EventRetrieveResponse, OutgoingWebhookRequestContent,
     OutgoingWebhookResponseContent, TotalEventsResponse, WebhookDeliveryAttemptListRequestInternal,
-    WebhookDeliveryRetryRequestInternal,
+    WebhookDeliveryRetryRequestInternal, WebhookEventReplayRequest,
+    WebhookEventReplayRequestInternal, WebhookEventReplayResponse,
+    WebhookEventReplayRetrieveRequestInternal, WebhookEventReplayStatus,
 };
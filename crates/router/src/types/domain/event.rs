@@ -71,6 +71,9 @@ pub struct Event {
 
     /// Indicates whether the event was ultimately delivered.
     pub is_overall_delivery_successful: Option<bool>,
+
+    /// Webhook endpoint to which the event is delivered, if not the profile webhook URL.
+    pub webhook_endpoint_id: Option<String>,
 }
 
 #[derive(Debug)]
@@ -130,6 +133,7 @@ impl super::behaviour::Conversion for Event {
             delivery_attempt: self.delivery_attempt,
             metadata: self.metadata,
             is_overall_delivery_successful: self.is_overall_delivery_successful,
+            webhook_endpoint_id: self.webhook_endpoint_id,
         })
     }
 
@@ -180,6 +184,7 @@ impl super::behaviour::Conversion for Event {
             delivery_attempt: item.delivery_attempt,
             metadata: item.metadata,
             is_overall_delivery_successful: item.is_overall_delivery_successful,
+            webhook_endpoint_id: item.webhook_endpoint_id,
         })
     }
 
@@ -202,6 +207,7 @@ impl super::behaviour::Conversion for Event {
             delivery_attempt: self.delivery_attempt,
             metadata: self.metadata,
             is_overall_delivery_successful: self.is_overall_delivery_successful,
+            webhook_endpoint_id: self.webhook_endpoint_id,
         })
     }
 }
@@ -237,6 +237,7 @@ impl JWTFlow {
 pub enum Origin {
     #[serde(rename = "sign_in_with_sso")]
     SignInWithSSO,
+    SignInWithPasskey,
     SignIn,
     SignUp,
     MagicLink,
@@ -249,6 +250,7 @@ impl Origin {
     fn get_flows(&self) -> &'static [UserFlow] {
         match self {
             Self::SignInWithSSO => &SIGNIN_WITH_SSO_FLOW,
+            Self::SignInWithPasskey => &SIGNIN_WITH_PASSKEY_FLOW,
             Self::SignIn => &SIGNIN_FLOW,
             Self::SignUp => &SIGNUP_FLOW,
             Self::VerifyEmail => &VERIFY_EMAIL_FLOW,
@@ -264,6 +266,12 @@ const SIGNIN_WITH_SSO_FLOW: [UserFlow; 2] = [
     UserFlow::JWTFlow(JWTFlow::UserInfo),
 ];
 
+// Passkeys verify the user on the authenticator, so a second factor is not required
+const SIGNIN_WITH_PASSKEY_FLOW: [UserFlow; 2] = [
+    UserFlow::SPTFlow(SPTFlow::MerchantSelect),
+    UserFlow::JWTFlow(JWTFlow::UserInfo),
+];
+
 const SIGNIN_FLOW: [UserFlow; 4] = [
     UserFlow::SPTFlow(SPTFlow::TOTP),
     UserFlow::SPTFlow(SPTFlow::ForceSetPassword),
@@ -519,3 +527,53 @@ impl From<JWTFlow> for UserFlow {
         Self::JWTFlow(value)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_signin_with_passkey_flows() {
+        let flows = Origin::SignInWithPasskey.get_flows();
+
+        // Passkey is itself a second factor, so TOTP is not asked again after signing in with it
+        assert!(!flows.contains(&UserFlow::SPTFlow(SPTFlow::TOTP)));
+        assert!(
+            flows
+                == [
+                    UserFlow::SPTFlow(SPTFlow::MerchantSelect),
+                    UserFlow::JWTFlow(JWTFlow::UserInfo),
+                ]
+        );
+    }
+
+    #[test]
+    fn test_signin_flows_require_totp() {
+        let origins = [
+            Origin::SignIn,
+            Origin::SignUp,
+            Origin::MagicLink,
+            Origin::VerifyEmail,
+            Origin::ResetPassword,
+        ];
+
+        for origin in origins {
+            assert!(
+                origin.get_flows().first() == Some(&UserFlow::SPTFlow(SPTFlow::TOTP)),
+                "{origin:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_signin_with_passkey_origin_serialization() {
+        let origin = serde_json::to_value(Origin::SignInWithPasskey).unwrap();
+
+        assert_eq!(origin, serde_json::json!("sign_in_with_passkey"));
+        assert!(matches!(
+            serde_json::from_value::<Origin>(origin).unwrap(),
+            Origin::SignInWithPasskey
+        ));
+    }
+}
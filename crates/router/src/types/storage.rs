@@ -1,4 +1,5 @@
 pub mod address;
+pub mod analytics_digest_subscription;
 pub mod api_keys;
 pub mod authentication;
 pub mod authorization;
@@ -31,7 +32,9 @@ pub mod merchant_key_store;
 pub mod payment_attempt;
 pub mod payment_link;
 pub mod payment_method;
+pub mod payment_method_migration_job;
 pub mod payout_attempt;
+pub mod payout_batch;
 pub mod payouts;
 pub mod refund;
 #[cfg(feature = "v2")]
@@ -39,10 +42,13 @@ pub mod revenue_recovery;
 pub mod reverse_lookup;
 pub mod role;
 pub mod routing_algorithm;
+pub mod subscription;
 pub mod unified_translations;
 pub mod user;
 pub mod user_authentication_method;
+pub mod user_passkey;
 pub mod user_role;
+pub mod webhook_endpoint;
 
 pub use diesel_models::{
     process_tracker::business_status, ProcessTracker, ProcessTrackerNew, ProcessTrackerRunner,
@@ -68,12 +74,13 @@ pub use hyperswitch_domain_models::{
 pub use scheduler::db::process_tracker;
 
 pub use self::{
-    address::*, api_keys::*, authentication::*, authorization::*, blocklist::*,
-    blocklist_fingerprint::*, blocklist_lookup::*, business_profile::*, callback_mapper::*,
-    capture::*, cards_info::*, configs::*, customers::*, dashboard_metadata::*, dispute::*,
-    dynamic_routing_stats::*, ephemeral_key::*, events::*, file::*, fraud_check::*,
+    address::*, analytics_digest_subscription::*, api_keys::*, authentication::*, authorization::*,
+    blocklist::*, blocklist_fingerprint::*, blocklist_lookup::*, business_profile::*,
+    callback_mapper::*, capture::*, cards_info::*, configs::*, customers::*, dashboard_metadata::*,
+    dispute::*, dynamic_routing_stats::*, ephemeral_key::*, events::*, file::*, fraud_check::*,
     generic_link::*, gsm::*, locker_mock_up::*, mandate::*, merchant_account::*,
     merchant_connector_account::*, merchant_key_store::*, payment_link::*, payment_method::*,
-    process_tracker::*, refund::*, reverse_lookup::*, role::*, routing_algorithm::*,
-    unified_translations::*, user::*, user_authentication_method::*, user_role::*,
+    payment_method_migration_job::*, payout_batch::*, process_tracker::*, refund::*,
+    reverse_lookup::*, role::*, routing_algorithm::*, subscription::*, unified_translations::*,
+    user::*, user_authentication_method::*, user_passkey::*, user_role::*, webhook_endpoint::*,
 };
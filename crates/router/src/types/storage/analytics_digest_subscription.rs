@@ -0,0 +1,3 @@
+pub use diesel_models::analytics_digest_subscription::{
+    AnalyticsDigestSubscription, AnalyticsDigestSubscriptionNew, AnalyticsDigestSubscriptionUpdate,
+};
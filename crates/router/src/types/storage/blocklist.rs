@@ -1 +1,9 @@
-pub use diesel_models::blocklist::{Blocklist, BlocklistNew};
+pub use diesel_models::blocklist::{Blocklist, BlocklistNew, BlocklistUpdate};
+
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone)]
+pub struct BlocklistEntryExpiryTrackingData {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub fingerprint_id: String,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub expires_at: time::PrimitiveDateTime,
+}
@@ -160,3 +160,10 @@ pub struct PaymentMethodStatusTrackingData {
     pub curr_status: enums::PaymentMethodStatus,
     pub merchant_id: common_utils::id_type::MerchantId,
 }
+
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone)]
+pub struct CardAccountUpdaterTrackingData {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    /// The profile to which the webhooks for the updated and closed cards are sent
+    pub profile_id: common_utils::id_type::ProfileId,
+}
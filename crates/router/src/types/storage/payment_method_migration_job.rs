@@ -0,0 +1,11 @@
+pub use diesel_models::payment_method_migration_job::{
+    PaymentMethodMigrationJob, PaymentMethodMigrationJobItem, PaymentMethodMigrationJobItemNew,
+    PaymentMethodMigrationJobItemUpdate, PaymentMethodMigrationJobNew,
+    PaymentMethodMigrationJobUpdate,
+};
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct PaymentMethodMigrationJobTrackingData {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub job_id: String,
+}
@@ -0,0 +1,10 @@
+pub use diesel_models::payout_batch::{
+    PayoutBatch, PayoutBatchItem, PayoutBatchItemNew, PayoutBatchItemUpdate, PayoutBatchNew,
+    PayoutBatchUpdate,
+};
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct PayoutBatchTrackingData {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub batch_id: String,
+}
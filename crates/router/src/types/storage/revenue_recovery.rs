@@ -7,7 +7,7 @@ use hyperswitch_domain_models::{
 };
 use router_env::logger;
 
-use crate::{db::StorageInterface, workflows::revenue_recovery};
+use crate::{db::StorageInterface, types::storage::gsm, workflows::revenue_recovery};
 #[derive(serde::Serialize, serde::Deserialize, Debug)]
 pub struct RevenueRecoveryWorkflowTrackingData {
     pub merchant_id: id_type::MerchantId,
@@ -16,6 +16,50 @@ pub struct RevenueRecoveryWorkflowTrackingData {
     pub payment_attempt_id: id_type::GlobalAttemptId,
     pub billing_mca_id: id_type::MerchantConnectorAccountId,
     pub revenue_recovery_retry: enums::RevenueRecoveryAlgorithmType,
+    /// Retry decisions taken by the task, recorded for auditing
+    #[serde(default)]
+    pub retry_decisions: Vec<RevenueRecoveryRetryDecision>,
+}
+
+/// Classification of the decline of a failed revenue recovery payment attempt
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum RevenueRecoveryDeclineType {
+    /// Retrying with the same payment method is expected to fail again
+    Hard,
+    /// The decline is transient and the payment can be retried later
+    Soft,
+    /// No GSM entry was found for the decline
+    Unknown,
+}
+
+impl RevenueRecoveryDeclineType {
+    pub fn from_gsm_record(gsm_record: Option<&gsm::GatewayStatusMap>) -> Self {
+        match gsm_record {
+            Some(gsm_record) => match gsm_record.error_category {
+                Some(error_category) if error_category.is_hard_decline() => Self::Hard,
+                _ => Self::Soft,
+            },
+            None => Self::Unknown,
+        }
+    }
+}
+
+/// Retry decision taken for a failed revenue recovery payment attempt
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone)]
+pub struct RevenueRecoveryRetryDecision {
+    pub payment_attempt_id: id_type::GlobalAttemptId,
+    pub retry_count: i32,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+    pub gsm_decision: Option<String>,
+    pub error_category: Option<enums::ErrorCategory>,
+    pub decline_type: RevenueRecoveryDeclineType,
+    /// Time at which the payment will be retried, `None` if the payment will not be retried
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub retry_scheduled_at: Option<time::PrimitiveDateTime>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub decided_at: time::PrimitiveDateTime,
 }
 
 #[derive(Debug, Clone)]
@@ -39,12 +83,23 @@ impl RevenueRecoveryPaymentData {
                 None
             }
             enums::RevenueRecoveryAlgorithmType::Cascading => {
-                revenue_recovery::get_schedule_time_to_retry_mit_payments(
-                    db,
-                    merchant_id,
-                    retry_count,
-                )
-                .await
+                match &self.profile.revenue_recovery_retry_policy {
+                    // Retry policy configured on the profile takes precedence over the global config
+                    Some(retry_policy) => {
+                        revenue_recovery::get_schedule_time_to_retry_mit_payments_from_policy(
+                            retry_policy,
+                            retry_count,
+                        )
+                    }
+                    None => {
+                        revenue_recovery::get_schedule_time_to_retry_mit_payments(
+                            db,
+                            merchant_id,
+                            retry_count,
+                        )
+                        .await
+                    }
+                }
             }
             enums::RevenueRecoveryAlgorithmType::Smart => {
                 // TODO: Integrate the smart retry call to return back a schedule time
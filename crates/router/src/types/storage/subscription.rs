@@ -0,0 +1,11 @@
+pub use diesel_models::subscription::{
+    Subscription, SubscriptionInvoice, SubscriptionInvoiceNew, SubscriptionInvoiceUpdate,
+    SubscriptionNew, SubscriptionPlan, SubscriptionPlanNew, SubscriptionPlanUpdate,
+    SubscriptionUpdate,
+};
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct SubscriptionTrackingData {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub subscription_id: String,
+}
@@ -160,6 +160,7 @@ impl ForeignTryFrom<storage_enums::AttemptStatus> for storage_enums::CaptureStat
             | storage_enums::AttemptStatus::AuthenticationPending
             | storage_enums::AttemptStatus::AuthenticationSuccessful
             | storage_enums::AttemptStatus::Authorized
+            | storage_enums::AttemptStatus::PartiallyAuthorized
             | storage_enums::AttemptStatus::AuthorizationFailed
             | storage_enums::AttemptStatus::Authorizing
             | storage_enums::AttemptStatus::CodInitiated
@@ -658,7 +659,8 @@ impl ForeignFrom<storage_enums::PayoutStatus> for Option<storage_enums::EventTyp
             | storage_enums::PayoutStatus::RequiresFulfillment
             | storage_enums::PayoutStatus::RequiresPayoutMethodData
             | storage_enums::PayoutStatus::RequiresVendorAccountCreation
-            | storage_enums::PayoutStatus::RequiresConfirmation => None,
+            | storage_enums::PayoutStatus::RequiresConfirmation
+            | storage_enums::PayoutStatus::Scheduled => None,
         }
     }
 }
@@ -687,6 +689,21 @@ impl ForeignFrom<storage_enums::MandateStatus> for Option<storage_enums::EventTy
     }
 }
 
+impl ForeignFrom<storage_enums::PaymentMethodStatus> for Option<storage_enums::EventType> {
+    fn foreign_from(value: storage_enums::PaymentMethodStatus) -> Self {
+        match value {
+            storage_enums::PaymentMethodStatus::Active => {
+                Some(storage_enums::EventType::PaymentMethodUpdated)
+            }
+            storage_enums::PaymentMethodStatus::Inactive => {
+                Some(storage_enums::EventType::PaymentMethodClosed)
+            }
+            storage_enums::PaymentMethodStatus::Processing
+            | storage_enums::PaymentMethodStatus::AwaitingData => None,
+        }
+    }
+}
+
 impl ForeignTryFrom<api_models::webhooks::IncomingWebhookEvent> for storage_enums::RefundStatus {
     type Error = errors::ValidationError;
 
@@ -889,6 +906,8 @@ impl
             api_key: StrongSecret::from(plaintext_api_key.peek().to_owned()),
             created: api_key.created_at,
             expiration: api_key.expires_at.into(),
+            permission_groups: api_key.permission_groups,
+            profile_id: api_key.profile_id,
         }
     }
 }
@@ -903,6 +922,8 @@ impl ForeignFrom<diesel_models::api_keys::ApiKey> for api_models::api_keys::Retr
             prefix: api_key.prefix.into(),
             created: api_key.created_at,
             expiration: api_key.expires_at.into(),
+            permission_groups: api_key.permission_groups,
+            profile_id: api_key.profile_id,
         }
     }
 }
@@ -916,6 +937,7 @@ impl ForeignFrom<api_models::api_keys::UpdateApiKeyRequest>
             description: api_key.description,
             expires_at: api_key.expiration.map(Into::into),
             last_used: None,
+            permission_groups: api_key.permission_groups,
         }
     }
 }
@@ -1,6 +1,5 @@
 use std::{
     collections::HashMap,
-    ops::Deref,
     str::FromStr,
     sync::{Arc, LazyLock},
 };
@@ -9,26 +8,21 @@ use api_models::enums;
 use common_utils::{date_time, errors::CustomResult, events::ApiEventMetric, ext_traits::AsyncExt};
 use currency_conversion::types::{CurrencyFactors, ExchangeRates};
 use error_stack::ResultExt;
-use masking::PeekInterface;
 use redis_interface::DelReply;
 use router_env::{instrument, tracing};
-use rust_decimal::Decimal;
-use strum::IntoEnumIterator;
 use tokio::sync::RwLock;
 use tracing_futures::Instrument;
 
 use crate::{
     logger,
     routes::app::settings::{Conversion, DefaultExchangeRates},
-    services, SessionState,
+    SessionState,
 };
+
+pub mod providers;
+
 const REDIX_FOREX_CACHE_KEY: &str = "{forex_cache}_lock";
 const REDIX_FOREX_CACHE_DATA: &str = "{forex_cache}_data";
-const FOREX_API_TIMEOUT: u64 = 5;
-const FOREX_BASE_URL: &str = "https://openexchangerates.org/api/latest.json?app_id=";
-const FOREX_BASE_CURRENCY: &str = "&base=USD";
-const FALLBACK_FOREX_BASE_URL: &str = "http://apilayer.net/api/live?access_key=";
-const FALLBACK_FOREX_API_CURRENCY_PREFIX: &str = "USD";
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct FxExchangeRatesCacheEntry {
@@ -83,27 +77,6 @@ pub enum ForexError {
     WriteLockNotAcquired,
 }
 
-#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
-struct ForexResponse {
-    pub rates: HashMap<String, FloatDecimal>,
-}
-
-#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
-struct FallbackForexResponse {
-    pub quotes: HashMap<String, FloatDecimal>,
-}
-
-#[derive(Debug, Copy, Clone, serde::Serialize, serde::Deserialize)]
-#[serde(transparent)]
-struct FloatDecimal(#[serde(with = "rust_decimal::serde::float")] Decimal);
-
-impl Deref for FloatDecimal {
-    type Target = Decimal;
-    fn deref(&self) -> &Self::Target {
-        &self.0
-    }
-}
-
 impl FxExchangeRatesCacheEntry {
     fn new(exchange_rate: ExchangeRates) -> Self {
         Self {
@@ -134,12 +107,12 @@ impl TryFrom<DefaultExchangeRates> for ExchangeRates {
     fn try_from(value: DefaultExchangeRates) -> Result<Self, Self::Error> {
         let mut conversion_usable: HashMap<enums::Currency, CurrencyFactors> = HashMap::new();
         for (curr, conversion) in value.conversion {
-            let enum_curr = enums::Currency::from_str(curr.as_str())
+            let enum_curr = enums::Currency::from_str(curr.to_uppercase().as_str())
                 .change_context(ForexError::ConversionError)
                 .attach_printable("Unable to Convert currency received")?;
             conversion_usable.insert(enum_curr, CurrencyFactors::from(conversion));
         }
-        let base_curr = enums::Currency::from_str(value.base_currency.as_str())
+        let base_curr = enums::Currency::from_str(value.base_currency.to_uppercase().as_str())
             .change_context(ForexError::ConversionError)
             .attach_printable("Unable to convert base currency")?;
         Ok(Self {
@@ -151,10 +124,7 @@ impl TryFrom<DefaultExchangeRates> for ExchangeRates {
 
 impl From<Conversion> for CurrencyFactors {
     fn from(value: Conversion) -> Self {
-        Self {
-            to_factor: value.to_factor,
-            from_factor: value.from_factor,
-        }
+        Self::new(value.to_factor, value.from_factor)
     }
 }
 
@@ -206,9 +176,8 @@ async fn call_forex_api_and_save_data_to_cache_and_redis(
     stale_redis_data: Option<FxExchangeRatesCacheEntry>,
 ) -> CustomResult<FxExchangeRatesCacheEntry, ForexError> {
     // spawn a new thread and do the api fetch and write operations on redis.
-    let forex_api_key = state.conf.forex_api.get_inner().api_key.peek();
-    if forex_api_key.is_empty() {
-        Err(ForexError::ConfigurationError("api_keys not provided".into()).into())
+    if !providers::has_configured_provider(state.conf.forex_api.get_inner()) {
+        Err(ForexError::ConfigurationError("forex providers not configured".into()).into())
     } else {
         let state = state.clone();
         tokio::spawn(
@@ -234,20 +203,11 @@ async fn acquire_redis_lock_and_call_forex_api(
         Err(ForexError::CouldNotAcquireLock.into())
     } else {
         logger::debug!("forex_log: redis lock acquired");
-        let api_rates = fetch_forex_rates_from_primary_api(state).await;
-        match api_rates {
+        match providers::fetch_forex_rates_from_providers(state).await {
             Ok(rates) => save_forex_data_to_cache_and_redis(state, rates).await,
             Err(error) => {
-                logger::error!(forex_error=?error,"primary_forex_error");
-                // API not able to fetch data call secondary service
-                let secondary_api_rates = fetch_forex_rates_from_fallback_api(state).await;
-                match secondary_api_rates {
-                    Ok(rates) => save_forex_data_to_cache_and_redis(state, rates).await,
-                    Err(error) => {
-                        release_redis_lock(state).await?;
-                        Err(error)
-                    }
-                }
+                release_redis_lock(state).await?;
+                Err(error)
             }
         }
     }
@@ -285,157 +245,6 @@ async fn call_forex_api_if_redis_data_expired(
     }
 }
 
-async fn fetch_forex_rates_from_primary_api(
-    state: &SessionState,
-) -> Result<FxExchangeRatesCacheEntry, error_stack::Report<ForexError>> {
-    let forex_api_key = state.conf.forex_api.get_inner().api_key.peek();
-
-    logger::debug!("forex_log: Primary api call for forex fetch");
-    let forex_url: String = format!("{}{}{}", FOREX_BASE_URL, forex_api_key, FOREX_BASE_CURRENCY);
-    let forex_request = services::RequestBuilder::new()
-        .method(services::Method::Get)
-        .url(&forex_url)
-        .build();
-
-    logger::info!(primary_forex_request=?forex_request,"forex_log: Primary api call for forex fetch");
-    let response = state
-        .api_client
-        .send_request(
-            &state.clone(),
-            forex_request,
-            Some(FOREX_API_TIMEOUT),
-            false,
-        )
-        .await
-        .change_context(ForexError::ApiUnresponsive)
-        .attach_printable("Primary forex fetch api unresponsive")?;
-    let forex_response = response
-        .json::<ForexResponse>()
-        .await
-        .change_context(ForexError::ParsingError)
-        .attach_printable(
-            "Unable to parse response received from primary api into ForexResponse",
-        )?;
-
-    logger::info!(primary_forex_response=?forex_response,"forex_log");
-
-    let mut conversions: HashMap<enums::Currency, CurrencyFactors> = HashMap::new();
-    for enum_curr in enums::Currency::iter() {
-        match forex_response.rates.get(&enum_curr.to_string()) {
-            Some(rate) => {
-                let from_factor = match Decimal::new(1, 0).checked_div(**rate) {
-                    Some(rate) => rate,
-                    None => {
-                        logger::error!(
-                            "forex_error: Rates for {} not received from API",
-                            &enum_curr
-                        );
-                        continue;
-                    }
-                };
-                let currency_factors = CurrencyFactors::new(**rate, from_factor);
-                conversions.insert(enum_curr, currency_factors);
-            }
-            None => {
-                logger::error!(
-                    "forex_error: Rates for {} not received from API",
-                    &enum_curr
-                );
-            }
-        };
-    }
-
-    Ok(FxExchangeRatesCacheEntry::new(ExchangeRates::new(
-        enums::Currency::USD,
-        conversions,
-    )))
-}
-
-pub async fn fetch_forex_rates_from_fallback_api(
-    state: &SessionState,
-) -> CustomResult<FxExchangeRatesCacheEntry, ForexError> {
-    let fallback_forex_api_key = state.conf.forex_api.get_inner().fallback_api_key.peek();
-
-    let fallback_forex_url: String =
-        format!("{}{}", FALLBACK_FOREX_BASE_URL, fallback_forex_api_key,);
-    let fallback_forex_request = services::RequestBuilder::new()
-        .method(services::Method::Get)
-        .url(&fallback_forex_url)
-        .build();
-
-    logger::info!(fallback_forex_request=?fallback_forex_request,"forex_log: Fallback api call for forex fetch");
-    let response = state
-        .api_client
-        .send_request(
-            &state.clone(),
-            fallback_forex_request,
-            Some(FOREX_API_TIMEOUT),
-            false,
-        )
-        .await
-        .change_context(ForexError::ApiUnresponsive)
-        .attach_printable("Fallback forex fetch api unresponsive")?;
-
-    let fallback_forex_response = response
-        .json::<FallbackForexResponse>()
-        .await
-        .change_context(ForexError::ParsingError)
-        .attach_printable(
-            "Unable to parse response received from fallback api into ForexResponse",
-        )?;
-
-    logger::info!(fallback_forex_response=?fallback_forex_response,"forex_log");
-
-    let mut conversions: HashMap<enums::Currency, CurrencyFactors> = HashMap::new();
-    for enum_curr in enums::Currency::iter() {
-        match fallback_forex_response.quotes.get(
-            format!(
-                "{}{}",
-                FALLBACK_FOREX_API_CURRENCY_PREFIX,
-                &enum_curr.to_string()
-            )
-            .as_str(),
-        ) {
-            Some(rate) => {
-                let from_factor = match Decimal::new(1, 0).checked_div(**rate) {
-                    Some(rate) => rate,
-                    None => {
-                        logger::error!(
-                            "forex_error: Rates for {} not received from API",
-                            &enum_curr
-                        );
-                        continue;
-                    }
-                };
-                let currency_factors = CurrencyFactors::new(**rate, from_factor);
-                conversions.insert(enum_curr, currency_factors);
-            }
-            None => {
-                if enum_curr == enums::Currency::USD {
-                    let currency_factors =
-                        CurrencyFactors::new(Decimal::new(1, 0), Decimal::new(1, 0));
-                    conversions.insert(enum_curr, currency_factors);
-                } else {
-                    logger::error!(
-                        "forex_error: Rates for {} not received from API",
-                        &enum_curr
-                    );
-                }
-            }
-        };
-    }
-
-    let rates =
-        FxExchangeRatesCacheEntry::new(ExchangeRates::new(enums::Currency::USD, conversions));
-    match acquire_redis_lock(state).await {
-        Ok(_) => {
-            save_forex_data_to_cache_and_redis(state, rates.clone()).await?;
-            Ok(rates)
-        }
-        Err(e) => Err(e),
-    }
-}
-
 async fn release_redis_lock(
     state: &SessionState,
 ) -> Result<DelReply, error_stack::Report<ForexError>> {
@@ -546,3 +355,43 @@ pub async fn convert_currency(
         currency: to_currency.to_string(),
     })
 }
+
+#[instrument(skip_all)]
+pub async fn convert_currency_with_rounding(
+    state: SessionState,
+    params: api_models::currency::RoundedCurrencyConversionParams,
+) -> CustomResult<api_models::currency::RoundedCurrencyConversionResponse, ForexError> {
+    let forex_api = state.conf.forex_api.get_inner();
+    let rates = get_forex_rates(&state, forex_api.data_expiration_delay_in_seconds)
+        .await
+        .change_context(ForexError::ApiError)?;
+
+    let to_currency = enums::Currency::from_str(params.to_currency.as_str())
+        .change_context(ForexError::CurrencyNotAcceptable)
+        .attach_printable("The provided currency is not acceptable")?;
+
+    let from_currency = enums::Currency::from_str(params.from_currency.as_str())
+        .change_context(ForexError::CurrencyNotAcceptable)
+        .attach_printable("The provided currency is not acceptable")?;
+
+    let converted_minor_amount = currency_conversion::conversion::convert_to_minor_unit(
+        &rates.data,
+        from_currency,
+        to_currency,
+        params.amount,
+        params.rounding_mode.unwrap_or_default(),
+        params.markup_bps,
+    )
+    .change_context(ForexError::ConversionError)
+    .attach_printable("Unable to perform currency conversion")?;
+    let converted_amount = currency_conversion::conversion::to_major_unit(
+        converted_minor_amount.get_amount_as_i64(),
+        to_currency,
+    );
+
+    Ok(api_models::currency::RoundedCurrencyConversionResponse {
+        converted_amount: converted_amount.to_string(),
+        converted_minor_amount,
+        currency: to_currency.to_string(),
+    })
+}
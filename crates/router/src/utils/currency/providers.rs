@@ -0,0 +1,578 @@
+use std::{collections::HashMap, future::Future, ops::Deref, path::Path};
+
+use api_models::enums;
+use common_utils::{date_time, errors::CustomResult, ext_traits::StringExt};
+use currency_conversion::types::{CurrencyFactors, ExchangeRates, RateSource};
+use error_stack::ResultExt;
+use masking::PeekInterface;
+use rust_decimal::Decimal;
+use strum::IntoEnumIterator;
+
+use super::{ForexError, FxExchangeRatesCacheEntry};
+use crate::{
+    logger,
+    routes::app::settings::{DefaultExchangeRates, ForexApi, ForexProviderType},
+    services, SessionState,
+};
+
+const FOREX_API_TIMEOUT: u64 = 5;
+const FOREX_BASE_URL: &str = "https://openexchangerates.org/api/latest.json?app_id=";
+const FOREX_BASE_CURRENCY: &str = "&base=USD";
+const FALLBACK_FOREX_BASE_URL: &str = "http://apilayer.net/api/live?access_key=";
+const FALLBACK_FOREX_API_CURRENCY_PREFIX: &str = "USD";
+
+/// Key of the entry in the `configs` table which holds the rates set by an admin, in the same
+/// format as the rates file.
+pub const MANUAL_FOREX_RATES_CONFIG_KEY: &str = "forex_manual_rates";
+
+/// A source of forex rates. Every rate returned by a provider records the provider and the time at
+/// which the rate was published.
+#[async_trait::async_trait]
+pub trait ForexProvider: Send + Sync {
+    fn provider_type(&self) -> ForexProviderType;
+
+    /// Whether the settings contain everything the provider needs to fetch rates
+    fn is_configured(&self, forex_api: &ForexApi) -> bool;
+
+    async fn fetch_rates(&self, state: &SessionState) -> CustomResult<ExchangeRates, ForexError>;
+}
+
+pub fn get_forex_provider(provider_type: ForexProviderType) -> Box<dyn ForexProvider> {
+    match provider_type {
+        ForexProviderType::OpenExchangeRates => Box::new(OpenExchangeRatesProvider),
+        ForexProviderType::ApiLayer => Box::new(ApiLayerProvider),
+        ForexProviderType::RatesFile => Box::new(RatesFileProvider),
+        ForexProviderType::Manual => Box::new(ManualRatesProvider),
+    }
+}
+
+/// Whether any of the providers in the settings is able to fetch rates, so that refreshing the
+/// rates is skipped altogether when none of them is.
+pub(super) fn has_configured_provider(forex_api: &ForexApi) -> bool {
+    forex_api
+        .providers
+        .iter()
+        .any(|provider_type| get_forex_provider(*provider_type).is_configured(forex_api))
+}
+
+/// Fetch the rates from the configured providers in order of preference, falling back to the next
+/// provider whenever a provider fails.
+pub(super) async fn fetch_forex_rates_from_providers(
+    state: &SessionState,
+) -> CustomResult<FxExchangeRatesCacheEntry, ForexError> {
+    fetch_rates_in_order_of_preference(
+        &state.conf.forex_api.get_inner().providers,
+        |provider_type| async move { get_forex_provider(provider_type).fetch_rates(state).await },
+    )
+    .await
+    .map(FxExchangeRatesCacheEntry::new)
+}
+
+async fn fetch_rates_in_order_of_preference<F, Fut>(
+    provider_types: &[ForexProviderType],
+    fetch_rates: F,
+) -> CustomResult<ExchangeRates, ForexError>
+where
+    F: Fn(ForexProviderType) -> Fut,
+    Fut: Future<Output = CustomResult<ExchangeRates, ForexError>>,
+{
+    for provider_type in provider_types {
+        logger::debug!("forex_log: Fetching forex rates from {provider_type}");
+        match fetch_rates(*provider_type).await {
+            Ok(rates) => return Ok(rates),
+            Err(error) => {
+                logger::error!(forex_error=?error, %provider_type, "forex_provider_error");
+            }
+        }
+    }
+
+    Err(ForexError::ForexDataUnavailable)
+        .attach_printable("None of the configured forex providers supplied rates")
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+struct ForexResponse {
+    pub rates: HashMap<String, FloatDecimal>,
+    pub timestamp: Option<i64>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+struct FallbackForexResponse {
+    pub quotes: HashMap<String, FloatDecimal>,
+    pub timestamp: Option<i64>,
+}
+
+#[derive(Debug, Copy, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(transparent)]
+struct FloatDecimal(#[serde(with = "rust_decimal::serde::float")] Decimal);
+
+impl Deref for FloatDecimal {
+    type Target = Decimal;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+fn get_rate_source(provider_type: ForexProviderType, timestamp: Option<i64>) -> RateSource {
+    RateSource {
+        provider: provider_type.to_string(),
+        timestamp: timestamp.unwrap_or_else(date_time::now_unix_timestamp),
+    }
+}
+
+fn exchange_rates_from_default_rates(
+    provider_type: ForexProviderType,
+    default_rates: DefaultExchangeRates,
+) -> CustomResult<ExchangeRates, ForexError> {
+    let source = get_rate_source(provider_type, Some(default_rates.timestamp));
+    ExchangeRates::try_from(default_rates).map(|rates| rates.with_source(source))
+}
+
+pub struct OpenExchangeRatesProvider;
+
+#[async_trait::async_trait]
+impl ForexProvider for OpenExchangeRatesProvider {
+    fn provider_type(&self) -> ForexProviderType {
+        ForexProviderType::OpenExchangeRates
+    }
+
+    fn is_configured(&self, forex_api: &ForexApi) -> bool {
+        !forex_api.api_key.peek().is_empty()
+    }
+
+    async fn fetch_rates(&self, state: &SessionState) -> CustomResult<ExchangeRates, ForexError> {
+        let forex_api_key = state.conf.forex_api.get_inner().api_key.peek();
+        if forex_api_key.is_empty() {
+            return Err(ForexError::ConfigurationError("api_key not provided".into()).into());
+        }
+
+        logger::debug!("forex_log: Primary api call for forex fetch");
+        let forex_url: String =
+            format!("{}{}{}", FOREX_BASE_URL, forex_api_key, FOREX_BASE_CURRENCY);
+        let forex_request = services::RequestBuilder::new()
+            .method(services::Method::Get)
+            .url(&forex_url)
+            .build();
+
+        logger::info!(primary_forex_request=?forex_request,"forex_log: Primary api call for forex fetch");
+        let response = state
+            .api_client
+            .send_request(
+                &state.clone(),
+                forex_request,
+                Some(FOREX_API_TIMEOUT),
+                false,
+            )
+            .await
+            .change_context(ForexError::ApiUnresponsive)
+            .attach_printable("Primary forex fetch api unresponsive")?;
+        let forex_response = response
+            .json::<ForexResponse>()
+            .await
+            .change_context(ForexError::ParsingError)
+            .attach_printable(
+                "Unable to parse response received from primary api into ForexResponse",
+            )?;
+
+        logger::info!(primary_forex_response=?forex_response,"forex_log");
+
+        let mut conversions: HashMap<enums::Currency, CurrencyFactors> = HashMap::new();
+        for enum_curr in enums::Currency::iter() {
+            match forex_response.rates.get(&enum_curr.to_string()) {
+                Some(rate) => {
+                    let from_factor = match Decimal::new(1, 0).checked_div(**rate) {
+                        Some(rate) => rate,
+                        None => {
+                            logger::error!(
+                                "forex_error: Rates for {} not received from API",
+                                &enum_curr
+                            );
+                            continue;
+                        }
+                    };
+                    let currency_factors = CurrencyFactors::new(**rate, from_factor);
+                    conversions.insert(enum_curr, currency_factors);
+                }
+                None => {
+                    logger::error!(
+                        "forex_error: Rates for {} not received from API",
+                        &enum_curr
+                    );
+                }
+            };
+        }
+
+        Ok(
+            ExchangeRates::new(enums::Currency::USD, conversions).with_source(get_rate_source(
+                self.provider_type(),
+                forex_response.timestamp,
+            )),
+        )
+    }
+}
+
+pub struct ApiLayerProvider;
+
+#[async_trait::async_trait]
+impl ForexProvider for ApiLayerProvider {
+    fn provider_type(&self) -> ForexProviderType {
+        ForexProviderType::ApiLayer
+    }
+
+    fn is_configured(&self, forex_api: &ForexApi) -> bool {
+        !forex_api.fallback_api_key.peek().is_empty()
+    }
+
+    async fn fetch_rates(&self, state: &SessionState) -> CustomResult<ExchangeRates, ForexError> {
+        let fallback_forex_api_key = state.conf.forex_api.get_inner().fallback_api_key.peek();
+        if fallback_forex_api_key.is_empty() {
+            return Err(
+                ForexError::ConfigurationError("fallback_api_key not provided".into()).into(),
+            );
+        }
+
+        let fallback_forex_url: String =
+            format!("{}{}", FALLBACK_FOREX_BASE_URL, fallback_forex_api_key,);
+        let fallback_forex_request = services::RequestBuilder::new()
+            .method(services::Method::Get)
+            .url(&fallback_forex_url)
+            .build();
+
+        logger::info!(fallback_forex_request=?fallback_forex_request,"forex_log: Fallback api call for forex fetch");
+        let response = state
+            .api_client
+            .send_request(
+                &state.clone(),
+                fallback_forex_request,
+                Some(FOREX_API_TIMEOUT),
+                false,
+            )
+            .await
+            .change_context(ForexError::ApiUnresponsive)
+            .attach_printable("Fallback forex fetch api unresponsive")?;
+
+        let fallback_forex_response = response
+            .json::<FallbackForexResponse>()
+            .await
+            .change_context(ForexError::ParsingError)
+            .attach_printable(
+                "Unable to parse response received from fallback api into ForexResponse",
+            )?;
+
+        logger::info!(fallback_forex_response=?fallback_forex_response,"forex_log");
+
+        let mut conversions: HashMap<enums::Currency, CurrencyFactors> = HashMap::new();
+        for enum_curr in enums::Currency::iter() {
+            match fallback_forex_response.quotes.get(
+                format!(
+                    "{}{}",
+                    FALLBACK_FOREX_API_CURRENCY_PREFIX,
+                    &enum_curr.to_string()
+                )
+                .as_str(),
+            ) {
+                Some(rate) => {
+                    let from_factor = match Decimal::new(1, 0).checked_div(**rate) {
+                        Some(rate) => rate,
+                        None => {
+                            logger::error!(
+                                "forex_error: Rates for {} not received from API",
+                                &enum_curr
+                            );
+                            continue;
+                        }
+                    };
+                    let currency_factors = CurrencyFactors::new(**rate, from_factor);
+                    conversions.insert(enum_curr, currency_factors);
+                }
+                None => {
+                    if enum_curr == enums::Currency::USD {
+                        let currency_factors =
+                            CurrencyFactors::new(Decimal::new(1, 0), Decimal::new(1, 0));
+                        conversions.insert(enum_curr, currency_factors);
+                    } else {
+                        logger::error!(
+                            "forex_error: Rates for {} not received from API",
+                            &enum_curr
+                        );
+                    }
+                }
+            };
+        }
+
+        Ok(
+            ExchangeRates::new(enums::Currency::USD, conversions).with_source(get_rate_source(
+                self.provider_type(),
+                fallback_forex_response.timestamp,
+            )),
+        )
+    }
+}
+
+/// Rates read from a TOML or JSON file, for deployments which cannot reach the forex APIs. The
+/// file is read on every refresh of the rates, so that it can be updated without a restart.
+pub struct RatesFileProvider;
+
+#[async_trait::async_trait]
+impl ForexProvider for RatesFileProvider {
+    fn provider_type(&self) -> ForexProviderType {
+        ForexProviderType::RatesFile
+    }
+
+    fn is_configured(&self, forex_api: &ForexApi) -> bool {
+        forex_api.rates_file_path.is_some()
+    }
+
+    async fn fetch_rates(&self, state: &SessionState) -> CustomResult<ExchangeRates, ForexError> {
+        let rates_file_path = state
+            .conf
+            .forex_api
+            .get_inner()
+            .rates_file_path
+            .clone()
+            .ok_or(ForexError::ConfigurationError(
+                "rates_file_path not provided".into(),
+            ))?;
+
+        logger::debug!(?rates_file_path, "forex_log: Reading forex rates from file");
+        let default_rates = read_rates_file(&rates_file_path)?;
+
+        exchange_rates_from_default_rates(self.provider_type(), default_rates)
+    }
+}
+
+fn read_rates_file(rates_file_path: &Path) -> CustomResult<DefaultExchangeRates, ForexError> {
+    // The format of the file is inferred from its extension
+    config::Config::builder()
+        .add_source(config::File::from(rates_file_path))
+        .build()
+        .and_then(|rates| rates.try_deserialize::<DefaultExchangeRates>())
+        .change_context(ForexError::LocalReadError)
+        .attach_printable_lazy(|| {
+            format!("Unable to read forex rates from file {rates_file_path:?}")
+        })
+}
+
+/// Rates set by an admin through the configs API, under the key
+/// [`MANUAL_FOREX_RATES_CONFIG_KEY`].
+pub struct ManualRatesProvider;
+
+#[async_trait::async_trait]
+impl ForexProvider for ManualRatesProvider {
+    fn provider_type(&self) -> ForexProviderType {
+        ForexProviderType::Manual
+    }
+
+    // The rates are set at runtime, so their presence is checked only when fetching them
+    fn is_configured(&self, _forex_api: &ForexApi) -> bool {
+        true
+    }
+
+    async fn fetch_rates(&self, state: &SessionState) -> CustomResult<ExchangeRates, ForexError> {
+        let manual_rates = state
+            .store
+            .find_config_by_key(MANUAL_FOREX_RATES_CONFIG_KEY)
+            .await
+            .change_context(ForexError::EntryNotFound)
+            .attach_printable("Manual forex rates are not configured")?
+            .config;
+        let default_rates = parse_manual_rates(manual_rates)?;
+
+        exchange_rates_from_default_rates(self.provider_type(), default_rates)
+    }
+}
+
+fn parse_manual_rates(manual_rates: String) -> CustomResult<DefaultExchangeRates, ForexError> {
+    manual_rates
+        .parse_struct::<DefaultExchangeRates>("DefaultExchangeRates")
+        .change_context(ForexError::ParsingError)
+        .attach_printable("Unable to parse manual forex rates")
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used)]
+    use std::sync::Mutex;
+
+    use super::*;
+
+    const RATES_FILE_TOML: &str = r#"
+base_currency = "USD"
+timestamp = 1735689600
+
+[conversion.EUR]
+to_factor = "0.92"
+from_factor = "1.087"
+"#;
+
+    const RATES_JSON: &str = r#"{
+        "base_currency": "USD",
+        "timestamp": 1735689600,
+        "conversion": {
+            "EUR": { "to_factor": "0.92", "from_factor": "1.087" }
+        }
+    }"#;
+
+    fn write_rates_file(file_name: &str, contents: &str) -> std::path::PathBuf {
+        let rates_file_path =
+            std::env::temp_dir().join(format!("{}_{file_name}", std::process::id()));
+        std::fs::write(&rates_file_path, contents).expect("Failed to write the rates file");
+        rates_file_path
+    }
+
+    fn assert_eur_rates(rates: &ExchangeRates, provider_type: ForexProviderType) {
+        let eur_factors = rates.conversion.get(&enums::Currency::EUR).unwrap();
+
+        assert_eq!(rates.base_currency, enums::Currency::USD);
+        assert_eq!(eur_factors.to_factor, Decimal::new(92, 2));
+        assert_eq!(eur_factors.from_factor, Decimal::new(1087, 3));
+        assert_eq!(
+            eur_factors.source,
+            Some(RateSource {
+                provider: provider_type.to_string(),
+                timestamp: 1735689600,
+            })
+        );
+    }
+
+    fn get_rates_from_provider(provider_type: ForexProviderType) -> ExchangeRates {
+        ExchangeRates::new(
+            enums::Currency::USD,
+            HashMap::from([(
+                enums::Currency::EUR,
+                CurrencyFactors::new(Decimal::new(92, 2), Decimal::new(1087, 3)),
+            )]),
+        )
+        .with_source(get_rate_source(provider_type, Some(1735689600)))
+    }
+
+    #[test]
+    fn test_rates_file_in_toml_format() {
+        let rates_file_path = write_rates_file("forex_rates.toml", RATES_FILE_TOML);
+        let default_rates = read_rates_file(&rates_file_path);
+        std::fs::remove_file(&rates_file_path).ok();
+
+        let rates =
+            exchange_rates_from_default_rates(ForexProviderType::RatesFile, default_rates.unwrap())
+                .unwrap();
+
+        assert_eur_rates(&rates, ForexProviderType::RatesFile);
+    }
+
+    #[test]
+    fn test_rates_file_in_json_format() {
+        let rates_file_path = write_rates_file("forex_rates.json", RATES_JSON);
+        let default_rates = read_rates_file(&rates_file_path);
+        std::fs::remove_file(&rates_file_path).ok();
+
+        let rates =
+            exchange_rates_from_default_rates(ForexProviderType::RatesFile, default_rates.unwrap())
+                .unwrap();
+
+        assert_eur_rates(&rates, ForexProviderType::RatesFile);
+    }
+
+    #[test]
+    fn test_missing_rates_file() {
+        let error = read_rates_file(Path::new("/nonexistent/forex_rates.toml")).unwrap_err();
+
+        assert!(matches!(
+            error.current_context(),
+            ForexError::LocalReadError
+        ));
+    }
+
+    #[test]
+    fn test_manual_rates() {
+        let default_rates = parse_manual_rates(RATES_JSON.to_string()).unwrap();
+        let rates =
+            exchange_rates_from_default_rates(ForexProviderType::Manual, default_rates).unwrap();
+
+        assert_eur_rates(&rates, ForexProviderType::Manual);
+    }
+
+    #[test]
+    fn test_invalid_manual_rates() {
+        let error = parse_manual_rates(r#"{"base_currency": "USD"}"#.to_string()).unwrap_err();
+
+        assert!(matches!(error.current_context(), ForexError::ParsingError));
+    }
+
+    #[test]
+    fn test_manual_rates_with_unknown_currency() {
+        let default_rates = parse_manual_rates(RATES_JSON.replace("EUR", "XYZ")).unwrap();
+        let error = exchange_rates_from_default_rates(ForexProviderType::Manual, default_rates)
+            .unwrap_err();
+
+        assert!(matches!(
+            error.current_context(),
+            ForexError::ConversionError
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_providers_are_tried_in_order_of_preference() {
+        let attempted_providers = Mutex::new(Vec::new());
+
+        let rates = fetch_rates_in_order_of_preference(
+            &[
+                ForexProviderType::RatesFile,
+                ForexProviderType::Manual,
+                ForexProviderType::OpenExchangeRates,
+            ],
+            |provider_type| {
+                attempted_providers.lock().unwrap().push(provider_type);
+                async move {
+                    match provider_type {
+                        ForexProviderType::RatesFile => Err(ForexError::LocalReadError.into()),
+                        _ => Ok(get_rates_from_provider(provider_type)),
+                    }
+                }
+            },
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            attempted_providers.into_inner().unwrap(),
+            vec![ForexProviderType::RatesFile, ForexProviderType::Manual]
+        );
+        assert_eur_rates(&rates, ForexProviderType::Manual);
+    }
+
+    #[tokio::test]
+    async fn test_no_rates_when_all_providers_fail() {
+        let error = fetch_rates_in_order_of_preference(
+            &[
+                ForexProviderType::OpenExchangeRates,
+                ForexProviderType::ApiLayer,
+            ],
+            |_provider_type| async { Err(ForexError::ApiUnresponsive.into()) },
+        )
+        .await
+        .unwrap_err();
+
+        assert!(matches!(
+            error.current_context(),
+            ForexError::ForexDataUnavailable
+        ));
+    }
+
+    #[test]
+    fn test_has_configured_provider() {
+        let forex_api = ForexApi::default();
+        assert!(!has_configured_provider(&forex_api));
+
+        let forex_api = ForexApi {
+            fallback_api_key: masking::Secret::new("fallback_api_key".to_string()),
+            ..ForexApi::default()
+        };
+        assert!(has_configured_provider(&forex_api));
+
+        let forex_api = ForexApi {
+            providers: vec![ForexProviderType::Manual],
+            ..ForexApi::default()
+        };
+        assert!(has_configured_provider(&forex_api));
+    }
+}
@@ -41,6 +41,7 @@ use crate::{
 };
 
 pub mod dashboard_metadata;
+pub mod passkey;
 pub mod password;
 #[cfg(feature = "dummy_connector")]
 pub mod sample_data;
@@ -163,6 +164,7 @@ impl ForeignFrom<&user_api::AuthConfig> for UserAuthType {
             user_api::AuthConfig::OpenIdConnect { .. } => Self::OpenIdConnect,
             user_api::AuthConfig::Password => Self::Password,
             user_api::AuthConfig::MagicLink => Self::MagicLink,
+            user_api::AuthConfig::Passkey => Self::Passkey,
         }
     }
 }
@@ -204,7 +206,9 @@ pub async fn construct_public_and_private_db_configs(
                 ),
             ))
         }
-        user_api::AuthConfig::Password | user_api::AuthConfig::MagicLink => Ok((None, None)),
+        user_api::AuthConfig::Password
+        | user_api::AuthConfig::MagicLink
+        | user_api::AuthConfig::Passkey => Ok((None, None)),
     }
 }
 
@@ -295,7 +299,7 @@ pub fn get_oidc_sso_redirect_url(state: &SessionState, provider: &str) -> String
 pub fn is_sso_auth_type(auth_type: UserAuthType) -> bool {
     match auth_type {
         UserAuthType::OpenIdConnect => true,
-        UserAuthType::Password | UserAuthType::MagicLink => false,
+        UserAuthType::Password | UserAuthType::MagicLink | UserAuthType::Passkey => false,
     }
 }
 
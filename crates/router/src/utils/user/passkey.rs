@@ -0,0 +1,422 @@
+use api_models::user as user_api;
+use base64::Engine;
+use error_stack::ResultExt;
+use redis_interface::{errors::RedisError, DelReply, RedisKey};
+use webauthn_rs::prelude::{
+    AuthenticationResult, CredentialID, DiscoverableAuthentication, DiscoverableKey, Passkey,
+    PasskeyAuthentication, PasskeyRegistration, PublicKeyCredential, RegisterPublicKeyCredential,
+    Url, Uuid, Webauthn, WebauthnBuilder,
+};
+
+use crate::{
+    consts,
+    core::errors::{UserErrors, UserResult},
+    routes::SessionState,
+    types::{storage, transformers::ForeignFrom},
+};
+
+/// Relying party for the WebAuthn ceremonies, which is the dashboard of the tenant.
+fn get_webauthn(state: &SessionState) -> UserResult<Webauthn> {
+    let rp_origin = Url::parse(super::get_base_url(state))
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to parse dashboard base url")?;
+    let rp_id = rp_origin
+        .host_str()
+        .ok_or(UserErrors::InternalServerError)
+        .attach_printable("Dashboard base url does not have a host")?;
+
+    WebauthnBuilder::new(rp_id, &rp_origin)
+        .map(|builder| builder.rp_name(&state.conf.user.totp_issuer_name))
+        .and_then(|builder| builder.build())
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to build webauthn relying party")
+}
+
+/// Namespace of the user handles, so that they do not collide with other name based uuids.
+const USER_HANDLE_NAMESPACE: Uuid = Uuid::from_u128(0x6d1c_7a3e_9f42_4b8e_a5d0_2c71_e8b9_f314);
+
+/// The user handle stored in the passkeys of the user. It is derived from the user id, as the user
+/// id is not guaranteed to be a uuid.
+fn get_user_handle(user_id: &str) -> Uuid {
+    Uuid::new_v5(&USER_HANDLE_NAMESPACE, user_id.as_bytes())
+}
+
+impl ForeignFrom<storage::UserPasskey> for user_api::PasskeyResponse {
+    fn foreign_from(from: storage::UserPasskey) -> Self {
+        Self {
+            passkey_id: from.passkey_id,
+            name: from.name,
+            created_at: from.created_at,
+            last_used_at: from.last_used_at,
+        }
+    }
+}
+
+pub fn get_credential_id(passkey: &Passkey) -> String {
+    common_utils::consts::BASE64_ENGINE_URL_SAFE.encode(passkey.cred_id())
+}
+
+pub async fn get_passkeys_for_user(
+    state: &SessionState,
+    user_id: &str,
+) -> UserResult<Vec<(storage::UserPasskey, Passkey)>> {
+    state
+        .global_store
+        .list_user_passkeys_by_user_id(user_id)
+        .await
+        .change_context(UserErrors::InternalServerError)?
+        .into_iter()
+        .map(|user_passkey| {
+            let passkey = serde_json::from_value(user_passkey.passkey.clone())
+                .change_context(UserErrors::InternalServerError)
+                .attach_printable("Failed to parse stored passkey")?;
+            Ok((user_passkey, passkey))
+        })
+        .collect()
+}
+
+/// Creates a registration challenge for a new passkey of the user, and returns the options to be
+/// passed to the authenticator. Passkeys already registered by the user are excluded.
+pub async fn start_registration(
+    state: &SessionState,
+    user_id: &str,
+    user_name: &str,
+    display_name: &str,
+    existing_passkeys: &[Passkey],
+) -> UserResult<serde_json::Value> {
+    let exclude_credentials = existing_passkeys
+        .iter()
+        .map(|passkey| passkey.cred_id().clone())
+        .collect::<Vec<CredentialID>>();
+
+    let (options, registration) = get_webauthn(state)?
+        .start_passkey_registration(
+            get_user_handle(user_id),
+            user_name,
+            display_name,
+            Some(exclude_credentials).filter(|credentials| !credentials.is_empty()),
+        )
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to start passkey registration")?;
+
+    insert_registration_state_in_redis(state, user_id, &registration).await?;
+
+    serde_json::to_value(options).change_context(UserErrors::InternalServerError)
+}
+
+/// Verifies the attestation of the authenticator against the challenge issued to the user, and
+/// returns the passkey to be stored.
+pub async fn finish_registration(
+    state: &SessionState,
+    user_id: &str,
+    credential: serde_json::Value,
+) -> UserResult<Passkey> {
+    let registration = get_registration_state_from_redis(state, user_id).await?;
+    let credential: RegisterPublicKeyCredential = serde_json::from_value(credential)
+        .change_context(UserErrors::InvalidPasskey)
+        .attach_printable("Failed to parse passkey credential")?;
+
+    get_webauthn(state)?
+        .finish_passkey_registration(&credential, &registration)
+        .change_context(UserErrors::InvalidPasskey)
+}
+
+/// Creates an authentication challenge for the passkeys of the user, and returns the options to
+/// be passed to the authenticator.
+pub async fn start_authentication(
+    state: &SessionState,
+    user_id: &str,
+) -> UserResult<serde_json::Value> {
+    let passkeys = get_passkeys_for_user(state, user_id)
+        .await?
+        .into_iter()
+        .map(|(_, passkey)| passkey)
+        .collect::<Vec<_>>();
+
+    if passkeys.is_empty() {
+        return Err(UserErrors::PasskeyNotSetup.into());
+    }
+
+    let (options, authentication) = get_webauthn(state)?
+        .start_passkey_authentication(&passkeys)
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to start passkey authentication")?;
+
+    insert_authentication_state_in_redis(state, user_id, &authentication).await?;
+
+    serde_json::to_value(options).change_context(UserErrors::InternalServerError)
+}
+
+/// Verifies the assertion of the authenticator against the challenge issued to the user.
+pub async fn finish_authentication(
+    state: &SessionState,
+    user_id: &str,
+    credential: serde_json::Value,
+) -> UserResult<()> {
+    let authentication = get_authentication_state_from_redis(state, user_id).await?;
+    let credential: PublicKeyCredential = serde_json::from_value(credential)
+        .change_context(UserErrors::InvalidPasskey)
+        .attach_printable("Failed to parse passkey credential")?;
+
+    let authentication_result = get_webauthn(state)?
+        .finish_passkey_authentication(&credential, &authentication)
+        .change_context(UserErrors::InvalidPasskey)?;
+
+    let passkeys = get_passkeys_for_user(state, user_id).await?;
+    update_passkey_usage(state, user_id, passkeys, &authentication_result).await
+}
+
+/// Creates a sign in challenge which can be answered by any passkey registered for the dashboard,
+/// so that it does not reveal whether a user exists. The challenge is stored against a random id,
+/// which is returned along with the options to be passed to the authenticator.
+pub async fn start_signin(state: &SessionState) -> UserResult<(String, serde_json::Value)> {
+    let (options, authentication) = get_webauthn(state)?
+        .start_discoverable_authentication()
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to start passkey sign in")?;
+
+    let challenge_id = Uuid::new_v4().to_string();
+    insert_signin_state_in_redis(state, &challenge_id, &authentication).await?;
+
+    let options = serde_json::to_value(options).change_context(UserErrors::InternalServerError)?;
+    Ok((challenge_id, options))
+}
+
+/// Verifies the assertion of the authenticator against the sign in challenge, and checks that the
+/// passkey used belongs to the user signing in.
+///
+/// The sign in request is not authenticated, so only an assertion made using a passkey of the user
+/// which fails verification is reported as `InvalidPasskey`, and counted against the attempts of
+/// the user. Any other credential is rejected with `InvalidCredentials`.
+pub async fn finish_signin(
+    state: &SessionState,
+    user_id: &str,
+    challenge_id: &str,
+    credential: serde_json::Value,
+) -> UserResult<()> {
+    let authentication = get_signin_state_from_redis(state, challenge_id).await?;
+    let credential: PublicKeyCredential = serde_json::from_value(credential)
+        .change_context(UserErrors::InvalidCredentials)
+        .attach_printable("Failed to parse passkey credential")?;
+
+    let webauthn = get_webauthn(state)?;
+    let (user_handle, credential_id) = webauthn
+        .identify_discoverable_authentication(&credential)
+        .change_context(UserErrors::InvalidCredentials)?;
+
+    if user_handle != get_user_handle(user_id) {
+        return Err(UserErrors::InvalidCredentials)
+            .attach_printable("Passkey used for sign in belongs to a different user");
+    }
+
+    let passkeys = get_passkeys_for_user(state, user_id).await?;
+    if !passkeys
+        .iter()
+        .any(|(_, passkey)| passkey.cred_id().as_ref() == credential_id)
+    {
+        return Err(UserErrors::InvalidCredentials)
+            .attach_printable("Passkey used for sign in is not registered for the user");
+    }
+
+    let discoverable_keys = passkeys
+        .iter()
+        .map(|(_, passkey)| DiscoverableKey::from(passkey))
+        .collect::<Vec<_>>();
+
+    let authentication_result = webauthn
+        .finish_discoverable_authentication(&credential, authentication, &discoverable_keys)
+        .change_context(UserErrors::InvalidPasskey)?;
+
+    update_passkey_usage(state, user_id, passkeys, &authentication_result).await
+}
+
+/// Persists the last usage and the updated signature counter of the passkey used.
+async fn update_passkey_usage(
+    state: &SessionState,
+    user_id: &str,
+    passkeys: Vec<(storage::UserPasskey, Passkey)>,
+    authentication_result: &AuthenticationResult,
+) -> UserResult<()> {
+    let (user_passkey, mut passkey) = passkeys
+        .into_iter()
+        .find(|(_, passkey)| passkey.cred_id() == authentication_result.cred_id())
+        .ok_or(UserErrors::InvalidPasskey)
+        .attach_printable("Passkey used for authentication is not registered for the user")?;
+
+    let updated_passkey = passkey
+        .update_credential(authentication_result)
+        .filter(|is_updated| *is_updated)
+        .map(|_| serde_json::to_value(&passkey))
+        .transpose()
+        .change_context(UserErrors::InternalServerError)?;
+
+    state
+        .global_store
+        .update_user_passkey_by_user_id_passkey_id(
+            user_id,
+            &user_passkey.passkey_id,
+            storage::UserPasskeyUpdate::PasskeyUsed {
+                passkey: updated_passkey,
+                last_used_at: common_utils::date_time::now(),
+            },
+        )
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+
+    Ok(())
+}
+
+async fn insert_registration_state_in_redis(
+    state: &SessionState,
+    user_id: &str,
+    registration: &PasskeyRegistration,
+) -> UserResult<()> {
+    let redis_conn = super::get_redis_connection_for_global_tenant(state)?;
+    redis_conn
+        .serialize_and_set_key_with_expiry(
+            &get_registration_state_key(user_id),
+            registration,
+            consts::user::REDIS_PASSKEY_CHALLENGE_TTL_IN_SECS,
+        )
+        .await
+        .change_context(UserErrors::InternalServerError)
+}
+
+async fn get_registration_state_from_redis(
+    state: &SessionState,
+    user_id: &str,
+) -> UserResult<PasskeyRegistration> {
+    get_and_delete_challenge_state(
+        state,
+        &get_registration_state_key(user_id),
+        "PasskeyRegistration",
+    )
+    .await
+}
+
+async fn insert_authentication_state_in_redis(
+    state: &SessionState,
+    user_id: &str,
+    authentication: &PasskeyAuthentication,
+) -> UserResult<()> {
+    let redis_conn = super::get_redis_connection_for_global_tenant(state)?;
+    redis_conn
+        .serialize_and_set_key_with_expiry(
+            &get_authentication_state_key(user_id),
+            authentication,
+            consts::user::REDIS_PASSKEY_CHALLENGE_TTL_IN_SECS,
+        )
+        .await
+        .change_context(UserErrors::InternalServerError)
+}
+
+async fn get_authentication_state_from_redis(
+    state: &SessionState,
+    user_id: &str,
+) -> UserResult<PasskeyAuthentication> {
+    get_and_delete_challenge_state(
+        state,
+        &get_authentication_state_key(user_id),
+        "PasskeyAuthentication",
+    )
+    .await
+}
+
+async fn insert_signin_state_in_redis(
+    state: &SessionState,
+    challenge_id: &str,
+    authentication: &DiscoverableAuthentication,
+) -> UserResult<()> {
+    let redis_conn = super::get_redis_connection_for_global_tenant(state)?;
+    redis_conn
+        .serialize_and_set_key_with_expiry(
+            &get_signin_state_key(challenge_id),
+            authentication,
+            consts::user::REDIS_PASSKEY_CHALLENGE_TTL_IN_SECS,
+        )
+        .await
+        .change_context(UserErrors::InternalServerError)
+}
+
+async fn get_signin_state_from_redis(
+    state: &SessionState,
+    challenge_id: &str,
+) -> UserResult<DiscoverableAuthentication> {
+    get_and_delete_challenge_state(
+        state,
+        &get_signin_state_key(challenge_id),
+        "DiscoverableAuthentication",
+    )
+    .await
+}
+
+/// Challenges are deleted once fetched, so that each of them can only be answered once. Only the
+/// request which deletes the challenge can use it, as concurrent requests may fetch it together.
+async fn get_and_delete_challenge_state<T>(
+    state: &SessionState,
+    key: &RedisKey,
+    type_name: &'static str,
+) -> UserResult<T>
+where
+    T: serde::de::DeserializeOwned,
+{
+    let redis_conn = super::get_redis_connection_for_global_tenant(state)?;
+    let challenge_state = redis_conn
+        .get_and_deserialize_key(key, type_name)
+        .await
+        .map_err(|error| match error.current_context() {
+            RedisError::NotFound => error.change_context(UserErrors::PasskeyChallengeNotFound),
+            _ => error.change_context(UserErrors::InternalServerError),
+        })?;
+
+    match redis_conn
+        .delete_key(key)
+        .await
+        .change_context(UserErrors::InternalServerError)?
+    {
+        DelReply::KeyDeleted => Ok(challenge_state),
+        DelReply::KeyNotDeleted => Err(UserErrors::PasskeyChallengeNotFound)
+            .attach_printable("Passkey challenge has already been used"),
+    }
+}
+
+fn get_registration_state_key(user_id: &str) -> RedisKey {
+    format!(
+        "{}{}",
+        consts::user::REDIS_PASSKEY_REGISTRATION_PREFIX,
+        user_id
+    )
+    .into()
+}
+
+fn get_authentication_state_key(user_id: &str) -> RedisKey {
+    format!(
+        "{}{}",
+        consts::user::REDIS_PASSKEY_AUTHENTICATION_PREFIX,
+        user_id
+    )
+    .into()
+}
+
+fn get_signin_state_key(challenge_id: &str) -> RedisKey {
+    format!(
+        "{}{}",
+        consts::user::REDIS_PASSKEY_SIGNIN_PREFIX,
+        challenge_id
+    )
+    .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_user_handle() {
+        let user_handle = get_user_handle("user_id_1");
+
+        assert_eq!(user_handle, get_user_handle("user_id_1"));
+        assert_ne!(user_handle, get_user_handle("user_id_2"));
+        assert_eq!(user_handle.get_version_num(), 5);
+    }
+}
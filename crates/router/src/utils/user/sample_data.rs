@@ -289,6 +289,8 @@ pub async fn generate_sample_data(
             force_3ds_challenge: None,
             force_3ds_challenge_trigger: None,
             is_iframe_redirection_enabled: None,
+            enable_partial_authorization: None,
+            l2_l3_data: None,
         };
         let (connector_transaction_id, processor_transaction_data) =
             ConnectorTransactionId::form_id_and_data(attempt_id.clone());
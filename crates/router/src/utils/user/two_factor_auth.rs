@@ -1,4 +1,5 @@
 use common_utils::pii;
+use diesel_models::enums::TotpStatus;
 use error_stack::ResultExt;
 use masking::{ExposeInterface, PeekInterface};
 use totp_rs::{Algorithm, TOTP};
@@ -50,6 +51,40 @@ pub async fn check_recovery_code_in_redis(state: &SessionState, user_id: &str) -
         .change_context(UserErrors::InternalServerError)
 }
 
+pub async fn check_passkey_in_redis(state: &SessionState, user_id: &str) -> UserResult<bool> {
+    let redis_conn = super::get_redis_connection_for_global_tenant(state)?;
+    let key = format!("{}{}", consts::user::REDIS_PASSKEY_PREFIX, user_id);
+    redis_conn
+        .exists::<()>(&key.into())
+        .await
+        .change_context(UserErrors::InternalServerError)
+}
+
+/// Checks if the user has completed any of the second factors in the current session.
+pub async fn check_two_factor_auth_in_redis(
+    state: &SessionState,
+    user_id: &str,
+) -> UserResult<bool> {
+    Ok(check_totp_in_redis(state, user_id).await?
+        || check_passkey_in_redis(state, user_id).await?
+        || check_recovery_code_in_redis(state, user_id).await?)
+}
+
+/// Users who have setup a passkey have to complete a second factor before setting up TOTP, as
+/// otherwise TOTP could be setup by anyone with the password of the user.
+pub fn is_two_factor_auth_required_for_totp_setup(
+    has_passkeys: bool,
+    is_two_factor_auth_completed: bool,
+) -> bool {
+    has_passkeys && !is_two_factor_auth_completed
+}
+
+/// TOTP is marked as set only when it has been verified in the current session, as users who have
+/// only setup a passkey do not have a TOTP secret.
+pub fn should_set_totp_status(totp_status: TotpStatus, is_totp_verified: bool) -> bool {
+    totp_status != TotpStatus::Set && is_totp_verified
+}
+
 pub async fn insert_totp_in_redis(state: &SessionState, user_id: &str) -> UserResult<()> {
     let redis_conn = super::get_redis_connection_for_global_tenant(state)?;
     let key = format!("{}{}", consts::user::REDIS_TOTP_PREFIX, user_id);
@@ -117,6 +152,19 @@ pub async fn insert_recovery_code_in_redis(state: &SessionState, user_id: &str)
         .change_context(UserErrors::InternalServerError)
 }
 
+pub async fn insert_passkey_in_redis(state: &SessionState, user_id: &str) -> UserResult<()> {
+    let redis_conn = super::get_redis_connection_for_global_tenant(state)?;
+    let key = format!("{}{}", consts::user::REDIS_PASSKEY_PREFIX, user_id);
+    redis_conn
+        .set_key_with_expiry(
+            &key.as_str().into(),
+            common_utils::date_time::now_unix_timestamp(),
+            state.conf.user.two_factor_auth_expiry_in_secs,
+        )
+        .await
+        .change_context(UserErrors::InternalServerError)
+}
+
 pub async fn delete_totp_from_redis(state: &SessionState, user_id: &str) -> UserResult<()> {
     let redis_conn = super::get_redis_connection_for_global_tenant(state)?;
     let key = format!("{}{}", consts::user::REDIS_TOTP_PREFIX, user_id);
@@ -127,6 +175,16 @@ pub async fn delete_totp_from_redis(state: &SessionState, user_id: &str) -> User
         .map(|_| ())
 }
 
+pub async fn delete_passkey_from_redis(state: &SessionState, user_id: &str) -> UserResult<()> {
+    let redis_conn = super::get_redis_connection_for_global_tenant(state)?;
+    let key = format!("{}{}", consts::user::REDIS_PASSKEY_PREFIX, user_id);
+    redis_conn
+        .delete_key(&key.into())
+        .await
+        .change_context(UserErrors::InternalServerError)
+        .map(|_| ())
+}
+
 pub async fn delete_recovery_code_from_redis(
     state: &SessionState,
     user_id: &str,
@@ -151,6 +209,10 @@ fn get_recovery_code_attempts_key(user_id: &str) -> String {
     )
 }
 
+fn get_passkey_attempts_key(user_id: &str) -> String {
+    format!("{}{}", consts::user::REDIS_PASSKEY_ATTEMPTS_PREFIX, user_id)
+}
+
 pub async fn insert_totp_attempts_in_redis(
     state: &SessionState,
     user_id: &str,
@@ -226,3 +288,89 @@ pub async fn delete_recovery_code_attempts_from_redis(
         .change_context(UserErrors::InternalServerError)
         .map(|_| ())
 }
+
+pub async fn insert_passkey_attempts_in_redis(
+    state: &SessionState,
+    user_id: &str,
+    user_passkey_attempts: u8,
+) -> UserResult<()> {
+    let redis_conn = super::get_redis_connection_for_global_tenant(state)?;
+    redis_conn
+        .set_key_with_expiry(
+            &get_passkey_attempts_key(user_id).into(),
+            user_passkey_attempts,
+            consts::user::REDIS_PASSKEY_ATTEMPTS_TTL_IN_SECS,
+        )
+        .await
+        .change_context(UserErrors::InternalServerError)
+}
+
+pub async fn get_passkey_attempts_from_redis(
+    state: &SessionState,
+    user_id: &str,
+) -> UserResult<u8> {
+    let redis_conn = super::get_redis_connection_for_global_tenant(state)?;
+    redis_conn
+        .get_key::<Option<u8>>(&get_passkey_attempts_key(user_id).into())
+        .await
+        .change_context(UserErrors::InternalServerError)
+        .map(|v| v.unwrap_or(0))
+}
+
+pub async fn delete_passkey_attempts_from_redis(
+    state: &SessionState,
+    user_id: &str,
+) -> UserResult<()> {
+    let redis_conn = super::get_redis_connection_for_global_tenant(state)?;
+    redis_conn
+        .delete_key(&get_passkey_attempts_key(user_id).into())
+        .await
+        .change_context(UserErrors::InternalServerError)
+        .map(|_| ())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_two_factor_auth_required_for_totp_setup() {
+        let cases = [
+            (false, false, false),
+            (false, true, false),
+            (true, false, true),
+            (true, true, false),
+        ];
+
+        for (has_passkeys, is_two_factor_auth_completed, expected) in cases {
+            assert_eq!(
+                is_two_factor_auth_required_for_totp_setup(
+                    has_passkeys,
+                    is_two_factor_auth_completed
+                ),
+                expected,
+                "has_passkeys: {has_passkeys}, completed: {is_two_factor_auth_completed}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_should_set_totp_status() {
+        let cases = [
+            (TotpStatus::NotSet, true, true),
+            (TotpStatus::NotSet, false, false),
+            (TotpStatus::InProgress, true, true),
+            (TotpStatus::InProgress, false, false),
+            (TotpStatus::Set, true, false),
+            (TotpStatus::Set, false, false),
+        ];
+
+        for (totp_status, is_totp_verified, expected) in cases {
+            assert_eq!(
+                should_set_totp_status(totp_status, is_totp_verified),
+                expected,
+                "totp_status: {totp_status:?}, verified: {is_totp_verified}"
+            );
+        }
+    }
+}
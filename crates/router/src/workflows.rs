@@ -1,13 +1,30 @@
 #[cfg(feature = "email")]
+pub mod analytics_digest;
+#[cfg(feature = "email")]
 pub mod api_key_expiry;
 #[cfg(feature = "payouts")]
 pub mod attach_payout_account_workflow;
+pub mod blocklist_entry_expiry;
+#[cfg(feature = "v1")]
+pub mod card_account_updater;
 pub mod outgoing_webhook_retry;
+#[cfg(feature = "v1")]
+pub mod payment_method_migration;
 pub mod payment_method_status_update;
 pub mod payment_sync;
+#[cfg(all(feature = "payouts", feature = "v1"))]
+pub mod payout_batch;
 
 pub mod refund_router;
+#[cfg(feature = "olap")]
+pub mod report_generation;
+#[cfg(all(feature = "payouts", feature = "v1"))]
+pub mod scheduled_payout;
+#[cfg(feature = "v1")]
+pub mod subscription_billing;
 
 pub mod tokenized_data;
+#[cfg(feature = "v1")]
+pub mod webhook_event_replay;
 
 pub mod revenue_recovery;
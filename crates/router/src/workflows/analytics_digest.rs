@@ -0,0 +1,313 @@
+use api_models::analytics::TimeRange;
+use common_enums::{AnalyticsDigestFrequency, Currency};
+use common_utils::ext_traits::ValueExt;
+use diesel_models::process_tracker::business_status;
+use masking::PeekInterface;
+use scheduler::{
+    consumer::types::process_data, utils as pt_utils, workflows::ProcessTrackerWorkflow,
+};
+
+use crate::{
+    consts,
+    core::analytics_digest::{self, AnalyticsDigest, AnalyticsDigestTrackingData},
+    errors,
+    logger::{error, info},
+    routes::SessionState,
+    services::email::types as email_types,
+    types::{domain::UserEmail, storage},
+    utils::user as user_utils,
+};
+
+pub struct AnalyticsDigestWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for AnalyticsDigestWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: AnalyticsDigestTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("AnalyticsDigestTrackingData")?;
+
+        let subscription = match db
+            .find_analytics_digest_subscription_by_merchant_id_subscription_id(
+                &tracking_data.merchant_id,
+                &tracking_data.subscription_id,
+            )
+            .await
+        {
+            Ok(subscription) => subscription,
+            Err(error) if error.current_context().is_db_not_found() => {
+                // The subscription was deleted after the task was picked up
+                db.as_scheduler()
+                    .finish_process_with_business_status(process, business_status::REVOKED)
+                    .await?;
+                return Ok(());
+            }
+            Err(error) => return Err(error.into()),
+        };
+
+        let period = analytics_digest::get_digest_period(
+            subscription.frequency,
+            process
+                .schedule_time
+                .unwrap_or_else(common_utils::date_time::now),
+        );
+        let next_digest_time = analytics_digest::get_next_digest_time(
+            subscription.frequency,
+            period.end_time.unwrap_or(period.start_time),
+        );
+
+        match send_digest(state, &subscription, period).await {
+            Ok(recipient_count) => {
+                info!(
+                    subscription_id = %subscription.subscription_id,
+                    recipient_count,
+                    "Analytics digest sent"
+                );
+                // There is nobody to send the digest of the period to if no user is subscribed
+                if recipient_count > 0 {
+                    db.update_analytics_digest_subscription_by_merchant_id_subscription_id(
+                        &subscription.merchant_id,
+                        &subscription.subscription_id,
+                        storage::AnalyticsDigestSubscriptionUpdate::DigestSent {
+                            last_sent_at: common_utils::date_time::now(),
+                        },
+                    )
+                    .await?;
+                }
+                db.as_scheduler()
+                    .reset_process(process, next_digest_time)
+                    .await?;
+            }
+            Err(digest_error) => {
+                error!(
+                    ?digest_error,
+                    subscription_id = %subscription.subscription_id,
+                    "Failed to send analytics digest"
+                );
+                // Retries the digest 3 times at intervals of 15 minutes
+                let retry_mapping = process_data::RetryMapping::new(60 * 15, &[(60 * 15, 2)]);
+                match pt_utils::get_retry_schedule_time(&retry_mapping, process.retry_count) {
+                    Some(schedule_time) => {
+                        db.as_scheduler()
+                            .retry_process(process, schedule_time)
+                            .await?
+                    }
+                    // Skip this period, the subscription still receives the next digest
+                    None => {
+                        db.as_scheduler()
+                            .reset_process(process, next_digest_time)
+                            .await?
+                    }
+                };
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        process: storage::ProcessTracker,
+        _error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        error!(%process.id, "Failed while executing workflow");
+        Ok(())
+    }
+}
+
+/// Computes the digest of the period and emails it to each of the users subscribed to it,
+/// returning the number of users it was sent to. Fails if it could not be sent to any of them.
+async fn send_digest(
+    state: &SessionState,
+    subscription: &storage::AnalyticsDigestSubscription,
+    period: TimeRange,
+) -> Result<usize, errors::ProcessTrackerError> {
+    let recipients = analytics_digest::get_digest_recipients(state, subscription)
+        .await
+        .map_err(|error| {
+            error!(?error, "Failed to fetch analytics digest recipients");
+            errors::ProcessTrackerError::ResourceFetchingFailed {
+                resource_name: "analytics_digest_recipients".to_string(),
+            }
+        })?;
+    if recipients.is_empty() {
+        return Ok(0);
+    }
+
+    let key_manager_state = &state.into();
+    let key_store = state
+        .store
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            &subscription.merchant_id,
+            &state.store.get_master_key().to_vec().into(),
+        )
+        .await?;
+    let merchant_account = state
+        .store
+        .find_merchant_account_by_merchant_id(
+            key_manager_state,
+            &subscription.merchant_id,
+            &key_store,
+        )
+        .await?;
+    let merchant_name = merchant_account
+        .merchant_name
+        .clone()
+        .map(|merchant_name| merchant_name.into_inner().peek().to_owned())
+        .unwrap_or_else(|| subscription.merchant_id.get_string_repr().to_owned());
+
+    let digest = analytics_digest::get_analytics_digest(state, subscription, period)
+        .await
+        .map_err(|error| {
+            error!(?error, "Failed to compute analytics digest");
+            errors::ProcessTrackerError::FlowExecutionError {
+                flow: "AnalyticsDigest",
+            }
+        })?;
+    let period = format_period(subscription.frequency, &digest.time_range);
+    let metrics = get_metric_rows(&digest);
+    let decline_reasons = digest
+        .top_decline_reasons
+        .iter()
+        .map(|reason| {
+            (
+                reason.reason.clone(),
+                format!("{} ({:.2}%)", reason.count, reason.percentage),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    // A failure to email one of the recipients does not prevent the others from receiving the
+    // digest, and is not retried so that the other recipients do not receive it twice
+    let mut sent_count = 0;
+    for recipient in recipients {
+        let recipient_email = match UserEmail::from_pii_email(recipient) {
+            Ok(recipient_email) => recipient_email,
+            Err(error) => {
+                error!(
+                    ?error,
+                    subscription_id = %subscription.subscription_id,
+                    "Failed to convert recipient's email to UserEmail from pii::Email"
+                );
+                continue;
+            }
+        };
+        let email_contents = email_types::AnalyticsDigest {
+            recipient_email,
+            subject: format!("{} {merchant_name}", consts::EMAIL_SUBJECT_ANALYTICS_DIGEST),
+            merchant_name: merchant_name.clone(),
+            period: period.clone(),
+            metrics: metrics.clone(),
+            decline_reasons: decline_reasons.clone(),
+        };
+
+        match state
+            .email_client
+            .clone()
+            .compose_and_send_email(
+                user_utils::get_base_url(state),
+                Box::new(email_contents),
+                state.conf.proxy.https_url.as_ref(),
+            )
+            .await
+        {
+            Ok(()) => sent_count += 1,
+            Err(error) => error!(
+                ?error,
+                subscription_id = %subscription.subscription_id,
+                "Failed to send analytics digest to a recipient"
+            ),
+        }
+    }
+
+    if sent_count == 0 {
+        return Err(errors::ProcessTrackerError::FlowExecutionError {
+            flow: "AnalyticsDigest",
+        });
+    }
+
+    Ok(sent_count)
+}
+
+fn get_metric_rows(digest: &AnalyticsDigest) -> Vec<(String, String)> {
+    let mut rows = vec![
+        (
+            "Payments attempted".to_string(),
+            digest.payment_count.to_string(),
+        ),
+        (
+            "Successful payments".to_string(),
+            digest.payment_success_count.to_string(),
+        ),
+        (
+            "Authorization rate".to_string(),
+            digest
+                .get_authorization_rate()
+                .map(|rate| format!("{rate:.2}%"))
+                .unwrap_or_else(|| "-".to_string()),
+        ),
+    ];
+    rows.extend(get_amount_rows(
+        "Processed amount",
+        &digest.payment_processed_amount,
+    ));
+    if let Some(amount) = digest.payment_processed_amount_in_usd {
+        rows.push((
+            "Total processed amount in USD".to_string(),
+            format_amount(Currency::USD, amount),
+        ));
+    }
+    rows.push(("Refunds".to_string(), digest.refund_count.to_string()));
+    rows.extend(get_amount_rows(
+        "Refunded amount",
+        &digest.refund_processed_amount,
+    ));
+    rows.push((
+        "Disputes opened".to_string(),
+        digest.disputes_opened.to_string(),
+    ));
+    rows
+}
+
+fn get_amount_rows(label: &str, amounts: &[(Currency, u64)]) -> Vec<(String, String)> {
+    amounts
+        .iter()
+        .map(|(currency, amount)| {
+            (
+                format!("{label} ({currency})"),
+                format_amount(*currency, *amount),
+            )
+        })
+        .collect()
+}
+
+/// Formats the amount in the lowest denomination of the currency in its base unit.
+fn format_amount(currency: Currency, amount: u64) -> String {
+    i64::try_from(amount)
+        .ok()
+        .and_then(|amount| currency.to_currency_base_unit(amount).ok())
+        .map(|amount| format!("{amount} {currency}"))
+        .unwrap_or_else(|| format!("{amount} (minor units) {currency}"))
+}
+
+fn format_period(frequency: AnalyticsDigestFrequency, time_range: &TimeRange) -> String {
+    let start_date = time_range.start_time.date();
+    match frequency {
+        AnalyticsDigestFrequency::Daily => start_date.to_string(),
+        AnalyticsDigestFrequency::Weekly => {
+            let end_date = time_range
+                .end_time
+                .map(|end_time| end_time.date().saturating_sub(time::Duration::days(1)))
+                .unwrap_or(start_date);
+            format!("{start_date} to {end_date}")
+        }
+    }
+}
@@ -0,0 +1,171 @@
+use common_utils::ext_traits::ValueExt;
+use error_stack::ResultExt;
+use scheduler::{
+    consumer::types::process_data, utils as pt_utils, workflows::ProcessTrackerWorkflow,
+};
+
+use crate::{
+    errors,
+    logger::error,
+    routes::SessionState,
+    types::storage::{self, BlocklistEntryExpiryTrackingData},
+};
+
+pub struct BlocklistEntryExpiryWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for BlocklistEntryExpiryWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: BlocklistEntryExpiryTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("BlocklistEntryExpiryTrackingData")?;
+
+        let blocklist_entry = match db
+            .find_blocklist_entry_by_merchant_id_fingerprint_id(
+                &tracking_data.merchant_id,
+                &tracking_data.fingerprint_id,
+            )
+            .await
+        {
+            Ok(blocklist_entry) => Some(blocklist_entry),
+            Err(error) if error.current_context().is_db_not_found() => None,
+            Err(error) => return Err(error.into()),
+        };
+
+        if !is_entry_pending_expiry(blocklist_entry.as_ref(), &tracking_data) {
+            return db
+                .as_scheduler()
+                .finish_process_with_business_status(process, "PROCESS_ALREADY_COMPLETED")
+                .await
+                .map_err(Into::<errors::ProcessTrackerError>::into);
+        }
+
+        let update_result = db
+            .update_blocklist_entry_by_merchant_id_fingerprint_id(
+                &tracking_data.merchant_id,
+                &tracking_data.fingerprint_id,
+                storage::BlocklistUpdate::StatusUpdate {
+                    status: common_enums::BlocklistEntryStatus::Expired,
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Unable to mark the blocklist entry as expired");
+
+        if update_result.is_ok() {
+            db.as_scheduler()
+                .finish_process_with_business_status(process, "COMPLETED_BY_PT")
+                .await?;
+        } else {
+            // An entry past its expiry is not blocked even while its status is not updated, the
+            // status is retried only to keep the list of active entries accurate
+            pt_utils::retry_process_or_finish(
+                db.as_scheduler(),
+                process,
+                &process_data::RetryMapping::new(60, &[(60 * 10, 5)]),
+            )
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        process: storage::ProcessTracker,
+        _error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        error!(%process.id, "Failed while executing workflow");
+        Ok(())
+    }
+}
+
+/// The entry may have been removed from the blocklist, or replaced by an entry with a different
+/// expiry, since the task was added
+fn is_entry_pending_expiry(
+    blocklist_entry: Option<&storage::Blocklist>,
+    tracking_data: &BlocklistEntryExpiryTrackingData,
+) -> bool {
+    blocklist_entry.is_some_and(|blocklist_entry| {
+        blocklist_entry.status == common_enums::BlocklistEntryStatus::Active
+            && blocklist_entry.expires_at == Some(tracking_data.expires_at)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_blocklist_entry(
+        status: common_enums::BlocklistEntryStatus,
+        expires_at: Option<time::PrimitiveDateTime>,
+    ) -> storage::Blocklist {
+        storage::Blocklist {
+            merchant_id: common_utils::id_type::MerchantId::default(),
+            fingerprint_id: String::from("411111"),
+            data_kind: common_enums::BlocklistDataKind::CardBin,
+            metadata: None,
+            created_at: common_utils::date_time::now(),
+            expires_at,
+            reason: None,
+            created_by: None,
+            status,
+        }
+    }
+
+    fn get_tracking_data(expires_at: time::PrimitiveDateTime) -> BlocklistEntryExpiryTrackingData {
+        BlocklistEntryExpiryTrackingData {
+            merchant_id: common_utils::id_type::MerchantId::default(),
+            fingerprint_id: String::from("411111"),
+            expires_at,
+        }
+    }
+
+    #[test]
+    fn test_active_entry_with_same_expiry_is_pending_expiry() {
+        let expires_at = common_utils::date_time::now();
+        let blocklist_entry =
+            get_blocklist_entry(common_enums::BlocklistEntryStatus::Active, Some(expires_at));
+
+        assert!(is_entry_pending_expiry(
+            Some(&blocklist_entry),
+            &get_tracking_data(expires_at)
+        ));
+    }
+
+    #[test]
+    fn test_removed_or_replaced_entry_is_not_pending_expiry() {
+        let expires_at = common_utils::date_time::now();
+        let tracking_data = get_tracking_data(expires_at);
+
+        // Removed from the blocklist
+        assert!(!is_entry_pending_expiry(None, &tracking_data));
+        // Blocked again with a different expiry, or without an expiry
+        for replaced_expires_at in [Some(expires_at + time::Duration::days(1)), None] {
+            let blocklist_entry = get_blocklist_entry(
+                common_enums::BlocklistEntryStatus::Active,
+                replaced_expires_at,
+            );
+            assert!(!is_entry_pending_expiry(
+                Some(&blocklist_entry),
+                &tracking_data
+            ));
+        }
+        // Already marked as expired
+        let blocklist_entry = get_blocklist_entry(
+            common_enums::BlocklistEntryStatus::Expired,
+            Some(expires_at),
+        );
+        assert!(!is_entry_pending_expiry(
+            Some(&blocklist_entry),
+            &tracking_data
+        ));
+    }
+}
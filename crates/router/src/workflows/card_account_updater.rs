@@ -0,0 +1,115 @@
+use common_utils::{date_time, ext_traits::ValueExt};
+use scheduler::{
+    consumer::types::process_data, utils as pt_utils, workflows::ProcessTrackerWorkflow,
+};
+
+use crate::{
+    core::payment_methods::account_updater,
+    errors,
+    logger::{error, info},
+    routes::SessionState,
+    types::{
+        domain,
+        storage::{self, CardAccountUpdaterTrackingData},
+    },
+};
+
+pub struct CardAccountUpdaterWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for CardAccountUpdaterWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: CardAccountUpdaterTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("CardAccountUpdaterTrackingData")?;
+
+        let key_manager_state = &state.into();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &key_store,
+            )
+            .await?;
+        let business_profile = db
+            .find_business_profile_by_profile_id(
+                key_manager_state,
+                &key_store,
+                &tracking_data.profile_id,
+            )
+            .await?;
+        let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(domain::Context(
+            merchant_account,
+            key_store,
+        )));
+
+        let update_result =
+            account_updater::update_expiring_cards(state, &merchant_context, &business_profile)
+                .await;
+
+        // The task is recurring, it is scheduled for the next interval once the run completes or
+        // the retries of a failed run are exhausted
+        let next_run_time = date_time::now().saturating_add(time::Duration::seconds(i64::from(
+            state.conf.card_account_updater.schedule_interval_in_seconds,
+        )));
+
+        match update_result {
+            Ok(summary) => {
+                info!(
+                    merchant_id = ?tracking_data.merchant_id,
+                    ?summary,
+                    "Card account updater run completed"
+                );
+                db.as_scheduler()
+                    .reset_process(process, next_run_time)
+                    .await?;
+            }
+            Err(update_error) => {
+                error!(
+                    ?update_error,
+                    merchant_id = ?tracking_data.merchant_id,
+                    "Card account updater run failed"
+                );
+                // Once the retries are exhausted, the task waits for the next scheduled run
+                let retry_mapping = process_data::RetryMapping::new(60 * 15, &[(60 * 60, 3)]);
+                match pt_utils::get_retry_schedule_time(&retry_mapping, process.retry_count) {
+                    Some(schedule_time) => {
+                        db.as_scheduler()
+                            .retry_process(process, schedule_time)
+                            .await?
+                    }
+                    None => {
+                        db.as_scheduler()
+                            .reset_process(process, next_run_time)
+                            .await?
+                    }
+                };
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        process: storage::ProcessTracker,
+        _error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        error!(%process.id, "Failed while executing workflow");
+        Ok(())
+    }
+}
@@ -100,6 +100,50 @@ impl ProcessTrackerWorkflow<SessionState> for OutgoingWebhookRetryWorkflow {
             }
         };
 
+        // Automatic retries are subject to the circuit breaker and rate limit of the endpoint when
+        // outgoing webhooks are delivered through the delivery queue
+        let endpoint_key = if state.conf.webhooks.delivery_queue.enabled {
+            webhooks_core::delivery_queue::get_endpoint_key(
+                &business_profile.merchant_id,
+                &initial_event,
+            )
+            .inspect_err(|error| logger::error!(?error, "Failed to obtain webhook endpoint key"))
+            .ok()
+        } else {
+            None
+        };
+
+        if let Some(endpoint_key) = &endpoint_key {
+            match webhooks_core::delivery_queue::check_retry_delivery_gate(state, endpoint_key)
+                .await
+            {
+                Ok(Some((reason, delay))) => {
+                    logger::info!(?reason, ?delay, "Deferring outgoing webhook retry");
+                    // The retry was not attempted, so the retry count is left unchanged
+                    let retry_count = process.retry_count;
+                    db.as_scheduler()
+                        .update_process(
+                            process,
+                            storage::ProcessTrackerUpdate::StatusRetryUpdate {
+                                status: storage::enums::ProcessTrackerStatus::Pending,
+                                retry_count,
+                                schedule_time: common_utils::date_time::now().saturating_add(
+                                    time::Duration::try_from(delay).unwrap_or(time::Duration::ZERO),
+                                ),
+                            },
+                        )
+                        .await?;
+                    return Ok(());
+                }
+                Ok(None) => {}
+                Err(error) => logger::error!(
+                    ?error,
+                    "Failed to check limits of webhook endpoint, retrying delivery"
+                ),
+            }
+        }
+
+        let merchant_id = business_profile.merchant_id.clone();
         let now = common_utils::date_time::now();
         let new_event = domain::Event {
             event_id,
@@ -119,6 +163,7 @@ impl ProcessTrackerWorkflow<SessionState> for OutgoingWebhookRetryWorkflow {
             delivery_attempt: Some(delivery_attempt),
             metadata: initial_event.metadata,
             is_overall_delivery_successful: Some(false),
+            webhook_endpoint_id: initial_event.webhook_endpoint_id,
         };
 
         let event = db
@@ -135,7 +180,7 @@ impl ProcessTrackerWorkflow<SessionState> for OutgoingWebhookRetryWorkflow {
                     .peek()
                     .parse_struct("OutgoingWebhookRequestContent")?;
 
-                Box::pin(webhooks_core::trigger_webhook_and_raise_event(
+                let status_code = Box::pin(webhooks_core::trigger_webhook_and_raise_event(
                     state.clone(),
                     business_profile,
                     &key_store,
@@ -146,6 +191,7 @@ impl ProcessTrackerWorkflow<SessionState> for OutgoingWebhookRetryWorkflow {
                     Some(process),
                 ))
                 .await;
+                record_retry_delivery_result(state, &merchant_id, endpoint_key, status_code).await;
             }
 
             // Event inserted by old version of application, fetch current information about
@@ -169,6 +215,7 @@ impl ProcessTrackerWorkflow<SessionState> for OutgoingWebhookRetryWorkflow {
                     merchant_account.clone(),
                     key_store.clone(),
                     &tracking_data,
+                    event.metadata.as_ref(),
                 ))
                 .await?;
 
@@ -183,10 +230,13 @@ impl ProcessTrackerWorkflow<SessionState> for OutgoingWebhookRetryWorkflow {
                             timestamp: event.created_at,
                         };
 
+                        // Events inserted by old versions of the application are never associated
+                        // with a webhook endpoint
                         let request_content = webhooks_core::get_outgoing_webhook_request(
                             &merchant_context,
                             outgoing_webhook,
                             &business_profile,
+                            None,
                         )
                         .map_err(|error| {
                             logger::error!(
@@ -196,7 +246,7 @@ impl ProcessTrackerWorkflow<SessionState> for OutgoingWebhookRetryWorkflow {
                             errors::ProcessTrackerError::EApiErrorResponse
                         })?;
 
-                        Box::pin(webhooks_core::trigger_webhook_and_raise_event(
+                        let status_code = Box::pin(webhooks_core::trigger_webhook_and_raise_event(
                             state.clone(),
                             business_profile,
                             &key_store,
@@ -207,6 +257,13 @@ impl ProcessTrackerWorkflow<SessionState> for OutgoingWebhookRetryWorkflow {
                             Some(process),
                         ))
                         .await;
+                        record_retry_delivery_result(
+                            state,
+                            &merchant_id,
+                            endpoint_key,
+                            status_code,
+                        )
+                        .await;
                     }
                     // Resource status has changed since the event was created, finish task
                     _ => {
@@ -324,6 +381,28 @@ pub(crate) async fn get_webhook_delivery_retry_schedule_time(
     scheduler_utils::get_time_from_delta(time_delta)
 }
 
+/// Records the result of the retry towards the circuit breaker of the webhook endpoint
+#[cfg(feature = "v1")]
+async fn record_retry_delivery_result(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    endpoint_key: Option<String>,
+    status_code: Option<u16>,
+) {
+    if let Some(endpoint_key) = endpoint_key {
+        webhooks_core::delivery_queue::record_retry_delivery_result(
+            state,
+            merchant_id,
+            &endpoint_key,
+            status_code,
+        )
+        .await
+        .unwrap_or_else(|error| {
+            logger::error!(?error, "Failed to record result of outgoing webhook retry")
+        });
+    }
+}
+
 /// Schedule the webhook delivery task for retry
 #[cfg(feature = "v1")]
 #[instrument(skip_all)]
@@ -357,6 +436,7 @@ async fn get_outgoing_webhook_content_and_event_type(
     merchant_account: domain::MerchantAccount,
     key_store: domain::MerchantKeyStore,
     tracking_data: &OutgoingWebhookTrackingData,
+    event_metadata: Option<&storage::EventMetadata>,
 ) -> Result<(OutgoingWebhookContent, Option<EventType>), errors::ProcessTrackerError> {
     use api_models::{
         mandates::MandateId,
@@ -368,8 +448,10 @@ async fn get_outgoing_webhook_content_and_event_type(
         core::{
             disputes::retrieve_dispute,
             mandate::get_mandate,
+            payment_methods,
             payments::{payments_core, CallConnectorAction, PaymentStatus},
             refunds::refund_retrieve_core_with_refund_id,
+            subscriptions,
         },
         services::{ApplicationResponse, AuthFlow},
         types::{
@@ -431,6 +513,7 @@ async fn get_outgoing_webhook_content_and_event_type(
                 | ApplicationResponse::JsonForRedirection(_)
                 | ApplicationResponse::Form(_)
                 | ApplicationResponse::GenericLinkForm(_)
+                | ApplicationResponse::StoredResponse(_)
                 | ApplicationResponse::PaymentLinkForm(_)
                 | ApplicationResponse::FileData(_) => {
                     Err(errors::ProcessTrackerError::ResourceFetchingFailed {
@@ -487,6 +570,7 @@ async fn get_outgoing_webhook_content_and_event_type(
                     | ApplicationResponse::JsonForRedirection(_)
                     | ApplicationResponse::Form(_)
                     | ApplicationResponse::GenericLinkForm(_)
+                    | ApplicationResponse::StoredResponse(_)
                     | ApplicationResponse::PaymentLinkForm(_)
                     | ApplicationResponse::FileData(_) => {
                         Err(errors::ProcessTrackerError::ResourceFetchingFailed {
@@ -519,6 +603,7 @@ async fn get_outgoing_webhook_content_and_event_type(
                     | ApplicationResponse::JsonForRedirection(_)
                     | ApplicationResponse::Form(_)
                     | ApplicationResponse::GenericLinkForm(_)
+                    | ApplicationResponse::StoredResponse(_)
                     | ApplicationResponse::PaymentLinkForm(_)
                     | ApplicationResponse::FileData(_) => {
                         Err(errors::ProcessTrackerError::ResourceFetchingFailed {
@@ -569,5 +654,46 @@ async fn get_outgoing_webhook_content_and_event_type(
                 event_type,
             ))
         }
+        diesel_models::enums::EventClass::PaymentMethods => {
+            let payment_method = state
+                .store
+                .find_payment_method(
+                    &(&state).into(),
+                    &key_store,
+                    &tracking_data.primary_object_id,
+                    merchant_account.storage_scheme,
+                )
+                .await?;
+            let event_type = Option::<EventType>::foreign_from(payment_method.status);
+            logger::debug!(current_resource_status=%payment_method.status);
+
+            Ok((
+                OutgoingWebhookContent::PaymentMethodDetails(Box::new(
+                    payment_methods::account_updater::get_payment_method_response(&payment_method),
+                )),
+                event_type,
+            ))
+        }
+        diesel_models::enums::EventClass::Subscriptions => {
+            // The primary object of the events which recur for a subscription is the payment of an
+            // invoice or an update of the subscription, the subscription is recorded on the event
+            let subscription_id = match event_metadata {
+                Some(storage::EventMetadata::Subscription { subscription_id }) => subscription_id,
+                _ => &tracking_data.primary_object_id,
+            };
+            let subscription_response = subscriptions::get_subscription_webhook_content(
+                &state,
+                &tracking_data.merchant_id,
+                subscription_id,
+            )
+            .await?;
+            logger::debug!(current_resource_status=%subscription_response.status);
+
+            // The lifecycle event of a subscription cannot be derived from its current status
+            Ok((
+                OutgoingWebhookContent::SubscriptionDetails(Box::new(subscription_response)),
+                Some(tracking_data.event_type),
+            ))
+        }
     }
 }
@@ -0,0 +1,94 @@
+use common_utils::ext_traits::ValueExt;
+use diesel_models::process_tracker::business_status;
+use scheduler::{utils as pt_utils, workflows::ProcessTrackerWorkflow};
+
+use crate::{
+    core::payment_methods::migration_job,
+    errors,
+    logger::{error, info},
+    routes::SessionState,
+    types::{
+        domain,
+        storage::{self, PaymentMethodMigrationJobTrackingData},
+    },
+};
+
+pub struct PaymentMethodMigrationWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for PaymentMethodMigrationWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: PaymentMethodMigrationJobTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("PaymentMethodMigrationJobTrackingData")?;
+
+        let key_manager_state = &state.into();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &key_store,
+            )
+            .await?;
+        let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(domain::Context(
+            merchant_account,
+            key_store,
+        )));
+
+        match migration_job::process_migration_job(state, &merchant_context, &tracking_data.job_id)
+            .await
+        {
+            Ok(job) => {
+                info!(
+                    job_id = %job.job_id,
+                    status = ?job.status,
+                    migrated_count = job.migrated_count,
+                    failed_count = job.failed_count,
+                    "Payment method migration job processed"
+                );
+                db.as_scheduler()
+                    .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                    .await?;
+            }
+            Err(job_error) => {
+                // The items of the job which were migrated are not migrated again on retry
+                error!(
+                    ?job_error,
+                    job_id = %tracking_data.job_id,
+                    "Payment method migration job processing failed"
+                );
+                pt_utils::retry_process_or_finish(
+                    db.as_scheduler(),
+                    process,
+                    &pt_utils::get_short_interval_retry_mapping(),
+                )
+                .await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        process: storage::ProcessTracker,
+        _error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        error!(%process.id, "Failed while executing workflow");
+        Ok(())
+    }
+}
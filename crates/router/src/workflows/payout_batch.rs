@@ -0,0 +1,105 @@
+use common_utils::ext_traits::ValueExt;
+use diesel_models::process_tracker::business_status;
+use scheduler::{utils as pt_utils, workflows::ProcessTrackerWorkflow};
+
+use crate::{
+    core::payouts::batch,
+    errors,
+    logger::{error, info},
+    routes::SessionState,
+    types::{
+        domain,
+        storage::{self, PayoutBatchTrackingData},
+    },
+};
+
+pub struct PayoutBatchWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for PayoutBatchWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: PayoutBatchTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("PayoutBatchTrackingData")?;
+
+        let key_manager_state = &state.into();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &key_store,
+            )
+            .await?;
+        let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(domain::Context(
+            merchant_account,
+            key_store,
+        )));
+
+        match batch::process_payout_batch(state, &merchant_context, &tracking_data.batch_id).await {
+            Ok(payout_batch) => {
+                info!(
+                    batch_id = %payout_batch.batch_id,
+                    status = ?payout_batch.status,
+                    succeeded_count = payout_batch.succeeded_count,
+                    failed_count = payout_batch.failed_count,
+                    "Payout batch processed"
+                );
+                db.as_scheduler()
+                    .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                    .await?;
+            }
+            Err(batch_error) => {
+                // The items of the batch which were processed are not processed again on retry
+                error!(
+                    ?batch_error,
+                    batch_id = %tracking_data.batch_id,
+                    "Payout batch processing failed"
+                );
+                let is_retried = pt_utils::retry_process_or_finish(
+                    db.as_scheduler(),
+                    process,
+                    &pt_utils::get_short_interval_retry_mapping(),
+                )
+                .await?;
+
+                if !is_retried {
+                    let fail_result =
+                        batch::fail_payout_batch(state, &merchant_context, &tracking_data.batch_id)
+                            .await;
+                    if let Err(error) = fail_result {
+                        error!(
+                            ?error,
+                            batch_id = %tracking_data.batch_id,
+                            "Failed to mark the payout batch as failed"
+                        );
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        process: storage::ProcessTracker,
+        _error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        error!(%process.id, "Failed while executing workflow");
+        Ok(())
+    }
+}
@@ -0,0 +1,149 @@
+use api_models::analytics::ReportStatus;
+use common_utils::ext_traits::{Encode, ValueExt};
+use diesel_models::process_tracker::business_status;
+use scheduler::workflows::ProcessTrackerWorkflow;
+
+use crate::{
+    core::reports::{self, ReportGenerationTrackingData},
+    errors,
+    logger::{error, info},
+    routes::SessionState,
+    types::storage::{self, enums as storage_enums},
+};
+
+const REPORT_GENERATION_MAX_RETRIES: i32 = 3;
+const REPORT_GENERATION_RETRY_INTERVAL_SECS: i64 = 300;
+
+pub struct ReportGenerationWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for ReportGenerationWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        mut process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let mut tracking_data: ReportGenerationTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("ReportGenerationTrackingData")?;
+
+        // The progress is persisted after each part of the report is uploaded, a retry resumes
+        // from the next part
+        loop {
+            match reports::generate_next_report_part(state, &mut tracking_data).await {
+                Ok(true) => {
+                    info!(
+                        report_id = %tracking_data.report_id,
+                        row_count = tracking_data.row_count,
+                        "Report generated"
+                    );
+                    tracking_data.status = ReportStatus::Completed;
+                    finish_with_tracking_data(
+                        db,
+                        process,
+                        &tracking_data,
+                        business_status::COMPLETED_BY_PT,
+                    )
+                    .await?;
+                    break;
+                }
+                Ok(false) => {
+                    process = update_tracking_data(db, process, &tracking_data).await?;
+                }
+                Err(report_error) => {
+                    error!(
+                        ?report_error,
+                        report_id = %tracking_data.report_id,
+                        "Report generation failed"
+                    );
+                    if process.retry_count < REPORT_GENERATION_MAX_RETRIES {
+                        let schedule_time = common_utils::date_time::now().saturating_add(
+                            time::Duration::seconds(REPORT_GENERATION_RETRY_INTERVAL_SECS),
+                        );
+                        db.as_scheduler()
+                            .retry_process(process, schedule_time)
+                            .await?;
+                    } else {
+                        tracking_data.status = ReportStatus::Failed;
+                        finish_with_tracking_data(
+                            db,
+                            process,
+                            &tracking_data,
+                            business_status::RETRIES_EXCEEDED,
+                        )
+                        .await?;
+                    }
+                    break;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        process: storage::ProcessTracker,
+        _error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        error!(%process.id, "Failed while executing workflow");
+        Ok(())
+    }
+}
+
+/// Persists the final status of the report and marks the task as finished.
+async fn finish_with_tracking_data(
+    db: &dyn crate::db::StorageInterface,
+    process: storage::ProcessTracker,
+    tracking_data: &ReportGenerationTrackingData,
+    business_status: &str,
+) -> Result<(), errors::ProcessTrackerError> {
+    let tracking_data = tracking_data.encode_to_value()?;
+
+    db.as_scheduler()
+        .update_process(
+            process,
+            storage::ProcessTrackerUpdate::Update {
+                name: None,
+                retry_count: None,
+                schedule_time: None,
+                tracking_data: Some(tracking_data),
+                business_status: Some(String::from(business_status)),
+                status: Some(storage_enums::ProcessTrackerStatus::Finish),
+                updated_at: Some(common_utils::date_time::now()),
+            },
+        )
+        .await?;
+
+    Ok(())
+}
+
+/// Persists the progress of the report generation.
+async fn update_tracking_data(
+    db: &dyn crate::db::StorageInterface,
+    process: storage::ProcessTracker,
+    tracking_data: &ReportGenerationTrackingData,
+) -> Result<storage::ProcessTracker, errors::ProcessTrackerError> {
+    let tracking_data = tracking_data.encode_to_value()?;
+
+    let updated_process = db
+        .as_scheduler()
+        .update_process(
+            process,
+            storage::ProcessTrackerUpdate::Update {
+                name: None,
+                retry_count: None,
+                schedule_time: None,
+                tracking_data: Some(tracking_data),
+                business_status: None,
+                status: None,
+                updated_at: Some(common_utils::date_time::now()),
+            },
+        )
+        .await?;
+
+    Ok(updated_process)
+}
@@ -200,3 +200,19 @@ pub(crate) async fn get_schedule_time_to_retry_mit_payments(
 
     scheduler_utils::get_time_from_delta(time_delta)
 }
+
+#[cfg(feature = "v2")]
+pub(crate) fn get_schedule_time_to_retry_mit_payments_from_policy(
+    retry_policy: &common_types::revenue_recovery::RevenueRecoveryRetryPolicy,
+    retry_count: i32,
+) -> Option<time::PrimitiveDateTime> {
+    let time_delta =
+        scheduler_utils::get_pcr_payments_retry_delay_from_policy(retry_policy, retry_count);
+
+    scheduler_utils::get_time_from_delta(time_delta).map(|schedule_time| {
+        scheduler_utils::get_pcr_payments_retry_time_outside_blackout_windows(
+            retry_policy,
+            schedule_time,
+        )
+    })
+}
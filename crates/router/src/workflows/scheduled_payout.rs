@@ -0,0 +1,112 @@
+use common_utils::ext_traits::{OptionExt, ValueExt};
+use diesel_models::process_tracker::business_status;
+use scheduler::{
+    consumer::types::process_data, utils as pt_utils, workflows::ProcessTrackerWorkflow,
+};
+
+use crate::{
+    core::payouts,
+    errors,
+    logger::error,
+    routes::SessionState,
+    types::{api, domain, storage},
+};
+
+pub struct ScheduledPayoutWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for ScheduledPayoutWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: api::PayoutRetrieveRequest = process
+            .tracking_data
+            .clone()
+            .parse_value("PayoutRetrieveRequest")?;
+
+        let merchant_id = tracking_data
+            .merchant_id
+            .clone()
+            .get_required_value("merchant_id")?;
+        let key_manager_state = &state.into();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(key_manager_state, &merchant_id, &key_store)
+            .await?;
+
+        let payout = db
+            .find_payout_by_merchant_id_payout_id(
+                &merchant_id,
+                &tracking_data.payout_id,
+                merchant_account.storage_scheme,
+            )
+            .await?;
+
+        // The payout was fulfilled or cancelled before the scheduled time
+        if payout.status != storage::enums::PayoutStatus::Scheduled {
+            db.as_scheduler()
+                .finish_process_with_business_status(
+                    process,
+                    business_status::RESOURCE_STATUS_MISMATCH,
+                )
+                .await?;
+            return Ok(());
+        }
+
+        let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(domain::Context(
+            merchant_account,
+            key_store,
+        )));
+        let request = api::PayoutActionRequest {
+            payout_id: tracking_data.payout_id.clone(),
+        };
+
+        match Box::pin(payouts::payouts_fulfill_core(
+            state.clone(),
+            merchant_context,
+            request,
+        ))
+        .await
+        {
+            Ok(_) => {
+                db.as_scheduler()
+                    .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                    .await?;
+            }
+            Err(fulfill_error) => {
+                error!(
+                    ?fulfill_error,
+                    payout_id = %tracking_data.payout_id,
+                    "Scheduled payout fulfillment failed"
+                );
+                pt_utils::retry_process_or_finish(
+                    db.as_scheduler(),
+                    process,
+                    &process_data::RetryMapping::new(60 * 5, &[(60 * 15, 5)]),
+                )
+                .await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        process: storage::ProcessTracker,
+        _error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        error!(%process.id, "Failed while executing workflow");
+        Ok(())
+    }
+}
@@ -0,0 +1,121 @@
+use common_utils::ext_traits::ValueExt;
+use diesel_models::process_tracker::business_status;
+use scheduler::{utils as pt_utils, workflows::ProcessTrackerWorkflow};
+
+use crate::{
+    core::subscriptions::{self, SubscriptionBillingOutcome},
+    errors,
+    logger::error,
+    routes::SessionState,
+    types::{domain, storage},
+};
+
+pub struct SubscriptionBillingWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for SubscriptionBillingWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: storage::SubscriptionTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("SubscriptionTrackingData")?;
+
+        let key_manager_state = &state.into();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &key_store,
+            )
+            .await?;
+
+        let subscription = db
+            .find_subscription_by_merchant_id_subscription_id(
+                &tracking_data.merchant_id,
+                &tracking_data.subscription_id,
+            )
+            .await?;
+
+        // The subscription was cancelled after the task was scheduled
+        if subscription.status == storage::enums::SubscriptionStatus::Cancelled {
+            db.as_scheduler()
+                .finish_process_with_business_status(
+                    process,
+                    business_status::RESOURCE_STATUS_MISMATCH,
+                )
+                .await?;
+            return Ok(());
+        }
+
+        let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(domain::Context(
+            merchant_account,
+            key_store,
+        )));
+
+        match subscriptions::bill_subscription(state, &merchant_context, subscription.clone())
+            .await?
+        {
+            SubscriptionBillingOutcome::Renewed { next_billing_at } => {
+                db.as_scheduler()
+                    .reset_process(process, next_billing_at)
+                    .await?;
+            }
+            SubscriptionBillingOutcome::Cancelled => {
+                db.as_scheduler()
+                    .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                    .await?;
+            }
+            SubscriptionBillingOutcome::PaymentProcessing
+            | SubscriptionBillingOutcome::PaymentFailed => {
+                match pt_utils::get_retry_schedule_time(
+                    &subscriptions::get_billing_retry_mapping(),
+                    process.retry_count,
+                ) {
+                    Some(schedule_time) => {
+                        db.as_scheduler()
+                            .retry_process(process, schedule_time)
+                            .await?
+                    }
+                    None => {
+                        subscriptions::cancel_unpaid_subscription(
+                            state,
+                            &merchant_context,
+                            &subscription,
+                        )
+                        .await?;
+                        db.as_scheduler()
+                            .finish_process_with_business_status(
+                                process,
+                                business_status::RETRIES_EXCEEDED,
+                            )
+                            .await?
+                    }
+                };
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        process: storage::ProcessTracker,
+        _error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        error!(%process.id, "Failed while executing workflow");
+        Ok(())
+    }
+}
@@ -0,0 +1,110 @@
+use common_utils::ext_traits::{Encode, ValueExt};
+use diesel_models::process_tracker::business_status;
+use scheduler::{utils as pt_utils, workflows::ProcessTrackerWorkflow};
+
+use crate::{
+    core::webhooks::{types::WebhookEventReplayTrackingData, webhook_events},
+    errors,
+    logger::{error, info},
+    routes::SessionState,
+    types::storage::{self, enums as storage_enums},
+};
+
+pub struct WebhookEventReplayWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for WebhookEventReplayWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        mut process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let mut tracking_data: WebhookEventReplayTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("WebhookEventReplayTrackingData")?;
+
+        match webhook_events::replay_next_batch(state, &mut process, &mut tracking_data).await {
+            Ok(true) => {
+                info!(
+                    replay_id = %tracking_data.replay_id,
+                    processed_count = tracking_data.processed_count,
+                    replayed_count = tracking_data.replayed_count,
+                    skipped_count = tracking_data.skipped_count,
+                    failed_count = tracking_data.failed_count,
+                    "Webhook event replay job completed"
+                );
+                let process = update_tracking_data(db, process, &tracking_data, None).await?;
+                db.as_scheduler()
+                    .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                    .await?;
+            }
+            Ok(false) => {
+                // The next batch is picked up after an interval, which rate limits the
+                // redeliveries to the merchant's webhook endpoint
+                let schedule_time =
+                    common_utils::date_time::now().saturating_add(time::Duration::seconds(
+                        webhook_events::WEBHOOK_EVENT_REPLAY_BATCH_INTERVAL_SECS,
+                    ));
+                update_tracking_data(db, process, &tracking_data, Some(schedule_time)).await?;
+            }
+            Err(replay_error) => {
+                // The progress is persisted after each event, the retry resumes after the last
+                // event which was processed before the failure
+                error!(
+                    ?replay_error,
+                    replay_id = %tracking_data.replay_id,
+                    "Webhook event replay job processing failed"
+                );
+                pt_utils::retry_process_or_finish(
+                    db.as_scheduler(),
+                    process,
+                    &pt_utils::get_short_interval_retry_mapping(),
+                )
+                .await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        process: storage::ProcessTracker,
+        _error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        error!(%process.id, "Failed while executing workflow");
+        Ok(())
+    }
+}
+
+/// Persists the progress of the replay job, and schedules the task for the next batch if a
+/// schedule time is provided.
+async fn update_tracking_data(
+    db: &dyn crate::db::StorageInterface,
+    process: storage::ProcessTracker,
+    tracking_data: &WebhookEventReplayTrackingData,
+    schedule_time: Option<time::PrimitiveDateTime>,
+) -> Result<storage::ProcessTracker, errors::ProcessTrackerError> {
+    let tracking_data = tracking_data.encode_to_value()?;
+
+    let updated_process = db
+        .as_scheduler()
+        .update_process(
+            process,
+            storage::ProcessTrackerUpdate::Update {
+                name: None,
+                retry_count: schedule_time.map(|_| 0),
+                schedule_time,
+                tracking_data: Some(tracking_data),
+                business_status: None,
+                status: schedule_time.map(|_| storage_enums::ProcessTrackerStatus::Pending),
+                updated_at: Some(common_utils::date_time::now()),
+            },
+        )
+        .await?;
+
+    Ok(updated_process)
+}
@@ -986,6 +986,8 @@ impl Default for PaymentAuthorizeType {
             surcharge_details: None,
             request_incremental_authorization: false,
             request_extended_authorization: None,
+            enable_partial_authorization: None,
+            l2_l3_data: None,
             metadata: None,
             authentication_data: None,
             customer_acceptance: None,
@@ -1157,6 +1159,7 @@ pub fn get_connector_metadata(
             connector_response_reference_id: _,
             incremental_authorization_allowed: _,
             charges: _,
+            authorized_amount: _,
         }) => connector_metadata,
         _ => None,
     }
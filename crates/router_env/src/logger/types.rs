@@ -118,6 +118,14 @@ pub enum Flow {
     PaymentMethodsCreate,
     /// Payment methods migrate flow.
     PaymentMethodsMigrate,
+    /// Payment methods migration job create flow.
+    PaymentMethodsMigrationJobCreate,
+    /// Payment methods migration job execute flow.
+    PaymentMethodsMigrationJobExecute,
+    /// Payment methods migration job retrieve flow.
+    PaymentMethodsMigrationJobRetrieve,
+    /// Payment methods migration job report flow.
+    PaymentMethodsMigrationJobReport,
     /// Payment methods list flow.
     PaymentMethodsList,
     /// Payment method save flow
@@ -140,6 +148,8 @@ pub enum Flow {
     PaymentMethodsDelete,
     /// Default Payment method flow.
     DefaultPaymentMethodsSet,
+    /// Toggle card account updater flow.
+    ToggleCardAccountUpdater,
     /// Payments create flow.
     PaymentsCreate,
     /// Payments Retrieve flow.
@@ -205,6 +215,32 @@ pub enum Flow {
     PayoutsFilter,
     /// Payouts accounts flow.
     PayoutsAccounts,
+    /// Payouts batch create flow.
+    PayoutsBatchCreate,
+    /// Payouts batch retrieve flow.
+    PayoutsBatchRetrieve,
+    /// Payouts batch report flow.
+    PayoutsBatchReport,
+    /// Subscription plan create flow.
+    SubscriptionPlanCreate,
+    /// Subscription plan retrieve flow.
+    SubscriptionPlanRetrieve,
+    /// Subscription plan list flow.
+    SubscriptionPlanList,
+    /// Subscription plan update flow.
+    SubscriptionPlanUpdate,
+    /// Subscription create flow.
+    SubscriptionCreate,
+    /// Subscription retrieve flow.
+    SubscriptionRetrieve,
+    /// Subscription list flow.
+    SubscriptionList,
+    /// Subscription update flow.
+    SubscriptionUpdate,
+    /// Subscription cancel flow.
+    SubscriptionCancel,
+    /// Subscription invoice list flow.
+    SubscriptionInvoiceList,
     /// Payout link initiate flow
     PayoutLinkInitiate,
     /// Payments Redirect flow
@@ -255,6 +291,8 @@ pub enum Flow {
     RoutingUpdateDefaultConfig,
     /// Routing delete config
     RoutingDeleteConfig,
+    /// Simulate a routing algorithm over past payments
+    RoutingSimulate,
     /// Toggle dynamic routing
     ToggleDynamicRouting,
     /// Update dynamic routing config
@@ -483,6 +521,22 @@ pub enum Flow {
     TerminateTwoFactorAuth,
     /// Check 2FA status
     TwoFactorAuthStatus,
+    /// Begin passkey registration
+    PasskeyRegistrationBegin,
+    /// Finish passkey registration
+    PasskeyRegistrationFinish,
+    /// Begin passkey authentication
+    PasskeyBegin,
+    /// Verify passkey
+    PasskeyVerify,
+    /// List passkeys of the user
+    PasskeyList,
+    /// Delete passkey
+    PasskeyDelete,
+    /// Begin sign in with passkey
+    PasskeySignInBegin,
+    /// Sign in with passkey
+    PasskeySignIn,
     /// Create user authentication method
     CreateUserAuthenticationMethod,
     /// Update user authentication method
@@ -523,12 +577,30 @@ pub enum Flow {
     WebhookEventDeliveryAttemptList,
     /// Manually retry the delivery for a webhook event
     WebhookEventDeliveryRetry,
+    /// Create a job to redeliver the webhook events of a profile in bulk
+    WebhookEventReplay,
+    /// Retrieve the progress of a webhook event replay job
+    WebhookEventReplayRetrieve,
+    /// Create a webhook endpoint for a profile
+    WebhookEndpointCreate,
+    /// Retrieve a webhook endpoint
+    WebhookEndpointRetrieve,
+    /// List webhook endpoints for a profile
+    WebhookEndpointList,
+    /// Update a webhook endpoint
+    WebhookEndpointUpdate,
+    /// Delete a webhook endpoint
+    WebhookEndpointDelete,
+    /// Rotate the secret of a webhook endpoint
+    WebhookEndpointSecretRotate,
     /// Retrieve status of the Poll
     RetrievePollStatus,
     /// Toggles the extended card info feature in profile level
     ToggleExtendedCardInfo,
     /// Toggles the extended card info feature in profile level
     ToggleConnectorAgnosticMit,
+    /// Rotate the key used to sign outgoing webhooks for a profile
+    ProfileWebhookSecretRotate,
     /// Get the extended card info associated to a payment_id
     GetExtendedCardInfo,
     /// Manually update the refund details like status, error code, error message etc.
@@ -9,6 +9,17 @@ pub struct RetryMapping {
     pub frequencies: Vec<(i32, i32)>, // (frequency, count)
 }
 
+impl RetryMapping {
+    /// Retries a failed task `start_after` seconds after it is scheduled, and then as per the
+    /// `(frequency, count)` pairs, with the frequencies in seconds
+    pub fn new(start_after: i32, frequencies: &[(i32, i32)]) -> Self {
+        Self {
+            start_after,
+            frequencies: frequencies.to_vec(),
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize)]
 pub struct ConnectorPTMapping {
     pub default_mapping: RetryMapping,
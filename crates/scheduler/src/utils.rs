@@ -369,6 +369,113 @@ pub fn get_pcr_payments_retry_schedule_time(
     }
 }
 
+/// Get the delay for a revenue recovery payment retry based on the retry policy configured on the
+/// profile
+pub fn get_pcr_payments_retry_delay_from_policy(
+    policy: &common_types::revenue_recovery::RevenueRecoveryRetryPolicy,
+    retry_count: i32,
+) -> Option<i32> {
+    if retry_count > i32::from(policy.max_retry_attempts) {
+        return None;
+    }
+
+    // For first try, get the `start_after` time
+    if retry_count == 0 {
+        return i32::try_from(policy.start_after_seconds).ok();
+    }
+
+    let frequencies = policy
+        .retry_intervals
+        .iter()
+        .filter_map(|step| {
+            i32::try_from(step.interval_in_seconds)
+                .ok()
+                .map(|interval| (interval, i32::from(step.count)))
+        })
+        .collect::<Vec<_>>();
+
+    // Keep using the interval of the last step until the maximum retry attempts are exhausted
+    get_delay(retry_count, &frequencies)
+        .or_else(|| frequencies.last().map(|&(frequency, _)| frequency))
+}
+
+/// Move the schedule time of a revenue recovery payment retry to the end of the blackout window it
+/// falls in, if any
+pub fn get_pcr_payments_retry_time_outside_blackout_windows(
+    policy: &common_types::revenue_recovery::RevenueRecoveryRetryPolicy,
+    schedule_time: time::PrimitiveDateTime,
+) -> time::PrimitiveDateTime {
+    let mut schedule_time = schedule_time;
+
+    // Bounded so that windows covering the whole day cannot postpone the retry indefinitely
+    for _ in 0..=policy.blackout_windows.len() {
+        let Some(window) = policy
+            .blackout_windows
+            .iter()
+            .find(|window| window.contains_hour(schedule_time.hour()))
+        else {
+            break;
+        };
+        let Ok(window_end_time) = time::Time::from_hms(window.end_hour, 0, 0) else {
+            break;
+        };
+
+        let window_end = schedule_time.replace_time(window_end_time);
+        schedule_time = if window_end > schedule_time {
+            window_end
+        } else {
+            window_end.saturating_add(time::Duration::DAY)
+        };
+    }
+
+    schedule_time
+}
+
+/// Get the time at which a failed task is to be retried as per the retry mapping, or `None` once
+/// the retries are exhausted
+pub fn get_retry_schedule_time(
+    retry_mapping: &process_data::RetryMapping,
+    retry_count: i32,
+) -> Option<time::PrimitiveDateTime> {
+    // For first try, get the `start_after` time
+    let time_delta = if retry_count == 0 {
+        Some(retry_mapping.start_after)
+    } else {
+        get_delay(retry_count, &retry_mapping.frequencies)
+    };
+
+    get_time_from_delta(time_delta)
+}
+
+/// Retry mapping of tasks which are retried soon after they fail: after a minute, and then up to 5
+/// more times at intervals of 5 minutes
+pub fn get_short_interval_retry_mapping() -> process_data::RetryMapping {
+    process_data::RetryMapping::new(60, &[(60 * 5, 5)])
+}
+
+/// Retry a failed task as per the retry mapping, or finish it with the `RETRIES_EXCEEDED` business
+/// status once the retries are exhausted. Returns whether the task was scheduled to be retried.
+pub async fn retry_process_or_finish(
+    db: &dyn SchedulerInterface,
+    process: storage::ProcessTracker,
+    retry_mapping: &process_data::RetryMapping,
+) -> Result<bool, errors::ProcessTrackerError> {
+    match get_retry_schedule_time(retry_mapping, process.retry_count) {
+        Some(schedule_time) => {
+            db.retry_process(process, schedule_time).await?;
+            Ok(true)
+        }
+        None => {
+            db.finish_process_with_business_status(
+                process,
+                storage::business_status::RETRIES_EXCEEDED,
+            )
+            .await?;
+            Ok(false)
+        }
+    }
+}
+
 /// Get the delay based on the retry count
 pub fn get_delay<'a>(
     retry_count: i32,
@@ -425,8 +532,45 @@ where
 
 #[cfg(test)]
 mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use common_types::revenue_recovery::{
+        RetryBlackoutWindow, RetryIntervalStep, RevenueRecoveryRetryPolicy,
+    };
+
     use super::*;
 
+    fn get_retry_policy(blackout_windows: Vec<(u8, u8)>) -> RevenueRecoveryRetryPolicy {
+        RevenueRecoveryRetryPolicy {
+            max_retry_attempts: 5,
+            start_after_seconds: 60,
+            retry_intervals: vec![
+                RetryIntervalStep {
+                    interval_in_seconds: 100,
+                    count: 2,
+                },
+                RetryIntervalStep {
+                    interval_in_seconds: 200,
+                    count: 1,
+                },
+            ],
+            blackout_windows: blackout_windows
+                .into_iter()
+                .map(|(start_hour, end_hour)| RetryBlackoutWindow {
+                    start_hour,
+                    end_hour,
+                })
+                .collect(),
+        }
+    }
+
+    fn get_time(day: u8, hour: u8, minute: u8) -> time::PrimitiveDateTime {
+        time::PrimitiveDateTime::new(
+            time::Date::from_calendar_date(2024, time::Month::January, day).unwrap(),
+            time::Time::from_hms(hour, minute, 0).unwrap(),
+        )
+    }
+
     #[test]
     fn test_get_delay() {
         let frequency_count = vec![(300, 10), (600, 5), (1800, 3), (3600, 2)];
@@ -455,4 +599,113 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_get_retry_schedule_time() {
+        let retry_mapping = process_data::RetryMapping::new(60, &[(300, 2), (600, 3)]);
+        let get_retry_delay_in_seconds = |retry_count| {
+            let now = common_utils::date_time::now();
+            get_retry_schedule_time(&retry_mapping, retry_count)
+                .map(|schedule_time| (schedule_time - now).whole_seconds())
+        };
+
+        let retry_counts_and_expected_delays = [
+            (0, Some(60)),
+            (1, Some(300)),
+            (2, Some(300)),
+            (3, Some(600)),
+            (5, Some(600)),
+            // The task is finished once the retries are exhausted
+            (6, None),
+        ];
+
+        for (retry_count, expected_delay) in retry_counts_and_expected_delays {
+            assert_eq!(
+                get_retry_delay_in_seconds(retry_count),
+                expected_delay,
+                "Delay and expected delay differ for `retry_count` = {retry_count}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_retry_blackout_window_contains_hour() {
+        let window = RetryBlackoutWindow {
+            start_hour: 9,
+            end_hour: 17,
+        };
+        let midnight_wrapping_window = RetryBlackoutWindow {
+            start_hour: 22,
+            end_hour: 6,
+        };
+
+        for hour in [9, 12, 16] {
+            assert!(window.contains_hour(hour), "hour = {hour}");
+            assert!(
+                !midnight_wrapping_window.contains_hour(hour),
+                "hour = {hour}"
+            );
+        }
+        for hour in [22, 23, 0, 5] {
+            assert!(!window.contains_hour(hour), "hour = {hour}");
+            assert!(
+                midnight_wrapping_window.contains_hour(hour),
+                "hour = {hour}"
+            );
+        }
+        assert!(!window.contains_hour(17));
+        assert!(!midnight_wrapping_window.contains_hour(6));
+    }
+
+    #[test]
+    fn test_get_pcr_payments_retry_delay_from_policy() {
+        let policy = get_retry_policy(Vec::new());
+
+        let retry_counts_and_expected_delays = [
+            (0, Some(60)),
+            (1, Some(100)),
+            (2, Some(100)),
+            (3, Some(200)),
+            // The interval of the last step is used once the steps are used up
+            (5, Some(200)),
+            (6, None),
+        ];
+
+        for (retry_count, expected_delay) in retry_counts_and_expected_delays {
+            assert_eq!(
+                get_pcr_payments_retry_delay_from_policy(&policy, retry_count),
+                expected_delay,
+                "Delay and expected delay differ for `retry_count` = {retry_count}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_get_pcr_payments_retry_time_outside_midnight_wrapping_blackout_window() {
+        let policy = get_retry_policy(vec![(22, 6)]);
+
+        assert_eq!(
+            get_pcr_payments_retry_time_outside_blackout_windows(&policy, get_time(10, 23, 30)),
+            get_time(11, 6, 0)
+        );
+        assert_eq!(
+            get_pcr_payments_retry_time_outside_blackout_windows(&policy, get_time(10, 3, 15)),
+            get_time(10, 6, 0)
+        );
+        assert_eq!(
+            get_pcr_payments_retry_time_outside_blackout_windows(&policy, get_time(10, 12, 0)),
+            get_time(10, 12, 0)
+        );
+    }
+
+    #[test]
+    fn test_get_pcr_payments_retry_time_with_all_hours_blacked_out() {
+        let policy = get_retry_policy(vec![(0, 12), (12, 0)]);
+
+        // The retry is postponed a bounded number of times instead of indefinitely
+        assert_eq!(
+            get_pcr_payments_retry_time_outside_blackout_windows(&policy, get_time(10, 10, 30)),
+            get_time(11, 12, 0)
+        );
+    }
 }
@@ -134,6 +134,35 @@ impl<T: DatabaseStore> PaymentMethodInterface for KVRouterStore<T> {
             .await
     }
 
+    // not supported in kv
+    #[cfg(all(
+        any(feature = "v1", feature = "v2"),
+        not(feature = "payment_methods_v2")
+    ))]
+    #[instrument(skip_all)]
+    async fn find_payment_method_by_merchant_id_payment_method_status(
+        &self,
+        state: &KeyManagerState,
+        key_store: &MerchantKeyStore,
+        merchant_id: &id_type::MerchantId,
+        payment_method: common_enums::PaymentMethod,
+        status: common_enums::PaymentMethodStatus,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<DomainPaymentMethod>, errors::StorageError> {
+        self.router_store
+            .find_payment_method_by_merchant_id_payment_method_status(
+                state,
+                key_store,
+                merchant_id,
+                payment_method,
+                status,
+                limit,
+                offset,
+            )
+            .await
+    }
+
     #[cfg(all(feature = "v2", feature = "payment_methods_v2"))]
     #[instrument(skip_all)]
     async fn insert_payment_method(
@@ -527,6 +556,37 @@ impl<T: DatabaseStore> PaymentMethodInterface for RouterStore<T> {
             })
     }
 
+    #[cfg(all(
+        any(feature = "v1", feature = "v2"),
+        not(feature = "payment_methods_v2")
+    ))]
+    #[instrument(skip_all)]
+    async fn find_payment_method_by_merchant_id_payment_method_status(
+        &self,
+        state: &KeyManagerState,
+        key_store: &MerchantKeyStore,
+        merchant_id: &id_type::MerchantId,
+        payment_method: common_enums::PaymentMethod,
+        status: common_enums::PaymentMethodStatus,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<DomainPaymentMethod>, errors::StorageError> {
+        let conn = pg_connection_read(self).await?;
+        self.find_resources(
+            state,
+            key_store,
+            PaymentMethod::find_by_merchant_id_payment_method_status(
+                &conn,
+                merchant_id,
+                payment_method,
+                status,
+                limit,
+                offset,
+            ),
+        )
+        .await
+    }
+
     #[instrument(skip_all)]
     async fn insert_payment_method(
         &self,
@@ -855,6 +915,35 @@ impl PaymentMethodInterface for MockDb {
         i64::try_from(count).change_context(errors::StorageError::MockDbError)
     }
 
+    #[cfg(all(
+        any(feature = "v1", feature = "v2"),
+        not(feature = "payment_methods_v2")
+    ))]
+    async fn find_payment_method_by_merchant_id_payment_method_status(
+        &self,
+        state: &KeyManagerState,
+        key_store: &MerchantKeyStore,
+        merchant_id: &id_type::MerchantId,
+        payment_method: common_enums::PaymentMethod,
+        status: common_enums::PaymentMethodStatus,
+        _limit: Option<i64>,
+        _offset: Option<i64>,
+    ) -> CustomResult<Vec<DomainPaymentMethod>, errors::StorageError> {
+        let payment_methods = self.payment_methods.lock().await;
+        self.get_resources(
+            state,
+            key_store,
+            payment_methods,
+            |pm| {
+                pm.merchant_id == *merchant_id
+                    && pm.payment_method == Some(payment_method)
+                    && pm.status == status
+            },
+            "cannot find payment method".to_string(),
+        )
+        .await
+    }
+
     async fn insert_payment_method(
         &self,
         _state: &KeyManagerState,
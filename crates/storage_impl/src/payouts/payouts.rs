@@ -123,6 +123,7 @@ impl<T: DatabaseStore> PayoutsInterface for KVRouterStore<T> {
                     payout_link_id: new.payout_link_id.clone(),
                     client_secret: new.client_secret.clone(),
                     priority: new.priority,
+                    scheduled_at: new.scheduled_at,
                 };
 
                 let redis_entry = kv::TypedSql {
@@ -937,6 +938,7 @@ impl DataModelExt for Payouts {
             payout_link_id: self.payout_link_id,
             client_secret: self.client_secret,
             priority: self.priority,
+            scheduled_at: self.scheduled_at,
         }
     }
 
@@ -966,6 +968,7 @@ impl DataModelExt for Payouts {
             payout_link_id: storage_model.payout_link_id,
             client_secret: storage_model.client_secret,
             priority: storage_model.priority,
+            scheduled_at: storage_model.scheduled_at,
         }
     }
 }
@@ -998,6 +1001,7 @@ impl DataModelExt for PayoutsNew {
             payout_link_id: self.payout_link_id,
             client_secret: self.client_secret,
             priority: self.priority,
+            scheduled_at: self.scheduled_at,
         }
     }
 
@@ -1027,6 +1031,7 @@ impl DataModelExt for PayoutsNew {
             payout_link_id: storage_model.payout_link_id,
             client_secret: storage_model.client_secret,
             priority: storage_model.priority,
+            scheduled_at: storage_model.scheduled_at,
         }
     }
 }
@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE events DROP COLUMN IF EXISTS webhook_endpoint_id;
+
+DROP INDEX IF EXISTS webhook_endpoint_merchant_id_profile_id_index;
+
+DROP TABLE IF EXISTS webhook_endpoint;
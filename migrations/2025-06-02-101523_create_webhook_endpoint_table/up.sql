@@ -0,0 +1,17 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS webhook_endpoint (
+    endpoint_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    profile_id VARCHAR(64) NOT NULL,
+    url TEXT NOT NULL,
+    secret VARCHAR(255) NOT NULL,
+    enabled_events "EventType"[] NOT NULL,
+    is_active BOOLEAN NOT NULL DEFAULT TRUE,
+    description VARCHAR(255),
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    last_modified_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE INDEX IF NOT EXISTS webhook_endpoint_merchant_id_profile_id_index ON webhook_endpoint (merchant_id, profile_id);
+
+ALTER TABLE events ADD COLUMN IF NOT EXISTS webhook_endpoint_id VARCHAR(64);
@@ -0,0 +1,8 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS blocklist_merchant_id_status_index;
+
+ALTER TABLE blocklist
+DROP COLUMN IF EXISTS expires_at,
+DROP COLUMN IF EXISTS reason,
+DROP COLUMN IF EXISTS created_by,
+DROP COLUMN IF EXISTS status;
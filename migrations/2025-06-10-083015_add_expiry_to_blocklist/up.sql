@@ -0,0 +1,8 @@
+-- Your SQL goes here
+ALTER TABLE blocklist
+ADD COLUMN IF NOT EXISTS expires_at TIMESTAMP,
+ADD COLUMN IF NOT EXISTS reason VARCHAR(255),
+ADD COLUMN IF NOT EXISTS created_by VARCHAR(255),
+ADD COLUMN IF NOT EXISTS status VARCHAR(32) NOT NULL DEFAULT 'active';
+
+CREATE INDEX IF NOT EXISTS blocklist_merchant_id_status_index ON blocklist (merchant_id, status);
@@ -0,0 +1,7 @@
+-- Your SQL goes here
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'payment_method_updated';
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'payment_method_closed';
+
+ALTER TYPE "EventObjectType" ADD VALUE IF NOT EXISTS 'payment_method_details';
+
+ALTER TYPE "EventClass" ADD VALUE IF NOT EXISTS 'payment_methods';
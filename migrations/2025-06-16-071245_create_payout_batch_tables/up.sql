@@ -0,0 +1,32 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS payout_batch (
+    batch_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    profile_id VARCHAR(64),
+    status VARCHAR(32) NOT NULL,
+    file_name VARCHAR(255),
+    total_count INTEGER NOT NULL,
+    succeeded_count INTEGER NOT NULL DEFAULT 0,
+    failed_count INTEGER NOT NULL DEFAULT 0,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    last_modified_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE INDEX IF NOT EXISTS payout_batch_merchant_id_index ON payout_batch (merchant_id);
+
+CREATE TABLE IF NOT EXISTS payout_batch_item (
+    batch_id VARCHAR(64) NOT NULL,
+    row_number INTEGER NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    status VARCHAR(32) NOT NULL,
+    payout_request BYTEA,
+    payout_id VARCHAR(64),
+    payout_status "PayoutStatus",
+    error_code VARCHAR(64),
+    error_message TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    last_modified_at TIMESTAMP NOT NULL DEFAULT now(),
+    PRIMARY KEY (batch_id, row_number)
+);
+
+CREATE INDEX IF NOT EXISTS payout_batch_item_batch_id_status_index ON payout_batch_item (batch_id, status);
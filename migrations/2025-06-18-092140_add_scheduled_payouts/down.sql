@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE payouts DROP COLUMN IF EXISTS scheduled_at;
+
+-- Enum values cannot be removed from a type, the 'scheduled' value of "PayoutStatus" is retained
@@ -0,0 +1,13 @@
+-- This file should undo anything in `up.sql`
+-- The values added to the `EventType`, `EventObjectType` and `EventClass` enums are retained
+DROP INDEX IF EXISTS subscription_invoice_subscription_id_status_index;
+
+DROP TABLE IF EXISTS subscription_invoice;
+
+DROP INDEX IF EXISTS subscription_merchant_id_customer_id_index;
+
+DROP TABLE IF EXISTS subscription;
+
+DROP INDEX IF EXISTS subscription_plan_merchant_id_index;
+
+DROP TABLE IF EXISTS subscription_plan;
@@ -0,0 +1,72 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS subscription_plan (
+    plan_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    name VARCHAR(255) NOT NULL,
+    description TEXT,
+    amount BIGINT NOT NULL,
+    currency "Currency" NOT NULL,
+    billing_interval VARCHAR(32) NOT NULL,
+    interval_count INTEGER NOT NULL DEFAULT 1,
+    trial_period_days INTEGER,
+    is_active BOOLEAN NOT NULL DEFAULT TRUE,
+    metadata JSONB,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    last_modified_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE INDEX IF NOT EXISTS subscription_plan_merchant_id_index ON subscription_plan (merchant_id);
+
+CREATE TABLE IF NOT EXISTS subscription (
+    subscription_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    profile_id VARCHAR(64) NOT NULL,
+    customer_id VARCHAR(64) NOT NULL,
+    plan_id VARCHAR(64) NOT NULL,
+    status VARCHAR(32) NOT NULL,
+    payment_method_id VARCHAR(64),
+    mandate_id VARCHAR(64),
+    current_period_start TIMESTAMP NOT NULL,
+    current_period_end TIMESTAMP NOT NULL,
+    trial_end TIMESTAMP,
+    cancel_at_period_end BOOLEAN NOT NULL DEFAULT FALSE,
+    cancelled_at TIMESTAMP,
+    credit_balance BIGINT NOT NULL DEFAULT 0,
+    metadata JSONB,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    last_modified_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE INDEX IF NOT EXISTS subscription_merchant_id_customer_id_index ON subscription (merchant_id, customer_id);
+
+CREATE TABLE IF NOT EXISTS subscription_invoice (
+    invoice_id VARCHAR(64) PRIMARY KEY,
+    subscription_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    customer_id VARCHAR(64) NOT NULL,
+    plan_id VARCHAR(64) NOT NULL,
+    billing_reason VARCHAR(32) NOT NULL,
+    status VARCHAR(32) NOT NULL,
+    amount BIGINT NOT NULL,
+    currency "Currency" NOT NULL,
+    period_start TIMESTAMP NOT NULL,
+    period_end TIMESTAMP NOT NULL,
+    payment_id VARCHAR(64),
+    attempt_count INTEGER NOT NULL DEFAULT 0,
+    error_code VARCHAR(64),
+    error_message TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    last_modified_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE INDEX IF NOT EXISTS subscription_invoice_subscription_id_status_index ON subscription_invoice (subscription_id, status);
+
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'subscription_created';
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'subscription_renewed';
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'subscription_updated';
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'subscription_payment_failed';
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'subscription_cancelled';
+
+ALTER TYPE "EventObjectType" ADD VALUE IF NOT EXISTS 'subscription_details';
+
+ALTER TYPE "EventClass" ADD VALUE IF NOT EXISTS 'subscriptions';
@@ -0,0 +1,8 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS payment_method_migration_job_item_job_id_status_index;
+
+DROP TABLE IF EXISTS payment_method_migration_job_item;
+
+DROP INDEX IF EXISTS payment_method_migration_job_merchant_id_index;
+
+DROP TABLE IF EXISTS payment_method_migration_job;
@@ -0,0 +1,34 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS payment_method_migration_job (
+    job_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    merchant_connector_id VARCHAR(64),
+    status VARCHAR(32) NOT NULL,
+    dry_run BOOLEAN NOT NULL DEFAULT FALSE,
+    file_name VARCHAR(255),
+    total_count INTEGER NOT NULL,
+    valid_count INTEGER NOT NULL DEFAULT 0,
+    invalid_count INTEGER NOT NULL DEFAULT 0,
+    migrated_count INTEGER NOT NULL DEFAULT 0,
+    failed_count INTEGER NOT NULL DEFAULT 0,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    last_modified_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE INDEX IF NOT EXISTS payment_method_migration_job_merchant_id_index ON payment_method_migration_job (merchant_id);
+
+CREATE TABLE IF NOT EXISTS payment_method_migration_job_item (
+    job_id VARCHAR(64) NOT NULL,
+    line_number INTEGER NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    status VARCHAR(32) NOT NULL,
+    record BYTEA,
+    customer_id VARCHAR(64),
+    payment_method_id VARCHAR(64),
+    error_message TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    last_modified_at TIMESTAMP NOT NULL DEFAULT now(),
+    PRIMARY KEY (job_id, line_number)
+);
+
+CREATE INDEX IF NOT EXISTS payment_method_migration_job_item_job_id_status_index ON payment_method_migration_job_item (job_id, status);
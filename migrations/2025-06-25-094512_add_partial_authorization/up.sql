@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TYPE "AttemptStatus" ADD VALUE IF NOT EXISTS 'partially_authorized';
+
+ALTER TABLE payment_intent ADD COLUMN IF NOT EXISTS enable_partial_authorization BOOLEAN;
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE api_keys
+DROP COLUMN IF EXISTS permission_groups,
+DROP COLUMN IF EXISTS profile_id;
@@ -0,0 +1,14 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS analytics_digest_subscription (
+    subscription_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    org_id VARCHAR(32) NOT NULL,
+    role_id VARCHAR(64) NOT NULL,
+    frequency VARCHAR(32) NOT NULL,
+    created_by VARCHAR(64) NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    last_modified_at TIMESTAMP NOT NULL DEFAULT now(),
+    last_sent_at TIMESTAMP
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS analytics_digest_subscription_merchant_id_role_id_index ON analytics_digest_subscription (merchant_id, role_id);
@@ -0,0 +1,15 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS user_passkeys (
+    passkey_id VARCHAR(64) PRIMARY KEY,
+    user_id VARCHAR(64) NOT NULL,
+    credential_id TEXT NOT NULL,
+    name VARCHAR(255) NOT NULL,
+    passkey JSONB NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    last_modified_at TIMESTAMP NOT NULL DEFAULT now(),
+    last_used_at TIMESTAMP
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS user_passkeys_credential_id_index ON user_passkeys (credential_id);
+
+CREATE INDEX IF NOT EXISTS user_passkeys_user_id_index ON user_passkeys (user_id);
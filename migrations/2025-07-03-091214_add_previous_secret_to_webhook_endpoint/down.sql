@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE webhook_endpoint
+DROP COLUMN IF EXISTS previous_secret,
+DROP COLUMN IF EXISTS previous_secret_expires_at;
@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TABLE webhook_endpoint
+ADD COLUMN IF NOT EXISTS previous_secret VARCHAR(255) DEFAULT NULL,
+ADD COLUMN IF NOT EXISTS previous_secret_expires_at TIMESTAMP DEFAULT NULL;
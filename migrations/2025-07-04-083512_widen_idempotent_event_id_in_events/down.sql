@@ -0,0 +1,2 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE events ALTER COLUMN idempotent_event_id TYPE VARCHAR(64);
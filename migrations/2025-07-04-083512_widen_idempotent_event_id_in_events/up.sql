@@ -0,0 +1,3 @@
+-- Your SQL goes here
+-- Events delivered to webhook endpoints have the endpoint id appended to their idempotent event id
+ALTER TABLE events ALTER COLUMN idempotent_event_id TYPE VARCHAR(255);